@@ -0,0 +1,822 @@
+//! Generates a module of precomputed constants for a fixed base.
+//!
+//! ```text
+//! gen_fixed_base --name <name>
+//!                (--bytes <hex> | --personalization <domain> --message <message>)
+//!                [--curve pallas|vesta] [--window-size <K>] [--short-widths <N>,...]
+//!                [--ecc-crate <path>] [--out <file>]
+//! ```
+//!
+//! The generator is given either as the hex encoding of its compressed point
+//! encoding, or as the output of `hash_to_curve(domain)(message)`.
+//!
+//! The emitted module contains the $z$- and $u$-values for full-width windows and
+//! for the windows of short scalars of each of the given bit widths (64 by
+//! default), and a type implementing [`FixedPoints`] with a `FullWidth` variant
+//! (also used for base field elements) and a variant per short width. Tables for
+//! 64-bit short scalars use the variant `Short` and constants such as `Z_SHORT`;
+//! tables for `N`-bit short scalars use `ShortN` and `Z_SHORT_N`. The short tables
+//! are also returned by `u_short::<N>()`, `z_short::<N>()` and
+//! `lagrange_coeffs_short::<N>()` for every variant. The Lagrange coefficients
+//! are recomputed from the generator at runtime.
+//!
+//! Before anything is emitted, the tables are checked to reproduce $[k]G$ for
+//! the largest and for random scalars $k$ of each width. The emitted module
+//! includes the same check as a test. The output is not formatted; run it
+//! through `rustfmt`.
+
+use std::{fmt::Write, fs, process};
+
+use ff::Field;
+use group::{prime::PrimeCurveAffine, Curve, GroupEncoding};
+use halo2_ecc::{
+    chip::{
+        compute_lagrange_coeffs, find_zs_and_us, mul_fixed_from_tables, num_windows, EccCurve,
+        L_SCALAR, L_VALUE,
+    },
+    gadget::FixedPoints,
+};
+use pasta_curves::{
+    arithmetic::{CurveAffine, CurveExt, FieldExt},
+    pallas, vesta,
+};
+
+const USAGE: &str = "\
+usage: gen_fixed_base --name <name>
+                      (--bytes <hex> | --personalization <domain> --message <message>)
+                      [--curve pallas|vesta] [--window-size <K>] [--short-widths <N>,...]
+                      [--ecc-crate <path>] [--out <file>]";
+
+/// Number of random scalars used to check the tables.
+const NUM_CHECKS: usize = 10;
+
+/// How the generator is specified.
+enum Generator {
+    /// Compressed point encoding.
+    Bytes(Vec<u8>),
+    /// `hash_to_curve(personalization)(message)`
+    HashToCurve {
+        personalization: String,
+        message: String,
+    },
+}
+
+struct Args {
+    /// Name of the fixed base, in snake case.
+    name: String,
+    generator: Generator,
+    curve: String,
+    window_size: usize,
+    /// Bit widths of the short scalars to emit tables for, in increasing order.
+    short_widths: Vec<usize>,
+    /// Path under which the emitted module refers to `halo2_ecc`.
+    ecc_crate: String,
+    out: Option<String>,
+}
+
+impl Args {
+    fn parse() -> Result<Self, String> {
+        let mut name = None;
+        let mut bytes = None;
+        let mut personalization = None;
+        let mut message = None;
+        let mut curve = String::from("pallas");
+        let mut window_size = halo2_ecc::gadget::FIXED_BASE_WINDOW_SIZE;
+        let mut short_widths = vec![L_VALUE];
+        let mut ecc_crate = String::from("halo2_ecc");
+        let mut out = None;
+
+        let mut args = std::env::args().skip(1);
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| format!("missing value for {}", arg))
+            };
+            match arg.as_str() {
+                "--name" => name = Some(value()?),
+                "--bytes" => bytes = Some(parse_hex(&value()?)?),
+                "--personalization" => personalization = Some(value()?),
+                "--message" => message = Some(value()?),
+                "--curve" => curve = value()?,
+                "--window-size" => {
+                    window_size = value()?
+                        .parse()
+                        .map_err(|_| String::from("window size must be an integer"))?
+                }
+                "--short-widths" => short_widths = parse_widths(&value()?)?,
+                "--ecc-crate" => ecc_crate = value()?,
+                "--out" => out = Some(value()?),
+                _ => return Err(format!("unexpected argument {}", arg)),
+            }
+        }
+
+        let name = name.ok_or("missing --name")?;
+        if name.is_empty()
+            || !name
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        {
+            return Err(String::from("name must be in snake case"));
+        }
+
+        let generator = match (bytes, personalization, message) {
+            (Some(bytes), None, None) => Generator::Bytes(bytes),
+            (None, Some(personalization), Some(message)) => Generator::HashToCurve {
+                personalization,
+                message,
+            },
+            _ => {
+                return Err(String::from(
+                    "expected either --bytes, or both --personalization and --message",
+                ))
+            }
+        };
+
+        Ok(Args {
+            name,
+            generator,
+            curve,
+            window_size,
+            short_widths,
+            ecc_crate,
+            out,
+        })
+    }
+
+    /// The arguments that determine the emitted constants, for the module docs.
+    fn invocation(&self) -> String {
+        let generator = match &self.generator {
+            Generator::Bytes(bytes) => format!("--bytes {}", to_hex(bytes)),
+            Generator::HashToCurve {
+                personalization,
+                message,
+            } => format!(
+                "--personalization {:?} --message {:?}",
+                personalization, message
+            ),
+        };
+        let short_widths: Vec<_> = self.short_widths.iter().map(|w| w.to_string()).collect();
+        format!(
+            "gen_fixed_base --name {} {} --curve {} --window-size {} --short-widths {}",
+            self.name,
+            generator,
+            self.curve,
+            self.window_size,
+            short_widths.join(",")
+        )
+    }
+}
+
+fn parse_hex(hex: &str) -> Result<Vec<u8>, String> {
+    if hex.len() % 2 != 0 || !hex.is_ascii() {
+        return Err(format!("invalid hex string {}", hex));
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&hex[i..i + 2], 16)
+                .map_err(|_| format!("invalid hex string {}", hex))
+        })
+        .collect()
+}
+
+/// Parses a comma-separated list of short scalar bit widths, each of which must be
+/// supported by the short fixed-base scalar multiplication.
+fn parse_widths(widths: &str) -> Result<Vec<usize>, String> {
+    let mut widths = widths
+        .split(',')
+        .map(|width| match width.parse() {
+            Ok(width) if width > 0 && width < L_SCALAR - 1 => Ok(width),
+            _ => Err(format!(
+                "short widths must be integers in [1, {}), got {}",
+                L_SCALAR - 1,
+                width
+            )),
+        })
+        .collect::<Result<Vec<usize>, _>>()?;
+    widths.sort_unstable();
+    widths.dedup();
+    Ok(widths)
+}
+
+/// Returns the suffix of the names of the constants for `width`-bit short scalars.
+fn short_suffix(width: usize) -> String {
+    if width == L_VALUE {
+        String::new()
+    } else {
+        format!("_{}", width)
+    }
+}
+
+/// Returns the name of the variant for `width`-bit short scalars.
+fn short_variant(width: usize) -> String {
+    if width == L_VALUE {
+        String::from("Short")
+    } else {
+        format!("Short{}", width)
+    }
+}
+
+/// Returns the scalar whose `num_bits` low bits are given by `bit`, from the most
+/// significant.
+fn scalar_from_bits<F: FieldExt>(num_bits: usize, mut bit: impl FnMut() -> bool) -> F {
+    (0..num_bits).fold(F::zero(), |acc, _| {
+        let acc = acc.double();
+        if bit() {
+            acc + F::one()
+        } else {
+            acc
+        }
+    })
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Converts a snake case name into the upper camel case name of a type.
+fn type_name(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+fn resolve_generator<C: CurveAffine>(generator: &Generator) -> Result<C, String> {
+    let point = match generator {
+        Generator::Bytes(bytes) => {
+            let mut repr = C::Repr::default();
+            if repr.as_ref().len() != bytes.len() {
+                return Err(format!(
+                    "expected a {}-byte point encoding",
+                    repr.as_ref().len()
+                ));
+            }
+            repr.as_mut().copy_from_slice(bytes);
+            Option::<C>::from(C::from_bytes(&repr)).ok_or("invalid point encoding")?
+        }
+        Generator::HashToCurve {
+            personalization,
+            message,
+        } => C::CurveExt::hash_to_curve(personalization)(message.as_bytes()).to_affine(),
+    };
+
+    if bool::from(point.is_identity()) {
+        return Err(String::from("the generator must not be the identity"));
+    }
+    Ok(point)
+}
+
+/// Precomputed constants for multiplying the generator by a scalar of a given
+/// number of windows.
+#[derive(Clone, Debug, Eq, PartialEq)]
+struct Tables<C: CurveAffine, const K: usize> {
+    generator: C,
+    zs_and_us: Vec<(u64, Vec<[u8; 32]>)>,
+}
+
+impl<C: CurveAffine, const K: usize> Tables<C, K> {
+    fn new(generator: C, num_windows: usize) -> Result<Self, String> {
+        let zs_and_us = find_zs_and_us::<C, K>(generator, num_windows)
+            .ok_or("could not find z-values for every window")?;
+        Ok(Tables {
+            generator,
+            zs_and_us,
+        })
+    }
+
+    /// Checks that the tables reproduce `[scalar] generator`.
+    fn check(&self, scalar: C::ScalarExt) -> Result<(), String>
+    where
+        C: EccCurve,
+    {
+        if mul_fixed_from_tables(self, scalar) == Some(self.generator * scalar) {
+            Ok(())
+        } else {
+            Err(format!("tables do not reproduce [{:?}]G", scalar))
+        }
+    }
+}
+
+impl<C: CurveAffine, const K: usize> FixedPoints<C, K> for Tables<C, K> {
+    fn generator(&self) -> C {
+        self.generator
+    }
+
+    fn u(&self) -> Vec<Vec<[u8; 32]>> {
+        self.zs_and_us.iter().map(|(_, us)| us.clone()).collect()
+    }
+
+    fn z(&self) -> Vec<u64> {
+        self.zs_and_us.iter().map(|(z, _)| *z).collect()
+    }
+
+    fn lagrange_coeffs(&self) -> Vec<Vec<C::Base>> {
+        compute_lagrange_coeffs::<C, K>(self.generator, self.zs_and_us.len())
+    }
+}
+
+fn generate<C: EccCurve, const K: usize>(args: &Args) -> Result<String, String> {
+    let generator = resolve_generator::<C>(&args.generator)?;
+
+    let full_width = Tables::<C, K>::new(generator, num_windows(L_SCALAR, K))?;
+    full_width.check(-C::ScalarExt::one())?;
+    for _ in 0..NUM_CHECKS {
+        full_width.check(C::ScalarExt::rand())?;
+    }
+
+    let mut shorts = vec![];
+    for &width in args.short_widths.iter() {
+        let short = Tables::<C, K>::new(generator, num_windows(width, K))?;
+        short.check(scalar_from_bits(width, || true))?;
+        for _ in 0..NUM_CHECKS {
+            short.check(scalar_from_bits(width, rand::random))?;
+        }
+        shorts.push((width, short));
+    }
+
+    Ok(emit(args, &full_width, &shorts))
+}
+
+fn fmt_bytes(bytes: &[u8; 32]) -> String {
+    let bytes: Vec<_> = bytes.iter().map(|b| format!("0x{:02x}", b)).collect();
+    format!("[{}]", bytes.join(", "))
+}
+
+fn fmt_zs<C: CurveAffine, const K: usize>(tables: &Tables<C, K>) -> String {
+    let zs: Vec<_> = tables.z().iter().map(|z| z.to_string()).collect();
+    format!("[{}]", zs.join(", "))
+}
+
+fn fmt_us<C: CurveAffine, const K: usize>(tables: &Tables<C, K>) -> String {
+    let windows: Vec<_> = tables
+        .u()
+        .iter()
+        .map(|us| {
+            let us: Vec<_> = us.iter().map(fmt_bytes).collect();
+            format!("    [\n        {},\n    ]", us.join(",\n        "))
+        })
+        .collect();
+    format!("[\n{},\n]", windows.join(",\n"))
+}
+
+/// Emits the module for the given full-width tables and short tables of each
+/// width.
+fn emit<C: CurveAffine, const K: usize>(
+    args: &Args,
+    full_width: &Tables<C, K>,
+    shorts: &[(usize, Tables<C, K>)],
+) -> String {
+    let name = &args.name;
+    let ty = type_name(name);
+    let curve = &args.curve;
+    let ecc = &args.ecc_crate;
+    let coordinates = full_width.generator.coordinates().unwrap();
+
+    let mut out = String::new();
+    macro_rules! emit {
+        ($($arg:tt)*) => {
+            writeln!(out, $($arg)*).unwrap()
+        };
+    }
+
+    emit!("//! Constants for the fixed base `{}`.", name);
+    emit!("//!");
+    emit!("//! Generated by `{}`.", args.invocation());
+    emit!("//! Do not edit by hand.");
+    emit!();
+    emit!(
+        "use {}::{{chip::compute_lagrange_coeffs, gadget::FixedPoints}};",
+        ecc
+    );
+    emit!("use pasta_curves::{{");
+    emit!("    arithmetic::{{CurveAffine, FieldExt}},");
+    emit!("    {},", curve);
+    emit!("}};");
+    emit!();
+    emit!("/// Window size for fixed-base scalar multiplication");
+    emit!("pub const WINDOW_SIZE: usize = {};", K);
+    emit!();
+    emit!("/// Number of windows for a full-width scalar or a base field element");
+    emit!(
+        "pub const NUM_WINDOWS: usize = {};",
+        full_width.zs_and_us.len()
+    );
+    emit!();
+    for (width, short) in shorts.iter() {
+        emit!(
+            "/// Number of windows for a {}-bit short signed scalar",
+            width
+        );
+        emit!(
+            "pub const NUM_WINDOWS_SHORT{}: usize = {};",
+            short_suffix(*width),
+            short.zs_and_us.len()
+        );
+        emit!();
+    }
+    emit!("/// Generator, as the encodings of its $x$- and $y$-coordinates");
+    emit!("pub const GENERATOR: ([u8; 32], [u8; 32]) = (");
+    emit!("    {},", fmt_bytes(&coordinates.x().to_bytes()));
+    emit!("    {},", fmt_bytes(&coordinates.y().to_bytes()));
+    emit!(");");
+    emit!();
+    emit!("/// $z$-values for full-width windows");
+    emit!("pub const Z: [u64; NUM_WINDOWS] = {};", fmt_zs(full_width));
+    emit!();
+    emit!("/// $u$-values for full-width windows");
+    emit!(
+        "pub const U: [[[u8; 32]; {}]; NUM_WINDOWS] = {};",
+        1 << K,
+        fmt_us(full_width)
+    );
+    emit!();
+    for (width, short) in shorts.iter() {
+        let suffix = short_suffix(*width);
+        emit!("/// $z$-values for {}-bit short windows", width);
+        emit!(
+            "pub const Z_SHORT{}: [u64; NUM_WINDOWS_SHORT{}] = {};",
+            suffix,
+            suffix,
+            fmt_zs(short)
+        );
+        emit!();
+        emit!("/// $u$-values for {}-bit short windows", width);
+        emit!(
+            "pub const U_SHORT{}: [[[u8; 32]; {}]; NUM_WINDOWS_SHORT{}] = {};",
+            suffix,
+            1 << K,
+            suffix,
+            fmt_us(short)
+        );
+        emit!();
+    }
+    emit!("/// Returns the generator.");
+    emit!("pub fn generator() -> {}::Affine {{", curve);
+    emit!("    {}::Affine::from_xy(", curve);
+    emit!(
+        "        {}::Base::from_bytes(&GENERATOR.0).unwrap(),",
+        curve
+    );
+    emit!(
+        "        {}::Base::from_bytes(&GENERATOR.1).unwrap(),",
+        curve
+    );
+    emit!("    )");
+    emit!("    .unwrap()");
+    emit!("}}");
+    emit!();
+    emit!("/// The fixed base `{}`.", name);
+    emit!("#[derive(Clone, Copy, Debug, Eq, PartialEq)]");
+    emit!("pub enum {} {{", ty);
+    emit!("    /// Multiplication by a full-width scalar or a base field element");
+    emit!("    FullWidth,");
+    for (width, _) in shorts.iter() {
+        emit!(
+            "    /// Multiplication by a {}-bit short signed scalar",
+            width
+        );
+        emit!("    {},", short_variant(*width));
+    }
+    emit!("}}");
+    emit!();
+    emit!(
+        "impl FixedPoints<{}::Affine, WINDOW_SIZE> for {} {{",
+        curve,
+        ty
+    );
+    emit!("    fn generator(&self) -> {}::Affine {{", curve);
+    emit!("        generator()");
+    emit!("    }}");
+    emit!();
+    emit!("    fn u(&self) -> Vec<Vec<[u8; 32]>> {{");
+    emit!("        match self {{");
+    emit!(
+        "            {}::FullWidth => U.iter().map(|us| us.to_vec()).collect(),",
+        ty
+    );
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {}::{} => U_SHORT{}.iter().map(|us| us.to_vec()).collect(),",
+            ty,
+            short_variant(*width),
+            short_suffix(*width)
+        );
+    }
+    emit!("        }}");
+    emit!("    }}");
+    emit!();
+    emit!("    fn z(&self) -> Vec<u64> {{");
+    emit!("        match self {{");
+    emit!("            {}::FullWidth => Z.to_vec(),", ty);
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {}::{} => Z_SHORT{}.to_vec(),",
+            ty,
+            short_variant(*width),
+            short_suffix(*width)
+        );
+    }
+    emit!("        }}");
+    emit!("    }}");
+    emit!();
+    emit!(
+        "    fn lagrange_coeffs(&self) -> Vec<Vec<{}::Base>> {{",
+        curve
+    );
+    emit!("        let num_windows = match self {{");
+    emit!("            {}::FullWidth => NUM_WINDOWS,", ty);
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {}::{} => NUM_WINDOWS_SHORT{},",
+            ty,
+            short_variant(*width),
+            short_suffix(*width)
+        );
+    }
+    emit!("        }};");
+    emit!("        compute_lagrange_coeffs::<_, WINDOW_SIZE>(generator(), num_windows)");
+    emit!("    }}");
+    emit!();
+    emit!("    fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {{");
+    emit!("        match N {{");
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {} => U_SHORT{}.iter().map(|us| us.to_vec()).collect(),",
+            width,
+            short_suffix(*width)
+        );
+    }
+    emit!("            _ => panic!(\"no u-values for {{}}-bit short scalars\", N),");
+    emit!("        }}");
+    emit!("    }}");
+    emit!();
+    emit!("    fn z_short<const N: usize>(&self) -> Vec<u64> {{");
+    emit!("        match N {{");
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {} => Z_SHORT{}.to_vec(),",
+            width,
+            short_suffix(*width)
+        );
+    }
+    emit!("            _ => panic!(\"no z-values for {{}}-bit short scalars\", N),");
+    emit!("        }}");
+    emit!("    }}");
+    emit!();
+    emit!(
+        "    fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<{}::Base>> {{",
+        curve
+    );
+    emit!("        let num_windows = match N {{");
+    for (width, _) in shorts.iter() {
+        emit!(
+            "            {} => NUM_WINDOWS_SHORT{},",
+            width,
+            short_suffix(*width)
+        );
+    }
+    emit!("            _ => panic!(\"no Lagrange coefficients for {{}}-bit short scalars\", N),");
+    emit!("        }};");
+    emit!("        compute_lagrange_coeffs::<_, WINDOW_SIZE>(generator(), num_windows)");
+    emit!("    }}");
+    emit!("}}");
+    emit!();
+    emit!("#[cfg(test)]");
+    emit!("mod tests {{");
+    emit!("    use super::*;");
+    emit!("    use {}::chip::mul_fixed_from_tables;", ecc);
+    emit!();
+    emit!("    #[test]");
+    emit!("    fn tables_reproduce_multiples() {{");
+    emit!("        let scalars = [");
+    emit!("            {}::Scalar::from_u64(1),", curve);
+    emit!("            -{}::Scalar::from_u64(1),", curve);
+    emit!("            {}::Scalar::rand(),", curve);
+    emit!("        ];");
+    emit!("        for scalar in scalars.iter() {{");
+    emit!("            assert_eq!(");
+    emit!(
+        "                mul_fixed_from_tables::<_, WINDOW_SIZE>(&{}::FullWidth, *scalar),",
+        ty
+    );
+    emit!("                Some(generator() * *scalar)");
+    emit!("            );");
+    emit!("        }}");
+    for (width, _) in shorts.iter() {
+        emit!();
+        emit!("        // 1 and 2^{} - 1", width);
+        emit!("        let one = {}::Scalar::from_u64(1);", curve);
+        emit!(
+            "        let max = (0..{}).fold({}::Scalar::from_u64(0), |acc, _| acc + acc + one);",
+            width,
+            curve
+        );
+        emit!("        for scalar in [one, max].iter() {{");
+        emit!("            assert_eq!(");
+        emit!(
+            "                mul_fixed_from_tables::<_, WINDOW_SIZE>(&{}::{}, *scalar),",
+            ty,
+            short_variant(*width)
+        );
+        emit!("                Some(generator() * *scalar)");
+        emit!("            );");
+        emit!("        }}");
+    }
+    emit!("    }}");
+    emit!("}}");
+
+    out
+}
+
+fn main() {
+    let args = Args::parse().unwrap_or_else(|err| {
+        eprintln!("error: {}\n\n{}", err, USAGE);
+        process::exit(1);
+    });
+
+    let module = match (args.curve.as_str(), args.window_size) {
+        ("pallas", 2) => generate::<pallas::Affine, 2>(&args),
+        ("pallas", 3) => generate::<pallas::Affine, 3>(&args),
+        ("vesta", 2) => generate::<vesta::Affine, 2>(&args),
+        ("vesta", 3) => generate::<vesta::Affine, 3>(&args),
+        ("pallas", _) | ("vesta", _) => Err(String::from("window size must be 2 or 3")),
+        (curve, _) => Err(format!("unsupported curve {}", curve)),
+    };
+
+    let result = module.and_then(|module| match &args.out {
+        Some(path) => fs::write(path, module).map_err(|err| err.to_string()),
+        None => {
+            print!("{}", module);
+            Ok(())
+        }
+    });
+
+    if let Err(err) = result {
+        eprintln!("error: {}", err);
+        process::exit(1);
+    }
+}
+
+// A module emitted by this generator, checked in so that its tests are compiled
+// and run alongside the generator's own. `emitted_module_is_up_to_date` checks
+// that it matches the current output.
+#[cfg(test)]
+#[rustfmt::skip]
+#[allow(dead_code)]
+#[path = "../../tests/fixtures/test_base.rs"]
+mod test_base;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Parses the constant `pub const <name>: ... = <value>;` out of an emitted
+    /// module, returning `<value>`.
+    fn constant<'a>(module: &'a str, name: &str) -> &'a str {
+        let start = module
+            .find(&format!("pub const {}: ", name))
+            .unwrap_or_else(|| panic!("{} is not emitted", name));
+        let value = &module[start..];
+        let value = &value[value.find(" = ").unwrap() + 3..];
+        &value[..value.find(";\n").unwrap()]
+    }
+
+    /// Parses the tables with the given constant name suffix out of an emitted
+    /// module.
+    fn parse_tables<C: CurveAffine, const K: usize>(
+        module: &str,
+        suffix: &str,
+        generator: C,
+    ) -> Tables<C, K> {
+        let zs: Vec<u64> = constant(module, &format!("Z{}", suffix))
+            .split(|c: char| !c.is_ascii_digit())
+            .filter(|z| !z.is_empty())
+            .map(|z| z.parse().unwrap())
+            .collect();
+        let bytes: Vec<u8> = constant(module, &format!("U{}", suffix))
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter_map(|byte| byte.strip_prefix("0x"))
+            .map(|byte| u8::from_str_radix(byte, 16).unwrap())
+            .collect();
+        let us: Vec<Vec<[u8; 32]>> = bytes
+            .chunks(32 * (1 << K))
+            .map(|window| {
+                window
+                    .chunks(32)
+                    .map(|u| {
+                        let mut bytes = [0; 32];
+                        bytes.copy_from_slice(u);
+                        bytes
+                    })
+                    .collect()
+            })
+            .collect();
+        assert_eq!(zs.len(), us.len());
+
+        Tables {
+            generator,
+            zs_and_us: zs.into_iter().zip(us.into_iter()).collect(),
+        }
+    }
+
+    fn emitted_tables<C: EccCurve, const K: usize>(curve: &str) {
+        let args = Args {
+            name: String::from("test_base"),
+            generator: Generator::HashToCurve {
+                personalization: String::from("z.cash:test"),
+                message: String::from("gen_fixed_base"),
+            },
+            curve: String::from(curve),
+            window_size: K,
+            // With 3-bit windows, the last window of a 32-bit scalar is partial.
+            short_widths: vec![32, L_VALUE],
+            ecc_crate: String::from("halo2_ecc"),
+            out: None,
+        };
+        let module = generate::<C, K>(&args).unwrap();
+        let generator = resolve_generator::<C>(&args.generator).unwrap();
+
+        // The emitted generator matches.
+        let coordinates = generator.coordinates().unwrap();
+        assert_eq!(
+            constant(&module, "GENERATOR"),
+            format!(
+                "(\n    {},\n    {},\n)",
+                fmt_bytes(&coordinates.x().to_bytes()),
+                fmt_bytes(&coordinates.y().to_bytes())
+            )
+        );
+
+        // The emitted full-width tables match the computed tables byte-for-byte, and
+        // reproduce [k]G.
+        let full_width = parse_tables::<C, K>(&module, "", generator);
+        assert_eq!(
+            full_width,
+            Tables::new(generator, num_windows(L_SCALAR, K)).unwrap()
+        );
+        for scalar in [
+            C::ScalarExt::one(),
+            -C::ScalarExt::one(),
+            C::ScalarExt::rand(),
+        ]
+        .iter()
+        {
+            full_width.check(*scalar).unwrap();
+        }
+
+        // Likewise for the tables of each short width.
+        for &width in args.short_widths.iter() {
+            let short = parse_tables::<C, K>(
+                &module,
+                &format!("_SHORT{}", short_suffix(width)),
+                generator,
+            );
+            assert_eq!(
+                short,
+                Tables::new(generator, num_windows(width, K)).unwrap()
+            );
+            let max = scalar_from_bits(width, || true);
+            let random = scalar_from_bits(width, rand::random);
+            for scalar in [C::ScalarExt::one(), max, random].iter() {
+                short.check(*scalar).unwrap();
+            }
+        }
+    }
+
+    #[test]
+    fn emitted_tables_reproduce_multiples() {
+        emitted_tables::<pallas::Affine, 2>("pallas");
+        emitted_tables::<pallas::Affine, 3>("pallas");
+        emitted_tables::<vesta::Affine, 2>("vesta");
+    }
+
+    #[test]
+    fn emitted_module_is_up_to_date() {
+        let args = Args {
+            name: String::from("test_base"),
+            // The Pallas generator (-1, 2).
+            generator: Generator::Bytes(
+                parse_hex("00000000ed302d991bf94c09fc98462200000000000000000000000000000040")
+                    .unwrap(),
+            ),
+            curve: String::from("pallas"),
+            window_size: 2,
+            // 9-bit scalars have a partial last window.
+            short_widths: vec![9, L_VALUE],
+            ecc_crate: String::from("halo2_ecc"),
+            out: None,
+        };
+        let module = generate::<pallas::Affine, 2>(&args).unwrap();
+        assert!(
+            module == include_str!("../../tests/fixtures/test_base.rs"),
+            "tests/fixtures/test_base.rs is out of date; regenerate it with `cargo run --bin {} --out halo2_ecc/tests/fixtures/test_base.rs`",
+            args.invocation().replacen(" ", " -- ", 1)
+        );
+    }
+}
@@ -1,4 +1,4 @@
-use super::gadget::{EccInstructions, FixedPoints, FIXED_BASE_WINDOW_SIZE, H};
+use super::gadget::{EccInstructions, FixedPoints, FIXED_BASE_WINDOW_SIZE};
 use arrayvec::ArrayVec;
 use std::marker::PhantomData;
 use utilities::{
@@ -6,46 +6,165 @@ use utilities::{
     CellValue, UtilitiesInstructions, Var,
 };
 
-use group::prime::PrimeCurveAffine;
+use ff::{PrimeField, PrimeFieldBits};
 use halo2::{
     circuit::{Chip, Layouter},
     plonk::{Advice, Column, ConstraintSystem, Error, Fixed, Selector},
 };
-use pasta_curves::{arithmetic::CurveAffine, pallas};
+use pasta_curves::{
+    arithmetic::{CurveAffine, FieldExt},
+    pallas, vesta,
+};
 
 pub(super) mod add;
 pub(super) mod add_incomplete;
+pub(super) mod double;
+pub(super) mod foreign;
+pub(super) mod is_equal;
+pub(super) mod map_to_curve;
 pub(super) mod mul;
 pub(super) mod mul_fixed;
+pub(super) mod neg;
+pub(super) mod poseidon;
+pub(super) mod select;
 pub(super) mod witness_point;
 
-pub use mul_fixed::{compute_lagrange_coeffs, compute_window_table, find_zs_and_us};
+#[cfg(test)]
+pub(super) mod test_circuit;
 
-/// Number of windows for a full-width scalar
-pub const NUM_WINDOWS: usize =
-    (L_PALLAS_SCALAR + FIXED_BASE_WINDOW_SIZE - 1) / FIXED_BASE_WINDOW_SIZE;
+pub use foreign::{
+    ForeignCurve, ForeignEccChip, ForeignEccConfig, ForeignPoint, ForeignScalarFixedShort,
+    NonIdentityForeignPoint,
+};
+pub use map_to_curve::{MapToCurveChip, MapToCurveConfig, SwuCurve};
+pub use mul_fixed::{
+    compute_lagrange_coeffs, compute_window_table, find_zs_and_us, find_zs_and_us_cached,
+    find_zs_and_us_cached_in, mul_fixed_from_tables,
+};
+pub use poseidon::{PoseidonChallenge, PoseidonHashToField};
 
-/// Number of windows for a short signed scalar
-pub const NUM_WINDOWS_SHORT: usize =
-    (L_VALUE + FIXED_BASE_WINDOW_SIZE - 1) / FIXED_BASE_WINDOW_SIZE;
+/// Number of `window_size`-bit windows needed to decompose a `num_bits`-bit scalar.
+pub const fn num_windows(num_bits: usize, window_size: usize) -> usize {
+    (num_bits + window_size - 1) / window_size
+}
+
+/// Number of windows for a full-width scalar, using the default window size
+pub const NUM_WINDOWS: usize = num_windows(L_SCALAR, FIXED_BASE_WINDOW_SIZE);
+
+/// Number of windows for a short signed scalar, using the default bit width and
+/// window size
+pub const NUM_WINDOWS_SHORT: usize = num_windows(L_VALUE, FIXED_BASE_WINDOW_SIZE);
 
 /// $\ell_\mathsf{value}$
-/// Number of bits in an unsigned short scalar.
-pub(crate) const L_VALUE: usize = 64;
+/// Default number of bits in an unsigned short scalar.
+pub const L_VALUE: usize = 64;
 
-/// Number of bits in a Pallas base field element.
-pub(crate) const L_PALLAS_BASE: usize = 255;
+/// Number of bits in a base field element of an [`EccCurve`].
+pub(crate) const L_BASE: usize = 255;
 
-/// Number of bits in a Pallas scalar field element.
-pub(crate) const L_PALLAS_SCALAR: usize = 255;
+/// Number of bits in a scalar field element of an [`EccCurve`].
+pub const L_SCALAR: usize = 255;
 
-/// The Pallas scalar field modulus is $q = 2^{254} + \mathsf{t_q}$.
+/// A curve over which [`EccChip`] can be instantiated.
+///
+/// The scalar decompositions used in the chip assume that the base field modulus
+/// $p = 2^{254} + \mathsf{t_p}$ and the scalar field modulus $q = 2^{254} + \mathsf{t_q}$
+/// are both 255-bit primes, with $\mathsf{t_p}, \mathsf{t_q} < 2^{130}$. This holds for
+/// both curves in the Pallas/Vesta cycle.
 /// <https://github.com/zcash/pasta>
-pub(crate) const T_Q: u128 = 45560315531506369815346746415080538113;
+pub trait EccCurve:
+    CurveAffine<Base = <Self as EccCurve>::BaseField, ScalarExt = <Self as EccCurve>::ScalarField>
+{
+    /// The base field of the curve, over which the circuit is defined.
+    type BaseField: FieldExt + PrimeFieldBits;
 
-/// The Pallas base field modulus is $p = 2^{254} + \mathsf{t_p}$.
-/// <https://github.com/zcash/pasta>
-pub(crate) const T_P: u128 = 45560315531419706090280762371685220353;
+    /// The scalar field of the curve.
+    type ScalarField: FieldExt + PrimeFieldBits;
+
+    /// The scalar field modulus is $q = 2^{254} + \mathsf{t_q}$.
+    const T_Q: u128;
+
+    /// The base field modulus is $p = 2^{254} + \mathsf{t_p}$.
+    const T_P: u128;
+
+    /// A primitive cube root of unity $\zeta$ in the base field, such that the
+    /// endomorphism $\phi(x, y) = (\zeta x, y)$ acts on the curve as $[\lambda]$.
+    const ZETA: Self::BaseField;
+
+    /// The primitive cube root of unity $\lambda$ in the scalar field for which
+    /// $\phi(P) = [\lambda] P$.
+    const LAMBDA: Self::ScalarField;
+
+    /// A reduced basis $(a_1, -b_1), (a_2, b_2)$ of the lattice
+    /// $\{(k_1, k_2) : k_1 + \lambda k_2 \equiv 0 \pmod{q}\}$, given as
+    /// $[a_1, b_1, a_2, b_2]$ with each entry positive and less than $2^{128}$.
+    ///
+    /// This is used to decompose scalars in [`EccInstructions::mul_endo`].
+    const GLV_BASIS: [u128; 4];
+}
+
+impl EccCurve for pallas::Affine {
+    type BaseField = pallas::Base;
+    type ScalarField = pallas::Scalar;
+
+    const T_Q: u128 = 45560315531506369815346746415080538113;
+    const T_P: u128 = 45560315531419706090280762371685220353;
+
+    const ZETA: pallas::Base = pallas::Base::from_raw([
+        0x7b7f_d22f_0201_b547,
+        0x0527_0d29_d19f_c7d2,
+        0xd355_2a23_a855_4e50,
+        0x2d33_357c_b532_458e,
+    ]);
+    const LAMBDA: pallas::Scalar = pallas::Scalar::from_raw([
+        0x619d_1840_af55_f1b1,
+        0x1259_527e_c1d4_752e,
+        0xaee2_4b27_e308_f0a6,
+        0x397e_65a7_d7c1_ad71,
+    ]);
+    const GLV_BASIS: [u128; 4] = [
+        98231058071100081932162823354453065728,
+        98231058071186745657228807397848383489,
+        196462116142286827589391630752301449217,
+        98231058071100081932162823354453065728,
+    ];
+}
+
+impl EccCurve for vesta::Affine {
+    type BaseField = vesta::Base;
+    type ScalarField = vesta::Scalar;
+
+    // The Vesta scalar field is the Pallas base field, and vice versa.
+    const T_Q: u128 = 45560315531419706090280762371685220353;
+    const T_P: u128 = 45560315531506369815346746415080538113;
+
+    const ZETA: vesta::Base = vesta::Base::from_raw([
+        0x2aa9_d2e0_50aa_0e4f,
+        0x0fed_467d_47c0_33af,
+        0x511d_b4d8_1cf7_0f5a,
+        0x0681_9a58_283e_528e,
+    ]);
+    const LAMBDA: vesta::Scalar = vesta::Scalar::from_raw([
+        0x1dad_5ebd_fdfe_4ab9,
+        0x1d1f_8bd2_37ad_3149,
+        0x2caa_d5dc_57aa_b1b0,
+        0x12cc_ca83_4acd_ba71,
+    ]);
+    const GLV_BASIS: [u128; 4] = [
+        98231058071186745657228807397848383488,
+        98231058071100081932162823354453065729,
+        98231058071100081932162823354453065729,
+        196462116142286827589391630752301449217,
+    ];
+}
+
+/// Maps a base field element into the scalar field of the curve, reducing it
+/// modulo the scalar field modulus if necessary.
+pub(crate) fn base_to_scalar<C: CurveAffine>(base: C::Base) -> C::ScalarExt {
+    let mut bytes = [0u8; 64];
+    bytes[..32].copy_from_slice(&base.to_bytes());
+    C::ScalarExt::from_bytes_wide(&bytes)
+}
 
 /// Number of bits in the lookup table.
 const LOOKUP_K: usize = 10;
@@ -53,22 +172,22 @@ const LOOKUP_K: usize = 10;
 /// A curve point represented in affine (x, y) coordinates. Each coordinate is
 /// assigned to a cell.
 #[derive(Clone, Debug)]
-pub struct EccPoint {
+pub struct EccPoint<C: EccCurve> {
     /// x-coordinate
-    x: CellValue<pallas::Base>,
+    x: CellValue<C::Base>,
     /// y-coordinate
-    y: CellValue<pallas::Base>,
+    y: CellValue<C::Base>,
 }
 
-impl EccPoint {
+impl<C: EccCurve> EccPoint<C> {
     /// Returns the value of this curve point, if known.
-    pub fn point(&self) -> Option<pallas::Affine> {
+    pub fn point(&self) -> Option<C> {
         match (self.x.value(), self.y.value()) {
             (Some(x), Some(y)) => {
-                if x == pallas::Base::zero() && y == pallas::Base::zero() {
-                    Some(pallas::Affine::identity())
+                if x == C::Base::zero() && y == C::Base::zero() {
+                    Some(C::identity())
                 } else {
-                    Some(pallas::Affine::from_xy(x, y).unwrap())
+                    Some(C::from_xy(x, y).unwrap())
                 }
             }
             _ => None,
@@ -76,25 +195,74 @@ impl EccPoint {
     }
     /// The cell containing the affine short-Weierstrass x-coordinate,
     /// or 0 for the zero point.
-    pub fn x(&self) -> CellValue<pallas::Base> {
+    pub fn x(&self) -> CellValue<C::Base> {
         self.x
     }
     /// The cell containing the affine short-Weierstrass y-coordinate,
     /// or 0 for the zero point.
-    pub fn y(&self) -> CellValue<pallas::Base> {
+    pub fn y(&self) -> CellValue<C::Base> {
         self.y
     }
 }
 
-/// Configuration for the ECC chip
+/// A curve point represented in affine (x, y) coordinates, that is constrained
+/// not to be the identity. Each coordinate is assigned to a cell.
+#[derive(Clone, Debug)]
+pub struct NonIdentityEccPoint<C: EccCurve> {
+    /// x-coordinate
+    x: CellValue<C::Base>,
+    /// y-coordinate
+    y: CellValue<C::Base>,
+}
+
+impl<C: EccCurve> NonIdentityEccPoint<C> {
+    /// Returns the value of this curve point, if known.
+    pub fn point(&self) -> Option<C> {
+        match (self.x.value(), self.y.value()) {
+            (Some(x), Some(y)) => Some(C::from_xy(x, y).unwrap()),
+            _ => None,
+        }
+    }
+    /// The cell containing the affine short-Weierstrass x-coordinate.
+    pub fn x(&self) -> CellValue<C::Base> {
+        self.x
+    }
+    /// The cell containing the affine short-Weierstrass y-coordinate.
+    pub fn y(&self) -> CellValue<C::Base> {
+        self.y
+    }
+}
+
+impl<C: EccCurve> From<NonIdentityEccPoint<C>> for EccPoint<C> {
+    fn from(non_id_point: NonIdentityEccPoint<C>) -> Self {
+        Self {
+            x: non_id_point.x,
+            y: non_id_point.y,
+        }
+    }
+}
+
+/// The method used for variable-base scalar multiplication by a base field element,
+/// selected when configuring the ECC chip.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum VarBaseMul {
+    /// Double-and-add over single bits of the scalar.
+    DoubleAndAdd,
+    /// Double-and-add over 4-bit windows of the scalar, using a table of odd
+    /// multiples of the base that is computed for each call.
+    Windowed,
+}
+
+/// Configuration for the ECC chip, using `K`-bit windows in fixed-base scalar mul.
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[allow(non_snake_case)]
-pub struct EccConfig {
+pub struct EccConfig<C: EccCurve, const K: usize> {
     /// Advice columns needed by instructions in the ECC chip.
     pub advices: [Column<Advice>; 10],
 
-    /// Coefficients of interpolation polynomials for x-coordinates (used in fixed-base scalar multiplication)
-    pub lagrange_coeffs: [Column<Fixed>; H],
+    /// Coefficients of interpolation polynomials for x-coordinates (used in fixed-base scalar multiplication).
+    /// There are $2^K$ of these.
+    pub lagrange_coeffs: Vec<Column<Fixed>>,
     /// Fixed z such that y + z = u^2 some square, and -y + z is a non-square. (Used in fixed-base scalar multiplication)
     pub fixed_z: Column<Fixed>,
 
@@ -104,6 +272,19 @@ pub struct EccConfig {
     /// Complete addition
     pub q_add: Selector,
 
+    /// Point doubling
+    pub q_double: Selector,
+    /// Point negation
+    pub q_neg: Selector,
+    /// Conditional point negation
+    pub q_cond_neg: Selector,
+    /// Conditional point selection
+    pub q_select: Selector,
+    /// Point equality test
+    pub q_point_eq: Selector,
+    /// Point identity test
+    pub q_point_is_id: Selector,
+
     /// Variable-base scalar multiplication (hi half)
     pub q_mul_hi: (Selector, Selector, Selector),
     /// Variable-base scalar multiplication (lo half)
@@ -114,11 +295,47 @@ pub struct EccConfig {
     pub q_mul_lsb: Selector,
     /// Variable-base scalar multiplication (overflow check)
     pub q_mul_overflow: Selector,
+    /// Variable-base scalar multiplication by a full-width scalar (canonicity check)
+    pub q_mul_full_width_canon: Selector,
+    /// Variable-base multi-scalar mul (first quadrupling of each ladder)
+    pub q_msm_quad_init: Selector,
+    /// Variable-base multi-scalar mul (quadrupling of the shared accumulator)
+    pub q_msm_quad: Selector,
+    /// Variable-base multi-scalar mul (window range check and incomplete addition)
+    pub q_msm_add: Selector,
+    /// Variable-base multi-scalar mul (chaining of consecutive additions in a window)
+    pub q_msm_chain: Selector,
+    /// Variable-base multi-scalar mul (output of the ladders)
+    pub q_msm_output: Selector,
+    /// Selector used to decompose the final window in variable-base multi-scalar mul
+    pub q_msm_lsb: Selector,
+    /// Computes $P \pm \phi(P)$ in endomorphism-accelerated variable-base scalar mul
+    pub q_mul_endo_table: Selector,
+    /// Endomorphism-accelerated variable-base scalar mul (incomplete addition)
+    pub q_mul_endo: (Selector, Selector, Selector),
+    /// Selector used to select the added point for the complete bits of
+    /// endomorphism-accelerated variable-base scalar mul
+    pub q_mul_endo_complete: Selector,
+    /// Endomorphism-accelerated variable-base scalar mul (scalar decomposition check)
+    pub q_mul_endo_decompose: Selector,
+    /// The method used in variable-base scalar mul by a base field element
+    pub var_base_mul: VarBaseMul,
+    /// Windowed variable-base scalar mul (double-and-add step)
+    pub q_mul_windowed: Selector,
+    /// Windowed variable-base scalar mul (window range check)
+    pub q_mul_windowed_range_check: Selector,
 
     /// Fixed-base full-width scalar multiplication
     pub q_mul_fixed_full: Selector,
+    /// Running sum of the windows of a full-width scalar copied into fixed-base mul
+    pub q_mul_fixed_full_running_sum: Selector,
+    /// Canonicity checks on a full-width scalar copied into fixed-base mul
+    pub q_mul_fixed_full_canon: Selector,
     /// Fixed-base signed short scalar multiplication
     pub q_mul_fixed_short: Selector,
+    /// Range check on the last window of a signed short scalar, indexed by the
+    /// number of bits in the last window minus one. There are $K$ of these.
+    pub q_mul_fixed_short_last_window: Vec<Selector>,
     /// Canonicity checks on base field element used as scalar in fixed-base mul
     pub q_mul_fixed_base_field: Selector,
     /// Running sum decomposition of a scalar used in fixed-base mul. This is used
@@ -127,22 +344,25 @@ pub struct EccConfig {
 
     /// Witness point
     pub q_point: Selector,
+    /// Witness non-identity point
+    pub q_point_non_id: Selector,
 
     /// Lookup range check using 10-bit lookup table
-    pub lookup_config: LookupRangeCheckConfig<pallas::Base, LOOKUP_K>,
+    pub lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
     /// Running sum decomposition.
-    pub running_sum_config: RunningSumConfig<pallas::Base, { FIXED_BASE_WINDOW_SIZE }>,
+    pub running_sum_config: RunningSumConfig<C::Base, K>,
 }
 
-/// A chip implementing EccInstructions
+/// A chip implementing EccInstructions, using `K`-bit windows in fixed-base
+/// scalar mul.
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub struct EccChip<Fixed: FixedPoints<pallas::Affine>> {
-    config: EccConfig,
+pub struct EccChip<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> {
+    config: EccConfig<C, K>,
     _marker: PhantomData<Fixed>,
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> Chip<pallas::Base> for EccChip<Fixed> {
-    type Config = EccConfig;
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> Chip<C::Base> for EccChip<C, Fixed, K> {
+    type Config = EccConfig<C, K>;
     type Loaded = ();
 
     fn config(&self) -> &Self::Config {
@@ -154,28 +374,82 @@ impl<Fixed: FixedPoints<pallas::Affine>> Chip<pallas::Base> for EccChip<Fixed> {
     }
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> UtilitiesInstructions<pallas::Base> for EccChip<Fixed> {
-    type Var = CellValue<pallas::Base>;
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> UtilitiesInstructions<C::Base>
+    for EccChip<C, Fixed, K>
+{
+    type Var = CellValue<C::Base>;
 }
 
-impl<F: FixedPoints<pallas::Affine>> EccChip<F> {
-    pub fn construct(config: <Self as Chip<pallas::Base>>::Config) -> Self {
+impl<C: EccCurve, F: FixedPoints<C, K>, const K: usize> EccChip<C, F, K> {
+    pub fn construct(config: <Self as Chip<C::Base>>::Config) -> Self {
         Self {
             config,
             _marker: PhantomData,
         }
     }
 
+    /// Configures the chip to use [`VarBaseMul::DoubleAndAdd`] in variable-base
+    /// scalar mul.
+    ///
     /// # Side effects
     ///
     /// All columns in `advices` will be equality-enabled.
-    #[allow(non_snake_case)]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is not 2 or 3, or if `lagrange_coeffs` does not contain
+    /// exactly $2^K$ columns.
     pub fn configure(
-        meta: &mut ConstraintSystem<pallas::Base>,
+        meta: &mut ConstraintSystem<C::Base>,
+        advices: [Column<Advice>; 10],
+        lagrange_coeffs: &[Column<Fixed>],
+        range_check: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    ) -> <Self as Chip<C::Base>>::Config {
+        Self::configure_with_var_base_mul(
+            meta,
+            advices,
+            lagrange_coeffs,
+            range_check,
+            VarBaseMul::DoubleAndAdd,
+        )
+    }
+
+    /// Configures the chip to use `var_base_mul` in variable-base scalar mul by a
+    /// base field element. Multiplication by a full-width scalar always uses
+    /// [`VarBaseMul::DoubleAndAdd`].
+    ///
+    /// [`VarBaseMul::Windowed`] range-checks each window using `range_check`, so
+    /// the lookup table must be loaded in any circuit that uses it.
+    ///
+    /// # Side effects
+    ///
+    /// All columns in `advices` will be equality-enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is not 2 or 3, or if `lagrange_coeffs` does not contain
+    /// exactly $2^K$ columns.
+    #[allow(non_snake_case)]
+    pub fn configure_with_var_base_mul(
+        meta: &mut ConstraintSystem<C::Base>,
         advices: [Column<Advice>; 10],
-        lagrange_coeffs: [Column<Fixed>; 8],
-        range_check: LookupRangeCheckConfig<pallas::Base, LOOKUP_K>,
-    ) -> <Self as Chip<pallas::Base>>::Config {
+        lagrange_coeffs: &[Column<Fixed>],
+        range_check: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+        var_base_mul: VarBaseMul,
+    ) -> <Self as Chip<C::Base>>::Config {
+        // The scalar decompositions in this chip are specialized to 255-bit fields.
+        assert_eq!(C::Base::NUM_BITS as usize, L_BASE);
+        assert_eq!(C::ScalarExt::NUM_BITS as usize, L_SCALAR);
+
+        // The canonicity check in mul_fixed::base_field_elem splits a base field
+        // element at bit 252, which must lie on a window boundary. Larger windows
+        // would also exceed the degree bound of the range check gates. 4-bit windows
+        // are rejected as well: each window needs a z such that z + y is square and
+        // z - y is non-square for all 16 of its points, and finding one takes around
+        // 2^32 attempts, so no fixed bases can be precomputed for them.
+        assert!((2..=3).contains(&K), "Unsupported window size {}", K);
+        assert_eq!(lagrange_coeffs.len(), 1 << K);
+
         // The following columns need to be equality-enabled for their use in sub-configs:
         //
         // add::Config and add_incomplete::Config:
@@ -188,7 +462,7 @@ impl<F: FixedPoints<pallas::Affine>> EccChip<F> {
         // - advices[4]: window
         // - advices[5]: u
         //
-        // mul_fixed::base_field_element::Config:
+        // mul_fixed::base_field_element::Config and mul_fixed::full_width::Config:
         // - [advices[6], advices[7], advices[8]]: canon_advices
         //
         // mul::overflow::Config:
@@ -213,141 +487,242 @@ impl<F: FixedPoints<pallas::Affine>> EccChip<F> {
 
         let config = EccConfig {
             advices,
-            lagrange_coeffs,
+            lagrange_coeffs: lagrange_coeffs.to_vec(),
             fixed_z: meta.fixed_column(),
             q_add_incomplete: meta.selector(),
             q_add: meta.selector(),
+            q_double: meta.selector(),
+            q_neg: meta.selector(),
+            q_cond_neg: meta.selector(),
+            q_select: meta.selector(),
+            q_point_eq: meta.selector(),
+            q_point_is_id: meta.selector(),
             q_mul_hi: (meta.selector(), meta.selector(), meta.selector()),
             q_mul_lo: (meta.selector(), meta.selector(), meta.selector()),
             q_mul_decompose_var: meta.selector(),
             q_mul_overflow: meta.selector(),
             q_mul_lsb: meta.selector(),
+            q_mul_full_width_canon: meta.selector(),
+            q_msm_quad_init: meta.selector(),
+            q_msm_quad: meta.selector(),
+            q_msm_add: meta.selector(),
+            q_msm_chain: meta.selector(),
+            q_msm_output: meta.selector(),
+            q_msm_lsb: meta.selector(),
+            q_mul_endo_table: meta.selector(),
+            q_mul_endo: (meta.selector(), meta.selector(), meta.selector()),
+            q_mul_endo_complete: meta.selector(),
+            q_mul_endo_decompose: meta.selector(),
+            var_base_mul,
+            q_mul_windowed: meta.selector(),
+            q_mul_windowed_range_check: meta.complex_selector(),
             q_mul_fixed_full: meta.selector(),
+            q_mul_fixed_full_running_sum: meta.selector(),
+            q_mul_fixed_full_canon: meta.selector(),
             q_mul_fixed_short: meta.selector(),
+            q_mul_fixed_short_last_window: (0..K).map(|_| meta.selector()).collect(),
             q_mul_fixed_base_field: meta.selector(),
             q_mul_fixed_running_sum,
             q_point: meta.selector(),
+            q_point_non_id: meta.selector(),
             lookup_config: range_check,
             running_sum_config,
         };
 
         // Create witness point gate
         {
-            let config: witness_point::Config = (&config).into();
+            let config: witness_point::Config<C> = (&config).into();
             config.create_gate(meta);
         }
 
         // Create incomplete point addition gate
         {
-            let config: add_incomplete::Config = (&config).into();
+            let config: add_incomplete::Config<C> = (&config).into();
             config.create_gate(meta);
         }
 
         // Create complete point addition gate
         {
-            let add_config: add::Config = (&config).into();
+            let add_config: add::Config<C> = (&config).into();
             add_config.create_gate(meta);
         }
 
         // Create variable-base scalar mul gates
         {
-            let mul_config: mul::Config = (&config).into();
+            let mul_config: mul::Config<C> = (&config).into();
             mul_config.create_gate(meta);
         }
 
+        // Create windowed variable-base scalar mul gates
+        if var_base_mul == VarBaseMul::Windowed {
+            let windowed_config: mul::windowed::Config<C> = (&config).into();
+            windowed_config.create_gate(meta);
+        }
+
+        // Create variable-base multi-scalar mul gates
+        {
+            let msm_config: mul::msm::Config<C> = (&config).into();
+            msm_config.create_gate(meta);
+        }
+
         // Create gate that is used both in fixed-base mul using a short signed exponent,
         // and fixed-base mul using a base field element.
         {
-            // The const generic does not matter when creating gates.
-            let mul_fixed_config: mul_fixed::Config<F, { NUM_WINDOWS }> = (&config).into();
+            // The number of windows does not matter when creating gates.
+            let mul_fixed_config: mul_fixed::Config<C, F, K> =
+                mul_fixed::Config::new(&config, num_windows(L_SCALAR, K));
             mul_fixed_config.running_sum_coords_gate(meta);
         }
 
         // Create gate that is only used in full-width fixed-base scalar mul.
         {
-            let mul_fixed_full_config: mul_fixed::full_width::Config<F> = (&config).into();
+            let mul_fixed_full_config: mul_fixed::full_width::Config<C, F, K> = (&config).into();
             mul_fixed_full_config.create_gate(meta);
         }
 
         // Create gate that is only used in short fixed-base scalar mul.
         {
-            let short_config: mul_fixed::short::Config<F> = (&config).into();
+            // The bit width of the short scalar does not matter when creating gates.
+            let short_config: mul_fixed::short::Config<C, F, K, L_VALUE> = (&config).into();
             short_config.create_gate(meta);
         }
 
         // Create gate that is only used in fixed-base mul using a base field element.
         {
-            let base_field_config: mul_fixed::base_field_elem::Config<F> = (&config).into();
+            let base_field_config: mul_fixed::base_field_elem::Config<C, F, K> = (&config).into();
             base_field_config.create_gate(meta);
         }
 
+        // Create point doubling gate
+        {
+            let double_config: double::Config<C> = (&config).into();
+            double_config.create_gate(meta);
+        }
+
+        // Create point negation gates
+        {
+            let neg_config: neg::Config<C> = (&config).into();
+            neg_config.create_gate(meta);
+        }
+
+        // Create point selection gate
+        {
+            let select_config: select::Config<C> = (&config).into();
+            select_config.create_gate(meta);
+        }
+
+        // Create point equality and identity test gates
+        {
+            let is_equal_config: is_equal::Config<C> = (&config).into();
+            is_equal_config.create_gate(meta);
+        }
+
+        // Create gates that are only used in full-width fixed-base mul with a scalar
+        // copied in as windows.
+        {
+            let mul_fixed_full_config: mul_fixed::full_width::Config<C, F, K> = (&config).into();
+            mul_fixed_full_config.create_canonicity_gates(meta);
+        }
+
+        // Create endomorphism-accelerated variable-base scalar mul gates
+        {
+            let mul_endo_config: mul::endo::Config<C> = (&config).into();
+            mul_endo_config.create_gate(meta);
+        }
+
         config
     }
 }
 
 /// A full-width scalar used for fixed-base scalar multiplication.
-/// This is decomposed into 85 3-bit windows in little-endian order,
-/// i.e. `windows` = [k_0, k_1, ..., k_84] (for a 255-bit scalar)
-/// where `scalar = k_0 + k_1 * (2^3) + ... + k_84 * (2^3)^84` and
-/// each `k_i` is in the range [0..2^3).
+/// This is decomposed into K-bit windows in little-endian order.
+/// With 3-bit windows, this gives `windows` = [k_0, k_1, ..., k_84]
+/// (for a 255-bit scalar) where `scalar = k_0 + k_1 * (2^3) + ... + k_84 * (2^3)^84`
+/// and each `k_i` is in the range [0..2^3).
+#[derive(Clone, Debug)]
+pub struct EccScalarFixed<C: EccCurve> {
+    value: Option<C::Scalar>,
+    windows: Vec<CellValue<C::Base>>,
+}
+
+/// A full-width scalar used for variable-base scalar multiplication.
+/// This is decomposed as `k = scalar + t_q (mod q)` into 255 bits in big-endian
+/// order, using a running sum `z` where z_{255} = 0 and z_i = 2 z_{i+1} + k_i.
+///
+/// `running_sum` = [z_0, ..., z_255], where z_0 = k (mod p). The decomposition
+/// is constrained to be canonical, i.e. 0 ≤ k < q.
 #[derive(Clone, Debug)]
-pub struct EccScalarFixed {
-    value: Option<pallas::Scalar>,
-    windows: ArrayVec<CellValue<pallas::Base>, { NUM_WINDOWS }>,
+pub struct EccScalarVarFullWidth<C: EccCurve> {
+    value: Option<C::Scalar>,
+    running_sum: ArrayVec<CellValue<C::Base>, { L_SCALAR + 1 }>,
 }
 
-/// A signed short scalar used for fixed-base scalar multiplication.
-/// A short scalar must have magnitude in the range [0..2^64), with
+/// A signed `N`-bit short scalar used for fixed-base scalar multiplication.
+/// A short scalar must have magnitude in the range [0..2^N), with
 /// a sign of either 1 or -1.
-/// This is decomposed into 3-bit windows in little-endian order
-/// using a running sum `z`, where z_{i+1} = (z_i - a_i) / (2^3)
-/// for element α = a_0 + (2^3) a_1 + ... + (2^{3(n-1)}) a_{n-1}.
-/// Each `a_i` is in the range [0..2^3).
+/// This is decomposed into K-bit windows in little-endian order
+/// using a running sum `z`, where z_{i+1} = (z_i - a_i) / (2^K)
+/// for element α = a_0 + (2^K) a_1 + ... + (2^{K(n-1)}) a_{n-1}.
+/// Each `a_i` is in the range [0..2^K).
 ///
-/// `windows` = [k_0, k_1, ..., k_21] (for a 64-bit magnitude)
-/// where `scalar = k_0 + k_1 * (2^3) + ... + k_84 * (2^3)^84` and
+/// With 3-bit windows, `windows` = [k_0, k_1, ..., k_21] (for a 64-bit magnitude)
+/// where `scalar = k_0 + k_1 * (2^3) + ... + k_21 * (2^3)^21` and
 /// each `k_i` is in the range [0..2^3).
 /// k_21 must be a single bit, i.e. 0 or 1.
 #[derive(Clone, Debug)]
-pub struct EccScalarFixedShort {
-    magnitude: CellValue<pallas::Base>,
-    sign: CellValue<pallas::Base>,
-    running_sum: ArrayVec<CellValue<pallas::Base>, { NUM_WINDOWS_SHORT + 1 }>,
+pub struct EccScalarFixedShort<C: EccCurve> {
+    magnitude: CellValue<C::Base>,
+    sign: CellValue<C::Base>,
+    running_sum: Vec<CellValue<C::Base>>,
+    // The bit width N of the magnitude.
+    num_bits: usize,
+}
+
+impl<C: EccCurve> EccScalarFixedShort<C> {
+    /// The number of bits `N` in the magnitude of the scalar.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
 }
 
 /// A base field element used for fixed-base scalar multiplication.
-/// This is decomposed into 3-bit windows in little-endian order
-/// using a running sum `z`, where z_{i+1} = (z_i - a_i) / (2^3)
-/// for element α = a_0 + (2^3) a_1 + ... + (2^{3(n-1)}) a_{n-1}.
-/// Each `a_i` is in the range [0..2^3).
+/// This is decomposed into K-bit windows in little-endian order
+/// using a running sum `z`, where z_{i+1} = (z_i - a_i) / (2^K)
+/// for element α = a_0 + (2^K) a_1 + ... + (2^{K(n-1)}) a_{n-1}.
+/// Each `a_i` is in the range [0..2^K).
 ///
-/// `running_sum` = [z_0, ..., z_85], where we expect z_85 = 0.
+/// `running_sum` = [z_0, ..., z_n], where we expect z_n = 0
+/// (with 3-bit windows, n = 85).
 /// Since z_0 is initialized as the scalar α, we store it as
 /// `base_field_elem`.
 #[derive(Clone, Debug)]
-struct EccBaseFieldElemFixed {
-    base_field_elem: CellValue<pallas::Base>,
-    running_sum: ArrayVec<CellValue<pallas::Base>, { NUM_WINDOWS + 1 }>,
+struct EccBaseFieldElemFixed<C: EccCurve> {
+    base_field_elem: CellValue<C::Base>,
+    running_sum: Vec<CellValue<C::Base>>,
 }
 
-impl EccBaseFieldElemFixed {
+impl<C: EccCurve> EccBaseFieldElemFixed<C> {
     #[cfg(test)]
-    fn base_field_elem(&self) -> CellValue<pallas::Base> {
+    fn base_field_elem(&self) -> CellValue<C::Base> {
         self.base_field_elem
     }
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> EccInstructions<pallas::Affine> for EccChip<Fixed> {
-    type ScalarFixed = EccScalarFixed;
-    type ScalarFixedShort = EccScalarFixedShort;
-    type ScalarVar = CellValue<pallas::Base>;
-    type Point = EccPoint;
-    type X = CellValue<pallas::Base>;
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> EccInstructions<C>
+    for EccChip<C, Fixed, K>
+{
+    type ScalarFixed = EccScalarFixed<C>;
+    type ScalarFixedShort = EccScalarFixedShort<C>;
+    type ScalarVar = CellValue<C::Base>;
+    type ScalarVarFullWidth = EccScalarVarFullWidth<C>;
+    type Point = EccPoint<C>;
+    type NonIdentityPoint = NonIdentityEccPoint<C>;
+    type X = CellValue<C::Base>;
     type FixedPoints = Fixed;
 
     fn constrain_equal(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<(), Error> {
@@ -364,23 +739,47 @@ impl<Fixed: FixedPoints<pallas::Affine>> EccInstructions<pallas::Affine> for Ecc
 
     fn witness_point(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
-        value: Option<pallas::Affine>,
+        layouter: &mut impl Layouter<C::Base>,
+        value: Option<C>,
     ) -> Result<Self::Point, Error> {
-        let config: witness_point::Config = self.config().into();
+        let config: witness_point::Config<C> = self.config().into();
         layouter.assign_region(
             || "witness point",
             |mut region| config.witness_point(value, 0, &mut region),
         )
     }
 
+    fn witness_point_non_id(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        value: Option<C>,
+    ) -> Result<Self::NonIdentityPoint, Error> {
+        let config: witness_point::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "witness non-identity point",
+            |mut region| config.witness_non_id(value, 0, &mut region),
+        )
+    }
+
+    fn constant_point(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        value: C,
+    ) -> Result<Self::Point, Error> {
+        let config: witness_point::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "constant point",
+            |mut region| config.constant_point(value, 0, &mut region),
+        )
+    }
+
     fn copy_point(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
         x: Self::Var,
         y: Self::Var,
     ) -> Result<Self::Point, Error> {
-        let config: witness_point::Config = self.config().into();
+        let config: witness_point::Config<C> = self.config().into();
         layouter.assign_region(
             || "witness point",
             |mut region| config.copy_point(x, y, 0, &mut region),
@@ -393,51 +792,209 @@ impl<Fixed: FixedPoints<pallas::Affine>> EccInstructions<pallas::Affine> for Ecc
 
     fn add_incomplete(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::NonIdentityPoint,
+        b: &Self::NonIdentityPoint,
+    ) -> Result<Self::NonIdentityPoint, Error> {
+        let config: add_incomplete::Config<C> = self.config().into();
+        let (a, b): (EccPoint<C>, EccPoint<C>) = (a.clone().into(), b.clone().into());
+        let result = layouter.assign_region(
+            || "incomplete point addition",
+            |mut region| config.assign_region(&a, &b, 0, &mut region),
+        )?;
+        // Incomplete addition returns an error in the exceptional cases where
+        // a = ±b, so the result is never the identity.
+        Ok(NonIdentityEccPoint {
+            x: result.x,
+            y: result.y,
+        })
+    }
+
+    fn add(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<Self::Point, Error> {
-        let config: add_incomplete::Config = self.config().into();
+        let config: add::Config<C> = self.config().into();
         layouter.assign_region(
-            || "incomplete point addition",
+            || "complete point addition",
             |mut region| config.assign_region(a, b, 0, &mut region),
         )
     }
 
-    fn add(
+    fn double(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let config: double::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "point doubling",
+            |mut region| config.assign_region(a, 0, &mut region),
+        )
+    }
+
+    fn neg(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let config: neg::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "point negation",
+            |mut region| config.assign_neg(a, 0, &mut region),
+        )
+    }
+
+    fn conditional_neg(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::Point,
+        bit: &Self::Var,
+    ) -> Result<Self::Point, Error> {
+        let config: neg::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "conditional point negation",
+            |mut region| config.assign_conditional_neg(a, bit, 0, &mut region),
+        )
+    }
+
+    fn sub(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<Self::Point, Error> {
-        let config: add::Config = self.config().into();
+        let neg_b = self.neg(layouter, b)?;
+        self.add(layouter, a, &neg_b)
+    }
+
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        cond: &Self::Var,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let config: select::Config<C> = self.config().into();
         layouter.assign_region(
-            || "complete point addition",
-            |mut region| config.assign_region(a, b, 0, &mut region),
+            || "point selection",
+            |mut region| config.assign_region(cond, a, b, 0, &mut region),
+        )
+    }
+
+    fn select_from_table(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        index_bits: &[Self::Var],
+        table: &[Self::Point],
+    ) -> Result<Self::Point, Error> {
+        let config: select::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "point selection from table",
+            |mut region| config.assign_table(index_bits, table, &mut region),
+        )
+    }
+
+    fn is_equal(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Var, Error> {
+        let config: is_equal::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "point equality",
+            |mut region| config.assign_is_equal(a, b, 0, &mut region),
+        )
+    }
+
+    fn is_identity(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        a: &Self::Point,
+    ) -> Result<Self::Var, Error> {
+        let config: is_equal::Config<C> = self.config().into();
+        layouter.assign_region(
+            || "point is identity",
+            |mut region| config.assign_is_identity(a, 0, &mut region),
         )
     }
 
     fn mul(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
+        scalar: &Self::Var,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVar), Error> {
+        match self.config().var_base_mul {
+            VarBaseMul::DoubleAndAdd => {
+                let config: mul::Config<C> = self.config().into();
+                config.assign(
+                    layouter.namespace(|| "variable-base scalar mul"),
+                    *scalar,
+                    &base.clone().into(),
+                )
+            }
+            VarBaseMul::Windowed => {
+                let config: mul::windowed::Config<C> = self.config().into();
+                config.assign(
+                    layouter.namespace(|| "windowed variable-base scalar mul"),
+                    *scalar,
+                    base,
+                )
+            }
+        }
+    }
+
+    fn mul_full_width(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        scalar: Option<C::Scalar>,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVarFullWidth), Error> {
+        let config: mul::Config<C> = self.config().into();
+        config.assign_full_width(
+            layouter.namespace(|| "variable-base scalar mul (full-width scalar)"),
+            scalar,
+            &base.clone().into(),
+        )
+    }
+
+    fn mul_endo(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
         scalar: &Self::Var,
-        base: &Self::Point,
+        base: &Self::NonIdentityPoint,
     ) -> Result<(Self::Point, Self::ScalarVar), Error> {
-        let config: mul::Config = self.config().into();
+        let config: mul::endo::Config<C> = self.config().into();
         config.assign(
-            layouter.namespace(|| "variable-base scalar mul"),
+            layouter.namespace(|| "endomorphism-accelerated variable-base scalar mul"),
             *scalar,
             base,
         )
     }
 
+    fn msm(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        terms: &[(Self::Var, Self::NonIdentityPoint)],
+    ) -> Result<Self::Point, Error> {
+        let config: mul::msm::Config<C> = self.config().into();
+        config.assign(
+            layouter.namespace(|| "variable-base multi-scalar mul"),
+            terms,
+        )
+    }
+
     fn mul_fixed(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
-        scalar: Option<pallas::Scalar>,
+        layouter: &mut impl Layouter<C::Base>,
+        scalar: Option<C::Scalar>,
         base: &Self::FixedPoints,
     ) -> Result<(Self::Point, Self::ScalarFixed), Error> {
-        let config: mul_fixed::full_width::Config<Fixed> = self.config().into();
+        let config: mul_fixed::full_width::Config<C, Fixed, K> = self.config().into();
         config.assign(
             layouter.namespace(|| format!("fixed-base mul of {:?}", base)),
             scalar,
@@ -445,13 +1002,27 @@ impl<Fixed: FixedPoints<pallas::Affine>> EccInstructions<pallas::Affine> for Ecc
         )
     }
 
-    fn mul_fixed_short(
+    fn mul_fixed_from_windows(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        windows: &[CellValue<C::Base>],
+        base: &Self::FixedPoints,
+    ) -> Result<(Self::Point, Self::ScalarFixed), Error> {
+        let config: mul_fixed::full_width::Config<C, Fixed, K> = self.config().into();
+        config.assign_from_windows(
+            layouter.namespace(|| format!("fixed-base mul of {:?} from windows", base)),
+            windows,
+            base,
+        )
+    }
+
+    fn mul_fixed_short<const N: usize>(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
-        magnitude_sign: (CellValue<pallas::Base>, CellValue<pallas::Base>),
+        layouter: &mut impl Layouter<C::Base>,
+        magnitude_sign: (CellValue<C::Base>, CellValue<C::Base>),
         base: &Self::FixedPoints,
     ) -> Result<(Self::Point, Self::ScalarFixedShort), Error> {
-        let config: mul_fixed::short::Config<Fixed> = self.config().into();
+        let config: mul_fixed::short::Config<C, Fixed, K, N> = self.config().into();
         config.assign(
             layouter.namespace(|| format!("short fixed-base mul of {:?}", base)),
             magnitude_sign,
@@ -461,11 +1032,11 @@ impl<Fixed: FixedPoints<pallas::Affine>> EccInstructions<pallas::Affine> for Ecc
 
     fn mul_fixed_base_field_elem(
         &self,
-        layouter: &mut impl Layouter<pallas::Base>,
-        base_field_elem: CellValue<pallas::Base>,
+        layouter: &mut impl Layouter<C::Base>,
+        base_field_elem: CellValue<C::Base>,
         base: &Self::FixedPoints,
     ) -> Result<Self::Point, Error> {
-        let config: mul_fixed::base_field_elem::Config<Fixed> = self.config().into();
+        let config: mul_fixed::base_field_elem::Config<C, Fixed, K> = self.config().into();
         config.assign(
             layouter.namespace(|| format!("base-field elem fixed-base mul of {:?}", base)),
             base_field_elem,
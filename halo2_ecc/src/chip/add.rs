@@ -1,18 +1,16 @@
 use std::array;
 
-use super::{copy, CellValue, EccConfig, EccPoint, Var};
-use ff::Field;
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
 use halo2::{
     arithmetic::BatchInvert,
     circuit::Region,
     plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
     poly::Rotation,
 };
-use pasta_curves::{arithmetic::FieldExt, pallas};
-use std::collections::HashSet;
+use std::{collections::HashSet, marker::PhantomData};
 
 #[derive(Clone, Debug)]
-pub struct Config {
+pub struct Config<C: EccCurve> {
     q_add: Selector,
     // lambda
     lambda: Column<Advice>,
@@ -32,10 +30,11 @@ pub struct Config {
     gamma: Column<Advice>,
     // δ = inv0(y_p + y_q) if x_q = x_p, 0 otherwise
     delta: Column<Advice>,
+    _marker: PhantomData<C>,
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         Self {
             q_add: ecc_config.q_add,
             x_p: ecc_config.advices[0],
@@ -47,11 +46,12 @@ impl From<&EccConfig> for Config {
             beta: ecc_config.advices[6],
             gamma: ecc_config.advices[7],
             delta: ecc_config.advices[8],
+            _marker: PhantomData,
         }
     }
 }
 
-impl Config {
+impl<C: EccCurve> Config<C> {
     pub(crate) fn advice_columns(&self) -> HashSet<Column<Advice>> {
         core::array::IntoIter::new([
             self.x_p,
@@ -71,7 +71,7 @@ impl Config {
         core::array::IntoIter::new([self.x_qr, self.y_qr]).collect()
     }
 
-    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("complete addition gates", |meta| {
             let q_add = meta.query_selector(self.q_add);
             let x_p = meta.query_advice(self.x_p, Rotation::cur());
@@ -102,9 +102,9 @@ impl Config {
             let if_delta = (y_q.clone() + y_p.clone()) * delta;
 
             // Useful constants
-            let one = Expression::Constant(pallas::Base::one());
-            let two = Expression::Constant(pallas::Base::from_u64(2));
-            let three = Expression::Constant(pallas::Base::from_u64(3));
+            let one = Expression::Constant(C::Base::one());
+            let two = Expression::Constant(C::Base::from_u64(2));
+            let three = Expression::Constant(C::Base::from_u64(3));
 
             // (x_q − x_p)⋅((x_q − x_p)⋅λ − (y_q−y_p)) = 0
             let poly1 = {
@@ -196,11 +196,11 @@ impl Config {
 
     pub(super) fn assign_region(
         &self,
-        p: &EccPoint,
-        q: &EccPoint,
+        p: &EccPoint<C>,
+        q: &EccPoint<C>,
         offset: usize,
-        region: &mut Region<'_, pallas::Base>,
-    ) -> Result<EccPoint, Error> {
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
         // Enable `q_add` selector
         self.q_add.enable(region, offset)?;
 
@@ -278,7 +278,7 @@ impl Config {
                 if x_q == x_p {
                     delta.ok_or(Error::SynthesisError)
                 } else {
-                    Ok(pallas::Base::zero())
+                    Ok(C::Base::zero())
                 }
             },
         )?;
@@ -297,15 +297,15 @@ impl Config {
                         // know that x_q != x_p in this branch.
                         (y_q - y_p) * alpha
                     } else {
-                        if y_p != pallas::Base::zero() {
+                        if y_p != C::Base::zero() {
                             // 3(x_p)^2
-                            let three_x_p_sq = pallas::Base::from_u64(3) * x_p.square();
+                            let three_x_p_sq = C::Base::from_u64(3) * x_p.square();
                             // 1 / 2(y_p)
-                            let inv_two_y_p = y_p.invert().unwrap() * pallas::Base::TWO_INV;
+                            let inv_two_y_p = y_p.invert().unwrap() * C::Base::TWO_INV;
                             // λ = 3(x_p)^2 / 2(y_p)
                             three_x_p_sq * inv_two_y_p
                         } else {
-                            pallas::Base::zero()
+                            C::Base::zero()
                         }
                     }
                 });
@@ -324,15 +324,15 @@ impl Config {
                 .zip(lambda)
                 .map(|((((x_p, y_p), x_q), y_q), lambda)| {
                     {
-                        if x_p == pallas::Base::zero() {
+                        if x_p == C::Base::zero() {
                             // 0 + Q = Q
                             (x_q, y_q)
-                        } else if x_q == pallas::Base::zero() {
+                        } else if x_q == C::Base::zero() {
                             // P + 0 = P
                             (x_p, y_p)
                         } else if (x_q == x_p) && (y_q == -y_p) {
                             // P + (-P) maps to (0,0)
-                            (pallas::Base::zero(), pallas::Base::zero())
+                            (C::Base::zero(), C::Base::zero())
                         } else {
                             // x_r = λ^2 - x_p - x_q
                             let x_r = lambda.square() - x_p - x_q;
@@ -362,8 +362,8 @@ impl Config {
         )?;
 
         let result = EccPoint {
-            x: CellValue::<pallas::Base>::new(x_r_cell, x_r),
-            y: CellValue::<pallas::Base>::new(y_r_cell, y_r),
+            x: CellValue::<C::Base>::new(x_r_cell, x_r),
+            y: CellValue::<C::Base>::new(y_r_cell, y_r),
         };
 
         #[cfg(test)]
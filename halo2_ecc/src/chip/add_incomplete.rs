@@ -1,16 +1,15 @@
-use std::{array, collections::HashSet};
+use std::{array, collections::HashSet, marker::PhantomData};
 
-use super::{copy, CellValue, EccConfig, EccPoint, Var};
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
 use group::Curve;
 use halo2::{
     circuit::Region,
     plonk::{Advice, Column, ConstraintSystem, Error, Selector},
     poly::Rotation,
 };
-use pasta_curves::{arithmetic::CurveAffine, pallas};
 
 #[derive(Clone, Debug)]
-pub struct Config {
+pub struct Config<C: EccCurve> {
     q_add_incomplete: Selector,
     // x-coordinate of P in P + Q = R
     pub x_p: Column<Advice>,
@@ -20,26 +19,28 @@ pub struct Config {
     pub x_qr: Column<Advice>,
     // y-coordinate of Q or R in P + Q = R
     pub y_qr: Column<Advice>,
+    _marker: PhantomData<C>,
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         Self {
             q_add_incomplete: ecc_config.q_add_incomplete,
             x_p: ecc_config.advices[0],
             y_p: ecc_config.advices[1],
             x_qr: ecc_config.advices[2],
             y_qr: ecc_config.advices[3],
+            _marker: PhantomData,
         }
     }
 }
 
-impl Config {
+impl<C: EccCurve> Config<C> {
     pub(crate) fn advice_columns(&self) -> HashSet<Column<Advice>> {
         core::array::IntoIter::new([self.x_p, self.y_p, self.x_qr, self.y_qr]).collect()
     }
 
-    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("incomplete addition gates", |meta| {
             let q_add_incomplete = meta.query_selector(self.q_add_incomplete);
             let x_p = meta.query_advice(self.x_p, Rotation::cur());
@@ -66,11 +67,11 @@ impl Config {
 
     pub(super) fn assign_region(
         &self,
-        p: &EccPoint,
-        q: &EccPoint,
+        p: &EccPoint<C>,
+        q: &EccPoint<C>,
         offset: usize,
-        region: &mut Region<'_, pallas::Base>,
-    ) -> Result<EccPoint, Error> {
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
         // Enable `q_add_incomplete` selector
         self.q_add_incomplete.enable(region, offset)?;
 
@@ -82,9 +83,9 @@ impl Config {
             .zip(y_q)
             .map(|(((x_p, y_p), x_q), y_q)| {
                 // P is point at infinity
-                if (x_p == pallas::Base::zero() && y_p == pallas::Base::zero())
+                if (x_p == C::Base::zero() && y_p == C::Base::zero())
                 // Q is point at infinity
-                || (x_q == pallas::Base::zero() && y_q == pallas::Base::zero())
+                || (x_q == C::Base::zero() && y_q == C::Base::zero())
                 // x_p = x_q
                 || (x_p == x_q)
                 {
@@ -134,8 +135,8 @@ impl Config {
         )?;
 
         let result = EccPoint {
-            x: CellValue::<pallas::Base>::new(x_r_var, x_r),
-            y: CellValue::<pallas::Base>::new(y_r_var, y_r),
+            x: CellValue::<C::Base>::new(x_r_var, x_r),
+            y: CellValue::<C::Base>::new(y_r_var, y_r),
         };
 
         Ok(result)
@@ -148,7 +149,7 @@ pub mod tests {
     use halo2::{circuit::Layouter, plonk::Error};
     use pasta_curves::pallas;
 
-    use crate::gadget::{EccInstructions, Point};
+    use crate::gadget::{EccInstructions, NonIdentityPoint};
 
     #[allow(clippy::too_many_arguments)]
     pub fn test_add_incomplete<
@@ -159,30 +160,30 @@ pub mod tests {
     ) -> Result<(), Error> {
         // Generate a random point P
         let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
-        let p = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+        let p = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
         let p_neg = -p_val;
-        let p_neg = Point::new(chip.clone(), layouter.namespace(|| "-P"), Some(p_neg))?;
+        let p_neg = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "-P"), Some(p_neg))?;
 
         // Generate a random point Q
         let q_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // Q
-        let q = Point::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+        let q = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
 
         // Make sure P and Q are not the same point.
         assert_ne!(p_val, q_val);
 
-        // Generate a (0,0) point to be used in other tests.
-        let zero = {
-            Point::new(
-                chip.clone(),
-                layouter.namespace(|| "identity"),
-                Some(pallas::Affine::identity()),
-            )?
-        };
+        // The identity cannot be witnessed as a non-identity point, so incomplete
+        // addition never has 𝒪 as an input.
+        NonIdentityPoint::new(
+            chip.clone(),
+            layouter.namespace(|| "identity"),
+            Some(pallas::Affine::identity()),
+        )
+        .expect_err("witnessing 𝒪 as a non-identity point should return an error");
 
         // P + Q
         {
             let result = p.add_incomplete(layouter.namespace(|| "P + Q"), &q)?;
-            let witnessed_result = Point::new(
+            let witnessed_result = NonIdentityPoint::new(
                 chip,
                 layouter.namespace(|| "witnessed P + Q"),
                 Some((p_val + q_val).to_affine()),
@@ -198,18 +199,6 @@ pub mod tests {
         p.add_incomplete(layouter.namespace(|| "P + (-P)"), &p_neg)
             .expect_err("P + (-P) should return an error");
 
-        // P + 𝒪 should return an error
-        p.add_incomplete(layouter.namespace(|| "P + 𝒪"), &zero)
-            .expect_err("P + 0 should return an error");
-
-        // 𝒪 + P should return an error
-        zero.add_incomplete(layouter.namespace(|| "𝒪 + P"), &p)
-            .expect_err("0 + P should return an error");
-
-        // 𝒪 + 𝒪 should return an error
-        zero.add_incomplete(layouter.namespace(|| "𝒪 + 𝒪"), &zero)
-            .expect_err("𝒪 + 𝒪 should return an error");
-
         Ok(())
     }
 }
@@ -0,0 +1,216 @@
+use std::{array, marker::PhantomData};
+
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
+use halo2::{
+    circuit::Region,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone, Debug)]
+pub struct Config<C: EccCurve> {
+    q_double: Selector,
+    // x-coordinate of P in [2]P = R
+    pub x_p: Column<Advice>,
+    // y-coordinate of P in [2]P = R
+    pub y_p: Column<Advice>,
+    // x-coordinate of R in [2]P = R
+    pub x_r: Column<Advice>,
+    // y-coordinate of R in [2]P = R
+    pub y_r: Column<Advice>,
+    // λ = 3 x_p^2 / (2 y_p)
+    lambda: Column<Advice>,
+    // α = inv0(y_p)
+    alpha: Column<Advice>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_double: ecc_config.q_double,
+            x_p: ecc_config.advices[0],
+            y_p: ecc_config.advices[1],
+            x_r: ecc_config.advices[2],
+            y_r: ecc_config.advices[3],
+            lambda: ecc_config.advices[4],
+            alpha: ecc_config.advices[5],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // Neither Pallas nor Vesta has a point of order two, so y_p = 0 if and only
+        // if P is the identity (0, 0). In that case, we constrain R = (0, 0).
+        meta.create_gate("point doubling", |meta| {
+            let q_double = meta.query_selector(self.q_double);
+            let x_p = meta.query_advice(self.x_p, Rotation::cur());
+            let y_p = meta.query_advice(self.y_p, Rotation::cur());
+            let x_r = meta.query_advice(self.x_r, Rotation::cur());
+            let y_r = meta.query_advice(self.y_r, Rotation::cur());
+            let lambda = meta.query_advice(self.lambda, Rotation::cur());
+            let alpha = meta.query_advice(self.alpha, Rotation::cur());
+
+            let two = Expression::Constant(C::Base::from_u64(2));
+            let three = Expression::Constant(C::Base::from_u64(3));
+            let one = Expression::Constant(C::Base::one());
+
+            // y_p⋅(2⋅y_p⋅λ - 3⋅x_p^2) = 0
+            let lambda_check =
+                y_p.clone() * (two.clone() * y_p.clone() * lambda.clone() - three * x_p.square());
+
+            // y_p⋅(λ^2 - 2⋅x_p - x_r) = 0
+            let x_check = y_p.clone() * (lambda.clone().square() - two * x_p.clone() - x_r.clone());
+
+            // y_p⋅(λ⋅(x_p - x_r) - y_p - y_r) = 0
+            let y_check = y_p.clone() * (lambda * (x_p - x_r.clone()) - y_p.clone() - y_r.clone());
+
+            // (1 - y_p⋅α)⋅x_r = 0
+            // (1 - y_p⋅α)⋅y_r = 0
+            let y_p_is_zero = one - y_p * alpha;
+            let x_r_zero = y_p_is_zero.clone() * x_r;
+            let y_r_zero = y_p_is_zero * y_r;
+
+            array::IntoIter::new([lambda_check, x_check, y_check, x_r_zero, y_r_zero])
+                .map(move |poly| q_double.clone() * poly)
+        });
+    }
+
+    pub(super) fn assign_region(
+        &self,
+        p: &EccPoint<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        // Enable `q_double` selector
+        self.q_double.enable(region, offset)?;
+
+        // Copy point `p` into `x_p`, `y_p` columns
+        copy(region, || "x_p", self.x_p, offset, &p.x)?;
+        copy(region, || "y_p", self.y_p, offset, &p.y)?;
+
+        let (x_p, y_p) = (p.x.value(), p.y.value());
+
+        // Assign α = inv0(y_p)
+        let alpha = y_p.map(|y_p| y_p.invert().unwrap_or(C::Base::zero()));
+        region.assign_advice(
+            || "α",
+            self.alpha,
+            offset,
+            || alpha.ok_or(Error::SynthesisError),
+        )?;
+
+        // Assign λ = 3 x_p^2 / (2 y_p), or 0 if P is the identity
+        let lambda = x_p
+            .zip(alpha)
+            .map(|(x_p, alpha)| C::Base::from_u64(3) * x_p.square() * alpha * C::Base::TWO_INV);
+        region.assign_advice(
+            || "λ",
+            self.lambda,
+            offset,
+            || lambda.ok_or(Error::SynthesisError),
+        )?;
+
+        // Compute `R = [2]P`
+        let r = x_p.zip(y_p).zip(lambda).map(|((x_p, y_p), lambda)| {
+            if y_p == C::Base::zero() {
+                // [2]0 maps to (0,0)
+                (C::Base::zero(), C::Base::zero())
+            } else {
+                // x_r = λ^2 - 2⋅x_p
+                let x_r = lambda.square() - x_p.double();
+                // y_r = λ(x_p - x_r) - y_p
+                let y_r = lambda * (x_p - x_r) - y_p;
+                (x_r, y_r)
+            }
+        });
+
+        let x_r = r.map(|r| r.0);
+        let x_r_cell = region.assign_advice(
+            || "x_r",
+            self.x_r,
+            offset,
+            || x_r.ok_or(Error::SynthesisError),
+        )?;
+
+        let y_r = r.map(|r| r.1);
+        let y_r_cell = region.assign_advice(
+            || "y_r",
+            self.y_r,
+            offset,
+            || y_r.ok_or(Error::SynthesisError),
+        )?;
+
+        let result = EccPoint {
+            x: CellValue::<C::Base>::new(x_r_cell, x_r),
+            y: CellValue::<C::Base>::new(y_r_cell, y_r),
+        };
+
+        #[cfg(test)]
+        // Check that the correct point is obtained.
+        {
+            use group::Curve;
+
+            let real_double = p.point().map(|p| (p + p).to_affine());
+            let result = result.point();
+
+            if let (Some(real_double), Some(result)) = (real_double, result) {
+                assert_eq!(real_double, result);
+            }
+        }
+
+        Ok(result)
+    }
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2::{circuit::Layouter, plonk::Error};
+    use pasta_curves::pallas;
+
+    use crate::gadget::{EccInstructions, Point};
+
+    pub fn test_double<EccChip: EccInstructions<pallas::Affine> + Clone + Eq + std::fmt::Debug>(
+        chip: EccChip,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate a random point P
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
+        let p = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+
+        // Generate a (0,0) point to be used in other tests.
+        let zero = {
+            Point::new(
+                chip.clone(),
+                layouter.namespace(|| "identity"),
+                Some(pallas::Affine::identity()),
+            )?
+        };
+
+        // [2]P
+        {
+            let result = p.double(layouter.namespace(|| "[2]P"))?;
+            let witnessed_result = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "witnessed [2]P"),
+                Some((p_val + p_val).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| "constrain [2]P"), &witnessed_result)?;
+
+            // [2]P should equal P + P
+            let p_plus_p = p.add(layouter.namespace(|| "P + P"), &p)?;
+            result.constrain_equal(layouter.namespace(|| "[2]P = P + P"), &p_plus_p)?;
+        }
+
+        // [2]𝒪 = 𝒪
+        {
+            let result = zero.double(layouter.namespace(|| "[2]𝒪"))?;
+            result.constrain_equal(layouter.namespace(|| "[2]𝒪 = 𝒪"), &zero)?;
+        }
+
+        Ok(())
+    }
+}
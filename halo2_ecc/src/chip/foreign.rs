@@ -0,0 +1,1123 @@
+//! Chip implementing [`EccInstructions`] for a curve whose base field is not the
+//! circuit field, emulating the base and scalar field arithmetic with
+//! [`NonNativeFieldChip`].
+//!
+//! Points are represented in affine coordinates, with the identity mapped to
+//! (0, 0). The formulas assume that the curve has prime order and a nonzero `b`
+//! coefficient, so that the identity is the only point with a zero y-coordinate.
+
+use super::LOOKUP_K;
+use crate::{
+    gadget::{ecdsa::EcdsaInstructions, EccInstructions, FIXED_BASE_WINDOW_SIZE},
+    primitive::secp256k1,
+};
+use utilities::{
+    lookup_range_check::LookupRangeCheckConfig,
+    non_native::{
+        invert_mod, mul_mod, ForeignField, NonNativeElement, NonNativeFieldChip,
+        NonNativeFieldConfig, NonNativeFieldInstructions, PallasBase, Secp256k1Base,
+        Secp256k1Scalar, VestaBase,
+    },
+    CellValue, UtilitiesInstructions,
+};
+
+use bigint::U256;
+use ff::PrimeFieldBits;
+use halo2::{
+    circuit::{Chip, Layouter},
+    plonk::{Advice, Column, ConstraintSystem, Error},
+};
+use pasta_curves::{
+    arithmetic::{CurveAffine, FieldExt},
+    pallas, vesta,
+};
+use std::marker::PhantomData;
+
+/// A curve over which [`ForeignEccChip`] can be instantiated.
+///
+/// The curve must have prime order and a nonzero `b` coefficient.
+pub trait ForeignCurve: CurveAffine {
+    /// The base field of the curve.
+    type BaseField: ForeignField;
+
+    /// The scalar field of the curve.
+    type ScalarField: ForeignField;
+}
+
+impl ForeignCurve for pallas::Affine {
+    type BaseField = PallasBase;
+    type ScalarField = VestaBase;
+}
+
+impl ForeignCurve for vesta::Affine {
+    type BaseField = VestaBase;
+    type ScalarField = PallasBase;
+}
+
+impl ForeignCurve for secp256k1::Affine {
+    type BaseField = Secp256k1Base;
+    type ScalarField = Secp256k1Scalar;
+}
+
+/// An element of the base field of `C`.
+type Element<F, C> = NonNativeElement<F, <C as ForeignCurve>::BaseField>;
+
+/// A curve point represented in affine (x, y) coordinates, with each coordinate
+/// emulated as a foreign field element.
+#[derive(Clone, Debug)]
+pub struct ForeignPoint<F: FieldExt, C: ForeignCurve> {
+    /// x-coordinate
+    x: Element<F, C>,
+    /// y-coordinate
+    y: Element<F, C>,
+}
+
+impl<F: FieldExt, C: ForeignCurve> ForeignPoint<F, C> {
+    /// Returns the value of this curve point, if known.
+    pub fn point(&self) -> Option<C> {
+        point_value(&self.x, &self.y)
+    }
+    /// The affine short-Weierstrass x-coordinate, or 0 for the zero point.
+    pub fn x(&self) -> &Element<F, C> {
+        &self.x
+    }
+    /// The affine short-Weierstrass y-coordinate, or 0 for the zero point.
+    pub fn y(&self) -> &Element<F, C> {
+        &self.y
+    }
+}
+
+/// A curve point represented in affine (x, y) coordinates, that is constrained
+/// not to be the identity.
+#[derive(Clone, Debug)]
+pub struct NonIdentityForeignPoint<F: FieldExt, C: ForeignCurve> {
+    /// x-coordinate
+    x: Element<F, C>,
+    /// y-coordinate
+    y: Element<F, C>,
+}
+
+impl<F: FieldExt, C: ForeignCurve> NonIdentityForeignPoint<F, C> {
+    /// Returns the value of this curve point, if known.
+    pub fn point(&self) -> Option<C> {
+        point_value(&self.x, &self.y)
+    }
+    /// The affine short-Weierstrass x-coordinate.
+    pub fn x(&self) -> &Element<F, C> {
+        &self.x
+    }
+    /// The affine short-Weierstrass y-coordinate.
+    pub fn y(&self) -> &Element<F, C> {
+        &self.y
+    }
+}
+
+impl<F: FieldExt, C: ForeignCurve> From<NonIdentityForeignPoint<F, C>> for ForeignPoint<F, C> {
+    fn from(non_id_point: NonIdentityForeignPoint<F, C>) -> Self {
+        Self {
+            x: non_id_point.x,
+            y: non_id_point.y,
+        }
+    }
+}
+
+/// A signed short scalar used for fixed-base scalar multiplication, with a
+/// magnitude in the range [0..2^N) and a sign of either 1 or -1.
+#[derive(Clone, Debug)]
+pub struct ForeignScalarFixedShort<F: FieldExt> {
+    magnitude: CellValue<F>,
+    sign: CellValue<F>,
+    num_bits: usize,
+}
+
+impl<F: FieldExt> ForeignScalarFixedShort<F> {
+    /// The cell containing the magnitude of the scalar.
+    pub fn magnitude(&self) -> CellValue<F> {
+        self.magnitude
+    }
+    /// The cell containing the sign of the scalar.
+    pub fn sign(&self) -> CellValue<F> {
+        self.sign
+    }
+    /// The number of bits `N` in the magnitude of the scalar.
+    pub fn num_bits(&self) -> usize {
+        self.num_bits
+    }
+}
+
+/// Configuration for the [`ForeignEccChip`].
+///
+/// The non-native field configuration does not depend on the modulus, and is
+/// used for both the base and the scalar field of the curve.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForeignEccConfig<F: FieldExt + PrimeFieldBits> {
+    pub non_native: NonNativeFieldConfig<F>,
+}
+
+/// A chip implementing [`EccInstructions`] for the curve `C` in a circuit over
+/// the field `F`.
+///
+/// Fixed bases are given as the points themselves. Scalar multiplications use
+/// double-and-add over the bits of the scalar, with complete addition.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ForeignEccChip<F: FieldExt + PrimeFieldBits, C: ForeignCurve> {
+    config: ForeignEccConfig<F>,
+    _marker: PhantomData<C>,
+}
+
+impl<F: FieldExt + PrimeFieldBits, C: ForeignCurve> Chip<F> for ForeignEccChip<F, C> {
+    type Config = ForeignEccConfig<F>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt + PrimeFieldBits, C: ForeignCurve> UtilitiesInstructions<F>
+    for ForeignEccChip<F, C>
+{
+    type Var = CellValue<F>;
+}
+
+impl<F: FieldExt + PrimeFieldBits, C: ForeignCurve> ForeignEccChip<F, C> {
+    /// The lookup table of `range_check` is not loaded by this chip.
+    ///
+    /// # Side effects
+    ///
+    /// All columns in `advices` will be equality-enabled.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advices: [Column<Advice>; 5],
+        range_check: LookupRangeCheckConfig<F, LOOKUP_K>,
+    ) -> ForeignEccConfig<F> {
+        // The scalar field is emulated with the same configuration, which is only
+        // checked against the base field modulus.
+        assert!(C::ScalarField::MODULUS.bits() > 212);
+
+        ForeignEccConfig {
+            non_native: NonNativeFieldChip::<F, C::BaseField>::configure(
+                meta,
+                advices,
+                range_check,
+            ),
+        }
+    }
+
+    pub fn construct(config: ForeignEccConfig<F>) -> Self {
+        Self {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns a chip for arithmetic over the base field of the curve.
+    pub fn base_chip(&self) -> NonNativeFieldChip<F, C::BaseField> {
+        NonNativeFieldChip::construct(self.config.non_native.clone())
+    }
+
+    /// Returns a chip for arithmetic over the scalar field of the curve.
+    pub fn scalar_chip(&self) -> NonNativeFieldChip<F, C::ScalarField> {
+        NonNativeFieldChip::construct(self.config.non_native.clone())
+    }
+
+    /// Returns the identity, assigned as the constant (0, 0).
+    fn identity(&self, layouter: &mut impl Layouter<F>) -> Result<ForeignPoint<F, C>, Error> {
+        let zero = self
+            .base_chip()
+            .constant_element(layouter.namespace(|| "0"), U256::zero())?;
+        Ok(ForeignPoint {
+            x: zero.clone(),
+            y: zero,
+        })
+    }
+
+    /// Returns $y^2 - (x^3 + a x + b)$, which is zero if and only if $(x, y)$ is
+    /// on the curve.
+    fn curve_equation(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        x: &Element<F, C>,
+        y: &Element<F, C>,
+    ) -> Result<Element<F, C>, Error> {
+        let chip = self.base_chip();
+
+        let y_sq = chip.mul(layouter.namespace(|| "y^2"), y, y)?;
+        let x_sq = chip.mul(layouter.namespace(|| "x^2"), x, x)?;
+        let x_cu = chip.mul(layouter.namespace(|| "x^3"), &x_sq, x)?;
+        let a = chip.constant_element(layouter.namespace(|| "a"), to_u256(C::a()))?;
+        let ax = chip.mul(layouter.namespace(|| "a⋅x"), &a, x)?;
+        let b = chip.constant_element(layouter.namespace(|| "b"), to_u256(C::b()))?;
+
+        let rhs = chip.add(layouter.namespace(|| "x^3 + a⋅x"), &x_cu, &ax)?;
+        let rhs = chip.add(layouter.namespace(|| "x^3 + a⋅x + b"), &rhs, &b)?;
+        chip.sub(layouter.namespace(|| "y^2 - (x^3 + a⋅x + b)"), &y_sq, &rhs)
+    }
+
+    /// Constrains `point` to be either on the curve, or (0, 0).
+    fn check_point(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        point: &ForeignPoint<F, C>,
+    ) -> Result<(), Error> {
+        let chip = self.base_chip();
+
+        let equation = self.curve_equation(layouter, &point.x, &point.y)?;
+        let is_identity = chip.is_zero(layouter.namespace(|| "y = 0"), &point.y)?;
+        // If y = 0 then x = 0, and otherwise the curve equation holds.
+        let check = chip.select(
+            layouter.namespace(|| "x or curve equation"),
+            &is_identity,
+            &point.x,
+            &equation,
+        )?;
+        let zero = chip.constant_element(layouter.namespace(|| "0"), U256::zero())?;
+        chip.assert_equal(layouter.namespace(|| "check = 0"), &check, &zero)
+    }
+
+    /// Witnesses the coordinates of `value`, mapping the identity to (0, 0).
+    fn witness_coordinates(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C>,
+    ) -> Result<ForeignPoint<F, C>, Error> {
+        let chip = self.base_chip();
+        let value = value.map(coordinates);
+
+        let x = chip.witness_element(layouter.namespace(|| "x"), value.map(|value| value.0))?;
+        let y = chip.witness_element(layouter.namespace(|| "y"), value.map(|value| value.1))?;
+        Ok(ForeignPoint { x, y })
+    }
+
+    /// Returns the numerator $3 x^2 + a$ and denominator $2 y$ of the slope of the
+    /// tangent at `point`.
+    fn tangent(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        point: &ForeignPoint<F, C>,
+    ) -> Result<(Element<F, C>, Element<F, C>), Error> {
+        let chip = self.base_chip();
+
+        let x_sq = chip.mul(layouter.namespace(|| "x^2"), &point.x, &point.x)?;
+        let x_sq_2 = chip.add(layouter.namespace(|| "2⋅x^2"), &x_sq, &x_sq)?;
+        let x_sq_3 = chip.add(layouter.namespace(|| "3⋅x^2"), &x_sq_2, &x_sq)?;
+        let a = chip.constant_element(layouter.namespace(|| "a"), to_u256(C::a()))?;
+        let num = chip.add(layouter.namespace(|| "3⋅x^2 + a"), &x_sq_3, &a)?;
+        let den = chip.add(layouter.namespace(|| "2⋅y"), &point.y, &point.y)?;
+
+        Ok((num, den))
+    }
+
+    /// Witnesses the slope λ, constraining λ⋅den = num unless `exceptional` is 1.
+    fn lambda(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        exceptional: &CellValue<F>,
+        num: &Element<F, C>,
+        den: &Element<F, C>,
+    ) -> Result<Element<F, C>, Error> {
+        let chip = self.base_chip();
+        let modulus = C::BaseField::MODULUS;
+
+        let lambda = num.value().zip(den.value()).map(|(num, den)| {
+            let den_inv = invert_mod(den % modulus, modulus).unwrap_or_else(U256::zero);
+            mul_mod(num, den_inv, modulus)
+        });
+        let lambda = chip.witness_element(layouter.namespace(|| "λ"), lambda)?;
+
+        let product = chip.mul(layouter.namespace(|| "λ⋅den"), &lambda, den)?;
+        let product = chip.select(
+            layouter.namespace(|| "skip exceptional case"),
+            exceptional,
+            num,
+            &product,
+        )?;
+        chip.assert_equal(layouter.namespace(|| "λ⋅den = num"), &product, num)?;
+
+        Ok(lambda)
+    }
+
+    /// Returns $(x_r, y_r)$ for $x_r = λ^2 - x_a - x_b$ and
+    /// $y_r = λ(x_a - x_r) - y_a$.
+    fn from_lambda(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        lambda: &Element<F, C>,
+        a: &ForeignPoint<F, C>,
+        x_b: &Element<F, C>,
+    ) -> Result<ForeignPoint<F, C>, Error> {
+        let chip = self.base_chip();
+
+        let lambda_sq = chip.mul(layouter.namespace(|| "λ^2"), lambda, lambda)?;
+        let x_r = chip.sub(layouter.namespace(|| "λ^2 - x_a"), &lambda_sq, &a.x)?;
+        let x_r = chip.sub(layouter.namespace(|| "λ^2 - x_a - x_b"), &x_r, x_b)?;
+
+        let dx = chip.sub(layouter.namespace(|| "x_a - x_r"), &a.x, &x_r)?;
+        let y_r = chip.mul(layouter.namespace(|| "λ⋅(x_a - x_r)"), lambda, &dx)?;
+        let y_r = chip.sub(layouter.namespace(|| "λ⋅(x_a - x_r) - y_a"), &y_r, &a.y)?;
+
+        Ok(ForeignPoint { x: x_r, y: y_r })
+    }
+
+    /// Returns the little-endian bits of a native scalar.
+    fn native_scalar_bits(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: &CellValue<F>,
+    ) -> Result<Vec<CellValue<F>>, Error> {
+        let chip = self.scalar_chip();
+        let scalar = chip.from_native(layouter.namespace(|| "scalar"), scalar)?;
+        chip.to_bits(layouter.namespace(|| "bits"), &scalar, F::NUM_BITS as usize)
+    }
+
+    /// Witnesses a canonical full-width scalar, and returns it with its
+    /// little-endian bits.
+    fn full_width_scalar_bits(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: Option<C::Scalar>,
+    ) -> Result<(NonNativeElement<F, C::ScalarField>, Vec<CellValue<F>>), Error> {
+        let scalar = self.witness_scalar(layouter, scalar)?;
+        let bits = self.scalar_chip().to_bits(
+            layouter.namespace(|| "bits"),
+            &scalar,
+            C::ScalarField::MODULUS.bits(),
+        )?;
+        Ok((scalar, bits))
+    }
+
+    /// Returns $\sum [k_i] P_i$ for the given little-endian scalar bits $k_i$ and
+    /// bases $P_i$, sharing the doublings across all terms.
+    fn double_and_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        terms: &[(Vec<CellValue<F>>, ForeignPoint<F, C>)],
+    ) -> Result<ForeignPoint<F, C>, Error> {
+        let num_bits = terms.first().map(|(bits, _)| bits.len()).unwrap_or(0);
+        assert!(terms.iter().all(|(bits, _)| bits.len() == num_bits));
+
+        let mut acc = self.identity(layouter)?;
+        for i in (0..num_bits).rev() {
+            // The accumulator is the identity before the first addition.
+            if i != num_bits - 1 {
+                acc = self.double(&mut layouter.namespace(|| format!("double {}", i)), &acc)?;
+            }
+            for (j, (bits, base)) in terms.iter().enumerate() {
+                let mut layouter = layouter.namespace(|| format!("bit {} of term {}", i, j));
+                let sum = self.add(&mut layouter, &acc, base)?;
+                acc = self.select(&mut layouter, &bits[i], &sum, &acc)?;
+            }
+        }
+
+        Ok(acc)
+    }
+}
+
+impl<F: FieldExt + PrimeFieldBits, C: ForeignCurve> EccInstructions<C, F> for ForeignEccChip<F, C> {
+    type ScalarFixed = NonNativeElement<F, C::ScalarField>;
+    type ScalarFixedShort = ForeignScalarFixedShort<F>;
+    type ScalarVar = CellValue<F>;
+    type ScalarVarFullWidth = NonNativeElement<F, C::ScalarField>;
+    type Point = ForeignPoint<F, C>;
+    type NonIdentityPoint = NonIdentityForeignPoint<F, C>;
+    type X = Element<F, C>;
+    type FixedPoints = C;
+
+    fn constrain_equal(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<(), Error> {
+        let chip = self.base_chip();
+        chip.assert_equal(layouter.namespace(|| "x_a = x_b"), &a.x, &b.x)?;
+        chip.assert_equal(layouter.namespace(|| "y_a = y_b"), &a.y, &b.y)
+    }
+
+    fn witness_point(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C>,
+    ) -> Result<Self::Point, Error> {
+        let point = self.witness_coordinates(layouter, value)?;
+        self.check_point(layouter, &point)?;
+        Ok(point)
+    }
+
+    fn witness_point_non_id(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C>,
+    ) -> Result<Self::NonIdentityPoint, Error> {
+        // Witnessing the identity as a non-identity point is an error.
+        if value == Some(C::identity()) {
+            return Err(Error::SynthesisError);
+        }
+
+        let point = self.witness_coordinates(layouter, value)?;
+        // (0, 0) is not on the curve, so the curve equation excludes the identity.
+        let equation = self.curve_equation(layouter, &point.x, &point.y)?;
+        let chip = self.base_chip();
+        let zero = chip.constant_element(layouter.namespace(|| "0"), U256::zero())?;
+        chip.assert_equal(layouter.namespace(|| "on curve"), &equation, &zero)?;
+
+        Ok(NonIdentityForeignPoint {
+            x: point.x,
+            y: point.y,
+        })
+    }
+
+    fn constant_point(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: C,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.base_chip();
+        let (x, y) = coordinates(value);
+        Ok(ForeignPoint {
+            x: chip.constant_element(layouter.namespace(|| "x"), x)?,
+            y: chip.constant_element(layouter.namespace(|| "y"), y)?,
+        })
+    }
+
+    fn copy_point(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        x: Self::Var,
+        y: Self::Var,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.base_chip();
+        let point = ForeignPoint {
+            x: chip.from_native(layouter.namespace(|| "x"), &x)?,
+            y: chip.from_native(layouter.namespace(|| "y"), &y)?,
+        };
+        self.check_point(layouter, &point)?;
+        Ok(point)
+    }
+
+    fn extract_p(point: &Self::Point) -> &Self::X {
+        &point.x
+    }
+
+    fn add_incomplete(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::NonIdentityPoint,
+        b: &Self::NonIdentityPoint,
+    ) -> Result<Self::NonIdentityPoint, Error> {
+        // Incomplete addition returns an error in the exceptional cases where
+        // a = ±b, so the result is never the identity.
+        let modulus = C::BaseField::MODULUS;
+        if let (Some(x_a), Some(x_b)) = (a.x.value(), b.x.value()) {
+            if x_a % modulus == x_b % modulus {
+                return Err(Error::SynthesisError);
+            }
+        }
+
+        let chip = self.base_chip();
+        let dx = chip.sub(layouter.namespace(|| "x_b - x_a"), &b.x, &a.x)?;
+        let dy = chip.sub(layouter.namespace(|| "y_b - y_a"), &b.y, &a.y)?;
+        let lambda = chip.div(layouter.namespace(|| "λ"), &dy, &dx)?;
+        let result = self.from_lambda(layouter, &lambda, &a.clone().into(), &b.x)?;
+
+        Ok(NonIdentityForeignPoint {
+            x: result.x,
+            y: result.y,
+        })
+    }
+
+    fn add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.base_chip();
+
+        let a_is_identity = chip.is_zero(layouter.namespace(|| "a = 0"), &a.y)?;
+        let b_is_identity = chip.is_zero(layouter.namespace(|| "b = 0"), &b.y)?;
+
+        let dx = chip.sub(layouter.namespace(|| "x_b - x_a"), &b.x, &a.x)?;
+        let dy = chip.sub(layouter.namespace(|| "y_b - y_a"), &b.y, &a.y)?;
+        let same_x = chip.is_zero(layouter.namespace(|| "x_a = x_b"), &dx)?;
+        let same_y = chip.is_zero(layouter.namespace(|| "y_a = y_b"), &dy)?;
+
+        // If x_a = x_b, then either a = b and the slope is that of the tangent,
+        // or a = -b and the sum is the identity.
+        let (tangent_num, tangent_den) = self.tangent(layouter, a)?;
+        let num = chip.select(layouter.namespace(|| "num"), &same_x, &tangent_num, &dy)?;
+        let den = chip.select(layouter.namespace(|| "den"), &same_x, &tangent_den, &dx)?;
+
+        let different_y = chip.not(layouter.namespace(|| "y_a ≠ y_b"), &same_y)?;
+        let inverse = chip.and(layouter.namespace(|| "a = -b"), &same_x, &different_y)?;
+        let exceptional = chip.or(
+            layouter.namespace(|| "a = 0 ∨ b = 0"),
+            &a_is_identity,
+            &b_is_identity,
+        )?;
+        let exceptional = chip.or(layouter.namespace(|| "exceptional"), &exceptional, &inverse)?;
+
+        let lambda = self.lambda(layouter, &exceptional, &num, &den)?;
+        let result = self.from_lambda(layouter, &lambda, a, &b.x)?;
+
+        // Handle the exceptional cases, in increasing order of precedence.
+        let identity = self.identity(layouter)?;
+        let result = self.select(layouter, &inverse, &identity, &result)?;
+        let result = self.select(layouter, &b_is_identity, a, &result)?;
+        self.select(layouter, &a_is_identity, b, &result)
+    }
+
+    fn double(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.base_chip();
+
+        let is_identity = chip.is_zero(layouter.namespace(|| "a = 0"), &a.y)?;
+        let (num, den) = self.tangent(layouter, a)?;
+        let lambda = self.lambda(layouter, &is_identity, &num, &den)?;
+        let result = self.from_lambda(layouter, &lambda, a, &a.x)?;
+
+        let identity = self.identity(layouter)?;
+        self.select(layouter, &is_identity, &identity, &result)
+    }
+
+    fn neg(&self, layouter: &mut impl Layouter<F>, a: &Self::Point) -> Result<Self::Point, Error> {
+        Ok(ForeignPoint {
+            x: a.x.clone(),
+            y: self.base_chip().neg(layouter.namespace(|| "-y"), &a.y)?,
+        })
+    }
+
+    fn conditional_neg(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        bit: &Self::Var,
+    ) -> Result<Self::Point, Error> {
+        let neg = self.neg(layouter, a)?;
+        self.select(layouter, bit, &neg, a)
+    }
+
+    fn sub(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let b_neg = self.neg(layouter, b)?;
+        self.add(layouter, a, &b_neg)
+    }
+
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        cond: &Self::Var,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.base_chip();
+        Ok(ForeignPoint {
+            x: chip.select(layouter.namespace(|| "x"), cond, &a.x, &b.x)?,
+            y: chip.select(layouter.namespace(|| "y"), cond, &a.y, &b.y)?,
+        })
+    }
+
+    fn select_from_table(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        index_bits: &[Self::Var],
+        table: &[Self::Point],
+    ) -> Result<Self::Point, Error> {
+        assert_eq!(table.len(), 1 << index_bits.len());
+
+        // Halve the table once for each bit, starting from the least significant.
+        let mut table = table.to_vec();
+        for (i, bit) in index_bits.iter().enumerate() {
+            table = table
+                .chunks(2)
+                .enumerate()
+                .map(|(j, pair)| {
+                    self.select(
+                        &mut layouter.namespace(|| format!("bit {}, pair {}", i, j)),
+                        bit,
+                        &pair[1],
+                        &pair[0],
+                    )
+                })
+                .collect::<Result<_, _>>()?;
+        }
+
+        Ok(table.remove(0))
+    }
+
+    fn is_equal(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Var, Error> {
+        let chip = self.base_chip();
+
+        let dx = chip.sub(layouter.namespace(|| "x_a - x_b"), &a.x, &b.x)?;
+        let dy = chip.sub(layouter.namespace(|| "y_a - y_b"), &a.y, &b.y)?;
+        let same_x = chip.is_zero(layouter.namespace(|| "x_a = x_b"), &dx)?;
+        let same_y = chip.is_zero(layouter.namespace(|| "y_a = y_b"), &dy)?;
+        chip.and(layouter.namespace(|| "a = b"), &same_x, &same_y)
+    }
+
+    fn is_identity(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+    ) -> Result<Self::Var, Error> {
+        self.base_chip()
+            .is_zero(layouter.namespace(|| "y = 0"), &a.y)
+    }
+
+    fn mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: &Self::Var,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVar), Error> {
+        let bits = self.native_scalar_bits(layouter, scalar)?;
+        let result = self.double_and_add(layouter, &[(bits, base.clone().into())])?;
+        Ok((result, *scalar))
+    }
+
+    fn mul_full_width(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: Option<C::Scalar>,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVarFullWidth), Error> {
+        let (scalar, bits) = self.full_width_scalar_bits(layouter, scalar)?;
+        let result = self.double_and_add(layouter, &[(bits, base.clone().into())])?;
+        Ok((result, scalar))
+    }
+
+    /// The curve endomorphism is not used by this chip, and this is equivalent to
+    /// [`EccInstructions::mul`].
+    fn mul_endo(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: &Self::Var,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVar), Error> {
+        self.mul(layouter, scalar, base)
+    }
+
+    fn msm(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        terms: &[(Self::Var, Self::NonIdentityPoint)],
+    ) -> Result<Self::Point, Error> {
+        let terms = terms
+            .iter()
+            .enumerate()
+            .map(|(i, (scalar, base))| {
+                let bits = self.native_scalar_bits(
+                    &mut layouter.namespace(|| format!("scalar {}", i)),
+                    scalar,
+                )?;
+                Ok((bits, base.clone().into()))
+            })
+            .collect::<Result<Vec<(_, ForeignPoint<F, C>)>, Error>>()?;
+        self.double_and_add(layouter, &terms)
+    }
+
+    fn mul_fixed(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: Option<C::Scalar>,
+        base: &Self::FixedPoints,
+    ) -> Result<(Self::Point, Self::ScalarFixed), Error> {
+        let (scalar, bits) = self.full_width_scalar_bits(layouter, scalar)?;
+        let base = self.constant_point(layouter, *base)?;
+        let result = self.double_and_add(layouter, &[(bits, base)])?;
+        Ok((result, scalar))
+    }
+
+    fn mul_fixed_from_windows(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        windows: &[Self::Var],
+        base: &Self::FixedPoints,
+    ) -> Result<(Self::Point, Self::ScalarFixed), Error> {
+        let chip = self.scalar_chip();
+
+        let mut bits = Vec::with_capacity(windows.len() * FIXED_BASE_WINDOW_SIZE);
+        for (i, window) in windows.iter().enumerate() {
+            bits.extend(chip.native_to_bits(
+                layouter.namespace(|| format!("window {}", i)),
+                window,
+                FIXED_BASE_WINDOW_SIZE,
+            )?);
+        }
+        let scalar = chip.from_bits(layouter.namespace(|| "scalar"), &bits)?;
+        chip.assert_canonical(layouter.namespace(|| "canonical"), &scalar)?;
+
+        let base = self.constant_point(layouter, *base)?;
+        let result = self.double_and_add(layouter, &[(bits, base)])?;
+        Ok((result, scalar))
+    }
+
+    fn mul_fixed_short<const N: usize>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        magnitude_sign: (Self::Var, Self::Var),
+        base: &Self::FixedPoints,
+    ) -> Result<(Self::Point, Self::ScalarFixedShort), Error> {
+        let (magnitude, sign) = magnitude_sign;
+        let chip = self.scalar_chip();
+
+        let bits = chip.native_to_bits(layouter.namespace(|| "magnitude"), &magnitude, N)?;
+        let is_negative = chip.sign_to_bit(layouter.namespace(|| "sign"), &sign)?;
+
+        let base = self.constant_point(layouter, *base)?;
+        let result = self.double_and_add(layouter, &[(bits, base)])?;
+        let result = self.conditional_neg(layouter, &result, &is_negative)?;
+
+        Ok((
+            result,
+            ForeignScalarFixedShort {
+                magnitude,
+                sign,
+                num_bits: N,
+            },
+        ))
+    }
+
+    fn mul_fixed_base_field_elem(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        base_field_elem: Self::Var,
+        base: &Self::FixedPoints,
+    ) -> Result<Self::Point, Error> {
+        let bits = self.native_scalar_bits(layouter, &base_field_elem)?;
+        let base = self.constant_point(layouter, *base)?;
+        self.double_and_add(layouter, &[(bits, base)])
+    }
+}
+
+impl<F: FieldExt + PrimeFieldBits, C: ForeignCurve> EcdsaInstructions<C, F>
+    for ForeignEccChip<F, C>
+{
+    type Scalar = NonNativeElement<F, C::ScalarField>;
+
+    fn witness_scalar(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C::Scalar>,
+    ) -> Result<Self::Scalar, Error> {
+        let chip = self.scalar_chip();
+        let scalar = chip.witness_element(layouter.namespace(|| "scalar"), value.map(to_u256))?;
+        chip.assert_canonical(layouter.namespace(|| "canonical"), &scalar)?;
+        Ok(scalar)
+    }
+
+    fn scalar_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+        b: &Self::Scalar,
+    ) -> Result<Self::Scalar, Error> {
+        self.scalar_chip().mul(layouter.namespace(|| "a⋅b"), a, b)
+    }
+
+    fn scalar_invert(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+    ) -> Result<Self::Scalar, Error> {
+        let chip = self.scalar_chip();
+        let one = chip.constant_element(layouter.namespace(|| "1"), U256::one())?;
+        chip.div(layouter.namespace(|| "1 / a"), &one, a)
+    }
+
+    fn mul_fixed_and_add_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+        g: &Self::FixedPoints,
+        b: &Self::Scalar,
+        p: &Self::NonIdentityPoint,
+    ) -> Result<Self::Point, Error> {
+        let chip = self.scalar_chip();
+        let num_bits = C::ScalarField::MODULUS.bits();
+        let a_bits = chip.to_bits(layouter.namespace(|| "a"), a, num_bits)?;
+        let b_bits = chip.to_bits(layouter.namespace(|| "b"), b, num_bits)?;
+
+        let g = self.constant_point(layouter, *g)?;
+        self.double_and_add(layouter, &[(a_bits, g), (b_bits, p.clone().into())])
+    }
+
+    fn constrain_x_to_scalar(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        point: &Self::Point,
+        scalar: &Self::Scalar,
+    ) -> Result<(), Error> {
+        // Reinterpret the canonical x-coordinate as an integer in the scalar field.
+        let base_chip = self.base_chip();
+        let x = base_chip.reduce(layouter.namespace(|| "x mod p"), &point.x)?;
+        let bits =
+            base_chip.to_bits(layouter.namespace(|| "x"), &x, C::BaseField::MODULUS.bits())?;
+
+        let scalar_chip = self.scalar_chip();
+        let x = scalar_chip.from_bits(layouter.namespace(|| "x mod n"), &bits)?;
+        scalar_chip.assert_equal(layouter.namespace(|| "x = scalar (mod n)"), &x, scalar)
+    }
+}
+
+/// Returns the integer value of a field element.
+fn to_u256<G: FieldExt>(value: G) -> U256 {
+    U256::from_little_endian(&value.to_bytes())
+}
+
+/// Returns the integer values of the coordinates of `point`, mapping the identity
+/// to (0, 0).
+fn coordinates<C: CurveAffine>(point: C) -> (U256, U256) {
+    if point == C::identity() {
+        (U256::zero(), U256::zero())
+    } else {
+        let point = point.coordinates().unwrap();
+        (to_u256(*point.x()), to_u256(*point.y()))
+    }
+}
+
+/// Returns the point with the given coordinates, if known, mapping (0, 0) to the
+/// identity.
+fn point_value<F: FieldExt, C: ForeignCurve>(x: &Element<F, C>, y: &Element<F, C>) -> Option<C> {
+    let base = |value: U256| {
+        let mut bytes = [0u8; 32];
+        (value % C::BaseField::MODULUS).to_little_endian(&mut bytes);
+        C::Base::from_bytes(&bytes).unwrap()
+    };
+    x.value().zip(y.value()).map(|(x, y)| {
+        let (x, y) = (base(x), base(y));
+        if x == C::Base::zero() && y == C::Base::zero() {
+            C::identity()
+        } else {
+            C::from_xy(x, y).unwrap()
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{prime::PrimeCurveAffine, Curve};
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas, vesta};
+    use std::marker::PhantomData;
+
+    use super::{ForeignCurve, ForeignEccChip, ForeignEccConfig};
+    use crate::{
+        chip::test_circuit::configure_foreign_ecc_chip,
+        gadget::{FixedPoint, NonIdentityPoint, Point},
+        primitive::secp256k1,
+    };
+    use utilities::{UtilitiesInstructions, Var};
+
+    type MyChip<C> = ForeignEccChip<pallas::Base, C>;
+    type MyPoint<C> = Point<C, MyChip<C>, pallas::Base>;
+    type MyNonIdentityPoint<C> = NonIdentityPoint<C, MyChip<C>, pallas::Base>;
+
+    // Checks that `point` has the expected value, both natively and in-circuit.
+    fn check<C: ForeignCurve>(
+        chip: &MyChip<C>,
+        mut layouter: impl Layouter<pallas::Base>,
+        point: &MyPoint<C>,
+        expected: C,
+    ) -> Result<(), Error> {
+        if let Some(value) = point.inner().point() {
+            assert_eq!(value, expected);
+        }
+        let expected = MyPoint::new(
+            chip.clone(),
+            layouter.namespace(|| "expected"),
+            Some(expected),
+        )?;
+        point.constrain_equal(layouter.namespace(|| "constrain equal"), &expected)
+    }
+
+    struct MyCircuit<C: ForeignCurve>(PhantomData<C>);
+
+    impl<C: ForeignCurve> Circuit<pallas::Base> for MyCircuit<C> {
+        type Config = ForeignEccConfig<pallas::Base>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            MyCircuit(PhantomData)
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_foreign_ecc_chip::<C>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = MyChip::<C>::construct(config.clone());
+
+            // Load 10-bit lookup table.
+            config.non_native.range_check.load(&mut layouter)?;
+
+            let g = C::generator();
+            let p_val = (g * C::ScalarExt::rand()).to_affine();
+            let q_val = (g * C::ScalarExt::rand()).to_affine();
+
+            let p = MyNonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+            let q = MyNonIdentityPoint::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+            let p_neg = MyPoint::new(chip.clone(), layouter.namespace(|| "-P"), Some(-p_val))?;
+            let identity = MyPoint::new(
+                chip.clone(),
+                layouter.namespace(|| "O"),
+                Some(C::identity()),
+            )?;
+            let (p, q) = (MyPoint::from(p), MyPoint::from(q));
+
+            // Complete addition, including the exceptional cases.
+            let sum = p.add(layouter.namespace(|| "P + Q"), &q)?;
+            check(
+                &chip,
+                layouter.namespace(|| "P + Q"),
+                &sum,
+                (p_val.to_curve() + q_val).to_affine(),
+            )?;
+            let sum = p.add(layouter.namespace(|| "P + P"), &p)?;
+            check(
+                &chip,
+                layouter.namespace(|| "P + P"),
+                &sum,
+                (p_val.to_curve() + p_val).to_affine(),
+            )?;
+            let sum = p.add(layouter.namespace(|| "P + (-P)"), &p_neg)?;
+            check(
+                &chip,
+                layouter.namespace(|| "P + (-P)"),
+                &sum,
+                C::identity(),
+            )?;
+            let sum = identity.add(layouter.namespace(|| "O + P"), &p)?;
+            check(&chip, layouter.namespace(|| "O + P"), &sum, p_val)?;
+            let sum = p.add(layouter.namespace(|| "P + O"), &identity)?;
+            check(&chip, layouter.namespace(|| "P + O"), &sum, p_val)?;
+
+            // Doubling, negation and subtraction
+            let doubled = p.double(layouter.namespace(|| "[2] P"))?;
+            check(
+                &chip,
+                layouter.namespace(|| "[2] P"),
+                &doubled,
+                (p_val.to_curve() + p_val).to_affine(),
+            )?;
+            let doubled = identity.double(layouter.namespace(|| "[2] O"))?;
+            check(
+                &chip,
+                layouter.namespace(|| "[2] O"),
+                &doubled,
+                C::identity(),
+            )?;
+            let neg = p.neg(layouter.namespace(|| "-P"))?;
+            check(&chip, layouter.namespace(|| "-P"), &neg, -p_val)?;
+            let difference = p.sub(layouter.namespace(|| "P - Q"), &q)?;
+            check(
+                &chip,
+                layouter.namespace(|| "P - Q"),
+                &difference,
+                (p_val.to_curve() - q_val).to_affine(),
+            )?;
+
+            // Conditional negation, selection and comparison
+            let one = chip.load_private(
+                layouter.namespace(|| "1"),
+                config.non_native.advices[0],
+                Some(pallas::Base::one()),
+            )?;
+            let neg = p.conditional_neg(layouter.namespace(|| "1 ? -P : P"), &one)?;
+            check(&chip, layouter.namespace(|| "1 ? -P : P"), &neg, -p_val)?;
+            let selected = MyPoint::select(layouter.namespace(|| "1 ? P : Q"), &one, &p, &q)?;
+            check(&chip, layouter.namespace(|| "1 ? P : Q"), &selected, p_val)?;
+
+            let equal = p.is_equal(layouter.namespace(|| "P = P"), &p)?;
+            assert_eq!(equal.value(), Some(pallas::Base::one()));
+            let equal = p.is_equal(layouter.namespace(|| "P = Q"), &q)?;
+            assert_eq!(equal.value(), Some(pallas::Base::zero()));
+            let is_identity = identity.is_identity(layouter.namespace(|| "O = O"))?;
+            assert_eq!(is_identity.value(), Some(pallas::Base::one()));
+
+            // Incomplete addition
+            {
+                let p =
+                    MyNonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+                let q =
+                    MyNonIdentityPoint::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+                let sum = p.add_incomplete(layouter.namespace(|| "P + Q"), &q)?;
+                check(
+                    &chip,
+                    layouter.namespace(|| "incomplete P + Q"),
+                    &sum.into(),
+                    (p_val.to_curve() + q_val).to_affine(),
+                )?;
+                assert!(p
+                    .add_incomplete(layouter.namespace(|| "P + P"), &p)
+                    .is_err());
+            }
+
+            // Fixed-base scalar multiplication with a short signed scalar
+            {
+                let magnitude = pallas::Base::from_u64(0xab);
+                let magnitude = chip.load_private(
+                    layouter.namespace(|| "magnitude"),
+                    config.non_native.advices[0],
+                    Some(magnitude),
+                )?;
+                let sign = chip.load_private(
+                    layouter.namespace(|| "sign"),
+                    config.non_native.advices[0],
+                    Some(-pallas::Base::one()),
+                )?;
+                let base = FixedPoint::from_inner(chip.clone(), g);
+                let (result, _) =
+                    base.mul_short::<8>(layouter.namespace(|| "[-0xab] G"), (magnitude, sign))?;
+                check(
+                    &chip,
+                    layouter.namespace(|| "[-0xab] G"),
+                    &result,
+                    -(g * C::ScalarExt::from_u64(0xab)).to_affine(),
+                )?;
+            }
+
+            Ok(())
+        }
+    }
+
+    fn test_foreign_ecc_chip<C: ForeignCurve>() {
+        let circuit = MyCircuit::<C>(PhantomData);
+        let prover = MockProver::<pallas::Base>::run(17, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn foreign_ecc_chip() {
+        test_foreign_ecc_chip::<vesta::Affine>();
+    }
+
+    #[test]
+    fn foreign_ecc_chip_secp256k1() {
+        test_foreign_ecc_chip::<secp256k1::Affine>();
+    }
+}
@@ -0,0 +1,278 @@
+use std::{array, marker::PhantomData};
+
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
+use halo2::{
+    circuit::Region,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone, Debug)]
+pub struct Config<C: EccCurve> {
+    q_point_eq: Selector,
+    q_point_is_id: Selector,
+    // x-coordinate of A
+    pub x_a: Column<Advice>,
+    // y-coordinate of A
+    pub y_a: Column<Advice>,
+    // x-coordinate of B
+    pub x_b: Column<Advice>,
+    // y-coordinate of B
+    pub y_b: Column<Advice>,
+    // α = inv0(x_a - x_b)
+    alpha: Column<Advice>,
+    // β = inv0(y_a - y_b)
+    beta: Column<Advice>,
+    // Boolean output
+    out: Column<Advice>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_point_eq: ecc_config.q_point_eq,
+            q_point_is_id: ecc_config.q_point_is_id,
+            x_a: ecc_config.advices[0],
+            y_a: ecc_config.advices[1],
+            x_b: ecc_config.advices[2],
+            y_b: ecc_config.advices[3],
+            alpha: ecc_config.advices[4],
+            beta: ecc_config.advices[5],
+            out: ecc_config.advices[6],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // Given (dx, dy) and their witnessed inverses (α, β), constrains
+        // out = 1 if dx = dy = 0, and out = 0 otherwise.
+        //
+        // If dx ≠ 0, the first constraint forces α = 1/dx and so (1 - dx⋅α) = 0.
+        // If dx = 0, then (1 - dx⋅α) = 1 for any α. The same holds for dy.
+        let is_zero_checks = |dx: Expression<C::Base>,
+                              dy: Expression<C::Base>,
+                              alpha: Expression<C::Base>,
+                              beta: Expression<C::Base>,
+                              out: Expression<C::Base>| {
+            let one = Expression::Constant(C::Base::one());
+            let dx_is_zero = one.clone() - dx.clone() * alpha;
+            let dy_is_zero = one - dy.clone() * beta;
+
+            // dx⋅(1 - dx⋅α) = 0
+            let dx_check = dx * dx_is_zero.clone();
+            // dy⋅(1 - dy⋅β) = 0
+            let dy_check = dy * dy_is_zero.clone();
+            // out = (1 - dx⋅α)⋅(1 - dy⋅β)
+            let out_check = out - dx_is_zero * dy_is_zero;
+
+            array::IntoIter::new([dx_check, dy_check, out_check])
+        };
+
+        meta.create_gate("point equality", |meta| {
+            let q_point_eq = meta.query_selector(self.q_point_eq);
+            let x_a = meta.query_advice(self.x_a, Rotation::cur());
+            let y_a = meta.query_advice(self.y_a, Rotation::cur());
+            let x_b = meta.query_advice(self.x_b, Rotation::cur());
+            let y_b = meta.query_advice(self.y_b, Rotation::cur());
+            let alpha = meta.query_advice(self.alpha, Rotation::cur());
+            let beta = meta.query_advice(self.beta, Rotation::cur());
+            let out = meta.query_advice(self.out, Rotation::cur());
+
+            is_zero_checks(x_a - x_b, y_a - y_b, alpha, beta, out)
+                .map(move |poly| q_point_eq.clone() * poly)
+        });
+
+        // The identity is represented as (0, 0).
+        meta.create_gate("point is identity", |meta| {
+            let q_point_is_id = meta.query_selector(self.q_point_is_id);
+            let x_a = meta.query_advice(self.x_a, Rotation::cur());
+            let y_a = meta.query_advice(self.y_a, Rotation::cur());
+            let alpha = meta.query_advice(self.alpha, Rotation::cur());
+            let beta = meta.query_advice(self.beta, Rotation::cur());
+            let out = meta.query_advice(self.out, Rotation::cur());
+
+            is_zero_checks(x_a, y_a, alpha, beta, out).map(move |poly| q_point_is_id.clone() * poly)
+        });
+    }
+
+    pub(super) fn assign_is_equal(
+        &self,
+        a: &EccPoint<C>,
+        b: &EccPoint<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<CellValue<C::Base>, Error> {
+        // Enable `q_point_eq` selector
+        self.q_point_eq.enable(region, offset)?;
+
+        // Copy points `a` and `b`
+        copy(region, || "x_a", self.x_a, offset, &a.x)?;
+        copy(region, || "y_a", self.y_a, offset, &a.y)?;
+        copy(region, || "x_b", self.x_b, offset, &b.x)?;
+        copy(region, || "y_b", self.y_b, offset, &b.y)?;
+
+        let dx = a.x.value().zip(b.x.value()).map(|(x_a, x_b)| x_a - x_b);
+        let dy = a.y.value().zip(b.y.value()).map(|(y_a, y_b)| y_a - y_b);
+
+        self.assign_is_zero(dx, dy, offset, region)
+    }
+
+    pub(super) fn assign_is_identity(
+        &self,
+        a: &EccPoint<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<CellValue<C::Base>, Error> {
+        // Enable `q_point_is_id` selector
+        self.q_point_is_id.enable(region, offset)?;
+
+        // Copy point `a`
+        copy(region, || "x_a", self.x_a, offset, &a.x)?;
+        copy(region, || "y_a", self.y_a, offset, &a.y)?;
+
+        self.assign_is_zero(a.x.value(), a.y.value(), offset, region)
+    }
+
+    /// Assigns α = inv0(dx), β = inv0(dy) and the boolean output
+    /// out = (dx == 0 && dy == 0).
+    fn assign_is_zero(
+        &self,
+        dx: Option<C::Base>,
+        dy: Option<C::Base>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<CellValue<C::Base>, Error> {
+        // Assign α = inv0(dx)
+        let alpha = dx.map(|dx| dx.invert().unwrap_or(C::Base::zero()));
+        region.assign_advice(
+            || "α",
+            self.alpha,
+            offset,
+            || alpha.ok_or(Error::SynthesisError),
+        )?;
+
+        // Assign β = inv0(dy)
+        let beta = dy.map(|dy| dy.invert().unwrap_or(C::Base::zero()));
+        region.assign_advice(
+            || "β",
+            self.beta,
+            offset,
+            || beta.ok_or(Error::SynthesisError),
+        )?;
+
+        // Assign the boolean output
+        let out = dx.zip(dy).map(|(dx, dy)| {
+            if dx == C::Base::zero() && dy == C::Base::zero() {
+                C::Base::one()
+            } else {
+                C::Base::zero()
+            }
+        });
+        let out_cell = region.assign_advice(
+            || "out",
+            self.out,
+            offset,
+            || out.ok_or(Error::SynthesisError),
+        )?;
+
+        Ok(CellValue::new(out_cell, out))
+    }
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoints, Point},
+    };
+    use utilities::{CellValue, UtilitiesInstructions, Var};
+
+    pub fn test_is_equal<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate a random point P
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
+        let p = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+        let p_neg = Point::new(chip.clone(), layouter.namespace(|| "-P"), Some(-p_val))?;
+
+        // Generate a random point Q
+        let q_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // Q
+        let q = Point::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+
+        // Generate a (0,0) point to be used in other tests.
+        let zero = {
+            Point::new(
+                chip.clone(),
+                layouter.namespace(|| "identity"),
+                Some(pallas::Affine::identity()),
+            )?
+        };
+
+        // Constrains the output of a check to equal the expected boolean.
+        fn constrain_bool<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: &EccChip<pallas::Affine, F, K>,
+            mut layouter: impl Layouter<pallas::Base>,
+            actual: CellValue<pallas::Base>,
+            expected: bool,
+        ) -> Result<(), Error> {
+            let expected = pallas::Base::from_u64(expected as u64);
+            assert_eq!(actual.value(), Some(expected));
+
+            let expected = chip.load_private(
+                layouter.namespace(|| "expected"),
+                chip.config().advices[0],
+                Some(expected),
+            )?;
+            layouter.assign_region(
+                || "constrain output",
+                |mut region| region.constrain_equal(actual.cell(), expected.cell()),
+            )
+        }
+
+        // P == P
+        let result = p.is_equal(layouter.namespace(|| "P == P"), &p)?;
+        constrain_bool(&chip, layouter.namespace(|| "P == P"), result, true)?;
+
+        // P == Q
+        let result = p.is_equal(layouter.namespace(|| "P == Q"), &q)?;
+        constrain_bool(&chip, layouter.namespace(|| "P == Q"), result, false)?;
+
+        // P == -P (same x-coordinate)
+        let result = p.is_equal(layouter.namespace(|| "P == -P"), &p_neg)?;
+        constrain_bool(&chip, layouter.namespace(|| "P == -P"), result, false)?;
+
+        // P == 𝒪
+        let result = p.is_equal(layouter.namespace(|| "P == 𝒪"), &zero)?;
+        constrain_bool(&chip, layouter.namespace(|| "P == 𝒪"), result, false)?;
+
+        // 𝒪 == 𝒪
+        let result = zero.is_equal(layouter.namespace(|| "𝒪 == 𝒪"), &zero)?;
+        constrain_bool(&chip, layouter.namespace(|| "𝒪 == 𝒪"), result, true)?;
+
+        // P is not the identity
+        let result = p.is_identity(layouter.namespace(|| "P == 𝒪"))?;
+        constrain_bool(
+            &chip,
+            layouter.namespace(|| "is_identity(P)"),
+            result,
+            false,
+        )?;
+
+        // 𝒪 is the identity
+        let result = zero.is_identity(layouter.namespace(|| "𝒪 == 𝒪"))?;
+        constrain_bool(&chip, layouter.namespace(|| "is_identity(𝒪)"), result, true)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,740 @@
+//! Chip implementing the map-to-curve step of hashing to a curve: the simplified
+//! SWU map onto an isogenous curve, followed by the isogeny.
+//!
+//! See [`crate::primitive::hash_to_curve`] for the native map.
+
+use super::{EccChip, EccCurve, NonIdentityEccPoint, LOOKUP_K};
+use crate::gadget::{hash_to_curve::MapToCurveInstructions, FixedPoints};
+use utilities::{
+    bitrange_subset, bool_check, copy, lookup_range_check::LookupRangeCheckConfig, CellValue, Var,
+};
+
+use halo2::{
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+use pasta_curves::pallas;
+
+/// A curve $E$ that is the image of an isogeny from a curve $E'$ with $A' B' \neq 0$,
+/// on which the simplified SWU map is defined.
+///
+/// The isogeny has degree 3, and is given by
+/// $$(x, y) \mapsto \left(\frac{k_0 x^3 + k_1 x^2 + k_2 x + k_3}{x^2 + k_4 x + k_5},
+///   y \frac{k_6 x^3 + k_7 x^2 + k_8 x + k_9}{x^3 + k_{10} x^2 + k_{11} x + k_{12}}\right)$$
+/// where $k_i$ are the [`SwuCurve::ISOGENY_CONSTANTS`].
+pub trait SwuCurve: EccCurve {
+    /// $A'$ in the equation of the iso-curve $E' : y^2 = x^3 + A' x + B'$.
+    const ISO_A: Self::Base;
+
+    /// $B'$ in the equation of the iso-curve $E' : y^2 = x^3 + A' x + B'$.
+    const ISO_B: Self::Base;
+
+    /// The non-square $Z$ used in the simplified SWU map.
+    const Z: Self::Base;
+
+    /// The coefficients $k_0, \dots, k_{12}$ of the isogeny $E' \to E$.
+    const ISOGENY_CONSTANTS: [Self::Base; 13];
+}
+
+impl SwuCurve for pallas::Affine {
+    const ISO_A: pallas::Base = pallas::Base::from_raw([
+        0x92bb_4b0b_657a_014b,
+        0xb741_3458_1a27_a59f,
+        0x49be_2d72_5837_0742,
+        0x1835_4a2e_b0ea_8c9c,
+    ]);
+
+    const ISO_B: pallas::Base = pallas::Base::from_raw([1265, 0, 0, 0]);
+
+    // -13
+    const Z: pallas::Base = pallas::Base::from_raw([
+        0x992d_30ec_ffff_fff4,
+        0x2246_98fc_094c_f91b,
+        0x0000_0000_0000_0000,
+        0x4000_0000_0000_0000,
+    ]);
+
+    const ISOGENY_CONSTANTS: [pallas::Base; 13] = [
+        pallas::Base::from_raw([
+            0x775f_6034_aaaa_aaab,
+            0x4081_7754_73d8_375b,
+            0xe38e_38e3_8e38_e38e,
+            0x0e38_e38e_38e3_8e38,
+        ]),
+        pallas::Base::from_raw([
+            0x8cf8_63b0_2814_fb76,
+            0x0f93_b82e_e4b9_9495,
+            0x267c_7ffa_51cf_412a,
+            0x3509_afd5_1872_d88e,
+        ]),
+        pallas::Base::from_raw([
+            0x0eb6_4fae_f37e_a4f7,
+            0x380a_f066_cfeb_6d69,
+            0x98c7_d7ac_3d98_fd13,
+            0x1732_9b9e_c525_3753,
+        ]),
+        pallas::Base::from_raw([
+            0xeebe_c069_5555_5580,
+            0x8102_eea8_e7b0_6eb6,
+            0xc71c_71c7_1c71_c71c,
+            0x1c71_c71c_71c7_1c71,
+        ]),
+        pallas::Base::from_raw([
+            0xc47f_2ab6_68bc_d71f,
+            0x9c43_4ac1_c96b_6980,
+            0x5a60_7fcc_e049_4a79,
+            0x1d57_2e7d_dc09_9cff,
+        ]),
+        pallas::Base::from_raw([
+            0x2aa3_af1e_ae5b_6604,
+            0xb4ab_f9fb_9a1f_c81c,
+            0x1d13_bf2a_7f22_b105,
+            0x3256_69be_caec_d5d1,
+        ]),
+        pallas::Base::from_raw([
+            0x5ad9_85b5_e38e_38e4,
+            0x7642_b01a_d461_bad2,
+            0x4bda_12f6_84bd_a12f,
+            0x1a12_f684_bda1_2f68,
+        ]),
+        pallas::Base::from_raw([
+            0xc67c_31d8_140a_7dbb,
+            0x07c9_dc17_725c_ca4a,
+            0x133e_3ffd_28e7_a095,
+            0x1a84_d7ea_8c39_6c47,
+        ]),
+        pallas::Base::from_raw([
+            0x02e2_be87_d225_b234,
+            0x1765_e924_f745_9378,
+            0x3032_16cc_e1db_9ff1,
+            0x3fb9_8ff0_d2dd_cadd,
+        ]),
+        pallas::Base::from_raw([
+            0x93e5_3ab3_71c7_1c4f,
+            0x0ac0_3e8e_134e_b3e4,
+            0x7b42_5ed0_97b4_25ed,
+            0x025e_d097_b425_ed09,
+        ]),
+        pallas::Base::from_raw([
+            0x5a28_279b_1d1b_42ae,
+            0x5941_a3a4_a97a_a1b3,
+            0x0790_bfb3_506d_efb6,
+            0x0c02_c5bc_ca0e_6b7f,
+        ]),
+        pallas::Base::from_raw([
+            0x4d90_ab82_0b12_320a,
+            0xd976_bbfa_bbc5_661d,
+            0x573b_3d7f_7d68_1310,
+            0x1703_3d3c_60c6_8173,
+        ]),
+        pallas::Base::from_raw([
+            0x992d_30ec_ffff_fde5,
+            0x2246_98fc_094c_f91b,
+            0x0000_0000_0000_0000,
+            0x4000_0000_0000_0000,
+        ]),
+    ];
+}
+
+/// Configuration for the map-to-curve chip.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MapToCurveConfig<C: SwuCurve> {
+    /// Advice columns needed by the map.
+    pub advices: [Column<Advice>; 9],
+    /// Simplified SWU map and isogeny
+    pub q_map_to_curve: Selector,
+    /// Parity of a field element
+    pub q_sgn0: Selector,
+    /// Lookup range check using 10-bit lookup table
+    pub lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+}
+
+/// A chip implementing [`MapToCurveInstructions`] for [`EccChip`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MapToCurveChip<C: SwuCurve> {
+    config: MapToCurveConfig<C>,
+}
+
+impl<C: SwuCurve> Chip<C::Base> for MapToCurveChip<C> {
+    type Config = MapToCurveConfig<C>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<C: SwuCurve> MapToCurveChip<C> {
+    pub fn construct(config: <Self as Chip<C::Base>>::Config) -> Self {
+        Self { config }
+    }
+
+    /// The lookup table of `range_check` is shared with the ECC chip, and is not
+    /// loaded by this chip.
+    ///
+    /// # Side effects
+    ///
+    /// All columns in `advices` will be equality-enabled.
+    pub fn configure(
+        meta: &mut ConstraintSystem<C::Base>,
+        advices: [Column<Advice>; 9],
+        range_check: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    ) -> <Self as Chip<C::Base>>::Config {
+        for column in &advices {
+            meta.enable_equality((*column).into());
+        }
+
+        let config = MapToCurveConfig {
+            advices,
+            q_map_to_curve: meta.selector(),
+            q_sgn0: meta.selector(),
+            lookup_config: range_check,
+        };
+
+        config.create_gate(meta);
+
+        config
+    }
+}
+
+/// The values witnessed when mapping `u` to the curve.
+#[derive(Clone, Copy, Debug)]
+struct Witness<F> {
+    tv: F,
+    den: F,
+    den_inv: F,
+    x1: F,
+    x2: F,
+    // 1 if g(x1) is a square, and 0 otherwise
+    e: F,
+    // (x, y) on the iso-curve
+    x: F,
+    y: F,
+    // A square root of Z g(x1) if g(x1) is not a square, and 0 otherwise
+    w: F,
+    // The inverse of g(x1) if g(x1) is not a square, and 0 otherwise
+    g_x1_inv: F,
+    // (x_out, y_out) on the curve
+    x_out: F,
+    y_out: F,
+}
+
+/// Computes the values witnessed when mapping `u` to the curve.
+fn witness<C: SwuCurve>(u: C::Base) -> Witness<C::Base> {
+    let (a, b, z) = (C::ISO_A, C::ISO_B, C::Z);
+    let g = |x: C::Base| x.square() * x + a * x + b;
+
+    let tv = z * u.square();
+    let den = tv.square() + tv;
+    let den_inv = den.invert().unwrap_or(C::Base::zero());
+    let x1 = if den == C::Base::zero() {
+        b * (z * a).invert().unwrap()
+    } else {
+        -b * a.invert().unwrap() * (C::Base::one() + den_inv)
+    };
+    let x2 = tv * x1;
+
+    let (e, x, y, w, g_x1_inv) = match Option::<C::Base>::from(g(x1).sqrt()) {
+        Some(y1) => (C::Base::one(), x1, y1, C::Base::zero(), C::Base::zero()),
+        None => (
+            C::Base::zero(),
+            x2,
+            g(x2).sqrt().unwrap(),
+            (z * g(x1)).sqrt().unwrap(),
+            g(x1).invert().unwrap(),
+        ),
+    };
+    // sgn0(y) = sgn0(u)
+    let y = if bitrange_subset(u, 0..1) == bitrange_subset(y, 0..1) {
+        y
+    } else {
+        -y
+    };
+
+    // If (x, y) is in the kernel of the isogeny, the denominators are zero, and
+    // the constraints cannot be satisfied.
+    let k = C::ISOGENY_CONSTANTS;
+    let x_num = ((k[0] * x + k[1]) * x + k[2]) * x + k[3];
+    let x_den = (x + k[4]) * x + k[5];
+    let y_num = (((k[6] * x + k[7]) * x + k[8]) * x + k[9]) * y;
+    let y_den = ((x + k[10]) * x + k[11]) * x + k[12];
+    let x_out = x_num * x_den.invert().unwrap_or(C::Base::zero());
+    let y_out = y_num * y_den.invert().unwrap_or(C::Base::zero());
+
+    Witness {
+        tv,
+        den,
+        den_inv,
+        x1,
+        x2,
+        e,
+        x,
+        y,
+        w,
+        g_x1_inv,
+        x_out,
+        y_out,
+    }
+}
+
+impl<C: SwuCurve> MapToCurveConfig<C> {
+    fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // The simplified SWU map onto the iso-curve E', as specified in
+        // <https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-method>,
+        // followed by the isogeny E' -> E. The parity check sgn0(u) = sgn0(y) is
+        // done separately.
+        meta.create_gate("Map to curve", |meta| {
+            let q_map_to_curve = meta.query_selector(self.q_map_to_curve);
+
+            let u = meta.query_advice(self.advices[0], Rotation::cur());
+            let tv = meta.query_advice(self.advices[1], Rotation::cur());
+            let den = meta.query_advice(self.advices[2], Rotation::cur());
+            let den_inv = meta.query_advice(self.advices[3], Rotation::cur());
+            let x1 = meta.query_advice(self.advices[4], Rotation::cur());
+            let x2 = meta.query_advice(self.advices[5], Rotation::cur());
+            let e = meta.query_advice(self.advices[6], Rotation::cur());
+            let x = meta.query_advice(self.advices[7], Rotation::cur());
+            let y = meta.query_advice(self.advices[8], Rotation::cur());
+            let w = meta.query_advice(self.advices[0], Rotation::next());
+            let x_out = meta.query_advice(self.advices[1], Rotation::next());
+            let y_out = meta.query_advice(self.advices[2], Rotation::next());
+            let g_x1_inv = meta.query_advice(self.advices[3], Rotation::next());
+
+            let one = Expression::Constant(C::Base::one());
+            let a = Expression::Constant(C::ISO_A);
+            let b = Expression::Constant(C::ISO_B);
+            let z = Expression::Constant(C::Z);
+
+            // g(x) = x^3 + A' x + B'
+            let g =
+                |x: Expression<C::Base>| x.clone().square() * x.clone() + a.clone() * x + b.clone();
+
+            // tv = Z u^2
+            let tv_check = tv.clone() - z.clone() * u.square();
+
+            // den = tv^2 + tv
+            let den_check = den.clone() - (tv.clone().square() + tv.clone());
+
+            // den_is_zero is 1 if den = 0, and 0 otherwise.
+            let den_is_zero = one.clone() - den.clone() * den_inv;
+            let den_is_zero_check = den.clone() * den_is_zero.clone();
+
+            // If den ≠ 0, x1 = (-B' / A')(1 + 1 / den), i.e. A' x1 den + B' (den + 1) = 0.
+            let x1_check = (one.clone() - den_is_zero.clone())
+                * (a.clone() * x1.clone() * den.clone() + b.clone() * (den + one.clone()));
+
+            // If den = 0, x1 = B' / (Z A'). This only occurs for u = 0.
+            let x1_exceptional_check =
+                den_is_zero * (z.clone() * a.clone() * x1.clone() - b.clone());
+
+            // x2 = tv x1
+            let x2_check = x2.clone() - tv * x1.clone();
+
+            // x = x1 if e = 1, and x2 otherwise.
+            let x_check = x.clone() - (x2.clone() + e.clone() * (x1.clone() - x2.clone()));
+
+            // y^2 = g(x1) if e = 1, and g(x2) otherwise.
+            let y_check =
+                y.clone().square() - (g(x2.clone()) + e.clone() * (g(x1.clone()) - g(x2)));
+
+            // If e = 0, then Z g(x1) = w^2 is a square, and g(x1) has an inverse. Since
+            // Z is not a square, g(x1) is then a non-zero non-square, so e = 0 only if
+            // x1 is not on the iso-curve. Without the inverse, g(x1) = w = 0 would
+            // satisfy the first constraint.
+            let non_square_check = (one.clone() - e.clone()) * (w.square() - z * g(x1.clone()));
+            let non_zero_check = (one.clone() - e.clone()) * (g(x1) * g_x1_inv - one);
+
+            // (x_out, y_out) is the image of (x, y) under the isogeny.
+            let k = |i: usize| Expression::Constant(C::ISOGENY_CONSTANTS[i]);
+            let x_num = ((k(0) * x.clone() + k(1)) * x.clone() + k(2)) * x.clone() + k(3);
+            let x_den = (x.clone() + k(4)) * x.clone() + k(5);
+            let y_num =
+                (((k(6) * x.clone() + k(7)) * x.clone() + k(8)) * x.clone() + k(9)) * y.clone();
+            let y_den = ((x.clone() + k(10)) * x.clone() + k(11)) * x + k(12);
+            let x_out_check = x_out * x_den - x_num;
+            let y_out_check = y_out * y_den - y_num;
+
+            std::iter::empty()
+                .chain(Some(("tv_check", tv_check)))
+                .chain(Some(("den_check", den_check)))
+                .chain(Some(("den_is_zero_check", den_is_zero_check)))
+                .chain(Some(("x1_check", x1_check)))
+                .chain(Some(("x1_exceptional_check", x1_exceptional_check)))
+                .chain(Some(("x2_check", x2_check)))
+                .chain(Some(("e_check", bool_check(e))))
+                .chain(Some(("x_check", x_check)))
+                .chain(Some(("y_check", y_check)))
+                .chain(Some(("non_square_check", non_square_check)))
+                .chain(Some(("non_zero_check", non_zero_check)))
+                .chain(Some(("x_out_check", x_out_check)))
+                .chain(Some(("y_out_check", y_out_check)))
+                .map(move |(name, poly)| (name, q_map_to_curve.clone() * poly))
+        });
+
+        // Decompose the canonical encoding of a field element v into its parity b
+        // and h = (v - b) / 2, as v = b + 2 (h_lo + 2^253 h_hi).
+        //
+        // We need h ≤ (p - 1 - b) / 2 for the decomposition to be canonical, where
+        // p = 2^254 + t_p. Note that t_p < 2^130.
+        //
+        // h_lo has been range-constrained to 253 bits. If h_hi = 1, then we
+        // additionally need h_lo + b < (t_p + 1) / 2:
+        //   - h_hi = 1 => 0 ≤ h_lo + b + 2^130 - (t_p + 1) / 2 < 2^130
+        //              => 13 ten-bit lookups of h_lo' = h_lo + b + 2^130 - (t_p + 1) / 2
+        //              => z_13_h_lo_prime = 0
+        meta.create_gate("sgn0", |meta| {
+            let q_sgn0 = meta.query_selector(self.q_sgn0);
+
+            let value = meta.query_advice(self.advices[0], Rotation::cur());
+            let b = meta.query_advice(self.advices[1], Rotation::cur());
+            let h_hi = meta.query_advice(self.advices[2], Rotation::cur());
+            let h_lo = meta.query_advice(self.advices[3], Rotation::cur());
+            let h_lo_prime = meta.query_advice(self.advices[4], Rotation::cur());
+            let z_13_h_lo_prime = meta.query_advice(self.advices[5], Rotation::cur());
+
+            let two_pow_130 = C::Base::from_u128(1 << 65).square();
+            let two_pow_253 = C::Base::from_u128(1 << 126).square().double();
+            let t_p_plus_one_halved = C::Base::from_u128((C::T_P + 1) / 2);
+
+            // v = b + 2 (h_lo + 2^253 h_hi)
+            let decomposition_check = value
+                - (b.clone() + (h_lo.clone() + h_hi.clone() * two_pow_253) * C::Base::from_u64(2));
+
+            // h_lo' = h_lo + b + 2^130 - (t_p + 1) / 2
+            let h_lo_prime_check = h_lo_prime
+                - (h_lo + b.clone() + Expression::Constant(two_pow_130 - t_p_plus_one_halved));
+
+            std::iter::empty()
+                .chain(Some(("b_check", bool_check(b))))
+                .chain(Some(("h_hi_check", bool_check(h_hi.clone()))))
+                .chain(Some(("decomposition_check", decomposition_check)))
+                .chain(Some(("h_lo_prime_check", h_lo_prime_check)))
+                .chain(Some((
+                    "h_hi = 1 => z_13_h_lo_prime = 0",
+                    h_hi * z_13_h_lo_prime,
+                )))
+                .map(move |(name, poly)| (name, q_sgn0.clone() * poly))
+        });
+    }
+
+    /// Maps `u` to the curve, returning $(x, y)$ on the iso-curve and the point
+    /// on the curve.
+    fn assign_region(
+        &self,
+        u: &CellValue<C::Base>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<(CellValue<C::Base>, NonIdentityEccPoint<C>), Error> {
+        // Enable `q_map_to_curve` selector
+        self.q_map_to_curve.enable(region, offset)?;
+
+        // Copy u
+        copy(region, || "u", self.advices[0], offset, u)?;
+
+        let witness = u.value().map(witness::<C>);
+        let mut assign = |annotation: &'static str,
+                          column: usize,
+                          offset: usize,
+                          value: Option<C::Base>|
+         -> Result<CellValue<C::Base>, Error> {
+            let cell = region.assign_advice(
+                || annotation,
+                self.advices[column],
+                offset,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            Ok(CellValue::new(cell, value))
+        };
+
+        assign("tv", 1, offset, witness.map(|w| w.tv))?;
+        assign("den", 2, offset, witness.map(|w| w.den))?;
+        assign("den_inv", 3, offset, witness.map(|w| w.den_inv))?;
+        assign("x1", 4, offset, witness.map(|w| w.x1))?;
+        assign("x2", 5, offset, witness.map(|w| w.x2))?;
+        assign("e", 6, offset, witness.map(|w| w.e))?;
+        assign("x", 7, offset, witness.map(|w| w.x))?;
+        let y = assign("y", 8, offset, witness.map(|w| w.y))?;
+        assign("w", 0, offset + 1, witness.map(|w| w.w))?;
+        let x_out = assign("x_out", 1, offset + 1, witness.map(|w| w.x_out))?;
+        let y_out = assign("y_out", 2, offset + 1, witness.map(|w| w.y_out))?;
+        assign("g_x1_inv", 3, offset + 1, witness.map(|w| w.g_x1_inv))?;
+
+        Ok((y, NonIdentityEccPoint { x: x_out, y: y_out }))
+    }
+
+    /// Returns a cell containing $\mathsf{sgn0}(v)$, the parity of the canonical
+    /// encoding of `value`.
+    fn sgn0(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        value: CellValue<C::Base>,
+    ) -> Result<CellValue<C::Base>, Error> {
+        let b = value.value().map(|v| bitrange_subset(v, 0..1));
+        let h_lo = value.value().map(|v| bitrange_subset(v, 1..254));
+        let h_hi = value.value().map(|v| bitrange_subset(v, 254..255));
+        let h_lo_prime = h_lo.zip(b).map(|(h_lo, b)| {
+            let two_pow_130 = C::Base::from_u128(1 << 65).square();
+            let t_p_plus_one_halved = C::Base::from_u128((C::T_P + 1) / 2);
+            h_lo + b + two_pow_130 - t_p_plus_one_halved
+        });
+
+        // Range-constrain h_lo to 253 bits, as 25 ten-bit words followed by a 3-bit
+        // word.
+        let h_lo = self.lookup_config.witness_check(
+            layouter.namespace(|| "Decompose h_lo"),
+            h_lo,
+            25,
+            false,
+        )?;
+        self.lookup_config.copy_short_check(
+            layouter.namespace(|| "h_lo[250..253]"),
+            h_lo[25],
+            3,
+        )?;
+
+        let h_lo_prime = self.lookup_config.witness_check(
+            layouter.namespace(|| "Decompose h_lo_prime"),
+            h_lo_prime,
+            13,
+            false,
+        )?;
+
+        layouter.assign_region(
+            || "sgn0",
+            |mut region| {
+                let offset = 0;
+
+                // Enable `q_sgn0` selector
+                self.q_sgn0.enable(&mut region, offset)?;
+
+                copy(&mut region, || "value", self.advices[0], offset, &value)?;
+
+                let b = {
+                    let cell = region.assign_advice(
+                        || "b",
+                        self.advices[1],
+                        offset,
+                        || b.ok_or(Error::SynthesisError),
+                    )?;
+                    CellValue::new(cell, b)
+                };
+                region.assign_advice(
+                    || "h_hi",
+                    self.advices[2],
+                    offset,
+                    || h_hi.ok_or(Error::SynthesisError),
+                )?;
+
+                copy(&mut region, || "h_lo", self.advices[3], offset, &h_lo[0])?;
+                copy(
+                    &mut region,
+                    || "h_lo_prime",
+                    self.advices[4],
+                    offset,
+                    &h_lo_prime[0],
+                )?;
+                copy(
+                    &mut region,
+                    || "z_13_h_lo_prime",
+                    self.advices[5],
+                    offset,
+                    &h_lo_prime[13],
+                )?;
+
+                Ok(b)
+            },
+        )
+    }
+}
+
+impl<C: SwuCurve, Fixed: FixedPoints<C, K>, const K: usize>
+    MapToCurveInstructions<C, EccChip<C, Fixed, K>> for MapToCurveChip<C>
+{
+    fn map_to_curve(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        u: &CellValue<C::Base>,
+    ) -> Result<NonIdentityEccPoint<C>, Error> {
+        let config = self.config();
+
+        let (y, point) = layouter.assign_region(
+            || "map to curve",
+            |mut region| config.assign_region(u, 0, &mut region),
+        )?;
+
+        // Constrain sgn0(u) = sgn0(y), which fixes the sign of y.
+        let sgn0_u = config.sgn0(layouter.namespace(|| "sgn0(u)"), *u)?;
+        let sgn0_y = config.sgn0(layouter.namespace(|| "sgn0(y)"), y)?;
+        layouter.assign_region(
+            || "sgn0(u) = sgn0(y)",
+            |mut region| region.constrain_equal(sgn0_u.cell(), sgn0_y.cell()),
+        )?;
+
+        Ok(point)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use group::Curve;
+    use halo2::{
+        circuit::{Chip, Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+    use poseidon::{
+        pow5t3::{Pow5T3Chip, Pow5T3Config},
+        primitive::P128Pow5T3,
+    };
+
+    use super::{MapToCurveChip, MapToCurveConfig};
+    use crate::{
+        chip::{
+            test_circuit::{configure_ecc_chip, NoFixedBases},
+            EccChip, EccConfig, PoseidonHashToField, VarBaseMul,
+        },
+        gadget::{
+            hash_to_curve::{map_to_curve, HashToCurve},
+            NonIdentityPoint, Point, FIXED_BASE_WINDOW_SIZE,
+        },
+        primitive::hash_to_curve as primitive,
+    };
+    use utilities::UtilitiesInstructions;
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    type MyEccChip = EccChip<pallas::Affine, NoFixedBases, K>;
+
+    struct MyCircuit;
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = (
+            EccConfig<pallas::Affine, K>,
+            MapToCurveConfig<pallas::Affine>,
+            Pow5T3Config<pallas::Base>,
+        );
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            MyCircuit
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let ecc_config = configure_ecc_chip::<NoFixedBases, K>(meta, VarBaseMul::DoubleAndAdd);
+            let advices = ecc_config.advices;
+            let map_to_curve_config = MapToCurveChip::configure(
+                meta,
+                [
+                    advices[0], advices[1], advices[2], advices[3], advices[4], advices[5],
+                    advices[6], advices[7], advices[8],
+                ],
+                ecc_config.lookup_config.clone(),
+            );
+
+            let rc_a = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+            let rc_b = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+            let poseidon_config = Pow5T3Chip::configure(
+                meta,
+                P128Pow5T3,
+                [advices[6], advices[7], advices[8]],
+                advices[5],
+                rc_a,
+                rc_b,
+            );
+
+            (ecc_config, map_to_curve_config, poseidon_config)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let ecc_chip = MyEccChip::construct(config.0.clone());
+            let map_to_curve_chip = MapToCurveChip::construct(config.1);
+
+            // Load 10-bit lookup table.
+            config.0.lookup_config.load(&mut layouter)?;
+
+            let column = config.0.advices[0];
+
+            // Map to curve, including the exceptional case u = 0, and opposite u
+            // which map to opposite points.
+            for (i, u) in [
+                pallas::Base::zero(),
+                pallas::Base::one(),
+                -pallas::Base::one(),
+                pallas::Base::rand(),
+                pallas::Base::rand(),
+            ]
+            .iter()
+            .enumerate()
+            {
+                let mut layouter = layouter.namespace(|| format!("u_{}", i));
+                let u_cell = ecc_chip.load_private(layouter.namespace(|| "u"), column, Some(*u))?;
+                let point = map_to_curve::<pallas::Affine, _, _>(
+                    ecc_chip.clone(),
+                    &map_to_curve_chip,
+                    layouter.namespace(|| "map to curve"),
+                    &u_cell,
+                )?;
+                let expected = NonIdentityPoint::new(
+                    ecc_chip.clone(),
+                    layouter.namespace(|| "expected"),
+                    Some(primitive::map_to_curve::<pallas::Affine>(*u)),
+                )?;
+                point.constrain_equal(layouter.namespace(|| "constrain equal"), &expected)?;
+            }
+
+            // Hash to curve, with Poseidon as the hash to the field
+            {
+                let hasher = HashToCurve::<pallas::Affine, _, _, _>::new(
+                    ecc_chip.clone(),
+                    map_to_curve_chip,
+                    PoseidonHashToField::construct(config.2),
+                );
+                let message = pallas::Base::rand();
+                let message_cell = ecc_chip.load_private(
+                    layouter.namespace(|| "message"),
+                    column,
+                    Some(message),
+                )?;
+                let point = hasher.hash(layouter.namespace(|| "hash to curve"), message_cell)?;
+                let expected = Point::new(
+                    ecc_chip,
+                    layouter.namespace(|| "expected"),
+                    Some(
+                        primitive::hash_to_curve::<pallas::Affine, _>(
+                            primitive::poseidon_hash_to_field,
+                            message,
+                        )
+                        .to_affine(),
+                    ),
+                )?;
+                point.constrain_equal(layouter.namespace(|| "constrain equal"), &expected)?;
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn map_to_curve_chip() {
+        let prover = MockProver::<pallas::Base>::run(11, &MyCircuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+}
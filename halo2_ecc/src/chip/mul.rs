@@ -1,9 +1,9 @@
-use super::{add, CellValue, EccConfig, EccPoint, Var, T_Q};
+use super::{add, CellValue, EccConfig, EccCurve, EccPoint, EccScalarVarFullWidth, Var, L_SCALAR};
 use std::ops::{Deref, Range};
 use utilities::copy;
 
 use bigint::U256;
-use ff::PrimeField;
+use ff::PrimeFieldBits;
 use halo2::{
     arithmetic::FieldExt,
     circuit::{Layouter, Region},
@@ -11,18 +11,23 @@ use halo2::{
     poly::Rotation,
 };
 
-use pasta_curves::pallas;
-
 mod complete;
+pub(crate) mod endo;
+mod full_width;
 mod incomplete;
+pub(crate) mod msm;
 mod overflow;
+pub(crate) mod windowed;
+
+#[cfg(test)]
+mod row_count;
 
 /// Number of bits for which complete addition needs to be used in variable-base
 /// scalar multiplication
 const NUM_COMPLETE_BITS: usize = 3;
 
 // Bits used in incomplete addition. k_{254} to k_{4} inclusive
-const INCOMPLETE_LEN: usize = pallas::Scalar::NUM_BITS as usize - 1 - NUM_COMPLETE_BITS;
+const INCOMPLETE_LEN: usize = L_SCALAR - 1 - NUM_COMPLETE_BITS;
 
 // Bits k_{254} to k_{4} inclusive are used in incomplete addition.
 // The `hi` half is k_{254} to k_{130} inclusive (length 125 bits).
@@ -38,23 +43,34 @@ const INCOMPLETE_LO_RANGE: Range<usize> = (INCOMPLETE_LEN / 2)..INCOMPLETE_LEN;
 // Bit k_{0} is handled separately.
 const COMPLETE_RANGE: Range<usize> = INCOMPLETE_LEN..(INCOMPLETE_LEN + NUM_COMPLETE_BITS);
 
-pub struct Config {
+/// Variable-base scalar multiplication, using a double-and-add over the bits of the
+/// scalar in which the `hi` and `lo` halves are assigned side by side, so that two
+/// bits are consumed per row.
+///
+/// A chip configured with [`VarBaseMul::Windowed`] instead uses [`windowed::Config`]
+/// for multiplication by a base field element, which consumes four bits per window
+/// using a per-call table of odd multiples of the base.
+///
+/// [`VarBaseMul::Windowed`]: super::VarBaseMul::Windowed
+pub struct Config<C: EccCurve> {
     // Selector used to check switching logic on LSB
     q_mul_lsb: Selector,
     // Configuration used in complete addition
-    add_config: add::Config,
+    add_config: add::Config<C>,
     // Configuration used for `hi` bits of the scalar
-    hi_config: incomplete::HiConfig,
+    hi_config: incomplete::HiConfig<C>,
     // Configuration used for `lo` bits of the scalar
-    lo_config: incomplete::LoConfig,
+    lo_config: incomplete::LoConfig<C>,
     // Configuration used for complete addition part of double-and-add algorithm
-    complete_config: complete::Config,
+    complete_config: complete::Config<C>,
     // Configuration used to check for overflow
-    overflow_config: overflow::Config,
+    overflow_config: overflow::Config<C>,
+    // Configuration used to check canonicity of a full-width scalar
+    full_width_config: full_width::Config<C>,
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         let config = Self {
             q_mul_lsb: ecc_config.q_mul_lsb,
             add_config: ecc_config.into(),
@@ -62,6 +78,7 @@ impl From<&EccConfig> for Config {
             lo_config: ecc_config.into(),
             complete_config: ecc_config.into(),
             overflow_config: ecc_config.into(),
+            full_width_config: ecc_config.into(),
         };
 
         assert_eq!(
@@ -92,8 +109,8 @@ impl From<&EccConfig> for Config {
     }
 }
 
-impl Config {
-    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         // If `lsb` is 0, (x, y) = (x_p, -y_p). If `lsb` is 1, (x, y) = (0,0).
         meta.create_gate("LSB check", |meta| {
             let q_mul_lsb = meta.query_selector(self.q_mul_lsb);
@@ -107,8 +124,8 @@ impl Config {
 
             //    z_0 = 2 * z_1 + k_0
             // => k_0 = z_0 - 2 * z_1
-            let lsb = z_0 - z_1 * pallas::Base::from_u64(2);
-            let one_minus_lsb = Expression::Constant(pallas::Base::one()) - lsb.clone();
+            let lsb = z_0 - z_1 * C::Base::from_u64(2);
+            let one_minus_lsb = Expression::Constant(C::Base::one()) - lsb.clone();
 
             let bool_check = lsb.clone() * one_minus_lsb.clone();
 
@@ -125,25 +142,106 @@ impl Config {
         self.lo_config.create_gate(meta);
         self.complete_config.create_gate(meta);
         self.overflow_config.create_gate(meta);
+        self.full_width_config.create_gate(meta);
     }
 
     pub(super) fn assign(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        alpha: CellValue<pallas::Base>,
-        base: &EccPoint,
-    ) -> Result<(EccPoint, CellValue<pallas::Base>), Error> {
-        let (result, zs): (EccPoint, Vec<Z<pallas::Base>>) = layouter.assign_region(
+        mut layouter: impl Layouter<C::Base>,
+        alpha: CellValue<C::Base>,
+        base: &EccPoint<C>,
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>), Error> {
+        // Decompose `k = alpha + t_q` bitwise (big-endian bit order).
+        let bits = decompose_for_scalar_mul::<C>(alpha.value());
+
+        let (result, zs) = self.double_and_add(&mut layouter, &bits, base)?;
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::Curve;
+
+            let base = base.point();
+            let alpha = alpha.value().map(super::base_to_scalar::<C>);
+            let real_mul = base.zip(alpha).map(|(base, alpha)| base * alpha);
+            let result = result.point();
+
+            if let (Some(real_mul), Some(result)) = (real_mul, result) {
+                assert_eq!(real_mul.to_affine(), result);
+            }
+        }
+
+        self.overflow_config.overflow_check(
+            layouter.namespace(|| "overflow check"),
+            alpha,
+            *zs[0],
+            *zs[130],
+            *zs[254],
+        )?;
+
+        Ok((result, alpha))
+    }
+
+    pub(super) fn assign_full_width(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        scalar: Option<C::Scalar>,
+        base: &EccPoint<C>,
+    ) -> Result<(EccPoint<C>, EccScalarVarFullWidth<C>), Error> {
+        // Decompose `k = scalar + t_q (mod q)` bitwise (big-endian bit order).
+        let bits = decompose_full_width_for_scalar_mul::<C>(scalar);
+
+        let (result, zs) = self.double_and_add(&mut layouter, &bits, base)?;
+
+        let scalar = EccScalarVarFullWidth {
+            value: scalar,
+            running_sum: zs.iter().map(|z| **z).collect(),
+        };
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::Curve;
+
+            let real_mul = base
+                .point()
+                .zip(scalar.value)
+                .map(|(base, scalar)| base * scalar);
+            let result = result.point();
+
+            if let (Some(real_mul), Some(result)) = (real_mul, result) {
+                assert_eq!(real_mul.to_affine(), result);
+            }
+        }
+
+        self.full_width_config
+            .canonicity_check(layouter.namespace(|| "canonicity check"), &scalar)?;
+
+        Ok((result, scalar))
+    }
+
+    /// Computes `[k + 2^254] base` using double-and-add, where `k` is given by
+    /// its 255-bit decomposition in big-endian bit order.
+    ///
+    /// Returns the result, along with the running sum
+    /// `[z_0, z_1, ..., z_{254}, z_{255}]` used to decompose `k`.
+    fn double_and_add(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        bits: &[Option<bool>],
+        base: &EccPoint<C>,
+    ) -> Result<(EccPoint<C>, Vec<Z<C::Base>>), Error> {
+        assert_eq!(bits.len(), L_SCALAR);
+
+        layouter.assign_region(
             || "variable-base scalar mul",
             |mut region| {
                 let offset = 0;
-                // Decompose `k = alpha + t_q` bitwise (big-endian bit order).
-                let bits = decompose_for_scalar_mul(alpha.value());
 
                 // Define ranges for each part of the algorithm.
                 let bits_incomplete_hi = &bits[INCOMPLETE_HI_RANGE];
                 let bits_incomplete_lo = &bits[INCOMPLETE_LO_RANGE];
-                let lsb = bits[pallas::Scalar::NUM_BITS as usize - 1];
+                let lsb = bits[L_SCALAR - 1];
 
                 // Initialize the accumulator `acc = [2]base`
                 let acc = self
@@ -159,10 +257,10 @@ impl Config {
                         || "z_init = 0",
                         self.hi_config.z,
                         offset,
-                        pallas::Base::zero(),
+                        C::Base::zero(),
                     )?;
 
-                    Z(CellValue::new(z_init_cell, Some(pallas::Base::zero())))
+                    Z(CellValue::new(z_init_cell, Some(C::Base::zero())))
                 };
 
                 // Double-and-add (incomplete addition) for the `hi` half of the scalar decomposition
@@ -216,23 +314,6 @@ impl Config {
                 let z_1 = zs_complete.last().unwrap();
                 let (result, z_0) = self.process_lsb(&mut region, offset, base, acc, *z_1, lsb)?;
 
-                #[cfg(test)]
-                // Check that the correct multiple is obtained.
-                {
-                    use group::Curve;
-
-                    let base = base.point();
-                    let alpha = alpha
-                        .value()
-                        .map(|alpha| pallas::Scalar::from_bytes(&alpha.to_bytes()).unwrap());
-                    let real_mul = base.zip(alpha).map(|(base, alpha)| base * alpha);
-                    let result = result.point();
-
-                    if let (Some(real_mul), Some(result)) = (real_mul, result) {
-                        assert_eq!(real_mul.to_affine(), result);
-                    }
-                }
-
                 let zs = {
                     let mut zs = std::iter::empty()
                         .chain(Some(z_init))
@@ -241,7 +322,7 @@ impl Config {
                         .chain(zs_complete.into_iter())
                         .chain(Some(z_0))
                         .collect::<Vec<_>>();
-                    assert_eq!(zs.len(), pallas::Scalar::NUM_BITS as usize + 1);
+                    assert_eq!(zs.len(), L_SCALAR + 1);
 
                     // This reverses zs to give us [z_0, z_1, ..., z_{254}, z_{255}].
                     zs.reverse();
@@ -250,12 +331,7 @@ impl Config {
 
                 Ok((result, zs))
             },
-        )?;
-
-        self.overflow_config
-            .overflow_check(layouter.namespace(|| "overflow check"), alpha, &zs)?;
-
-        Ok((result, alpha))
+        )
     }
 
     /// Processes the final scalar bit `k_0`.
@@ -275,13 +351,13 @@ impl Config {
     /// ```
     fn process_lsb(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        base: &EccPoint,
-        acc: EccPoint,
-        z_1: Z<pallas::Base>,
+        base: &EccPoint<C>,
+        acc: EccPoint<C>,
+        z_1: Z<C::Base>,
         lsb: Option<bool>,
-    ) -> Result<(EccPoint, Z<pallas::Base>), Error> {
+    ) -> Result<(EccPoint<C>, Z<C::Base>), Error> {
         // Enforce switching logic on LSB using a custom gate
         self.q_mul_lsb.enable(region, offset + 1)?;
 
@@ -289,8 +365,8 @@ impl Config {
         // Assign z_0 = 2⋅z_1 + k_0
         let z_0 = {
             let z_0_val = z_1.value().zip(lsb).map(|(z_1, lsb)| {
-                let lsb = pallas::Base::from_u64(lsb as u64);
-                z_1 * pallas::Base::from_u64(2) + lsb
+                let lsb = C::Base::from_u64(lsb as u64);
+                z_1 * C::Base::from_u64(2) + lsb
             });
             let z_0_cell = region.assign_advice(
                 || "z_0",
@@ -323,7 +399,7 @@ impl Config {
             if !lsb {
                 base.x.value()
             } else {
-                Some(pallas::Base::zero())
+                Some(C::Base::zero())
             }
         } else {
             None
@@ -333,7 +409,7 @@ impl Config {
             if !lsb {
                 base.y.value().map(|y_p| -y_p)
             } else {
-                Some(pallas::Base::zero())
+                Some(C::Base::zero())
             }
         } else {
             None
@@ -354,8 +430,8 @@ impl Config {
         )?;
 
         let p = EccPoint {
-            x: CellValue::<pallas::Base>::new(x_cell, x),
-            y: CellValue::<pallas::Base>::new(y_cell, y),
+            x: CellValue::<C::Base>::new(x_cell, x),
+            y: CellValue::<C::Base>::new(y_cell, y),
         };
 
         // Return the result of the final complete addition as `[scalar]B`
@@ -398,14 +474,14 @@ impl<F: FieldExt> Deref for Z<F> {
     }
 }
 
-fn decompose_for_scalar_mul(scalar: Option<pallas::Base>) -> Vec<Option<bool>> {
+fn decompose_for_scalar_mul<C: EccCurve>(scalar: Option<C::Base>) -> Vec<Option<bool>> {
     let bitstring = scalar.map(|scalar| {
         // We use `k = scalar + t_q` in the double-and-add algorithm, where
         // the scalar field `F_q = 2^254 + t_q`.
         // Note that the addition `scalar + t_q` is not reduced.
         //
         let scalar = U256::from_little_endian(&scalar.to_bytes());
-        let t_q = U256::from_little_endian(&T_Q.to_le_bytes());
+        let t_q = U256::from_little_endian(&C::T_Q.to_le_bytes());
         let k = scalar + t_q;
 
         // Big-endian bit representation of `k`.
@@ -422,7 +498,29 @@ fn decompose_for_scalar_mul(scalar: Option<pallas::Base>) -> Vec<Option<bool>> {
         };
 
         // Take the first 255 bits.
-        let mut bitstring = bitstring[0..pallas::Scalar::NUM_BITS as usize].to_vec();
+        let mut bitstring = bitstring[0..L_SCALAR].to_vec();
+        bitstring.reverse();
+        bitstring
+    });
+
+    if let Some(bitstring) = bitstring {
+        bitstring.into_iter().map(Some).collect()
+    } else {
+        vec![None; L_SCALAR]
+    }
+}
+
+fn decompose_full_width_for_scalar_mul<C: EccCurve>(
+    scalar: Option<C::Scalar>,
+) -> Vec<Option<bool>> {
+    let bitstring = scalar.map(|scalar| {
+        // We use `k = scalar + t_q (mod q)` in the double-and-add algorithm, where
+        // the scalar field `F_q = 2^254 + t_q`. The algorithm computes
+        // `[k + 2^254] base = [scalar + q] base = [scalar] base`.
+        let k = scalar + C::ScalarExt::from_u128(C::T_Q);
+
+        // Big-endian bit representation of `k`.
+        let mut bitstring: Vec<bool> = k.to_le_bits().into_iter().take(L_SCALAR).collect();
         bitstring.reverse();
         bitstring
     });
@@ -430,7 +528,7 @@ fn decompose_for_scalar_mul(scalar: Option<pallas::Base>) -> Vec<Option<bool>> {
     if let Some(bitstring) = bitstring {
         bitstring.into_iter().map(Some).collect()
     } else {
-        vec![None; pallas::Scalar::NUM_BITS as usize]
+        vec![None; L_SCALAR]
     }
 }
 
@@ -445,26 +543,17 @@ pub mod tests {
 
     use crate::{
         chip::EccChip,
-        gadget::{EccInstructions, FixedPoints, Point},
+        gadget::{EccInstructions, FixedPoints, NonIdentityPoint, Point},
     };
     use utilities::UtilitiesInstructions;
 
-    pub fn test_mul<F: FixedPoints<pallas::Affine>>(
-        chip: EccChip<F>,
+    pub fn test_mul<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
-        // Generate a random point P
+        // Generate a random non-identity point P
         let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
-        let p = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
-
-        // Generate a (0,0) point to be used in other tests.
-        let zero = {
-            Point::new(
-                chip.clone(),
-                layouter.namespace(|| "identity"),
-                Some(pallas::Affine::identity()),
-            )?
-        };
+        let p = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
 
         let column = chip.config().advices[0];
 
@@ -508,18 +597,14 @@ pub mod tests {
             )?;
         }
 
-        // [a]𝒪 should return an error since variable-base scalar multiplication
-        // uses incomplete addition at the beginning of its double-and-add.
-        {
-            let scalar_val = pallas::Base::rand();
-            let scalar = chip.load_private(
-                layouter.namespace(|| "random scalar"),
-                column,
-                Some(scalar_val),
-            )?;
-            zero.mul(layouter.namespace(|| "[a]𝒪"), &scalar)
-                .expect_err("[a]𝒪 should return an error");
-        }
+        // The base of a variable-base scalar multiplication cannot be 𝒪, since
+        // it uses incomplete addition at the beginning of its double-and-add.
+        NonIdentityPoint::new(
+            chip.clone(),
+            layouter.namespace(|| "identity"),
+            Some(pallas::Affine::identity()),
+        )
+        .expect_err("witnessing 𝒪 as a non-identity point should return an error");
 
         // [0]B should return (0,0) since variable-base scalar multiplication
         // uses complete addition for the final bits of the scalar.
@@ -558,4 +643,42 @@ pub mod tests {
 
         Ok(())
     }
+
+    pub fn test_mul_full_width<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate a random non-identity point P
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
+        let p = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+
+        // The scalar field modulus is q = 2^254 + t_q.
+        let t_q = pallas::Scalar::from_u128(45560315531506369815346746415080538113);
+
+        let scalars = [
+            ("random [a]B", pallas::Scalar::rand()),
+            ("[0]B", pallas::Scalar::zero()),
+            // The largest possible scalar field element.
+            ("[-1]B", -pallas::Scalar::one()),
+            // k = scalar + t_q = 2^254, the smallest decomposition with k_254 = 1.
+            (
+                "[2^254 - t_q]B",
+                pallas::Scalar::from_u128(1 << 127).square() - t_q,
+            ),
+            // k = scalar + t_q = q - 1, the largest canonical decomposition.
+            ("[-t_q - 1]B", -t_q - pallas::Scalar::one()),
+        ];
+
+        for (name, scalar_val) in scalars.iter() {
+            let (result, _) = p.mul_full_width(layouter.namespace(|| *name), Some(*scalar_val))?;
+            let expected = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "expected point"),
+                Some((p_val * scalar_val).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| *name), &expected)?;
+        }
+
+        Ok(())
+    }
 }
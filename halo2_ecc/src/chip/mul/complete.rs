@@ -1,5 +1,5 @@
-use super::super::{add, copy, CellValue, EccConfig, EccPoint, Var};
-use super::{COMPLETE_RANGE, X, Y, Z};
+use super::super::{add, copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
+use super::{X, Y, Z};
 
 use halo2::{
     circuit::Region,
@@ -7,19 +7,17 @@ use halo2::{
     poly::Rotation,
 };
 
-use pasta_curves::{arithmetic::FieldExt, pallas};
-
-pub struct Config {
+pub struct Config<C: EccCurve> {
     // Selector used to constrain the cells used in complete addition.
     q_mul_decompose_var: Selector,
     // Advice column used to decompose scalar in complete addition.
     pub z_complete: Column<Advice>,
     // Configuration used in complete addition
-    add_config: add::Config,
+    add_config: add::Config<C>,
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         let config = Self {
             q_mul_decompose_var: ecc_config.q_mul_decompose_var,
             z_complete: ecc_config.advices[9],
@@ -36,12 +34,12 @@ impl From<&EccConfig> for Config {
     }
 }
 
-impl Config {
+impl<C: EccCurve> Config<C> {
     /// Gate used to check scalar decomposition is correct.
     /// This is used to check the bits used in complete addition, since the incomplete
     /// addition gate (controlled by `q_mul`) already checks scalar decomposition for
     /// the other bits.
-    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate(
             "Decompose scalar for complete bits of variable-base mul",
             |meta| {
@@ -52,8 +50,8 @@ impl Config {
                 let z_next = meta.query_advice(self.z_complete, Rotation::next());
 
                 // k_{i} = z_{i} - 2⋅z_{i+1}
-                let k = z_next - Expression::Constant(pallas::Base::from_u64(2)) * z_prev;
-                let k_minus_one = k.clone() - Expression::Constant(pallas::Base::one());
+                let k = z_next - Expression::Constant(C::Base::from_u64(2)) * z_prev;
+                let k_minus_one = k.clone() - Expression::Constant(C::Base::one());
                 // (k_i) ⋅ (k_i - 1) = 0
                 let bool_check = k.clone() * k_minus_one.clone();
 
@@ -72,25 +70,24 @@ impl Config {
         );
     }
 
+    /// Processes `bits` (in big-endian order) using complete addition, starting
+    /// from the accumulator `(x_a, y_a)` and the running sum `z`. Each bit uses two
+    /// rows.
     #[allow(clippy::type_complexity)]
     #[allow(non_snake_case)]
     #[allow(clippy::too_many_arguments)]
     pub(super) fn assign_region(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
         bits: &[Option<bool>],
-        base: &EccPoint,
-        x_a: X<pallas::Base>,
-        y_a: Y<pallas::Base>,
-        z: Z<pallas::Base>,
-    ) -> Result<(EccPoint, Vec<Z<pallas::Base>>), Error> {
-        // Make sure we have the correct number of bits for the complete addition
-        // part of variable-base scalar mul.
-        assert_eq!(bits.len(), COMPLETE_RANGE.len());
-
+        base: &EccPoint<C>,
+        x_a: X<C::Base>,
+        y_a: Y<C::Base>,
+        z: Z<C::Base>,
+    ) -> Result<(EccPoint<C>, Vec<Z<C::Base>>), Error> {
         // Enable selectors for complete range
-        for row in 0..COMPLETE_RANGE.len() {
+        for row in 0..bits.len() {
             // Each iteration uses 2 rows (two complete additions)
             let row = 2 * row;
             // Check scalar decomposition for each iteration. Since the gate enabled by
@@ -115,7 +112,7 @@ impl Config {
         };
 
         // Store interstitial running sum `z`s in vector
-        let mut zs: Vec<Z<pallas::Base>> = Vec::with_capacity(bits.len());
+        let mut zs: Vec<Z<C::Base>> = Vec::with_capacity(bits.len());
 
         // Complete addition
         for (iter, k) in bits.iter().enumerate() {
@@ -125,9 +122,10 @@ impl Config {
             // Update `z`.
             z = {
                 // z_next = z_cur * 2 + k_next
-                let z_val = z.value().zip(k.as_ref()).map(|(z_val, k)| {
-                    pallas::Base::from_u64(2) * z_val + pallas::Base::from_u64(*k as u64)
-                });
+                let z_val = z
+                    .value()
+                    .zip(k.as_ref())
+                    .map(|(z_val, k)| C::Base::from_u64(2) * z_val + C::Base::from_u64(*k as u64));
                 let z_cell = region.assign_advice(
                     || "z",
                     self.z_complete,
@@ -160,7 +158,7 @@ impl Config {
                     row + offset,
                     || y_p.ok_or(Error::SynthesisError),
                 )?;
-                CellValue::<pallas::Base>::new(y_p_cell, y_p)
+                CellValue::<C::Base>::new(y_p_cell, y_p)
             };
 
             // U = P if the bit is set; U = -P is the bit is not set.
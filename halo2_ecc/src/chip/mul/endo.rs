@@ -0,0 +1,1272 @@
+use super::super::{
+    add, copy, CellValue, EccConfig, EccCurve, EccPoint, NonIdentityEccPoint, Var, LOOKUP_K,
+};
+use super::{X, Y, Z};
+use utilities::{bitrange_subset, bool_check, lookup_range_check::LookupRangeCheckConfig};
+
+use bigint::{U256, U512};
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, VirtualCells},
+    poly::Rotation,
+};
+
+/// Number of bits in each half of the decomposed scalar.
+const NUM_BITS: usize = 128;
+
+/// Number of double-and-add steps for which complete addition needs to be used.
+const NUM_COMPLETE_BITS: usize = 3;
+
+/// Number of double-and-add steps that use incomplete addition.
+const NUM_INCOMPLETE_BITS: usize = NUM_BITS - NUM_COMPLETE_BITS;
+
+/// Offset added to the lattice coefficients $c_1, c_2$ so that they are range-checked
+/// as non-negative integers. This is even, so that $c_i + 16$ has the parity of $c_i$.
+const C_OFFSET: u64 = 16;
+
+/// Variable-base scalar multiplication using the endomorphism
+/// $\phi(x, y) = (\zeta x, y) = [\lambda] (x, y)$ of the curve, as described by
+/// Gallant, Lambert and Vanstone.
+///
+/// The scalar `alpha` is decomposed as $k_1 + \lambda k_2 \equiv \alpha \pmod{q}$,
+/// where each $k_i = 2^{128} + 2 m_i + 1$ for a 128-bit $m_i$. Writing the bits of
+/// $m_i$ as $b_{i,j}$, and $d_{i,j} = 2 b_{i,j} - 1$, the joint double-and-add
+///
+/// ```text
+/// Acc := [2] S, where S = P + φ(P) = (ζ^2 x_P, -y_P)
+/// for j from 127 down to 0:
+///     Acc := (Acc + [d_{1,j}] P + [d_{2,j}] φ(P)) + Acc
+/// ```
+///
+/// computes $[k_1] P + [k_2] \phi(P) = [\alpha] P$ with 128 doublings instead of 254.
+/// The point added in each step is one of $\pm S$ or $\pm D$, where $D = P - \phi(P)$,
+/// depending on the bits $b_{1,j}, b_{2,j}$.
+///
+/// The accumulator after $j$ steps is $[\alpha_j] P + [\beta_j] \phi(P)$ with
+/// $\alpha_j, \beta_j \in [2^j + 1, 3 \cdot 2^j - 1]$. For the lattice bases of both
+/// Pasta curves, no vector $(k_1, k_2)$ with $k_1 + \lambda k_2 \equiv 0$ lies in
+/// $[2^j - 1, 3 \cdot 2^j + 1]^2$ for $j < 126$, so that incomplete addition can be
+/// used for all but the last three steps.
+///
+/// The decomposition is computed natively by Babai rounding, and constrained by
+/// witnessing the lattice coefficients $c_1, c_2$ such that
+///
+/// ```text
+/// k_1 = alpha - c_1 a_1 - c_2 a_2
+/// k_2 =         c_1 b_1 - c_2 b_2
+/// ```
+///
+/// over the integers, where $(a_1, -b_1), (a_2, b_2)$ is [`EccCurve::GLV_BASIS`].
+/// These equations are checked in the base field, as described in the
+/// decomposition gate.
+pub struct Config<C: EccCurve> {
+    // Selector used to compute S, D and [2]S from the base.
+    q_mul_endo_table: Selector,
+    // Selectors used to constrain the cells used in incomplete addition.
+    q_mul_endo: (Selector, Selector, Selector),
+    // Selector used to select the added point in each complete double-and-add step.
+    q_mul_endo_complete: Selector,
+    // Selector used to check the scalar decomposition.
+    q_mul_endo_decompose: Selector,
+    // x-coordinate of S = P + φ(P).
+    x_s: Column<Advice>,
+    // y-coordinate of S = P + φ(P).
+    y_s: Column<Advice>,
+    // x-coordinate of D = P - φ(P).
+    x_d: Column<Advice>,
+    // y-coordinate of D = P - φ(P).
+    y_d: Column<Advice>,
+    // x-coordinate of the base P, in the table row.
+    x_p: Column<Advice>,
+    // y-coordinate of the base P, in the table row.
+    y_p: Column<Advice>,
+    // Running sum used to decompose m_1 in incomplete addition.
+    z_1: Column<Advice>,
+    // Running sum used to decompose m_2 in incomplete addition.
+    z_2: Column<Advice>,
+    // x-coordinate of the accumulator in each double-and-add iteration.
+    x_a: Column<Advice>,
+    // lambda1 in each double-and-add iteration.
+    lambda1: Column<Advice>,
+    // lambda2 in each double-and-add iteration.
+    lambda2: Column<Advice>,
+    // Running sums used to decompose m_1 and m_2 in complete addition.
+    z_complete: Column<Advice>,
+    // Advice columns used in the decomposition check.
+    advices: [Column<Advice>; 10],
+    // Configuration used in complete addition
+    add_config: add::Config<C>,
+    // Configuration used to range-check the decomposition
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        let config = Self {
+            q_mul_endo_table: ecc_config.q_mul_endo_table,
+            q_mul_endo: ecc_config.q_mul_endo,
+            q_mul_endo_complete: ecc_config.q_mul_endo_complete,
+            q_mul_endo_decompose: ecc_config.q_mul_endo_decompose,
+            x_s: ecc_config.advices[0],
+            y_s: ecc_config.advices[1],
+            x_d: ecc_config.advices[4],
+            y_d: ecc_config.advices[5],
+            x_p: ecc_config.advices[2],
+            y_p: ecc_config.advices[3],
+            z_1: ecc_config.advices[2],
+            z_2: ecc_config.advices[3],
+            x_a: ecc_config.advices[6],
+            lambda1: ecc_config.advices[7],
+            lambda2: ecc_config.advices[8],
+            z_complete: ecc_config.advices[9],
+            advices: ecc_config.advices,
+            add_config: ecc_config.into(),
+            lookup_config: ecc_config.lookup_config.clone(),
+        };
+
+        // The point selected in each complete step is assigned in the `x_p, y_p`
+        // columns of the following complete addition, and the table is assigned in
+        // the same row as the output of the previous complete addition.
+        assert_eq!(config.add_config.x_p, config.x_s);
+        assert_eq!(config.add_config.y_p, config.y_s);
+        let add_config_outputs = config.add_config.output_columns();
+        for column in [config.x_d, config.y_d, config.z_complete].iter() {
+            assert!(
+                !add_config_outputs.contains(column),
+                "table columns cannot overlap with complete addition outputs."
+            );
+        }
+        assert!(
+            !config
+                .add_config
+                .advice_columns()
+                .contains(&config.z_complete),
+            "z_complete cannot overlap with complete addition columns."
+        );
+
+        config
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        let one = || Expression::Constant(C::Base::one());
+
+        // S = P + φ(P) = -φ^2(P) = (ζ^2 x_P, -y_P), D = P - φ(P), and the initial
+        // accumulator A = [2] S.
+        //
+        // ```text
+        // | x_s | y_s | x_p | y_p | x_d | y_d | lambda | x_a | y_a | q_mul_endo_table
+        // ```
+        meta.create_gate("GLV table", |meta| {
+            let q_mul_endo_table = meta.query_selector(self.q_mul_endo_table);
+
+            let x_s = meta.query_advice(self.x_s, Rotation::cur());
+            let y_s = meta.query_advice(self.y_s, Rotation::cur());
+            let x_p = meta.query_advice(self.x_p, Rotation::cur());
+            let y_p = meta.query_advice(self.y_p, Rotation::cur());
+            let x_d = meta.query_advice(self.x_d, Rotation::cur());
+            let y_d = meta.query_advice(self.y_d, Rotation::cur());
+            let lambda = meta.query_advice(self.advices[6], Rotation::cur());
+            let x_a = meta.query_advice(self.advices[7], Rotation::cur());
+            let y_a = meta.query_advice(self.advices[8], Rotation::cur());
+
+            let zeta = C::ZETA;
+            let two = C::Base::from_u64(2);
+
+            let x_s_check = x_s.clone() - x_p.clone() * zeta.square();
+            let y_s_check = y_s.clone() + y_p.clone();
+
+            // D = P + (ζ x_P, -y_P) by incomplete addition, where x_P ≠ ζ x_P since
+            // the base is not the identity.
+            //   (x_D + ζ x_P + x_P)(x_P - ζ x_P)^2 - (2 y_P)^2 = 0
+            //   (y_D + y_P)(x_P - ζ x_P) - 2 y_P (x_P - x_D) = 0
+            let x_p_minus_x_q = x_p.clone() * (C::Base::one() - zeta);
+            let x_d_check = (x_d.clone() + x_p.clone() * (C::Base::one() + zeta))
+                * x_p_minus_x_q.clone().square()
+                - (y_p.clone() * two).square();
+            let y_d_check = (y_d + y_p.clone()) * x_p_minus_x_q - y_p * two * (x_p - x_d);
+
+            // A = [2] S, where y_S ≠ 0 since the curve has no points of order 2.
+            //   2 y_S λ = 3 x_S^2
+            //   x_A = λ^2 - 2 x_S
+            //   y_A = λ (x_S - x_A) - y_S
+            let lambda_check =
+                y_s.clone() * lambda.clone() * two - x_s.clone().square() * C::Base::from_u64(3);
+            let x_a_check = x_a.clone() - (lambda.clone().square() - x_s.clone() * two);
+            let y_a_check = y_a - (lambda * (x_s - x_a) - y_s);
+
+            std::iter::empty()
+                .chain(Some(("x_s_check", x_s_check)))
+                .chain(Some(("y_s_check", y_s_check)))
+                .chain(Some(("x_d_check", x_d_check)))
+                .chain(Some(("y_d_check", y_d_check)))
+                .chain(Some(("lambda_check", lambda_check)))
+                .chain(Some(("x_a_check", x_a_check)))
+                .chain(Some(("y_a_check", y_a_check)))
+                .map(move |(name, poly)| (name, q_mul_endo_table.clone() * poly))
+        });
+
+        // Closure to compute the point Q_i = [d_{1,i}] P + [d_{2,i}] φ(P) added in the
+        // double-and-add step at `rotation`, given the running sums `z_1`, `z_2` at
+        // `rotation` and the row before it. Also returns the bits b_{1,i}, b_{2,i}.
+        //
+        // Q_i is ±S if b_{1,i} = b_{2,i}, and ±D otherwise, with the sign of d_{1,i}.
+        let q_point = |meta: &mut VirtualCells<C::Base>,
+                       (z_1, z_2): (Column<Advice>, Column<Advice>),
+                       (rot_cur, rot_prev): (Rotation, Rotation),
+                       rot_table: Rotation| {
+            let x_s = meta.query_advice(self.x_s, rot_table);
+            let y_s = meta.query_advice(self.y_s, rot_table);
+            let x_d = meta.query_advice(self.x_d, rot_table);
+            let y_d = meta.query_advice(self.y_d, rot_table);
+
+            // b_{i} = z_{i} - 2⋅z_{i+1}
+            let bit = |meta: &mut VirtualCells<C::Base>, z: Column<Advice>| {
+                let z_cur = meta.query_advice(z, rot_cur);
+                let z_prev = meta.query_advice(z, rot_prev);
+                z_cur - z_prev * C::Base::from_u64(2)
+            };
+            let b_1 = bit(meta, z_1);
+            let b_2 = bit(meta, z_2);
+
+            let xor = b_1.clone() + b_2.clone() - b_1.clone() * b_2.clone() * C::Base::from_u64(2);
+            let x_q = x_s.clone() + xor.clone() * (x_d - x_s);
+            let y_q =
+                (b_1.clone() * C::Base::from_u64(2) - one()) * (y_s.clone() + xor * (y_d - y_s));
+
+            (x_q, y_q, b_1, b_2)
+        };
+
+        let incomplete_q_point = |meta: &mut VirtualCells<C::Base>, rotation: Rotation| {
+            q_point(
+                meta,
+                (self.z_1, self.z_2),
+                (rotation, Rotation(rotation.0 - 1)),
+                rotation,
+            )
+        };
+
+        // Closure to compute x_{R,i} = λ_{1,i}^2 - x_{A,i} - x_{Q,i}
+        let x_r = |meta: &mut VirtualCells<C::Base>, rotation: Rotation| {
+            let x_a = meta.query_advice(self.x_a, rotation);
+            let lambda_1 = meta.query_advice(self.lambda1, rotation);
+            let (x_q, _, _, _) = incomplete_q_point(meta, rotation);
+            lambda_1.square() - x_a - x_q
+        };
+
+        // Closure to compute y_{A,i} = (λ_{1,i} + λ_{2,i}) * (x_{A,i} - x_{R,i}) / 2
+        let y_a = |meta: &mut VirtualCells<C::Base>, rotation: Rotation| {
+            let x_a = meta.query_advice(self.x_a, rotation);
+            let lambda_1 = meta.query_advice(self.lambda1, rotation);
+            let lambda_2 = meta.query_advice(self.lambda2, rotation);
+
+            (lambda_1 + lambda_2) * (x_a - x_r(meta, rotation)) * C::Base::TWO_INV
+        };
+
+        // Constraints used for q_mul_endo_{2, 3} == 1
+        let for_loop = |meta: &mut VirtualCells<C::Base>,
+                        q_mul: Expression<C::Base>,
+                        y_a_next: Expression<C::Base>| {
+            // x_{A,i}
+            let x_a_cur = meta.query_advice(self.x_a, Rotation::cur());
+            // x_{A,i-1}
+            let x_a_next = meta.query_advice(self.x_a, Rotation::next());
+            // λ_{1,i}
+            let lambda1_cur = meta.query_advice(self.lambda1, Rotation::cur());
+            // λ_{2,i}
+            let lambda2_cur = meta.query_advice(self.lambda2, Rotation::cur());
+
+            let y_a_cur = y_a(meta, Rotation::cur());
+            let (x_q_cur, y_q_cur, b_1, b_2) = incomplete_q_point(meta, Rotation::cur());
+
+            // λ_{1,i}⋅(x_{A,i} − x_{Q,i}) − y_{A,i} + y_{Q,i} = 0
+            let gradient_1 = lambda1_cur * (x_a_cur.clone() - x_q_cur) - y_a_cur.clone() + y_q_cur;
+
+            // λ_{2,i}^2 − x_{A,i-1} − x_{R,i} − x_{A,i} = 0
+            let secant_line = lambda2_cur.clone().square()
+                - x_a_next.clone()
+                - x_r(meta, Rotation::cur())
+                - x_a_cur.clone();
+
+            // λ_{2,i}⋅(x_{A,i} − x_{A,i-1}) − y_{A,i} − y_{A,i-1} = 0
+            let gradient_2 = lambda2_cur * (x_a_cur - x_a_next) - y_a_cur - y_a_next;
+
+            std::iter::empty()
+                .chain(Some(("b_1_check", bool_check(b_1))))
+                .chain(Some(("b_2_check", bool_check(b_2))))
+                .chain(Some(("gradient_1", gradient_1)))
+                .chain(Some(("secant_line", secant_line)))
+                .chain(Some(("gradient_2", gradient_2)))
+                .map(move |(name, poly)| (name, q_mul.clone() * poly))
+        };
+
+        // q_mul_endo_1 == 1 checks
+        meta.create_gate("GLV q_mul_1 == 1 checks", |meta| {
+            let q_mul_1 = meta.query_selector(self.q_mul_endo.0);
+
+            let y_a_next = y_a(meta, Rotation::next());
+            let y_a_witnessed = meta.query_advice(self.lambda1, Rotation::cur());
+            vec![("init y_a", q_mul_1 * (y_a_witnessed - y_a_next))]
+        });
+
+        // q_mul_endo_2 == 1 checks
+        meta.create_gate("GLV q_mul_2 == 1 checks", |meta| {
+            let q_mul_2 = meta.query_selector(self.q_mul_endo.1);
+
+            let y_a_next = y_a(meta, Rotation::next());
+
+            // S and D remain constant throughout the double-and-add.
+            let table_checks = [self.x_s, self.y_s, self.x_d, self.y_d]
+                .iter()
+                .map(|column| {
+                    meta.query_advice(*column, Rotation::cur())
+                        - meta.query_advice(*column, Rotation::next())
+                })
+                .collect::<Vec<_>>();
+
+            std::iter::empty()
+                .chain(
+                    table_checks
+                        .into_iter()
+                        .map(|poly| ("table_check", q_mul_2.clone() * poly)),
+                )
+                .chain(for_loop(meta, q_mul_2, y_a_next))
+        });
+
+        // q_mul_endo_3 == 1 checks
+        meta.create_gate("GLV q_mul_3 == 1 checks", |meta| {
+            let q_mul_3 = meta.query_selector(self.q_mul_endo.2);
+            let y_a_final = meta.query_advice(self.lambda1, Rotation::next());
+            for_loop(meta, q_mul_3, y_a_final)
+        });
+
+        // Selects the point Q_i added in a complete double-and-add step. The running
+        // sums for m_1 and m_2 alternate in the `z_complete` column, so that z_{1,i} is
+        // two rows below z_{1,i+1}, and Q_i is assigned in the `x_p, y_p` columns of
+        // the next row, as the first input to complete addition.
+        //
+        // ```text
+        // |      |      |      |      |      |      | ... | z_{1,i+1} |
+        // |      |      |      |      |      |      | ... | z_{2,i+1} |
+        // | x_s  | y_s  |      |      | x_d  | y_d  | ... |           | q_mul_endo_complete
+        // | x_q  | y_q  |   complete addition              | z_{1,i}   |
+        // |      |      |   complete addition              | z_{2,i}   |
+        // ```
+        meta.create_gate("GLV complete step selection", |meta| {
+            let q_mul_endo_complete = meta.query_selector(self.q_mul_endo_complete);
+
+            let z_1_prev = meta.query_advice(self.z_complete, Rotation(-2));
+            let z_2_prev = meta.query_advice(self.z_complete, Rotation::prev());
+            let z_1_cur = meta.query_advice(self.z_complete, Rotation::next());
+            let z_2_cur = meta.query_advice(self.z_complete, Rotation(2));
+            let x_q_witnessed = meta.query_advice(self.add_config.x_p, Rotation::next());
+            let y_q_witnessed = meta.query_advice(self.add_config.y_p, Rotation::next());
+
+            let b = |z_cur: Expression<C::Base>, z_prev: Expression<C::Base>| {
+                z_cur - z_prev * C::Base::from_u64(2)
+            };
+            let b_1 = b(z_1_cur, z_1_prev);
+            let b_2 = b(z_2_cur, z_2_prev);
+
+            let x_s = meta.query_advice(self.x_s, Rotation::cur());
+            let y_s = meta.query_advice(self.y_s, Rotation::cur());
+            let x_d = meta.query_advice(self.x_d, Rotation::cur());
+            let y_d = meta.query_advice(self.y_d, Rotation::cur());
+
+            let xor = b_1.clone() + b_2.clone() - b_1.clone() * b_2.clone() * C::Base::from_u64(2);
+            let x_q = x_s.clone() + xor.clone() * (x_d - x_s);
+            let y_q =
+                (b_1.clone() * C::Base::from_u64(2) - one()) * (y_s.clone() + xor * (y_d - y_s));
+
+            std::iter::empty()
+                .chain(Some(("b_1_check", bool_check(b_1))))
+                .chain(Some(("b_2_check", bool_check(b_2))))
+                .chain(Some(("x_q_check", x_q_witnessed - x_q)))
+                .chain(Some(("y_q_check", y_q_witnessed - y_q)))
+                .map(move |(name, poly)| (name, q_mul_endo_complete.clone() * poly))
+        });
+
+        // Checks that k_1 + λ k_2 ≡ alpha (mod q), where k_i = 2^128 + 2 m_i + 1.
+        //
+        // We witness c_i = l_i + 2 h_i - 16, with l_i boolean and h_i range-constrained
+        // to below 2^{w_i - 1} (where w_1, w_2 are the bit lengths of b_2, b_1), and
+        // check the equations
+        //
+        //   V_1 = k_1 - alpha + c_1 a_1 + c_2 a_2 = 0
+        //   V_2 = k_2 - c_1 b_1 + c_2 b_2 = 0
+        //
+        // in the base field. With these ranges |V_1|, |V_2| < 2p, so that each V_i is
+        // one of -p, 0 or p. Since p is odd, V_i = 0 holds over the integers if and only
+        // if V_i is also even. The parity of V_1 depends on the parity of alpha, which
+        // we obtain from its canonical decomposition
+        //
+        //   alpha = b + 2 (h_lo + 2^253 h_hi),
+        //
+        // where h_lo is range-constrained to 253 bits. If h_hi = 1, we additionally
+        // need h_lo + b < (t_p + 1) / 2 for the decomposition to be canonical:
+        //   - h_hi = 1 => 0 ≤ h_lo + b + 2^130 - (t_p + 1) / 2 < 2^130
+        //              => 13 ten-bit lookups of h_lo' = h_lo + b + 2^130 - (t_p + 1) / 2
+        //              => z_13_h_lo_prime = 0
+        //
+        // ```text
+        // | alpha | b   | h_hi | h_lo | h_lo' | z_13_h_lo' | m_1 | m_2 | q_mul_endo_decompose
+        // |  l_1  | h_1 | l_2  | h_2  |       |            |     |     |
+        // ```
+        meta.create_gate("GLV decomposition check", |meta| {
+            let q_mul_endo_decompose = meta.query_selector(self.q_mul_endo_decompose);
+
+            let alpha = meta.query_advice(self.advices[0], Rotation::cur());
+            let b = meta.query_advice(self.advices[1], Rotation::cur());
+            let h_hi = meta.query_advice(self.advices[2], Rotation::cur());
+            let h_lo = meta.query_advice(self.advices[3], Rotation::cur());
+            let h_lo_prime = meta.query_advice(self.advices[4], Rotation::cur());
+            let z_13_h_lo_prime = meta.query_advice(self.advices[5], Rotation::cur());
+            let m_1 = meta.query_advice(self.advices[6], Rotation::cur());
+            let m_2 = meta.query_advice(self.advices[7], Rotation::cur());
+            let l_1 = meta.query_advice(self.advices[0], Rotation::next());
+            let h_1 = meta.query_advice(self.advices[1], Rotation::next());
+            let l_2 = meta.query_advice(self.advices[2], Rotation::next());
+            let h_2 = meta.query_advice(self.advices[3], Rotation::next());
+
+            let two = C::Base::from_u64(2);
+            let two_pow_128 = C::Base::from_u128(1 << 64).square();
+            let two_pow_130 = two_pow_128 * C::Base::from_u64(4);
+            let two_pow_253 = C::Base::from_u128(1 << 126).square().double();
+            let t_p_plus_one_halved = C::Base::from_u128((C::T_P + 1) / 2);
+            let [a_1, b_1, a_2, b_2] = C::GLV_BASIS;
+
+            // alpha = b + 2 (h_lo + 2^253 h_hi)
+            let alpha_check =
+                alpha.clone() - (b.clone() + (h_lo.clone() + h_hi.clone() * two_pow_253) * two);
+
+            // h_lo' = h_lo + b + 2^130 - (t_p + 1) / 2
+            let h_lo_prime_check = h_lo_prime
+                - (h_lo + b.clone() + Expression::Constant(two_pow_130 - t_p_plus_one_halved));
+
+            // k_i = 2^128 + 2 m_i + 1
+            let k = |m: Expression<C::Base>| {
+                m * two + Expression::Constant(two_pow_128 + C::Base::one())
+            };
+            // c_i = l_i + 2 h_i - 16
+            let c = |l: Expression<C::Base>, h: Expression<C::Base>| {
+                l + h * two - Expression::Constant(C::Base::from_u64(C_OFFSET))
+            };
+            let (c_1, c_2) = (c(l_1.clone(), h_1), c(l_2.clone(), h_2));
+            let constant = |x: u128| C::Base::from_u128(x);
+
+            let v_1 = k(m_1) - alpha + c_1.clone() * constant(a_1) + c_2.clone() * constant(a_2);
+            let v_2 = k(m_2) - c_1 * constant(b_1) + c_2 * constant(b_2);
+
+            // The parity of V_i is that of s_i ∈ [0, 4], where k_i is odd, the parity of
+            // alpha is b, and the parity of c_i is l_i.
+            let parity = |s: Expression<C::Base>| {
+                s.clone()
+                    * (s.clone() - Expression::Constant(two))
+                    * (s - Expression::Constant(C::Base::from_u64(4)))
+            };
+            let s_1 = one()
+                + b.clone()
+                + l_1.clone() * constant(a_1 & 1)
+                + l_2.clone() * constant(a_2 & 1);
+            let s_2 = one() + l_1.clone() * constant(b_1 & 1) + l_2.clone() * constant(b_2 & 1);
+
+            std::iter::empty()
+                .chain(Some(("b_check", bool_check(b))))
+                .chain(Some(("h_hi_check", bool_check(h_hi.clone()))))
+                .chain(Some(("alpha_check", alpha_check)))
+                .chain(Some(("h_lo_prime_check", h_lo_prime_check)))
+                .chain(Some((
+                    "h_hi = 1 => z_13_h_lo_prime = 0",
+                    h_hi * z_13_h_lo_prime,
+                )))
+                .chain(Some(("l_1_check", bool_check(l_1))))
+                .chain(Some(("l_2_check", bool_check(l_2))))
+                .chain(Some(("v_1_check", v_1)))
+                .chain(Some(("v_2_check", v_2)))
+                .chain(Some(("v_1_parity_check", parity(s_1))))
+                .chain(Some(("v_2_parity_check", parity(s_2))))
+                .map(move |(name, poly)| (name, q_mul_endo_decompose.clone() * poly))
+        });
+    }
+
+    pub(crate) fn assign(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        alpha: CellValue<C::Base>,
+        base: &NonIdentityEccPoint<C>,
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>), Error> {
+        let decomposition = alpha.value().map(decompose_for_mul_endo::<C>);
+
+        // Big-endian bit pairs (b_{1,j}, b_{2,j}) of m_1 and m_2.
+        let bits: Vec<Option<(bool, bool)>> = (0..NUM_BITS)
+            .rev()
+            .map(|j| decomposition.map(|d| ((d.m[0] >> j) & 1 == 1, (d.m[1] >> j) & 1 == 1)))
+            .collect();
+
+        let (result, m_1, m_2) = self.double_and_add(&mut layouter, &bits, base)?;
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::Curve;
+
+            let base = base.point();
+            let alpha = alpha.value().map(super::super::base_to_scalar::<C>);
+            let real_mul = base.zip(alpha).map(|(base, alpha)| base * alpha);
+            let result = result.point();
+
+            if let (Some(real_mul), Some(result)) = (real_mul, result) {
+                assert_eq!(real_mul.to_affine(), result);
+            }
+        }
+
+        self.decomposition_check(
+            layouter.namespace(|| "GLV decomposition check"),
+            alpha,
+            (m_1, m_2),
+            decomposition.map(|d| d.c),
+        )?;
+
+        Ok((result, alpha))
+    }
+
+    /// Computes `[k_1] base + [k_2] φ(base)` using the joint double-and-add, where
+    /// `k_i = 2^128 + 2 m_i + 1` and the bits of `m_1, m_2` are given in big-endian
+    /// order.
+    ///
+    /// Returns the result, along with the cells containing `m_1` and `m_2`.
+    ///
+    /// ```text
+    /// | x_s  | y_s  | x_p | y_p | x_d  | y_d  | lambda | x_a  | y_a  |         | q_mul_endo_table
+    /// |      |      |  0  |  0  |      |      |        | y_a  |      |         | q_mul_endo_1
+    /// | x_s  | y_s  | z_1 | z_2 | x_d  | y_d  |  x_a   | λ1   | λ2   |         | q_mul_endo_2
+    /// | ...                                                                    |
+    /// | x_s  | y_s  | z_1 | z_2 | x_d  | y_d  |  x_a   | λ1   | λ2   | z_1     | q_mul_endo_3
+    /// |      |      |     |     |      |      |  x_a   | y_a  |      | z_2     |
+    /// | complete steps (3 rows each)                                           |
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn double_and_add(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        bits: &[Option<(bool, bool)>],
+        base: &NonIdentityEccPoint<C>,
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>, CellValue<C::Base>), Error> {
+        assert_eq!(bits.len(), NUM_BITS);
+
+        layouter.assign_region(
+            || "GLV variable-base scalar mul",
+            |mut region| {
+                let offset = 0;
+
+                let (table, acc) = self.assign_table(&mut region, offset, base)?;
+
+                let offset = offset + 1;
+                let (x_a, y_a, z_1, z_2) = self.incomplete(
+                    &mut region,
+                    offset,
+                    &table,
+                    &bits[..NUM_INCOMPLETE_BITS],
+                    acc,
+                )?;
+
+                // The incomplete addition uses one row to initialize the running sums,
+                // one row per bit, and a final row for the accumulator.
+                let offset = offset + NUM_INCOMPLETE_BITS + 2;
+                self.complete(
+                    &mut region,
+                    offset,
+                    &table,
+                    &bits[NUM_INCOMPLETE_BITS..],
+                    (x_a, y_a),
+                    (z_1, z_2),
+                )
+            },
+        )
+    }
+
+    /// Assigns S, D and the initial accumulator [2]S in row `offset`.
+    fn assign_table(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        offset: usize,
+        base: &NonIdentityEccPoint<C>,
+    ) -> Result<([CellValue<C::Base>; 4], (X<C::Base>, Y<C::Base>)), Error> {
+        self.q_mul_endo_table.enable(region, offset)?;
+
+        copy(region, || "x_p", self.x_p, offset, &base.x())?;
+        copy(region, || "y_p", self.y_p, offset, &base.y())?;
+
+        let (x_p, y_p) = (base.x().value(), base.y().value());
+        let zeta = C::ZETA;
+
+        // S = (ζ^2 x_P, -y_P)
+        let x_s = x_p.map(|x_p| zeta.square() * x_p);
+        let y_s = y_p.map(|y_p| -y_p);
+
+        // D = P + (ζ x_P, -y_P)
+        let (x_d, y_d) = {
+            let lambda = x_p
+                .zip(y_p)
+                .map(|(x_p, y_p)| y_p.double() * (x_p * (C::Base::one() - zeta)).invert().unwrap());
+            let x_d = lambda
+                .zip(x_p)
+                .map(|(lambda, x_p)| lambda.square() - x_p * (C::Base::one() + zeta));
+            let y_d = lambda
+                .zip(x_p)
+                .zip(x_d)
+                .zip(y_p)
+                .map(|(((lambda, x_p), x_d), y_p)| lambda * (x_p - x_d) - y_p);
+            (x_d, y_d)
+        };
+
+        // A = [2] S
+        let lambda = x_s
+            .zip(y_s)
+            .map(|(x_s, y_s)| x_s.square() * C::Base::from_u64(3) * y_s.double().invert().unwrap());
+        let x_a = lambda
+            .zip(x_s)
+            .map(|(lambda, x_s)| lambda.square() - x_s.double());
+        let y_a = lambda
+            .zip(x_s)
+            .zip(x_a)
+            .zip(y_s)
+            .map(|(((lambda, x_s), x_a), y_s)| lambda * (x_s - x_a) - y_s);
+
+        let mut assign = |annotation: &'static str,
+                          column: Column<Advice>,
+                          value: Option<C::Base>|
+         -> Result<CellValue<C::Base>, Error> {
+            let cell = region.assign_advice(
+                || annotation,
+                column,
+                offset,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            Ok(CellValue::new(cell, value))
+        };
+
+        let x_s = assign("x_s", self.x_s, x_s)?;
+        let y_s = assign("y_s", self.y_s, y_s)?;
+        let x_d = assign("x_d", self.x_d, x_d)?;
+        let y_d = assign("y_d", self.y_d, y_d)?;
+        assign("lambda", self.advices[6], lambda)?;
+        let x_a = assign("x_a", self.advices[7], x_a)?;
+        let y_a = assign("y_a", self.advices[8], y_a)?;
+
+        Ok(([x_s, y_s, x_d, y_d], (X(x_a), Y(y_a))))
+    }
+
+    /// Performs the double-and-add steps that use incomplete addition, starting
+    /// in row `offset`. Returns the accumulator and the running sums `z_1, z_2`.
+    #[allow(clippy::type_complexity)]
+    fn incomplete(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        offset: usize,
+        table: &[CellValue<C::Base>; 4],
+        bits: &[Option<(bool, bool)>],
+        acc: (X<C::Base>, Y<C::Base>),
+    ) -> Result<(X<C::Base>, Y<C::Base>, Z<C::Base>, Z<C::Base>), Error> {
+        let num_bits = bits.len();
+
+        // Set q_mul_endo values
+        {
+            // q_mul_endo_1 = 1 on offset 0
+            self.q_mul_endo.0.enable(region, offset)?;
+
+            let offset = offset + 1;
+            // q_mul_endo_2 = 1 on all rows after offset 0, excluding the last row.
+            for idx in 0..(num_bits - 1) {
+                self.q_mul_endo.1.enable(region, offset + idx)?;
+            }
+
+            // q_mul_endo_3 = 1 on the last row.
+            self.q_mul_endo.2.enable(region, offset + num_bits - 1)?;
+        }
+
+        // Initialise double-and-add
+        let (mut x_a, mut y_a, mut z_1, mut z_2) = {
+            // Initialise the running sums for m_1 and m_2 to zero.
+            let mut z_init = |column: Column<Advice>| -> Result<CellValue<C::Base>, Error> {
+                let cell = region.assign_advice_from_constant(
+                    || "z_init = 0",
+                    column,
+                    offset,
+                    C::Base::zero(),
+                )?;
+                Ok(CellValue::new(cell, Some(C::Base::zero())))
+            };
+            let z_1 = z_init(self.z_1)?;
+            let z_2 = z_init(self.z_2)?;
+
+            // Initialise acc
+            let x_a = copy(region, || "starting x_a", self.x_a, offset + 1, &acc.0)?;
+            let y_a = copy(region, || "starting y_a", self.lambda1, offset, &acc.1)?;
+
+            (x_a, y_a.value(), z_1, z_2)
+        };
+
+        // Increase offset by 1; we used row 0 for initializing `z`.
+        let offset = offset + 1;
+
+        // The table is copied into the first row, and checked to be constant
+        // thereafter.
+        let table_columns = [self.x_s, self.y_s, self.x_d, self.y_d];
+        for (column, cell) in table_columns.iter().zip(table.iter()) {
+            copy(region, || "table", *column, offset, cell)?;
+        }
+        let table_values: Option<[C::Base; 4]> = match (
+            table[0].value(),
+            table[1].value(),
+            table[2].value(),
+            table[3].value(),
+        ) {
+            (Some(x_s), Some(y_s), Some(x_d), Some(y_d)) => Some([x_s, y_s, x_d, y_d]),
+            _ => None,
+        };
+
+        for (row, bits) in bits.iter().enumerate() {
+            // z_{i} = 2 * z_{i+1} + b_i
+            let mut running_sum = |z: CellValue<C::Base>,
+                                   column: Column<Advice>,
+                                   bit: Option<bool>|
+             -> Result<CellValue<C::Base>, Error> {
+                let z_val = z
+                    .value()
+                    .zip(bit)
+                    .map(|(z, bit)| C::Base::from_u64(2) * z + C::Base::from_u64(bit as u64));
+                let cell = region.assign_advice(
+                    || "z",
+                    column,
+                    row + offset,
+                    || z_val.ok_or(Error::SynthesisError),
+                )?;
+                Ok(CellValue::new(cell, z_val))
+            };
+            z_1 = running_sum(z_1, self.z_1, bits.map(|(b_1, _)| b_1))?;
+            z_2 = running_sum(z_2, self.z_2, bits.map(|(_, b_2)| b_2))?;
+
+            if row > 0 {
+                for (column, value) in table_columns.iter().zip(0..4) {
+                    region.assign_advice(
+                        || "table",
+                        *column,
+                        row + offset,
+                        || {
+                            table_values
+                                .map(|table| table[value])
+                                .ok_or(Error::SynthesisError)
+                        },
+                    )?;
+                }
+            }
+
+            let (x_q, y_q) = {
+                let q = table_values
+                    .zip(*bits)
+                    .map(|(table, bits)| q_point::<C>(table, bits));
+                (q.map(|q| q.0), q.map(|q| q.1))
+            };
+
+            // Compute λ1⋅(x_A − x_Q) = y_A − y_Q
+            let lambda1 = y_a
+                .zip(y_q)
+                .zip(x_a.value())
+                .zip(x_q)
+                .map(|(((y_a, y_q), x_a), x_q)| (y_a - y_q) * (x_a - x_q).invert().unwrap());
+            region.assign_advice(
+                || "lambda1",
+                self.lambda1,
+                row + offset,
+                || lambda1.ok_or(Error::SynthesisError),
+            )?;
+
+            // x_R = λ1^2 - x_A - x_Q
+            let x_r = lambda1
+                .zip(x_a.value())
+                .zip(x_q)
+                .map(|((lambda1, x_a), x_q)| lambda1 * lambda1 - x_a - x_q);
+
+            // λ2 = (2(y_A) / (x_A - x_R)) - λ1
+            let lambda2 =
+                lambda1
+                    .zip(y_a)
+                    .zip(x_a.value())
+                    .zip(x_r)
+                    .map(|(((lambda1, y_a), x_a), x_r)| {
+                        C::Base::from_u64(2) * y_a * (x_a - x_r).invert().unwrap() - lambda1
+                    });
+            region.assign_advice(
+                || "lambda2",
+                self.lambda2,
+                row + offset,
+                || lambda2.ok_or(Error::SynthesisError),
+            )?;
+
+            // Compute and assign `x_a` for the next row
+            let x_a_new = lambda2
+                .zip(x_a.value())
+                .zip(x_r)
+                .map(|((lambda2, x_a), x_r)| lambda2.square() - x_a - x_r);
+            y_a = lambda2
+                .zip(x_a.value())
+                .zip(x_a_new)
+                .zip(y_a)
+                .map(|(((lambda2, x_a), x_a_new), y_a)| lambda2 * (x_a - x_a_new) - y_a);
+            let x_a_cell = region.assign_advice(
+                || "x_a",
+                self.x_a,
+                row + offset + 1,
+                || x_a_new.ok_or(Error::SynthesisError),
+            )?;
+            x_a = CellValue::new(x_a_cell, x_a_new);
+        }
+
+        // Witness final y_a
+        let y_a = {
+            let cell = region.assign_advice(
+                || "y_a",
+                self.lambda1,
+                offset + num_bits,
+                || y_a.ok_or(Error::SynthesisError),
+            )?;
+            CellValue::new(cell, y_a)
+        };
+
+        // Move the running sums into `z_complete`, on the last two rows, for use in
+        // the complete steps.
+        let z_1 = copy(
+            region,
+            || "z_1",
+            self.z_complete,
+            offset + num_bits - 1,
+            &z_1,
+        )?;
+        let z_2 = copy(region, || "z_2", self.z_complete, offset + num_bits, &z_2)?;
+
+        Ok((X(x_a), Y(y_a), Z(z_1), Z(z_2)))
+    }
+
+    /// Performs the double-and-add steps that use complete addition, starting in
+    /// row `offset`, where `z_1` and `z_2` are in the `z_complete` column two rows
+    /// and one row before `offset` respectively.
+    ///
+    /// Returns the result, along with the cells containing `m_1` and `m_2`.
+    #[allow(clippy::type_complexity)]
+    fn complete(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        offset: usize,
+        table: &[CellValue<C::Base>; 4],
+        bits: &[Option<(bool, bool)>],
+        acc: (X<C::Base>, Y<C::Base>),
+        zs: (Z<C::Base>, Z<C::Base>),
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>, CellValue<C::Base>), Error> {
+        let mut acc = EccPoint {
+            x: *acc.0,
+            y: *acc.1,
+        };
+        let (mut z_1, mut z_2) = (*zs.0, *zs.1);
+
+        for (iter, bits) in bits.iter().enumerate() {
+            // Each iteration uses 3 rows: one to select the point, and two complete
+            // additions.
+            let row = offset + 3 * iter;
+
+            self.q_mul_endo_complete.enable(region, row)?;
+
+            let table_columns = [self.x_s, self.y_s, self.x_d, self.y_d];
+            for (column, cell) in table_columns.iter().zip(table.iter()) {
+                copy(region, || "table", *column, row, cell)?;
+            }
+
+            // z_{i} = 2 * z_{i+1} + b_i
+            let mut running_sum = |z: CellValue<C::Base>,
+                                   offset: usize,
+                                   bit: Option<bool>|
+             -> Result<CellValue<C::Base>, Error> {
+                let z_val = z
+                    .value()
+                    .zip(bit)
+                    .map(|(z, bit)| C::Base::from_u64(2) * z + C::Base::from_u64(bit as u64));
+                let cell = region.assign_advice(
+                    || "z",
+                    self.z_complete,
+                    offset,
+                    || z_val.ok_or(Error::SynthesisError),
+                )?;
+                Ok(CellValue::new(cell, z_val))
+            };
+            z_1 = running_sum(z_1, row + 1, bits.map(|(b_1, _)| b_1))?;
+            z_2 = running_sum(z_2, row + 2, bits.map(|(_, b_2)| b_2))?;
+
+            // Assign the selected point Q as the first input to complete addition.
+            let q = {
+                let table_values = table[0]
+                    .value()
+                    .zip(table[1].value())
+                    .zip(table[2].value())
+                    .zip(table[3].value())
+                    .map(|(((x_s, y_s), x_d), y_d)| [x_s, y_s, x_d, y_d]);
+                let q = table_values
+                    .zip(*bits)
+                    .map(|(table, bits)| q_point::<C>(table, bits));
+
+                let x_cell = region.assign_advice(
+                    || "x_q",
+                    self.add_config.x_p,
+                    row + 1,
+                    || q.map(|q| q.0).ok_or(Error::SynthesisError),
+                )?;
+                let y_cell = region.assign_advice(
+                    || "y_q",
+                    self.add_config.y_p,
+                    row + 1,
+                    || q.map(|q| q.1).ok_or(Error::SynthesisError),
+                )?;
+                EccPoint {
+                    x: CellValue::new(x_cell, q.map(|q| q.0)),
+                    y: CellValue::new(y_cell, q.map(|q| q.1)),
+                }
+            };
+
+            // Acc + Q
+            let tmp_acc = self.add_config.assign_region(&q, &acc, row + 1, region)?;
+
+            // Acc + Q + Acc
+            acc = self
+                .add_config
+                .assign_region(&acc, &tmp_acc, row + 2, region)?;
+        }
+
+        Ok((acc, z_1, z_2))
+    }
+
+    /// Constrains `k_1 + λ k_2 ≡ alpha (mod q)`, given the cells containing `m_1`
+    /// and `m_2`, and the witnessed lattice coefficients `c_i + 16`.
+    fn decomposition_check(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        alpha: CellValue<C::Base>,
+        (m_1, m_2): (CellValue<C::Base>, CellValue<C::Base>),
+        c: Option<[u128; 2]>,
+    ) -> Result<(), Error> {
+        let b = alpha.value().map(|v| bitrange_subset(v, 0..1));
+        let h_lo = alpha.value().map(|v| bitrange_subset(v, 1..254));
+        let h_hi = alpha.value().map(|v| bitrange_subset(v, 254..255));
+        let h_lo_prime = h_lo.zip(b).map(|(h_lo, b)| {
+            let two_pow_130 = C::Base::from_u128(1 << 65).square();
+            let t_p_plus_one_halved = C::Base::from_u128((C::T_P + 1) / 2);
+            h_lo + b + two_pow_130 - t_p_plus_one_halved
+        });
+
+        // Range-constrain h_lo to 253 bits, as 25 ten-bit words followed by a 3-bit
+        // word.
+        let h_lo = self.lookup_config.witness_check(
+            layouter.namespace(|| "Decompose h_lo"),
+            h_lo,
+            25,
+            false,
+        )?;
+        self.lookup_config.copy_short_check(
+            layouter.namespace(|| "h_lo[250..253]"),
+            h_lo[25],
+            3,
+        )?;
+
+        let h_lo_prime = self.lookup_config.witness_check(
+            layouter.namespace(|| "Decompose h_lo_prime"),
+            h_lo_prime,
+            13,
+            false,
+        )?;
+
+        // Range-constrain h_i = (c_i + 16 - l_i) / 2 to below 2^{w_i - 1}.
+        let [_, b_1, _, b_2] = C::GLV_BASIS;
+        let mut range_check_h = |i: usize, num_bits: usize| -> Result<CellValue<C::Base>, Error> {
+            let h = c.map(|c| C::Base::from_u128(c[i] >> 1));
+            let (num_words, short_bits) = (num_bits / LOOKUP_K, num_bits % LOOKUP_K);
+            let zs = self.lookup_config.witness_check(
+                layouter.namespace(|| format!("Decompose h_{}", i + 1)),
+                h,
+                num_words,
+                short_bits == 0,
+            )?;
+            if short_bits > 0 {
+                self.lookup_config.copy_short_check(
+                    layouter.namespace(|| format!("h_{} short check", i + 1)),
+                    zs[num_words],
+                    short_bits,
+                )?;
+            }
+            Ok(zs[0])
+        };
+        let h_1 = range_check_h(0, c_bits(b_2) - 1)?;
+        let h_2 = range_check_h(1, c_bits(b_1) - 1)?;
+
+        layouter.assign_region(
+            || "GLV decomposition",
+            |mut region| {
+                let offset = 0;
+
+                // Enable `q_mul_endo_decompose` selector
+                self.q_mul_endo_decompose.enable(&mut region, offset)?;
+
+                copy(&mut region, || "alpha", self.advices[0], offset, &alpha)?;
+                region.assign_advice(
+                    || "b",
+                    self.advices[1],
+                    offset,
+                    || b.ok_or(Error::SynthesisError),
+                )?;
+                region.assign_advice(
+                    || "h_hi",
+                    self.advices[2],
+                    offset,
+                    || h_hi.ok_or(Error::SynthesisError),
+                )?;
+                copy(&mut region, || "h_lo", self.advices[3], offset, &h_lo[0])?;
+                copy(
+                    &mut region,
+                    || "h_lo_prime",
+                    self.advices[4],
+                    offset,
+                    &h_lo_prime[0],
+                )?;
+                copy(
+                    &mut region,
+                    || "z_13_h_lo_prime",
+                    self.advices[5],
+                    offset,
+                    &h_lo_prime[13],
+                )?;
+                copy(&mut region, || "m_1", self.advices[6], offset, &m_1)?;
+                copy(&mut region, || "m_2", self.advices[7], offset, &m_2)?;
+
+                region.assign_advice(
+                    || "l_1",
+                    self.advices[0],
+                    offset + 1,
+                    || {
+                        c.map(|c| C::Base::from_u128(c[0] & 1))
+                            .ok_or(Error::SynthesisError)
+                    },
+                )?;
+                copy(&mut region, || "h_1", self.advices[1], offset + 1, &h_1)?;
+                region.assign_advice(
+                    || "l_2",
+                    self.advices[2],
+                    offset + 1,
+                    || {
+                        c.map(|c| C::Base::from_u128(c[1] & 1))
+                            .ok_or(Error::SynthesisError)
+                    },
+                )?;
+                copy(&mut region, || "h_2", self.advices[3], offset + 1, &h_2)?;
+
+                Ok(())
+            },
+        )
+    }
+}
+
+/// Returns the point `[d_1] P + [d_2] φ(P)` for the bits `(b_1, b_2)`, where
+/// `d_i = 2 b_i - 1`, given `[x_s, y_s, x_d, y_d]`.
+fn q_point<C: EccCurve>(table: [C::Base; 4], (b_1, b_2): (bool, bool)) -> (C::Base, C::Base) {
+    let [x_s, y_s, x_d, y_d] = table;
+    let (x, y) = if b_1 == b_2 { (x_s, y_s) } else { (x_d, y_d) };
+    if b_1 {
+        (x, y)
+    } else {
+        (x, -y)
+    }
+}
+
+/// Number of bits to which $c_i + 16$ is range-constrained, where `b` is the
+/// lattice basis entry $b_2$ for $c_1$, or $b_1$ for $c_2$.
+///
+/// Since $q = a_1 b_2 + a_2 b_1$, Babai rounding gives $|c_1| \lesssim b_2$ and
+/// $|c_2| \lesssim b_1$.
+fn c_bits(b: u128) -> usize {
+    (128 - b.leading_zeros()) as usize
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Decomposition {
+    // m_1, m_2, where k_i = 2^128 + 2 m_i + 1.
+    m: [u128; 2],
+    // c_1 + 16, c_2 + 16.
+    c: [u128; 2],
+}
+
+/// Decomposes `alpha` as `k_1 + λ k_2 ≡ alpha (mod q)`, where each `k_i` is odd and
+/// in the range `[2^128 + 1, 3⋅2^128)`.
+fn decompose_for_mul_endo<C: EccCurve>(alpha: C::Base) -> Decomposition {
+    let [a_1, b_1, a_2, b_2] = C::GLV_BASIS;
+    let u256 = |x: u128| U256::from_little_endian(&x.to_le_bytes());
+    let one = U256::from(1u64);
+    let q = (one << 254) + u256(C::T_Q);
+    let two_pow_129 = one << 129;
+    let alpha_int = U256::from_little_endian(&alpha.to_bytes());
+
+    // Babai rounding of (alpha - 2^129, -2^129) in the lattice basis gives
+    //   c_1 ≈ (alpha b_2 + 2^129 (a_2 - b_2)) / q
+    //   c_2 ≈ (alpha b_1 - 2^129 (a_1 + b_1)) / q
+    // We add 8q to each numerator to keep it positive, and then search the nearby
+    // coefficients for ones that give k_1, k_2 in range.
+    let eight_q = q.full_mul(U256::from(8u64));
+    let num_1 = alpha_int.full_mul(u256(b_2)) + two_pow_129.full_mul(u256(a_2)) + eight_q
+        - two_pow_129.full_mul(u256(b_2));
+    let num_2 =
+        alpha_int.full_mul(u256(b_1)) + eight_q - two_pow_129.full_mul(u256(a_1) + u256(b_1));
+    let low_u128 = |x: U512| (((x >> 64).low_u64() as u128) << 64) | (x.low_u64() as u128);
+    let q_wide = q.full_mul(one);
+    // c_i + 16 ≈ num_i / q - 8 + 16
+    let (e_1, e_2) = (low_u128(num_1 / q_wide) + 8, low_u128(num_2 / q_wide) + 8);
+
+    // Returns m such that k = 2^128 + 2m + 1, if k is odd and in range.
+    let two_pow_128 = C::Base::from_u128(1 << 64).square();
+    let half_scalar = |k: C::Base| -> Option<u128> {
+        let m = (k - two_pow_128 - C::Base::one()) * C::Base::TWO_INV;
+        let bytes = m.to_bytes();
+        if bytes[16..].iter().all(|byte| *byte == 0) {
+            Some(
+                bytes[..16]
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, byte| (acc << 8) | *byte as u128),
+            )
+        } else {
+            None
+        }
+    };
+
+    let constant = |x: u128| C::Base::from_u128(x);
+    let offset = C::Base::from_u64(C_OFFSET);
+    for c_1 in (e_1 - 2)..(e_1 + 4) {
+        for c_2 in (e_2 - 2)..(e_2 + 4) {
+            let (c_1_val, c_2_val) = (constant(c_1) - offset, constant(c_2) - offset);
+            let k_1 = alpha - c_1_val * constant(a_1) - c_2_val * constant(a_2);
+            let k_2 = c_1_val * constant(b_1) - c_2_val * constant(b_2);
+            if let (Some(m_1), Some(m_2)) = (half_scalar(k_1), half_scalar(k_2)) {
+                return Decomposition {
+                    m: [m_1, m_2],
+                    c: [c_1, c_2],
+                };
+            }
+        }
+    }
+
+    unreachable!("the lattice basis should be reduced")
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::{
+        arithmetic::{CurveAffine, FieldExt},
+        pallas, vesta,
+    };
+
+    use super::decompose_for_mul_endo;
+    use crate::{
+        chip::{base_to_scalar, EccChip, EccCurve},
+        gadget::{FixedPoints, NonIdentityPoint, Point},
+    };
+    use utilities::UtilitiesInstructions;
+
+    pub fn test_mul_endo<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate a random non-identity point P
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
+        let p = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+
+        let column = chip.config().advices[0];
+
+        let scalars = [
+            ("random [a]B", pallas::Base::rand()),
+            ("[0]B", pallas::Base::zero()),
+            ("[1]B", pallas::Base::one()),
+            // The largest possible base field element.
+            ("[-1]B", -pallas::Base::one()),
+        ];
+
+        for (name, scalar_val) in scalars.iter() {
+            let scalar =
+                chip.load_private(layouter.namespace(|| *name), column, Some(*scalar_val))?;
+            let (result, _) = p.mul_endo(layouter.namespace(|| *name), &scalar)?;
+
+            let expected = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "expected point"),
+                Some((p_val * base_to_scalar::<pallas::Affine>(*scalar_val)).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| *name), &expected)?;
+        }
+
+        Ok(())
+    }
+
+    fn check_endomorphism<C: EccCurve>() {
+        let p = C::CurveExt::random(rand::rngs::OsRng).to_affine();
+        let coords = p.coordinates().unwrap();
+        let phi_p = C::from_xy(*coords.x() * C::ZETA, *coords.y()).unwrap();
+        assert_eq!((p * C::LAMBDA).to_affine(), phi_p);
+
+        let [a_1, b_1, a_2, b_2] = C::GLV_BASIS;
+        let scalar = |x: u128| C::ScalarField::from_u128(x);
+        assert_eq!(scalar(a_1), C::LAMBDA * scalar(b_1));
+        assert_eq!(scalar(a_2), -C::LAMBDA * scalar(b_2));
+    }
+
+    fn check_decomposition<C: EccCurve>(alpha: C::Base) {
+        let decomposition = decompose_for_mul_endo::<C>(alpha);
+        let two_pow_128 = C::ScalarField::from_u128(1 << 64).square();
+        let k =
+            |m: u128| two_pow_128 + C::ScalarField::from_u128(m).double() + C::ScalarField::one();
+        let (k_1, k_2) = (k(decomposition.m[0]), k(decomposition.m[1]));
+        assert_eq!(k_1 + C::LAMBDA * k_2, base_to_scalar::<C>(alpha));
+    }
+
+    #[test]
+    fn endomorphism() {
+        check_endomorphism::<pallas::Affine>();
+        check_endomorphism::<vesta::Affine>();
+    }
+
+    #[test]
+    fn decomposition() {
+        fn check<C: EccCurve>() {
+            // The range of alpha, including the extremes and the values around the
+            // scalar field modulus q = 2^254 + t_q.
+            let t_q = C::Base::from_u128(C::T_Q);
+            let two_pow_254 = C::Base::from_u128(1 << 127).square().double();
+            for alpha in [
+                C::Base::zero(),
+                C::Base::one(),
+                -C::Base::one(),
+                two_pow_254 + t_q - C::Base::one(),
+                two_pow_254 + t_q,
+                two_pow_254 + t_q + C::Base::one(),
+            ]
+            .iter()
+            {
+                check_decomposition::<C>(*alpha);
+            }
+            for _ in 0..1000 {
+                check_decomposition::<C>(C::Base::rand());
+            }
+        }
+        check::<pallas::Affine>();
+        check::<vesta::Affine>();
+    }
+}
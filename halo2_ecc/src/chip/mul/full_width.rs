@@ -0,0 +1,162 @@
+use super::super::{copy, CellValue, EccConfig, EccCurve, EccScalarVarFullWidth, Var, LOOKUP_K};
+use utilities::lookup_range_check::LookupRangeCheckConfig;
+
+use halo2::{
+    circuit::Layouter,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+use std::iter;
+
+pub struct Config<C: EccCurve> {
+    // Selector to check that the decomposition of a full-width scalar is canonical.
+    q_mul_full_width_canon: Selector,
+    // 10-bit lookup table
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    // Advice columns
+    advices: [Column<Advice>; 2],
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_mul_full_width_canon: ecc_config.q_mul_full_width_canon,
+            lookup_config: ecc_config.lookup_config.clone(),
+            // Use advice columns that don't conflict with the lookup range checks.
+            advices: [ecc_config.advices[6], ecc_config.advices[7]],
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // We want to enforce canonicity of the 255-bit decomposition of `k`.
+        // That is, we want to check that 0 ≤ k < q, where q is the scalar
+        // field modulus q = 2^254 + t_q. Note that t_q < 2^130.
+        //
+        // If the MSB k_254 = 1, then:
+        //      - bits k_{130..=253} are all zero, i.e. z_130 = 2^124; and
+        //      - the low 130 bits k_lo satisfy k_lo < t_q. To enforce this:
+        //          - 0 ≤ k_lo + 2^130 - t_q < 2^130
+        //              => 13 ten-bit lookups of k_lo + 2^130 - t_q
+        //              => z_13_k_lo_prime = 0
+        meta.create_gate("full-width scalar canonicity checks", |meta| {
+            let q_mul_full_width_canon = meta.query_selector(self.q_mul_full_width_canon);
+
+            let two_pow_124 = Expression::Constant(C::Base::from_u128(1 << 124));
+            let two_pow_130 =
+                two_pow_124.clone() * Expression::Constant(C::Base::from_u128(1 << 6));
+            let t_q = Expression::Constant(C::Base::from_u128(C::T_Q));
+
+            let z_0 = meta.query_advice(self.advices[0], Rotation::prev());
+            let z_130 = meta.query_advice(self.advices[0], Rotation::cur());
+            let z_13_k_lo_prime = meta.query_advice(self.advices[0], Rotation::next());
+
+            let k_254 = meta.query_advice(self.advices[1], Rotation::prev());
+            let k_lo_prime = meta.query_advice(self.advices[1], Rotation::cur());
+
+            // k_lo = z_0 - 2^130 z_130
+            let k_lo = z_0 - z_130.clone() * two_pow_130.clone();
+
+            // k_lo_prime = k_lo + 2^130 - t_q
+            let k_lo_prime_check = k_lo_prime - (k_lo + two_pow_130 - t_q);
+
+            // k_254 * (z_130 - 2^124) = 0
+            let hi_zero = k_254.clone() * (z_130 - two_pow_124);
+
+            // k_254 * z_13_k_lo_prime = 0
+            let lo_check = k_254 * z_13_k_lo_prime;
+
+            iter::empty()
+                .chain(Some(k_lo_prime_check))
+                .chain(Some(hi_zero))
+                .chain(Some(lo_check))
+                .map(|poly| q_mul_full_width_canon.clone() * poly)
+                .collect::<Vec<_>>()
+        });
+    }
+
+    pub(super) fn canonicity_check(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        scalar: &EccScalarVarFullWidth<C>,
+    ) -> Result<(), Error> {
+        let zs = &scalar.running_sum;
+
+        // Witness k_lo_prime = k_lo + 2^130 - t_q, and decompose it using
+        // thirteen 10-bit lookups.
+        let (k_lo_prime, z_13_k_lo_prime) = {
+            let k_lo_prime = zs[0].value().zip(zs[130].value()).map(|(z_0, z_130)| {
+                let two_pow_130 = C::Base::from_u128(1 << 65).square();
+                let t_q = C::Base::from_u128(C::T_Q);
+                z_0 - z_130 * two_pow_130 + two_pow_130 - t_q
+            });
+
+            // Number of k-bit words we can use in the lookup decomposition.
+            let num_words = 130 / LOOKUP_K;
+            assert!(num_words * LOOKUP_K == 130);
+
+            let zs = self.lookup_config.witness_check(
+                layouter.namespace(|| "Lookup range check k_lo + 2^130 - t_q"),
+                k_lo_prime,
+                num_words,
+                false,
+            )?;
+
+            (zs[0], zs[num_words])
+        };
+
+        layouter.assign_region(
+            || "full-width scalar canonicity check",
+            |mut region| {
+                let offset = 0;
+
+                // Enable canonicity check gate
+                self.q_mul_full_width_canon
+                    .enable(&mut region, offset + 1)?;
+
+                // Copy `z_0`
+                copy(&mut region, || "copy z_0", self.advices[0], offset, &zs[0])?;
+
+                // Copy `z_130`
+                copy(
+                    &mut region,
+                    || "copy z_130",
+                    self.advices[0],
+                    offset + 1,
+                    &zs[130],
+                )?;
+
+                // Copy `z_13_k_lo_prime`
+                copy(
+                    &mut region,
+                    || "copy z_13_k_lo_prime",
+                    self.advices[0],
+                    offset + 2,
+                    &z_13_k_lo_prime,
+                )?;
+
+                // Copy `k_254` = z_254
+                copy(
+                    &mut region,
+                    || "copy k_254",
+                    self.advices[1],
+                    offset,
+                    &zs[254],
+                )?;
+
+                // Copy `k_lo_prime`
+                copy(
+                    &mut region,
+                    || "copy k_lo + 2^130 - t_q",
+                    self.advices[1],
+                    offset + 1,
+                    &k_lo_prime,
+                )?;
+
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,1023 @@
+use super::super::{
+    add, add_incomplete, copy, double, neg, select, CellValue, EccConfig, EccCurve, EccPoint,
+    NonIdentityEccPoint, Var, L_SCALAR,
+};
+use super::{decompose_for_scalar_mul, overflow};
+use utilities::{bool_check, range_check};
+
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::{Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, VirtualCells},
+    poly::Rotation,
+};
+
+use std::array;
+
+/// Number of bits in each window of the shared double-and-add.
+const WINDOW_SIZE: usize = 2;
+
+/// Number of windows, covering bits k_{253} to k_{2} inclusive. Bit k_{254} is
+/// used to initialize the accumulator, and bits k_{1} and k_{0} are processed
+/// using complete addition.
+const NUM_WINDOWS: usize = (L_SCALAR - 3) / WINDOW_SIZE;
+
+/// Number of ladders assigned side by side in the double-and-add region.
+const NUM_LADDERS: usize = 3;
+
+/// Number of consecutive windows processed by each ladder.
+const WINDOWS_PER_LADDER: usize = NUM_WINDOWS / NUM_LADDERS;
+
+/// The window after which the running sum is z_{130}, which is used in the
+/// overflow check.
+const Z_130_WINDOW: usize = (L_SCALAR - 3 - 130) / WINDOW_SIZE;
+
+// Cells used by each ladder in an addition step.
+const CELL_Z: usize = 0;
+const CELL_Z_PREV: usize = 1;
+const CELL_X_A: usize = 2;
+const CELL_LAMBDA: usize = 3;
+const CELL_INV: usize = 4;
+const NUM_ADD_CELLS: usize = 5;
+
+/// Variable-base multi-scalar multiplication using Straus' interleaved
+/// double-and-add, with signed 2-bit windows.
+///
+/// Each scalar `alpha_i` is decomposed as `k_i = alpha_i + t_q` into 255 bits,
+/// exactly as in single variable-base scalar mul, and the overflow check is
+/// reused to ensure that `k_i` is the canonical decomposition. Bits k_{253} to
+/// k_{2} are split into 126 two-bit windows `u ∈ [0..4)`, each of which selects
+/// the signed digit `d = 2u - 3 ∈ {-3, -1, 1, 3}` from the per-term table
+/// `{P_i, [3] P_i}`. The accumulator is shared across all terms, so that each
+/// doubling is only performed once instead of once per term:
+///
+/// ```text
+/// Acc := Σ_i [3 + 2 k_{i,254}] P_i
+/// for each window m from 0 to 125:
+///     Acc := [4] Acc
+///     for each i:
+///         Acc := Acc + [2 u_{i,m} - 3] P_i      (incomplete addition)
+/// Acc := [2] Acc + Σ_i [2 k_{i,1} + k_{i,0} - 2] P_i   (complete addition)
+/// ```
+///
+/// The result is `Σ_i [k_i + 2^254] P_i = Σ_i [alpha_i + q] P_i = Σ_i [alpha_i] P_i`.
+///
+/// The windows are processed by three ladders of 42 windows each, where each
+/// ladder starts from the output of the previous one. Since the ladders do not
+/// share any cells within a row, they are assigned side by side, and each row of
+/// the double-and-add region serves three windows at once:
+///
+/// ```text
+/// |     a0,a1     |   a2,a3   |   a4,a5   |  a6..a8   |                           |
+/// -------------------------------------------------------------------------------
+/// | Acc_0 input   |Acc_1 input|Acc_2 input| inv(y_in) |                           |
+/// | x_a, λ1, λ2 (ladder 0) | x_a, λ1, λ2 (ladder 1) | x_a, λ1, λ2 (ladder 2) | q_msm_quad
+/// | x_{P_0},y_{P_0} | z, z_prev, x_a, λ, inv for each ladder (2 rows)  | q_msm_add
+/// |x_{3P_0},y_{3P_0}|                                                  |
+/// | x_{P_1},y_{P_1} |                       ...                        | q_msm_add
+/// |      ...        |                       ...                        |
+/// | Acc_0 output  |Acc_1 output|Acc_2 output|                         | q_msm_output
+/// ```
+///
+/// The y-coordinate of the accumulator is never assigned inside a ladder; it is
+/// derived from the cells of the addition step that consumes it. The input point
+/// of each ladder is constrained to have a nonzero y-coordinate, which ensures that
+/// the doublings are well-defined. Each incomplete addition is constrained to have
+/// distinct x-coordinates, so the honest prover fails (with negligible probability
+/// for independent bases) if it encounters an exceptional case.
+pub struct Config<C: EccCurve> {
+    // Selector for the first quadrupling of each ladder, which takes its input
+    // from the input row.
+    q_msm_quad_init: Selector,
+    // Selector for the quadrupling at the start of each window.
+    q_msm_quad: Selector,
+    // Selector for the range check and incomplete addition of a window.
+    q_msm_add: Selector,
+    // Selector chaining each addition step to the next one in the same window.
+    q_msm_chain: Selector,
+    // Selector for the output row of the ladders.
+    q_msm_output: Selector,
+    // Selector used to decompose the final window k_{1}, k_{0}.
+    q_msm_lsb: Selector,
+    // Advice columns
+    advices: [Column<Advice>; 10],
+    // Configuration used in complete addition
+    add_config: add::Config<C>,
+    // Configuration used in incomplete addition
+    add_incomplete_config: add_incomplete::Config<C>,
+    // Configuration used in point doubling
+    double_config: double::Config<C>,
+    // Configuration used in point negation
+    neg_config: neg::Config<C>,
+    // Configuration used in point selection
+    select_config: select::Config<C>,
+    // Configuration used to check for overflow
+    overflow_config: overflow::Config<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_msm_quad_init: ecc_config.q_msm_quad_init,
+            q_msm_quad: ecc_config.q_msm_quad,
+            q_msm_add: ecc_config.q_msm_add,
+            q_msm_chain: ecc_config.q_msm_chain,
+            q_msm_output: ecc_config.q_msm_output,
+            q_msm_lsb: ecc_config.q_msm_lsb,
+            advices: ecc_config.advices,
+            add_config: ecc_config.into(),
+            add_incomplete_config: ecc_config.into(),
+            double_config: ecc_config.into(),
+            neg_config: ecc_config.into(),
+            select_config: ecc_config.into(),
+            overflow_config: ecc_config.into(),
+        }
+    }
+}
+
+/// Expressions for an addition step `R = A + Q` of a ladder.
+struct AddStep<F: FieldExt> {
+    // x-coordinate of the accumulator A
+    x_a: Expression<F>,
+    // y-coordinate of the accumulator A, derived from λ and Q
+    y_a: Expression<F>,
+    // x-coordinate of the selected point Q
+    x_q: Expression<F>,
+    // x-coordinate of R
+    x_r: Expression<F>,
+    // y-coordinate of R
+    y_r: Expression<F>,
+    // Window `u = z - 4 z_prev`
+    u: Expression<F>,
+    // inv = 1 / (x_a - x_q)
+    inv: Expression<F>,
+}
+
+/// Expressions for a quadrupling `C = [4] A` of a ladder.
+struct QuadStep<F: FieldExt> {
+    // x-coordinate of the accumulator A
+    x_a: Expression<F>,
+    // x-coordinate of C
+    x_c: Expression<F>,
+    // y-coordinate of C
+    y_c: Expression<F>,
+    // Constraints on λ1 and λ2
+    checks: [Expression<F>; 2],
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        meta.create_gate("MSM initial quadrupling", |meta| {
+            let q_msm_quad_init = meta.query_selector(self.q_msm_quad_init);
+            let one = Expression::Constant(C::Base::one());
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                let x_in = meta.query_advice(self.advices[2 * ladder], Rotation::prev());
+                let y_in = meta.query_advice(self.advices[2 * ladder + 1], Rotation::prev());
+                let inv_y = meta.query_advice(self.advices[6 + ladder], Rotation::prev());
+
+                let quad = self.query_quad(meta, ladder, y_in.clone());
+                let next = self.query_add_step(meta, ladder, 1);
+
+                // y_in ≠ 0, so that the doublings are well-defined.
+                constraints.push(inv_y * y_in - one.clone());
+                // A is the input of the ladder.
+                constraints.push(quad.x_a - x_in);
+                constraints.extend(array::IntoIter::new(quad.checks));
+                // C is the accumulator of the first addition step.
+                constraints.push(next.x_a - quad.x_c);
+                constraints.push(next.y_a - quad.y_c);
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_msm_quad_init.clone() * poly)
+        });
+
+        meta.create_gate("MSM quadrupling", |meta| {
+            let q_msm_quad = meta.query_selector(self.q_msm_quad);
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                // A is the output of the last addition step of the previous window.
+                let prev = self.query_add_step(meta, ladder, -2);
+                let quad = self.query_quad(meta, ladder, prev.y_r);
+                let next = self.query_add_step(meta, ladder, 1);
+
+                constraints.push(quad.x_a - prev.x_r);
+                constraints.extend(array::IntoIter::new(quad.checks));
+                // C is the accumulator of the first addition step.
+                constraints.push(next.x_a - quad.x_c);
+                constraints.push(next.y_a - quad.y_c);
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_msm_quad.clone() * poly)
+        });
+
+        meta.create_gate("MSM window addition", |meta| {
+            let q_msm_add = meta.query_selector(self.q_msm_add);
+            let one = Expression::Constant(C::Base::one());
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                let step = self.query_add_step(meta, ladder, 0);
+
+                // u ∈ [0..4)
+                constraints.push(range_check(step.u, 1 << WINDOW_SIZE));
+                // inv⋅(x_a - x_q) = 1, so that the incomplete addition is well-defined.
+                constraints.push(step.inv * (step.x_a - step.x_q) - one.clone());
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_msm_add.clone() * poly)
+        });
+
+        meta.create_gate("MSM addition chain", |meta| {
+            let q_msm_chain = meta.query_selector(self.q_msm_chain);
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                let cur = self.query_add_step(meta, ladder, 0);
+                let next = self.query_add_step(meta, ladder, 2);
+
+                // The output of this addition step is the accumulator of the next.
+                constraints.push(next.x_a - cur.x_r);
+                constraints.push(next.y_a - cur.y_r);
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_msm_chain.clone() * poly)
+        });
+
+        meta.create_gate("MSM output", |meta| {
+            let q_msm_output = meta.query_selector(self.q_msm_output);
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                let prev = self.query_add_step(meta, ladder, -2);
+                let x_out = meta.query_advice(self.advices[2 * ladder], Rotation::cur());
+                let y_out = meta.query_advice(self.advices[2 * ladder + 1], Rotation::cur());
+
+                constraints.push(x_out - prev.x_r);
+                constraints.push(y_out - prev.y_r);
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_msm_output.clone() * poly)
+        });
+
+        meta.create_gate("MSM final window", |meta| {
+            let q_msm_lsb = meta.query_selector(self.q_msm_lsb);
+
+            let z_2 = meta.query_advice(self.advices[5], Rotation::cur());
+            let k_1 = meta.query_advice(self.advices[6], Rotation::cur());
+            let k_0 = meta.query_advice(self.advices[7], Rotation::cur());
+            let z_0 = meta.query_advice(self.advices[8], Rotation::cur());
+
+            // z_0 = 4⋅z_2 + 2⋅k_1 + k_0
+            let running_sum_check = z_0
+                - (z_2 * C::Base::from_u64(4) + k_1.clone() * C::Base::from_u64(2) + k_0.clone());
+
+            array::IntoIter::new([bool_check(k_1), bool_check(k_0), running_sum_check])
+                .map(move |poly| q_msm_lsb.clone() * poly)
+        });
+    }
+
+    /// Returns the column and row offset, relative to the first row of an addition
+    /// step, of the given cell of a ladder. The cells of the three ladders are packed
+    /// into `advices[2..10]` over the two rows of the step.
+    fn add_cell(&self, ladder: usize, cell: usize) -> (Column<Advice>, usize) {
+        let idx = NUM_ADD_CELLS * ladder + cell;
+        (self.advices[2 + idx % 8], idx / 8)
+    }
+
+    /// Queries the addition step of the given ladder which starts at `rotation`.
+    ///
+    /// The point `Q = [2u - 3] P` is selected from the table `P`, `[3] P` in
+    /// `advices[0..2]` of the two rows of the step:
+    /// - `x_q = x_p + (u - 1)(u - 2)/2 ⋅ (x_3p - x_p)`
+    /// - `y_q = d ⋅ ((27 y_p - y_3p) + d^2 ⋅ (y_3p - 3 y_p)) / 24`, where `d = 2u - 3`.
+    fn query_add_step(
+        &self,
+        meta: &mut VirtualCells<'_, C::Base>,
+        ladder: usize,
+        rotation: i32,
+    ) -> AddStep<C::Base> {
+        let mut query = |cell| {
+            let (column, row) = self.add_cell(ladder, cell);
+            meta.query_advice(column, Rotation(rotation + row as i32))
+        };
+        let z = query(CELL_Z);
+        let z_prev = query(CELL_Z_PREV);
+        let x_a = query(CELL_X_A);
+        let lambda = query(CELL_LAMBDA);
+        let inv = query(CELL_INV);
+
+        let x_p = meta.query_advice(self.advices[0], Rotation(rotation));
+        let y_p = meta.query_advice(self.advices[1], Rotation(rotation));
+        let x_3p = meta.query_advice(self.advices[0], Rotation(rotation + 1));
+        let y_3p = meta.query_advice(self.advices[1], Rotation(rotation + 1));
+
+        let one = Expression::Constant(C::Base::one());
+        let two = Expression::Constant(C::Base::from_u64(2));
+        let three = Expression::Constant(C::Base::from_u64(3));
+
+        // u = z - 4⋅z_prev
+        let u = z - z_prev * C::Base::from_u64(4);
+        // d = 2u - 3
+        let d = u.clone() * C::Base::from_u64(2) - three;
+
+        let x_q =
+            x_p.clone() + (u.clone() - one) * (u.clone() - two) * (x_3p - x_p) * C::Base::TWO_INV;
+        let y_q = d.clone()
+            * ((y_p.clone() * C::Base::from_u64(27) - y_3p.clone())
+                + d.square() * (y_3p - y_p * C::Base::from_u64(3)))
+            * C::Base::from_u64(24).invert().unwrap();
+
+        // y_a = λ⋅(x_a - x_q) + y_q
+        let y_a = lambda.clone() * (x_a.clone() - x_q.clone()) + y_q;
+        // x_r = λ^2 - x_a - x_q
+        let x_r = lambda.clone().square() - x_a.clone() - x_q.clone();
+        // y_r = λ⋅(x_a - x_r) - y_a
+        let y_r = lambda * (x_a.clone() - x_r.clone()) - y_a.clone();
+
+        AddStep {
+            x_a,
+            y_a,
+            x_q,
+            x_r,
+            y_r,
+            u,
+            inv,
+        }
+    }
+
+    /// Queries the quadrupling `C = [4] A` of the given ladder on the current row,
+    /// where `y_a` is the y-coordinate of `A`. This is computed as two doublings
+    /// `B = [2] A` and `C = [2] B`.
+    fn query_quad(
+        &self,
+        meta: &mut VirtualCells<'_, C::Base>,
+        ladder: usize,
+        y_a: Expression<C::Base>,
+    ) -> QuadStep<C::Base> {
+        let x_a = meta.query_advice(self.advices[3 * ladder], Rotation::cur());
+        let lambda1 = meta.query_advice(self.advices[3 * ladder + 1], Rotation::cur());
+        let lambda2 = meta.query_advice(self.advices[3 * ladder + 2], Rotation::cur());
+
+        let two = C::Base::from_u64(2);
+        let three = C::Base::from_u64(3);
+
+        // x_b = λ1^2 - 2⋅x_a
+        let x_b = lambda1.clone().square() - x_a.clone() * two;
+        // y_b = λ1⋅(x_a - x_b) - y_a
+        let y_b = lambda1.clone() * (x_a.clone() - x_b.clone()) - y_a.clone();
+        // x_c = λ2^2 - 2⋅x_b
+        let x_c = lambda2.clone().square() - x_b.clone() * two;
+        // y_c = λ2⋅(x_b - x_c) - y_b
+        let y_c = lambda2.clone() * (x_b.clone() - x_c.clone()) - y_b.clone();
+
+        // 2⋅y_a⋅λ1 = 3⋅x_a^2
+        let lambda1_check = y_a * lambda1 * two - x_a.clone().square() * three;
+        // 2⋅y_b⋅λ2 = 3⋅x_b^2
+        let lambda2_check = y_b * lambda2 * two - x_b.square() * three;
+
+        QuadStep {
+            x_a,
+            x_c,
+            y_c,
+            checks: [lambda1_check, lambda2_check],
+        }
+    }
+
+    pub(crate) fn assign(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        terms: &[(CellValue<C::Base>, NonIdentityEccPoint<C>)],
+    ) -> Result<EccPoint<C>, Error> {
+        assert!(!terms.is_empty(), "MSM must have at least one term.");
+
+        // Decompose each scalar as `k = alpha + t_q` (big-endian bit order).
+        let bits: Vec<_> = terms
+            .iter()
+            .map(|(alpha, _)| decompose_for_scalar_mul::<C>(alpha.value()))
+            .collect();
+
+        // Compute the table `P`, `[3] P` and the initial point `[3 + 2 k_254] P`
+        // for each term.
+        let tables = terms
+            .iter()
+            .zip(bits.iter())
+            .enumerate()
+            .map(|(idx, ((_, base), bits))| {
+                layouter.assign_region(
+                    || format!("MSM table {}", idx),
+                    |mut region| self.assign_table(&mut region, base, bits[0]),
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        // Initialize the accumulator as `Σ_i [3 + 2 k_{i,254}] P_i`.
+        let acc = layouter.assign_region(
+            || "MSM initialize accumulator",
+            |mut region| {
+                let mut acc = tables[0].init.clone();
+                for (idx, table) in tables.iter().enumerate().skip(1) {
+                    acc = self.add_config.assign_region(
+                        &table.init,
+                        &acc,
+                        2 * (idx - 1),
+                        &mut region,
+                    )?;
+                }
+                Ok(acc)
+            },
+        )?;
+
+        // Shared double-and-add over the windows k_{253} to k_{2}.
+        let (acc, running_sums) = layouter.assign_region(
+            || "MSM shared double-and-add",
+            |mut region| self.assign_ladders(&mut region, &acc, &tables, &bits),
+        )?;
+
+        // Acc := [2] Acc
+        let mut acc = layouter.assign_region(
+            || "MSM double accumulator",
+            |mut region| self.double_config.assign_region(&acc, 0, &mut region),
+        )?;
+
+        // Process the final window k_{1}, k_{0} of each term using complete addition,
+        // and check that each decomposition does not overflow.
+        for (idx, (((alpha, _), bits), (table, (z_130, z_2)))) in terms
+            .iter()
+            .zip(bits.iter())
+            .zip(tables.iter().zip(running_sums.iter()))
+            .enumerate()
+        {
+            let (points, k_1, k_0, z_0) = layouter.assign_region(
+                || format!("MSM final window {}", idx),
+                |mut region| self.assign_final_window(&mut region, table, *z_2, bits),
+            )?;
+
+            acc = layouter.assign_region(
+                || format!("MSM final addition {}", idx),
+                |mut region| {
+                    // Select [2 k_1 + k_0 - 2] P from [-2P, -P, 𝒪, P].
+                    let selected =
+                        self.select_config
+                            .assign_table(&[k_0, k_1], &points, &mut region)?;
+                    self.add_config
+                        .assign_region(&selected, &acc, 3, &mut region)
+                },
+            )?;
+
+            self.overflow_config.overflow_check(
+                layouter.namespace(|| format!("MSM overflow check {}", idx)),
+                *alpha,
+                z_0,
+                *z_130,
+                table.k_254,
+            )?;
+        }
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::{Curve, Group};
+
+            let real_msm =
+                terms
+                    .iter()
+                    .fold(Some(C::CurveExt::identity()), |acc, (alpha, base)| {
+                        let alpha = alpha.value().map(super::super::base_to_scalar::<C>);
+                        acc.zip(base.point())
+                            .zip(alpha)
+                            .map(|((acc, base), alpha)| acc + base * alpha)
+                    });
+            let result = acc.point();
+
+            if let (Some(real_msm), Some(result)) = (real_msm, result) {
+                assert_eq!(real_msm.to_affine(), result);
+            }
+        }
+
+        Ok(acc)
+    }
+
+    /// Computes `[2] P`, `[3] P` and `[3 + 2 k_254] P`, and witnesses `k_254`.
+    ///
+    /// ```text
+    /// |  a0   |  a1   |  a2   |  a3   |  a4  |  a5  |   a6    |
+    /// ----------------------------------------------------------
+    /// |   doubling P → [2] P                        |  k_254  | q_double = 1
+    /// |   P   |   P   | [2] P | [2] P |      |      |         | q_add_incomplete = 1
+    /// |       |       | [3] P | [3] P |      |      |         |
+    /// | [3] P | [3] P | [2] P | [2] P |      |      |         | q_add_incomplete = 1
+    /// |       |       | [5] P | [5] P |      |      |         |
+    /// | [5] P | [5] P | [3] P | [3] P |k_254 | init | init    | q_select = 1
+    /// ```
+    fn assign_table(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        base: &NonIdentityEccPoint<C>,
+        k_254: Option<bool>,
+    ) -> Result<Table<C>, Error> {
+        let p: EccPoint<C> = base.clone().into();
+
+        // [2] P
+        let p2 = self.double_config.assign_region(&p, 0, region)?;
+
+        let k_254 = {
+            let k_254 = k_254.map(|k_254| C::Base::from_u64(k_254 as u64));
+            assign_cell(region, "k_254", self.advices[6], 0, k_254)?
+        };
+
+        // [3] P = P + [2] P
+        let p3 = self
+            .add_incomplete_config
+            .assign_region(&p, &p2, 1, region)?;
+        // [5] P = [3] P + [2] P
+        let p5 = self
+            .add_incomplete_config
+            .assign_region(&p3, &p2, 3, region)?;
+
+        // [3 + 2 k_254] P. The point selection constrains k_254 to be boolean.
+        let init = self
+            .select_config
+            .assign_region(&k_254, &p5, &p3, 5, region)?;
+
+        Ok(Table {
+            p,
+            p2,
+            p3,
+            init,
+            k_254,
+        })
+    }
+
+    /// Assigns the three ladders of the shared double-and-add.
+    ///
+    /// Returns the output of the last ladder, along with the running sums
+    /// `(z_130, z_2)` of each term.
+    #[allow(clippy::type_complexity)]
+    fn assign_ladders(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        acc: &EccPoint<C>,
+        tables: &[Table<C>],
+        bits: &[Vec<Option<bool>>],
+    ) -> Result<(EccPoint<C>, Vec<(CellValue<C::Base>, CellValue<C::Base>)>), Error> {
+        let num_terms = tables.len();
+        let period = 1 + 2 * num_terms;
+        let quad_row = |s: usize| 1 + s * period;
+        let add_row = |s: usize, i: usize| quad_row(s) + 1 + 2 * i;
+        let output_row = quad_row(WINDOWS_PER_LADDER);
+
+        // The windows u_{i,m} of each term, where window m consists of the bits
+        // k_{253 - 2m}, k_{252 - 2m}.
+        let windows: Option<Vec<Vec<usize>>> = bits
+            .iter()
+            .map(|bits| {
+                (0..NUM_WINDOWS)
+                    .map(|m| {
+                        bits[1 + WINDOW_SIZE * m..1 + WINDOW_SIZE * (m + 1)]
+                            .iter()
+                            .try_fold(0, |acc, bit| bit.map(|bit| 2 * acc + bit as usize))
+                    })
+                    .collect::<Option<Vec<_>>>()
+            })
+            .collect();
+
+        let values = {
+            let acc = acc.x.value().zip(acc.y.value());
+            let table: Option<Vec<_>> = tables
+                .iter()
+                .map(|table| {
+                    let p = table.p.x.value().zip(table.p.y.value());
+                    let p3 = table.p3.x.value().zip(table.p3.y.value());
+                    p.zip(p3)
+                })
+                .collect();
+            acc.zip(table)
+                .zip(windows.as_ref())
+                .map(|((acc, table), windows)| ladder_values(acc, &table, windows))
+                .transpose()?
+        };
+
+        // Assign the input row.
+        let mut inputs = Vec::with_capacity(NUM_LADDERS);
+        for ladder in 0..NUM_LADDERS {
+            let x_col = self.advices[2 * ladder];
+            let y_col = self.advices[2 * ladder + 1];
+            let input = if ladder == 0 {
+                EccPoint {
+                    x: copy(region, || "x_in", x_col, 0, &acc.x)?,
+                    y: copy(region, || "y_in", y_col, 0, &acc.y)?,
+                }
+            } else {
+                // This is constrained to equal the output of the previous ladder.
+                let input = values.as_ref().map(|values| values[ladder].input);
+                EccPoint {
+                    x: assign_cell(region, "x_in", x_col, 0, input.map(|p| p.0))?,
+                    y: assign_cell(region, "y_in", y_col, 0, input.map(|p| p.1))?,
+                }
+            };
+            let inv_y = values.as_ref().map(|values| values[ladder].inv_y);
+            assign_cell(region, "inv(y_in)", self.advices[6 + ladder], 0, inv_y)?;
+            inputs.push(input);
+        }
+
+        // Enable the selectors, and copy in the table of each term.
+        for s in 0..WINDOWS_PER_LADDER {
+            if s == 0 {
+                self.q_msm_quad_init.enable(region, quad_row(s))?;
+            } else {
+                self.q_msm_quad.enable(region, quad_row(s))?;
+            }
+
+            for (i, table) in tables.iter().enumerate() {
+                let row = add_row(s, i);
+                self.q_msm_add.enable(region, row)?;
+                if i + 1 < num_terms {
+                    self.q_msm_chain.enable(region, row)?;
+                }
+
+                copy(region, || "x_p", self.advices[0], row, &table.p.x)?;
+                copy(region, || "y_p", self.advices[1], row, &table.p.y)?;
+                copy(region, || "x_3p", self.advices[0], row + 1, &table.p3.x)?;
+                copy(region, || "y_3p", self.advices[1], row + 1, &table.p3.y)?;
+            }
+        }
+
+        // Assign the quadrupling and addition steps of each ladder.
+        let mut z_prev: Vec<_> = tables.iter().map(|table| table.k_254).collect();
+        let mut z_130 = vec![None; num_terms];
+        for ladder in 0..NUM_LADDERS {
+            for s in 0..WINDOWS_PER_LADDER {
+                let m = WINDOWS_PER_LADDER * ladder + s;
+
+                let row = quad_row(s);
+                let quad = values.as_ref().map(|values| values[ladder].quads[s]);
+                let x_a_col = self.advices[3 * ladder];
+                let lambda1_col = self.advices[3 * ladder + 1];
+                let lambda2_col = self.advices[3 * ladder + 2];
+                assign_cell(region, "x_a", x_a_col, row, quad.map(|q| q.x_a))?;
+                assign_cell(region, "λ1", lambda1_col, row, quad.map(|q| q.lambda1))?;
+                assign_cell(region, "λ2", lambda2_col, row, quad.map(|q| q.lambda2))?;
+
+                for i in 0..num_terms {
+                    let row = add_row(s, i);
+                    let step = values.as_ref().map(|values| values[ladder].adds[s][i]);
+
+                    let (column, offset) = self.add_cell(ladder, CELL_Z_PREV);
+                    copy(region, || "z_prev", column, row + offset, &z_prev[i])?;
+
+                    // z = 4⋅z_prev + u
+                    let u = windows
+                        .as_ref()
+                        .map(|windows| C::Base::from_u64(windows[i][m] as u64));
+                    let z = z_prev[i]
+                        .value()
+                        .zip(u)
+                        .map(|(z_prev, u)| z_prev * C::Base::from_u64(4) + u);
+                    let (column, offset) = self.add_cell(ladder, CELL_Z);
+                    let z = assign_cell(region, "z", column, row + offset, z)?;
+
+                    let (column, offset) = self.add_cell(ladder, CELL_X_A);
+                    assign_cell(
+                        region,
+                        "x_a",
+                        column,
+                        row + offset,
+                        step.map(|step| step.x_a),
+                    )?;
+                    let (column, offset) = self.add_cell(ladder, CELL_LAMBDA);
+                    assign_cell(
+                        region,
+                        "λ",
+                        column,
+                        row + offset,
+                        step.map(|step| step.lambda),
+                    )?;
+                    let (column, offset) = self.add_cell(ladder, CELL_INV);
+                    assign_cell(
+                        region,
+                        "inv",
+                        column,
+                        row + offset,
+                        step.map(|step| step.inv),
+                    )?;
+
+                    if m == Z_130_WINDOW {
+                        z_130[i] = Some(z);
+                    }
+                    z_prev[i] = z;
+                }
+            }
+        }
+
+        // Assign the output row.
+        self.q_msm_output.enable(region, output_row)?;
+        let mut outputs = Vec::with_capacity(NUM_LADDERS);
+        for ladder in 0..NUM_LADDERS {
+            let output = values.as_ref().map(|values| values[ladder].output);
+            let x_col = self.advices[2 * ladder];
+            let y_col = self.advices[2 * ladder + 1];
+            outputs.push(EccPoint {
+                x: assign_cell(region, "x_out", x_col, output_row, output.map(|p| p.0))?,
+                y: assign_cell(region, "y_out", y_col, output_row, output.map(|p| p.1))?,
+            });
+        }
+
+        // Each ladder starts from the output of the previous one.
+        for (input, output) in inputs.iter().skip(1).zip(outputs.iter()) {
+            region.constrain_equal(input.x.cell(), output.x.cell())?;
+            region.constrain_equal(input.y.cell(), output.y.cell())?;
+        }
+
+        let running_sums = z_130
+            .into_iter()
+            .map(|z_130| z_130.expect("z_130 is assigned"))
+            .zip(z_prev.into_iter())
+            .collect();
+
+        Ok((outputs[NUM_LADDERS - 1].clone(), running_sums))
+    }
+
+    /// Decomposes the final window as `z_0 = 4⋅z_2 + 2⋅k_1 + k_0`, and computes
+    /// the table `[-2P, -P, 𝒪, P]` from which `[2 k_1 + k_0 - 2] P` is selected.
+    ///
+    /// ```text
+    /// |  a0   |  a1   |  a2   |  a3   |  a4  |  a5  |  a6  |  a7  |  a8  |
+    /// --------------------------------------------------------------------
+    /// |       |  y_p  |       | -y_p  |      | z_2  | k_1  | k_0  | z_0  | q_neg = q_msm_lsb = 1
+    /// |       | y_2p  |       | -y_2p |      |  0   |  0   |      |      | q_neg = 1
+    /// ```
+    #[allow(clippy::type_complexity)]
+    fn assign_final_window(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        table: &Table<C>,
+        z_2: CellValue<C::Base>,
+        bits: &[Option<bool>],
+    ) -> Result<
+        (
+            [EccPoint<C>; 4],
+            CellValue<C::Base>,
+            CellValue<C::Base>,
+            CellValue<C::Base>,
+        ),
+        Error,
+    > {
+        self.q_msm_lsb.enable(region, 0)?;
+
+        copy(region, || "z_2", self.advices[5], 0, &z_2)?;
+        let k_1 = bits[L_SCALAR - 2].map(|k_1| C::Base::from_u64(k_1 as u64));
+        let k_1 = assign_cell(region, "k_1", self.advices[6], 0, k_1)?;
+        let k_0 = bits[L_SCALAR - 1].map(|k_0| C::Base::from_u64(k_0 as u64));
+        let k_0 = assign_cell(region, "k_0", self.advices[7], 0, k_0)?;
+        let z_0 = z_2
+            .value()
+            .zip(k_1.value())
+            .zip(k_0.value())
+            .map(|((z_2, k_1), k_0)| z_2 * C::Base::from_u64(4) + k_1 * C::Base::from_u64(2) + k_0);
+        let z_0 = assign_cell(region, "z_0", self.advices[8], 0, z_0)?;
+
+        let neg_p = self.neg_config.assign_neg(&table.p, 0, region)?;
+        let neg_p2 = self.neg_config.assign_neg(&table.p2, 1, region)?;
+        let identity = {
+            let x = region.assign_advice_from_constant(
+                || "x = 0",
+                self.advices[5],
+                1,
+                C::Base::zero(),
+            )?;
+            let y = region.assign_advice_from_constant(
+                || "y = 0",
+                self.advices[6],
+                1,
+                C::Base::zero(),
+            )?;
+            EccPoint {
+                x: CellValue::new(x, Some(C::Base::zero())),
+                y: CellValue::new(y, Some(C::Base::zero())),
+            }
+        };
+
+        Ok(([neg_p2, neg_p, identity, table.p.clone()], k_1, k_0, z_0))
+    }
+}
+
+/// The points computed in-circuit for each term of the MSM.
+struct Table<C: EccCurve> {
+    // P
+    p: EccPoint<C>,
+    // [2] P
+    p2: EccPoint<C>,
+    // [3] P
+    p3: EccPoint<C>,
+    // [3 + 2 k_254] P
+    init: EccPoint<C>,
+    // The most significant bit k_254 of the scalar decomposition
+    k_254: CellValue<C::Base>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct QuadValues<F: FieldExt> {
+    x_a: F,
+    lambda1: F,
+    lambda2: F,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct AddValues<F: FieldExt> {
+    x_a: F,
+    lambda: F,
+    inv: F,
+}
+
+#[derive(Clone, Debug)]
+struct LadderValues<F: FieldExt> {
+    input: (F, F),
+    inv_y: F,
+    // Indexed by the step s of the ladder.
+    quads: Vec<QuadValues<F>>,
+    // Indexed by the step s of the ladder, then by term.
+    adds: Vec<Vec<AddValues<F>>>,
+    output: (F, F),
+}
+
+/// Computes the witness values of each ladder, starting from `acc`.
+///
+/// Returns an error if an exceptional case is encountered, i.e. if the input
+/// of a ladder is the identity, or if an incomplete addition has inputs with
+/// the same x-coordinate.
+#[allow(clippy::type_complexity)]
+fn ladder_values<F: FieldExt>(
+    mut acc: (F, F),
+    table: &[((F, F), (F, F))],
+    windows: &[Vec<usize>],
+) -> Result<Vec<LadderValues<F>>, Error> {
+    // Returns [2] A and λ = 3 x_a^2 / (2 y_a).
+    fn double<F: FieldExt>((x_a, y_a): (F, F)) -> Result<((F, F), F), Error> {
+        let inv = Option::<F>::from(y_a.double().invert()).ok_or(Error::SynthesisError)?;
+        let lambda = x_a.square() * F::from_u64(3) * inv;
+        let x_r = lambda.square() - x_a.double();
+        let y_r = lambda * (x_a - x_r) - y_a;
+        Ok(((x_r, y_r), lambda))
+    }
+
+    let mut ladders = Vec::with_capacity(NUM_LADDERS);
+    for ladder in 0..NUM_LADDERS {
+        let input = acc;
+        let inv_y = Option::<F>::from(acc.1.invert()).ok_or(Error::SynthesisError)?;
+
+        let mut quads = Vec::with_capacity(WINDOWS_PER_LADDER);
+        let mut adds = Vec::with_capacity(WINDOWS_PER_LADDER);
+        for s in 0..WINDOWS_PER_LADDER {
+            let m = WINDOWS_PER_LADDER * ladder + s;
+
+            // Acc := [4] Acc
+            let x_a = acc.0;
+            let (b, lambda1) = double(acc)?;
+            let (c, lambda2) = double(b)?;
+            quads.push(QuadValues {
+                x_a,
+                lambda1,
+                lambda2,
+            });
+            acc = c;
+
+            // Acc := Acc + [2u - 3] P_i for each term
+            let mut step = Vec::with_capacity(table.len());
+            for (((x_p, y_p), (x_3p, y_3p)), windows) in table.iter().zip(windows.iter()) {
+                let (x_q, y_q) = match windows[m] {
+                    0 => (*x_3p, -*y_3p),
+                    1 => (*x_p, -*y_p),
+                    2 => (*x_p, *y_p),
+                    3 => (*x_3p, *y_3p),
+                    _ => unreachable!(),
+                };
+                let (x_a, y_a) = acc;
+                let inv = Option::<F>::from((x_a - x_q).invert()).ok_or(Error::SynthesisError)?;
+                // λ = (y_a - y_q) / (x_a - x_q)
+                let lambda = (y_a - y_q) * inv;
+                let x_r = lambda.square() - x_a - x_q;
+                let y_r = lambda * (x_a - x_r) - y_a;
+                step.push(AddValues { x_a, lambda, inv });
+                acc = (x_r, y_r);
+            }
+            adds.push(step);
+        }
+
+        ladders.push(LadderValues {
+            input,
+            inv_y,
+            quads,
+            adds,
+            output: acc,
+        });
+    }
+
+    Ok(ladders)
+}
+
+/// Assigns `value` to the given cell.
+fn assign_cell<F: FieldExt>(
+    region: &mut Region<'_, F>,
+    annotation: &str,
+    column: Column<Advice>,
+    offset: usize,
+    value: Option<F>,
+) -> Result<CellValue<F>, Error> {
+    let cell = region.assign_advice(
+        || annotation,
+        column,
+        offset,
+        || value.ok_or(Error::SynthesisError),
+    )?;
+    Ok(CellValue::new(cell, value))
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoints, NonIdentityPoint, Point},
+    };
+    use utilities::UtilitiesInstructions;
+
+    pub fn test_msm<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let column = chip.config().advices[0];
+
+        // Σ [a_i] P_i, including a zero scalar and the largest possible base field
+        // element.
+        let terms = [
+            (
+                pallas::Base::rand(),
+                pallas::Point::random(rand::rngs::OsRng).to_affine(),
+            ),
+            (
+                -pallas::Base::one(),
+                pallas::Point::random(rand::rngs::OsRng).to_affine(),
+            ),
+            (
+                pallas::Base::zero(),
+                pallas::Point::random(rand::rngs::OsRng).to_affine(),
+            ),
+        ];
+
+        let witnessed = terms
+            .iter()
+            .enumerate()
+            .map(|(idx, (scalar_val, base_val))| {
+                let scalar = chip.load_private(
+                    layouter.namespace(|| format!("scalar {}", idx)),
+                    column,
+                    Some(*scalar_val),
+                )?;
+                let base = NonIdentityPoint::new(
+                    chip.clone(),
+                    layouter.namespace(|| format!("base {}", idx)),
+                    Some(*base_val),
+                )?;
+                Ok((scalar, base))
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        let result = Point::msm(chip.clone(), layouter.namespace(|| "MSM"), &witnessed)?;
+
+        let expected = {
+            let expected = terms
+                .iter()
+                .fold(pallas::Point::identity(), |acc, (scalar, base)| {
+                    // Move scalar from base field into scalar field (which always fits
+                    // for Pallas).
+                    let scalar = pallas::Scalar::from_bytes(&scalar.to_bytes()).unwrap();
+                    acc + base * scalar
+                });
+            Point::new(
+                chip,
+                layouter.namespace(|| "expected MSM"),
+                Some(expected.to_affine()),
+            )?
+        };
+        result.constrain_equal(layouter.namespace(|| "constrain MSM"), &expected)
+    }
+}
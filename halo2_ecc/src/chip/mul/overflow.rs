@@ -1,5 +1,4 @@
-use super::super::{copy, CellValue, EccConfig, Var, LOOKUP_K};
-use super::{T_Q, Z};
+use super::super::{copy, CellValue, EccConfig, EccCurve, Var, LOOKUP_K};
 use utilities::lookup_range_check::LookupRangeCheckConfig;
 
 use halo2::{
@@ -8,22 +7,19 @@ use halo2::{
     poly::Rotation,
 };
 
-use ff::Field;
-use pasta_curves::{arithmetic::FieldExt, pallas};
-
 use std::iter;
 
-pub struct Config {
+pub struct Config<C: EccCurve> {
     // Selector to check z_0 = alpha + t_q (mod p)
     q_mul_overflow: Selector,
     // 10-bit lookup table
-    lookup_config: LookupRangeCheckConfig<pallas::Base, LOOKUP_K>,
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
     // Advice columns
     advices: [Column<Advice>; 3],
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         Self {
             q_mul_overflow: ecc_config.q_mul_overflow,
             lookup_config: ecc_config.lookup_config.clone(),
@@ -38,16 +34,16 @@ impl From<&EccConfig> for Config {
     }
 }
 
-impl Config {
-    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("overflow checks", |meta| {
             let q_mul_overflow = meta.query_selector(self.q_mul_overflow);
 
             // Constant expressions
-            let one = Expression::Constant(pallas::Base::one());
-            let two_pow_124 = Expression::Constant(pallas::Base::from_u128(1 << 124));
+            let one = Expression::Constant(C::Base::one());
+            let two_pow_124 = Expression::Constant(C::Base::from_u128(1 << 124));
             let two_pow_130 =
-                two_pow_124.clone() * Expression::Constant(pallas::Base::from_u128(1 << 6));
+                two_pow_124.clone() * Expression::Constant(C::Base::from_u128(1 << 6));
 
             let z_0 = meta.query_advice(self.advices[0], Rotation::prev());
             let z_130 = meta.query_advice(self.advices[0], Rotation::cur());
@@ -62,9 +58,9 @@ impl Config {
             let s = meta.query_advice(self.advices[2], Rotation::cur());
             let s_check = s - (alpha.clone() + k_254.clone() * two_pow_130);
 
-            // q = 2^254 + t_q is the Pallas scalar field modulus.
+            // q = 2^254 + t_q is the scalar field modulus.
             // We cast t_q into the base field to check alpha + t_q (mod p).
-            let t_q = Expression::Constant(pallas::Base::from_u128(T_Q));
+            let t_q = Expression::Constant(C::Base::from_u128(C::T_Q));
 
             // z_0 - alpha - t_q = 0 (mod p)
             let recovery = z_0 - alpha - t_q;
@@ -91,20 +87,21 @@ impl Config {
 
     pub(super) fn overflow_check(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        alpha: CellValue<pallas::Base>,
-        zs: &[Z<pallas::Base>], // [z_0, z_1, ..., z_{254}, z_{255}]
+        mut layouter: impl Layouter<C::Base>,
+        alpha: CellValue<C::Base>,
+        z_0: CellValue<C::Base>,
+        z_130: CellValue<C::Base>,
+        k_254: CellValue<C::Base>,
     ) -> Result<(), Error> {
         // s = alpha + k_254 ⋅ 2^130 is witnessed here, and then copied into
         // the decomposition as well as the overflow check gate.
         // In the overflow check gate, we check that s is properly derived
         // from alpha and k_254.
         let s = {
-            let k_254 = *zs[254];
             let s_val = alpha
                 .value()
                 .zip(k_254.value())
-                .map(|(alpha, k_254)| alpha + k_254 * pallas::Base::from_u128(1 << 65).square());
+                .map(|(alpha, k_254)| alpha + k_254 * C::Base::from_u128(1 << 65).square());
 
             layouter.assign_region(
                 || "s = alpha + k_254 ⋅ 2^130",
@@ -134,7 +131,7 @@ impl Config {
                 self.q_mul_overflow.enable(&mut region, offset + 1)?;
 
                 // Copy `z_0`
-                copy(&mut region, || "copy z_0", self.advices[0], offset, &*zs[0])?;
+                copy(&mut region, || "copy z_0", self.advices[0], offset, &z_0)?;
 
                 // Copy `z_130`
                 copy(
@@ -142,14 +139,14 @@ impl Config {
                     || "copy z_130",
                     self.advices[0],
                     offset + 1,
-                    &*zs[130],
+                    &z_130,
                 )?;
 
                 // Witness η = inv0(z_130), where inv0(x) = 0 if x = 0, 1/x otherwise
                 {
-                    let eta = zs[130].value().map(|z_130| {
-                        if z_130 == pallas::Base::zero() {
-                            pallas::Base::zero()
+                    let eta = z_130.value().map(|z_130| {
+                        if z_130 == C::Base::zero() {
+                            C::Base::zero()
                         } else {
                             z_130.invert().unwrap()
                         }
@@ -168,7 +165,7 @@ impl Config {
                     || "copy k_254",
                     self.advices[1],
                     offset,
-                    &*zs[254],
+                    &k_254,
                 )?;
 
                 // Copy original alpha
@@ -201,9 +198,9 @@ impl Config {
 
     fn s_minus_lo_130(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        s: CellValue<pallas::Base>,
-    ) -> Result<CellValue<pallas::Base>, Error> {
+        mut layouter: impl Layouter<C::Base>,
+        s: CellValue<C::Base>,
+    ) -> Result<CellValue<C::Base>, Error> {
         // Number of k-bit words we can use in the lookup decomposition.
         let num_words = 130 / LOOKUP_K;
         assert!(num_words * LOOKUP_K == 130);
@@ -0,0 +1,224 @@
+//! Compares the number of rows used by the variable-base scalar multiplication
+//! instructions.
+//!
+//! The rows used by a gadget are measured by appending a region that occupies
+//! the last row of every advice column, and finding the largest such region that
+//! still fits into the circuit.
+
+use std::marker::PhantomData;
+
+use group::{Curve, Group};
+use halo2::{
+    circuit::{Chip, Layouter, SimpleFloorPlanner},
+    dev::MockProver,
+    plonk::{Circuit, ConstraintSystem, Error},
+};
+use pasta_curves::{arithmetic::FieldExt, pallas};
+
+use crate::{
+    chip::{
+        test_circuit::{configure_ecc_chip, NoFixedBases},
+        EccChip, EccConfig, VarBaseMul,
+    },
+    gadget::{NonIdentityPoint, Point, FIXED_BASE_WINDOW_SIZE},
+};
+use utilities::{CellValue, UtilitiesInstructions};
+
+const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+/// The circuit size used to measure row counts.
+const CIRCUIT_K: u32 = 11;
+
+type MyEccChip = EccChip<pallas::Affine, NoFixedBases, K>;
+
+/// A gadget whose row count is measured.
+trait Gadget {
+    /// The variable-base scalar mul method that the chip is configured with.
+    const VAR_BASE_MUL: VarBaseMul = VarBaseMul::DoubleAndAdd;
+
+    fn synthesize(chip: MyEccChip, layouter: impl Layouter<pallas::Base>) -> Result<(), Error>;
+}
+
+type Term = (
+    CellValue<pallas::Base>,
+    NonIdentityPoint<pallas::Affine, MyEccChip>,
+);
+
+/// Witnesses `N` random scalars and `N` random non-identity bases.
+fn witness_terms<const N: usize>(
+    chip: &MyEccChip,
+    mut layouter: impl Layouter<pallas::Base>,
+) -> Result<Vec<Term>, Error> {
+    let column = chip.config().advices[0];
+    (0..N)
+        .map(|idx| {
+            let scalar = chip.load_private(
+                layouter.namespace(|| format!("scalar {}", idx)),
+                column,
+                Some(pallas::Base::rand()),
+            )?;
+            let base = NonIdentityPoint::new(
+                chip.clone(),
+                layouter.namespace(|| format!("base {}", idx)),
+                Some(pallas::Point::random(rand::rngs::OsRng).to_affine()),
+            )?;
+            Ok((scalar, base))
+        })
+        .collect()
+}
+
+/// A circuit that does not use any rows.
+struct Empty;
+
+impl Gadget for Empty {
+    fn synthesize(_: MyEccChip, _: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+/// A single variable-base scalar multiplication.
+struct Mul;
+
+impl Gadget for Mul {
+    fn synthesize(chip: MyEccChip, mut layouter: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        let terms = witness_terms::<1>(&chip, layouter.namespace(|| "terms"))?;
+        let (scalar, base) = &terms[0];
+        base.mul(layouter.namespace(|| "mul"), scalar)?;
+        Ok(())
+    }
+}
+
+/// A single variable-base scalar multiplication, using 4-bit windows.
+struct WindowedMul;
+
+impl Gadget for WindowedMul {
+    const VAR_BASE_MUL: VarBaseMul = VarBaseMul::Windowed;
+
+    fn synthesize(chip: MyEccChip, layouter: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        Mul::synthesize(chip, layouter)
+    }
+}
+
+/// A variable-base multi-scalar multiplication with `N` terms.
+struct Msm<const N: usize>;
+
+impl<const N: usize> Gadget for Msm<N> {
+    fn synthesize(chip: MyEccChip, mut layouter: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        let terms = witness_terms::<N>(&chip, layouter.namespace(|| "terms"))?;
+        Point::msm(chip, layouter.namespace(|| "msm"), &terms)?;
+        Ok(())
+    }
+}
+
+struct RowCountCircuit<G: Gadget> {
+    // Height of the region assigned after the gadget.
+    filler: usize,
+    _marker: PhantomData<G>,
+}
+
+impl<G: Gadget> Circuit<pallas::Base> for RowCountCircuit<G> {
+    type Config = EccConfig<pallas::Affine, K>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        RowCountCircuit {
+            filler: self.filler,
+            _marker: PhantomData,
+        }
+    }
+
+    fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+        configure_ecc_chip::<NoFixedBases, K>(meta, G::VAR_BASE_MUL)
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let chip = MyEccChip::construct(config.clone());
+
+        // The lookup table only occupies a fixed column, so loading it does not
+        // change the number of advice rows that are available.
+        config.lookup_config.load(&mut layouter)?;
+
+        G::synthesize(chip, layouter.namespace(|| "gadget"))?;
+
+        if self.filler > 0 {
+            layouter.assign_region(
+                || "filler",
+                |mut region| {
+                    for column in config.advices.iter() {
+                        region.assign_advice(
+                            || "filler",
+                            *column,
+                            self.filler - 1,
+                            || Ok(pallas::Base::zero()),
+                        )?;
+                    }
+                    Ok(())
+                },
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the largest filler region that fits after the gadget.
+fn max_filler<G: Gadget>() -> usize {
+    let fits = |filler| {
+        let circuit = RowCountCircuit::<G> {
+            filler,
+            _marker: PhantomData,
+        };
+        MockProver::run(CIRCUIT_K, &circuit, vec![]).is_ok()
+    };
+
+    // Invariant: `lo` fits and `hi` does not.
+    let (mut lo, mut hi) = (0, 1 << CIRCUIT_K);
+    assert!(fits(lo), "the gadget does not fit into the circuit");
+    while hi - lo > 1 {
+        let mid = (lo + hi) / 2;
+        if fits(mid) {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+    }
+    lo
+}
+
+/// Returns the number of rows used by the gadget.
+fn rows<G: Gadget>() -> usize {
+    max_filler::<Empty>() - max_filler::<G>()
+}
+
+#[test]
+fn msm_uses_fewer_rows_than_repeated_mul() {
+    let mul = rows::<Mul>();
+
+    let msm = [rows::<Msm<2>>(), rows::<Msm<3>>(), rows::<Msm<4>>()];
+    for (n, msm) in (2..).zip(msm.iter()) {
+        assert!(
+            *msm < n * mul,
+            "MSM with {} terms uses {} rows, but {} muls use {} rows",
+            n,
+            msm,
+            n,
+            n * mul
+        );
+    }
+}
+
+#[test]
+fn windowed_mul_uses_fewer_rows_than_double_and_add() {
+    let mul = rows::<Mul>();
+    let windowed = rows::<WindowedMul>();
+    assert!(
+        windowed < mul,
+        "windowed mul uses {} rows, but double-and-add uses {} rows",
+        windowed,
+        mul
+    );
+}
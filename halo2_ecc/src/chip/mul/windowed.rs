@@ -0,0 +1,904 @@
+use super::super::{
+    add_incomplete, copy, double, select, CellValue, EccConfig, EccCurve, EccPoint,
+    NonIdentityEccPoint, Var, LOOKUP_K, L_SCALAR,
+};
+use super::{decompose_for_scalar_mul, overflow, X, Y, Z};
+use utilities::lookup_range_check::LookupRangeCheckConfig;
+
+use ff::Field;
+use halo2::{
+    arithmetic::{lagrange_interpolate, FieldExt},
+    circuit::{Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+/// Number of bits in each window.
+const WINDOW_SIZE: usize = 4;
+
+/// Number of windows, covering bits k_{253} to k_{2} inclusive. Bit k_{254} is
+/// used to initialize the accumulator, and bits k_{1} and k_{0} are processed
+/// using complete addition as in [`super::Config`].
+const NUM_WINDOWS: usize = (L_SCALAR - 3) / WINDOW_SIZE;
+
+/// Number of odd multiples `[1] P, [3] P, ..., [15] P` in the per-call table.
+const TABLE_SIZE: usize = 1 << (WINDOW_SIZE - 1);
+
+/// Number of ladders assigned side by side in the double-and-add region.
+const NUM_LADDERS: usize = 7;
+
+/// Number of consecutive windows processed by each ladder.
+const WINDOWS_PER_LADDER: usize = NUM_WINDOWS / NUM_LADDERS;
+
+/// Number of rows used by a step of the ladders.
+const STEP_ROWS: usize = NUM_LADDERS + 5;
+
+/// The window after which the running sum is z_{130}, which is used in the
+/// overflow check.
+const Z_130_WINDOW: usize = (L_SCALAR - 1 - 130) / WINDOW_SIZE - 1;
+
+// Rows of a step holding the accumulator x_a, y_a, the selected point x_q, y_q / d
+// and the running sum z of each ladder, in the column indexed by the ladder.
+const ROW_X_A: usize = 0;
+const ROW_Y_A: usize = 1;
+const ROW_X_Q: usize = 2;
+const ROW_Y_Q_DIV_D: usize = 3;
+const ROW_Z: usize = STEP_ROWS - 1;
+
+// Row of a step holding the cells of ladder 0, followed by those of the other
+// ladders.
+const ROW_LADDER: usize = 4;
+
+// Columns of the cells that each ladder uses in its own row of a step.
+const COL_LAMBDA: usize = WINDOW_SIZE;
+const COL_INV: usize = WINDOW_SIZE + 1;
+const COL_T: usize = WINDOW_SIZE + 2;
+const COL_T2: usize = WINDOW_SIZE + 3;
+const COL_U: usize = WINDOW_SIZE + 4;
+
+/// The (column, row) of each coordinate of the table within a step, filling the
+/// cells that are not used by the ladders. The coordinates of `[2j + 1] P` are at
+/// indices `2j` and `2j + 1`.
+const TABLE_CELLS: [(usize, usize); 2 * TABLE_SIZE] = [
+    (7, 0),
+    (8, 0),
+    (9, 0),
+    (7, 1),
+    (8, 1),
+    (9, 1),
+    (7, 2),
+    (8, 2),
+    (9, 2),
+    (7, 3),
+    (8, 3),
+    (9, 3),
+    (9, 4),
+    (9, 5),
+    (9, 6),
+    (9, 7),
+];
+
+/// Variable-base scalar multiplication using a double-and-add over signed 4-bit
+/// windows of the scalar.
+///
+/// The scalar `alpha` is decomposed as `k = alpha + t_q` into 255 bits, exactly as
+/// in the double-and-add over single bits, and the overflow check is reused to
+/// ensure that `k` is the canonical decomposition. Bits k_{253} to k_{2} are split
+/// into 63 four-bit windows `u ∈ [0..16)`, each of which selects the signed odd
+/// digit `d = 2u - 15` from a table of `[1] P, [3] P, ..., [15] P` computed for
+/// each call:
+///
+/// ```text
+/// Acc := [3 + 2 k_254] P
+/// for each window m from 0 to 62:
+///     Acc := [16] Acc + [2 u_m - 15] P      (incomplete addition)
+/// Acc := [2] Acc + [2 k_1 - 1] P             (complete addition)
+/// Acc := Acc - [1 - k_0] P                   (complete addition)
+/// ```
+///
+/// After the windows, the accumulator is `[2^253 + 2 z_2 + 1] P` where
+/// `z_2 = k >> 2`, which is the accumulator of the double-and-add over single
+/// bits after processing bit k_{2}. The final bits k_{1} and k_{0} are therefore
+/// processed by the complete addition and LSB steps of [`super::Config`], and the
+/// result is `[k + 2^254] P = [alpha + q] P = [alpha] P`.
+///
+/// Lookup arguments can only be made against fixed columns, so the table is
+/// copied into every step of the double-and-add and the point `Q = [d] P` is
+/// selected from it by a gate. Each window is instead range-checked through the
+/// lookup table of [`LookupRangeCheckConfig`], by looking up both `u` and
+/// `2^6 u`. Given `u`, `Q` is the Lagrange interpolation over
+/// `d^2 ∈ {1, 9, ..., 225}` of the table:
+/// - `x_q = Σ_j M_j(t) ⋅ x_{[2j + 1] P}`
+/// - `y_q = d ⋅ Σ_j M_j(t) ⋅ y_{[2j + 1] P} / (2j + 1)`
+///
+/// where `t = d^2` and `M_j` is the Lagrange basis polynomial that is 1 at
+/// `(2j + 1)^2`.
+///
+/// The powers `t` and `t^2`, and the interpolated `x_q` and `y_q / d`, are
+/// witnessed. The interpolations are then constraints of degree 5 (the highest
+/// power `t^7 = t ⋅ (t^2)^3` times a table coordinate), and the four doublings of
+/// the accumulator, which are not witnessed, give constraints of degree 4. With
+/// the selector, the gate has degree 6, which is the degree of the complete
+/// addition gate, so it does not increase the degree of the circuit.
+///
+/// To share each copy of the table, the windows are processed by seven ladders
+/// of nine windows each, where each ladder starts from the output of the
+/// previous one. Each step of the ladders occupies twelve rows, and serves seven
+/// windows at once:
+///
+/// ```text
+/// |       a0 .. a6        |  a7  |  a8  |  a9  |
+/// ----------------------------------------------
+/// | z_prev of each ladder |      |      |      |
+/// |   x_a of each ladder  |  T   |  T   |  T   | q_mul_windowed
+/// |   y_a of each ladder  |  T   |  T   |  T   |
+/// |   x_q of each ladder  |  T   |  T   |  T   |
+/// | y_q / d of each ladder|  T   |  T   |  T   |
+/// | λ1 λ2 λ3 λ4 λ inv t   |  t2  |  u   |  T   | q_mul_windowed_range_check (ladder 0)
+/// |          ...          |      |      |      |
+/// | λ1 λ2 λ3 λ4 λ inv t   |  t2  |  u   |      | q_mul_windowed_range_check (ladder 6)
+/// |    z of each ladder   |      |      |      |
+/// |   x_a of each ladder  |  T   |  T   |  T   | q_mul_windowed
+/// |          ...          |      |      |      |
+/// ```
+///
+/// where `T` are the cells of the table, and `λ1..λ4` and `λ` are the slopes of
+/// the four doublings and the addition of a window. The running sum `z` of the
+/// previous step, and the accumulator of the next step, are read from the
+/// neighbouring steps. This uses 111 rows for the 63 windows, where the
+/// double-and-add over single bits uses one row for every two bits.
+///
+/// Each incomplete addition is constrained to have distinct x-coordinates. For
+/// windows other than the last one, the accumulator is a multiple of `P` that is
+/// smaller than `q`, so this always holds. When `p > q`, the honest prover fails
+/// in the last window for a negligible fraction of scalars `alpha ≥ q - 62`.
+pub struct Config<C: EccCurve> {
+    // Selector for a step of the ladders.
+    q_mul_windowed: Selector,
+    // Selector for the lookup range check on the window of a ladder.
+    q_mul_windowed_range_check: Selector,
+    // Selector used to compute the odd multiples in the table.
+    q_add_incomplete: Selector,
+    // Advice columns
+    advices: [Column<Advice>; 10],
+    // 10-bit lookup table
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    // Configuration used in incomplete addition
+    add_incomplete_config: add_incomplete::Config<C>,
+    // Configuration used in point doubling
+    double_config: double::Config<C>,
+    // Configuration used in point selection
+    select_config: select::Config<C>,
+    // Configuration used to check for overflow
+    overflow_config: overflow::Config<C>,
+    // Configuration used to process the final bits k_{1} and k_{0}
+    mul_config: super::Config<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_mul_windowed: ecc_config.q_mul_windowed,
+            q_mul_windowed_range_check: ecc_config.q_mul_windowed_range_check,
+            q_add_incomplete: ecc_config.q_add_incomplete,
+            advices: ecc_config.advices,
+            lookup_config: ecc_config.lookup_config.clone(),
+            add_incomplete_config: ecc_config.into(),
+            double_config: ecc_config.into(),
+            select_config: ecc_config.into(),
+            overflow_config: ecc_config.into(),
+            mul_config: ecc_config.into(),
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // u ∈ [0..16)
+        self.lookup_config.configure_short_lookup(
+            meta,
+            self.q_mul_windowed_range_check,
+            WINDOW_SIZE,
+            |meta| meta.query_advice(self.advices[COL_U], Rotation::cur()),
+        );
+
+        // The coefficients of the Lagrange basis polynomials M_j, in increasing
+        // order of degree.
+        let basis = lagrange_basis::<C::Base>();
+
+        meta.create_gate("windowed variable-base scalar mul", |meta| {
+            let q_mul_windowed = meta.query_selector(self.q_mul_windowed);
+
+            let mut query =
+                |column: usize, row: i32| meta.query_advice(self.advices[column], Rotation(row));
+
+            // Σ_j c_{j,i} ⋅ x_{[2j + 1] P} and Σ_j c_{j,i} ⋅ y_{[2j + 1] P} / (2j + 1)
+            // for each power t^i, where c_{j,i} is the coefficient of t^i in M_j.
+            let (x_coeffs, y_coeffs) = {
+                let table: Vec<_> = (0..TABLE_SIZE)
+                    .map(|j| {
+                        let (x_col, x_row) = TABLE_CELLS[2 * j];
+                        let (y_col, y_row) = TABLE_CELLS[2 * j + 1];
+                        let inv = C::Base::from_u64(2 * j as u64 + 1).invert().unwrap();
+                        (query(x_col, x_row as i32), query(y_col, y_row as i32) * inv)
+                    })
+                    .collect();
+                let combine = |coords: &dyn Fn(usize) -> Expression<C::Base>, i: usize| {
+                    (0..TABLE_SIZE).fold(Expression::Constant(C::Base::zero()), |acc, j| {
+                        acc + coords(j) * basis[j][i]
+                    })
+                };
+                (0..TABLE_SIZE)
+                    .map(|i| {
+                        (
+                            combine(&|j| table[j].0.clone(), i),
+                            combine(&|j| table[j].1.clone(), i),
+                        )
+                    })
+                    .unzip::<_, _, Vec<_>, Vec<_>>()
+            };
+
+            let two = C::Base::from_u64(2);
+            let three = C::Base::from_u64(3);
+            let one = Expression::Constant(C::Base::one());
+
+            let mut constraints = Vec::new();
+            for ladder in 0..NUM_LADDERS {
+                let row = (ROW_LADDER + ladder) as i32;
+                let x_a = query(ladder, ROW_X_A as i32);
+                let y_a = query(ladder, ROW_Y_A as i32);
+                let lambdas: Vec<_> = (0..WINDOW_SIZE).map(|col| query(col, row)).collect();
+                let lambda = query(COL_LAMBDA, row);
+                let inv = query(COL_INV, row);
+                let t = query(COL_T, row);
+                let t2 = query(COL_T2, row);
+                let u = query(COL_U, row);
+                let x_q = query(ladder, ROW_X_Q as i32);
+                let y_q_div_d = query(ladder, ROW_Y_Q_DIV_D as i32);
+                let z = query(ladder, ROW_Z as i32);
+                let z_prev = query(ladder, -1);
+                let x_next = query(ladder, (STEP_ROWS + ROW_X_A) as i32);
+                let y_next = query(ladder, (STEP_ROWS + ROW_Y_A) as i32);
+
+                // u = z - 16⋅z_prev
+                constraints.push(z - z_prev * C::Base::from_u64(16) - u.clone());
+
+                // d = 2u - 15, t = d^2, t2 = t^2
+                let d = u * two - Expression::Constant(C::Base::from_u64(15));
+                constraints.push(t.clone() - d.clone().square());
+                constraints.push(t2.clone() - t.clone().square());
+
+                // E = [16] A, computed as four doublings.
+                let (x_e, y_e) = lambdas.into_iter().fold((x_a, y_a), |(x, y), lambda| {
+                    // 2⋅y⋅λ = 3⋅x^2
+                    constraints.push(y.clone() * lambda.clone() * two - x.clone().square() * three);
+                    // x_r = λ^2 - 2⋅x
+                    let x_r = lambda.clone().square() - x.clone() * two;
+                    // y_r = λ⋅(x - x_r) - y
+                    let y_r = lambda * (x - x_r.clone()) - y;
+                    (x_r, y_r)
+                });
+
+                // Q = [d] P
+                let y_q = {
+                    let t4 = t2.clone().square();
+                    let powers = [
+                        one.clone(),
+                        t.clone(),
+                        t2.clone(),
+                        t.clone() * t2.clone(),
+                        t4.clone(),
+                        t.clone() * t4.clone(),
+                        t2.clone() * t4.clone(),
+                        t * t2 * t4,
+                    ];
+                    let interpolate = |coeffs: &[Expression<C::Base>]| {
+                        powers.iter().zip(coeffs.iter()).fold(
+                            Expression::Constant(C::Base::zero()),
+                            |acc, (power, coeff)| acc + power.clone() * coeff.clone(),
+                        )
+                    };
+                    constraints.push(x_q.clone() - interpolate(&x_coeffs));
+                    constraints.push(y_q_div_d.clone() - interpolate(&y_coeffs));
+                    d * y_q_div_d
+                };
+
+                // inv⋅(x_e - x_q) = 1, so that the incomplete addition is well-defined.
+                constraints.push(inv * (x_e.clone() - x_q.clone()) - one.clone());
+                // λ⋅(x_e - x_q) = y_e - y_q
+                constraints
+                    .push(lambda.clone() * (x_e.clone() - x_q.clone()) - (y_e.clone() - y_q));
+
+                // The sum R = E + Q is the accumulator of the next step.
+                // x_r = λ^2 - x_e - x_q
+                let x_r = lambda.clone().square() - x_e.clone() - x_q;
+                // y_r = λ⋅(x_e - x_r) - y_e
+                let y_r = lambda * (x_e - x_r.clone()) - y_e;
+                constraints.push(x_next - x_r);
+                constraints.push(y_next - y_r);
+            }
+
+            constraints
+                .into_iter()
+                .map(move |poly| q_mul_windowed.clone() * poly)
+        });
+    }
+
+    pub(crate) fn assign(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        alpha: CellValue<C::Base>,
+        base: &NonIdentityEccPoint<C>,
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>), Error> {
+        // Decompose `k = alpha + t_q` bitwise (big-endian bit order).
+        let bits = decompose_for_scalar_mul::<C>(alpha.value());
+
+        // Compute the table `[1] P, [3] P, ..., [15] P` and the initial point
+        // `[3 + 2 k_254] P`.
+        let table = layouter.assign_region(
+            || "windowed mul table",
+            |mut region| self.assign_table(&mut region, base, bits[0]),
+        )?;
+
+        // Double-and-add over the windows k_{253} to k_{2}.
+        let (acc, z_130, z_2) = layouter.assign_region(
+            || "windowed double-and-add",
+            |mut region| self.assign_ladders(&mut region, &table, &bits),
+        )?;
+
+        // Process the final bits k_{1} and k_{0} using complete addition.
+        let (result, z_0) = layouter.assign_region(
+            || "windowed mul complete bits",
+            |mut region| {
+                let base: EccPoint<C> = base.clone().into();
+                let complete_bits = &bits[(L_SCALAR - 2)..(L_SCALAR - 1)];
+                let (acc, zs) = self.mul_config.complete_config.assign_region(
+                    &mut region,
+                    0,
+                    complete_bits,
+                    &base,
+                    X(acc.x),
+                    Y(acc.y),
+                    Z(z_2),
+                )?;
+
+                // Each complete bit uses two rows.
+                let offset = 2 * complete_bits.len();
+                let z_1 = zs.last().expect("there is one complete bit");
+                self.mul_config.process_lsb(
+                    &mut region,
+                    offset,
+                    &base,
+                    acc,
+                    *z_1,
+                    bits[L_SCALAR - 1],
+                )
+            },
+        )?;
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::Curve;
+
+            let base = base.point();
+            let alpha = alpha.value().map(super::super::base_to_scalar::<C>);
+            let real_mul = base.zip(alpha).map(|(base, alpha)| base * alpha);
+            let result = result.point();
+
+            if let (Some(real_mul), Some(result)) = (real_mul, result) {
+                assert_eq!(real_mul.to_affine(), result);
+            }
+        }
+
+        self.overflow_config.overflow_check(
+            layouter.namespace(|| "overflow check"),
+            alpha,
+            *z_0,
+            z_130,
+            table.k_254,
+        )?;
+
+        Ok((result, alpha))
+    }
+
+    /// Computes the table `[1] P, [3] P, ..., [15] P` and the initial point
+    /// `[3 + 2 k_254] P`, and witnesses `k_254`.
+    ///
+    /// ```text
+    /// |   a0   |   a1   |   a2   |   a3   |  a4   |  a5  |   a6    |
+    /// ---------------------------------------------------------------
+    /// |      doubling P → [2] P                          |  k_254  | q_double = 1
+    /// |   P    |   P    | [2] P  | [2] P  |       |      |         | q_add_incomplete = 1
+    /// | [2] P  | [2] P  | [3] P  | [3] P  |       |      |         | q_add_incomplete = 1
+    /// |                       ...                                  |
+    /// | [2] P  | [2] P  | [13] P | [13] P |       |      |         | q_add_incomplete = 1
+    /// |        |        | [15] P | [15] P |       |      |         |
+    /// | [5] P  | [5] P  | [3] P  | [3] P  | k_254 | init |  init   | q_select = 1
+    /// ```
+    fn assign_table(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        base: &NonIdentityEccPoint<C>,
+        k_254: Option<bool>,
+    ) -> Result<Table<C>, Error> {
+        let p: EccPoint<C> = base.clone().into();
+
+        // [2] P
+        let p2 = self.double_config.assign_region(&p, 0, region)?;
+
+        let k_254 = {
+            let k_254 = k_254.map(|k_254| C::Base::from_u64(k_254 as u64));
+            assign_cell(region, "k_254", self.advices[6], 0, k_254)?
+        };
+
+        // [3] P = P + [2] P
+        let p3 = self
+            .add_incomplete_config
+            .assign_region(&p, &p2, 1, region)?;
+        let mut odd_multiples = vec![p, p3];
+
+        // [2j + 3] P = [2j + 1] P + [2] P, where [2j + 1] P is the output of the
+        // previous addition.
+        let offset = 2;
+        for row in offset..(offset + TABLE_SIZE - 2) {
+            self.q_add_incomplete.enable(region, row)?;
+            copy(
+                region,
+                || "x_2p",
+                self.add_incomplete_config.x_p,
+                row,
+                &p2.x,
+            )?;
+            copy(
+                region,
+                || "y_2p",
+                self.add_incomplete_config.y_p,
+                row,
+                &p2.y,
+            )?;
+
+            let sum = odd_multiples[odd_multiples.len() - 1]
+                .point()
+                .zip(p2.point())
+                .map(|(acc, p2)| {
+                    use group::Curve;
+                    let sum = (acc + p2).to_affine().coordinates().unwrap();
+                    (*sum.x(), *sum.y())
+                });
+            let x = assign_cell(
+                region,
+                "x",
+                self.add_incomplete_config.x_qr,
+                row + 1,
+                sum.map(|sum| sum.0),
+            )?;
+            let y = assign_cell(
+                region,
+                "y",
+                self.add_incomplete_config.y_qr,
+                row + 1,
+                sum.map(|sum| sum.1),
+            )?;
+            odd_multiples.push(EccPoint { x, y });
+        }
+
+        // [3 + 2 k_254] P. The point selection constrains k_254 to be boolean.
+        let init = self.select_config.assign_region(
+            &k_254,
+            &odd_multiples[2],
+            &odd_multiples[1],
+            offset + TABLE_SIZE - 1,
+            region,
+        )?;
+
+        Ok(Table {
+            odd_multiples,
+            init,
+            k_254,
+        })
+    }
+
+    /// Assigns the ladders of the double-and-add, starting from the initial point
+    /// of the table.
+    ///
+    /// Returns the output of the last ladder, along with the running sums z_{130}
+    /// and z_{2}.
+    #[allow(clippy::type_complexity)]
+    fn assign_ladders(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        table: &Table<C>,
+        bits: &[Option<bool>],
+    ) -> Result<(EccPoint<C>, CellValue<C::Base>, CellValue<C::Base>), Error> {
+        // The first row holds the running sum that each ladder starts from.
+        let step_row = |s: usize| 1 + s * STEP_ROWS;
+        let output_row = step_row(WINDOWS_PER_LADDER);
+
+        // The windows u_m, where window m consists of the bits k_{253 - 4m} to
+        // k_{250 - 4m}.
+        let windows: Option<Vec<usize>> = (0..NUM_WINDOWS)
+            .map(|m| {
+                bits[1 + WINDOW_SIZE * m..1 + WINDOW_SIZE * (m + 1)]
+                    .iter()
+                    .try_fold(0, |acc, bit| bit.map(|bit| 2 * acc + bit as usize))
+            })
+            .collect();
+
+        let values = {
+            let init = table.init.x.value().zip(table.init.y.value());
+            let odd_multiples: Option<Vec<_>> = table
+                .odd_multiples
+                .iter()
+                .map(|p| p.x.value().zip(p.y.value()))
+                .collect();
+            init.zip(odd_multiples)
+                .zip(windows.as_ref())
+                .map(|((init, odd_multiples), windows)| {
+                    ladder_values(init, &odd_multiples, windows)
+                })
+                .transpose()?
+        };
+
+        // Enable the selectors, and copy in the table.
+        for s in 0..WINDOWS_PER_LADDER {
+            let row = step_row(s);
+            self.q_mul_windowed.enable(region, row)?;
+            for ladder in 0..NUM_LADDERS {
+                self.q_mul_windowed_range_check
+                    .enable(region, row + ROW_LADDER + ladder)?;
+            }
+
+            for (j, point) in table.odd_multiples.iter().enumerate() {
+                let (x_col, x_row) = TABLE_CELLS[2 * j];
+                let (y_col, y_row) = TABLE_CELLS[2 * j + 1];
+                copy(region, || "x_p", self.advices[x_col], row + x_row, &point.x)?;
+                copy(region, || "y_p", self.advices[y_col], row + y_row, &point.y)?;
+            }
+        }
+
+        let mut z_prev = copy(region, || "z_prev", self.advices[0], 0, &table.k_254)?;
+        let mut z_130 = None;
+        let mut outputs: Vec<EccPoint<C>> = Vec::with_capacity(NUM_LADDERS);
+        for (ladder, &column) in self.advices.iter().take(NUM_LADDERS).enumerate() {
+            // Each ladder starts from the running sum and the output of the previous one.
+            if ladder > 0 {
+                let z = assign_cell(region, "z_prev", column, 0, z_prev.value())?;
+                region.constrain_equal(z.cell(), z_prev.cell())?;
+                z_prev = z;
+            }
+
+            for s in 0..WINDOWS_PER_LADDER {
+                let m = WINDOWS_PER_LADDER * ladder + s;
+                let row = step_row(s);
+                let step = values.as_ref().map(|values| values[ladder].steps[s]);
+
+                if s == 0 && ladder == 0 {
+                    copy(region, || "x_a", column, row + ROW_X_A, &table.init.x)?;
+                    copy(region, || "y_a", column, row + ROW_Y_A, &table.init.y)?;
+                } else {
+                    let x_a = step.map(|step| step.x_a);
+                    let y_a = step.map(|step| step.y_a);
+                    let x_a = assign_cell(region, "x_a", column, row + ROW_X_A, x_a)?;
+                    let y_a = assign_cell(region, "y_a", column, row + ROW_Y_A, y_a)?;
+                    if s == 0 {
+                        let input = &outputs[ladder - 1];
+                        region.constrain_equal(x_a.cell(), input.x.cell())?;
+                        region.constrain_equal(y_a.cell(), input.y.cell())?;
+                    }
+                }
+
+                let ladder_row = row + ROW_LADDER + ladder;
+                for (i, column) in self.advices.iter().take(WINDOW_SIZE).enumerate() {
+                    let lambda = step.map(|step| step.lambdas[i]);
+                    assign_cell(region, "λ", *column, ladder_row, lambda)?;
+                }
+                let cells = [
+                    ("λ", COL_LAMBDA, step.map(|step| step.lambda)),
+                    ("inv", COL_INV, step.map(|step| step.inv)),
+                    ("t", COL_T, step.map(|step| step.t)),
+                    ("t2", COL_T2, step.map(|step| step.t2)),
+                ];
+                for &(annotation, col, value) in cells.iter() {
+                    assign_cell(region, annotation, self.advices[col], ladder_row, value)?;
+                }
+                let x_q = step.map(|step| step.x_q);
+                let y_q_div_d = step.map(|step| step.y_q_div_d);
+                assign_cell(region, "x_q", column, row + ROW_X_Q, x_q)?;
+                assign_cell(region, "y_q / d", column, row + ROW_Y_Q_DIV_D, y_q_div_d)?;
+
+                // z = 16⋅z_prev + u
+                let u = windows
+                    .as_ref()
+                    .map(|windows| C::Base::from_u64(windows[m] as u64));
+                assign_cell(region, "u", self.advices[COL_U], ladder_row, u)?;
+                let z = z_prev
+                    .value()
+                    .zip(u)
+                    .map(|(z_prev, u)| z_prev * C::Base::from_u64(16) + u);
+                let z = assign_cell(region, "z", column, row + ROW_Z, z)?;
+
+                if m == Z_130_WINDOW {
+                    z_130 = Some(z);
+                }
+                z_prev = z;
+            }
+
+            // The output is the accumulator of the step following the last one.
+            let output = values.as_ref().map(|values| values[ladder].output);
+            outputs.push(EccPoint {
+                x: assign_cell(
+                    region,
+                    "x_out",
+                    column,
+                    output_row + ROW_X_A,
+                    output.map(|p| p.0),
+                )?,
+                y: assign_cell(
+                    region,
+                    "y_out",
+                    column,
+                    output_row + ROW_Y_A,
+                    output.map(|p| p.1),
+                )?,
+            });
+        }
+
+        Ok((
+            outputs.pop().expect("there is at least one ladder"),
+            z_130.expect("z_130 is assigned"),
+            z_prev,
+        ))
+    }
+}
+
+/// The points computed in-circuit for a windowed scalar mul.
+struct Table<C: EccCurve> {
+    // [1] P, [3] P, ..., [15] P
+    odd_multiples: Vec<EccPoint<C>>,
+    // [3 + 2 k_254] P
+    init: EccPoint<C>,
+    // The most significant bit k_254 of the scalar decomposition
+    k_254: CellValue<C::Base>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct StepValues<F: FieldExt> {
+    x_a: F,
+    y_a: F,
+    lambdas: [F; WINDOW_SIZE],
+    x_q: F,
+    y_q_div_d: F,
+    lambda: F,
+    inv: F,
+    t: F,
+    t2: F,
+}
+
+#[derive(Clone, Debug)]
+struct LadderValues<F: FieldExt> {
+    // Indexed by the step s of the ladder.
+    steps: Vec<StepValues<F>>,
+    output: (F, F),
+}
+
+/// Assigns `value` to a cell, returning an error if it is missing when
+/// witnessing.
+fn assign_cell<F: FieldExt>(
+    region: &mut Region<'_, F>,
+    annotation: &str,
+    column: Column<Advice>,
+    offset: usize,
+    value: Option<F>,
+) -> Result<CellValue<F>, Error> {
+    let cell = region.assign_advice(
+        || annotation,
+        column,
+        offset,
+        || value.ok_or(Error::SynthesisError),
+    )?;
+    Ok(CellValue::new(cell, value))
+}
+
+/// Computes the witness values of each ladder, starting from `acc`.
+///
+/// Returns an error if an incomplete addition has inputs with the same
+/// x-coordinate.
+fn ladder_values<F: FieldExt>(
+    mut acc: (F, F),
+    table: &[(F, F)],
+    windows: &[usize],
+) -> Result<Vec<LadderValues<F>>, Error> {
+    let mut ladders = Vec::with_capacity(NUM_LADDERS);
+    for ladder in 0..NUM_LADDERS {
+        let mut steps = Vec::with_capacity(WINDOWS_PER_LADDER);
+        for s in 0..WINDOWS_PER_LADDER {
+            let (x_a, y_a) = acc;
+
+            // Acc := [16] Acc, where λ = 3 x^2 / (2 y) for each doubling.
+            let mut lambdas = [F::zero(); WINDOW_SIZE];
+            for lambda in lambdas.iter_mut() {
+                let (x, y) = acc;
+                let inv = Option::<F>::from(y.double().invert()).ok_or(Error::SynthesisError)?;
+                *lambda = x.square() * F::from_u64(3) * inv;
+                let x_r = lambda.square() - x.double();
+                let y_r = *lambda * (x - x_r) - y;
+                acc = (x_r, y_r);
+            }
+
+            // Acc := Acc + [2u - 15] P
+            let u = windows[WINDOWS_PER_LADDER * ladder + s];
+            let half = TABLE_SIZE;
+            let (x_q, y_q) = if u >= half {
+                table[u - half]
+            } else {
+                let (x, y) = table[half - 1 - u];
+                (x, -y)
+            };
+            let (x_e, y_e) = acc;
+            let inv = Option::<F>::from((x_e - x_q).invert()).ok_or(Error::SynthesisError)?;
+            // λ = (y_e - y_q) / (x_e - x_q)
+            let lambda = (y_e - y_q) * inv;
+            let x_r = lambda.square() - x_e - x_q;
+            let y_r = lambda * (x_e - x_r) - y_e;
+
+            // d = 2u - 15, which is odd and therefore non-zero.
+            let d = F::from_u64(2 * u as u64) - F::from_u64(15);
+            let t = d.square();
+            steps.push(StepValues {
+                x_a,
+                y_a,
+                lambdas,
+                x_q,
+                y_q_div_d: y_q * d.invert().unwrap(),
+                lambda,
+                inv,
+                t,
+                t2: t.square(),
+            });
+            acc = (x_r, y_r);
+        }
+
+        ladders.push(LadderValues { steps, output: acc });
+    }
+
+    Ok(ladders)
+}
+
+/// Returns the coefficients, in increasing order of degree, of the Lagrange basis
+/// polynomials `M_j` on the points `(2j + 1)^2` for `j ∈ [0..8)`.
+fn lagrange_basis<F: FieldExt>() -> Vec<Vec<F>> {
+    let points: Vec<F> = (0..TABLE_SIZE)
+        .map(|j| F::from_u64(((2 * j + 1) * (2 * j + 1)) as u64))
+        .collect();
+    (0..TABLE_SIZE)
+        .map(|j| {
+            let evals: Vec<F> = (0..TABLE_SIZE)
+                .map(|i| if i == j { F::one() } else { F::zero() })
+                .collect();
+            lagrange_interpolate(&points, &evals)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::{
+            base_to_scalar,
+            test_circuit::{configure_ecc_chip, NoFixedBases},
+            EccChip, EccConfig, EccCurve, VarBaseMul,
+        },
+        gadget::{NonIdentityPoint, Point, FIXED_BASE_WINDOW_SIZE},
+    };
+    use utilities::UtilitiesInstructions;
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    type MyEccChip = EccChip<pallas::Affine, NoFixedBases, K>;
+
+    struct MyCircuit {
+        base: Option<pallas::Affine>,
+        scalar: Option<pallas::Base>,
+    }
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = EccConfig<pallas::Affine, K>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            MyCircuit {
+                base: None,
+                scalar: None,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_ecc_chip::<NoFixedBases, K>(meta, VarBaseMul::Windowed)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = MyEccChip::construct(config.clone());
+            config.lookup_config.load(&mut layouter)?;
+
+            let base =
+                NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "base"), self.base)?;
+            let scalar = chip.load_private(
+                layouter.namespace(|| "scalar"),
+                config.advices[0],
+                self.scalar,
+            )?;
+
+            // [scalar] base
+            {
+                let (result, _) = base.mul(layouter.namespace(|| "windowed mul"), &scalar)?;
+                let expected = self.base.zip(self.scalar).map(|(base, scalar)| {
+                    (base * base_to_scalar::<pallas::Affine>(scalar)).to_affine()
+                });
+                let expected =
+                    Point::new(chip.clone(), layouter.namespace(|| "expected"), expected)?;
+                result.constrain_equal(layouter.namespace(|| "[scalar] base"), &expected)?;
+            }
+
+            // Multiplication by a full-width scalar is unaffected by the mode.
+            {
+                let scalar = self.scalar.map(base_to_scalar::<pallas::Affine>);
+                let (result, _) =
+                    base.mul_full_width(layouter.namespace(|| "full-width mul"), scalar)?;
+                let expected = self
+                    .base
+                    .zip(scalar)
+                    .map(|(base, scalar)| (base * scalar).to_affine());
+                let expected =
+                    Point::new(chip, layouter.namespace(|| "expected full-width"), expected)?;
+                result.constrain_equal(
+                    layouter.namespace(|| "full-width [scalar] base"),
+                    &expected,
+                )?;
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn windowed_mul() {
+        let base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+
+        // 2^254 - t_q, for which k = 2^254 has k_254 = 1 and all other bits zero.
+        let k_254_only = {
+            let two_pow_127 = pallas::Base::from_u128(1 << 127);
+            two_pow_127 * two_pow_127 - pallas::Base::from_u128(pallas::Affine::T_Q)
+        };
+
+        let scalars = [
+            pallas::Base::rand(),
+            pallas::Base::zero(),
+            pallas::Base::one(),
+            -pallas::Base::one(),
+            k_254_only,
+            // k = 2^254 - 1, for which every window is 15.
+            k_254_only - pallas::Base::one(),
+        ];
+        for scalar in scalars.iter() {
+            let circuit = MyCircuit {
+                base: Some(base),
+                scalar: Some(*scalar),
+            };
+            let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use super::{
-    add, add_incomplete, CellValue, EccBaseFieldElemFixed, EccConfig, EccPoint, EccScalarFixed,
-    EccScalarFixedShort, FixedPoints, Var, FIXED_BASE_WINDOW_SIZE, H,
+    add, add_incomplete, base_to_scalar, CellValue, EccBaseFieldElemFixed, EccConfig, EccCurve,
+    EccPoint, EccScalarFixed, EccScalarFixedShort, FixedPoints, Var,
 };
 use std::marker::PhantomData;
 
@@ -10,31 +10,26 @@ use halo2::{
     plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector, VirtualCells},
     poly::Rotation,
 };
-use lazy_static::lazy_static;
-use pasta_curves::{
-    arithmetic::{CurveAffine, FieldExt},
-    pallas,
-};
+use pasta_curves::arithmetic::{CurveAffine, FieldExt};
 
 pub mod base_field_elem;
+pub mod cache;
 pub mod full_width;
 pub mod short;
 pub mod util;
 
-pub use util::{compute_lagrange_coeffs, compute_window_table, find_zs_and_us};
-
-lazy_static! {
-    static ref TWO_SCALAR: pallas::Scalar = pallas::Scalar::from_u64(2);
-    // H = 2^3 (3-bit window)
-    static ref H_SCALAR: pallas::Scalar = pallas::Scalar::from_u64(H as u64);
-    static ref H_BASE: pallas::Base = pallas::Base::from_u64(H as u64);
-}
+pub use cache::{find_zs_and_us_cached, find_zs_and_us_cached_in};
+pub use util::{
+    compute_lagrange_coeffs, compute_window_table, find_zs_and_us, mul_fixed_from_tables,
+};
 
 #[derive(Clone, Debug)]
-pub struct Config<F: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> {
+pub struct Config<C: EccCurve, F: FixedPoints<C, K>, const K: usize> {
     q_mul_fixed_running_sum: Selector,
+    // Number of `K`-bit windows in the scalar decomposition.
+    num_windows: usize,
     // The fixed Lagrange interpolation coefficients for `x_p`.
-    lagrange_coeffs: [Column<Fixed>; H],
+    lagrange_coeffs: Vec<Column<Fixed>>,
     // The fixed `z` for each window such that `y + z = u^2`.
     fixed_z: Column<Fixed>,
     // Decomposition of an `n-1`-bit scalar into `k`-bit windows:
@@ -47,19 +42,18 @@ pub struct Config<F: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> {
     // y-coordinate of accumulator (only used in the final row).
     u: Column<Advice>,
     // Configuration for `add`
-    add_config: add::Config,
+    add_config: add::Config<C>,
     // Configuration for `add_incomplete`
-    add_incomplete_config: add_incomplete::Config,
+    add_incomplete_config: add_incomplete::Config<C>,
     _marker: PhantomData<F>,
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> From<&EccConfig>
-    for Config<Fixed, NUM_WINDOWS>
-{
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> Config<C, Fixed, K> {
+    pub(super) fn new(ecc_config: &EccConfig<C, K>, num_windows: usize) -> Self {
         let config = Self {
             q_mul_fixed_running_sum: ecc_config.q_mul_fixed_running_sum,
-            lagrange_coeffs: ecc_config.lagrange_coeffs,
+            num_windows,
+            lagrange_coeffs: ecc_config.lagrange_coeffs.clone(),
             fixed_z: ecc_config.fixed_z,
             x_p: ecc_config.advices[0],
             y_p: ecc_config.advices[1],
@@ -102,9 +96,7 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> From<&EccConf
 
         config
     }
-}
 
-impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed, NUM_WINDOWS> {
     /// Check that each window in the running sum decomposition uses the correct y_p
     /// and interpolated x_p.
     ///
@@ -112,18 +104,18 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
     /// helpers, which decompose the scalar using a running sum.
     ///
     /// This gate is not used in the mul_fixed::full_width helper, since the full-width
-    /// scalar is witnessed directly as three-bit windows instead of being decomposed
+    /// scalar is witnessed directly as `K`-bit windows instead of being decomposed
     /// via a running sum.
-    pub(crate) fn running_sum_coords_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+    pub(crate) fn running_sum_coords_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("Running sum coordinates check", |meta| {
             let q_mul_fixed_running_sum = meta.query_selector(self.q_mul_fixed_running_sum);
 
             let z_cur = meta.query_advice(self.window, Rotation::cur());
             let z_next = meta.query_advice(self.window, Rotation::next());
 
-            //    z_{i+1} = (z_i - a_i) / 2^3
-            // => a_i = z_i - z_{i+1} * 2^3
-            let word = z_cur - z_next * pallas::Base::from_u64(H as u64);
+            //    z_{i+1} = (z_i - a_i) / 2^K
+            // => a_i = z_i - z_{i+1} * 2^K
+            let word = z_cur - z_next * C::Base::from_u64(1 << K);
 
             self.coords_check(meta, q_mul_fixed_running_sum, word)
         });
@@ -132,25 +124,25 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
     #[allow(clippy::op_ref)]
     fn coords_check(
         &self,
-        meta: &mut VirtualCells<'_, pallas::Base>,
-        toggle: Expression<pallas::Base>,
-        window: Expression<pallas::Base>,
-    ) -> Vec<(&'static str, Expression<pallas::Base>)> {
+        meta: &mut VirtualCells<'_, C::Base>,
+        toggle: Expression<C::Base>,
+        window: Expression<C::Base>,
+    ) -> Vec<(&'static str, Expression<C::Base>)> {
         let y_p = meta.query_advice(self.y_p, Rotation::cur());
         let x_p = meta.query_advice(self.x_p, Rotation::cur());
         let z = meta.query_fixed(self.fixed_z, Rotation::cur());
         let u = meta.query_advice(self.u, Rotation::cur());
 
-        let window_pow: Vec<Expression<pallas::Base>> = (0..H)
+        let window_pow: Vec<Expression<C::Base>> = (0..(1 << K))
             .map(|pow| {
-                (0..pow).fold(Expression::Constant(pallas::Base::one()), |acc, _| {
+                (0..pow).fold(Expression::Constant(C::Base::one()), |acc, _| {
                     acc * window.clone()
                 })
             })
             .collect();
 
         let interpolated_x = window_pow.iter().zip(self.lagrange_coeffs.iter()).fold(
-            Expression::Constant(pallas::Base::zero()),
+            Expression::Constant(C::Base::zero()),
             |acc, (window_pow, coeff)| {
                 acc + (window_pow.clone() * meta.query_fixed(*coeff, Rotation::cur()))
             },
@@ -161,8 +153,7 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
         // Check that `y + z = u^2`, where `z` is fixed and `u`, `y` are witnessed
         let y_check = u.square() - y_p.clone() - z;
         // Check that (x, y) is on the curve
-        let on_curve =
-            y_p.square() - x_p.clone().square() * x_p - Expression::Constant(pallas::Affine::b());
+        let on_curve = y_p.square() - x_p.clone().square() * x_p - Expression::Constant(C::b());
 
         vec![
             ("check x", toggle.clone() * x_check),
@@ -174,12 +165,12 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
     #[allow(clippy::type_complexity)]
     fn assign_region_inner(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        scalar: &ScalarFixed,
-        base: &Fixed,
+        scalar: &ScalarFixed<C>,
+        base: &impl FixedPoints<C, K>,
         coords_check_toggle: Selector,
-    ) -> Result<(EccPoint, EccPoint), Error> {
+    ) -> Result<(EccPoint<C>, EccPoint<C>), Error> {
         // Assign fixed columns for given fixed base
         self.assign_fixed_constants(region, offset, base, coords_check_toggle)?;
 
@@ -197,20 +188,28 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
 
     fn assign_fixed_constants(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        base: &Fixed,
+        base: &impl FixedPoints<C, K>,
         coords_check_toggle: Selector,
     ) -> Result<(), Error> {
         let lagrange_coeffs = base.lagrange_coeffs();
         let z = base.z();
 
+        // The last window of the tables depends on the number of windows, so the
+        // tables must have been computed for the width of this scalar.
+        assert_eq!(
+            z.len(),
+            self.num_windows,
+            "Fixed base tables have the wrong number of windows for this scalar."
+        );
+
         // Assign fixed columns for given fixed base
-        for window in 0..NUM_WINDOWS {
+        for window in 0..self.num_windows {
             coords_check_toggle.enable(region, window + offset)?;
 
             // Assign x-coordinate Lagrange interpolation coefficients
-            for k in 0..(H) {
+            for k in 0..(1 << K) {
                 region.assign_fixed(
                     || {
                         format!(
@@ -229,7 +228,7 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
                 || format!("z-value for window: {:?}", window),
                 self.fixed_z,
                 window + offset,
-                || Ok(pallas::Base::from_u64(z[window])),
+                || Ok(C::Base::from_u64(z[window])),
             )?;
         }
 
@@ -238,20 +237,23 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
 
     fn process_window(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
         w: usize,
-        k: Option<pallas::Scalar>,
+        k: Option<C::Scalar>,
         k_usize: Option<usize>,
-        base: &Fixed,
-    ) -> Result<EccPoint, Error> {
+        base: &impl FixedPoints<C, K>,
+    ) -> Result<EccPoint<C>, Error> {
         let base_value = base.generator();
         let base_u = base.u();
 
-        // Compute [(k_w + 2) ⋅ 8^w]B
+        // Compute [(k_w + 2) ⋅ (2^K)^w]B
         let mul_b = {
+            let two_scalar = C::ScalarExt::from_u64(2);
+            // h = 2^K (K-bit window)
+            let h_scalar = C::ScalarExt::from_u64(1 << K);
             let mul_b =
-                k.map(|k| base_value * (k + *TWO_SCALAR) * H_SCALAR.pow(&[w as u64, 0, 0, 0]));
+                k.map(|k| base_value * (k + two_scalar) * h_scalar.pow(&[w as u64, 0, 0, 0]));
             let mul_b = mul_b.map(|mul_b| mul_b.to_affine().coordinates().unwrap());
 
             let x = mul_b.map(|mul_b| *mul_b.x());
@@ -276,7 +278,7 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
         };
 
         // Assign u = (y_p + z_w).sqrt()
-        let u_val = k_usize.map(|k| pallas::Base::from_bytes(&base_u[w][k]).unwrap());
+        let u_val = k_usize.map(|k| C::Base::from_bytes(&base_u[w][k]).unwrap());
         region.assign_advice(
             || "u",
             self.u,
@@ -289,30 +291,30 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
 
     fn initialize_accumulator(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        base: &Fixed,
-        scalar: &ScalarFixed,
-    ) -> Result<EccPoint, Error> {
+        base: &impl FixedPoints<C, K>,
+        scalar: &ScalarFixed<C>,
+    ) -> Result<EccPoint<C>, Error> {
         // Recall that the message at each window `w` is represented as
-        // `m_w = [(k_w + 2) ⋅ 8^w]B`.
+        // `m_w = [(k_w + 2) ⋅ (2^K)^w]B`.
         // When `w = 0`, we have `m_0 = [(k_0 + 2)]B`.
         let w = 0;
-        let k0 = scalar.windows_field()[0];
-        let k0_usize = scalar.windows_usize()[0];
+        let k0 = scalar.windows_field::<K>()[0];
+        let k0_usize = scalar.windows_usize::<K>()[0];
         self.process_window(region, offset, w, k0, k0_usize, base)
     }
 
     fn add_incomplete(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        mut acc: EccPoint,
-        base: &Fixed,
-        scalar: &ScalarFixed,
-    ) -> Result<EccPoint, Error> {
-        let scalar_windows_field = scalar.windows_field();
-        let scalar_windows_usize = scalar.windows_usize();
+        mut acc: EccPoint<C>,
+        base: &impl FixedPoints<C, K>,
+        scalar: &ScalarFixed<C>,
+    ) -> Result<EccPoint<C>, Error> {
+        let scalar_windows_field = scalar.windows_field::<K>();
+        let scalar_windows_usize = scalar.windows_usize::<K>();
 
         for (w, (k, k_usize)) in scalar_windows_field[..(scalar_windows_field.len() - 1)]
             .iter()
@@ -321,7 +323,7 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
             // Skip k_0 (already processed).
             .skip(1)
         {
-            // Compute [(k_w + 2) ⋅ 8^w]B
+            // Compute [(k_w + 2) ⋅ (2^K)^w]B
             let mul_b = self.process_window(region, offset, w, *k, *k_usize, base)?;
 
             // Add to the accumulator
@@ -334,31 +336,36 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
 
     fn process_msb(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        base: &Fixed,
-        scalar: &ScalarFixed,
-    ) -> Result<EccPoint, Error> {
+        base: &impl FixedPoints<C, K>,
+        scalar: &ScalarFixed<C>,
+    ) -> Result<EccPoint<C>, Error> {
+        let num_windows = self.num_windows;
+
         // Assign u = (y_p + z_w).sqrt() for the most significant window
         {
-            let u_val = scalar.windows_usize()[NUM_WINDOWS - 1]
-                .map(|k| pallas::Base::from_bytes(&base.u()[NUM_WINDOWS - 1][k]).unwrap());
+            let u_val = scalar.windows_usize::<K>()[num_windows - 1]
+                .map(|k| C::Base::from_bytes(&base.u()[num_windows - 1][k]).unwrap());
             region.assign_advice(
                 || "u",
                 self.u,
-                offset + NUM_WINDOWS - 1,
+                offset + num_windows - 1,
                 || u_val.ok_or(Error::SynthesisError),
             )?;
         }
 
-        // offset_acc = \sum_{j = 0}^{NUM_WINDOWS - 2} 2^{FIXED_BASE_WINDOW_SIZE*j + 1}
-        let offset_acc = (0..(NUM_WINDOWS - 1)).fold(pallas::Scalar::zero(), |acc, w| {
-            acc + (*TWO_SCALAR).pow(&[FIXED_BASE_WINDOW_SIZE as u64 * w as u64 + 1, 0, 0, 0])
+        // offset_acc = \sum_{j = 0}^{num_windows - 2} 2^{K*j + 1}
+        let two_scalar = C::ScalarExt::from_u64(2);
+        let offset_acc = (0..(num_windows - 1)).fold(C::ScalarExt::zero(), |acc, w| {
+            acc + two_scalar.pow(&[K as u64 * w as u64 + 1, 0, 0, 0])
         });
 
-        // `scalar = [k * 8^84 - offset_acc]`, where `offset_acc = \sum_{j = 0}^{83} 2^{FIXED_BASE_WINDOW_SIZE*j + 1}`.
-        let scalar = scalar.windows_field()[scalar.windows_field().len() - 1]
-            .map(|k| k * (*H_SCALAR).pow(&[(NUM_WINDOWS - 1) as u64, 0, 0, 0]) - offset_acc);
+        // `scalar = [k * (2^K)^{num_windows - 1} - offset_acc]`.
+        let h_scalar = C::ScalarExt::from_u64(1 << K);
+        let windows_field = scalar.windows_field::<K>();
+        let scalar = windows_field[windows_field.len() - 1]
+            .map(|k| k * h_scalar.pow(&[(num_windows - 1) as u64, 0, 0, 0]) - offset_acc);
 
         let mul_b = {
             let mul_b = scalar.map(|scalar| base.generator() * scalar);
@@ -366,18 +373,18 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
 
             let x = mul_b.map(|mul_b| *mul_b.x());
             let x_cell = region.assign_advice(
-                || format!("mul_b_x, window {}", NUM_WINDOWS - 1),
+                || format!("mul_b_x, window {}", num_windows - 1),
                 self.x_p,
-                offset + NUM_WINDOWS - 1,
+                offset + num_windows - 1,
                 || x.ok_or(Error::SynthesisError),
             )?;
             let x = CellValue::new(x_cell, x);
 
             let y = mul_b.map(|mul_b| *mul_b.y());
             let y_cell = region.assign_advice(
-                || format!("mul_b_y, window {}", NUM_WINDOWS - 1),
+                || format!("mul_b_y, window {}", num_windows - 1),
                 self.y_p,
-                offset + NUM_WINDOWS - 1,
+                offset + num_windows - 1,
                 || y.ok_or(Error::SynthesisError),
             )?;
             let y = CellValue::new(y_cell, y);
@@ -389,70 +396,69 @@ impl<Fixed: FixedPoints<pallas::Affine>, const NUM_WINDOWS: usize> Config<Fixed,
     }
 }
 
-enum ScalarFixed {
-    FullWidth(EccScalarFixed),
-    Short(EccScalarFixedShort),
-    BaseFieldElem(EccBaseFieldElemFixed),
+enum ScalarFixed<C: EccCurve> {
+    FullWidth(EccScalarFixed<C>),
+    // The running sum decomposition of the magnitude of a short scalar.
+    Short(Vec<CellValue<C::Base>>),
+    BaseFieldElem(EccBaseFieldElemFixed<C>),
 }
 
-impl From<&EccScalarFixed> for ScalarFixed {
-    fn from(scalar_fixed: &EccScalarFixed) -> Self {
+impl<C: EccCurve> From<&EccScalarFixed<C>> for ScalarFixed<C> {
+    fn from(scalar_fixed: &EccScalarFixed<C>) -> Self {
         Self::FullWidth(scalar_fixed.clone())
     }
 }
 
-impl From<&EccScalarFixedShort> for ScalarFixed {
-    fn from(scalar_fixed: &EccScalarFixedShort) -> Self {
-        Self::Short(scalar_fixed.clone())
+impl<C: EccCurve> From<&EccScalarFixedShort<C>> for ScalarFixed<C> {
+    fn from(scalar_fixed: &EccScalarFixedShort<C>) -> Self {
+        Self::Short(scalar_fixed.running_sum.clone())
     }
 }
 
-impl From<&EccBaseFieldElemFixed> for ScalarFixed {
-    fn from(base_field_elem: &EccBaseFieldElemFixed) -> Self {
+impl<C: EccCurve> From<&EccBaseFieldElemFixed<C>> for ScalarFixed<C> {
+    fn from(base_field_elem: &EccBaseFieldElemFixed<C>) -> Self {
         Self::BaseFieldElem(base_field_elem.clone())
     }
 }
 
-impl ScalarFixed {
+impl<C: EccCurve> ScalarFixed<C> {
     // The scalar decomposition was done in the base field. For computation
     // outside the circuit, we now convert them back into the scalar field.
-    fn windows_field(&self) -> Vec<Option<pallas::Scalar>> {
-        let running_sum_to_windows = |zs: Vec<CellValue<pallas::Base>>| {
+    fn windows_field<const K: usize>(&self) -> Vec<Option<C::Scalar>> {
+        let h_base = C::Base::from_u64(1 << K);
+        let running_sum_to_windows = |zs: Vec<CellValue<C::Base>>| {
             (0..(zs.len() - 1))
                 .map(|idx| {
                     let z_cur = zs[idx].value();
                     let z_next = zs[idx + 1].value();
                     let word = z_cur
                         .zip(z_next)
-                        .map(|(z_cur, z_next)| z_cur - z_next * *H_BASE);
-                    word.map(|word| pallas::Scalar::from_bytes(&word.to_bytes()).unwrap())
+                        .map(|(z_cur, z_next)| z_cur - z_next * h_base);
+                    word.map(base_to_scalar::<C>)
                 })
                 .collect::<Vec<_>>()
         };
         match self {
-            Self::BaseFieldElem(scalar) => running_sum_to_windows(scalar.running_sum.to_vec()),
-            Self::Short(scalar) => running_sum_to_windows(scalar.running_sum.to_vec()),
+            Self::BaseFieldElem(scalar) => running_sum_to_windows(scalar.running_sum.clone()),
+            Self::Short(running_sum) => running_sum_to_windows(running_sum.clone()),
             Self::FullWidth(scalar) => scalar
                 .windows
                 .iter()
-                .map(|bits| {
-                    bits.value()
-                        .map(|value| pallas::Scalar::from_bytes(&value.to_bytes()).unwrap())
-                })
+                .map(|bits| bits.value().map(base_to_scalar::<C>))
                 .collect::<Vec<_>>(),
         }
     }
 
-    // The scalar decomposition is guaranteed to be in three-bit windows,
+    // The scalar decomposition is guaranteed to be in K-bit windows,
     // so we also cast the least significant 4 bytes in their serialisation
     // into usize for convenient indexing into `u`-values
-    fn windows_usize(&self) -> Vec<Option<usize>> {
-        self.windows_field()
+    fn windows_usize<const K: usize>(&self) -> Vec<Option<usize>> {
+        self.windows_field::<K>()
             .iter()
             .map(|window| {
                 if let Some(window) = window {
                     let window = window.get_lower_32() as usize;
-                    assert!(window < H);
+                    assert!(window < (1 << K));
                     Some(window)
                 } else {
                     None
@@ -1,8 +1,7 @@
 use super::super::{
-    EccBaseFieldElemFixed, EccConfig, EccPoint, FixedPoints, FIXED_BASE_WINDOW_SIZE, LOOKUP_K,
-    L_PALLAS_BASE, NUM_WINDOWS, T_P,
+    num_windows, EccBaseFieldElemFixed, EccConfig, EccCurve, EccPoint, FixedPoints, LOOKUP_K,
+    L_BASE,
 };
-use super::H_BASE;
 use utilities::{
     bitrange_subset, copy, decompose_running_sum::RunningSumConfig,
     lookup_range_check::LookupRangeCheckConfig, range_check, CellValue, Var,
@@ -13,28 +12,27 @@ use halo2::{
     plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
     poly::Rotation,
 };
-use pasta_curves::{arithmetic::FieldExt, pallas};
 
-use std::convert::TryInto;
-
-pub struct Config<Fixed: FixedPoints<pallas::Affine>> {
+pub struct Config<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> {
     q_mul_fixed_running_sum: Selector,
     q_mul_fixed_base_field: Selector,
     canon_advices: [Column<Advice>; 3],
-    lookup_config: LookupRangeCheckConfig<pallas::Base, LOOKUP_K>,
-    running_sum_config: RunningSumConfig<pallas::Base, { FIXED_BASE_WINDOW_SIZE }>,
-    super_config: super::Config<Fixed, { NUM_WINDOWS }>,
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    running_sum_config: RunningSumConfig<C::Base, K>,
+    super_config: super::Config<C, Fixed, K>,
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> From<&EccConfig> for Config<Fixed> {
-    fn from(config: &EccConfig) -> Self {
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> From<&EccConfig<C, K>>
+    for Config<C, Fixed, K>
+{
+    fn from(config: &EccConfig<C, K>) -> Self {
         let config = Self {
             q_mul_fixed_running_sum: config.q_mul_fixed_running_sum,
             q_mul_fixed_base_field: config.q_mul_fixed_base_field,
             canon_advices: [config.advices[6], config.advices[7], config.advices[8]],
             lookup_config: config.lookup_config.clone(),
             running_sum_config: config.running_sum_config.clone(),
-            super_config: config.into(),
+            super_config: super::Config::new(config, num_windows(L_BASE, K)),
         };
 
         let add_incomplete_advices = config.super_config.add_incomplete_config.advice_columns();
@@ -47,62 +45,77 @@ impl<Fixed: FixedPoints<pallas::Affine>> From<&EccConfig> for Config<Fixed> {
 
         assert_eq!(config.running_sum_config.z, config.super_config.window);
 
+        // α is split at bit 252 in the canonicity check.
+        assert_eq!(252 % K, 0);
+
         config
     }
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
-    pub fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> Config<C, Fixed, K> {
+    // Index of z_252, the running sum containing the top three bits of α.
+    // This is z_84 for 3-bit windows.
+    const Z_252_IDX: usize = 252 / K;
+
+    // Index of z_hi, the first running sum at or above bit 130 of α.
+    // This is z_44 for 3-bit windows.
+    const Z_HI_IDX: usize = (130 + K - 1) / K;
+
+    // Number of bits of the window a_{hi - 1} = z_{hi - 1} - 2^K z_hi that lie
+    // below bit 130. For 3-bit windows, this window is a_43 = α[129..=131],
+    // of which only α[129] may be set.
+    const A_HI_NUM_BITS: usize = 130 - (Self::Z_HI_IDX - 1) * K;
+
+    pub fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         // Check that the base field element is canonical.
         meta.create_gate("Canonicity checks", |meta| {
             let q_mul_fixed_base_field = meta.query_selector(self.q_mul_fixed_base_field);
 
             let alpha = meta.query_advice(self.canon_advices[0], Rotation::prev());
             // The last three bits of α.
-            let z_84_alpha = meta.query_advice(self.canon_advices[2], Rotation::prev());
+            let z_252_alpha = meta.query_advice(self.canon_advices[2], Rotation::prev());
 
             // Decompose α into three pieces, in little-endian order:
             //            α = α_0 (252 bits)  || α_1 (2 bits) || α_2 (1 bit).
             //
             // α_0 is derived, not witnessed.
             let alpha_0 = {
-                let two_pow_252 = pallas::Base::from_u128(1 << 126).square();
-                alpha - (z_84_alpha.clone() * two_pow_252)
+                let two_pow_252 = C::Base::from_u128(1 << 126).square();
+                alpha - (z_252_alpha.clone() * two_pow_252)
             };
             let alpha_1 = meta.query_advice(self.canon_advices[1], Rotation::cur());
             let alpha_2 = meta.query_advice(self.canon_advices[2], Rotation::cur());
 
             let alpha_0_prime = meta.query_advice(self.canon_advices[0], Rotation::cur());
             let z_13_alpha_0_prime = meta.query_advice(self.canon_advices[0], Rotation::next());
-            let z_44_alpha = meta.query_advice(self.canon_advices[1], Rotation::next());
-            let z_43_alpha = meta.query_advice(self.canon_advices[2], Rotation::next());
+            let z_hi_alpha = meta.query_advice(self.canon_advices[1], Rotation::next());
+            let z_hi_minus_1_alpha = meta.query_advice(self.canon_advices[2], Rotation::next());
 
             let decomposition_checks = {
                 // Range-constrain α_1 to be 2 bits
                 let alpha_1_range_check = range_check(alpha_1.clone(), 1 << 2);
                 // Boolean-constrain α_2
                 let alpha_2_range_check = range_check(alpha_2.clone(), 1 << 1);
-                // Check that α_1 + 2^2 α_2 = z_84_alpha
-                let z_84_alpha_check = z_84_alpha.clone()
-                    - (alpha_1.clone() + alpha_2.clone() * pallas::Base::from_u64(1 << 2));
+                // Check that α_1 + 2^2 α_2 = z_252_alpha
+                let z_252_alpha_check = z_252_alpha.clone()
+                    - (alpha_1.clone() + alpha_2.clone() * C::Base::from_u64(1 << 2));
 
                 std::iter::empty()
                     .chain(Some(("alpha_1_range_check", alpha_1_range_check)))
                     .chain(Some(("alpha_2_range_check", alpha_2_range_check)))
-                    .chain(Some(("z_84_alpha_check", z_84_alpha_check)))
+                    .chain(Some(("z_252_alpha_check", z_252_alpha_check)))
             };
 
             // Check α_0_prime = α_0 + 2^130 - t_p
             let alpha_0_prime_check = {
-                let two_pow_130 = Expression::Constant(pallas::Base::from_u128(1 << 65).square());
-                let t_p = Expression::Constant(pallas::Base::from_u128(T_P));
+                let two_pow_130 = Expression::Constant(C::Base::from_u128(1 << 65).square());
+                let t_p = Expression::Constant(C::Base::from_u128(C::T_P));
                 alpha_0_prime - (alpha_0 + two_pow_130 - t_p)
             };
 
             // We want to enforce canonicity of a 255-bit base field element, α.
-            // That is, we want to check that 0 ≤ α < p, where p is Pallas base
-            // field modulus p = 2^254 + t_p
-            //                 = 2^254 + 45560315531419706090280762371685220353.
+            // That is, we want to check that 0 ≤ α < p, where p is the base
+            // field modulus p = 2^254 + t_p.
             // Note that t_p < 2^130.
             //
             // α has been decomposed into three pieces in little-endian order:
@@ -113,31 +126,38 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
             //      - α_2 = 1 => α_1 = 0, and
             //      - α_2 = 1 => α_0 < t_p. To enforce this:
             //          - α_2 = 1 => 0 ≤ α_0 < 2^130
-            //                - alpha_0_hi_120 = 0 (constrain α_0 to be 132 bits)
-            //                - a_43 = 0 or 1 (constrain α_0[130..=131] to be 0)
+            //                - alpha_0_hi = 0 (constrain α_0 to be K * z_hi bits)
+            //                - a_hi < 2^A_HI_NUM_BITS (constrain the remaining bits
+            //                  of α_0 at or above bit 130 to be 0)
             //          - α_2 = 1 => 0 ≤ α_0 + 2^130 - t_p < 2^130
             //                    => 13 ten-bit lookups of α_0 + 2^130 - t_p
             //                    => z_13_alpha_0_prime = 0
             //
+            // For 3-bit windows, z_hi = z_44, so alpha_0_hi is α_0[132..252]
+            // and a_hi = a_43 = α_0[129..=131] must be 0 or 1.
             let canon_checks = {
-                // alpha_0_hi_120 = z_44 - 2^120 z_84
-                let alpha_0_hi_120 = {
-                    let two_pow_120 =
-                        Expression::Constant(pallas::Base::from_u128(1 << 60).square());
-                    z_44_alpha.clone() - z_84_alpha * two_pow_120
+                // alpha_0_hi = z_hi - 2^(252 - K * z_hi) z_252
+                let alpha_0_hi = {
+                    let two_pow = Expression::Constant(C::Base::from_u64(2).pow(&[
+                        (252 - Self::Z_HI_IDX * K) as u64,
+                        0,
+                        0,
+                        0,
+                    ]));
+                    z_hi_alpha.clone() - z_252_alpha * two_pow
                 };
-                // a_43 = z_43 - (2^3)z_44
-                let a_43 = z_43_alpha - z_44_alpha * *H_BASE;
+                // a_hi = z_{hi - 1} - (2^K) z_hi
+                let a_hi = z_hi_minus_1_alpha - z_hi_alpha * C::Base::from_u64(1 << K);
 
                 std::iter::empty()
                     .chain(Some(("MSB = 1 => alpha_1 = 0", alpha_2.clone() * alpha_1)))
                     .chain(Some((
-                        "MSB = 1 => alpha_0_hi_120 = 0",
-                        alpha_2.clone() * alpha_0_hi_120,
+                        "MSB = 1 => alpha_0_hi = 0",
+                        alpha_2.clone() * alpha_0_hi,
                     )))
                     .chain(Some((
-                        "MSB = 1 => a_43 = 0 or 1",
-                        alpha_2.clone() * range_check(a_43, 2),
+                        "MSB = 1 => a_hi range check",
+                        alpha_2.clone() * range_check(a_hi, 1 << Self::A_HI_NUM_BITS),
                     )))
                     .chain(Some((
                         "MSB = 1 => z_13_alpha_0_prime = 0",
@@ -154,10 +174,10 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
 
     pub fn assign(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        scalar: CellValue<pallas::Base>,
+        mut layouter: impl Layouter<C::Base>,
+        scalar: CellValue<C::Base>,
         base: &Fixed,
-    ) -> Result<EccPoint, Error> {
+    ) -> Result<EccPoint<C>, Error> {
         let (scalar, acc, mul_b) = layouter.assign_region(
             || "Base-field elem fixed-base mul (incomplete addition)",
             |mut region| {
@@ -170,12 +190,12 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                         offset,
                         scalar,
                         true,
-                        L_PALLAS_BASE,
-                        NUM_WINDOWS,
+                        L_BASE,
+                        self.super_config.num_windows,
                     )?;
                     EccBaseFieldElemFixed {
                         base_field_elem: running_sum[0],
-                        running_sum: (*running_sum).as_slice().try_into().unwrap(),
+                        running_sum: running_sum.to_vec(),
                     }
                 };
 
@@ -204,12 +224,10 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
         #[cfg(test)]
         // Check that the correct multiple is obtained.
         {
+            use super::super::super::base_to_scalar;
             use group::Curve;
 
-            let scalar = &scalar
-                .base_field_elem()
-                .value()
-                .map(|scalar| pallas::Scalar::from_bytes(&scalar.to_bytes()).unwrap());
+            let scalar = &scalar.base_field_elem().value().map(base_to_scalar::<C>);
             let real_mul = scalar.map(|scalar| base.generator() * scalar);
             let result = result.point();
 
@@ -219,9 +237,8 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
         }
 
         // We want to enforce canonicity of a 255-bit base field element, α.
-        // That is, we want to check that 0 ≤ α < p, where p is Pallas base
-        // field modulus p = 2^254 + t_p
-        //                 = 2^254 + 45560315531419706090280762371685220353.
+        // That is, we want to check that 0 ≤ α < p, where p is the base
+        // field modulus p = 2^254 + t_p.
         // Note that t_p < 2^130.
         //
         // α has been decomposed into three pieces in little-endian order:
@@ -238,24 +255,24 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
         //                => z_13_alpha_0_prime = 0
         //
         let (alpha, running_sum) = (scalar.base_field_elem, &scalar.running_sum);
-        let z_43_alpha = running_sum[43];
-        let z_44_alpha = running_sum[44];
-        let z_84_alpha = running_sum[84];
+        let z_hi_minus_1_alpha = running_sum[Self::Z_HI_IDX - 1];
+        let z_hi_alpha = running_sum[Self::Z_HI_IDX];
+        let z_252_alpha = running_sum[Self::Z_252_IDX];
 
-        // α_0 = α - z_84_alpha * 2^252
+        // α_0 = α - z_252_alpha * 2^252
         let alpha_0 = alpha
             .value()
-            .zip(z_84_alpha.value())
-            .map(|(alpha, z_84_alpha)| {
-                let two_pow_252 = pallas::Base::from_u128(1 << 126).square();
-                alpha - z_84_alpha * two_pow_252
+            .zip(z_252_alpha.value())
+            .map(|(alpha, z_252_alpha)| {
+                let two_pow_252 = C::Base::from_u128(1 << 126).square();
+                alpha - z_252_alpha * two_pow_252
             });
 
         let (alpha_0_prime, z_13_alpha_0_prime) = {
             // alpha_0_prime = alpha + 2^130 - t_p.
             let alpha_0_prime = alpha_0.map(|alpha_0| {
-                let two_pow_130 = pallas::Base::from_u128(1 << 65).square();
-                let t_p = pallas::Base::from_u128(T_P);
+                let two_pow_130 = C::Base::from_u128(1 << 65).square();
+                let t_p = C::Base::from_u128(C::T_P);
                 alpha_0 + two_pow_130 - t_p
             });
             let zs = self.lookup_config.witness_check(
@@ -288,13 +305,13 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                         &alpha,
                     )?;
 
-                    // z_84_alpha = the top three bits of alpha.
+                    // z_252_alpha = the top three bits of alpha.
                     copy(
                         &mut region,
-                        || "Copy z_84_alpha",
+                        || "Copy z_252_alpha",
                         self.canon_advices[2],
                         offset,
-                        &z_84_alpha,
+                        &z_252_alpha,
                     )?;
                 }
 
@@ -345,22 +362,22 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                         &z_13_alpha_0_prime,
                     )?;
 
-                    // Copy z_44_alpha
+                    // Copy z_hi_alpha
                     copy(
                         &mut region,
-                        || "Copy z_44_alpha",
+                        || "Copy z_hi_alpha",
                         self.canon_advices[1],
                         offset,
-                        &z_44_alpha,
+                        &z_hi_alpha,
                     )?;
 
-                    // Copy z_43_alpha
+                    // Copy z_{hi - 1}_alpha
                     copy(
                         &mut region,
-                        || "Copy z_43_alpha",
+                        || "Copy z_{hi - 1}_alpha",
                         self.canon_advices[2],
                         offset,
-                        &z_43_alpha,
+                        &z_hi_minus_1_alpha,
                     )?;
                 }
 
@@ -383,7 +400,7 @@ pub mod tests {
 
     use crate::{
         chip::{EccChip, NUM_WINDOWS},
-        gadget::{FixedPoint, FixedPoints, Point, H},
+        gadget::{FixedPoint, FixedPoints, Point, FIXED_BASE_WINDOW_SIZE, H},
     };
     use utilities::UtilitiesInstructions;
 
@@ -391,13 +408,14 @@ pub mod tests {
 
     lazy_static! {
         static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
-        static ref ZS_AND_US: Vec<(u64, [[u8; 32]; H])> =
-            crate::chip::find_zs_and_us(*BASE, NUM_WINDOWS).unwrap();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            crate::chip::find_zs_and_us_cached::<_, { FIXED_BASE_WINDOW_SIZE }>(*BASE, NUM_WINDOWS)
+                .unwrap();
     }
 
-    pub fn test_mul_fixed_base_field<F: FixedPoints<pallas::Affine>>(
+    pub fn test_mul_fixed_base_field<F: FixedPoints<pallas::Affine, K>, const K: usize>(
         base: F,
-        chip: EccChip<F>,
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
         test_single_base(
@@ -409,20 +427,20 @@ pub mod tests {
     }
 
     #[allow(clippy::op_ref)]
-    fn test_single_base<F: FixedPoints<pallas::Affine>>(
-        chip: EccChip<F>,
+    fn test_single_base<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
-        base: FixedPoint<pallas::Affine, EccChip<F>>,
+        base: FixedPoint<pallas::Affine, EccChip<pallas::Affine, F, K>>,
         base_val: pallas::Affine,
     ) -> Result<(), Error> {
         let column = chip.config().advices[0];
 
-        fn constrain_equal<F: FixedPoints<pallas::Affine>>(
-            chip: EccChip<F>,
+        fn constrain_equal<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
             base_val: pallas::Affine,
             scalar_val: pallas::Base,
-            result: Point<pallas::Affine, EccChip<F>>,
+            result: Point<pallas::Affine, EccChip<pallas::Affine, F, K>>,
         ) -> Result<(), Error> {
             // Move scalar from base field into scalar field (which always fits for Pallas).
             let scalar = pallas::Scalar::from_bytes(&scalar_val.to_bytes()).unwrap();
@@ -0,0 +1,245 @@
+//! On-disk cache for the $z$- and $u$-values of fixed bases.
+//!
+//! [`find_zs_and_us`] searches for each $z$ by brute force, which dominates setup
+//! time when there are many fixed bases. The results are cached in files keyed by
+//! the curve, the window size, the number of windows and the encoding of the base.
+//! Cached values are checked against the base before they are used, so a stale or
+//! corrupted file is recomputed rather than trusted.
+//!
+//! The cache directory is `$HALO2_ECC_CACHE_DIR` if it is set, and `halo2_ecc` in
+//! the system temporary directory otherwise.
+
+use std::{
+    convert::TryInto,
+    env, fs, io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use ff::Field;
+use group::GroupEncoding;
+use pasta_curves::arithmetic::{CurveAffine, CurveExt, FieldExt};
+
+use super::util::{compute_window_table, find_zs_and_us};
+
+/// Identifies the cache file format.
+const MAGIC: &[u8; 8] = b"zsus\x00\x00\x00\x01";
+
+/// Returns the directory in which $z$- and $u$-values are cached.
+pub fn cache_dir() -> PathBuf {
+    env::var_os("HALO2_ECC_CACHE_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| env::temp_dir().join("halo2_ecc"))
+}
+
+/// Like [`find_zs_and_us`], but reads the values from the cache in [`cache_dir`]
+/// if they are present, and writes them to it otherwise.
+pub fn find_zs_and_us_cached<C: CurveAffine, const K: usize>(
+    base: C,
+    num_windows: usize,
+) -> Option<Vec<(u64, Vec<[u8; 32]>)>> {
+    find_zs_and_us_cached_in::<C, K>(&cache_dir(), base, num_windows)
+}
+
+/// Like [`find_zs_and_us_cached`], but uses the cache in `dir`.
+///
+/// Failing to read or write the cache is not an error: the values are computed
+/// with [`find_zs_and_us`] instead.
+pub fn find_zs_and_us_cached_in<C: CurveAffine, const K: usize>(
+    dir: &Path,
+    base: C,
+    num_windows: usize,
+) -> Option<Vec<(u64, Vec<[u8; 32]>)>> {
+    let path = dir.join(file_name::<C, K>(base, num_windows));
+
+    if let Some(zs_and_us) = fs::read(&path)
+        .ok()
+        .and_then(|bytes| decode::<C, K>(&bytes, base, num_windows))
+    {
+        return Some(zs_and_us);
+    }
+
+    let zs_and_us = find_zs_and_us::<C, K>(base, num_windows)?;
+    let _ = write_atomic(dir, &path, &encode::<C, K>(base, &zs_and_us));
+    Some(zs_and_us)
+}
+
+fn file_name<C: CurveAffine, const K: usize>(base: C, num_windows: usize) -> String {
+    let encoding: String = base
+        .to_bytes()
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+    format!(
+        "{}-{}-{}-{}.zsus",
+        C::CurveExt::CURVE_ID,
+        K,
+        num_windows,
+        encoding
+    )
+}
+
+/// The file header: magic bytes, window size, number of windows and the
+/// encoding of the base.
+fn header<C: CurveAffine, const K: usize>(base: C, num_windows: usize) -> Vec<u8> {
+    let mut header = MAGIC.to_vec();
+    header.push(K as u8);
+    header.extend_from_slice(&(num_windows as u32).to_le_bytes());
+    header.extend_from_slice(base.to_bytes().as_ref());
+    header
+}
+
+/// Encodes the header followed by, for each window, $z$ as a little-endian `u64`
+/// and the $2^K$ $u$-values.
+fn encode<C: CurveAffine, const K: usize>(base: C, zs_and_us: &[(u64, Vec<[u8; 32]>)]) -> Vec<u8> {
+    let mut bytes = header::<C, K>(base, zs_and_us.len());
+    for (z, us) in zs_and_us.iter() {
+        bytes.extend_from_slice(&z.to_le_bytes());
+        for u in us.iter() {
+            bytes.extend_from_slice(u);
+        }
+    }
+    bytes
+}
+
+/// Decodes cached values, returning `None` if the header does not match or the
+/// values are not valid for `base`.
+fn decode<C: CurveAffine, const K: usize>(
+    bytes: &[u8],
+    base: C,
+    num_windows: usize,
+) -> Option<Vec<(u64, Vec<[u8; 32]>)>> {
+    let body = bytes.strip_prefix(header::<C, K>(base, num_windows).as_slice())?;
+
+    let window_len = 8 + 32 * (1 << K);
+    if body.len() != num_windows * window_len {
+        return None;
+    }
+
+    let zs_and_us: Vec<_> = body
+        .chunks_exact(window_len)
+        .map(|window| {
+            let z = u64::from_le_bytes(window[..8].try_into().unwrap());
+            let us = window[8..]
+                .chunks_exact(32)
+                .map(|u| u.try_into().unwrap())
+                .collect();
+            (z, us)
+        })
+        .collect();
+
+    if is_valid::<C, K>(base, &zs_and_us) {
+        Some(zs_and_us)
+    } else {
+        None
+    }
+}
+
+/// Checks that for each point $(x, y)$ in each window, $z + y = u^2$ and $z - y$
+/// is not a square.
+fn is_valid<C: CurveAffine, const K: usize>(base: C, zs_and_us: &[(u64, Vec<[u8; 32]>)]) -> bool {
+    let window_table = compute_window_table::<C, K>(base, zs_and_us.len());
+
+    window_table
+        .iter()
+        .zip(zs_and_us.iter())
+        .all(|(window_points, (z, us))| {
+            let z = C::Base::from_u64(*z);
+            window_points.iter().zip(us.iter()).all(|(point, u)| {
+                let y = *point.coordinates().unwrap().y();
+                let u = Option::<C::Base>::from(C::Base::from_bytes(u));
+                u.map_or(false, |u| u.square() == z + y) && bool::from((z - y).sqrt().is_none())
+            })
+        })
+}
+
+/// Writes `bytes` to a temporary file in `dir` and then renames it to `path`, so
+/// that concurrent readers never see a partially written file.
+fn write_atomic(dir: &Path, path: &Path, bytes: &[u8]) -> io::Result<()> {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fs::create_dir_all(dir)?;
+    let tmp = path.with_extension(format!(
+        "{}.{}.tmp",
+        process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    ));
+    fs::write(&tmp, bytes)?;
+    fs::rename(&tmp, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decode, encode, file_name, find_zs_and_us_cached_in, header};
+    use crate::chip::{find_zs_and_us, NUM_WINDOWS_SHORT};
+    use crate::gadget::FIXED_BASE_WINDOW_SIZE;
+
+    use group::{Curve, Group};
+    use pasta_curves::pallas;
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    #[test]
+    fn cache_round_trip() {
+        // Use a fresh directory rather than the shared cache, so that concurrent
+        // test runs and files left by other checkouts cannot interfere.
+        let dir = tempfile::tempdir().unwrap();
+        let base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+
+        let expected = find_zs_and_us::<_, K>(base, NUM_WINDOWS_SHORT).unwrap();
+
+        // Cache miss writes the file; cache hit reads it back.
+        let computed =
+            find_zs_and_us_cached_in::<_, K>(dir.path(), base, NUM_WINDOWS_SHORT).unwrap();
+        assert_eq!(computed, expected);
+        let path = dir.path().join(file_name::<_, K>(base, NUM_WINDOWS_SHORT));
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            encode::<_, K>(base, &expected)
+        );
+        let cached = find_zs_and_us_cached_in::<_, K>(dir.path(), base, NUM_WINDOWS_SHORT).unwrap();
+        assert_eq!(cached, expected);
+
+        // A corrupted file is recomputed and overwritten.
+        std::fs::write(&path, b"corrupted").unwrap();
+        let recomputed =
+            find_zs_and_us_cached_in::<_, K>(dir.path(), base, NUM_WINDOWS_SHORT).unwrap();
+        assert_eq!(recomputed, expected);
+        assert_eq!(
+            std::fs::read(&path).unwrap(),
+            encode::<_, K>(base, &expected)
+        );
+    }
+
+    #[test]
+    fn cache_rejects_invalid_values() {
+        let base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let zs_and_us = find_zs_and_us::<_, K>(base, NUM_WINDOWS_SHORT).unwrap();
+        let bytes = encode::<_, K>(base, &zs_and_us);
+        assert_eq!(
+            decode::<_, K>(&bytes, base, NUM_WINDOWS_SHORT),
+            Some(zs_and_us)
+        );
+
+        // Wrong number of windows
+        assert_eq!(decode::<_, K>(&bytes, base, NUM_WINDOWS_SHORT - 1), None);
+
+        // Wrong base
+        let other_base = (base + base).to_affine();
+        assert_eq!(decode::<_, K>(&bytes, other_base, NUM_WINDOWS_SHORT), None);
+
+        // Corrupted u-value
+        let mut corrupted = bytes.clone();
+        let offset = header::<_, K>(base, NUM_WINDOWS_SHORT).len() + 8;
+        corrupted[offset] ^= 1;
+        assert_eq!(decode::<_, K>(&corrupted, base, NUM_WINDOWS_SHORT), None);
+
+        // Truncated file
+        assert_eq!(
+            decode::<_, K>(&bytes[..bytes.len() - 1], base, NUM_WINDOWS_SHORT),
+            None
+        );
+    }
+}
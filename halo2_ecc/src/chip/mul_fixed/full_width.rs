@@ -1,34 +1,64 @@
 use super::super::{
-    EccConfig, EccPoint, EccScalarFixed, FixedPoints, FIXED_BASE_WINDOW_SIZE, H, L_PALLAS_SCALAR,
-    NUM_WINDOWS,
+    base_to_scalar, num_windows, EccConfig, EccCurve, EccPoint, EccScalarFixed, FixedPoints,
+    LOOKUP_K, L_SCALAR,
 };
 
-use arrayvec::ArrayVec;
 use halo2::{
     circuit::{Layouter, Region},
-    plonk::{ConstraintSystem, Error, Selector},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
     poly::Rotation,
 };
-use pasta_curves::{arithmetic::FieldExt, pallas};
-use utilities::{decompose_word, range_check, CellValue, Var};
+use utilities::{
+    copy, decompose_word, lookup_range_check::LookupRangeCheckConfig, range_check, CellValue, Var,
+};
 
-pub struct Config<Fixed: FixedPoints<pallas::Affine>> {
+pub struct Config<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> {
     q_mul_fixed_full: Selector,
-    super_config: super::Config<Fixed, NUM_WINDOWS>,
+    q_mul_fixed_full_running_sum: Selector,
+    q_mul_fixed_full_canon: Selector,
+    canon_advices: [Column<Advice>; 3],
+    lookup_config: LookupRangeCheckConfig<C::Base, LOOKUP_K>,
+    super_config: super::Config<C, Fixed, K>,
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> From<&EccConfig> for Config<Fixed> {
-    fn from(config: &EccConfig) -> Self {
-        Self {
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> From<&EccConfig<C, K>>
+    for Config<C, Fixed, K>
+{
+    fn from(config: &EccConfig<C, K>) -> Self {
+        let config = Self {
             q_mul_fixed_full: config.q_mul_fixed_full,
-            super_config: config.into(),
+            q_mul_fixed_full_running_sum: config.q_mul_fixed_full_running_sum,
+            q_mul_fixed_full_canon: config.q_mul_fixed_full_canon,
+            canon_advices: [config.advices[6], config.advices[7], config.advices[8]],
+            lookup_config: config.lookup_config.clone(),
+            super_config: super::Config::new(config, num_windows(L_SCALAR, K)),
+        };
+
+        // The running sum of copied windows is assigned alongside the scalar mul.
+        let add_incomplete_advices = config.super_config.add_incomplete_config.advice_columns();
+        for canon_advice in config.canon_advices.iter() {
+            assert!(
+                !add_incomplete_advices.contains(canon_advice),
+                "Deconflict canon_advice columns with incomplete addition columns."
+            );
+            assert_ne!(*canon_advice, config.super_config.window);
+            assert_ne!(*canon_advice, config.super_config.u);
         }
+
+        // The scalar is split at bit 252 in the canonicity check.
+        assert_eq!(252 % K, 0);
+
+        config
     }
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
-    pub fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
-        // Check that each window `k` is within 3 bits
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> Config<C, Fixed, K> {
+    // Index of z_252, the running sum containing the bits of the scalar at or
+    // above bit 252. This is z_84 for 3-bit windows.
+    const Z_252_IDX: usize = 252 / K;
+
+    pub fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // Check that each window `k` is within K bits
         meta.create_gate("Full-width fixed-base scalar mul", |meta| {
             let q_mul_fixed_full = meta.query_selector(self.q_mul_fixed_full);
             let window = meta.query_advice(self.super_config.window, Rotation::cur());
@@ -36,25 +66,99 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
             self.super_config
                 .coords_check(meta, q_mul_fixed_full.clone(), window.clone())
                 .into_iter()
-                // Constrain each window to a 3-bit value:
-                // 1 * (window - 0) * (window - 1) * ... * (window - 7)
+                // Constrain each window to a K-bit value:
+                // 1 * (window - 0) * (window - 1) * ... * (window - (2^K - 1))
                 .chain(Some((
                     "window range check",
-                    q_mul_fixed_full * range_check(window, H),
+                    q_mul_fixed_full * range_check(window, 1 << K),
                 )))
         });
     }
 
-    /// Witnesses the given scalar as `NUM_WINDOWS` 3-bit windows.
+    /// Creates the gates used when the scalar is copied in as existing windows.
+    pub fn create_canonicity_gates(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // Check that the running sum z is derived from the copied windows:
+        //     z_i = k_i + 2^K z_{i+1}.
+        meta.create_gate("Full-width fixed-base scalar running sum", |meta| {
+            let q_mul_fixed_full_running_sum =
+                meta.query_selector(self.q_mul_fixed_full_running_sum);
+
+            let window = meta.query_advice(self.super_config.window, Rotation::cur());
+            let z_cur = meta.query_advice(self.canon_advices[0], Rotation::cur());
+            let z_next = meta.query_advice(self.canon_advices[0], Rotation::next());
+
+            vec![
+                q_mul_fixed_full_running_sum
+                    * (z_cur - (window + z_next * C::Base::from_u64(1 << K))),
+            ]
+        });
+
+        meta.create_gate("Full-width fixed-base scalar canonicity checks", |meta| {
+            let q_mul_fixed_full_canon = meta.query_selector(self.q_mul_fixed_full_canon);
+
+            // z_0 = k (mod p)
+            let z_0 = meta.query_advice(self.canon_advices[0], Rotation::cur());
+            // The bits of k at or above bit 252.
+            let z_252 = meta.query_advice(self.canon_advices[1], Rotation::cur());
+            let alpha_0_prime = meta.query_advice(self.canon_advices[2], Rotation::cur());
+            let z_13_alpha_0_prime = meta.query_advice(self.canon_advices[0], Rotation::next());
+
+            // We want to enforce canonicity of the scalar k. That is, we want to
+            // check that 0 ≤ k < q, where q is the scalar field modulus
+            // q = 2^254 + t_q. Note that t_q < 2^130.
+            //
+            // k is split into two pieces in little-endian order:
+            //            k = α_0 (252 bits) || z_252,
+            //              = α_0 + 2^252 z_252,
+            // where α_0 = z_0 - 2^252 z_252 is derived, not witnessed.
+            //
+            // Then k < q if and only if either:
+            //      - z_252 < 4, so that k < 2^254; or
+            //      - z_252 = 4 and α_0 < t_q. To enforce this:
+            //          - z_252 = 4 => 0 ≤ α_0 + 2^130 - t_q < 2^130
+            //                      => 13 ten-bit lookups of α_0 + 2^130 - t_q
+            //                      => z_13_alpha_0_prime = 0
+            //
+            // Since α_0 < 2^252, α_0 + 2^130 - t_q does not wrap around p.
+            let alpha_0 = {
+                let two_pow_252 = C::Base::from_u128(1 << 126).square();
+                z_0 - z_252.clone() * two_pow_252
+            };
+
+            // Check α_0_prime = α_0 + 2^130 - t_q
+            let alpha_0_prime_check = {
+                let two_pow_130 = Expression::Constant(C::Base::from_u128(1 << 65).square());
+                let t_q = Expression::Constant(C::Base::from_u128(C::T_Q));
+                alpha_0_prime - (alpha_0 + two_pow_130 - t_q)
+            };
+
+            // z_252 ∈ [0, 4]
+            let z_252_range_check = range_check(z_252.clone(), 5);
+
+            // Given z_252 ∈ [0, 4], this is non-zero if and only if z_252 = 4.
+            let z_252_is_four = range_check(z_252, 4);
+
+            std::iter::empty()
+                .chain(Some(("z_252 range check", z_252_range_check)))
+                .chain(Some(("alpha_0_prime check", alpha_0_prime_check)))
+                .chain(Some((
+                    "z_252 = 4 => z_13_alpha_0_prime = 0",
+                    z_252_is_four * z_13_alpha_0_prime,
+                )))
+                .map(move |(name, poly)| (name, q_mul_fixed_full_canon.clone() * poly))
+        });
+    }
+
+    /// Witnesses the given scalar as `num_windows` K-bit windows.
     ///
     /// The scalar is allowed to be non-canonical.
     fn witness(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        scalar: Option<pallas::Scalar>,
-    ) -> Result<EccScalarFixed, Error> {
-        let windows = self.decompose_scalar_fixed::<L_PALLAS_SCALAR>(scalar, offset, region)?;
+        scalar: Option<C::Scalar>,
+    ) -> Result<EccScalarFixed<C>, Error> {
+        let windows = self.decompose_scalar_fixed::<L_SCALAR>(scalar, offset, region)?;
 
         Ok(EccScalarFixed {
             value: scalar,
@@ -62,36 +166,37 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
         })
     }
 
-    /// Witnesses the given scalar as `NUM_WINDOWS` 3-bit windows.
+    /// Witnesses the given scalar as `num_windows` K-bit windows.
     ///
     /// The scalar is allowed to be non-canonical.
     fn decompose_scalar_fixed<const SCALAR_NUM_BITS: usize>(
         &self,
-        scalar: Option<pallas::Scalar>,
+        scalar: Option<C::Scalar>,
         offset: usize,
-        region: &mut Region<'_, pallas::Base>,
-    ) -> Result<ArrayVec<CellValue<pallas::Base>, NUM_WINDOWS>, Error> {
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<Vec<CellValue<C::Base>>, Error> {
+        let num_windows = self.super_config.num_windows;
+
         // Enable `q_mul_fixed_full` selector
-        for idx in 0..NUM_WINDOWS {
+        for idx in 0..num_windows {
             self.q_mul_fixed_full.enable(region, offset + idx)?;
         }
 
         // Decompose scalar into `k-bit` windows
-        let scalar_windows: Option<Vec<u8>> = scalar.map(|scalar| {
-            decompose_word::<pallas::Scalar>(scalar, SCALAR_NUM_BITS, FIXED_BASE_WINDOW_SIZE)
-        });
+        let scalar_windows: Option<Vec<u8>> =
+            scalar.map(|scalar| decompose_word::<C::ScalarExt>(scalar, SCALAR_NUM_BITS, K));
 
         // Store the scalar decomposition
-        let mut windows: ArrayVec<CellValue<pallas::Base>, NUM_WINDOWS> = ArrayVec::new();
+        let mut windows: Vec<CellValue<C::Base>> = Vec::with_capacity(num_windows);
 
-        let scalar_windows: Vec<Option<pallas::Base>> = if let Some(windows) = scalar_windows {
-            assert_eq!(windows.len(), NUM_WINDOWS);
+        let scalar_windows: Vec<Option<C::Base>> = if let Some(windows) = scalar_windows {
+            assert_eq!(windows.len(), num_windows);
             windows
                 .into_iter()
-                .map(|window| Some(pallas::Base::from_u64(window as u64)))
+                .map(|window| Some(C::Base::from_u64(window as u64)))
                 .collect()
         } else {
-            vec![None; NUM_WINDOWS]
+            vec![None; num_windows]
         };
 
         for (idx, window) in scalar_windows.into_iter().enumerate() {
@@ -109,10 +214,10 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
 
     pub fn assign(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        scalar: Option<pallas::Scalar>,
+        mut layouter: impl Layouter<C::Base>,
+        scalar: Option<C::Scalar>,
         base: &Fixed,
-    ) -> Result<(EccPoint, EccScalarFixed), Error> {
+    ) -> Result<(EccPoint<C>, EccScalarFixed<C>), Error> {
         let (scalar, acc, mul_b) = layouter.assign_region(
             || "Full-width fixed-base mul (incomplete addition)",
             |mut region| {
@@ -157,22 +262,272 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
 
         Ok((result, scalar))
     }
+
+    /// Copies in the given scalar, decomposed into `num_windows` K-bit windows in
+    /// little-endian order, and computes the running sum
+    /// `z_i = k_i + 2^K z_{i+1}` from `z_{num_windows} = 0`.
+    ///
+    /// Returns the scalar and the running sum `[z_0, ..., z_{num_windows}]`.
+    fn copy_windows(
+        &self,
+        region: &mut Region<'_, C::Base>,
+        offset: usize,
+        windows: &[CellValue<C::Base>],
+    ) -> Result<(EccScalarFixed<C>, Vec<CellValue<C::Base>>), Error> {
+        let num_windows = self.super_config.num_windows;
+        let h = C::Base::from_u64(1 << K);
+
+        // The windows are used to index into the u-values of the fixed base, so
+        // they must be K-bit values to be assigned at all.
+        for window in windows.iter() {
+            if let Some(window) = window.value() {
+                if !(0..(1 << K)).any(|k| window == C::Base::from_u64(k)) {
+                    return Err(Error::SynthesisError);
+                }
+            }
+        }
+
+        // Copy the windows, enabling `q_mul_fixed_full` to range-constrain them.
+        let windows = windows
+            .iter()
+            .enumerate()
+            .map(|(idx, window)| {
+                self.q_mul_fixed_full.enable(region, offset + idx)?;
+                copy(
+                    region,
+                    || format!("k[{:?}]", idx),
+                    self.super_config.window,
+                    offset + idx,
+                    window,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // Assign the running sum, starting from z_{num_windows} = 0.
+        let mut running_sum = Vec::with_capacity(num_windows + 1);
+        let mut z = {
+            let z_cell = region.assign_advice_from_constant(
+                || format!("z_{:?} = 0", num_windows),
+                self.canon_advices[0],
+                offset + num_windows,
+                C::Base::zero(),
+            )?;
+            CellValue::new(z_cell, Some(C::Base::zero()))
+        };
+        running_sum.push(z);
+        for (idx, window) in windows.iter().enumerate().rev() {
+            self.q_mul_fixed_full_running_sum
+                .enable(region, offset + idx)?;
+
+            let z_val = window.value().zip(z.value()).map(|(k, z)| k + z * h);
+            let z_cell = region.assign_advice(
+                || format!("z_{:?}", idx),
+                self.canon_advices[0],
+                offset + idx,
+                || z_val.ok_or(Error::SynthesisError),
+            )?;
+            z = CellValue::new(z_cell, z_val);
+            running_sum.push(z);
+        }
+        running_sum.reverse();
+
+        // scalar = Σ k_i (2^K)^i
+        let h_scalar = C::ScalarExt::from_u64(1 << K);
+        let value = windows
+            .iter()
+            .rev()
+            .fold(Some(C::ScalarExt::zero()), |acc, window| {
+                acc.zip(window.value())
+                    .map(|(acc, window)| acc * h_scalar + base_to_scalar::<C>(window))
+            });
+
+        Ok((EccScalarFixed { value, windows }, running_sum))
+    }
+
+    /// Performs fixed-base scalar multiplication by a full-width scalar that is
+    /// copied in from the given little-endian K-bit windows, and constrains the
+    /// scalar to be canonical.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of windows is not `num_windows(L_SCALAR, K)`.
+    pub fn assign_from_windows(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        windows: &[CellValue<C::Base>],
+        base: &Fixed,
+    ) -> Result<(EccPoint<C>, EccScalarFixed<C>), Error> {
+        assert_eq!(windows.len(), self.super_config.num_windows);
+
+        let (scalar, running_sum, acc, mul_b) = layouter.assign_region(
+            || "Full-width fixed-base mul from windows (incomplete addition)",
+            |mut region| {
+                let offset = 0;
+
+                let (scalar, running_sum) = self.copy_windows(&mut region, offset, windows)?;
+
+                let (acc, mul_b) = self.super_config.assign_region_inner(
+                    &mut region,
+                    offset,
+                    &(&scalar).into(),
+                    base,
+                    self.q_mul_fixed_full,
+                )?;
+
+                Ok((scalar, running_sum, acc, mul_b))
+            },
+        )?;
+
+        // Add to the accumulator and return the final result as `[scalar]B`.
+        let result = layouter.assign_region(
+            || "Full-width fixed-base mul from windows (last window, complete addition)",
+            |mut region| {
+                self.super_config
+                    .add_config
+                    .assign_region(&mul_b, &acc, 0, &mut region)
+            },
+        )?;
+
+        #[cfg(test)]
+        // Check that the correct multiple is obtained.
+        {
+            use group::Curve;
+
+            let real_mul = scalar.value.map(|scalar| base.generator() * scalar);
+            let result = result.point();
+
+            if let (Some(real_mul), Some(result)) = (real_mul, result) {
+                assert_eq!(real_mul.to_affine(), result);
+            }
+        }
+
+        self.canonicity_check(
+            layouter.namespace(|| "canonicity check"),
+            running_sum[0],
+            running_sum[Self::Z_252_IDX],
+        )?;
+
+        Ok((result, scalar))
+    }
+
+    /// Constrains the scalar k = z_0 (mod p), with bits at or above bit 252 given
+    /// by z_252, to be canonical. See `create_canonicity_gates` for details.
+    fn canonicity_check(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        z_0: CellValue<C::Base>,
+        z_252: CellValue<C::Base>,
+    ) -> Result<(), Error> {
+        // α_0 = z_0 - 2^252 z_252
+        let alpha_0 = z_0.value().zip(z_252.value()).map(|(z_0, z_252)| {
+            let two_pow_252 = C::Base::from_u128(1 << 126).square();
+            z_0 - z_252 * two_pow_252
+        });
+
+        let (alpha_0_prime, z_13_alpha_0_prime) = {
+            // alpha_0_prime = alpha_0 + 2^130 - t_q.
+            let alpha_0_prime = alpha_0.map(|alpha_0| {
+                let two_pow_130 = C::Base::from_u128(1 << 65).square();
+                let t_q = C::Base::from_u128(C::T_Q);
+                alpha_0 + two_pow_130 - t_q
+            });
+            let zs = self.lookup_config.witness_check(
+                layouter.namespace(|| "Lookup range check alpha_0 + 2^130 - t_q"),
+                alpha_0_prime,
+                13,
+                false,
+            )?;
+
+            (zs[0], zs[13])
+        };
+
+        layouter.assign_region(
+            || "Canonicity checks",
+            |mut region| {
+                // Activate canonicity check gate
+                self.q_mul_fixed_full_canon.enable(&mut region, 0)?;
+
+                // Offset 0
+                {
+                    let offset = 0;
+
+                    copy(
+                        &mut region,
+                        || "Copy z_0",
+                        self.canon_advices[0],
+                        offset,
+                        &z_0,
+                    )?;
+                    copy(
+                        &mut region,
+                        || "Copy z_252",
+                        self.canon_advices[1],
+                        offset,
+                        &z_252,
+                    )?;
+                    // We constrain this in the custom gate to be derived correctly.
+                    copy(
+                        &mut region,
+                        || "Copy α_0 + 2^130 - t_q",
+                        self.canon_advices[2],
+                        offset,
+                        &alpha_0_prime,
+                    )?;
+                }
+
+                // Offset 1
+                copy(
+                    &mut region,
+                    || "Copy z_13(α_0 + 2^130 - t_q)",
+                    self.canon_advices[0],
+                    1,
+                    &z_13_alpha_0_prime,
+                )?;
+
+                Ok(())
+            },
+        )
+    }
 }
 
 #[cfg(feature = "testing")]
 pub mod tests {
     use group::Curve;
-    use halo2::{circuit::Layouter, plonk::Error};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
     use pasta_curves::{arithmetic::FieldExt, pallas};
 
     use crate::{
-        chip::EccChip,
+        chip::{EccChip, L_SCALAR},
         gadget::{FixedPoint, FixedPoints, Point, H},
     };
+    use utilities::{decompose_word, CellValue, UtilitiesInstructions};
 
-    pub fn test_mul_fixed<F: FixedPoints<pallas::Affine>>(
+    /// Loads the given windows of a scalar as private inputs.
+    fn load_windows<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: &EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+        windows: &[u8],
+    ) -> Result<Vec<CellValue<pallas::Base>>, Error> {
+        let column = chip.config().advices[0];
+        windows
+            .iter()
+            .enumerate()
+            .map(|(idx, window)| {
+                chip.load_private(
+                    layouter.namespace(|| format!("k[{}]", idx)),
+                    column,
+                    Some(pallas::Base::from_u64(*window as u64)),
+                )
+            })
+            .collect()
+    }
+
+    pub fn test_mul_fixed<F: FixedPoints<pallas::Affine, K>, const K: usize>(
         base: F,
-        chip: EccChip<F>,
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
         test_single_base(
@@ -186,18 +541,18 @@ pub mod tests {
     }
 
     #[allow(clippy::op_ref)]
-    fn test_single_base<F: FixedPoints<pallas::Affine>>(
-        chip: EccChip<F>,
+    fn test_single_base<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
-        base: FixedPoint<pallas::Affine, EccChip<F>>,
+        base: FixedPoint<pallas::Affine, EccChip<pallas::Affine, F, K>>,
         base_val: pallas::Affine,
     ) -> Result<(), Error> {
-        fn constrain_equal<F: FixedPoints<pallas::Affine>>(
-            chip: EccChip<F>,
+        fn constrain_equal<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
             base_val: pallas::Affine,
             scalar_val: pallas::Scalar,
-            result: Point<pallas::Affine, EccChip<F>>,
+            result: Point<pallas::Affine, EccChip<pallas::Affine, F, K>>,
         ) -> Result<(), Error> {
             let expected = Point::new(
                 chip,
@@ -258,6 +613,31 @@ pub mod tests {
             )?;
         }
 
+        // [a]B, [0]B and [-1]B with the scalar copied in as windows. The top bits
+        // of -1 are 2^254, which exercises the lookup in the canonicity check.
+        for scalar_fixed in [
+            pallas::Scalar::rand(),
+            pallas::Scalar::zero(),
+            -pallas::Scalar::one(),
+        ]
+        .iter()
+        {
+            let windows = load_windows(
+                &chip,
+                layouter.namespace(|| "windows"),
+                &decompose_word::<pallas::Scalar>(*scalar_fixed, L_SCALAR, K),
+            )?;
+            let (result, _) =
+                base.mul_from_windows(layouter.namespace(|| "mul from windows"), &windows)?;
+            constrain_equal(
+                chip.clone(),
+                layouter.namespace(|| "mul from windows"),
+                base_val,
+                *scalar_fixed,
+                result,
+            )?;
+        }
+
         // [-1]B is the largest scalar field element.
         {
             let scalar_fixed = -pallas::Scalar::one();
@@ -274,3 +654,130 @@ pub mod tests {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod canonicity_tests {
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use lazy_static::lazy_static;
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::{
+            compute_lagrange_coeffs, find_zs_and_us_cached, test_circuit::configure_ecc_chip,
+            EccChip, EccConfig, VarBaseMul, L_SCALAR, NUM_WINDOWS,
+        },
+        gadget::{FixedPoint, FixedPoints, FIXED_BASE_WINDOW_SIZE},
+    };
+    use utilities::{decompose_word, UtilitiesInstructions};
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    lazy_static! {
+        static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, NUM_WINDOWS).unwrap();
+    }
+
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    struct FixedBase;
+
+    impl FixedPoints<pallas::Affine, K> for FixedBase {
+        fn generator(&self) -> pallas::Affine {
+            *BASE
+        }
+
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
+        }
+
+        fn z(&self) -> Vec<u64> {
+            ZS_AND_US.iter().map(|(z, _)| *z).collect()
+        }
+
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, K>(self.generator(), NUM_WINDOWS)
+        }
+    }
+
+    struct MyCircuit {
+        windows: Vec<u8>,
+    }
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = EccConfig<pallas::Affine, K>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            MyCircuit {
+                windows: vec![0; NUM_WINDOWS],
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_ecc_chip::<FixedBase, K>(meta, VarBaseMul::DoubleAndAdd)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = EccChip::construct(config.clone());
+            config.lookup_config.load(&mut layouter)?;
+
+            let windows = self
+                .windows
+                .iter()
+                .enumerate()
+                .map(|(idx, window)| {
+                    chip.load_private(
+                        layouter.namespace(|| format!("k[{}]", idx)),
+                        config.advices[0],
+                        Some(pallas::Base::from_u64(*window as u64)),
+                    )
+                })
+                .collect::<Result<Vec<_>, Error>>()?;
+            FixedPoint::from_inner(chip, FixedBase)
+                .mul_from_windows(layouter.namespace(|| "mul from windows"), &windows)?;
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn non_canonical_windows() {
+        let check = |windows: Vec<u8>, canonical: bool| {
+            let circuit = MyCircuit { windows };
+            let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify().is_ok(), canonical);
+        };
+
+        // q - 1 is the largest canonical scalar. Its lowest window is zero.
+        let q_minus_one = decompose_word::<pallas::Scalar>(-pallas::Scalar::one(), L_SCALAR, K);
+        assert_eq!(q_minus_one[0], 0);
+        check(q_minus_one.clone(), true);
+
+        // q and q + 1
+        for k_0 in [1, 2].iter() {
+            let mut windows = q_minus_one.clone();
+            windows[0] = *k_0;
+            check(windows, false);
+        }
+
+        // 2^254 is canonical, and 2^254 + 2^252 is the smallest non-canonical scalar
+        // with the bits above 252 set to 5.
+        for (top, canonical) in [(4, true), (5, false)].iter() {
+            let mut windows = vec![0; NUM_WINDOWS];
+            windows[NUM_WINDOWS - 1] = *top;
+            check(windows, *canonical);
+        }
+
+        // 2^255 - 1
+        check(vec![(1 << K) - 1; NUM_WINDOWS], false);
+    }
+}
@@ -1,52 +1,132 @@
-use std::{array, convert::TryInto};
+use std::array;
 
 use super::super::{
-    EccConfig, EccPoint, EccScalarFixedShort, FixedPoints, FIXED_BASE_WINDOW_SIZE, L_VALUE,
-    NUM_WINDOWS_SHORT,
+    num_windows, EccConfig, EccCurve, EccPoint, EccScalarFixedShort, FixedPoints, L_BASE,
 };
-use utilities::{copy, decompose_running_sum::RunningSumConfig, CellValue, Var};
+use utilities::{copy, decompose_running_sum::RunningSumConfig, range_check, CellValue, Var};
 
 use halo2::{
     circuit::{Layouter, Region},
     plonk::{ConstraintSystem, Error, Expression, Selector},
     poly::Rotation,
 };
-use pasta_curves::pallas;
 
+/// Configuration for fixed-base scalar mul by a signed short scalar with an
+/// `N`-bit magnitude.
 #[derive(Clone)]
-pub struct Config<Fixed: FixedPoints<pallas::Affine>> {
+pub struct Config<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize, const N: usize> {
     // Selector used for fixed-base scalar mul with short signed exponent.
     q_mul_fixed_short: Selector,
+    // Selectors for the range check on the last window, indexed by the number of
+    // bits in the last window minus one.
+    q_mul_fixed_short_last_window: Vec<Selector>,
     q_mul_fixed_running_sum: Selector,
-    running_sum_config: RunningSumConfig<pallas::Base, { FIXED_BASE_WINDOW_SIZE }>,
-    super_config: super::Config<Fixed, NUM_WINDOWS_SHORT>,
+    running_sum_config: RunningSumConfig<C::Base, K>,
+    super_config: super::Config<C, Fixed, K>,
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> From<&EccConfig> for Config<Fixed> {
-    fn from(config: &EccConfig) -> Self {
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize, const N: usize> From<&EccConfig<C, K>>
+    for Config<C, Fixed, K, N>
+{
+    fn from(config: &EccConfig<C, K>) -> Self {
+        // The signed magnitude ±m must fit in both fields without the two signs
+        // colliding, so we require 2^N < p/2.
+        assert!(
+            N > 0 && N < L_BASE - 1,
+            "Unsupported short scalar bit width {}",
+            N
+        );
+
         Self {
             q_mul_fixed_short: config.q_mul_fixed_short,
+            q_mul_fixed_short_last_window: config.q_mul_fixed_short_last_window.clone(),
             q_mul_fixed_running_sum: config.q_mul_fixed_running_sum,
             running_sum_config: config.running_sum_config.clone(),
-            super_config: config.into(),
+            super_config: super::Config::new(config, num_windows(N, K)),
         }
     }
 }
 
-impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
-    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+/// A fixed base whose tables are those it provides for `N`-bit short scalars.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct ShortBase<'a, Fixed, const N: usize>(&'a Fixed);
+
+impl<'a, C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize, const N: usize> FixedPoints<C, K>
+    for ShortBase<'a, Fixed, N>
+{
+    fn generator(&self) -> C {
+        self.0.generator()
+    }
+
+    fn u(&self) -> Vec<Vec<[u8; 32]>> {
+        let u = self.0.u_short::<N>();
+        check_num_windows::<N>("u_short", u.len(), num_windows(N, K));
+        u
+    }
+
+    fn z(&self) -> Vec<u64> {
+        let z = self.0.z_short::<N>();
+        check_num_windows::<N>("z_short", z.len(), num_windows(N, K));
+        z
+    }
+
+    fn lagrange_coeffs(&self) -> Vec<Vec<C::Base>> {
+        let lagrange_coeffs = self.0.lagrange_coeffs_short::<N>();
+        check_num_windows::<N>(
+            "lagrange_coeffs_short",
+            lagrange_coeffs.len(),
+            num_windows(N, K),
+        );
+        lagrange_coeffs
+    }
+}
+
+/// Panics with an explanatory message if the tables returned by the fixed base for
+/// `N`-bit short scalars do not have the expected number of windows.
+fn check_num_windows<const N: usize>(method: &str, actual: usize, expected: usize) {
+    assert_eq!(
+        actual, expected,
+        "{}::<{}>() returned {} windows, but {}-bit short scalars have {} windows",
+        method, N, actual, N, expected
+    );
+}
+
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize, const N: usize> Config<C, Fixed, K, N> {
+    // Number of bits of the magnitude in the last window, which is between 1 and K.
+    // For 3-bit windows and a 64-bit magnitude, this is 1.
+    fn last_window_num_bits(&self) -> usize {
+        N - (self.super_config.num_windows - 1) * K
+    }
+
+    /// Creates the gate for short fixed-base scalar mul with magnitudes of any bit
+    /// width.
+    pub(crate) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("Short fixed-base mul gate", |meta| {
             let q_mul_fixed_short = meta.query_selector(self.q_mul_fixed_short);
             let y_p = meta.query_advice(self.super_config.y_p, Rotation::cur());
             let y_a = meta.query_advice(self.super_config.add_config.y_qr, Rotation::cur());
-            // z_21
+            // z_{num_windows - 1} (z_21 for 3-bit windows and a 64-bit magnitude)
             let last_window = meta.query_advice(self.super_config.u, Rotation::cur());
             let sign = meta.query_advice(self.super_config.window, Rotation::cur());
 
-            let one = Expression::Constant(pallas::Base::one());
-
-            // Check that last window is either 0 or 1.
-            let last_window_check = last_window.clone() * (one.clone() - last_window);
+            let one = Expression::Constant(C::Base::one());
+
+            // Check that the last window fits in the remaining bits of the magnitude.
+            // The selector for the number of remaining bits is enabled alongside
+            // `q_mul_fixed_short`. For 3-bit windows and a 64-bit magnitude, this
+            // checks that it is either 0 or 1.
+            let last_window_check = self
+                .q_mul_fixed_short_last_window
+                .iter()
+                .enumerate()
+                .map(|(idx, q_last_window)| {
+                    let num_bits = idx + 1;
+                    meta.query_selector(*q_last_window)
+                        * range_check(last_window.clone(), 1 << num_bits)
+                })
+                .fold(Expression::Constant(C::Base::zero()), |acc, check| {
+                    acc + check
+                });
             // Check that sign is either 1 or -1.
             let sign_check = sign.clone() * sign.clone() - one;
 
@@ -59,22 +139,25 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
             // Check that the correct sign is witnessed s.t. sign * y_p = y_a
             let negation_check = sign * y_p - y_a;
 
-            array::IntoIter::new([
-                ("last_window_check", last_window_check),
-                ("sign_check", sign_check),
-                ("y_check", y_check),
-                ("negation_check", negation_check),
-            ])
-            .map(move |(name, poly)| (name, q_mul_fixed_short.clone() * poly))
+            std::iter::empty()
+                .chain(Some(("last_window_check", last_window_check)))
+                .chain(
+                    array::IntoIter::new([
+                        ("sign_check", sign_check),
+                        ("y_check", y_check),
+                        ("negation_check", negation_check),
+                    ])
+                    .map(move |(name, poly)| (name, q_mul_fixed_short.clone() * poly)),
+                )
         });
     }
 
     fn decompose(
         &self,
-        region: &mut Region<'_, pallas::Base>,
+        region: &mut Region<'_, C::Base>,
         offset: usize,
-        magnitude_sign: (CellValue<pallas::Base>, CellValue<pallas::Base>),
-    ) -> Result<EccScalarFixedShort, Error> {
+        magnitude_sign: (CellValue<C::Base>, CellValue<C::Base>),
+    ) -> Result<EccScalarFixedShort<C>, Error> {
         let (magnitude, sign) = magnitude_sign;
 
         // Decompose magnitude
@@ -83,23 +166,24 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
             offset,
             magnitude,
             true,
-            L_VALUE,
-            NUM_WINDOWS_SHORT,
+            N,
+            self.super_config.num_windows,
         )?;
 
         Ok(EccScalarFixedShort {
             magnitude,
             sign,
-            running_sum: (*running_sum).as_slice().try_into().unwrap(),
+            running_sum: running_sum.to_vec(),
+            num_bits: N,
         })
     }
 
     pub fn assign(
         &self,
-        mut layouter: impl Layouter<pallas::Base>,
-        magnitude_sign: (CellValue<pallas::Base>, CellValue<pallas::Base>),
+        mut layouter: impl Layouter<C::Base>,
+        magnitude_sign: (CellValue<C::Base>, CellValue<C::Base>),
         base: &Fixed,
-    ) -> Result<(EccPoint, EccScalarFixedShort), Error> {
+    ) -> Result<(EccPoint<C>, EccScalarFixedShort<C>), Error> {
         let (scalar, acc, mul_b) = layouter.assign_region(
             || "Short fixed-base mul (incomplete addition)",
             |mut region| {
@@ -112,7 +196,7 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                     &mut region,
                     offset,
                     &(&scalar).into(),
-                    base,
+                    &ShortBase::<_, N>(base),
                     self.q_mul_fixed_running_sum,
                 )?;
 
@@ -148,19 +232,19 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                 // Copy last window to `u` column.
                 // (Although the last window is not a `u` value; we are copying it into the `u`
                 // column because there is an available cell there.)
-                let z_21 = scalar.running_sum[21];
+                let last_window = scalar.running_sum[self.super_config.num_windows - 1];
                 copy(
                     &mut region,
                     || "last_window",
                     self.super_config.u,
                     offset,
-                    &z_21,
+                    &last_window,
                 )?;
 
                 // Conditionally negate `y`-coordinate
                 let y_val = if let Some(sign) = sign.value() {
-                    if sign == -pallas::Base::one() {
-                        magnitude_mul.y.value().map(|y: pallas::Base| -y)
+                    if sign == -C::Base::one() {
+                        magnitude_mul.y.value().map(|y: C::Base| -y)
                     } else {
                         magnitude_mul.y.value()
                     }
@@ -168,8 +252,10 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
                     None
                 };
 
-                // Enable mul_fixed_short selector on final row
+                // Enable mul_fixed_short selectors on final row
                 self.q_mul_fixed_short.enable(&mut region, offset)?;
+                self.q_mul_fixed_short_last_window[self.last_window_num_bits() - 1]
+                    .enable(&mut region, offset)?;
 
                 // Assign final `y` to `y_p` column and return final point
                 let y_var = region.assign_advice(
@@ -188,30 +274,28 @@ impl<Fixed: FixedPoints<pallas::Affine>> Config<Fixed> {
 
         #[cfg(test)]
         // Check that the correct multiple is obtained.
-        // This inlined test is only done for valid 64-bit magnitudes
+        // This inlined test is only done for valid N-bit magnitudes
         // and valid +/- 1 signs.
         // Invalid values result in constraint failures which are
         // tested at the circuit-level.
         {
+            use super::super::super::base_to_scalar;
             use group::Curve;
-            use pasta_curves::arithmetic::FieldExt;
 
             if let (Some(magnitude), Some(sign)) = (scalar.magnitude.value(), scalar.sign.value()) {
-                let magnitude_is_valid =
-                    magnitude <= pallas::Base::from_u64(0xFFFF_FFFF_FFFF_FFFFu64);
-                let sign_is_valid = sign * sign == pallas::Base::one();
+                let magnitude_is_valid = magnitude < C::Base::from_u64(2).pow(&[N as u64, 0, 0, 0]);
+                let sign_is_valid = sign * sign == C::Base::one();
                 if magnitude_is_valid && sign_is_valid {
                     let scalar = scalar.magnitude.value().zip(scalar.sign.value()).map(
                         |(magnitude, sign)| {
                             // Move magnitude from base field into scalar field (which always fits
-                            // for Pallas).
-                            let magnitude =
-                                pallas::Scalar::from_bytes(&magnitude.to_bytes()).unwrap();
+                            // for an N-bit magnitude).
+                            let magnitude = base_to_scalar::<C>(magnitude);
 
-                            let sign = if sign == pallas::Base::one() {
-                                pallas::Scalar::one()
+                            let sign = if sign == C::Base::one() {
+                                C::ScalarExt::one()
                             } else {
-                                -pallas::Scalar::one()
+                                -C::ScalarExt::one()
                             };
 
                             magnitude * sign
@@ -242,22 +326,22 @@ pub mod tests {
     use pasta_curves::{arithmetic::FieldExt, pallas};
 
     use crate::{
-        chip::EccChip,
+        chip::{EccChip, L_VALUE},
         gadget::{FixedPoint, FixedPoints, Point},
     };
     use utilities::{CellValue, UtilitiesInstructions};
 
     #[allow(clippy::op_ref)]
-    pub fn test_mul_fixed_short<F: FixedPoints<pallas::Affine>>(
+    pub fn test_mul_fixed_short<F: FixedPoints<pallas::Affine, K>, const K: usize>(
         base: F,
-        chip: EccChip<F>,
+        chip: EccChip<pallas::Affine, F, K>,
         mut layouter: impl Layouter<pallas::Base>,
     ) -> Result<(), Error> {
         let base_val = base.generator();
         let base = FixedPoint::from_inner(chip.clone(), base);
 
-        fn load_magnitude_sign<F: FixedPoints<pallas::Affine>>(
-            chip: EccChip<F>,
+        fn load_magnitude_sign<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
             magnitude: pallas::Base,
             sign: pallas::Base,
@@ -270,12 +354,12 @@ pub mod tests {
             Ok((magnitude, sign))
         }
 
-        fn constrain_equal<F: FixedPoints<pallas::Affine>>(
-            chip: EccChip<F>,
+        fn constrain_equal<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
             base_val: pallas::Affine,
             scalar_val: pallas::Scalar,
-            result: Point<pallas::Affine, EccChip<F>>,
+            result: Point<pallas::Affine, EccChip<pallas::Affine, F, K>>,
         ) -> Result<(), Error> {
             let expected = Point::new(
                 chip,
@@ -332,7 +416,7 @@ pub mod tests {
                     *magnitude,
                     *sign,
                 )?;
-                base.mul_short(layouter.namespace(|| *name), magnitude_sign)?
+                base.mul_short::<{ L_VALUE }>(layouter.namespace(|| *name), magnitude_sign)?
             };
             // Move from base field into scalar field
             let scalar = {
@@ -370,35 +454,58 @@ pub mod tests {
 
         use crate::{
             chip::{compute_lagrange_coeffs, NUM_WINDOWS_SHORT},
-            gadget::H,
+            gadget::FIXED_BASE_WINDOW_SIZE,
         };
         use group::{Curve, Group};
         use lazy_static::lazy_static;
 
         lazy_static! {
             static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
-            static ref ZS_AND_US: Vec<(u64, [[u8; 32]; H])> =
-                crate::chip::find_zs_and_us(*BASE, NUM_WINDOWS_SHORT).unwrap();
+            static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+                crate::chip::find_zs_and_us_cached::<_, { FIXED_BASE_WINDOW_SIZE }>(
+                    *BASE,
+                    NUM_WINDOWS_SHORT
+                )
+                .unwrap();
         }
 
         #[derive(Debug, Eq, PartialEq, Clone)]
         struct FixedBase;
 
-        impl FixedPoints<pallas::Affine> for FixedBase {
+        impl FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }> for FixedBase {
             fn generator(&self) -> pallas::Affine {
                 *BASE
             }
 
-            fn u(&self) -> Vec<[[u8; 32]; H]> {
-                ZS_AND_US.iter().map(|(_, us)| *us).collect()
+            fn u(&self) -> Vec<Vec<[u8; 32]>> {
+                ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
             }
 
             fn z(&self) -> Vec<u64> {
                 ZS_AND_US.iter().map(|(z, _)| *z).collect()
             }
 
-            fn lagrange_coeffs(&self) -> Vec<[pallas::Base; H]> {
-                compute_lagrange_coeffs(self.generator(), NUM_WINDOWS_SHORT)
+            fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+                compute_lagrange_coeffs::<_, { FIXED_BASE_WINDOW_SIZE }>(
+                    self.generator(),
+                    NUM_WINDOWS_SHORT,
+                )
+            }
+
+            // The tables above are those for 64-bit short scalars.
+            fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {
+                assert_eq!(N, L_VALUE);
+                self.u()
+            }
+
+            fn z_short<const N: usize>(&self) -> Vec<u64> {
+                assert_eq!(N, L_VALUE);
+                self.z()
+            }
+
+            fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<pallas::Base>> {
+                assert_eq!(N, L_VALUE);
+                self.lagrange_coeffs()
             }
         }
 
@@ -413,7 +520,7 @@ pub mod tests {
         }
 
         impl Circuit<pallas::Base> for MyCircuit {
-            type Config = EccConfig;
+            type Config = EccConfig<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>;
             type FloorPlanner = SimpleFloorPlanner;
 
             fn without_witnesses(&self) -> Self {
@@ -450,7 +557,12 @@ pub mod tests {
                 meta.enable_constant(constants);
 
                 let range_check = LookupRangeCheckConfig::configure(meta, advices[9], lookup_table);
-                EccChip::<FixedBase>::configure(meta, advices, lagrange_coeffs, range_check)
+                EccChip::<pallas::Affine, FixedBase, { FIXED_BASE_WINDOW_SIZE }>::configure(
+                    meta,
+                    advices,
+                    &lagrange_coeffs,
+                    range_check,
+                )
             }
 
             fn synthesize(
@@ -460,7 +572,12 @@ pub mod tests {
             ) -> Result<(), Error> {
                 let column = config.advices[0];
 
-                let short_config: super::Config<FixedBase> = (&config).into();
+                let short_config: super::Config<
+                    pallas::Affine,
+                    FixedBase,
+                    { FIXED_BASE_WINDOW_SIZE },
+                    { L_VALUE },
+                > = (&config).into();
                 let magnitude_sign = {
                     let magnitude = self.load_private(
                         layouter.namespace(|| "load magnitude"),
@@ -520,7 +637,7 @@ pub mod tests {
                     Err(vec![
                         VerifyFailure::ConstraintNotSatisfied {
                             constraint: (
-                                (16, "Short fixed-base mul gate").into(),
+                                (20, "Short fixed-base mul gate").into(),
                                 0,
                                 "last_window_check"
                             )
@@ -552,13 +669,13 @@ pub mod tests {
                 prover.verify(),
                 Err(vec![
                     VerifyFailure::ConstraintNotSatisfied {
-                        constraint: ((16, "Short fixed-base mul gate").into(), 1, "sign_check")
+                        constraint: ((20, "Short fixed-base mul gate").into(), 1, "sign_check")
                             .into(),
                         row: 26
                     },
                     VerifyFailure::ConstraintNotSatisfied {
                         constraint: (
-                            (16, "Short fixed-base mul gate").into(),
+                            (20, "Short fixed-base mul gate").into(),
                             3,
                             "negation_check"
                         )
@@ -570,3 +687,164 @@ pub mod tests {
         }
     }
 }
+
+#[cfg(test)]
+mod bit_width_tests {
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use lazy_static::lazy_static;
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::{
+            compute_lagrange_coeffs, find_zs_and_us_cached, num_windows,
+            test_circuit::configure_ecc_chip, EccChip, EccConfig, VarBaseMul, NUM_WINDOWS,
+        },
+        gadget::{FixedPoint, FixedPoints, Point, FIXED_BASE_WINDOW_SIZE},
+    };
+    use utilities::UtilitiesInstructions;
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    lazy_static! {
+        static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, NUM_WINDOWS).unwrap();
+        static ref ZS_AND_US_16: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, num_windows(16, K)).unwrap();
+        static ref ZS_AND_US_32: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, num_windows(32, K)).unwrap();
+        static ref ZS_AND_US_128: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, num_windows(128, K)).unwrap();
+    }
+
+    // A single base with tables for full-width scalars, and for 16-bit, 32-bit and
+    // 128-bit magnitudes. For 3-bit windows, the last windows of the short scalars
+    // have one, two and two bits respectively.
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    struct FixedBase;
+
+    fn zs_and_us_short<const N: usize>() -> &'static [(u64, Vec<[u8; 32]>)] {
+        match N {
+            16 => ZS_AND_US_16.as_slice(),
+            32 => ZS_AND_US_32.as_slice(),
+            128 => ZS_AND_US_128.as_slice(),
+            _ => unreachable!(),
+        }
+    }
+
+    impl FixedPoints<pallas::Affine, K> for FixedBase {
+        fn generator(&self) -> pallas::Affine {
+            *BASE
+        }
+
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
+        }
+
+        fn z(&self) -> Vec<u64> {
+            ZS_AND_US.iter().map(|(z, _)| *z).collect()
+        }
+
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, K>(self.generator(), NUM_WINDOWS)
+        }
+
+        fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {
+            zs_and_us_short::<N>()
+                .iter()
+                .map(|(_, us)| us.clone())
+                .collect()
+        }
+
+        fn z_short<const N: usize>(&self) -> Vec<u64> {
+            zs_and_us_short::<N>().iter().map(|(z, _)| *z).collect()
+        }
+
+        fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, K>(self.generator(), zs_and_us_short::<N>().len())
+        }
+    }
+
+    #[derive(Default)]
+    struct MyCircuit<const N: usize> {
+        magnitude: Option<pallas::Base>,
+        sign: Option<pallas::Base>,
+    }
+
+    impl<const N: usize> Circuit<pallas::Base> for MyCircuit<N> {
+        type Config = EccConfig<pallas::Affine, K>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_ecc_chip::<FixedBase, K>(meta, VarBaseMul::DoubleAndAdd)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = EccChip::construct(config.clone());
+            config.lookup_config.load(&mut layouter)?;
+            let base = FixedPoint::from_inner(chip.clone(), FixedBase);
+
+            let column = config.advices[0];
+            let magnitude =
+                chip.load_private(layouter.namespace(|| "magnitude"), column, self.magnitude)?;
+            let sign = chip.load_private(layouter.namespace(|| "sign"), column, self.sign)?;
+            base.mul_short::<N>(layouter.namespace(|| "mul_short"), (magnitude, sign))?;
+
+            // The same base is used with a full-width scalar.
+            let scalar = pallas::Scalar::rand();
+            let (result, _) = base.mul(layouter.namespace(|| "mul"), Some(scalar))?;
+            let expected = Point::new(
+                chip,
+                layouter.namespace(|| "expected"),
+                Some((*BASE * scalar).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| "constrain result"), &expected)
+        }
+    }
+
+    fn check<const N: usize>(magnitude: pallas::Base, valid: bool) {
+        for sign in [pallas::Base::one(), -pallas::Base::one()].iter() {
+            let circuit = MyCircuit::<N> {
+                magnitude: Some(magnitude),
+                sign: Some(*sign),
+            };
+            let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify().is_ok(), valid);
+        }
+    }
+
+    #[test]
+    fn short_bit_widths() {
+        // 16-bit magnitudes
+        check::<16>(pallas::Base::zero(), true);
+        check::<16>(pallas::Base::from_u64(rand::random::<u16>().into()), true);
+        check::<16>(pallas::Base::from_u64((1 << 16) - 1), true);
+        check::<16>(pallas::Base::from_u64(1 << 16), false);
+        check::<16>(pallas::Base::from_u64(1 << 17), false);
+
+        // 32-bit magnitudes
+        check::<32>(pallas::Base::zero(), true);
+        check::<32>(pallas::Base::from_u64(rand::random::<u32>().into()), true);
+        check::<32>(pallas::Base::from_u64(u32::MAX.into()), true);
+        check::<32>(pallas::Base::from_u64(1 << 32), false);
+        check::<32>(pallas::Base::from_u64(1 << 33), false);
+
+        // 128-bit magnitudes
+        check::<128>(pallas::Base::from_u128(rand::random::<u128>()), true);
+        check::<128>(pallas::Base::from_u128(u128::MAX), true);
+        check::<128>(pallas::Base::from_u128(1 << 64).square(), false);
+    }
+}
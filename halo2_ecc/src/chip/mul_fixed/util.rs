@@ -1,57 +1,55 @@
 //! Utilities to compute associated constants for fixed bases.
-use super::{FIXED_BASE_WINDOW_SIZE, H};
-use arrayvec::ArrayVec;
-use ff::Field;
-use group::Curve;
-use halo2::arithmetic::lagrange_interpolate;
+use crate::gadget::FixedPoints;
+use ff::{Field, PrimeFieldBits};
+use group::{Curve, Group};
+use halo2::arithmetic::{eval_polynomial, lagrange_interpolate};
 use pasta_curves::arithmetic::{CurveAffine, FieldExt};
+use utilities::decompose_word;
 
-/// For each fixed base, we calculate its scalar multiples in three-bit windows.
-/// Each window will have $2^3 = 8$ points.
-pub fn compute_window_table<C: CurveAffine>(base: C, num_windows: usize) -> Vec<[C; H]> {
-    let mut window_table: Vec<[C; H]> = Vec::with_capacity(num_windows);
+#[cfg(feature = "multicore")]
+use rayon::prelude::*;
+
+/// For each fixed base, we calculate its scalar multiples in `K`-bit windows.
+/// Each window will have $2^K$ points.
+pub fn compute_window_table<C: CurveAffine, const K: usize>(
+    base: C,
+    num_windows: usize,
+) -> Vec<Vec<C>> {
+    let h = 1 << K;
+    let mut window_table: Vec<Vec<C>> = Vec::with_capacity(num_windows);
 
     // Generate window table entries for all windows but the last.
-    // For these first `num_windows - 1` windows, we compute the multiple [(k+2)*(2^3)^w]B.
+    // For these first `num_windows - 1` windows, we compute the multiple [(k+2)*(2^K)^w]B.
     // Here, w ranges from [0..`num_windows - 1`)
     for w in 0..(num_windows - 1) {
         window_table.push(
-            (0..H)
+            (0..h)
                 .map(|k| {
-                    // scalar = (k+2)*(8^w)
+                    // scalar = (k+2)*((2^K)^w)
                     let scalar = C::ScalarExt::from_u64(k as u64 + 2)
-                        * C::ScalarExt::from_u64(H as u64).pow(&[w as u64, 0, 0, 0]);
+                        * C::ScalarExt::from_u64(h as u64).pow(&[w as u64, 0, 0, 0]);
                     (base * scalar).to_affine()
                 })
-                .collect::<ArrayVec<C, H>>()
-                .into_inner()
-                .unwrap(),
+                .collect(),
         );
     }
 
     // Generate window table entries for the last window, w = `num_windows - 1`.
-    // For the last window, we compute [k * (2^3)^w - sum]B, where sum is defined
-    // as sum = \sum_{j = 0}^{`num_windows - 2`} 2^{3j+1}
+    // For the last window, we compute [k * (2^K)^w - sum]B, where sum is defined
+    // as sum = \sum_{j = 0}^{`num_windows - 2`} 2^{Kj+1}
     let sum = (0..(num_windows - 1)).fold(C::ScalarExt::zero(), |acc, j| {
-        acc + C::ScalarExt::from_u64(2).pow(&[
-            FIXED_BASE_WINDOW_SIZE as u64 * j as u64 + 1,
-            0,
-            0,
-            0,
-        ])
+        acc + C::ScalarExt::from_u64(2).pow(&[K as u64 * j as u64 + 1, 0, 0, 0])
     });
     window_table.push(
-        (0..H)
+        (0..h)
             .map(|k| {
-                // scalar = k * (2^3)^w - sum, where w = `num_windows - 1`
+                // scalar = k * (2^K)^w - sum, where w = `num_windows - 1`
                 let scalar = C::ScalarExt::from_u64(k as u64)
-                    * C::ScalarExt::from_u64(H as u64).pow(&[(num_windows - 1) as u64, 0, 0, 0])
+                    * C::ScalarExt::from_u64(h as u64).pow(&[(num_windows - 1) as u64, 0, 0, 0])
                     - sum;
                 (base * scalar).to_affine()
             })
-            .collect::<ArrayVec<C, H>>()
-            .into_inner()
-            .unwrap(),
+            .collect(),
     );
 
     window_table
@@ -59,11 +57,14 @@ pub fn compute_window_table<C: CurveAffine>(base: C, num_windows: usize) -> Vec<
 
 /// For each window, we interpolate the $x$-coordinate.
 /// Here, we pre-compute and store the coefficients of the interpolation polynomial.
-pub fn compute_lagrange_coeffs<C: CurveAffine>(base: C, num_windows: usize) -> Vec<[C::Base; H]> {
-    // We are interpolating over the 3-bit window, k \in [0..8)
-    let points: Vec<_> = (0..H).map(|i| C::Base::from_u64(i as u64)).collect();
+pub fn compute_lagrange_coeffs<C: CurveAffine, const K: usize>(
+    base: C,
+    num_windows: usize,
+) -> Vec<Vec<C::Base>> {
+    // We are interpolating over the K-bit window, k \in [0..2^K)
+    let points: Vec<_> = (0..(1 << K)).map(|i| C::Base::from_u64(i as u64)).collect();
 
-    let window_table = compute_window_table(base, num_windows);
+    let window_table = compute_window_table::<C, K>(base, num_windows);
 
     window_table
         .iter()
@@ -73,10 +74,6 @@ pub fn compute_lagrange_coeffs<C: CurveAffine>(base: C, num_windows: usize) -> V
                 .map(|point| *point.coordinates().unwrap().x())
                 .collect();
             lagrange_interpolate(&points, &x_window_points)
-                .into_iter()
-                .collect::<ArrayVec<C::Base, H>>()
-                .into_inner()
-                .unwrap()
         })
         .collect()
 }
@@ -84,20 +81,25 @@ pub fn compute_lagrange_coeffs<C: CurveAffine>(base: C, num_windows: usize) -> V
 /// For each window, $z$ is a field element such that for each point $(x, y)$ in the window:
 /// - $z + y = u^2$ (some square in the field); and
 /// - $z - y$ is not a square.
-/// If successful, return a vector of `(z: u64, us: [C::Base; H])` for each window.
-pub fn find_zs_and_us<C: CurveAffine>(
+/// If successful, return a vector of `(z: u64, us: Vec<[u8; 32]>)` for each window,
+/// where each `us` has $2^K$ entries.
+///
+/// With the `multicore` feature, the windows are searched in parallel.
+pub fn find_zs_and_us<C: CurveAffine, const K: usize>(
     base: C,
     num_windows: usize,
-) -> Option<Vec<(u64, [[u8; 32]; H])>> {
+) -> Option<Vec<(u64, Vec<[u8; 32]>)>> {
+    let h = 1 << K;
+
     // Closure to find z and u's for one window
     let find_z_and_us = |window_points: &[C]| {
-        assert_eq!(H, window_points.len());
+        assert_eq!(h, window_points.len());
 
         let ys: Vec<_> = window_points
             .iter()
             .map(|point| *point.coordinates().unwrap().y())
             .collect();
-        (0..(1000 * (1 << (2 * H)))).find_map(|z| {
+        (0..(1000 * (1 << (2 * h)))).find_map(|z| {
             ys.iter()
                 .map(|&y| {
                     let u = if (-y + C::Base::from_u64(z)).sqrt().is_none().into() {
@@ -107,14 +109,58 @@ pub fn find_zs_and_us<C: CurveAffine>(
                     };
                     u.map(|u: C::Base| u.to_bytes())
                 })
-                .collect::<Option<ArrayVec<[u8; 32], H>>>()
-                .map(|us| (z, us.into_inner().unwrap()))
+                .collect::<Option<Vec<[u8; 32]>>>()
+                .map(|us| (z, us))
         })
     };
 
-    let window_table = compute_window_table(base, num_windows);
-    window_table
-        .iter()
+    let window_table = compute_window_table::<C, K>(base, num_windows);
+
+    #[cfg(feature = "multicore")]
+    let windows = window_table.par_iter();
+    #[cfg(not(feature = "multicore"))]
+    let windows = window_table.iter();
+
+    windows
         .map(|window_points| find_z_and_us(window_points))
         .collect()
 }
+
+/// Computes $[k]B$ natively from the constants that `base` provides for
+/// fixed-base scalar multiplication, where $k$ is `scalar`.
+///
+/// The scalar is decomposed into as many `K`-bit windows as `base` has $z$-values,
+/// and must fit in that many bits. For each window $w$ with value $k_w$, the
+/// $x$-coordinate of the window point is the interpolation polynomial evaluated
+/// at $k_w$, and its $y$-coordinate is $u_{w,k_w}^2 - z_w$. This is what the
+/// fixed-base scalar mul gates constrain, so it can be used to sanity-check
+/// precomputed tables.
+///
+/// Returns `None` if any window point is not on the curve.
+pub fn mul_fixed_from_tables<C: CurveAffine, const K: usize>(
+    base: &impl FixedPoints<C, K>,
+    scalar: C::ScalarExt,
+) -> Option<C::Curve>
+where
+    C::ScalarExt: PrimeFieldBits,
+{
+    let lagrange_coeffs = base.lagrange_coeffs();
+    let zs = base.z();
+    let us = base.u();
+    assert_eq!(lagrange_coeffs.len(), zs.len());
+    assert_eq!(us.len(), zs.len());
+
+    let windows = decompose_word::<C::ScalarExt>(scalar, zs.len() * K, K);
+
+    windows
+        .iter()
+        .enumerate()
+        .try_fold(C::Curve::identity(), |acc, (w, &k)| {
+            let k = k as usize;
+            let x = eval_polynomial(&lagrange_coeffs[w], C::Base::from_u64(k as u64));
+            let u = Option::<C::Base>::from(C::Base::from_bytes(&us[w][k]))?;
+            let y = u.square() - C::Base::from_u64(zs[w]);
+            let point = Option::<C>::from(C::from_xy(x, y))?;
+            Some(acc + point)
+        })
+}
@@ -0,0 +1,243 @@
+use std::{array, marker::PhantomData};
+
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
+use halo2::{
+    circuit::Region,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone, Debug)]
+pub struct Config<C: EccCurve> {
+    q_neg: Selector,
+    q_cond_neg: Selector,
+    // y-coordinate of P in ±P = R
+    pub y_p: Column<Advice>,
+    // y-coordinate of R in ±P = R
+    pub y_r: Column<Advice>,
+    // Boolean flag selecting whether to negate P
+    bit: Column<Advice>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_neg: ecc_config.q_neg,
+            q_cond_neg: ecc_config.q_cond_neg,
+            y_p: ecc_config.advices[1],
+            y_r: ecc_config.advices[3],
+            bit: ecc_config.advices[4],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        // -P = (x_p, -y_p). The x-coordinate of the result is the same cell as
+        // the x-coordinate of P, so we only need to constrain y_r. This maps the
+        // identity (0, 0) to itself.
+        meta.create_gate("point negation", |meta| {
+            let q_neg = meta.query_selector(self.q_neg);
+            let y_p = meta.query_advice(self.y_p, Rotation::cur());
+            let y_r = meta.query_advice(self.y_r, Rotation::cur());
+
+            // y_r + y_p = 0
+            array::IntoIter::new([q_neg * (y_r + y_p)])
+        });
+
+        meta.create_gate("conditional point negation", |meta| {
+            let q_cond_neg = meta.query_selector(self.q_cond_neg);
+            let y_p = meta.query_advice(self.y_p, Rotation::cur());
+            let y_r = meta.query_advice(self.y_r, Rotation::cur());
+            let bit = meta.query_advice(self.bit, Rotation::cur());
+
+            let one = Expression::Constant(C::Base::one());
+            let two = Expression::Constant(C::Base::from_u64(2));
+
+            // bit⋅(1 - bit) = 0
+            let bool_check = bit.clone() * (one.clone() - bit.clone());
+
+            // y_r = y_p⋅(1 - 2⋅bit)
+            let y_check = y_r - y_p * (one - two * bit);
+
+            array::IntoIter::new([bool_check, y_check]).map(move |poly| q_cond_neg.clone() * poly)
+        });
+    }
+
+    pub(super) fn assign_neg(
+        &self,
+        p: &EccPoint<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        // Enable `q_neg` selector
+        self.q_neg.enable(region, offset)?;
+
+        // Copy `y_p`
+        copy(region, || "y_p", self.y_p, offset, &p.y)?;
+
+        // Assign y_r = -y_p
+        let y_r = p.y.value().map(|y_p| -y_p);
+        let y_r_cell = region.assign_advice(
+            || "y_r",
+            self.y_r,
+            offset,
+            || y_r.ok_or(Error::SynthesisError),
+        )?;
+
+        Ok(EccPoint {
+            x: p.x,
+            y: CellValue::<C::Base>::new(y_r_cell, y_r),
+        })
+    }
+
+    pub(super) fn assign_conditional_neg(
+        &self,
+        p: &EccPoint<C>,
+        bit: &CellValue<C::Base>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        // Enable `q_cond_neg` selector
+        self.q_cond_neg.enable(region, offset)?;
+
+        // Copy `y_p` and `bit`
+        copy(region, || "y_p", self.y_p, offset, &p.y)?;
+        copy(region, || "bit", self.bit, offset, bit)?;
+
+        // Assign y_r = y_p if bit = 0, or -y_p if bit = 1
+        let y_r = p.y.value().zip(bit.value()).map(
+            |(y_p, bit)| {
+                if bit == C::Base::one() {
+                    -y_p
+                } else {
+                    y_p
+                }
+            },
+        );
+        let y_r_cell = region.assign_advice(
+            || "y_r",
+            self.y_r,
+            offset,
+            || y_r.ok_or(Error::SynthesisError),
+        )?;
+
+        Ok(EccPoint {
+            x: p.x,
+            y: CellValue::<C::Base>::new(y_r_cell, y_r),
+        })
+    }
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::pallas;
+
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoints, Point},
+    };
+    use utilities::UtilitiesInstructions;
+
+    pub fn test_neg<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate a random point P
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // P
+        let p = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+        let p_neg = Point::new(chip.clone(), layouter.namespace(|| "-P"), Some(-p_val))?;
+
+        // Generate a random point Q
+        let q_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // Q
+        let q = Point::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+
+        // Generate a (0,0) point to be used in other tests.
+        let zero = {
+            Point::new(
+                chip.clone(),
+                layouter.namespace(|| "identity"),
+                Some(pallas::Affine::identity()),
+            )?
+        };
+
+        let column = chip.config().advices[0];
+
+        // -P
+        {
+            let result = p.neg(layouter.namespace(|| "-P"))?;
+            result.constrain_equal(layouter.namespace(|| "constrain -P"), &p_neg)?;
+        }
+
+        // -𝒪 = 𝒪
+        {
+            let result = zero.neg(layouter.namespace(|| "-𝒪"))?;
+            result.constrain_equal(layouter.namespace(|| "-𝒪 = 𝒪"), &zero)?;
+        }
+
+        // Conditionally negate P with bit = 0
+        {
+            let bit = chip.load_private(
+                layouter.namespace(|| "bit = 0"),
+                column,
+                Some(pallas::Base::zero()),
+            )?;
+            let result = p.conditional_neg(layouter.namespace(|| "P (bit = 0)"), &bit)?;
+            result.constrain_equal(layouter.namespace(|| "constrain P (bit = 0)"), &p)?;
+        }
+
+        // Conditionally negate P with bit = 1
+        {
+            let bit = chip.load_private(
+                layouter.namespace(|| "bit = 1"),
+                column,
+                Some(pallas::Base::one()),
+            )?;
+            let result = p.conditional_neg(layouter.namespace(|| "-P (bit = 1)"), &bit)?;
+            result.constrain_equal(layouter.namespace(|| "constrain -P (bit = 1)"), &p_neg)?;
+        }
+
+        // Conditionally negate 𝒪 with bit = 1
+        {
+            let bit = chip.load_private(
+                layouter.namespace(|| "bit = 1"),
+                column,
+                Some(pallas::Base::one()),
+            )?;
+            let result = zero.conditional_neg(layouter.namespace(|| "-𝒪 (bit = 1)"), &bit)?;
+            result.constrain_equal(layouter.namespace(|| "constrain -𝒪 (bit = 1)"), &zero)?;
+        }
+
+        // P - Q
+        {
+            let result = p.sub(layouter.namespace(|| "P - Q"), &q)?;
+            let witnessed_result = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "witnessed P - Q"),
+                Some((p_val - q_val).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| "constrain P - Q"), &witnessed_result)?;
+        }
+
+        // P - P = 𝒪
+        {
+            let result = p.sub(layouter.namespace(|| "P - P"), &p)?;
+            result.constrain_equal(layouter.namespace(|| "P - P = 𝒪"), &zero)?;
+        }
+
+        // P - 𝒪 = P
+        {
+            let result = p.sub(layouter.namespace(|| "P - 𝒪"), &zero)?;
+            result.constrain_equal(layouter.namespace(|| "P - 𝒪 = P"), &p)?;
+        }
+
+        Ok(())
+    }
+}
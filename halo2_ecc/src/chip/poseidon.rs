@@ -0,0 +1,142 @@
+//! Hashes built from the Poseidon chip, for use by the ECC gadgets.
+//!
+//! See [`crate::primitive::signature::poseidon_challenge`] and
+//! [`crate::primitive::hash_to_curve::poseidon_hash_to_field`] for the native hashes.
+
+use super::{EccChip, EccCurve};
+use crate::gadget::{
+    hash_to_curve::HashToField, signature::ChallengeHash, FixedPoints, NonIdentityPoint, Point,
+};
+use poseidon::{
+    gadget::{Hash, Word},
+    pow5t3::{Pow5T3Chip, Pow5T3Config, StateWord},
+    primitive::{ConstantLength, P128Pow5T3, Spec},
+};
+use utilities::{CellValue, Var};
+
+use halo2::{arithmetic::FieldExt, circuit::Layouter, plonk::Error};
+use pasta_curves::arithmetic::CurveAffine;
+
+/// A [`ChallengeHash`] that computes the Schnorr challenge
+/// $c = \mathsf{Poseidon}(x_R, y_R, x_\mathsf{vk}, y_\mathsf{vk}, m)$ for a message
+/// digest $m$, using [`P128Pow5T3`].
+///
+/// The identity is hashed as $(0, 0)$, which is how it is represented in the circuit.
+#[derive(Clone, Debug)]
+pub struct PoseidonChallenge<F: FieldExt> {
+    config: Pow5T3Config<F>,
+}
+
+impl<F: FieldExt> PoseidonChallenge<F> {
+    /// Constructs the hash from the configuration of a [`Pow5T3Chip`] that was
+    /// configured with [`P128Pow5T3`].
+    pub fn construct(config: Pow5T3Config<F>) -> Self {
+        PoseidonChallenge { config }
+    }
+}
+
+impl<C: EccCurve, Fixed: FixedPoints<C, K>, const K: usize> ChallengeHash<C, EccChip<C, Fixed, K>>
+    for PoseidonChallenge<C::Base>
+where
+    P128Pow5T3: Spec<C::Base, 3, 2>,
+{
+    type Message = CellValue<C::Base>;
+
+    fn challenge(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        r: &Point<C, EccChip<C, Fixed, K>>,
+        vk: &NonIdentityPoint<C, EccChip<C, Fixed, K>>,
+        message: Self::Message,
+    ) -> Result<CellValue<C::Base>, Error> {
+        let word = |var: CellValue<C::Base>| {
+            Word::<_, _, P128Pow5T3, 3, 2>::from_inner(StateWord::new(var.cell(), var.value()))
+        };
+
+        let hasher = Hash::init(
+            Pow5T3Chip::construct(self.config.clone()),
+            layouter.namespace(|| "init"),
+            ConstantLength::<5>,
+        )?;
+        let c = hasher.hash(
+            layouter.namespace(|| "Poseidon(R, vk, m)"),
+            [
+                word(r.inner().x()),
+                word(r.inner().y()),
+                word(vk.inner().x()),
+                word(vk.inner().y()),
+                word(message),
+            ],
+        )?;
+
+        Ok(c.inner().into())
+    }
+}
+
+/// A [`HashToField`] that hashes a field element $m$ to
+/// $u_i = \mathsf{Poseidon}(i, m)$ for $i \in \{0, 1\}$, using [`P128Pow5T3`].
+///
+/// The domain tags $i$ are loaded as constants, so the chip must be configured
+/// with a fixed column that has been enabled for constants.
+#[derive(Clone, Debug)]
+pub struct PoseidonHashToField<F: FieldExt> {
+    config: Pow5T3Config<F>,
+}
+
+impl<F: FieldExt> PoseidonHashToField<F> {
+    /// Constructs the hash from the configuration of a [`Pow5T3Chip`] that was
+    /// configured with [`P128Pow5T3`].
+    pub fn construct(config: Pow5T3Config<F>) -> Self {
+        PoseidonHashToField { config }
+    }
+}
+
+impl<C: CurveAffine> HashToField<C, CellValue<C::Base>> for PoseidonHashToField<C::Base>
+where
+    P128Pow5T3: Spec<C::Base, 3, 2>,
+{
+    type Message = CellValue<C::Base>;
+
+    fn hash_to_field(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        message: Self::Message,
+    ) -> Result<[CellValue<C::Base>; 2], Error> {
+        let mut u = |i: u64| -> Result<CellValue<C::Base>, Error> {
+            let mut layouter = layouter.namespace(|| format!("u_{}", i));
+
+            let tag = C::Base::from_u64(i);
+            let tag_cell = layouter.assign_region(
+                || "domain tag",
+                |mut region| {
+                    region.assign_advice_from_constant(
+                        || format!("tag {}", i),
+                        self.config.state()[0],
+                        0,
+                        tag,
+                    )
+                },
+            )?;
+
+            let hasher = Hash::init(
+                Pow5T3Chip::construct(self.config.clone()),
+                layouter.namespace(|| "init"),
+                ConstantLength::<2>,
+            )?;
+            let u = hasher.hash(
+                layouter.namespace(|| "Poseidon(i, m)"),
+                [
+                    Word::<_, _, P128Pow5T3, 3, 2>::from_inner(StateWord::new(tag_cell, Some(tag))),
+                    Word::<_, _, P128Pow5T3, 3, 2>::from_inner(StateWord::new(
+                        message.cell(),
+                        message.value(),
+                    )),
+                ],
+            )?;
+
+            Ok(u.inner().into())
+        };
+
+        Ok([u(0)?, u(1)?])
+    }
+}
@@ -0,0 +1,266 @@
+use std::{array, marker::PhantomData};
+
+use super::{copy, CellValue, EccConfig, EccCurve, EccPoint, Var};
+use halo2::{
+    circuit::Region,
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
+    poly::Rotation,
+};
+
+#[derive(Clone, Debug)]
+pub struct Config<C: EccCurve> {
+    q_select: Selector,
+    // Boolean flag choosing between A (cond = 1) and B (cond = 0)
+    cond: Column<Advice>,
+    // x-coordinate of A
+    pub x_a: Column<Advice>,
+    // y-coordinate of A
+    pub y_a: Column<Advice>,
+    // x-coordinate of B
+    pub x_b: Column<Advice>,
+    // y-coordinate of B
+    pub y_b: Column<Advice>,
+    // x-coordinate of the selected point R
+    pub x_r: Column<Advice>,
+    // y-coordinate of the selected point R
+    pub y_r: Column<Advice>,
+    _marker: PhantomData<C>,
+}
+
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
+        Self {
+            q_select: ecc_config.q_select,
+            x_a: ecc_config.advices[0],
+            y_a: ecc_config.advices[1],
+            x_b: ecc_config.advices[2],
+            y_b: ecc_config.advices[3],
+            cond: ecc_config.advices[4],
+            x_r: ecc_config.advices[5],
+            y_r: ecc_config.advices[6],
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
+        meta.create_gate("point selection", |meta| {
+            let q_select = meta.query_selector(self.q_select);
+            let cond = meta.query_advice(self.cond, Rotation::cur());
+            let x_a = meta.query_advice(self.x_a, Rotation::cur());
+            let y_a = meta.query_advice(self.y_a, Rotation::cur());
+            let x_b = meta.query_advice(self.x_b, Rotation::cur());
+            let y_b = meta.query_advice(self.y_b, Rotation::cur());
+            let x_r = meta.query_advice(self.x_r, Rotation::cur());
+            let y_r = meta.query_advice(self.y_r, Rotation::cur());
+
+            let one = Expression::Constant(C::Base::one());
+
+            // cond⋅(1 - cond) = 0
+            let bool_check = cond.clone() * (one - cond.clone());
+
+            // x_r = cond⋅(x_a - x_b) + x_b
+            let x_check = x_r - (cond.clone() * (x_a - x_b.clone()) + x_b);
+
+            // y_r = cond⋅(y_a - y_b) + y_b
+            let y_check = y_r - (cond * (y_a - y_b.clone()) + y_b);
+
+            array::IntoIter::new([bool_check, x_check, y_check])
+                .map(move |poly| q_select.clone() * poly)
+        });
+    }
+
+    /// Assigns `R = cond ? A : B` at the given offset.
+    pub(super) fn assign_region(
+        &self,
+        cond: &CellValue<C::Base>,
+        a: &EccPoint<C>,
+        b: &EccPoint<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        // Enable `q_select` selector
+        self.q_select.enable(region, offset)?;
+
+        // Copy `cond`, `a` and `b`
+        copy(region, || "cond", self.cond, offset, cond)?;
+        copy(region, || "x_a", self.x_a, offset, &a.x)?;
+        copy(region, || "y_a", self.y_a, offset, &a.y)?;
+        copy(region, || "x_b", self.x_b, offset, &b.x)?;
+        copy(region, || "y_b", self.y_b, offset, &b.y)?;
+
+        // Assign R = A if cond = 1, or B if cond = 0
+        let r = cond.value().map(|cond| {
+            if cond == C::Base::one() {
+                (a.x.value(), a.y.value())
+            } else {
+                (b.x.value(), b.y.value())
+            }
+        });
+
+        // Assign x_r
+        let x_r = r.and_then(|r| r.0);
+        let x_r_cell = region.assign_advice(
+            || "x_r",
+            self.x_r,
+            offset,
+            || x_r.ok_or(Error::SynthesisError),
+        )?;
+
+        // Assign y_r
+        let y_r = r.and_then(|r| r.1);
+        let y_r_cell = region.assign_advice(
+            || "y_r",
+            self.y_r,
+            offset,
+            || y_r.ok_or(Error::SynthesisError),
+        )?;
+
+        Ok(EccPoint {
+            x: CellValue::<C::Base>::new(x_r_cell, x_r),
+            y: CellValue::<C::Base>::new(y_r_cell, y_r),
+        })
+    }
+
+    /// Selects `table[index]`, where `index` is given by its little-endian
+    /// bits, using a binary tree of point selections.
+    pub(super) fn assign_table(
+        &self,
+        index_bits: &[CellValue<C::Base>],
+        table: &[EccPoint<C>],
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        assert!(!table.is_empty());
+        assert_eq!(table.len(), 1 << index_bits.len());
+
+        let mut offset = 0;
+        let mut layer = table.to_vec();
+        for bit in index_bits.iter() {
+            // Each pair (layer[2j], layer[2j + 1]) is reduced to layer[2j + bit].
+            layer = layer
+                .chunks(2)
+                .map(|pair| {
+                    let selected = self.assign_region(bit, &pair[1], &pair[0], offset, region);
+                    offset += 1;
+                    selected
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+        }
+
+        assert_eq!(layer.len(), 1);
+        Ok(layer.remove(0))
+    }
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoints, Point},
+    };
+    use utilities::UtilitiesInstructions;
+
+    pub fn test_select<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // Generate random points A and B
+        let a_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // A
+        let a = Point::new(chip.clone(), layouter.namespace(|| "A"), Some(a_val))?;
+        let b_val = pallas::Point::random(rand::rngs::OsRng).to_affine(); // B
+        let b = Point::new(chip.clone(), layouter.namespace(|| "B"), Some(b_val))?;
+
+        // Generate a (0,0) point to be used in other tests.
+        let zero = {
+            Point::new(
+                chip.clone(),
+                layouter.namespace(|| "identity"),
+                Some(pallas::Affine::identity()),
+            )?
+        };
+
+        let column = chip.config().advices[0];
+
+        // cond = 1 selects A
+        {
+            let cond = chip.load_private(
+                layouter.namespace(|| "cond = 1"),
+                column,
+                Some(pallas::Base::one()),
+            )?;
+            let result = Point::select(layouter.namespace(|| "1 ? A : B"), &cond, &a, &b)?;
+            result.constrain_equal(layouter.namespace(|| "1 ? A : B = A"), &a)?;
+        }
+
+        // cond = 0 selects B
+        {
+            let cond = chip.load_private(
+                layouter.namespace(|| "cond = 0"),
+                column,
+                Some(pallas::Base::zero()),
+            )?;
+            let result = Point::select(layouter.namespace(|| "0 ? A : B"), &cond, &a, &b)?;
+            result.constrain_equal(layouter.namespace(|| "0 ? A : B = B"), &b)?;
+        }
+
+        // Selecting the identity
+        {
+            let cond = chip.load_private(
+                layouter.namespace(|| "cond = 1"),
+                column,
+                Some(pallas::Base::one()),
+            )?;
+            let result = Point::select(layouter.namespace(|| "1 ? 𝒪 : A"), &cond, &zero, &a)?;
+            result.constrain_equal(layouter.namespace(|| "1 ? 𝒪 : A = 𝒪"), &zero)?;
+        }
+
+        // Select from a table of eight points
+        {
+            let table_vals: Vec<_> = (0..8)
+                .map(|_| pallas::Point::random(rand::rngs::OsRng).to_affine())
+                .collect();
+            let table = table_vals
+                .iter()
+                .enumerate()
+                .map(|(i, val)| {
+                    Point::new(
+                        chip.clone(),
+                        layouter.namespace(|| format!("table[{}]", i)),
+                        Some(*val),
+                    )
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for index in [0usize, 5, 7].iter() {
+                let index_bits = (0..3)
+                    .map(|i| {
+                        chip.load_private(
+                            layouter.namespace(|| format!("bit {}", i)),
+                            column,
+                            Some(pallas::Base::from_u64(((index >> i) & 1) as u64)),
+                        )
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                let result = Point::select_from_table(
+                    layouter.namespace(|| format!("table[{}]", index)),
+                    &index_bits,
+                    &table,
+                )?;
+                result.constrain_equal(
+                    layouter.namespace(|| format!("constrain table[{}]", index)),
+                    &table[*index],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
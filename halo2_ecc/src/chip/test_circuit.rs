@@ -0,0 +1,95 @@
+//! Column layouts shared by the test circuits of this crate.
+
+use group::prime::PrimeCurveAffine;
+use halo2::plonk::ConstraintSystem;
+use pasta_curves::pallas;
+use utilities::lookup_range_check::LookupRangeCheckConfig;
+
+use super::{EccChip, EccConfig, ForeignCurve, ForeignEccChip, ForeignEccConfig, VarBaseMul};
+use crate::gadget::FixedPoints;
+
+/// Fixed bases for circuits that do not do any fixed-base scalar multiplication.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub(crate) struct NoFixedBases;
+
+impl<const K: usize> FixedPoints<pallas::Affine, K> for NoFixedBases {
+    fn generator(&self) -> pallas::Affine {
+        pallas::Affine::generator()
+    }
+
+    fn u(&self) -> Vec<Vec<[u8; 32]>> {
+        vec![]
+    }
+
+    fn z(&self) -> Vec<u64> {
+        vec![]
+    }
+
+    fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+        vec![]
+    }
+}
+
+/// Configures an [`EccChip`] over ten advice columns, where the last column is
+/// used by the lookup range check, along with a fixed column for loading
+/// constants.
+///
+/// The lookup table is not loaded.
+pub(crate) fn configure_ecc_chip<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+    meta: &mut ConstraintSystem<pallas::Base>,
+    var_base_mul: VarBaseMul,
+) -> EccConfig<pallas::Affine, K> {
+    let advices = [
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+    ];
+    let lookup_table = meta.lookup_table_column();
+    let lagrange_coeffs: Vec<_> = (0..(1 << K)).map(|_| meta.fixed_column()).collect();
+
+    // Shared fixed column for loading constants
+    let constants = meta.fixed_column();
+    meta.enable_constant(constants);
+
+    let range_check = LookupRangeCheckConfig::configure(meta, advices[9], lookup_table);
+    EccChip::<pallas::Affine, F, K>::configure_with_var_base_mul(
+        meta,
+        advices,
+        &lagrange_coeffs,
+        range_check,
+        var_base_mul,
+    )
+}
+
+/// Configures a [`ForeignEccChip`] for `C` over five advice columns, along with
+/// an advice column for the running sum of the lookup range check and a fixed
+/// column for loading constants.
+///
+/// The lookup table is not loaded.
+pub(crate) fn configure_foreign_ecc_chip<C: ForeignCurve>(
+    meta: &mut ConstraintSystem<pallas::Base>,
+) -> ForeignEccConfig<pallas::Base> {
+    let advices = [
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+        meta.advice_column(),
+    ];
+    let running_sum = meta.advice_column();
+    let lookup_table = meta.lookup_table_column();
+
+    // Shared fixed column for loading constants
+    let constants = meta.fixed_column();
+    meta.enable_constant(constants);
+
+    let range_check = LookupRangeCheckConfig::configure(meta, running_sum, lookup_table);
+    ForeignEccChip::<pallas::Base, C>::configure(meta, advices, range_check)
+}
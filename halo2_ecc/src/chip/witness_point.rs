@@ -1,40 +1,42 @@
-use super::{CellValue, EccConfig, EccPoint, Var};
-
-use group::prime::PrimeCurveAffine;
+use super::{CellValue, EccConfig, EccCurve, EccPoint, NonIdentityEccPoint, Var};
 
 use halo2::{
     circuit::Region,
     plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector},
     poly::Rotation,
 };
-use pasta_curves::{arithmetic::CurveAffine, pallas};
+use std::marker::PhantomData;
 use utilities::copy;
 
 #[derive(Clone, Debug)]
-pub struct Config {
+pub struct Config<C: EccCurve> {
     q_point: Selector,
+    q_point_non_id: Selector,
     // x-coordinate
     pub x: Column<Advice>,
     // y-coordinate
     pub y: Column<Advice>,
+    _marker: PhantomData<C>,
 }
 
-impl From<&EccConfig> for Config {
-    fn from(ecc_config: &EccConfig) -> Self {
+impl<C: EccCurve, const K: usize> From<&EccConfig<C, K>> for Config<C> {
+    fn from(ecc_config: &EccConfig<C, K>) -> Self {
         Self {
             q_point: ecc_config.q_point,
+            q_point_non_id: ecc_config.q_point_non_id,
             x: ecc_config.advices[0],
             y: ecc_config.advices[1],
+            _marker: PhantomData,
         }
     }
 }
 
-impl Config {
-    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<pallas::Base>) {
+impl<C: EccCurve> Config<C> {
+    pub(super) fn create_gate(&self, meta: &mut ConstraintSystem<C::Base>) {
         meta.create_gate("witness point", |meta| {
             // Check that either the point being witness is either:
             // - the identity, which is mapped to (0, 0) in affine coordinates; or
-            // - a valid curve point y^2 = x^3 + b, where b = 5 in the Pallas equation
+            // - a valid curve point y^2 = x^3 + b, where b = 5 in the Pallas and Vesta equations
 
             let q_point = meta.query_selector(self.q_point);
             let x = meta.query_advice(self.x, Rotation::cur());
@@ -43,28 +45,42 @@ impl Config {
             // y^2 = x^3 + b
             let curve_eqn = y.clone().square()
                 - (x.clone().square() * x.clone())
-                - Expression::Constant(pallas::Affine::b());
+                - Expression::Constant(C::b());
 
             vec![
                 q_point.clone() * x * curve_eqn.clone(),
                 q_point * y * curve_eqn,
             ]
         });
+
+        meta.create_gate("witness non-identity point", |meta| {
+            // Check that the point being witnessed is a valid curve point
+            // y^2 = x^3 + b. Since b ≠ 0, this rules out the identity (0, 0).
+
+            let q_point_non_id = meta.query_selector(self.q_point_non_id);
+            let x = meta.query_advice(self.x, Rotation::cur());
+            let y = meta.query_advice(self.y, Rotation::cur());
+
+            // y^2 = x^3 + b
+            let curve_eqn = y.square() - (x.clone().square() * x) - Expression::Constant(C::b());
+
+            vec![q_point_non_id * curve_eqn]
+        });
     }
 
     pub(super) fn witness_point(
         &self,
-        value: Option<pallas::Affine>,
+        value: Option<C>,
         offset: usize,
-        region: &mut Region<'_, pallas::Base>,
-    ) -> Result<EccPoint, Error> {
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
         // Enable `q_point` selector
         self.q_point.enable(region, offset)?;
 
         let value = value.map(|value| {
             // Map the identity to (0, 0).
-            if value == pallas::Affine::identity() {
-                (pallas::Base::zero(), pallas::Base::zero())
+            if value == C::identity() {
+                (C::Base::zero(), C::Base::zero())
             } else {
                 let value = value.coordinates().unwrap();
                 (*value.x(), *value.y())
@@ -90,18 +106,92 @@ impl Config {
         )?;
 
         Ok(EccPoint {
-            x: CellValue::<pallas::Base>::new(x_var, x_val),
-            y: CellValue::<pallas::Base>::new(y_var, y_val),
+            x: CellValue::<C::Base>::new(x_var, x_val),
+            y: CellValue::<C::Base>::new(y_var, y_val),
+        })
+    }
+
+    pub(super) fn witness_non_id(
+        &self,
+        value: Option<C>,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<NonIdentityEccPoint<C>, Error> {
+        // Enable `q_point_non_id` selector
+        self.q_point_non_id.enable(region, offset)?;
+
+        let value = value
+            .map(|value| {
+                // Witnessing the identity as a non-identity point is an error.
+                if value == C::identity() {
+                    Err(Error::SynthesisError)
+                } else {
+                    let value = value.coordinates().unwrap();
+                    Ok((*value.x(), *value.y()))
+                }
+            })
+            .transpose()?;
+
+        // Assign `x` value
+        let x_val = value.map(|value| value.0);
+        let x_var = region.assign_advice(
+            || "x",
+            self.x,
+            offset,
+            || x_val.ok_or(Error::SynthesisError),
+        )?;
+
+        // Assign `y` value
+        let y_val = value.map(|value| value.1);
+        let y_var = region.assign_advice(
+            || "y",
+            self.y,
+            offset,
+            || y_val.ok_or(Error::SynthesisError),
+        )?;
+
+        Ok(NonIdentityEccPoint {
+            x: CellValue::<C::Base>::new(x_var, x_val),
+            y: CellValue::<C::Base>::new(y_var, y_val),
+        })
+    }
+
+    pub(super) fn constant_point(
+        &self,
+        value: C,
+        offset: usize,
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
+        // Map the identity to (0, 0).
+        let (x_val, y_val) = if value == C::identity() {
+            (C::Base::zero(), C::Base::zero())
+        } else {
+            let value = value.coordinates().unwrap();
+            (*value.x(), *value.y())
+        };
+
+        // The coordinates are constrained to the fixed constants, so there is no
+        // need to check that they lie on the curve.
+
+        // Assign `x` value
+        let x_var = region.assign_advice_from_constant(|| "x", self.x, offset, x_val)?;
+
+        // Assign `y` value
+        let y_var = region.assign_advice_from_constant(|| "y", self.y, offset, y_val)?;
+
+        Ok(EccPoint {
+            x: CellValue::<C::Base>::new(x_var, Some(x_val)),
+            y: CellValue::<C::Base>::new(y_var, Some(y_val)),
         })
     }
 
     pub(super) fn copy_point(
         &self,
-        x: CellValue<pallas::Base>,
-        y: CellValue<pallas::Base>,
+        x: CellValue<C::Base>,
+        y: CellValue<C::Base>,
         offset: usize,
-        region: &mut Region<'_, pallas::Base>,
-    ) -> Result<EccPoint, Error> {
+        region: &mut Region<'_, C::Base>,
+    ) -> Result<EccPoint<C>, Error> {
         // Enable `q_point` selector
         self.q_point.enable(region, offset)?;
 
@@ -113,3 +203,61 @@ impl Config {
         Ok(EccPoint { x, y })
     }
 }
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use halo2::{circuit::Layouter, plonk::Error};
+    use pasta_curves::pallas;
+
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoints, Point},
+    };
+
+    pub fn test_constant_point<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        // A constant point is equal to the same point witnessed.
+        let p_val = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let p = Point::constant(chip.clone(), layouter.namespace(|| "constant P"), p_val)?;
+        {
+            let witnessed = Point::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+            p.constrain_equal(layouter.namespace(|| "constant P = P"), &witnessed)?;
+        }
+
+        // The constant identity is mapped to (0, 0).
+        let zero = Point::constant(
+            chip.clone(),
+            layouter.namespace(|| "constant 𝒪"),
+            pallas::Affine::identity(),
+        )?;
+        {
+            let witnessed = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "𝒪"),
+                Some(pallas::Affine::identity()),
+            )?;
+            zero.constrain_equal(layouter.namespace(|| "constant 𝒪 = 𝒪"), &witnessed)?;
+        }
+
+        // Constant points can be used in other instructions.
+        {
+            let result = p.add(layouter.namespace(|| "P + 𝒪"), &zero)?;
+            result.constrain_equal(layouter.namespace(|| "P + 𝒪 = P"), &p)?;
+
+            let q_val = pallas::Point::random(rand::rngs::OsRng).to_affine();
+            let q = Point::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+            let result = p.add(layouter.namespace(|| "P + Q"), &q)?;
+            let expected = Point::new(
+                chip,
+                layouter.namespace(|| "expected P + Q"),
+                Some((p_val.to_curve() + q_val).to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| "constrain P + Q"), &expected)?;
+        }
+
+        Ok(())
+    }
+}
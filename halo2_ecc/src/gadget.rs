@@ -3,22 +3,32 @@
 use std::fmt::Debug;
 
 use halo2::{
-    arithmetic::CurveAffine,
+    arithmetic::{CurveAffine, FieldExt},
     circuit::{Chip, Layouter},
     plonk::Error,
 };
 
 use utilities::UtilitiesInstructions;
 
-/// Window size for fixed-base scalar multiplication
+pub mod commitment;
+pub mod ecdsa;
+pub mod hash_to_curve;
+pub mod signature;
+
+/// Default window size for fixed-base scalar multiplication
 pub const FIXED_BASE_WINDOW_SIZE: usize = 3;
 
 /// $2^{`FIXED_BASE_WINDOW_SIZE`}$
 pub const H: usize = 1 << FIXED_BASE_WINDOW_SIZE;
 
 /// The set of circuit instructions required to use the ECC gadgets.
-pub trait EccInstructions<C: CurveAffine>:
-    Chip<C::Base> + UtilitiesInstructions<C::Base> + Clone + Debug + Eq
+///
+/// The circuit is defined over the field `F`, which defaults to the base field of
+/// `C`. A chip for a curve whose base field is not the circuit field, such as
+/// [`crate::chip::ForeignEccChip`], sets `F` to the circuit field and emulates
+/// the base field arithmetic.
+pub trait EccInstructions<C: CurveAffine, F: FieldExt = <C as CurveAffine>::Base>:
+    Chip<F> + UtilitiesInstructions<F> + Clone + Debug + Eq
 {
     /// Variable representing an element of the elliptic curve's base field, that
     /// is used as a scalar in variable-base scalar mul.
@@ -33,25 +43,35 @@ pub trait EccInstructions<C: CurveAffine>:
     /// https://zips.z.cash/protocol/nu5.pdf#orchardkeycomponents.)
     type ScalarVar: Clone + Debug;
     /// Variable representing a full-width element of the elliptic curve's
+    /// scalar field, to be used for variable-base scalar mul.
+    type ScalarVarFullWidth: Clone + Debug;
+    /// Variable representing a full-width element of the elliptic curve's
     /// scalar field, to be used for fixed-base scalar mul.
     type ScalarFixed: Clone + Debug;
     /// Variable representing a signed short element of the elliptic curve's
     /// scalar field, to be used for fixed-base scalar mul.
     ///
-    /// A `ScalarFixedShort` must be in the range [-(2^64 - 1), 2^64 - 1].
+    /// The bit width `N` of the magnitude is chosen by each call to
+    /// [`EccInstructions::mul_fixed_short`], and the scalar must be in the range
+    /// [-(2^N - 1), 2^N - 1].
     type ScalarFixedShort: Clone + Debug;
     /// Variable representing an elliptic curve point.
     type Point: Clone + Debug;
+    /// Variable representing a non-identity elliptic curve point.
+    type NonIdentityPoint: Clone + Debug + Into<Self::Point>;
     /// Variable representing the affine short Weierstrass x-coordinate of an
     /// elliptic curve point.
     type X: Clone + Debug;
     /// Enumeration of the set of fixed bases to be used in scalar mul with a full-width scalar.
-    type FixedPoints: FixedPoints<C>;
+    ///
+    /// Chips will usually require this to implement [`FixedPoints`] for the window
+    /// size they use in fixed-base scalar mul.
+    type FixedPoints: Clone + Debug + Eq;
 
     /// Constrains point `a` to be equal in value to point `b`.
     fn constrain_equal(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<(), Error>;
@@ -60,16 +80,35 @@ pub trait EccInstructions<C: CurveAffine>:
     /// This maps the identity to (0, 0) in affine coordinates.
     fn witness_point(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         value: Option<C>,
     ) -> Result<Self::Point, Error>;
 
+    /// Witnesses the given point as a private input to the circuit, constraining
+    /// it not to be the identity.
+    ///
+    /// This returns an error if `value` is the identity.
+    fn witness_point_non_id(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C>,
+    ) -> Result<Self::NonIdentityPoint, Error>;
+
+    /// Loads the given constant point into the circuit, assigning its coordinates
+    /// from the fixed constants column so that they are bound in the verifying key.
+    /// This maps the identity to (0, 0) in affine coordinates.
+    fn constant_point(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: C,
+    ) -> Result<Self::Point, Error>;
+
     /// Copies a point given existing x- and y-coordinate variables,
     /// checking that the coordinates indeed belong to a valid point.
     /// This maps the identity to (0, 0) in affine coordinates.
     fn copy_point(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         x: Self::Var,
         y: Self::Var,
     ) -> Result<Self::Point, Error>;
@@ -82,41 +121,156 @@ pub trait EccInstructions<C: CurveAffine>:
     /// This returns an error in exceptional cases.
     fn add_incomplete(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::NonIdentityPoint,
+        b: &Self::NonIdentityPoint,
+    ) -> Result<Self::NonIdentityPoint, Error>;
+
+    /// Performs complete point addition, returning `a + b`.
+    fn add(
+        &self,
+        layouter: &mut impl Layouter<F>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<Self::Point, Error>;
 
-    /// Performs complete point addition, returning `a + b`.
-    fn add(
+    /// Performs point doubling, returning `[2] a`.
+    fn double(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+    ) -> Result<Self::Point, Error>;
+
+    /// Performs point negation, returning `-a`.
+    fn neg(&self, layouter: &mut impl Layouter<F>, a: &Self::Point) -> Result<Self::Point, Error>;
+
+    /// Performs conditional point negation, returning `-a` if `bit` is 1 and
+    /// `a` if `bit` is 0. `bit` is constrained to be boolean.
+    fn conditional_neg(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        bit: &Self::Var,
+    ) -> Result<Self::Point, Error>;
+
+    /// Performs complete point subtraction, returning `a - b`.
+    fn sub(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         a: &Self::Point,
         b: &Self::Point,
     ) -> Result<Self::Point, Error>;
 
+    /// Returns `a` if `cond` is 1, and `b` if `cond` is 0. `cond` is
+    /// constrained to be boolean.
+    fn select(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        cond: &Self::Var,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Point, Error>;
+
+    /// Returns `table[index]`, where `index` is given by its little-endian
+    /// boolean decomposition `index_bits`.
+    ///
+    /// `table` must contain exactly `2^index_bits.len()` points.
+    fn select_from_table(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        index_bits: &[Self::Var],
+        table: &[Self::Point],
+    ) -> Result<Self::Point, Error>;
+
+    /// Returns a boolean variable that is 1 if `a == b`, and 0 otherwise.
+    fn is_equal(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+        b: &Self::Point,
+    ) -> Result<Self::Var, Error>;
+
+    /// Returns a boolean variable that is 1 if `a` is the identity, and 0
+    /// otherwise.
+    fn is_identity(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Point,
+    ) -> Result<Self::Var, Error>;
+
     /// Performs variable-base scalar multiplication, returning `[scalar] base`.
-    /// Multiplication of the identity `[scalar] 𝒪 ` returns an error.
     fn mul(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
+        scalar: &Self::Var,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVar), Error>;
+
+    /// Performs variable-base scalar multiplication using a full-width scalar,
+    /// returning `[scalar] base`.
+    ///
+    /// The scalar is witnessed as a canonical decomposition.
+    fn mul_full_width(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        scalar: Option<C::Scalar>,
+        base: &Self::NonIdentityPoint,
+    ) -> Result<(Self::Point, Self::ScalarVarFullWidth), Error>;
+
+    /// Performs variable-base scalar multiplication, returning `[scalar] base`.
+    ///
+    /// This is an alternative to [`EccInstructions::mul`] that uses the curve
+    /// endomorphism to halve the number of doublings, decomposing the scalar into
+    /// two halves of roughly 128 bits each.
+    fn mul_endo(
+        &self,
+        layouter: &mut impl Layouter<F>,
         scalar: &Self::Var,
-        base: &Self::Point,
+        base: &Self::NonIdentityPoint,
     ) -> Result<(Self::Point, Self::ScalarVar), Error>;
 
+    /// Performs variable-base multi-scalar multiplication, returning
+    /// `Σ [scalar_i] base_i` for the given `(scalar_i, base_i)` terms.
+    ///
+    /// Unlike repeated calls to [`EccInstructions::mul`], the doublings are shared
+    /// across all terms.
+    fn msm(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        terms: &[(Self::Var, Self::NonIdentityPoint)],
+    ) -> Result<Self::Point, Error>;
+
     /// Performs fixed-base scalar multiplication using a full-width scalar, returning `[scalar] base`.
     fn mul_fixed(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         scalar: Option<C::Scalar>,
         base: &Self::FixedPoints,
     ) -> Result<(Self::Point, Self::ScalarFixed), Error>;
 
-    /// Performs fixed-base scalar multiplication using a short signed scalar, returning
-    /// `[magnitude * sign] base`.
-    fn mul_fixed_short(
+    /// Performs fixed-base scalar multiplication using a full-width scalar that is
+    /// already in the circuit, returning `[scalar] base`.
+    ///
+    /// The scalar is given as its little-endian decomposition into the chip's
+    /// fixed-base windows `k_i`, such that `scalar = Σ k_i * (2^K)^i` for window
+    /// size `K`. The windows are constrained to be `K`-bit values which encode a
+    /// canonical scalar, i.e. an integer in `[0, q)`.
+    fn mul_fixed_from_windows(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
+        windows: &[Self::Var],
+        base: &Self::FixedPoints,
+    ) -> Result<(Self::Point, Self::ScalarFixed), Error>;
+
+    /// Performs fixed-base scalar multiplication using a short signed scalar with an
+    /// `N`-bit magnitude, returning `[magnitude * sign] base`.
+    ///
+    /// For [`crate::chip::EccChip`], `base` must provide tables for `N`-bit short
+    /// scalars from [`FixedPoints::u_short`], [`FixedPoints::z_short`] and
+    /// [`FixedPoints::lagrange_coeffs_short`].
+    fn mul_fixed_short<const N: usize>(
+        &self,
+        layouter: &mut impl Layouter<F>,
         magnitude_sign: (Self::Var, Self::Var),
         base: &Self::FixedPoints,
     ) -> Result<(Self::Point, Self::ScalarFixedShort), Error>;
@@ -126,18 +280,56 @@ pub trait EccInstructions<C: CurveAffine>:
     /// instruction.
     fn mul_fixed_base_field_elem(
         &self,
-        layouter: &mut impl Layouter<C::Base>,
+        layouter: &mut impl Layouter<F>,
         base_field_elem: Self::Var,
         base: &Self::FixedPoints,
     ) -> Result<Self::Point, Error>;
 }
 
-/// Returns information about a fixed point.
-pub trait FixedPoints<C: CurveAffine>: Debug + Eq + Clone {
+/// Returns information about a fixed point, precomputed for fixed-base scalar
+/// mul using `K`-bit windows.
+///
+/// Each window of `u()` and `lagrange_coeffs()` has $2^K$ entries.
+///
+/// The tables must have one window for each `K`-bit window of the scalars they
+/// are used with, since the last window depends on the number of windows.
+/// `u()`, `z()` and `lagrange_coeffs()` are used for full-width scalars and
+/// base field elements, and `u_short::<N>()`, `z_short::<N>()` and
+/// `lagrange_coeffs_short::<N>()` for short scalars with an `N`-bit magnitude, so
+/// that a single fixed base can be used with scalars of several widths.
+pub trait FixedPoints<C: CurveAffine, const K: usize>: Debug + Eq + Clone {
     fn generator(&self) -> C;
-    fn u(&self) -> Vec<[[u8; 32]; H]>;
+    fn u(&self) -> Vec<Vec<[u8; 32]>>;
     fn z(&self) -> Vec<u64>;
-    fn lagrange_coeffs(&self) -> Vec<[C::Base; H]>;
+    fn lagrange_coeffs(&self) -> Vec<Vec<C::Base>>;
+
+    /// Returns `u` for short scalars with an `N`-bit magnitude, with one window
+    /// for each of the $\lceil N / K \rceil$ windows of the magnitude.
+    ///
+    /// Fixed bases that are used with short scalars must implement this; the
+    /// default panics.
+    fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {
+        panic!("no u-values for {}-bit short scalars", N)
+    }
+
+    /// Returns `z` for short scalars with an `N`-bit magnitude, with one value
+    /// for each of the $\lceil N / K \rceil$ windows of the magnitude.
+    ///
+    /// Fixed bases that are used with short scalars must implement this; the
+    /// default panics.
+    fn z_short<const N: usize>(&self) -> Vec<u64> {
+        panic!("no z-values for {}-bit short scalars", N)
+    }
+
+    /// Returns the Lagrange coefficients for short scalars with an `N`-bit
+    /// magnitude, with one window for each of the $\lceil N / K \rceil$ windows
+    /// of the magnitude.
+    ///
+    /// Fixed bases that are used with short scalars must implement this; the
+    /// default panics.
+    fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<C::Base>> {
+        panic!("no Lagrange coefficients for {}-bit short scalars", N)
+    }
 }
 
 /// An element of the given elliptic curve's base field, that is used as a scalar
@@ -152,47 +344,89 @@ pub trait FixedPoints<C: CurveAffine>: Debug + Eq + Clone {
 /// to be in the base field of the curve. (See non-normative notes in
 /// https://zips.z.cash/protocol/nu5.pdf#orchardkeycomponents.)
 #[derive(Debug)]
-pub struct ScalarVar<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct ScalarVar<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
     chip: EccChip,
     inner: EccChip::ScalarVar,
 }
 
+/// A full-width element of the given elliptic curve's scalar field, to be used for
+/// variable-base scalar mul.
+#[derive(Debug)]
+pub struct ScalarVarFullWidth<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    chip: EccChip,
+    inner: EccChip::ScalarVarFullWidth,
+}
+
 /// A full-width element of the given elliptic curve's scalar field, to be used for fixed-base scalar mul.
 #[derive(Debug)]
-pub struct ScalarFixed<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct ScalarFixed<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
     chip: EccChip,
     inner: EccChip::ScalarFixed,
 }
 
-/// A signed short element of the given elliptic curve's scalar field, to be used for fixed-base scalar mul.
+/// A signed `N`-bit short element of the given elliptic curve's scalar field, to be used for fixed-base scalar mul.
 #[derive(Debug)]
-pub struct ScalarFixedShort<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct ScalarFixedShort<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    const N: usize,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
     chip: EccChip,
     inner: EccChip::ScalarFixedShort,
 }
 
 /// An elliptic curve point over the given curve.
 #[derive(Copy, Clone, Debug)]
-pub struct Point<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct Point<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
     chip: EccChip,
     inner: EccChip::Point,
 }
 
-impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> Point<C, EccChip, F> {
     /// Constructs a new point with the given value.
     pub fn new(
         chip: EccChip,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         value: Option<C>,
     ) -> Result<Self, Error> {
         let point = chip.witness_point(&mut layouter, value);
         point.map(|inner| Point { chip, inner })
     }
 
+    /// Constructs a new point with the given constant value.
+    ///
+    /// Unlike [`Point::new`], the coordinates are fixed in the circuit rather than
+    /// witnessed.
+    pub fn constant(
+        chip: EccChip,
+        mut layouter: impl Layouter<F>,
+        value: C,
+    ) -> Result<Self, Error> {
+        let point = chip.constant_point(&mut layouter, value);
+        point.map(|inner| Point { chip, inner })
+    }
+
     /// Constructs a new point by copying in its coordinates as `x`, `y` cells.
     pub fn copy(
         chip: EccChip,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         x: EccChip::Var,
         y: EccChip::Var,
     ) -> Result<Self, Error> {
@@ -203,7 +437,7 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
     /// Constrains this point to be equal in value to another point.
     pub fn constrain_equal(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         other: &Self,
     ) -> Result<(), Error> {
         self.chip
@@ -216,7 +450,7 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
     }
 
     /// Extracts the x-coordinate of a point.
-    pub fn extract_p(&self) -> X<C, EccChip> {
+    pub fn extract_p(&self) -> X<C, EccChip, F> {
         X::from_inner(self.chip.clone(), EccChip::extract_p(&self.inner).clone())
     }
 
@@ -226,7 +460,7 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
     }
 
     /// Returns `self + other` using complete addition.
-    pub fn add(&self, mut layouter: impl Layouter<C::Base>, other: &Self) -> Result<Self, Error> {
+    pub fn add(&self, mut layouter: impl Layouter<F>, other: &Self) -> Result<Self, Error> {
         assert_eq!(self.chip, other.chip);
         self.chip
             .add(&mut layouter, &self.inner, &other.inner)
@@ -236,16 +470,191 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
             })
     }
 
+    /// Returns `[2] self`.
+    pub fn double(&self, mut layouter: impl Layouter<F>) -> Result<Self, Error> {
+        self.chip
+            .double(&mut layouter, &self.inner)
+            .map(|inner| Point {
+                chip: self.chip.clone(),
+                inner,
+            })
+    }
+
+    /// Returns `-self`.
+    pub fn neg(&self, mut layouter: impl Layouter<F>) -> Result<Self, Error> {
+        self.chip
+            .neg(&mut layouter, &self.inner)
+            .map(|inner| Point {
+                chip: self.chip.clone(),
+                inner,
+            })
+    }
+
+    /// Returns `-self` if `bit` is 1, and `self` if `bit` is 0.
+    pub fn conditional_neg(
+        &self,
+        mut layouter: impl Layouter<F>,
+        bit: &EccChip::Var,
+    ) -> Result<Self, Error> {
+        self.chip
+            .conditional_neg(&mut layouter, &self.inner, bit)
+            .map(|inner| Point {
+                chip: self.chip.clone(),
+                inner,
+            })
+    }
+
+    /// Returns `self - other` using complete addition.
+    pub fn sub(&self, mut layouter: impl Layouter<F>, other: &Self) -> Result<Self, Error> {
+        assert_eq!(self.chip, other.chip);
+        self.chip
+            .sub(&mut layouter, &self.inner, &other.inner)
+            .map(|inner| Point {
+                chip: self.chip.clone(),
+                inner,
+            })
+    }
+
+    /// Returns `a` if `cond` is 1, and `b` if `cond` is 0.
+    pub fn select(
+        mut layouter: impl Layouter<F>,
+        cond: &EccChip::Var,
+        a: &Self,
+        b: &Self,
+    ) -> Result<Self, Error> {
+        assert_eq!(a.chip, b.chip);
+        a.chip
+            .select(&mut layouter, cond, &a.inner, &b.inner)
+            .map(|inner| Point {
+                chip: a.chip.clone(),
+                inner,
+            })
+    }
+
+    /// Returns `table[index]`, where `index` is given by its little-endian
+    /// boolean decomposition `index_bits`.
+    pub fn select_from_table(
+        mut layouter: impl Layouter<F>,
+        index_bits: &[EccChip::Var],
+        table: &[Self],
+    ) -> Result<Self, Error> {
+        assert!(!table.is_empty());
+        let chip = table[0].chip.clone();
+        for point in table.iter() {
+            assert_eq!(chip, point.chip);
+        }
+        let table: Vec<_> = table.iter().map(|point| point.inner.clone()).collect();
+
+        chip.select_from_table(&mut layouter, index_bits, &table)
+            .map(|inner| Point { chip, inner })
+    }
+
+    /// Returns a boolean variable that is 1 if `self == other`, and 0 otherwise.
+    pub fn is_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        other: &Self,
+    ) -> Result<EccChip::Var, Error> {
+        assert_eq!(self.chip, other.chip);
+        self.chip.is_equal(&mut layouter, &self.inner, &other.inner)
+    }
+
+    /// Returns a boolean variable that is 1 if `self` is the identity, and 0
+    /// otherwise.
+    pub fn is_identity(&self, mut layouter: impl Layouter<F>) -> Result<EccChip::Var, Error> {
+        self.chip.is_identity(&mut layouter, &self.inner)
+    }
+
+    /// Returns `Σ [scalar_i] base_i` over the given `(scalar_i, base_i)` terms,
+    /// sharing the doublings across all terms.
+    pub fn msm(
+        chip: EccChip,
+        mut layouter: impl Layouter<F>,
+        terms: &[(EccChip::Var, NonIdentityPoint<C, EccChip, F>)],
+    ) -> Result<Self, Error> {
+        let terms: Vec<_> = terms
+            .iter()
+            .map(|(scalar, base)| {
+                assert_eq!(chip, base.chip);
+                (*scalar, base.inner.clone())
+            })
+            .collect();
+        chip.msm(&mut layouter, &terms)
+            .map(|inner| Point { chip, inner })
+    }
+}
+
+/// A non-identity elliptic curve point over the given curve.
+#[derive(Copy, Clone, Debug)]
+pub struct NonIdentityPoint<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    chip: EccChip,
+    inner: EccChip::NonIdentityPoint,
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> NonIdentityPoint<C, EccChip, F> {
+    /// Constructs a new point with the given value.
+    ///
+    /// This returns an error if `value` is the identity.
+    pub fn new(
+        chip: EccChip,
+        mut layouter: impl Layouter<F>,
+        value: Option<C>,
+    ) -> Result<Self, Error> {
+        let point = chip.witness_point_non_id(&mut layouter, value);
+        point.map(|inner| NonIdentityPoint { chip, inner })
+    }
+
+    /// Constrains this point to be equal in value to another point.
+    pub fn constrain_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        other: &Self,
+    ) -> Result<(), Error> {
+        self.chip.constrain_equal(
+            &mut layouter,
+            &self.inner.clone().into(),
+            &other.inner.clone().into(),
+        )
+    }
+
+    /// Returns the inner point.
+    pub fn inner(&self) -> &EccChip::NonIdentityPoint {
+        &self.inner
+    }
+
+    /// Extracts the x-coordinate of a point.
+    pub fn extract_p(&self) -> X<C, EccChip, F> {
+        Point::from(self.clone()).extract_p()
+    }
+
+    /// Wraps the given point (obtained directly from an instruction) in a gadget.
+    pub fn from_inner(chip: EccChip, inner: EccChip::NonIdentityPoint) -> Self {
+        NonIdentityPoint { chip, inner }
+    }
+
+    /// Returns `self + other` using complete addition.
+    pub fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        other: &Point<C, EccChip, F>,
+    ) -> Result<Point<C, EccChip, F>, Error> {
+        Point::from(self.clone()).add(layouter, other)
+    }
+
     /// Returns `self + other` using incomplete addition.
     pub fn add_incomplete(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         other: &Self,
     ) -> Result<Self, Error> {
         assert_eq!(self.chip, other.chip);
         self.chip
             .add_incomplete(&mut layouter, &self.inner, &other.inner)
-            .map(|inner| Point {
+            .map(|inner| NonIdentityPoint {
                 chip: self.chip.clone(),
                 inner,
             })
@@ -254,9 +663,9 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
     /// Returns `[by] self`.
     pub fn mul(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         by: &EccChip::Var,
-    ) -> Result<(Self, ScalarVar<C, EccChip>), Error> {
+    ) -> Result<(Point<C, EccChip, F>, ScalarVar<C, EccChip, F>), Error> {
         self.chip
             .mul(&mut layouter, by, &self.inner)
             .map(|(point, scalar)| {
@@ -272,17 +681,74 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> Point<C, EccChip> {
                 )
             })
     }
+
+    /// Returns `[by] self`, using the curve endomorphism to halve the number of
+    /// doublings. See [`EccInstructions::mul_endo`].
+    pub fn mul_endo(
+        &self,
+        mut layouter: impl Layouter<F>,
+        by: &EccChip::Var,
+    ) -> Result<(Point<C, EccChip, F>, ScalarVar<C, EccChip, F>), Error> {
+        self.chip
+            .mul_endo(&mut layouter, by, &self.inner)
+            .map(|(point, scalar)| {
+                (
+                    Point {
+                        chip: self.chip.clone(),
+                        inner: point,
+                    },
+                    ScalarVar {
+                        chip: self.chip.clone(),
+                        inner: scalar,
+                    },
+                )
+            })
+    }
+
+    /// Returns `[by] self`, where `by` is a full-width scalar field element.
+    pub fn mul_full_width(
+        &self,
+        mut layouter: impl Layouter<F>,
+        by: Option<C::Scalar>,
+    ) -> Result<(Point<C, EccChip, F>, ScalarVarFullWidth<C, EccChip, F>), Error> {
+        self.chip
+            .mul_full_width(&mut layouter, by, &self.inner)
+            .map(|(point, scalar)| {
+                (
+                    Point {
+                        chip: self.chip.clone(),
+                        inner: point,
+                    },
+                    ScalarVarFullWidth {
+                        chip: self.chip.clone(),
+                        inner: scalar,
+                    },
+                )
+            })
+    }
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>>
+    From<NonIdentityPoint<C, EccChip, F>> for Point<C, EccChip, F>
+{
+    fn from(non_id_point: NonIdentityPoint<C, EccChip, F>) -> Self {
+        Point {
+            chip: non_id_point.chip,
+            inner: non_id_point.inner.into(),
+        }
+    }
 }
 
 /// The affine short Weierstrass x-coordinate of an elliptic curve point over the
 /// given curve.
 #[derive(Debug)]
-pub struct X<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct X<C: CurveAffine, EccChip: EccInstructions<C, F>, F: FieldExt = <C as CurveAffine>::Base>
+{
     chip: EccChip,
     inner: EccChip::X,
 }
 
-impl<C: CurveAffine, EccChip: EccInstructions<C>> X<C, EccChip> {
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> X<C, EccChip, F> {
     /// Wraps the given x-coordinate (obtained directly from an instruction) in a gadget.
     pub fn from_inner(chip: EccChip, inner: EccChip::X) -> Self {
         X { chip, inner }
@@ -299,20 +765,24 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> X<C, EccChip> {
 ///
 /// Used in scalar multiplication with full-width scalars.
 #[derive(Clone, Debug)]
-pub struct FixedPoint<C: CurveAffine, EccChip: EccInstructions<C>> {
+pub struct FixedPoint<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
     chip: EccChip,
     /// UNDO THIS pub.
     pub inner: EccChip::FixedPoints,
 }
 
-impl<C: CurveAffine, EccChip: EccInstructions<C>> FixedPoint<C, EccChip> {
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> FixedPoint<C, EccChip, F> {
     #[allow(clippy::type_complexity)]
     /// Returns `[by] self`.
     pub fn mul(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         by: Option<C::Scalar>,
-    ) -> Result<(Point<C, EccChip>, ScalarFixed<C, EccChip>), Error> {
+    ) -> Result<(Point<C, EccChip, F>, ScalarFixed<C, EccChip, F>), Error> {
         self.chip
             .mul_fixed(&mut layouter, by, &self.inner)
             .map(|(point, scalar)| {
@@ -329,13 +799,39 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> FixedPoint<C, EccChip> {
             })
     }
 
+    #[allow(clippy::type_complexity)]
+    /// Returns `[by] self`, where `by` is a full-width scalar given by its existing
+    /// little-endian fixed-base windows.
+    ///
+    /// See [`EccInstructions::mul_fixed_from_windows`].
+    pub fn mul_from_windows(
+        &self,
+        mut layouter: impl Layouter<F>,
+        windows: &[EccChip::Var],
+    ) -> Result<(Point<C, EccChip, F>, ScalarFixed<C, EccChip, F>), Error> {
+        self.chip
+            .mul_fixed_from_windows(&mut layouter, windows, &self.inner)
+            .map(|(point, scalar)| {
+                (
+                    Point {
+                        chip: self.chip.clone(),
+                        inner: point,
+                    },
+                    ScalarFixed {
+                        chip: self.chip.clone(),
+                        inner: scalar,
+                    },
+                )
+            })
+    }
+
     #[allow(clippy::type_complexity)]
     /// Returns `[by] self`.
     pub fn mul_base_field(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         by: EccChip::Var,
-    ) -> Result<Point<C, EccChip>, Error> {
+    ) -> Result<Point<C, EccChip, F>, Error> {
         self.chip
             .mul_fixed_base_field_elem(&mut layouter, by, &self.inner)
             .map(|inner| Point {
@@ -345,12 +841,13 @@ impl<C: CurveAffine, EccChip: EccInstructions<C>> FixedPoint<C, EccChip> {
     }
 
     #[allow(clippy::type_complexity)]
-    /// Returns `[by] self`.
-    pub fn mul_short(
+    /// Returns `[by] self`, where `by` is a signed short scalar with an `N`-bit
+    /// magnitude.
+    pub fn mul_short<const N: usize>(
         &self,
-        mut layouter: impl Layouter<C::Base>,
+        mut layouter: impl Layouter<F>,
         magnitude_sign: (EccChip::Var, EccChip::Var),
-    ) -> Result<(Point<C, EccChip>, ScalarFixedShort<C, EccChip>), Error> {
+    ) -> Result<(Point<C, EccChip, F>, ScalarFixedShort<C, EccChip, N, F>), Error> {
         self.chip
             .mul_fixed_short(&mut layouter, magnitude_sign, &self.inner)
             .map(|(point, scalar)| {
@@ -389,11 +886,15 @@ pub mod testing {
 
     use std::marker::PhantomData;
 
-    pub struct MyCircuit<S: EccTest<F>, F: FixedPoints<pallas::Affine>>(pub PhantomData<(S, F)>);
+    pub struct MyCircuit<S: EccTest<F, K>, F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        pub PhantomData<(S, F)>,
+    );
 
     #[allow(non_snake_case)]
-    impl<S: EccTest<F>, F: FixedPoints<pallas::Affine>> Circuit<pallas::Base> for MyCircuit<S, F> {
-        type Config = EccConfig;
+    impl<S: EccTest<F, K>, F: FixedPoints<pallas::Affine, K>, const K: usize> Circuit<pallas::Base>
+        for MyCircuit<S, F, K>
+    {
+        type Config = EccConfig<pallas::Affine, K>;
         type FloorPlanner = SimpleFloorPlanner;
 
         fn without_witnesses(&self) -> Self {
@@ -414,22 +915,13 @@ pub mod testing {
                 meta.advice_column(),
             ];
             let lookup_table = meta.lookup_table_column();
-            let lagrange_coeffs = [
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-                meta.fixed_column(),
-            ];
+            let lagrange_coeffs: Vec<_> = (0..(1 << K)).map(|_| meta.fixed_column()).collect();
             // Shared fixed column for loading constants
             let constants = meta.fixed_column();
             meta.enable_constant(constants);
 
             let range_check = LookupRangeCheckConfig::configure(meta, advices[9], lookup_table);
-            EccChip::<F>::configure(meta, advices, lagrange_coeffs, range_check)
+            EccChip::<pallas::Affine, F, K>::configure(meta, advices, &lagrange_coeffs, range_check)
         }
 
         fn synthesize(
@@ -445,10 +937,33 @@ pub mod testing {
 
             S::test_add(chip.clone(), layouter.namespace(|| "addition"))?;
             S::test_add_incomplete(chip.clone(), layouter.namespace(|| "incomplete addition"))?;
+            S::test_double(chip.clone(), layouter.namespace(|| "point doubling"))?;
+            S::test_neg(
+                chip.clone(),
+                layouter.namespace(|| "point negation and subtraction"),
+            )?;
+            S::test_select(chip.clone(), layouter.namespace(|| "point selection"))?;
+            S::test_is_equal(
+                chip.clone(),
+                layouter.namespace(|| "point equality and identity tests"),
+            )?;
             S::test_mul(
                 chip.clone(),
                 layouter.namespace(|| "variable-base scalar multiplication"),
             )?;
+            S::test_mul_full_width(
+                chip.clone(),
+                layouter.namespace(|| "variable-base scalar multiplication with full-width scalar"),
+            )?;
+            S::test_msm(
+                chip.clone(),
+                layouter.namespace(|| "variable-base multi-scalar multiplication"),
+            )?;
+            S::test_mul_endo(
+                chip.clone(),
+                layouter
+                    .namespace(|| "endomorphism-accelerated variable-base scalar multiplication"),
+            )?;
             S::test_mul_fixed(
                 chip.clone(),
                 layouter.namespace(|| "fixed-base scalar multiplication with full-width scalar"),
@@ -458,36 +973,100 @@ pub mod testing {
                 layouter.namespace(|| "fixed-base scalar multiplication with short signed scalar"),
             )?;
             S::test_mul_fixed_base_field(
-                chip,
+                chip.clone(),
                 layouter.namespace(|| "fixed-base scalar multiplication with base field element"),
             )?;
+            S::test_commitment(chip.clone(), layouter.namespace(|| "Pedersen commitments"))?;
+            S::test_constant_point(chip, layouter.namespace(|| "constant points"))?;
 
             Ok(())
         }
     }
 
-    pub trait EccTest<F: FixedPoints<pallas::Affine>> {
+    pub trait EccTest<F: FixedPoints<pallas::Affine, K>, const K: usize> {
         fn fixed_bases_full() -> Vec<F>;
         fn fixed_bases_short() -> Vec<F>;
         fn fixed_bases_base_field() -> Vec<F>;
 
-        fn test_add(chip: EccChip<F>, layouter: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        fn test_constant_point(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::witness_point::tests::test_constant_point(chip, layouter)
+        }
+
+        fn test_add(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
             crate::chip::add::tests::test_add(chip, layouter)
         }
 
         fn test_add_incomplete(
-            chip: EccChip<F>,
+            chip: EccChip<pallas::Affine, F, K>,
             layouter: impl Layouter<pallas::Base>,
         ) -> Result<(), Error> {
             crate::chip::add_incomplete::tests::test_add_incomplete(chip, layouter)
         }
 
-        fn test_mul(chip: EccChip<F>, layouter: impl Layouter<pallas::Base>) -> Result<(), Error> {
+        fn test_double(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::double::tests::test_double(chip, layouter)
+        }
+
+        fn test_neg(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::neg::tests::test_neg(chip, layouter)
+        }
+
+        fn test_select(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::select::tests::test_select(chip, layouter)
+        }
+
+        fn test_is_equal(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::is_equal::tests::test_is_equal(chip, layouter)
+        }
+
+        fn test_mul(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
             crate::chip::mul::tests::test_mul(chip, layouter)
         }
 
+        fn test_mul_full_width(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::mul::tests::test_mul_full_width(chip, layouter)
+        }
+
+        fn test_msm(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::mul::msm::tests::test_msm(chip, layouter)
+        }
+
+        fn test_mul_endo(
+            chip: EccChip<pallas::Affine, F, K>,
+            layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            crate::chip::mul::endo::tests::test_mul_endo(chip, layouter)
+        }
+
         fn test_mul_fixed(
-            chip: EccChip<F>,
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
         ) -> Result<(), Error> {
             for base in Self::fixed_bases_full().into_iter() {
@@ -502,7 +1081,7 @@ pub mod testing {
         }
 
         fn test_mul_fixed_short(
-            chip: EccChip<F>,
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
         ) -> Result<(), Error> {
             for base in Self::fixed_bases_short().into_iter() {
@@ -517,7 +1096,7 @@ pub mod testing {
         }
 
         fn test_mul_fixed_base_field(
-            chip: EccChip<F>,
+            chip: EccChip<pallas::Affine, F, K>,
             mut layouter: impl Layouter<pallas::Base>,
         ) -> Result<(), Error> {
             for base in Self::fixed_bases_base_field().into_iter() {
@@ -530,6 +1109,25 @@ pub mod testing {
 
             Ok(())
         }
+
+        fn test_commitment(
+            chip: EccChip<pallas::Affine, F, K>,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            for (short_base, full_base) in Self::fixed_bases_short()
+                .into_iter()
+                .zip(Self::fixed_bases_full().into_iter())
+            {
+                crate::gadget::commitment::tests::test_commitment(
+                    short_base,
+                    full_base,
+                    chip.clone(),
+                    layouter.namespace(|| "Pedersen commitment"),
+                )?;
+            }
+
+            Ok(())
+        }
     }
 }
 
@@ -540,8 +1138,11 @@ mod tests {
     use pasta_curves::pallas;
 
     use crate::{
-        chip::{compute_lagrange_coeffs, find_zs_and_us, NUM_WINDOWS, NUM_WINDOWS_SHORT},
-        gadget::{FixedPoints, H},
+        chip::{
+            compute_lagrange_coeffs, find_zs_and_us_cached, num_windows, L_SCALAR, L_VALUE,
+            NUM_WINDOWS, NUM_WINDOWS_SHORT,
+        },
+        gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE},
     };
     use lazy_static::lazy_static;
 
@@ -551,47 +1152,102 @@ mod tests {
         Short,
     }
 
+    /// Constants precomputed for `BASE` for a given window size.
+    struct Precomputed {
+        zs_and_us: Vec<(u64, Vec<[u8; 32]>)>,
+        lagrange_coeffs: Vec<Vec<pallas::Base>>,
+    }
+
+    impl Precomputed {
+        fn new<const K: usize>(num_windows: usize) -> Self {
+            Precomputed {
+                zs_and_us: find_zs_and_us_cached::<_, K>(*BASE, num_windows).unwrap(),
+                lagrange_coeffs: compute_lagrange_coeffs::<_, K>(*BASE, num_windows),
+            }
+        }
+    }
+
     lazy_static! {
         static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
-        static ref ZS_AND_US: Vec<(u64, [[u8; 32]; H])> =
-            find_zs_and_us(*BASE, NUM_WINDOWS).unwrap();
-        static ref ZS_AND_US_SHORT: Vec<(u64, [[u8; 32]; H])> =
-            find_zs_and_us(*BASE, NUM_WINDOWS_SHORT).unwrap();
-        static ref LAGRANGE_COEFFS: Vec<[pallas::Base; H]> =
-            compute_lagrange_coeffs(*BASE, NUM_WINDOWS);
-        static ref LAGRANGE_COEFFS_SHORT: Vec<[pallas::Base; H]> =
-            compute_lagrange_coeffs(*BASE, NUM_WINDOWS_SHORT);
+        static ref FULL_WIDTH: Precomputed =
+            Precomputed::new::<{ FIXED_BASE_WINDOW_SIZE }>(NUM_WINDOWS);
+        static ref SHORT: Precomputed =
+            Precomputed::new::<{ FIXED_BASE_WINDOW_SIZE }>(NUM_WINDOWS_SHORT);
+        static ref FULL_WIDTH_2: Precomputed = Precomputed::new::<2>(num_windows(L_SCALAR, 2));
+        static ref SHORT_2: Precomputed = Precomputed::new::<2>(num_windows(L_VALUE, 2));
+    }
+
+    impl FixedBase {
+        fn precomputed<const K: usize>(&self) -> &'static Precomputed {
+            match (self, K) {
+                (FixedBase::FullWidth, FIXED_BASE_WINDOW_SIZE) => &*FULL_WIDTH,
+                (FixedBase::Short, FIXED_BASE_WINDOW_SIZE) => &*SHORT,
+                (FixedBase::FullWidth, 2) => &*FULL_WIDTH_2,
+                (FixedBase::Short, 2) => &*SHORT_2,
+                _ => panic!("no precomputed tables for K = {}", K),
+            }
+        }
+
+        fn precomputed_short<const K: usize, const N: usize>() -> &'static Precomputed {
+            assert_eq!(
+                N, L_VALUE,
+                "no precomputed tables for {}-bit short scalars",
+                N
+            );
+            FixedBase::Short.precomputed::<K>()
+        }
     }
 
-    impl FixedPoints<pallas::Affine> for FixedBase {
+    impl<const K: usize> FixedPoints<pallas::Affine, K> for FixedBase {
         fn generator(&self) -> pallas::Affine {
             *BASE
         }
 
-        fn u(&self) -> Vec<[[u8; 32]; H]> {
-            match self {
-                FixedBase::FullWidth => ZS_AND_US.iter().map(|(_, us)| *us).collect(),
-                FixedBase::Short => ZS_AND_US_SHORT.iter().map(|(_, us)| *us).collect(),
-            }
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            self.precomputed::<K>()
+                .zs_and_us
+                .iter()
+                .map(|(_, us)| us.clone())
+                .collect()
         }
 
         fn z(&self) -> Vec<u64> {
-            match self {
-                FixedBase::FullWidth => ZS_AND_US.iter().map(|(z, _)| *z).collect(),
-                FixedBase::Short => ZS_AND_US_SHORT.iter().map(|(z, _)| *z).collect(),
-            }
+            self.precomputed::<K>()
+                .zs_and_us
+                .iter()
+                .map(|(z, _)| *z)
+                .collect()
         }
 
-        fn lagrange_coeffs(&self) -> Vec<[pallas::Base; H]> {
-            match self {
-                FixedBase::FullWidth => LAGRANGE_COEFFS.to_vec(),
-                FixedBase::Short => LAGRANGE_COEFFS_SHORT.to_vec(),
-            }
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            self.precomputed::<K>().lagrange_coeffs.clone()
+        }
+
+        fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {
+            FixedBase::precomputed_short::<K, N>()
+                .zs_and_us
+                .iter()
+                .map(|(_, us)| us.clone())
+                .collect()
+        }
+
+        fn z_short<const N: usize>(&self) -> Vec<u64> {
+            FixedBase::precomputed_short::<K, N>()
+                .zs_and_us
+                .iter()
+                .map(|(z, _)| *z)
+                .collect()
+        }
+
+        fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<pallas::Base>> {
+            FixedBase::precomputed_short::<K, N>()
+                .lagrange_coeffs
+                .clone()
         }
     }
 
     struct Test;
-    impl super::testing::EccTest<FixedBase> for Test {
+    impl<const K: usize> super::testing::EccTest<FixedBase, K> for Test {
         fn fixed_bases_full() -> Vec<FixedBase> {
             vec![FixedBase::FullWidth]
         }
@@ -607,12 +1263,218 @@ mod tests {
     fn ecc_chip() {
         use halo2::dev::MockProver;
 
-        let k = 13;
-        let circuit = super::testing::MyCircuit::<Test, FixedBase>(std::marker::PhantomData);
+        let k = 14;
+        let circuit = super::testing::MyCircuit::<Test, FixedBase, { FIXED_BASE_WINDOW_SIZE }>(
+            std::marker::PhantomData,
+        );
         let prover = MockProver::run(k, &circuit, vec![]).unwrap();
         assert_eq!(prover.verify(), Ok(()))
     }
 
+    #[test]
+    fn ecc_chip_2_bit_windows() {
+        use halo2::dev::MockProver;
+
+        let k = 14;
+        let circuit = super::testing::MyCircuit::<Test, FixedBase, 2>(std::marker::PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn fixed_base_tables() {
+        use crate::chip::mul_fixed_from_tables;
+        use pasta_curves::arithmetic::FieldExt;
+
+        fn check<const K: usize>() {
+            let scalars = [
+                pallas::Scalar::zero(),
+                pallas::Scalar::one(),
+                -pallas::Scalar::one(),
+                pallas::Scalar::rand(),
+            ];
+            for scalar in scalars.iter() {
+                assert_eq!(
+                    mul_fixed_from_tables::<_, K>(&FixedBase::FullWidth, *scalar),
+                    Some(*BASE * *scalar)
+                );
+            }
+
+            let magnitudes = [0, 1, u64::MAX, rand::random::<u64>()];
+            for magnitude in magnitudes.iter() {
+                let scalar = pallas::Scalar::from_u64(*magnitude);
+                assert_eq!(
+                    mul_fixed_from_tables::<_, K>(&FixedBase::Short, scalar),
+                    Some(*BASE * scalar)
+                );
+            }
+        }
+
+        check::<{ FIXED_BASE_WINDOW_SIZE }>();
+        check::<2>();
+    }
+
+    #[test]
+    fn ecc_chip_vesta() {
+        use crate::{
+            chip::{EccChip, EccConfig},
+            gadget::{FixedPoint, NonIdentityPoint, Point},
+        };
+        use halo2::{
+            circuit::{Layouter, SimpleFloorPlanner},
+            dev::MockProver,
+            plonk::{Circuit, ConstraintSystem, Error},
+        };
+        use pasta_curves::{arithmetic::FieldExt, vesta};
+        use utilities::{lookup_range_check::LookupRangeCheckConfig, UtilitiesInstructions};
+
+        lazy_static! {
+            static ref VESTA_BASE: vesta::Affine = vesta::Point::generator().to_affine();
+            static ref VESTA_ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+                find_zs_and_us_cached::<_, { FIXED_BASE_WINDOW_SIZE }>(*VESTA_BASE, NUM_WINDOWS)
+                    .unwrap();
+        }
+
+        #[derive(Debug, Eq, PartialEq, Clone)]
+        struct VestaFixedBase;
+
+        impl FixedPoints<vesta::Affine, { FIXED_BASE_WINDOW_SIZE }> for VestaFixedBase {
+            fn generator(&self) -> vesta::Affine {
+                *VESTA_BASE
+            }
+
+            fn u(&self) -> Vec<Vec<[u8; 32]>> {
+                VESTA_ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
+            }
+
+            fn z(&self) -> Vec<u64> {
+                VESTA_ZS_AND_US.iter().map(|(z, _)| *z).collect()
+            }
+
+            fn lagrange_coeffs(&self) -> Vec<Vec<vesta::Base>> {
+                compute_lagrange_coeffs::<_, { FIXED_BASE_WINDOW_SIZE }>(
+                    self.generator(),
+                    NUM_WINDOWS,
+                )
+            }
+        }
+
+        struct MyCircuit;
+
+        impl Circuit<vesta::Base> for MyCircuit {
+            type Config = EccConfig<vesta::Affine, { FIXED_BASE_WINDOW_SIZE }>;
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                MyCircuit
+            }
+
+            fn configure(meta: &mut ConstraintSystem<vesta::Base>) -> Self::Config {
+                let advices = [
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                    meta.advice_column(),
+                ];
+                let lookup_table = meta.lookup_table_column();
+                let lagrange_coeffs = [
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                    meta.fixed_column(),
+                ];
+                // Shared fixed column for loading constants
+                let constants = meta.fixed_column();
+                meta.enable_constant(constants);
+
+                let range_check = LookupRangeCheckConfig::configure(meta, advices[9], lookup_table);
+                EccChip::<vesta::Affine, VestaFixedBase, { FIXED_BASE_WINDOW_SIZE }>::configure(
+                    meta,
+                    advices,
+                    &lagrange_coeffs,
+                    range_check,
+                )
+            }
+
+            fn synthesize(
+                &self,
+                config: Self::Config,
+                mut layouter: impl Layouter<vesta::Base>,
+            ) -> Result<(), Error> {
+                let chip =
+                    EccChip::<vesta::Affine, VestaFixedBase, { FIXED_BASE_WINDOW_SIZE }>::construct(
+                        config.clone(),
+                    );
+                config.lookup_config.load(&mut layouter)?;
+
+                let p_val = vesta::Point::random(rand::rngs::OsRng).to_affine();
+                let q_val = vesta::Point::random(rand::rngs::OsRng).to_affine();
+                let p =
+                    NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "P"), Some(p_val))?;
+                let q = Point::new(chip.clone(), layouter.namespace(|| "Q"), Some(q_val))?;
+
+                // P + Q
+                {
+                    let result = p.add(layouter.namespace(|| "P + Q"), &q)?;
+                    let expected = Point::new(
+                        chip.clone(),
+                        layouter.namespace(|| "expected P + Q"),
+                        Some((p_val + q_val).to_affine()),
+                    )?;
+                    result.constrain_equal(layouter.namespace(|| "constrain P + Q"), &expected)?;
+                }
+
+                // [a]P, where a is a base field element
+                {
+                    let scalar_val = vesta::Base::rand();
+                    let scalar = chip.load_private(
+                        layouter.namespace(|| "scalar"),
+                        config.advices[0],
+                        Some(scalar_val),
+                    )?;
+                    let (result, _) = p.mul(layouter.namespace(|| "[a]P"), &scalar)?;
+                    let expected = Point::new(
+                        chip.clone(),
+                        layouter.namespace(|| "expected [a]P"),
+                        Some(
+                            (p_val * crate::chip::base_to_scalar::<vesta::Affine>(scalar_val))
+                                .to_affine(),
+                        ),
+                    )?;
+                    result.constrain_equal(layouter.namespace(|| "constrain [a]P"), &expected)?;
+                }
+
+                // [b]B, where B is a fixed base
+                {
+                    let base = FixedPoint::from_inner(chip.clone(), VestaFixedBase);
+                    let scalar_val = vesta::Scalar::rand();
+                    let (result, _) = base.mul(layouter.namespace(|| "[b]B"), Some(scalar_val))?;
+                    let expected = Point::new(
+                        chip,
+                        layouter.namespace(|| "expected [b]B"),
+                        Some((*VESTA_BASE * scalar_val).to_affine()),
+                    )?;
+                    result.constrain_equal(layouter.namespace(|| "constrain [b]B"), &expected)?;
+                }
+
+                Ok(())
+            }
+        }
+
+        let prover = MockProver::run(12, &MyCircuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
     #[cfg(feature = "dev-graph")]
     #[test]
     fn print_ecc_chip() {
@@ -622,9 +1484,11 @@ mod tests {
         root.fill(&WHITE).unwrap();
         let root = root.titled("Ecc Chip Layout", ("sans-serif", 60)).unwrap();
 
-        let circuit = super::testing::MyCircuit::<Test, FixedBase>(std::marker::PhantomData);
+        let circuit = super::testing::MyCircuit::<Test, FixedBase, { FIXED_BASE_WINDOW_SIZE }>(
+            std::marker::PhantomData,
+        );
         halo2::dev::CircuitLayout::default()
-            .render(13, &circuit, &root)
+            .render(14, &circuit, &root)
             .unwrap();
     }
 }
@@ -0,0 +1,258 @@
+//! Gadgets for Pedersen commitments over fixed bases.
+//!
+//! See [`crate::primitive::commitment`] for the native commitments.
+
+use std::array;
+
+use halo2::{circuit::Layouter, plonk::Error};
+use pasta_curves::arithmetic::{CurveAffine, FieldExt};
+
+use super::{EccInstructions, FixedPoint, Point};
+use crate::chip::L_VALUE;
+
+/// The scalar of one term of a Pedersen commitment in the circuit.
+#[derive(Clone, Debug)]
+pub enum CommitmentScalar<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    /// A signed short scalar, given as `(magnitude, sign)` cells, where the
+    /// magnitude is in $[0, 2^{64})$ and the sign is $\pm 1$.
+    Short(EccChip::Var, EccChip::Var),
+    /// A full-width scalar, which is witnessed by the fixed-base scalar
+    /// multiplication.
+    Full(Option<C::Scalar>),
+}
+
+/// A Pedersen commitment $\sum_i [s_i] B_i$ over `N` fixed bases $B_i$.
+///
+/// Terms with a short scalar use fixed-base scalar multiplication by a short
+/// signed scalar, and so require their base to provide windows for short scalars.
+#[derive(Clone, Debug)]
+pub struct PedersenCommitment<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    const N: usize,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    bases: [FixedPoint<C, EccChip, F>; N],
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>, const N: usize>
+    PedersenCommitment<C, EccChip, N, F>
+{
+    /// Constructs a Pedersen commitment over the given bases.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    pub fn new(bases: [FixedPoint<C, EccChip, F>; N]) -> Self {
+        assert!(N > 0);
+        PedersenCommitment { bases }
+    }
+
+    /// Returns the commitment to `scalars`.
+    pub fn commit(
+        &self,
+        mut layouter: impl Layouter<F>,
+        scalars: [CommitmentScalar<C, EccChip, F>; N],
+    ) -> Result<Point<C, EccChip, F>, Error> {
+        let mut commitment: Option<Point<C, EccChip, F>> = None;
+
+        for (i, (base, scalar)) in self
+            .bases
+            .iter()
+            .zip(array::IntoIter::new(scalars))
+            .enumerate()
+        {
+            // [s_i] B_i
+            let term = match scalar {
+                CommitmentScalar::Short(magnitude, sign) => {
+                    base.mul_short::<{ L_VALUE }>(
+                        layouter.namespace(|| format!("[s_{}] B_{}", i, i)),
+                        (magnitude, sign),
+                    )?
+                    .0
+                }
+                CommitmentScalar::Full(scalar) => {
+                    base.mul(layouter.namespace(|| format!("[s_{}] B_{}", i, i)), scalar)?
+                        .0
+                }
+            };
+
+            commitment = Some(match commitment {
+                None => term,
+                Some(commitment) => commitment.add(
+                    layouter.namespace(|| format!("add [s_{}] B_{}", i, i)),
+                    &term,
+                )?,
+            });
+        }
+
+        Ok(commitment.unwrap())
+    }
+}
+
+/// The value commitment $[v] V + [\mathsf{rcv}] R$, for a signed 64-bit value $v$
+/// and a full-width randomness $\mathsf{rcv}$.
+#[derive(Clone, Debug)]
+pub struct ValueCommit<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    inner: PedersenCommitment<C, EccChip, 2, F>,
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> ValueCommit<C, EccChip, F> {
+    /// Constructs the value commitment with value base `v_base`, which must provide
+    /// windows for short scalars, and randomness base `r_base`.
+    pub fn new(v_base: FixedPoint<C, EccChip, F>, r_base: FixedPoint<C, EccChip, F>) -> Self {
+        ValueCommit {
+            inner: PedersenCommitment::new([v_base, r_base]),
+        }
+    }
+
+    /// Returns the commitment to the value given by its `(magnitude, sign)` cells,
+    /// with randomness `rcv`.
+    pub fn commit(
+        &self,
+        layouter: impl Layouter<F>,
+        magnitude_sign: (EccChip::Var, EccChip::Var),
+        rcv: Option<C::Scalar>,
+    ) -> Result<Point<C, EccChip, F>, Error> {
+        let (magnitude, sign) = magnitude_sign;
+        self.inner.commit(
+            layouter,
+            [
+                CommitmentScalar::Short(magnitude, sign),
+                CommitmentScalar::Full(rcv),
+            ],
+        )
+    }
+}
+
+#[cfg(feature = "testing")]
+pub mod tests {
+    use group::Curve;
+    use halo2::{
+        circuit::{Chip, Layouter},
+        plonk::Error,
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    use super::{CommitmentScalar, PedersenCommitment, ValueCommit};
+    use crate::{
+        chip::EccChip,
+        gadget::{FixedPoint, FixedPoints, Point},
+        primitive::commitment::{self as primitive, pedersen_commitment, value_commit},
+    };
+    use utilities::{CellValue, UtilitiesInstructions};
+
+    pub fn test_commitment<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+        short_base: F,
+        full_base: F,
+        chip: EccChip<pallas::Affine, F, K>,
+        mut layouter: impl Layouter<pallas::Base>,
+    ) -> Result<(), Error> {
+        let v_base = FixedPoint::from_inner(chip.clone(), short_base.clone());
+        let r_base = FixedPoint::from_inner(chip.clone(), full_base.clone());
+        let (v_base_val, r_base_val) = (short_base.generator(), full_base.generator());
+
+        // Loads the `(magnitude, sign)` cells of a short scalar.
+        fn magnitude_sign<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: &EccChip<pallas::Affine, F, K>,
+            mut layouter: impl Layouter<pallas::Base>,
+            magnitude: u64,
+            negative: bool,
+        ) -> Result<(CellValue<pallas::Base>, CellValue<pallas::Base>), Error> {
+            let column = chip.config().advices[0];
+            let magnitude = chip.load_private(
+                layouter.namespace(|| "magnitude"),
+                column,
+                Some(pallas::Base::from_u64(magnitude)),
+            )?;
+            let sign = if negative {
+                -pallas::Base::one()
+            } else {
+                pallas::Base::one()
+            };
+            let sign = chip.load_private(layouter.namespace(|| "sign"), column, Some(sign))?;
+            Ok((magnitude, sign))
+        }
+
+        // Constrains `result` to equal the witnessed `expected` point.
+        fn constrain_equal<F: FixedPoints<pallas::Affine, K>, const K: usize>(
+            chip: &EccChip<pallas::Affine, F, K>,
+            mut layouter: impl Layouter<pallas::Base>,
+            result: Point<pallas::Affine, EccChip<pallas::Affine, F, K>>,
+            expected: pallas::Point,
+        ) -> Result<(), Error> {
+            let expected = Point::new(
+                chip.clone(),
+                layouter.namespace(|| "expected"),
+                Some(expected.to_affine()),
+            )?;
+            result.constrain_equal(layouter.namespace(|| "constrain result"), &expected)
+        }
+
+        // Value commitments to positive, negative and extreme values
+        {
+            let value_commit_gadget = ValueCommit::new(v_base.clone(), r_base.clone());
+            for (magnitude, negative) in [
+                (rand::random::<u64>(), false),
+                (rand::random::<u64>(), true),
+                (0, true),
+                (u64::MAX, true),
+            ]
+            .iter()
+            {
+                let rcv = pallas::Scalar::rand();
+                let value =
+                    magnitude_sign(&chip, layouter.namespace(|| "value"), *magnitude, *negative)?;
+                let cv =
+                    value_commit_gadget.commit(layouter.namespace(|| "cv"), value, Some(rcv))?;
+                constrain_equal(
+                    &chip,
+                    layouter.namespace(|| "cv"),
+                    cv,
+                    value_commit(v_base_val, r_base_val, *magnitude, *negative, rcv),
+                )?;
+            }
+        }
+
+        // A commitment over three bases with mixed scalars
+        {
+            let magnitude = rand::random::<u64>();
+            let scalars = [
+                primitive::CommitmentScalar::Short {
+                    magnitude,
+                    negative: true,
+                },
+                primitive::CommitmentScalar::Full(pallas::Scalar::rand()),
+                primitive::CommitmentScalar::Full(-pallas::Scalar::one()),
+            ];
+            let expected = pedersen_commitment(&[v_base_val, r_base_val, r_base_val], &scalars);
+
+            let (magnitude, sign) =
+                magnitude_sign(&chip, layouter.namespace(|| "s_0"), magnitude, true)?;
+            let commitment = PedersenCommitment::new([v_base, r_base.clone(), r_base]).commit(
+                layouter.namespace(|| "commitment"),
+                [
+                    CommitmentScalar::Short(magnitude, sign),
+                    CommitmentScalar::Full(Some(scalars[1].value())),
+                    CommitmentScalar::Full(Some(scalars[2].value())),
+                ],
+            )?;
+            constrain_equal(
+                &chip,
+                layouter.namespace(|| "commitment"),
+                commitment,
+                expected,
+            )?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,313 @@
+//! Gadget for verifying ECDSA signatures.
+//!
+//! See [`crate::primitive::ecdsa`] for the native scheme.
+
+use std::fmt::Debug;
+
+use halo2::{circuit::Layouter, plonk::Error};
+use pasta_curves::arithmetic::{CurveAffine, FieldExt};
+
+use super::{EccInstructions, FixedPoint, NonIdentityPoint};
+use crate::primitive::ecdsa as primitive;
+
+/// The set of circuit instructions required to verify ECDSA signatures, in
+/// addition to the [`EccInstructions`].
+pub trait EcdsaInstructions<C: CurveAffine, F: FieldExt = <C as CurveAffine>::Base>:
+    EccInstructions<C, F>
+{
+    /// Variable representing an element of the elliptic curve's scalar field,
+    /// which supports arithmetic modulo the order of the curve.
+    type Scalar: Clone + Debug;
+
+    /// Witnesses the given scalar, constraining it to be canonical.
+    fn witness_scalar(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<C::Scalar>,
+    ) -> Result<Self::Scalar, Error>;
+
+    /// Returns `a * b` modulo the order of the curve.
+    fn scalar_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+        b: &Self::Scalar,
+    ) -> Result<Self::Scalar, Error>;
+
+    /// Returns the inverse of `a` modulo the order of the curve.
+    ///
+    /// The constraints cannot be satisfied if `a` is zero.
+    fn scalar_invert(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+    ) -> Result<Self::Scalar, Error>;
+
+    /// Returns `[a] g + [b] p` for the fixed base `g`.
+    ///
+    /// This may share work between the two scalar multiplications.
+    fn mul_fixed_and_add_mul(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        a: &Self::Scalar,
+        g: &Self::FixedPoints,
+        b: &Self::Scalar,
+        p: &Self::NonIdentityPoint,
+    ) -> Result<Self::Point, Error>;
+
+    /// Constrains the x-coordinate of `point`, reduced modulo the order of the
+    /// curve, to be equal to `scalar`.
+    ///
+    /// The identity has x-coordinate zero in this representation.
+    fn constrain_x_to_scalar(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        point: &Self::Point,
+        scalar: &Self::Scalar,
+    ) -> Result<(), Error>;
+}
+
+/// An ECDSA signature $(r, s)$ witnessed in the circuit, with both components
+/// constrained to be canonical.
+#[derive(Debug)]
+pub struct Signature<
+    C: CurveAffine,
+    EccChip: EcdsaInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    chip: EccChip,
+    r: EccChip::Scalar,
+    s: EccChip::Scalar,
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EcdsaInstructions<C, F>> Signature<C, EccChip, F> {
+    /// Witnesses the given signature.
+    pub fn new(
+        chip: EccChip,
+        mut layouter: impl Layouter<F>,
+        value: Option<primitive::Signature<C>>,
+    ) -> Result<Self, Error> {
+        let r = chip.witness_scalar(
+            &mut layouter.namespace(|| "r"),
+            value.map(|signature| signature.r()),
+        )?;
+        let s = chip.witness_scalar(
+            &mut layouter.namespace(|| "s"),
+            value.map(|signature| signature.s()),
+        )?;
+        Ok(Signature { chip, r, s })
+    }
+
+    /// Returns $r$.
+    pub fn r(&self) -> &EccChip::Scalar {
+        &self.r
+    }
+
+    /// Returns $s$.
+    pub fn s(&self) -> &EccChip::Scalar {
+        &self.s
+    }
+}
+
+/// Constrains `signature` to be a valid signature on the message hash `z` for the
+/// public key `pk`, where $G$ is `generator`.
+///
+/// The caller is responsible for constraining `z`, for example to the output of
+/// an in-circuit hash of the message, or to a public input.
+pub fn verify_signature<C: CurveAffine, F: FieldExt, EccChip: EcdsaInstructions<C, F>>(
+    mut layouter: impl Layouter<F>,
+    generator: &FixedPoint<C, EccChip, F>,
+    pk: &NonIdentityPoint<C, EccChip, F>,
+    signature: &Signature<C, EccChip, F>,
+    z: &EccChip::Scalar,
+) -> Result<(), Error> {
+    let chip = &signature.chip;
+
+    // r ≠ 0. As the identity has x-coordinate zero, this also ensures that R ≠ O
+    // when x(R) is compared with r below.
+    chip.scalar_invert(&mut layouter.namespace(|| "r^{-1}"), &signature.r)?;
+
+    // w = s^{-1}, which constrains s ≠ 0.
+    let w = chip.scalar_invert(&mut layouter.namespace(|| "s^{-1}"), &signature.s)?;
+
+    // u_1 = z⋅w, u_2 = r⋅w
+    let u_1 = chip.scalar_mul(&mut layouter.namespace(|| "z⋅w"), z, &w)?;
+    let u_2 = chip.scalar_mul(&mut layouter.namespace(|| "r⋅w"), &signature.r, &w)?;
+
+    // R = [u_1] G + [u_2] pk
+    let r = chip.mul_fixed_and_add_mul(
+        &mut layouter.namespace(|| "[u_1] G + [u_2] pk"),
+        &u_1,
+        &generator.inner,
+        &u_2,
+        pk.inner(),
+    )?;
+
+    // x(R) = r (mod n)
+    chip.constrain_x_to_scalar(&mut layouter.namespace(|| "x(R) = r"), &r, &signature.r)
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::Field;
+    use group::prime::PrimeCurveAffine;
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::pallas;
+
+    use super::{verify_signature, EcdsaInstructions, Signature};
+    use crate::{
+        chip::{test_circuit::configure_foreign_ecc_chip, ForeignEccChip, ForeignEccConfig},
+        gadget::{FixedPoint, NonIdentityPoint},
+        primitive::{
+            ecdsa::{self as primitive, public_key, test_vectors},
+            secp256k1,
+        },
+    };
+
+    type MyChip = ForeignEccChip<pallas::Base, secp256k1::Affine>;
+
+    // Verifies secp256k1 signatures in a circuit over the Pallas base field.
+    #[derive(Default)]
+    struct MyCircuit {
+        pk: Option<secp256k1::Affine>,
+        signature: Option<primitive::Signature<secp256k1::Affine>>,
+        z: Option<secp256k1::Scalar>,
+    }
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = ForeignEccConfig<pallas::Base>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            configure_foreign_ecc_chip::<secp256k1::Affine>(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = MyChip::construct(config.clone());
+            config.non_native.range_check.load(&mut layouter)?;
+
+            let generator = FixedPoint::from_inner(chip.clone(), secp256k1::Affine::generator());
+            let pk = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "pk"), self.pk)?;
+            let signature = Signature::new(
+                chip.clone(),
+                layouter.namespace(|| "signature"),
+                self.signature,
+            )?;
+            let z = chip.witness_scalar(&mut layouter.namespace(|| "z"), self.z)?;
+
+            verify_signature(
+                layouter.namespace(|| "verify"),
+                &generator,
+                &pk,
+                &signature,
+                &z,
+            )
+        }
+    }
+
+    /// Returns `(pk, signature, z, valid)` for the published signatures and for a set
+    /// of signatures that must be rejected.
+    #[allow(clippy::type_complexity)]
+    fn cases() -> Vec<(
+        secp256k1::Affine,
+        primitive::Signature<secp256k1::Affine>,
+        secp256k1::Scalar,
+        bool,
+    )> {
+        let g = secp256k1::Affine::generator();
+        let vectors = test_vectors::secp256k1();
+        let mut cases = vec![];
+
+        for tv in vectors.iter() {
+            let pk = public_key(g, tv.sk);
+
+            // Published signature
+            cases.push((pk, primitive::Signature::from_parts(tv.r, tv.s), tv.z, true));
+
+            // The malleated, high-s signature (r, n - s) is accepted too.
+            cases.push((
+                pk,
+                primitive::Signature::from_parts(tv.r, -tv.s),
+                tv.z,
+                true,
+            ));
+        }
+
+        let tv = &vectors[0];
+        let pk = public_key(g, tv.sk);
+        let signature = primitive::Signature::from_parts(tv.r, tv.s);
+
+        // Wrong message hash
+        cases.push((pk, signature, vectors[1].z, false));
+
+        // Tampered s
+        cases.push((
+            pk,
+            primitive::Signature::from_parts(tv.r, tv.s + secp256k1::Scalar::one()),
+            tv.z,
+            false,
+        ));
+
+        // Wrong public key
+        cases.push((public_key(g, vectors[1].sk), signature, tv.z, false));
+
+        // r = 0 and z = 0, for which R is the identity.
+        cases.push((
+            pk,
+            primitive::Signature::from_parts(secp256k1::Scalar::zero(), tv.s),
+            secp256k1::Scalar::zero(),
+            false,
+        ));
+
+        cases
+    }
+
+    fn check_in_circuit(
+        pk: secp256k1::Affine,
+        signature: primitive::Signature<secp256k1::Affine>,
+        z: secp256k1::Scalar,
+        valid: bool,
+    ) {
+        let circuit = MyCircuit {
+            pk: Some(pk),
+            signature: Some(signature),
+            z: Some(z),
+        };
+        let prover = MockProver::<pallas::Base>::run(21, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify().is_ok(), valid);
+    }
+
+    #[test]
+    fn verify_ecdsa_signature() {
+        let g = secp256k1::Affine::generator();
+        let cases = cases();
+
+        // The circuit is large, so only the first published signature is checked in
+        // the circuit by default; the others are checked natively.
+        for (pk, signature, z, valid) in cases.iter() {
+            assert_eq!(primitive::verify(g, *pk, signature, *z), *valid);
+        }
+        let (pk, signature, z, valid) = cases[0];
+        check_in_circuit(pk, signature, z, valid);
+    }
+
+    #[test]
+    #[ignore]
+    fn verify_ecdsa_signature_all_cases() {
+        for (pk, signature, z, valid) in cases() {
+            check_in_circuit(pk, signature, z, valid);
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Gadgets for hashing to a curve.
+//!
+//! A message is hashed to a point in two steps: hash-to-field, which expands the
+//! message and reduces it to two base field elements $u_0, u_1$; and map-to-curve,
+//! which maps each $u_i$ to a point $Q_i$, so that the hash is $Q_0 + Q_1$.
+//!
+//! The hash-to-field step is pluggable via [`HashToField`], so that it can be
+//! provided by any gadget whose output is a pair of base field elements, such as
+//! [`crate::chip::PoseidonHashToField`]. See [`crate::primitive::hash_to_curve`]
+//! for the native map.
+
+use std::{fmt::Debug, marker::PhantomData};
+
+use halo2::{
+    circuit::{Chip, Layouter},
+    plonk::Error,
+};
+use pasta_curves::arithmetic::CurveAffine;
+
+use super::{EccInstructions, NonIdentityPoint, Point};
+
+/// The set of circuit instructions required to map base field elements to curve
+/// points.
+pub trait MapToCurveInstructions<C: CurveAffine, EccChip: EccInstructions<C>>:
+    Chip<C::Base> + Clone + Debug
+{
+    /// Maps `u` to a point on the curve.
+    ///
+    /// The result is the identity only for inputs in the preimage of the kernel
+    /// of the isogeny, for which the constraints cannot be satisfied.
+    fn map_to_curve(
+        &self,
+        layouter: &mut impl Layouter<C::Base>,
+        u: &EccChip::Var,
+    ) -> Result<EccChip::NonIdentityPoint, Error>;
+}
+
+/// The hash-to-field step of hashing to a curve, which hashes a message to two
+/// base field elements.
+///
+/// The output must be indistinguishable from two uniformly random field elements
+/// for the hash to be indistinguishable from a random oracle.
+pub trait HashToField<C: CurveAffine, Var> {
+    /// The message being hashed.
+    type Message;
+
+    /// Hashes `message` to $u_0, u_1$.
+    fn hash_to_field(
+        &self,
+        layouter: impl Layouter<C::Base>,
+        message: Self::Message,
+    ) -> Result<[Var; 2], Error>;
+}
+
+/// Maps `u` to a point on the curve.
+pub fn map_to_curve<C, EccChip, MapChip>(
+    ecc_chip: EccChip,
+    map_chip: &MapChip,
+    mut layouter: impl Layouter<C::Base>,
+    u: &EccChip::Var,
+) -> Result<NonIdentityPoint<C, EccChip>, Error>
+where
+    C: CurveAffine,
+    EccChip: EccInstructions<C>,
+    MapChip: MapToCurveInstructions<C, EccChip>,
+{
+    map_chip
+        .map_to_curve(&mut layouter, u)
+        .map(|point| NonIdentityPoint::from_inner(ecc_chip, point))
+}
+
+/// A hash to the curve, composed of a [`HashToField`] step and the map to the curve.
+#[derive(Clone, Debug)]
+pub struct HashToCurve<C, EccChip, MapChip, H>
+where
+    C: CurveAffine,
+    EccChip: EccInstructions<C>,
+    MapChip: MapToCurveInstructions<C, EccChip>,
+    H: HashToField<C, EccChip::Var>,
+{
+    ecc_chip: EccChip,
+    map_chip: MapChip,
+    hash_to_field: H,
+    _marker: PhantomData<C>,
+}
+
+impl<C, EccChip, MapChip, H> HashToCurve<C, EccChip, MapChip, H>
+where
+    C: CurveAffine,
+    EccChip: EccInstructions<C>,
+    MapChip: MapToCurveInstructions<C, EccChip>,
+    H: HashToField<C, EccChip::Var>,
+{
+    /// Constructs a hash to the curve with the given hash-to-field step.
+    pub fn new(ecc_chip: EccChip, map_chip: MapChip, hash_to_field: H) -> Self {
+        HashToCurve {
+            ecc_chip,
+            map_chip,
+            hash_to_field,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Hashes `message` to a point on the curve.
+    pub fn hash(
+        &self,
+        mut layouter: impl Layouter<C::Base>,
+        message: H::Message,
+    ) -> Result<Point<C, EccChip>, Error> {
+        let [u_0, u_1] = self
+            .hash_to_field
+            .hash_to_field(layouter.namespace(|| "hash to field"), message)?;
+
+        let q_0 = map_to_curve(
+            self.ecc_chip.clone(),
+            &self.map_chip,
+            layouter.namespace(|| "map u_0 to curve"),
+            &u_0,
+        )?;
+        let q_1 = map_to_curve(
+            self.ecc_chip.clone(),
+            &self.map_chip,
+            layouter.namespace(|| "map u_1 to curve"),
+            &u_1,
+        )?;
+
+        // Q_0 + Q_1, which is the identity if Q_0 = -Q_1.
+        q_0.add(layouter.namespace(|| "Q_0 + Q_1"), &q_1.into())
+    }
+}
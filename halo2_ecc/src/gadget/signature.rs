@@ -0,0 +1,423 @@
+//! Gadget for verifying Schnorr signatures in the style of RedPallas.
+//!
+//! See [`crate::primitive::signature`] for the native scheme, and for how the
+//! challenge is interpreted as a scalar.
+//!
+//! The challenge is computed in the circuit from $R$, $\mathsf{vk}$ and the message
+//! by a pluggable [`ChallengeHash`], such as [`crate::chip::PoseidonChallenge`].
+
+use ff::PrimeFieldBits;
+use halo2::{
+    circuit::Layouter,
+    plonk::{Advice, Column, Error},
+};
+use pasta_curves::arithmetic::{CurveAffine, FieldExt};
+
+use super::{EccInstructions, FixedPoint, NonIdentityPoint, Point, ScalarFixed};
+use crate::primitive::signature as primitive;
+use utilities::decompose_word;
+
+/// The hash that computes the challenge $c$ of a signature from $R$, the
+/// verification key $\mathsf{vk}$, and the message.
+///
+/// The native counterpart of the hash is given to [`primitive::sign`] and
+/// [`primitive::verify`].
+pub trait ChallengeHash<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+>
+{
+    /// The message being signed.
+    type Message;
+
+    /// Hashes $R$, $\mathsf{vk}$ and `message` to the challenge $c$.
+    fn challenge(
+        &self,
+        layouter: impl Layouter<F>,
+        r: &Point<C, EccChip, F>,
+        vk: &NonIdentityPoint<C, EccChip, F>,
+        message: Self::Message,
+    ) -> Result<EccChip::Var, Error>;
+}
+
+/// A Schnorr signature $(R, s)$ witnessed in the circuit.
+///
+/// $s$ is held as its little-endian decomposition into the chip's fixed-base
+/// windows, which is constrained to be canonical when the signature is verified.
+#[derive(Debug)]
+pub struct Signature<
+    C: CurveAffine,
+    EccChip: EccInstructions<C, F>,
+    F: FieldExt = <C as CurveAffine>::Base,
+> {
+    r: Point<C, EccChip, F>,
+    s: Vec<EccChip::Var>,
+}
+
+impl<C: CurveAffine, F: FieldExt, EccChip: EccInstructions<C, F>> Signature<C, EccChip, F> {
+    /// Witnesses $R$ for the given signature, and the `K`-bit windows of $s$ in
+    /// `column`.
+    ///
+    /// `K` must be the window size that `chip` uses for fixed-base scalar mul.
+    pub fn new<const K: usize>(
+        chip: EccChip,
+        mut layouter: impl Layouter<F>,
+        column: Column<Advice>,
+        value: Option<primitive::Signature<C>>,
+    ) -> Result<Self, Error>
+    where
+        C::Scalar: PrimeFieldBits,
+    {
+        let r = Point::new(
+            chip.clone(),
+            layouter.namespace(|| "R"),
+            value.map(|signature| signature.r()),
+        )?;
+
+        let num_bits = C::Scalar::NUM_BITS as usize;
+        let windows: Vec<Option<u8>> = match value {
+            Some(signature) => decompose_word(signature.s(), num_bits, K)
+                .into_iter()
+                .map(Some)
+                .collect(),
+            None => vec![None; (num_bits + K - 1) / K],
+        };
+        let s = windows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, window)| {
+                chip.load_private(
+                    layouter.namespace(|| format!("s[{}]", idx)),
+                    column,
+                    window.map(|window| F::from_u64(window as u64)),
+                )
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+
+        Ok(Signature { r, s })
+    }
+
+    /// Constructs a signature from $R$ and the little-endian fixed-base windows of
+    /// $s$, which may already be in the circuit.
+    pub fn from_parts(r: Point<C, EccChip, F>, s: Vec<EccChip::Var>) -> Self {
+        Signature { r, s }
+    }
+
+    /// Returns $R$.
+    pub fn r(&self) -> &Point<C, EccChip, F> {
+        &self.r
+    }
+}
+
+/// Constrains `signature` to be valid on `message` for the verification key `vk`,
+/// i.e. $[s] G = R + [c] \mathsf{vk}$, where $G$ is `generator` and $c$ is computed
+/// from $R$, $\mathsf{vk}$ and `message` by `hash`.
+///
+/// $s$ is constrained to be canonical, so that a valid signature cannot be made
+/// into another valid signature by adding the scalar field modulus to $s$.
+///
+/// Returns the witnessed $s$.
+pub fn verify_signature<C, F, EccChip, H>(
+    mut layouter: impl Layouter<F>,
+    generator: &FixedPoint<C, EccChip, F>,
+    hash: &H,
+    vk: &NonIdentityPoint<C, EccChip, F>,
+    signature: &Signature<C, EccChip, F>,
+    message: H::Message,
+) -> Result<ScalarFixed<C, EccChip, F>, Error>
+where
+    C: CurveAffine,
+    F: FieldExt,
+    EccChip: EccInstructions<C, F>,
+    H: ChallengeHash<C, EccChip, F>,
+{
+    // c = H(R, vk, m)
+    let c = hash.challenge(
+        layouter.namespace(|| "c = H(R, vk, m)"),
+        &signature.r,
+        vk,
+        message,
+    )?;
+
+    // [s] G
+    let (s_g, s) = generator.mul_from_windows(layouter.namespace(|| "[s] G"), &signature.s)?;
+
+    // [c] vk
+    let (c_vk, _) = vk.mul(layouter.namespace(|| "[c] vk"), &c)?;
+
+    // R + [c] vk
+    let rhs = signature
+        .r
+        .add(layouter.namespace(|| "R + [c] vk"), &c_vk)?;
+
+    s_g.constrain_equal(layouter.namespace(|| "[s] G = R + [c] vk"), &rhs)?;
+
+    Ok(s)
+}
+
+#[cfg(test)]
+mod tests {
+    use ff::PrimeField;
+    use group::{Curve, Group};
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use lazy_static::lazy_static;
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+    use poseidon::{
+        pow5t3::{Pow5T3Chip, Pow5T3Config},
+        primitive::P128Pow5T3,
+    };
+
+    use super::{verify_signature, Signature};
+    use crate::{
+        chip::{
+            compute_lagrange_coeffs, find_zs_and_us_cached, test_circuit::configure_ecc_chip,
+            EccChip, EccConfig, PoseidonChallenge, VarBaseMul, NUM_WINDOWS,
+        },
+        gadget::{FixedPoint, FixedPoints, NonIdentityPoint, Point, FIXED_BASE_WINDOW_SIZE},
+        primitive::signature::{self as primitive, poseidon_challenge, sign, verification_key},
+    };
+    use utilities::{decompose_word, UtilitiesInstructions};
+
+    const K: usize = FIXED_BASE_WINDOW_SIZE;
+
+    lazy_static! {
+        static ref BASE: pallas::Affine = pallas::Point::generator().to_affine();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, K>(*BASE, NUM_WINDOWS).unwrap();
+    }
+
+    #[derive(Debug, Eq, PartialEq, Clone)]
+    struct FixedBase;
+
+    impl FixedPoints<pallas::Affine, K> for FixedBase {
+        fn generator(&self) -> pallas::Affine {
+            *BASE
+        }
+
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
+        }
+
+        fn z(&self) -> Vec<u64> {
+            ZS_AND_US.iter().map(|(z, _)| *z).collect()
+        }
+
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, K>(self.generator(), NUM_WINDOWS)
+        }
+    }
+
+    type MyEccChip = EccChip<pallas::Affine, FixedBase, K>;
+
+    #[derive(Default)]
+    struct MyCircuit {
+        vk: Option<pallas::Affine>,
+        signature: Option<primitive::Signature<pallas::Affine>>,
+        // If set, these K-bit windows are witnessed for s in place of its canonical
+        // decomposition.
+        s_windows: Option<Vec<u8>>,
+        message: Option<pallas::Base>,
+    }
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = (EccConfig<pallas::Affine, K>, Pow5T3Config<pallas::Base>);
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let ecc_config = configure_ecc_chip::<FixedBase, K>(meta, VarBaseMul::DoubleAndAdd);
+            let advices = ecc_config.advices;
+
+            let rc_a = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+            let rc_b = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+            let poseidon_config = Pow5T3Chip::configure(
+                meta,
+                P128Pow5T3,
+                [advices[6], advices[7], advices[8]],
+                advices[5],
+                rc_a,
+                rc_b,
+            );
+
+            (ecc_config, poseidon_config)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            let chip = MyEccChip::construct(config.0.clone());
+            config.0.lookup_config.load(&mut layouter)?;
+            let column = config.0.advices[0];
+
+            let generator = FixedPoint::from_inner(chip.clone(), FixedBase);
+            let hash = PoseidonChallenge::construct(config.1);
+
+            let vk = NonIdentityPoint::new(chip.clone(), layouter.namespace(|| "vk"), self.vk)?;
+            let signature = match &self.s_windows {
+                None => Signature::new::<K>(
+                    chip.clone(),
+                    layouter.namespace(|| "signature"),
+                    column,
+                    self.signature,
+                )?,
+                Some(s_windows) => {
+                    let r = Point::new(
+                        chip.clone(),
+                        layouter.namespace(|| "R"),
+                        self.signature.map(|signature| signature.r()),
+                    )?;
+                    let s = s_windows
+                        .iter()
+                        .enumerate()
+                        .map(|(idx, window)| {
+                            chip.load_private(
+                                layouter.namespace(|| format!("s[{}]", idx)),
+                                column,
+                                Some(pallas::Base::from_u64(*window as u64)),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    Signature::from_parts(r, s)
+                }
+            };
+            let message = chip.load_private(layouter.namespace(|| "m"), column, self.message)?;
+
+            verify_signature(
+                layouter.namespace(|| "verify"),
+                &generator,
+                &hash,
+                &vk,
+                &signature,
+                message,
+            )?;
+
+            Ok(())
+        }
+    }
+
+    /// Returns the `K`-bit windows of `s + q`, which is a non-canonical encoding of
+    /// `s` when `s + q < 2^255`.
+    fn non_canonical_windows(s: pallas::Scalar) -> Vec<u8> {
+        let q_minus_one = (-pallas::Scalar::one()).to_bytes();
+        let mut bytes = s.to_bytes();
+        let mut carry = 1u16;
+        for (byte, q_byte) in bytes.iter_mut().zip(q_minus_one.iter()) {
+            let sum = *byte as u16 + *q_byte as u16 + carry;
+            *byte = sum as u8;
+            carry = sum >> 8;
+        }
+        assert_eq!(carry, 0);
+        assert_eq!(bytes[31] >> 7, 0);
+
+        let bit = |i: usize| (bytes[i / 8] >> (i % 8)) & 1;
+        (0..NUM_WINDOWS)
+            .map(|window| {
+                (0..K)
+                    .map(|j| window * K + j)
+                    .filter(|i| *i < 256)
+                    .fold(0, |acc, i| acc + (bit(i) << (i - window * K)))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn verify_signature_with_poseidon_challenge() {
+        let g = *BASE;
+        let challenge = |m: pallas::Base| {
+            move |r: &pallas::Affine, vk: &pallas::Affine| poseidon_challenge(r, vk, m)
+        };
+
+        let sk = pallas::Scalar::rand();
+        let vk = verification_key(g, sk);
+        let m = pallas::Base::rand();
+
+        // Pick a signature whose s has a non-canonical encoding s + q < 2^255.
+        let signature = loop {
+            let signature = sign(g, sk, pallas::Scalar::rand(), challenge(m));
+            if signature.s().to_bytes()[31] < 0x40 {
+                break signature;
+            }
+        };
+
+        let check = |vk, signature, m| {
+            let circuit = MyCircuit {
+                vk: Some(vk),
+                signature: Some(signature),
+                s_windows: None,
+                message: Some(m),
+            };
+            let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
+            assert_eq!(
+                prover.verify().is_ok(),
+                primitive::verify(g, vk, &signature, challenge(m))
+            );
+        };
+
+        // Valid signature
+        check(vk, signature, m);
+
+        // Wrong message
+        check(vk, signature, m + pallas::Base::one());
+
+        // Wrong verification key
+        check(
+            verification_key(g, sk + pallas::Scalar::one()),
+            signature,
+            m,
+        );
+
+        // Tampered s
+        check(
+            vk,
+            primitive::Signature::from_parts(signature.r(), signature.s() + pallas::Scalar::one()),
+            m,
+        );
+
+        // Tampered R
+        check(
+            vk,
+            primitive::Signature::from_parts((signature.r() + g).to_affine(), signature.s()),
+            m,
+        );
+
+        // The canonical windows of s, given explicitly, are accepted.
+        let s_windows = |s_windows| {
+            let circuit = MyCircuit {
+                vk: Some(vk),
+                signature: Some(signature),
+                s_windows: Some(s_windows),
+                message: Some(m),
+            };
+            MockProver::<pallas::Base>::run(11, &circuit, vec![])
+                .unwrap()
+                .verify()
+                .is_ok()
+        };
+        assert!(s_windows(decompose_word(
+            signature.s(),
+            pallas::Scalar::NUM_BITS as usize,
+            K
+        )));
+
+        // s + q satisfies the verification equation, but is not canonical.
+        assert!(!s_windows(non_canonical_windows(signature.s())));
+    }
+}
@@ -1,3 +1,4 @@
 //! ECC gadget and chip
 pub mod chip;
 pub mod gadget;
+pub mod primitive;
@@ -0,0 +1,8 @@
+//! Native implementations of the schemes built from the ECC gadgets, and of the
+//! curves they are instantiated over.
+
+pub mod commitment;
+pub mod ecdsa;
+pub mod hash_to_curve;
+pub(crate) mod secp256k1;
+pub mod signature;
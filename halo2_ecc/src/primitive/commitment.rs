@@ -0,0 +1,121 @@
+//! Pedersen commitments over fixed bases.
+
+use group::Group;
+use pasta_curves::arithmetic::{CurveAffine, FieldExt};
+
+/// The scalar of one term of a Pedersen commitment.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommitmentScalar<C: CurveAffine> {
+    /// A signed short scalar $\pm m$, with magnitude $m \in [0, 2^{64})$.
+    Short { magnitude: u64, negative: bool },
+    /// A full-width scalar.
+    Full(C::Scalar),
+}
+
+impl<C: CurveAffine> CommitmentScalar<C> {
+    /// Returns the scalar as an element of the scalar field.
+    pub fn value(&self) -> C::Scalar {
+        match *self {
+            CommitmentScalar::Short {
+                magnitude,
+                negative,
+            } => {
+                let magnitude = C::ScalarExt::from_u64(magnitude);
+                if negative {
+                    -magnitude
+                } else {
+                    magnitude
+                }
+            }
+            CommitmentScalar::Full(scalar) => scalar,
+        }
+    }
+}
+
+/// Returns the Pedersen commitment $\sum_i [s_i] B_i$ to the scalars $s_i$ under
+/// the bases $B_i$.
+pub fn pedersen_commitment<C: CurveAffine, const N: usize>(
+    bases: &[C; N],
+    scalars: &[CommitmentScalar<C>; N],
+) -> C::Curve {
+    bases
+        .iter()
+        .zip(scalars.iter())
+        .fold(C::Curve::identity(), |acc, (base, scalar)| {
+            acc + *base * scalar.value()
+        })
+}
+
+/// Returns the value commitment $[v] V + [\mathsf{rcv}] R$, where $v = \pm m$ is the
+/// signed value with magnitude $m$.
+///
+/// Defined in [Zcash Protocol Spec § 5.4.8.3: Homomorphic Pedersen commitments (Sapling and Orchard)][concretehomomorphiccommit].
+///
+/// [concretehomomorphiccommit]: https://zips.z.cash/protocol/nu5.pdf#concretehomomorphiccommit
+pub fn value_commit<C: CurveAffine>(
+    v_base: C,
+    r_base: C,
+    magnitude: u64,
+    negative: bool,
+    rcv: C::Scalar,
+) -> C::Curve {
+    pedersen_commitment(
+        &[v_base, r_base],
+        &[
+            CommitmentScalar::Short {
+                magnitude,
+                negative,
+            },
+            CommitmentScalar::Full(rcv),
+        ],
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pedersen_commitment, value_commit, CommitmentScalar};
+
+    use group::{Curve, Group};
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    #[test]
+    fn value_commit_is_homomorphic() {
+        let v_base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let r_base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+
+        let (rcv_a, rcv_b) = (pallas::Scalar::rand(), pallas::Scalar::rand());
+        let a = value_commit(v_base, r_base, 10, false, rcv_a);
+        let b = value_commit(v_base, r_base, 3, true, rcv_b);
+
+        // cv(10, rcv_a) + cv(-3, rcv_b) = cv(7, rcv_a + rcv_b)
+        assert_eq!(a + b, value_commit(v_base, r_base, 7, false, rcv_a + rcv_b));
+    }
+
+    #[test]
+    fn short_scalar_value() {
+        let magnitude = u64::MAX;
+        let positive = CommitmentScalar::<pallas::Affine>::Short {
+            magnitude,
+            negative: false,
+        };
+        let negative = CommitmentScalar::<pallas::Affine>::Short {
+            magnitude,
+            negative: true,
+        };
+        assert_eq!(positive.value(), pallas::Scalar::from_u64(magnitude));
+        assert_eq!(negative.value(), -pallas::Scalar::from_u64(magnitude));
+
+        // A negative zero is zero.
+        let base = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        assert_eq!(
+            pedersen_commitment(
+                &[base],
+                &[CommitmentScalar::Short {
+                    magnitude: 0,
+                    negative: true
+                }]
+            ),
+            pallas::Point::identity()
+        );
+    }
+}
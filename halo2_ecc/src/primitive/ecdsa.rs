@@ -0,0 +1,163 @@
+//! ECDSA signatures.
+//!
+//! A signature $(r, s)$ on the message hash $z$ is valid for the public key
+//! $Q$ if $r, s \neq 0$ and $x(R) \equiv r \pmod{n}$, where
+//! $R = [z s^{-1}] G + [r s^{-1}] Q \neq \mathcal{O}$, $G$ is a fixed generator,
+//! and $n$ is the order of the curve.
+//!
+//! The message hash is given as a scalar, i.e. the caller is responsible for
+//! truncating the hash to the bit length of $n$ and reducing it. Signatures
+//! with a high $s$ are accepted; schemes that require a low $s$ (such as
+//! Ethereum transactions) must check that separately.
+
+use ff::Field;
+use group::Curve;
+use pasta_curves::arithmetic::CurveAffine;
+
+use crate::chip::base_to_scalar;
+
+#[cfg(test)]
+pub(crate) mod test_vectors;
+
+/// An ECDSA signature $(r, s)$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<C: CurveAffine> {
+    r: C::Scalar,
+    s: C::Scalar,
+}
+
+impl<C: CurveAffine> Signature<C> {
+    /// Constructs a signature from its components.
+    pub fn from_parts(r: C::Scalar, s: C::Scalar) -> Self {
+        Signature { r, s }
+    }
+
+    /// Returns $r$.
+    pub fn r(&self) -> C::Scalar {
+        self.r
+    }
+
+    /// Returns $s$.
+    pub fn s(&self) -> C::Scalar {
+        self.s
+    }
+}
+
+/// Returns the x-coordinate of `point`, reduced modulo $n$, or zero for the
+/// identity.
+pub fn x_to_scalar<C: CurveAffine>(point: &C) -> C::Scalar {
+    if *point == C::identity() {
+        C::Scalar::zero()
+    } else {
+        base_to_scalar::<C>(*point.coordinates().unwrap().x())
+    }
+}
+
+/// Returns the public key $[\mathsf{sk}] G$ for the secret key $\mathsf{sk}$.
+pub fn public_key<C: CurveAffine>(g: C, sk: C::Scalar) -> C {
+    (g * sk).to_affine()
+}
+
+/// Signs the message hash $z$ with the secret key $\mathsf{sk}$ and the nonce $k$,
+/// which must be uniformly random and never reused.
+///
+/// Returns `None` if $r$ or $s$ is zero, in which case a new nonce must be chosen.
+pub fn sign<C: CurveAffine>(
+    g: C,
+    sk: C::Scalar,
+    k: C::Scalar,
+    z: C::Scalar,
+) -> Option<Signature<C>> {
+    let r = x_to_scalar(&(g * k).to_affine());
+    let s = Option::<C::Scalar>::from(k.invert())? * (z + r * sk);
+
+    if r == C::Scalar::zero() || s == C::Scalar::zero() {
+        None
+    } else {
+        Some(Signature { r, s })
+    }
+}
+
+/// Checks that `signature` is a valid signature on the message hash $z$ for the
+/// public key `pk`.
+///
+/// The identity is rejected as a public key, as it is by the gadget.
+pub fn verify<C: CurveAffine>(g: C, pk: C, signature: &Signature<C>, z: C::Scalar) -> bool {
+    if bool::from(pk.is_identity()) || signature.r == C::Scalar::zero() {
+        return false;
+    }
+    let w = match Option::<C::Scalar>::from(signature.s.invert()) {
+        Some(w) => w,
+        None => return false,
+    };
+
+    let r = (g * (z * w) + pk * (signature.r * w)).to_affine();
+    !bool::from(r.is_identity()) && x_to_scalar(&r) == signature.r
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{public_key, sign, test_vectors, verify, Signature};
+
+    use group::prime::PrimeCurveAffine;
+    use pasta_curves::{arithmetic::FieldExt, vesta};
+
+    use crate::primitive::secp256k1;
+
+    #[test]
+    fn test_vectors() {
+        let g = secp256k1::Affine::generator();
+
+        for tv in test_vectors::secp256k1() {
+            let pk = public_key(g, tv.sk);
+            let published = Signature::from_parts(tv.r, tv.s);
+            assert!(verify(g, pk, &published, tv.z));
+
+            // The malleated signature (r, n - s) has a high s, and is also valid.
+            let malleated = Signature::from_parts(tv.r, -tv.s);
+            assert!(malleated.s() > published.s());
+            assert!(verify(g, pk, &malleated, tv.z));
+
+            // `sign` does not normalize s, so it returns either of the two.
+            let signature = sign(g, tv.sk, tv.k, tv.z).unwrap();
+            assert!(signature == published || signature == malleated);
+        }
+    }
+
+    #[test]
+    fn sign_and_verify() {
+        let g = vesta::Affine::generator();
+        let sk = vesta::Scalar::rand();
+        let pk = public_key(g, sk);
+        let z = vesta::Scalar::rand();
+
+        let signature = sign(g, sk, vesta::Scalar::rand(), z).unwrap();
+        assert!(verify(g, pk, &signature, z));
+
+        // Both s and -s verify.
+        let negated = Signature::from_parts(signature.r(), -signature.s());
+        assert!(verify(g, pk, &negated, z));
+
+        // Wrong message hash
+        assert!(!verify(g, pk, &signature, z + vesta::Scalar::one()));
+
+        // Wrong public key
+        let other_pk = public_key(g, sk + vesta::Scalar::one());
+        assert!(!verify(g, other_pk, &signature, z));
+
+        // Tampered signature
+        let tampered = Signature::from_parts(signature.r(), signature.s() + vesta::Scalar::one());
+        assert!(!verify(g, pk, &tampered, z));
+        let tampered = Signature::from_parts(signature.r() + vesta::Scalar::one(), signature.s());
+        assert!(!verify(g, pk, &tampered, z));
+
+        // Zero components
+        let zero = Signature::from_parts(vesta::Scalar::zero(), signature.s());
+        assert!(!verify(g, pk, &zero, z));
+        let zero = Signature::from_parts(signature.r(), vesta::Scalar::zero());
+        assert!(!verify(g, pk, &zero, z));
+
+        // Identity public key
+        assert!(!verify(g, vesta::Affine::identity(), &signature, z));
+    }
+}
@@ -0,0 +1,87 @@
+//! Published secp256k1 ECDSA signatures.
+
+use ff::Field;
+
+use crate::primitive::secp256k1;
+
+pub(crate) struct TestVector {
+    /// The secret key.
+    pub(crate) sk: secp256k1::Scalar,
+    /// The RFC 6979 nonce for `sk` and `z`.
+    pub(crate) k: secp256k1::Scalar,
+    /// The message hash.
+    pub(crate) z: secp256k1::Scalar,
+    /// The published signature, which has a low $s$.
+    pub(crate) r: secp256k1::Scalar,
+    pub(crate) s: secp256k1::Scalar,
+}
+
+pub(crate) fn secp256k1() -> Vec<TestVector> {
+    vec![
+        // The example transaction of EIP-155 (https://eips.ethereum.org/EIPS/eip-155),
+        // signed with the secret key 0x4646...46. `z` is its signing hash.
+        TestVector {
+            sk: secp256k1::Scalar::from_raw([
+                0x4646_4646_4646_4646,
+                0x4646_4646_4646_4646,
+                0x4646_4646_4646_4646,
+                0x4646_4646_4646_4646,
+            ]),
+            k: secp256k1::Scalar::from_raw([
+                0x8414_c5ba_d5cd_3e56,
+                0xb93a_740b_2d1e_1229,
+                0x5cc6_0050_1d77_7cbd,
+                0x4944_e270_76bf_36f6,
+            ]),
+            z: secp256k1::Scalar::from_raw([
+                0x1ad2_3db9_2e4c_8e53,
+                0xc7ef_83ea_dac0_f279,
+                0x2197_303d_7b57_4746,
+                0xdaf5_a779_ae97_2f97,
+            ]),
+            r: secp256k1::Scalar::from_raw([
+                0xe159_0620_aa63_6276,
+                0x03e1_a15d_3c71_ff63,
+                0x2195_fe53_7567_8660,
+                0x28ef_6134_0bd9_39bc,
+            ]),
+            s: secp256k1::Scalar::from_raw([
+                0x297f_b196_6a3b_6d83,
+                0xf555_c9f3_dc64_214b,
+                0xecb7_0330_4b38_00cc,
+                0x67cb_e9d8_997f_761a,
+            ]),
+        },
+        // SHA-256("Satoshi Nakamoto") signed with the secret key 1, a common RFC 6979
+        // test vector (for example in the ECDSA fixtures of bitcoinjs-lib). The
+        // published signature has been normalized to a low s; the nonce gives the
+        // high one.
+        TestVector {
+            sk: secp256k1::Scalar::one(),
+            k: secp256k1::Scalar::from_raw([
+                0xee69_063b_74f1_5d15,
+                0x24f5_f525_4277_2691,
+                0x56b2_8062_1e35_8cce,
+                0x8f8a_276c_19f4_1496,
+            ]),
+            z: secp256k1::Scalar::from_raw([
+                0x5425_f733_7704_883e,
+                0x2650_5daa_aed3_8515,
+                0xcbea_0ac2_7401_5b95,
+                0xa0dc_65ff_ca79_9873,
+            ]),
+            r: secp256k1::Scalar::from_raw([
+                0x60d7_a6ab_210e_e3d8,
+                0x3ce3_c9a7_e6a4_a498,
+                0x57e2_b0c0_17d0_b614,
+                0x934b_1ea1_0a4b_3c17,
+            ]),
+            s: secp256k1::Scalar::from_raw([
+                0x96f0_1a51_2aaf_d9e5,
+                0x6b49_743e_2ffa_1c44,
+                0x1080_1478_3e92_3ec3,
+                0x2442_ce9d_2b91_6064,
+            ]),
+        },
+    ]
+}
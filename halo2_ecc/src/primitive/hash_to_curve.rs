@@ -0,0 +1,275 @@
+//! Hashing to a curve with the simplified SWU map onto an isogenous curve.
+//!
+//! A message is hashed to a point in two steps. First, hash-to-field expands the
+//! message and reduces it to two base field elements $u_0, u_1$. Then each $u_i$ is
+//! mapped to a point $Q_i$, and the hash is $Q_0 + Q_1$.
+//!
+//! The map is the simplified SWU map onto the iso-curve $E'$, followed by the
+//! isogeny $E' \to E$, as specified in [RFC 9380 § 6.6.3][sswu-ab0]. This is the
+//! map used by `pallas::Point::hash_to_curve`; the hash-to-field step is left to
+//! the caller, so that it can be replaced by one that is cheap to compute in a
+//! circuit, such as [`poseidon_hash_to_field`].
+//!
+//! [sswu-ab0]: https://www.rfc-editor.org/rfc/rfc9380.html#name-simplified-swu-for-ab-0
+
+use pasta_curves::arithmetic::FieldExt;
+use poseidon::primitive::{ConstantLength, Hash, P128Pow5T3, Spec};
+
+use crate::chip::SwuCurve;
+
+/// Returns the parity of the canonical encoding of `f`, as defined by `sgn0` in
+/// [RFC 9380 § 4.1](https://www.rfc-editor.org/rfc/rfc9380.html#name-the-sgn0-function).
+pub(crate) fn sgn0<F: FieldExt>(f: F) -> bool {
+    f.to_bytes()[0] & 1 == 1
+}
+
+/// Maps `u` to a point $(x, y)$ on the iso-curve $E'$ with the simplified SWU map.
+///
+/// The sign of $y$ is chosen such that $\mathsf{sgn0}(y) = \mathsf{sgn0}(u)$.
+pub fn map_to_iso_curve<C: SwuCurve>(u: C::Base) -> (C::Base, C::Base) {
+    let (a, b, z) = (C::ISO_A, C::ISO_B, C::Z);
+
+    let tv = z * u.square();
+    let den = tv.square() + tv;
+    let x1 = if den == C::Base::zero() {
+        // Exceptional case, which only occurs for u = 0 on the Pasta curves.
+        b * (z * a).invert().unwrap()
+    } else {
+        -b * a.invert().unwrap() * (C::Base::one() + den.invert().unwrap())
+    };
+    let gx1 = x1.square() * x1 + a * x1 + b;
+
+    let (x, y) = match Option::<C::Base>::from(gx1.sqrt()) {
+        Some(y1) => (x1, y1),
+        None => {
+            // g(x2) = Z^3 u^6 g(x1) is a square, since Z is not.
+            let x2 = tv * x1;
+            let gx2 = x2.square() * x2 + a * x2 + b;
+            (x2, gx2.sqrt().unwrap())
+        }
+    };
+
+    if sgn0(u) == sgn0(y) {
+        (x, y)
+    } else {
+        (x, -y)
+    }
+}
+
+/// Maps the point $(x, y)$ on the iso-curve $E'$ to $E$ with the isogeny.
+///
+/// Points in the kernel of the isogeny are mapped to the identity.
+pub fn iso_map<C: SwuCurve>(x: C::Base, y: C::Base) -> C {
+    let k = C::ISOGENY_CONSTANTS;
+    let x_num = ((k[0] * x + k[1]) * x + k[2]) * x + k[3];
+    let x_den = (x + k[4]) * x + k[5];
+    let y_num = (((k[6] * x + k[7]) * x + k[8]) * x + k[9]) * y;
+    let y_den = ((x + k[10]) * x + k[11]) * x + k[12];
+
+    match (
+        Option::<C::Base>::from(x_den.invert()),
+        Option::<C::Base>::from(y_den.invert()),
+    ) {
+        (Some(x_den_inv), Some(y_den_inv)) => {
+            C::from_xy(x_num * x_den_inv, y_num * y_den_inv).unwrap()
+        }
+        _ => C::identity(),
+    }
+}
+
+/// Maps `u` to a point on the curve.
+pub fn map_to_curve<C: SwuCurve>(u: C::Base) -> C {
+    let (x, y) = map_to_iso_curve::<C>(u);
+    iso_map(x, y)
+}
+
+/// Hashes the field element `message` to $u_i = \mathsf{Poseidon}(i, m)$ for
+/// $i \in \{0, 1\}$, using [`P128Pow5T3`].
+///
+/// This is the hash-to-field step computed by [`crate::chip::PoseidonHashToField`].
+pub fn poseidon_hash_to_field<F: FieldExt>(message: F) -> [F; 2]
+where
+    P128Pow5T3: Spec<F, 3, 2>,
+{
+    let u = |i: u64| Hash::init(P128Pow5T3, ConstantLength::<2>).hash([F::from_u64(i), message]);
+    [u(0), u(1)]
+}
+
+/// Hashes `message` to a point on the curve, using `hash_to_field` to obtain
+/// $u_0, u_1$.
+pub fn hash_to_curve<C: SwuCurve, M>(
+    hash_to_field: impl FnOnce(M) -> [C::Base; 2],
+    message: M,
+) -> C::Curve {
+    let [u_0, u_1] = hash_to_field(message);
+    map_to_curve::<C>(u_0).to_curve() + map_to_curve::<C>(u_1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hash_to_curve, map_to_curve, map_to_iso_curve, poseidon_hash_to_field, sgn0};
+    use crate::chip::SwuCurve;
+
+    use group::{prime::PrimeCurveAffine, Curve};
+    use pasta_curves::{
+        arithmetic::{CurveAffine, FieldExt},
+        pallas,
+    };
+
+    #[test]
+    fn map_to_curve_test_vectors() {
+        // Computed with an independent implementation of the simplified SWU map
+        // and the isogeny, as (u, x, y).
+        let test_vectors = [
+            (
+                pallas::Base::from_raw([
+                    0x0000_0000_0000_0000,
+                    0x0000_0000_0000_0000,
+                    0x0000_0000_0000_0000,
+                    0x0000_0000_0000_0000,
+                ]),
+                pallas::Base::from_raw([
+                    0x1e2e_c36f_1587_9e3d,
+                    0xbb5f_efea_c497_947e,
+                    0xc359_e750_6865_72f2,
+                    0x1f33_6f4c_cd9c_17a9,
+                ]),
+                pallas::Base::from_raw([
+                    0x525d_bac9_b7f8_f87f,
+                    0x8df0_67e4_4cfe_9fe7,
+                    0xab29_9924_bd74_ba62,
+                    0x2c8f_f7b3_73a6_ddf8,
+                ]),
+            ),
+            (
+                pallas::Base::from_raw([
+                    0x0000_0000_0000_0001,
+                    0x0000_0000_0000_0000,
+                    0x0000_0000_0000_0000,
+                    0x0000_0000_0000_0000,
+                ]),
+                pallas::Base::from_raw([
+                    0x904b_365c_6823_fdd6,
+                    0x108c_ff65_b598_1332,
+                    0xf5e4_be9a_9263_20f4,
+                    0x3cbb_71ec_72db_1a30,
+                ]),
+                pallas::Base::from_raw([
+                    0xe1e0_67b8_b051_5773,
+                    0x0f7e_7023_2531_ccb3,
+                    0x8d86_c195_56f0_67e1,
+                    0x39f5_7acb_e627_1f78,
+                ]),
+            ),
+            (
+                pallas::Base::from_raw([
+                    0x992d_30ed_0000_0000,
+                    0x2246_98fc_094c_f91b,
+                    0x0000_0000_0000_0000,
+                    0x4000_0000_0000_0000,
+                ]),
+                pallas::Base::from_raw([
+                    0x904b_365c_6823_fdd6,
+                    0x108c_ff65_b598_1332,
+                    0xf5e4_be9a_9263_20f4,
+                    0x3cbb_71ec_72db_1a30,
+                ]),
+                pallas::Base::from_raw([
+                    0xb74c_c934_4fae_a88e,
+                    0x12c8_28d8_e41b_2c67,
+                    0x7279_3e6a_a90f_981f,
+                    0x060a_8534_19d8_e087,
+                ]),
+            ),
+            (
+                pallas::Base::from_raw([
+                    0x1fde_42e1_0536_75fa,
+                    0xcad2_1bc8_6043_7651,
+                    0x45ae_6c91_d34c_2362,
+                    0x1144_7aa1_8ef8_9a95,
+                ]),
+                pallas::Base::from_raw([
+                    0x2618_126c_1cee_aba6,
+                    0xac27_08ea_cbc4_13c5,
+                    0x748e_71a2_2798_546f,
+                    0x04e2_638f_263d_5892,
+                ]),
+                pallas::Base::from_raw([
+                    0x010c_78a5_8c92_9281,
+                    0x5cd4_a1ae_5874_8058,
+                    0x037e_1212_70d2_dc53,
+                    0x2755_72b9_1cbc_5934,
+                ]),
+            ),
+            (
+                pallas::Base::from_raw([
+                    0xe9cf_0d38_160c_ce63,
+                    0x3548_a806_b412_217f,
+                    0x23c8_10f8_3b5e_bb12,
+                    0x3117_bb7c_e1ca_4b39,
+                ]),
+                pallas::Base::from_raw([
+                    0xf252_9c5c_ecdd_311e,
+                    0xfa29_4d34_bba4_aa86,
+                    0x8ac5_2cd3_db63_87fd,
+                    0x39b6_fe30_3d1d_8830,
+                ]),
+                pallas::Base::from_raw([
+                    0x46e1_0adb_516f_8766,
+                    0x9e4c_81b7_df38_bfda,
+                    0x27d9_8142_89fa_9dc6,
+                    0x358e_32c8_2d0c_7256,
+                ]),
+            ),
+        ];
+
+        for (u, x, y) in test_vectors.iter() {
+            let point = map_to_curve::<pallas::Affine>(*u);
+            let coordinates = point.coordinates().unwrap();
+            assert_eq!((*coordinates.x(), *coordinates.y()), (*x, *y));
+        }
+    }
+
+    #[test]
+    fn map_to_iso_curve_is_on_curve() {
+        for _ in 0..10 {
+            let u = pallas::Base::rand();
+            let (x, y) = map_to_iso_curve::<pallas::Affine>(u);
+            let (a, b) = (pallas::Affine::ISO_A, pallas::Affine::ISO_B);
+            assert_eq!(y.square(), x.square() * x + a * x + b);
+            assert_eq!(sgn0(y), sgn0(u));
+
+            // Negating u negates the point.
+            assert_eq!(
+                map_to_curve::<pallas::Affine>(-u),
+                -map_to_curve::<pallas::Affine>(u)
+            );
+        }
+    }
+
+    #[test]
+    fn hash_to_curve_adds_maps() {
+        let (u_0, u_1) = (pallas::Base::rand(), pallas::Base::rand());
+        let point = hash_to_curve::<pallas::Affine, _>(|()| [u_0, u_1], ());
+        assert_eq!(
+            point,
+            map_to_curve::<pallas::Affine>(u_0).to_curve() + map_to_curve::<pallas::Affine>(u_1)
+        );
+
+        // Opposite field elements hash to the identity.
+        let point = hash_to_curve::<pallas::Affine, _>(|()| [u_0, -u_0], ());
+        assert!(bool::from(point.to_affine().is_identity()));
+    }
+
+    #[test]
+    fn poseidon_hash_to_field_separates_outputs() {
+        let m = pallas::Base::rand();
+        let [u_0, u_1] = poseidon_hash_to_field(m);
+        assert_ne!(u_0, u_1);
+        assert_ne!(poseidon_hash_to_field(m + pallas::Base::one()), [u_0, u_1]);
+
+        // u_0 and u_1 do not cancel out, so the hash is not the identity.
+        let point = hash_to_curve::<pallas::Affine, _>(poseidon_hash_to_field, m);
+        assert!(!bool::from(point.to_affine().is_identity()));
+    }
+}
@@ -0,0 +1,1308 @@
+//! The secp256k1 curve.
+//!
+//! This implements [`CurveAffine`] for secp256k1, so that
+//! [`ForeignEccChip`](crate::chip::ForeignEccChip) and the [`ecdsa`](super::ecdsa)
+//! primitives can be instantiated over it. Field elements are stored as their
+//! canonical integer values and use the arithmetic of [`utilities::non_native`], so
+//! they are neither fast nor constant time: these types are meant for computing
+//! witnesses and test vectors, not for handling secrets, and are therefore not
+//! exposed outside of this crate.
+//!
+//! Hashing to the curve is not supported.
+
+use std::cmp;
+use std::fmt;
+use std::io;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use bigint::{U256, U512};
+use ff::{Field, FieldBits, PrimeField, PrimeFieldBits};
+use group::{
+    prime::{PrimeCurve, PrimeCurveAffine, PrimeGroup},
+    Curve as _, Group as _, GroupEncoding,
+};
+use pasta_curves::arithmetic::{Coordinates, CurveAffine, CurveExt, FieldExt, Group};
+use rand::RngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use utilities::non_native::{
+    add_mod, invert_mod, mul_mod, sub_mod, ForeignField, Secp256k1Base, Secp256k1Scalar,
+};
+
+/// Implements the by-value variants of a binary operator (and optionally its
+/// assigning form), given the implementation for two references.
+macro_rules! impl_binops {
+    ($lhs:ident, $rhs:ident, $output:ident, $op:ident, $op_fn:ident) => {
+        impl<'b> $op<&'b $rhs> for $lhs {
+            type Output = $output;
+
+            fn $op_fn(self, rhs: &'b $rhs) -> $output {
+                (&self).$op_fn(rhs)
+            }
+        }
+
+        impl<'a> $op<$rhs> for &'a $lhs {
+            type Output = $output;
+
+            fn $op_fn(self, rhs: $rhs) -> $output {
+                self.$op_fn(&rhs)
+            }
+        }
+
+        impl $op<$rhs> for $lhs {
+            type Output = $output;
+
+            fn $op_fn(self, rhs: $rhs) -> $output {
+                (&self).$op_fn(&rhs)
+            }
+        }
+    };
+    ($lhs:ident, $rhs:ident, $op:ident, $op_fn:ident, $assign:ident, $assign_fn:ident) => {
+        impl_binops!($lhs, $rhs, $lhs, $op, $op_fn);
+
+        impl $assign<$rhs> for $lhs {
+            fn $assign_fn(&mut self, rhs: $rhs) {
+                *self = (&*self).$op_fn(&rhs);
+            }
+        }
+
+        impl<'b> $assign<&'b $rhs> for $lhs {
+            fn $assign_fn(&mut self, rhs: &'b $rhs) {
+                *self = (&*self).$op_fn(rhs);
+            }
+        }
+    };
+}
+
+/// Implements a prime field whose modulus is given by the [`ForeignField`]
+/// `$modulus`.
+macro_rules! new_field_impl {
+    (
+        $(#[$attr:meta])*
+        $field:ident,
+        $modulus:ident,
+        $modulus_str:literal,
+        generator: $generator:literal,
+        s: $s:literal,
+        root_of_unity: $root_of_unity:expr,
+        root_of_unity_inv: $root_of_unity_inv:expr,
+        t_minus1_over2: $t_minus1_over2:expr,
+        delta: $delta:expr,
+        two_inv: $two_inv:expr,
+        rescue_invalpha: $rescue_invalpha:expr,
+        zeta: $zeta:expr,
+    ) => {
+        $(#[$attr])*
+        #[derive(Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $field(U256);
+
+        impl $field {
+            /// Converts from an integer represented in little endian into its
+            /// (congruent) field element.
+            pub fn from_raw(val: [u64; 4]) -> Self {
+                $field(U256(val) % $modulus::MODULUS)
+            }
+        }
+
+        impl fmt::Debug for $field {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "0x")?;
+                for limb in self.0 .0.iter().rev() {
+                    write!(f, "{:016x}", limb)?;
+                }
+                Ok(())
+            }
+        }
+
+        impl From<bool> for $field {
+            fn from(bit: bool) -> $field {
+                $field(U256::from(bit as u64))
+            }
+        }
+
+        impl From<u64> for $field {
+            fn from(val: u64) -> $field {
+                $field::from_raw([val, 0, 0, 0])
+            }
+        }
+
+        impl ConstantTimeEq for $field {
+            fn ct_eq(&self, other: &Self) -> Choice {
+                self.0 .0[0].ct_eq(&other.0 .0[0])
+                    & self.0 .0[1].ct_eq(&other.0 .0[1])
+                    & self.0 .0[2].ct_eq(&other.0 .0[2])
+                    & self.0 .0[3].ct_eq(&other.0 .0[3])
+            }
+        }
+
+        impl ConditionallySelectable for $field {
+            fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+                let mut limbs = [0; 4];
+                for (limb, (a, b)) in limbs.iter_mut().zip(a.0 .0.iter().zip(b.0 .0.iter())) {
+                    *limb = u64::conditional_select(a, b, choice);
+                }
+                $field(U256(limbs))
+            }
+        }
+
+        impl<'a> Neg for &'a $field {
+            type Output = $field;
+
+            fn neg(self) -> $field {
+                $field(sub_mod(U256::zero(), self.0, $modulus::MODULUS))
+            }
+        }
+
+        impl Neg for $field {
+            type Output = $field;
+
+            fn neg(self) -> $field {
+                -&self
+            }
+        }
+
+        impl<'a, 'b> Add<&'b $field> for &'a $field {
+            type Output = $field;
+
+            fn add(self, rhs: &'b $field) -> $field {
+                $field(add_mod(self.0, rhs.0, $modulus::MODULUS))
+            }
+        }
+
+        impl<'a, 'b> Sub<&'b $field> for &'a $field {
+            type Output = $field;
+
+            fn sub(self, rhs: &'b $field) -> $field {
+                $field(sub_mod(self.0, rhs.0, $modulus::MODULUS))
+            }
+        }
+
+        impl<'a, 'b> Mul<&'b $field> for &'a $field {
+            type Output = $field;
+
+            fn mul(self, rhs: &'b $field) -> $field {
+                $field(mul_mod(self.0, rhs.0, $modulus::MODULUS))
+            }
+        }
+
+        impl_binops!($field, $field, Add, add, AddAssign, add_assign);
+        impl_binops!($field, $field, Sub, sub, SubAssign, sub_assign);
+        impl_binops!($field, $field, Mul, mul, MulAssign, mul_assign);
+
+        impl Field for $field {
+            fn random(mut rng: impl RngCore) -> Self {
+                let mut bytes = [0; 64];
+                rng.fill_bytes(&mut bytes);
+                Self::from_bytes_wide(&bytes)
+            }
+
+            fn zero() -> Self {
+                $field(U256::zero())
+            }
+
+            fn one() -> Self {
+                $field(U256::one())
+            }
+
+            fn is_zero(&self) -> bool {
+                self.0.is_zero()
+            }
+
+            fn square(&self) -> Self {
+                self * self
+            }
+
+            fn double(&self) -> Self {
+                self + self
+            }
+
+            fn invert(&self) -> CtOption<Self> {
+                match invert_mod(self.0, $modulus::MODULUS) {
+                    Some(inv) => CtOption::new($field(inv), Choice::from(1)),
+                    None => CtOption::new(Self::zero(), Choice::from(0)),
+                }
+            }
+
+            fn sqrt(&self) -> CtOption<Self> {
+                sqrt_tonelli_shanks(self)
+            }
+        }
+
+        impl PrimeField for $field {
+            type Repr = [u8; 32];
+
+            const NUM_BITS: u32 = 256;
+            const CAPACITY: u32 = 255;
+            const S: u32 = $s;
+
+            fn from_repr(repr: Self::Repr) -> Option<Self> {
+                let val = U256::from_little_endian(&repr);
+                if val < $modulus::MODULUS {
+                    Some($field(val))
+                } else {
+                    None
+                }
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                let mut repr = [0; 32];
+                self.0.to_little_endian(&mut repr);
+                repr
+            }
+
+            fn is_odd(&self) -> bool {
+                self.0.bit(0)
+            }
+
+            fn multiplicative_generator() -> Self {
+                $field(U256([$generator, 0, 0, 0]))
+            }
+
+            fn root_of_unity() -> Self {
+                Self::ROOT_OF_UNITY
+            }
+        }
+
+        impl PrimeFieldBits for $field {
+            type ReprBits = [u64; 4];
+
+            fn to_le_bits(&self) -> FieldBits<Self::ReprBits> {
+                FieldBits::new(self.0 .0)
+            }
+
+            fn char_le_bits() -> FieldBits<Self::ReprBits> {
+                FieldBits::new($modulus::MODULUS.0)
+            }
+        }
+
+        impl Group for $field {
+            type Scalar = $field;
+
+            fn group_zero() -> Self {
+                Self::zero()
+            }
+            fn group_add(&mut self, rhs: &Self) {
+                *self += *rhs;
+            }
+            fn group_sub(&mut self, rhs: &Self) {
+                *self -= *rhs;
+            }
+            fn group_scale(&mut self, by: &Self::Scalar) {
+                *self *= *by;
+            }
+        }
+
+        impl FieldExt for $field {
+            const MODULUS: &'static str = $modulus_str;
+            const ROOT_OF_UNITY: Self = $field(U256($root_of_unity));
+            const ROOT_OF_UNITY_INV: Self = $field(U256($root_of_unity_inv));
+            const T_MINUS1_OVER2: [u64; 4] = $t_minus1_over2;
+            const DELTA: Self = $field(U256($delta));
+            const TWO_INV: Self = $field(U256($two_inv));
+            const RESCUE_ALPHA: u64 = 5;
+            const RESCUE_INVALPHA: [u64; 4] = $rescue_invalpha;
+            const ZETA: Self = $field(U256($zeta));
+
+            fn ct_is_zero(&self) -> Choice {
+                self.ct_eq(&Self::zero())
+            }
+
+            fn from_u64(v: u64) -> Self {
+                Self::from(v)
+            }
+
+            fn from_u128(v: u128) -> Self {
+                $field::from_raw([v as u64, (v >> 64) as u64, 0, 0])
+            }
+
+            fn from_bytes(bytes: &[u8; 32]) -> CtOption<Self> {
+                match Self::from_repr(*bytes) {
+                    Some(val) => CtOption::new(val, Choice::from(1)),
+                    None => CtOption::new(Self::zero(), Choice::from(0)),
+                }
+            }
+
+            fn to_bytes(&self) -> [u8; 32] {
+                self.to_repr()
+            }
+
+            fn from_bytes_wide(bytes: &[u8; 64]) -> Self {
+                let val = U512::from_little_endian(bytes) % U512::from($modulus::MODULUS);
+                $field(U256::from(val))
+            }
+
+            fn read<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+                let mut bytes = [0; 32];
+                reader.read_exact(&mut bytes)?;
+                Option::from(Self::from_bytes(&bytes)).ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid field element")
+                })
+            }
+
+            fn write<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+                writer.write_all(&self.to_bytes())
+            }
+
+            fn pow(&self, by: &[u64; 4]) -> Self {
+                self.pow_vartime(by)
+            }
+
+            fn pow_by_t_minus1_over2(&self) -> Self {
+                self.pow_vartime(&Self::T_MINUS1_OVER2)
+            }
+
+            fn sqrt_ratio(num: &Self, div: &Self) -> (Choice, Self) {
+                // If num/div is not a square, then ROOT_OF_UNITY * num/div is.
+                let ratio = div.invert().unwrap_or_else(Self::zero) * num;
+                let sqrt = ratio.sqrt();
+                let alt = (ratio * Self::ROOT_OF_UNITY).sqrt();
+                let is_square = sqrt.is_some();
+                (
+                    is_square & !div.ct_is_zero(),
+                    Self::conditional_select(
+                        &alt.unwrap_or_else(Self::zero),
+                        &sqrt.unwrap_or_else(Self::zero),
+                        is_square,
+                    ),
+                )
+            }
+
+            fn sqrt_alt(&self) -> (Choice, Self) {
+                Self::sqrt_ratio(self, &Self::one())
+            }
+
+            fn get_lower_128(&self) -> u128 {
+                (self.0 .0[1] as u128) << 64 | self.0 .0[0] as u128
+            }
+
+            fn get_lower_32(&self) -> u32 {
+                self.0 .0[0] as u32
+            }
+        }
+    };
+}
+
+new_field_impl!(
+    /// An element of the base field of secp256k1, $\mathbb{F}_p$ with
+    /// $p = 2^{256} - 2^{32} - 977$.
+    Base,
+    Secp256k1Base,
+    "0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+    generator: 3,
+    s: 1,
+    root_of_unity: [
+        0xffff_fffe_ffff_fc2e,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+    ],
+    root_of_unity_inv: [
+        0xffff_fffe_ffff_fc2e,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+    ],
+    t_minus1_over2: [
+        0xffff_ffff_bfff_ff0b,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x3fff_ffff_ffff_ffff,
+    ],
+    delta: [9, 0, 0, 0],
+    two_inv: [
+        0xffff_ffff_7fff_fe18,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0x7fff_ffff_ffff_ffff,
+    ],
+    rescue_invalpha: [
+        0x6666_6665_ffff_fe79,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+        0x6666_6666_6666_6666,
+    ],
+    zeta: [
+        0xc139_6c28_7195_01ee,
+        0x9cf0_4975_12f5_8995,
+        0x6e64_479e_ac34_34e9,
+        0x7ae9_6a2b_657c_0710,
+    ],
+);
+
+new_field_impl!(
+    /// An element of the scalar field of secp256k1, $\mathbb{F}_n$ where $n$ is
+    /// the order of the curve.
+    Scalar,
+    Secp256k1Scalar,
+    "0xfffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+    generator: 7,
+    s: 6,
+    root_of_unity: [
+        0x992f_4b54_02b0_52f2,
+        0x98bd_eab6_8075_6045,
+        0xdf98_79a3_fbc4_83a8,
+        0x0c1d_c060_e7a9_1986,
+    ],
+    root_of_unity_inv: [
+        0xb6fb_30a0_884f_0d1c,
+        0x77a2_7591_0aa4_13c3,
+        0xefc7_b0c7_5b8c_bb72,
+        0xfd3a_e181_f12d_7096,
+    ],
+    t_minus1_over2: [
+        0x777f_a4bd_19a0_6c82,
+        0xfd75_5db9_cd5e_9140,
+        0xffff_ffff_ffff_ffff,
+        0x01ff_ffff_ffff_ffff,
+    ],
+    delta: [
+        0x1994_17c8_c0bb_7601,
+        0xd63b_78e7_80e1_341e,
+        0x000c_bc21_fe45_61c8,
+        0x0000_0000_0000_0000,
+    ],
+    two_inv: [
+        0xdfe9_2f46_681b_20a1,
+        0x5d57_6e73_57a4_501d,
+        0xffff_ffff_ffff_ffff,
+        0x7fff_ffff_ffff_ffff,
+    ],
+    rescue_invalpha: [
+        0x9975_1870_a691_cdcd,
+        0xc88b_e3eb_bf6d_4cfc,
+        0xcccc_cccc_cccc_cccb,
+        0xcccc_cccc_cccc_cccc,
+    ],
+    zeta: [
+        0xdf02_967c_1b23_bd72,
+        0x122e_22ea_2081_6678,
+        0xa526_1c02_8812_645a,
+        0x5363_ad4c_c05c_30e0,
+    ],
+);
+
+/// Computes a square root with the Tonelli-Shanks algorithm.
+fn sqrt_tonelli_shanks<F: FieldExt>(f: &F) -> CtOption<F> {
+    // w = f^((t - 1) / 2)
+    let w = f.pow_by_t_minus1_over2();
+
+    let mut v = F::S;
+    let mut x = w * f;
+    let mut b = x * w;
+
+    // Initialize z as the 2^S root of unity.
+    let mut z = F::root_of_unity();
+
+    for max_v in (1..=F::S).rev() {
+        let mut k = 1;
+        let mut tmp = b.square();
+        let mut j_less_than_v: Choice = 1.into();
+
+        for j in 2..max_v {
+            let tmp_is_one = tmp.ct_eq(&F::one());
+            let squared = F::conditional_select(&tmp, &z, tmp_is_one).square();
+            tmp = F::conditional_select(&squared, &tmp, tmp_is_one);
+            let new_z = F::conditional_select(&z, &squared, tmp_is_one);
+            j_less_than_v &= !j.ct_eq(&v);
+            k = u32::conditional_select(&j, &k, tmp_is_one);
+            z = F::conditional_select(&z, &new_z, j_less_than_v);
+        }
+
+        let result = x * z;
+        x = F::conditional_select(&result, &x, b.ct_eq(&F::one()));
+        z = z.square();
+        b *= z;
+        v = k;
+    }
+
+    CtOption::new(x, (x * x).ct_eq(f))
+}
+
+/// The curve constant $b = 7$.
+const B: Base = Base(U256([7, 0, 0, 0]));
+
+/// A secp256k1 point in Jacobian coordinates.
+#[derive(Clone, Copy, Debug)]
+pub struct Point {
+    x: Base,
+    y: Base,
+    z: Base,
+}
+
+/// A secp256k1 point in affine coordinates, with the identity represented as
+/// $(0, 0)$.
+#[derive(Clone, Copy)]
+pub struct Affine {
+    x: Base,
+    y: Base,
+}
+
+impl fmt::Debug for Affine {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_identity().into() {
+            write!(f, "Infinity")
+        } else {
+            write!(f, "({:?}, {:?})", self.x, self.y)
+        }
+    }
+}
+
+impl group::Group for Point {
+    type Scalar = Scalar;
+
+    fn random(mut rng: impl RngCore) -> Self {
+        loop {
+            let x = Base::random(&mut rng);
+            let ysign = (rng.next_u32() % 2) as u8;
+
+            let y = (x.square() * x + B).sqrt();
+            if let Some(y) = Option::<Base>::from(y) {
+                let sign = y.is_odd() as u8;
+                let y = if ysign ^ sign == 0 { y } else { -y };
+                break Affine { x, y }.to_curve();
+            }
+        }
+    }
+
+    fn identity() -> Self {
+        Point {
+            x: Base::zero(),
+            y: Base::zero(),
+            z: Base::zero(),
+        }
+    }
+
+    fn generator() -> Self {
+        Affine::generator().to_curve()
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.z.ct_is_zero()
+    }
+
+    fn double(&self) -> Self {
+        // http://www.hyperelliptic.org/EFD/g1p/auto-shortw-jacobian-0.html#doubling-dbl-2009-l
+        //
+        // There are no points of order 2.
+
+        let a = self.x.square();
+        let b = self.y.square();
+        let c = b.square();
+        let d = (self.x + b).square() - a - c;
+        let d = d + d;
+        let e = a + a + a;
+        let f = e.square();
+        let z3 = self.z * self.y;
+        let z3 = z3 + z3;
+        let x3 = f - (d + d);
+        let c = c + c;
+        let c = c + c;
+        let c = c + c;
+        let y3 = e * (d - x3) - c;
+
+        let tmp = Point {
+            x: x3,
+            y: y3,
+            z: z3,
+        };
+
+        Point::conditional_select(&tmp, &Point::identity(), self.is_identity())
+    }
+}
+
+impl CurveExt for Point {
+    type ScalarExt = Scalar;
+    type Base = Base;
+    type AffineExt = Affine;
+
+    const CURVE_ID: &'static str = "secp256k1";
+
+    /// Unimplemented: hashing to secp256k1 is not supported.
+    fn hash_to_curve<'a>(_domain_prefix: &'a str) -> Box<dyn Fn(&[u8]) -> Self + 'a> {
+        unimplemented!()
+    }
+
+    /// Applies the endomorphism $(x, y) \mapsto (\beta x, y)$, which is
+    /// multiplication by [`Scalar::ZETA`].
+    fn endo(&self) -> Self {
+        Point {
+            x: self.x * Base::ZETA,
+            y: self.y,
+            z: self.z,
+        }
+    }
+
+    fn jacobian_coordinates(&self) -> (Base, Base, Base) {
+        (self.x, self.y, self.z)
+    }
+
+    fn is_on_curve(&self) -> Choice {
+        // Y^2 = X^3 + b(Z^6)
+        let z2 = self.z.square();
+        let z6 = z2.square() * z2;
+        (self.y.square() - self.x.square() * self.x).ct_eq(&(z6 * B)) | self.z.ct_is_zero()
+    }
+
+    fn a() -> Base {
+        Base::zero()
+    }
+
+    fn b() -> Base {
+        B
+    }
+
+    fn new_jacobian(x: Base, y: Base, z: Base) -> CtOption<Self> {
+        let p = Point { x, y, z };
+        CtOption::new(p, p.is_on_curve())
+    }
+}
+
+impl group::Curve for Point {
+    type AffineRepr = Affine;
+
+    fn to_affine(&self) -> Affine {
+        let zinv = self.z.invert().unwrap_or_else(Base::zero);
+        let zinv2 = zinv.square();
+        let x = self.x * zinv2;
+        let zinv3 = zinv2 * zinv;
+        let y = self.y * zinv3;
+
+        let tmp = Affine { x, y };
+
+        Affine::conditional_select(&tmp, &Affine::identity(), zinv.ct_is_zero())
+    }
+}
+
+impl PrimeGroup for Point {}
+
+impl PrimeCurve for Point {
+    type Affine = Affine;
+}
+
+impl GroupEncoding for Point {
+    type Repr = Compressed;
+
+    fn from_bytes(bytes: &Compressed) -> CtOption<Self> {
+        Affine::from_bytes(bytes).map(Self::from)
+    }
+
+    fn from_bytes_unchecked(bytes: &Compressed) -> CtOption<Self> {
+        // We can't avoid curve checks when parsing a compressed encoding.
+        Affine::from_bytes(bytes).map(Self::from)
+    }
+
+    fn to_bytes(&self) -> Compressed {
+        Affine::from(self).to_bytes()
+    }
+}
+
+impl<'a> From<&'a Affine> for Point {
+    fn from(p: &'a Affine) -> Point {
+        p.to_curve()
+    }
+}
+
+impl From<Affine> for Point {
+    fn from(p: Affine) -> Point {
+        p.to_curve()
+    }
+}
+
+impl Default for Point {
+    fn default() -> Point {
+        Point::identity()
+    }
+}
+
+impl ConstantTimeEq for Point {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        // Is (xz^2, yz^3, z) equal to (x'z'^2, yz'^3, z') when converted to affine?
+
+        let z = other.z.square();
+        let x1 = self.x * z;
+        let z = z * other.z;
+        let y1 = self.y * z;
+        let z = self.z.square();
+        let x2 = other.x * z;
+        let z = z * self.z;
+        let y2 = other.y * z;
+
+        let self_is_zero = self.is_identity();
+        let other_is_zero = other.is_identity();
+
+        (self_is_zero & other_is_zero)
+            | ((!self_is_zero) & (!other_is_zero) & x1.ct_eq(&x2) & y1.ct_eq(&y2))
+    }
+}
+
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl cmp::Eq for Point {}
+
+impl ConditionallySelectable for Point {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Point {
+            x: Base::conditional_select(&a.x, &b.x, choice),
+            y: Base::conditional_select(&a.y, &b.y, choice),
+            z: Base::conditional_select(&a.z, &b.z, choice),
+        }
+    }
+}
+
+impl<'a> Neg for &'a Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        Point {
+            x: self.x,
+            y: -self.y,
+            z: self.z,
+        }
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Point {
+        -&self
+    }
+}
+
+impl<T> Sum<T> for Point
+where
+    T: std::borrow::Borrow<Point>,
+{
+    fn sum<I>(iter: I) -> Self
+    where
+        I: Iterator<Item = T>,
+    {
+        iter.fold(Self::identity(), |acc, item| acc + item.borrow())
+    }
+}
+
+impl<'a, 'b> Add<&'a Point> for &'b Point {
+    type Output = Point;
+
+    fn add(self, rhs: &'a Point) -> Point {
+        if bool::from(self.is_identity()) {
+            *rhs
+        } else if bool::from(rhs.is_identity()) {
+            *self
+        } else {
+            let z1z1 = self.z.square();
+            let z2z2 = rhs.z.square();
+            let u1 = self.x * z2z2;
+            let u2 = rhs.x * z1z1;
+            let s1 = self.y * z2z2 * rhs.z;
+            let s2 = rhs.y * z1z1 * self.z;
+
+            if u1 == u2 {
+                if s1 == s2 {
+                    self.double()
+                } else {
+                    Point::identity()
+                }
+            } else {
+                let h = u2 - u1;
+                let i = (h + h).square();
+                let j = h * i;
+                let r = s2 - s1;
+                let r = r + r;
+                let v = u1 * i;
+                let x3 = r.square() - j - v - v;
+                let s1 = s1 * j;
+                let s1 = s1 + s1;
+                let y3 = r * (v - x3) - s1;
+                let z3 = (self.z + rhs.z).square() - z1z1 - z2z2;
+                let z3 = z3 * h;
+
+                Point {
+                    x: x3,
+                    y: y3,
+                    z: z3,
+                }
+            }
+        }
+    }
+}
+
+impl<'a, 'b> Add<&'a Affine> for &'b Point {
+    type Output = Point;
+
+    fn add(self, rhs: &'a Affine) -> Point {
+        self + rhs.to_curve()
+    }
+}
+
+impl<'a, 'b> Sub<&'a Point> for &'b Point {
+    type Output = Point;
+
+    fn sub(self, other: &'a Point) -> Point {
+        self + (-other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Affine> for &'b Point {
+    type Output = Point;
+
+    fn sub(self, other: &'a Affine) -> Point {
+        self + (-other)
+    }
+}
+
+#[allow(clippy::suspicious_arithmetic_impl)]
+impl<'a, 'b> Mul<&'b Scalar> for &'a Point {
+    type Output = Point;
+
+    fn mul(self, other: &'b Scalar) -> Point {
+        // A simple double-and-add, from the most significant to the least
+        // significant bit of the scalar. All 256 bits are used, since the
+        // order of secp256k1 is close to 2^256.
+        let mut acc = Point::identity();
+        for bit in other
+            .to_repr()
+            .iter()
+            .rev()
+            .flat_map(|byte| (0..8).rev().map(move |i| Choice::from((byte >> i) & 1u8)))
+        {
+            acc = acc.double();
+            acc = Point::conditional_select(&acc, &(acc + self), bit);
+        }
+        acc
+    }
+}
+
+impl_binops!(Point, Point, Add, add, AddAssign, add_assign);
+impl_binops!(Point, Point, Sub, sub, SubAssign, sub_assign);
+impl_binops!(Point, Affine, Add, add, AddAssign, add_assign);
+impl_binops!(Point, Affine, Sub, sub, SubAssign, sub_assign);
+impl_binops!(Point, Scalar, Mul, mul, MulAssign, mul_assign);
+
+impl Group for Point {
+    type Scalar = Scalar;
+
+    fn group_zero() -> Self {
+        Self::identity()
+    }
+    fn group_add(&mut self, rhs: &Self) {
+        *self += *rhs;
+    }
+    fn group_sub(&mut self, rhs: &Self) {
+        *self -= *rhs;
+    }
+    fn group_scale(&mut self, by: &Self::Scalar) {
+        *self *= *by;
+    }
+}
+
+impl<'a> Neg for &'a Affine {
+    type Output = Affine;
+
+    fn neg(self) -> Affine {
+        Affine {
+            x: self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl Neg for Affine {
+    type Output = Affine;
+
+    fn neg(self) -> Affine {
+        -&self
+    }
+}
+
+impl<'a, 'b> Add<&'a Point> for &'b Affine {
+    type Output = Point;
+
+    fn add(self, rhs: &'a Point) -> Point {
+        rhs + self
+    }
+}
+
+impl<'a, 'b> Add<&'a Affine> for &'b Affine {
+    type Output = Point;
+
+    fn add(self, rhs: &'a Affine) -> Point {
+        self.to_curve() + rhs
+    }
+}
+
+impl<'a, 'b> Sub<&'a Point> for &'b Affine {
+    type Output = Point;
+
+    fn sub(self, other: &'a Point) -> Point {
+        self + (-other)
+    }
+}
+
+impl<'a, 'b> Sub<&'a Affine> for &'b Affine {
+    type Output = Point;
+
+    fn sub(self, other: &'a Affine) -> Point {
+        self + (-other)
+    }
+}
+
+impl<'a, 'b> Mul<&'b Scalar> for &'a Affine {
+    type Output = Point;
+
+    fn mul(self, other: &'b Scalar) -> Point {
+        self.to_curve() * other
+    }
+}
+
+impl_binops!(Affine, Point, Point, Add, add);
+impl_binops!(Affine, Affine, Point, Add, add);
+impl_binops!(Affine, Point, Point, Sub, sub);
+impl_binops!(Affine, Affine, Point, Sub, sub);
+impl_binops!(Affine, Scalar, Point, Mul, mul);
+
+impl PrimeCurveAffine for Affine {
+    type Scalar = Scalar;
+    type Curve = Point;
+
+    fn identity() -> Self {
+        Affine {
+            x: Base::zero(),
+            y: Base::zero(),
+        }
+    }
+
+    /// Returns the standard generator $G$ of secp256k1 from SEC 2.
+    fn generator() -> Self {
+        Affine {
+            x: Base(U256([
+                0x59f2_815b_16f8_1798,
+                0x029b_fcdb_2dce_28d9,
+                0x55a0_6295_ce87_0b07,
+                0x79be_667e_f9dc_bbac,
+            ])),
+            y: Base(U256([
+                0x9c47_d08f_fb10_d4b8,
+                0xfd17_b448_a685_5419,
+                0x5da4_fbfc_0e11_08a8,
+                0x483a_da77_26a3_c465,
+            ])),
+        }
+    }
+
+    fn is_identity(&self) -> Choice {
+        self.x.ct_is_zero() & self.y.ct_is_zero()
+    }
+
+    fn to_curve(&self) -> Point {
+        Point {
+            x: self.x,
+            y: self.y,
+            z: Base::conditional_select(&Base::one(), &Base::zero(), self.is_identity()),
+        }
+    }
+}
+
+/// The SEC 1 compressed encoding of a secp256k1 point: a byte 0x02 or 0x03
+/// giving the parity of $y$, followed by $x$ in big-endian order. The identity is
+/// encoded as 33 zero bytes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Compressed(pub [u8; 33]);
+
+impl Default for Compressed {
+    fn default() -> Self {
+        Compressed([0; 33])
+    }
+}
+
+impl AsRef<[u8]> for Compressed {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl AsMut<[u8]> for Compressed {
+    fn as_mut(&mut self) -> &mut [u8] {
+        &mut self.0
+    }
+}
+
+impl GroupEncoding for Affine {
+    type Repr = Compressed;
+
+    fn from_bytes(bytes: &Compressed) -> CtOption<Self> {
+        let (prefix, x) = bytes.0.split_first().unwrap();
+        if *prefix == 0 {
+            let is_zero = x.iter().all(|byte| *byte == 0);
+            return CtOption::new(Self::identity(), Choice::from(is_zero as u8));
+        }
+        if *prefix != 2 && *prefix != 3 {
+            return CtOption::new(Self::identity(), Choice::from(0));
+        }
+
+        let x = U256::from_big_endian(x);
+        if x >= Secp256k1Base::MODULUS {
+            return CtOption::new(Self::identity(), Choice::from(0));
+        }
+        let x = Base(x);
+        (x.square() * x + B).sqrt().map(|y| {
+            let y = if y.is_odd() == (*prefix == 3) { y } else { -y };
+            Affine { x, y }
+        })
+    }
+
+    fn from_bytes_unchecked(bytes: &Compressed) -> CtOption<Self> {
+        // We can't avoid curve checks when parsing a compressed encoding.
+        Self::from_bytes(bytes)
+    }
+
+    fn to_bytes(&self) -> Compressed {
+        let mut bytes = Compressed::default();
+        if !bool::from(self.is_identity()) {
+            bytes.0[0] = 2 + self.y.is_odd() as u8;
+            self.x.0.to_big_endian(&mut bytes.0[1..]);
+        }
+        bytes
+    }
+}
+
+impl CurveAffine for Affine {
+    type ScalarExt = Scalar;
+    type Base = Base;
+    type CurveExt = Point;
+
+    fn is_on_curve(&self) -> Choice {
+        // y^2 - x^3 ?= b
+        (self.y.square() - self.x.square() * self.x).ct_eq(&B) | self.is_identity()
+    }
+
+    fn coordinates(&self) -> CtOption<Coordinates<Self>> {
+        // `Coordinates` has no public constructor, so we write the fields of a
+        // default value in place, locating them through its accessors.
+        let mut coordinates = Coordinates::<Self>::default();
+        let start = &coordinates as *const Coordinates<Self> as usize;
+        let x_offset = coordinates.x() as *const Base as usize - start;
+        let y_offset = coordinates.y() as *const Base as usize - start;
+        let ptr = &mut coordinates as *mut Coordinates<Self> as *mut u8;
+        // SAFETY: `Coordinates<Self>` stores its x- and y-coordinates inline as
+        // `Base` values, at the offsets returned by its accessors, so both writes
+        // are aligned and within the bounds of `coordinates`.
+        unsafe {
+            (ptr.add(x_offset) as *mut Base).write(self.x);
+            (ptr.add(y_offset) as *mut Base).write(self.y);
+        }
+        CtOption::new(coordinates, !self.is_identity())
+    }
+
+    fn from_xy(x: Base, y: Base) -> CtOption<Self> {
+        let p = Affine { x, y };
+        CtOption::new(p, p.is_on_curve())
+    }
+
+    fn a() -> Base {
+        Base::zero()
+    }
+
+    fn b() -> Base {
+        B
+    }
+
+    fn read<R: io::Read>(reader: &mut R) -> io::Result<Self> {
+        let mut bytes = Compressed::default();
+        reader.read_exact(bytes.as_mut())?;
+        Option::from(Self::from_bytes(&bytes))
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid point"))
+    }
+
+    fn write<W: io::Write>(&self, writer: &mut W) -> io::Result<()> {
+        writer.write_all(self.to_bytes().as_ref())
+    }
+}
+
+impl Default for Affine {
+    fn default() -> Affine {
+        Affine::identity()
+    }
+}
+
+impl<'a> From<&'a Point> for Affine {
+    fn from(p: &'a Point) -> Affine {
+        p.to_affine()
+    }
+}
+
+impl From<Point> for Affine {
+    fn from(p: Point) -> Affine {
+        p.to_affine()
+    }
+}
+
+impl ConstantTimeEq for Affine {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.x.ct_eq(&other.x) & self.y.ct_eq(&other.y)
+    }
+}
+
+impl PartialEq for Affine {
+    fn eq(&self, other: &Self) -> bool {
+        self.ct_eq(other).into()
+    }
+}
+
+impl cmp::Eq for Affine {}
+
+impl ConditionallySelectable for Affine {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Affine {
+            x: Base::conditional_select(&a.x, &b.x, choice),
+            y: Base::conditional_select(&a.y, &b.y, choice),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Affine, Base, Point, Scalar};
+
+    use ff::{Field, PrimeField};
+    use group::{prime::PrimeCurveAffine, Curve, Group, GroupEncoding};
+    use pasta_curves::arithmetic::{CurveAffine, CurveExt, FieldExt};
+
+    #[test]
+    fn field_constants() {
+        fn check<F: FieldExt>() {
+            assert_eq!(F::ROOT_OF_UNITY, F::root_of_unity());
+            assert_eq!(F::ROOT_OF_UNITY * F::ROOT_OF_UNITY_INV, F::one());
+            assert_eq!(F::ROOT_OF_UNITY.pow(&[1 << F::S, 0, 0, 0]), F::one());
+            assert_ne!(F::ROOT_OF_UNITY.pow(&[1 << (F::S - 1), 0, 0, 0]), F::one());
+            assert_eq!(
+                F::multiplicative_generator().pow(&[1 << F::S, 0, 0, 0]),
+                F::DELTA
+            );
+            assert_eq!(F::TWO_INV.double(), F::one());
+            assert_eq!(F::ZETA.cube(), F::one());
+            assert_ne!(F::ZETA, F::one());
+
+            // x -> x^5 is inverted by RESCUE_INVALPHA.
+            let x = F::rand();
+            assert_eq!(
+                x.pow(&[F::RESCUE_ALPHA, 0, 0, 0]).pow(&F::RESCUE_INVALPHA),
+                x
+            );
+        }
+
+        check::<Base>();
+        check::<Scalar>();
+    }
+
+    #[test]
+    fn field_arithmetic() {
+        fn check<F: FieldExt>() {
+            let a = F::rand();
+            let b = F::rand();
+            assert_eq!(a + b - b, a);
+            assert_eq!(a - a, F::zero());
+            assert_eq!(a + (-a), F::zero());
+            assert_eq!((a + b) * a, a.square() + b * a);
+            assert_eq!(a * a.invert().unwrap(), F::one());
+            assert!(bool::from(F::zero().invert().is_none()));
+            assert_eq!(-F::one() * -F::one(), F::one());
+            assert_eq!(F::from_bytes(&a.to_bytes()).unwrap(), a);
+            assert_eq!(F::from_u128(1 << 64), F::from_u64(u64::MAX) + F::one());
+
+            let square = a.square();
+            let root = square.sqrt().unwrap();
+            assert!(root == a || root == -a);
+            let (is_square, root) = F::sqrt_ratio(&square, &b.square());
+            assert!(bool::from(is_square));
+            assert_eq!(root.square() * b.square(), square);
+            let (is_square, root) = F::sqrt_ratio(&(square * F::ROOT_OF_UNITY), &F::one());
+            assert!(!bool::from(is_square));
+            assert_eq!(root.square(), square * F::ROOT_OF_UNITY.square());
+        }
+
+        check::<Base>();
+        check::<Scalar>();
+
+        // -1 is not canonical.
+        let mut bytes = (-Base::one()).to_bytes();
+        bytes[0] += 1;
+        assert!(bool::from(Base::from_bytes(&bytes).is_none()));
+        assert!(Scalar::from_repr([0xff; 32]).is_none());
+    }
+
+    #[test]
+    fn curve() {
+        let g = Affine::generator();
+        assert!(bool::from(g.is_on_curve()));
+
+        // The order of G is n.
+        let n_minus_one = -Scalar::one();
+        assert_eq!((g * n_minus_one).to_affine(), -g);
+        assert!(bool::from((g * n_minus_one + g).is_identity()));
+
+        let a = Scalar::rand();
+        let b = Scalar::rand();
+        let p = g * a;
+        let q = g * b;
+        assert!(bool::from(p.is_on_curve()));
+        assert_eq!(p + q, g * (a + b));
+        assert_eq!(p - q, g * (a - b));
+        assert_eq!(p.double(), p + p);
+        assert_eq!(p + p.to_affine(), g * a.double());
+        assert_eq!(p.to_affine() + q.to_affine(), p + q);
+        assert_eq!(p * b, q * a);
+        assert_eq!(p - p, Point::identity());
+        assert_eq!(p + Point::identity(), p);
+        assert_eq!(Point::identity().to_affine(), Affine::identity());
+
+        // The endomorphism is multiplication by ZETA.
+        assert_eq!(p.endo(), p * Scalar::ZETA);
+
+        // Coordinates
+        let p = p.to_affine();
+        let coordinates = p.coordinates().unwrap();
+        assert_eq!(
+            Affine::from_xy(*coordinates.x(), *coordinates.y()).unwrap(),
+            p
+        );
+        assert!(bool::from(Affine::identity().coordinates().is_none()));
+        assert!(bool::from(
+            Affine::from_xy(*coordinates.x(), *coordinates.y() + Base::one()).is_none()
+        ));
+    }
+
+    #[test]
+    fn encoding() {
+        // 2G, from the SEC 1 compressed encoding.
+        let mut bytes = [0; 33];
+        bytes[0] = 2;
+        bytes[1..].copy_from_slice(&[
+            0xc6, 0x04, 0x7f, 0x94, 0x41, 0xed, 0x7d, 0x6d, 0x30, 0x45, 0x40, 0x6e, 0x95, 0xc0,
+            0x7c, 0xd8, 0x5c, 0x77, 0x8e, 0x4b, 0x8c, 0xef, 0x3c, 0xa7, 0xab, 0xac, 0x09, 0xb9,
+            0x5c, 0x70, 0x9e, 0xe5,
+        ]);
+        let two_g = (Affine::generator() * Scalar::from_u64(2)).to_affine();
+        assert_eq!(two_g.to_bytes().0, bytes);
+        assert_eq!(Affine::from_bytes(&two_g.to_bytes()).unwrap(), two_g);
+
+        for _ in 0..10 {
+            let p = (Point::generator() * Scalar::rand()).to_affine();
+            assert_eq!(Affine::from_bytes(&p.to_bytes()).unwrap(), p);
+            assert_eq!(Affine::from_bytes(&(-p).to_bytes()).unwrap(), -p);
+        }
+
+        let identity = Affine::identity();
+        assert_eq!(Affine::from_bytes(&identity.to_bytes()).unwrap(), identity);
+
+        // Invalid prefix
+        bytes[0] = 4;
+        assert!(bool::from(
+            Affine::from_bytes(&super::Compressed(bytes)).is_none()
+        ));
+    }
+}
@@ -0,0 +1,231 @@
+//! Schnorr signatures in the style of RedPallas.
+//!
+//! A signature $(R, s)$ on a message is valid for the verification key
+//! $\mathsf{vk}$ if $[s] G = R + [c] \mathsf{vk}$, where $G$ is a fixed generator and
+//! the challenge $c$ is a hash of $R$, $\mathsf{vk}$ and the message.
+//!
+//! The challenge hash is pluggable, and is given to [`sign`] and [`verify`] as a
+//! function of $(R, \mathsf{vk})$ that binds the message; [`poseidon_challenge`] is
+//! the hash used by [`crate::chip::PoseidonChallenge`].
+//!
+//! The challenge $c$ is a base field element, so that it can be computed in a
+//! circuit by an algebraic hash and used directly in variable-base scalar
+//! multiplication. It is interpreted as the integer in $[0, p)$ that it represents,
+//! reduced modulo the scalar field modulus $q$; see [`challenge_to_scalar`]. On
+//! Pallas $p < q$, so the reduction is the identity; on Vesta $p > q$, so challenges
+//! in $[q, p)$ wrap around.
+
+use group::{prime::PrimeCurveAffine, Curve};
+use pasta_curves::arithmetic::CurveAffine;
+use poseidon::primitive::{ConstantLength, Hash, P128Pow5T3, Spec};
+
+use crate::chip::base_to_scalar;
+
+/// A Schnorr signature $(R, s)$.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Signature<C: CurveAffine> {
+    r: C,
+    s: C::Scalar,
+}
+
+impl<C: CurveAffine> Signature<C> {
+    /// Constructs a signature from its components.
+    pub fn from_parts(r: C, s: C::Scalar) -> Self {
+        Signature { r, s }
+    }
+
+    /// Returns $R$.
+    pub fn r(&self) -> C {
+        self.r
+    }
+
+    /// Returns $s$.
+    pub fn s(&self) -> C::Scalar {
+        self.s
+    }
+}
+
+/// Maps the challenge $c$ into the scalar field, as the integer it represents
+/// reduced modulo $q$.
+///
+/// This matches the in-circuit interpretation of a base field element used as the
+/// scalar in variable-base scalar multiplication.
+pub fn challenge_to_scalar<C: CurveAffine>(c: C::Base) -> C::Scalar {
+    base_to_scalar::<C>(c)
+}
+
+/// Returns the verification key $[\mathsf{sk}] G$ for the signing key $\mathsf{sk}$.
+pub fn verification_key<C: CurveAffine>(g: C, sk: C::Scalar) -> C {
+    (g * sk).to_affine()
+}
+
+/// Computes the challenge $c = \mathsf{Poseidon}(x_R, y_R, x_\mathsf{vk}, y_\mathsf{vk}, m)$
+/// for the message digest $m$, using [`P128Pow5T3`].
+///
+/// The identity is hashed as $(0, 0)$, which is how it is represented in the circuit.
+pub fn poseidon_challenge<C: CurveAffine>(r: &C, vk: &C, message: C::Base) -> C::Base
+where
+    P128Pow5T3: Spec<C::Base, 3, 2>,
+{
+    let coordinates = |point: &C| {
+        Option::from(point.coordinates())
+            .map(|coordinates| (*coordinates.x(), *coordinates.y()))
+            .unwrap_or((C::Base::zero(), C::Base::zero()))
+    };
+    let (x_r, y_r) = coordinates(r);
+    let (x_vk, y_vk) = coordinates(vk);
+
+    Hash::init(P128Pow5T3, ConstantLength::<5>).hash([x_r, y_r, x_vk, y_vk, message])
+}
+
+/// Signs with the signing key $\mathsf{sk}$ and the nonce $\alpha$, which must be
+/// uniformly random and never reused.
+///
+/// `challenge` computes $c$ from $R = [\alpha] G$ and $\mathsf{vk}$, and binds the
+/// message being signed. The signature is $(R, s)$ with
+/// $s = \alpha + c \cdot \mathsf{sk}$.
+pub fn sign<C: CurveAffine>(
+    g: C,
+    sk: C::Scalar,
+    alpha: C::Scalar,
+    challenge: impl FnOnce(&C, &C) -> C::Base,
+) -> Signature<C> {
+    let r = (g * alpha).to_affine();
+    let vk = verification_key(g, sk);
+    let c = challenge_to_scalar::<C>(challenge(&r, &vk));
+    Signature {
+        r,
+        s: alpha + c * sk,
+    }
+}
+
+/// Checks that `signature` is valid for the verification key `vk`, i.e. that
+/// $[s] G = R + [c] \mathsf{vk}$ where `challenge` computes $c$ from $(R, \mathsf{vk})$.
+///
+/// The identity is rejected as a verification key, as it is by the gadget.
+pub fn verify<C: CurveAffine>(
+    g: C,
+    vk: C,
+    signature: &Signature<C>,
+    challenge: impl FnOnce(&C, &C) -> C::Base,
+) -> bool {
+    if bool::from(vk.is_identity()) {
+        return false;
+    }
+
+    let c = challenge_to_scalar::<C>(challenge(&signature.r, &vk));
+    g * signature.s == signature.r.to_curve() + vk * c
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        challenge_to_scalar, poseidon_challenge, sign, verification_key, verify, Signature,
+    };
+
+    use group::{prime::PrimeCurveAffine, Curve, Group};
+    use pasta_curves::{arithmetic::FieldExt, pallas, vesta};
+
+    #[test]
+    fn sign_and_verify() {
+        let g = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let sk = pallas::Scalar::rand();
+        let vk = verification_key(g, sk);
+        let m = pallas::Base::rand();
+        let challenge = |m: pallas::Base| {
+            move |r: &pallas::Affine, vk: &pallas::Affine| poseidon_challenge(r, vk, m)
+        };
+
+        let signature = sign(g, sk, pallas::Scalar::rand(), challenge(m));
+        assert!(verify(g, vk, &signature, challenge(m)));
+
+        // Wrong message
+        assert!(!verify(
+            g,
+            vk,
+            &signature,
+            challenge(m + pallas::Base::one())
+        ));
+
+        // Wrong verification key
+        let other_vk = verification_key(g, sk + pallas::Scalar::one());
+        assert!(!verify(g, other_vk, &signature, challenge(m)));
+
+        // Tampered signature
+        let tampered = Signature::from_parts(signature.r(), signature.s() + pallas::Scalar::one());
+        assert!(!verify(g, vk, &tampered, challenge(m)));
+
+        // Identity verification key
+        let signature = sign(
+            g,
+            pallas::Scalar::zero(),
+            pallas::Scalar::rand(),
+            challenge(m),
+        );
+        assert!(!verify(
+            g,
+            pallas::Affine::identity(),
+            &signature,
+            challenge(m)
+        ));
+    }
+
+    #[test]
+    fn poseidon_challenge_binds_points() {
+        let r = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let vk = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let m = pallas::Base::rand();
+        let c = poseidon_challenge(&r, &vk, m);
+
+        assert_ne!(poseidon_challenge(&vk, &r, m), c);
+        assert_ne!(poseidon_challenge(&-r, &vk, m), c);
+        assert_ne!(poseidon_challenge(&r, &-vk, m), c);
+
+        // The identity is hashed as (0, 0).
+        assert_eq!(
+            poseidon_challenge(&pallas::Affine::identity(), &vk, m),
+            poseidon::primitive::Hash::init(
+                poseidon::primitive::P128Pow5T3,
+                poseidon::primitive::ConstantLength::<5>
+            )
+            .hash([
+                pallas::Base::zero(),
+                pallas::Base::zero(),
+                *vk.coordinates().unwrap().x(),
+                *vk.coordinates().unwrap().y(),
+                m
+            ])
+        );
+    }
+
+    #[test]
+    fn challenge_reduction() {
+        // On Pallas, p < q, so the challenge is not reduced: p - 1 is not -1 mod q.
+        let c = -pallas::Base::one();
+        let expected = pallas::Scalar::from_bytes(&c.to_bytes()).unwrap();
+        assert_eq!(challenge_to_scalar::<pallas::Affine>(c), expected);
+        assert_ne!(
+            challenge_to_scalar::<pallas::Affine>(c),
+            -pallas::Scalar::one()
+        );
+
+        // On Vesta, p > q, so the challenge p - 1 is reduced to p - 1 - q.
+        let c = -vesta::Base::one();
+        let q = vesta::Base::from_bytes(&(-vesta::Scalar::one()).to_bytes()).unwrap()
+            + vesta::Base::one();
+        let expected = vesta::Scalar::from_bytes(&(c - q).to_bytes()).unwrap();
+        assert_eq!(challenge_to_scalar::<vesta::Affine>(c), expected);
+
+        // Signatures with such challenges verify on both curves.
+        let g = vesta::Point::random(rand::rngs::OsRng).to_affine();
+        let sk = vesta::Scalar::rand();
+        let signature = sign(g, sk, vesta::Scalar::rand(), |_, _| c);
+        assert!(verify(g, verification_key(g, sk), &signature, |_, _| c));
+
+        let c = -pallas::Base::one();
+        let g = pallas::Point::random(rand::rngs::OsRng).to_affine();
+        let sk = pallas::Scalar::rand();
+        let signature = sign(g, sk, pallas::Scalar::rand(), |_, _| c);
+        assert!(verify(g, verification_key(g, sk), &signature, |_, _| c));
+    }
+}
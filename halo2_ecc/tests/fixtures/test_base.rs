@@ -0,0 +1,1156 @@
+//! Constants for the fixed base `test_base`.
+//!
+//! Generated by `gen_fixed_base --name test_base --bytes 00000000ed302d991bf94c09fc98462200000000000000000000000000000040 --curve pallas --window-size 2 --short-widths 9,64`.
+//! Do not edit by hand.
+
+use halo2_ecc::{chip::compute_lagrange_coeffs, gadget::FixedPoints};
+use pasta_curves::{
+    arithmetic::{CurveAffine, FieldExt},
+    pallas,
+};
+
+/// Window size for fixed-base scalar multiplication
+pub const WINDOW_SIZE: usize = 2;
+
+/// Number of windows for a full-width scalar or a base field element
+pub const NUM_WINDOWS: usize = 128;
+
+/// Number of windows for a 9-bit short signed scalar
+pub const NUM_WINDOWS_SHORT_9: usize = 5;
+
+/// Number of windows for a 64-bit short signed scalar
+pub const NUM_WINDOWS_SHORT: usize = 32;
+
+/// Generator, as the encodings of its $x$- and $y$-coordinates
+pub const GENERATOR: ([u8; 32], [u8; 32]) = (
+    [0x00, 0x00, 0x00, 0x00, 0xed, 0x30, 0x2d, 0x99, 0x1b, 0xf9, 0x4c, 0x09, 0xfc, 0x98, 0x46, 0x22, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x40],
+    [0x02, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+);
+
+/// $z$-values for full-width windows
+pub const Z: [u64; NUM_WINDOWS] = [85, 467, 40, 82, 719, 933, 60, 434, 482, 180, 32, 839, 1444, 146, 611, 115, 106, 337, 321, 333, 75, 822, 3, 86, 304, 214, 139, 428, 729, 480, 1076, 94, 754, 20, 1, 179, 832, 79, 66, 231, 225, 18, 302, 78, 254, 458, 632, 45, 28, 83, 217, 130, 426, 110, 131, 279, 284, 37, 297, 475, 283, 197, 418, 24, 173, 214, 479, 138, 84, 106, 305, 37, 266, 393, 229, 146, 33, 72, 566, 364, 5, 217, 118, 370, 116, 547, 77, 74, 236, 89, 436, 265, 58, 337, 53, 8, 294, 542, 429, 181, 470, 233, 15, 29, 620, 779, 55, 234, 434, 184, 139, 510, 14, 423, 145, 216, 11, 239, 768, 108, 419, 19, 1138, 97, 212, 727, 352, 6];
+
+/// $u$-values for full-width windows
+pub const U: [[[u8; 32]; 4]; NUM_WINDOWS] = [
+    [
+        [0xa1, 0x60, 0xb0, 0x2c, 0x11, 0xc7, 0x54, 0xb8, 0x03, 0xe8, 0xb8, 0x76, 0x69, 0x61, 0x9c, 0x45, 0x48, 0xd4, 0xad, 0x37, 0xf7, 0x5f, 0xe9, 0xf1, 0x54, 0x71, 0xf3, 0xd6, 0x5c, 0x26, 0xfa, 0x1e],
+        [0xfc, 0xb5, 0x50, 0xdb, 0x70, 0xcd, 0xf9, 0x07, 0xb4, 0x7d, 0x93, 0x55, 0xb7, 0xc0, 0x25, 0x14, 0x4f, 0x47, 0x15, 0xbf, 0xa4, 0x9e, 0xb2, 0xd0, 0xe1, 0xad, 0x1b, 0x7b, 0xf7, 0x2c, 0x3b, 0x21],
+        [0xf5, 0xb7, 0x2e, 0x1a, 0x94, 0xc7, 0x7d, 0x8b, 0xaf, 0x20, 0x3d, 0x8f, 0xf1, 0x16, 0x3d, 0x38, 0xfe, 0x4f, 0xf0, 0xd5, 0x08, 0xe4, 0xb8, 0xec, 0x29, 0x9f, 0x87, 0xfe, 0x98, 0xd6, 0x6e, 0x3a],
+        [0x90, 0xdb, 0x5a, 0x59, 0x75, 0x8d, 0x87, 0xad, 0x21, 0xf3, 0xba, 0xa4, 0x53, 0x79, 0x4d, 0xe7, 0x18, 0xab, 0xfa, 0x8f, 0x7d, 0x86, 0x9f, 0xcb, 0x55, 0x61, 0x63, 0xe1, 0x5d, 0x73, 0xa8, 0x2e],
+    ],
+    [
+        [0x23, 0xfd, 0xd0, 0x4e, 0x0e, 0x99, 0xa0, 0x3b, 0x6d, 0xf4, 0x89, 0x13, 0x9f, 0x2d, 0xe1, 0xce, 0x69, 0x53, 0x69, 0x19, 0xff, 0x43, 0xbd, 0x3e, 0xd0, 0x48, 0x1f, 0xae, 0xf3, 0x7a, 0x9a, 0x12],
+        [0xb7, 0xa5, 0x42, 0x4f, 0xb0, 0xe8, 0x0f, 0x90, 0xa4, 0xff, 0xb4, 0x32, 0xb2, 0x00, 0x93, 0xaf, 0x88, 0xf8, 0x76, 0xdc, 0x35, 0x67, 0x75, 0xc3, 0x7d, 0x73, 0x8d, 0x6b, 0x4d, 0xe7, 0x1f, 0x1a],
+        [0xb1, 0x94, 0xcc, 0x7e, 0xcc, 0xfa, 0x47, 0x53, 0x61, 0x40, 0x68, 0x7d, 0x14, 0xd1, 0xa1, 0x11, 0x0e, 0xff, 0xef, 0xc0, 0xfe, 0x86, 0x58, 0x06, 0x65, 0xac, 0x03, 0x85, 0x6a, 0x91, 0x5d, 0x35],
+        [0xa6, 0x10, 0x1e, 0x19, 0x23, 0xaa, 0x99, 0x78, 0x06, 0xa8, 0x10, 0xd6, 0x7a, 0x6d, 0xbe, 0xa0, 0xb7, 0x13, 0x1a, 0xa4, 0x97, 0x2e, 0x97, 0xfa, 0xf1, 0x01, 0x75, 0x1c, 0x8d, 0x8c, 0x0c, 0x3a],
+    ],
+    [
+        [0x53, 0xf5, 0x31, 0x02, 0xa2, 0x67, 0x8c, 0xfc, 0x61, 0x45, 0xc8, 0xb3, 0x49, 0xd6, 0x85, 0xc6, 0xb1, 0xea, 0x94, 0x54, 0xe7, 0xe4, 0x44, 0xa9, 0x2e, 0x86, 0x80, 0x23, 0x8b, 0x09, 0x8c, 0x17],
+        [0x5f, 0x46, 0x47, 0x2a, 0x90, 0xae, 0x00, 0x8d, 0x54, 0x1b, 0x08, 0x91, 0x93, 0xec, 0x80, 0xd1, 0xd5, 0xc3, 0x1a, 0xd4, 0x02, 0xf5, 0x4c, 0x55, 0xca, 0x8c, 0xe5, 0x46, 0xe7, 0x6b, 0xd6, 0x21],
+        [0x74, 0xfa, 0xef, 0x80, 0x81, 0xac, 0xa8, 0x3c, 0x41, 0x75, 0x3e, 0xd6, 0x10, 0x72, 0x57, 0x51, 0x6f, 0xd1, 0xfe, 0x76, 0x28, 0xab, 0x59, 0xe3, 0x80, 0x30, 0x27, 0xf6, 0xe0, 0x29, 0xfb, 0x18],
+        [0xf3, 0xdf, 0xb5, 0xf8, 0x0a, 0x33, 0x5d, 0x75, 0x78, 0xd8, 0x3d, 0xb4, 0x93, 0xba, 0x00, 0x22, 0x3a, 0xe1, 0x37, 0x80, 0x58, 0xca, 0xd4, 0x5b, 0xda, 0x44, 0x86, 0x16, 0x10, 0x1f, 0xeb, 0x12],
+    ],
+    [
+        [0xcc, 0xb3, 0x06, 0x9a, 0xfd, 0xd9, 0xcf, 0x66, 0x07, 0xea, 0x8b, 0xa5, 0xf1, 0xff, 0x32, 0xbe, 0x3d, 0x40, 0xf6, 0x37, 0xd7, 0xdd, 0xe1, 0xa9, 0xc5, 0xd4, 0x6d, 0xa6, 0x6e, 0xd4, 0x89, 0x08],
+        [0x6c, 0x9a, 0x68, 0x45, 0x2f, 0x1b, 0x8b, 0xe3, 0x7e, 0x90, 0x16, 0xb7, 0xb1, 0xbe, 0x27, 0xa4, 0x5e, 0xe6, 0x18, 0x4b, 0xf6, 0xf1, 0x5c, 0x66, 0x41, 0xd6, 0x71, 0xd3, 0xb8, 0x68, 0xa2, 0x10],
+        [0xea, 0x3a, 0x77, 0xc2, 0x7c, 0x4a, 0xcb, 0xbb, 0xe6, 0x6d, 0x99, 0xb1, 0x43, 0xae, 0x92, 0xef, 0x4e, 0xda, 0x17, 0xbc, 0x0b, 0x73, 0xfd, 0x3d, 0xc0, 0x98, 0xb8, 0x3a, 0x42, 0x2f, 0x8e, 0x05],
+        [0x98, 0x79, 0x31, 0x52, 0x21, 0x8b, 0x4a, 0xcc, 0x5e, 0xf2, 0xa0, 0xd4, 0x27, 0x23, 0x02, 0x01, 0x2c, 0x22, 0xd3, 0x03, 0xa1, 0x18, 0xd7, 0x5b, 0x63, 0x60, 0x17, 0x5a, 0x81, 0x01, 0xc5, 0x0a],
+    ],
+    [
+        [0xdf, 0xd3, 0x08, 0x2b, 0x32, 0xb5, 0xda, 0xac, 0x88, 0xd5, 0xce, 0xc0, 0x66, 0x6b, 0xdf, 0x84, 0x43, 0x4b, 0x49, 0xc8, 0x8a, 0xdc, 0x9e, 0xb3, 0xe8, 0x9c, 0xe0, 0x5d, 0x2c, 0x7b, 0xe1, 0x21],
+        [0xdd, 0x73, 0x0c, 0xfb, 0x07, 0x56, 0x89, 0x53, 0x01, 0x89, 0x63, 0xdb, 0xba, 0x02, 0x59, 0x94, 0x75, 0xca, 0xc7, 0xbc, 0xac, 0x7f, 0xd2, 0x0b, 0xd1, 0xae, 0xb9, 0x8a, 0x6b, 0xaf, 0x1b, 0x12],
+        [0x32, 0x5e, 0x4c, 0xd6, 0x26, 0xec, 0xc2, 0xdc, 0xe5, 0xc0, 0xb4, 0xf5, 0x2b, 0xb0, 0xd3, 0xd3, 0xe2, 0x0c, 0xfd, 0xd2, 0x28, 0x0d, 0x83, 0x80, 0x55, 0x31, 0x92, 0x46, 0xd9, 0x23, 0x7a, 0x39],
+        [0x9c, 0xac, 0x1a, 0xca, 0x85, 0x65, 0x40, 0xb7, 0x64, 0xd8, 0xc7, 0x35, 0x8b, 0x35, 0xaf, 0xf0, 0x6a, 0xf1, 0xd2, 0xdf, 0xc2, 0x7d, 0xca, 0xdd, 0xf3, 0x26, 0xfc, 0x57, 0x47, 0x65, 0x3e, 0x00],
+    ],
+    [
+        [0x01, 0x71, 0x5d, 0x02, 0xec, 0xd2, 0x33, 0xb5, 0xe9, 0x14, 0x35, 0xe0, 0x68, 0xa0, 0xf1, 0x33, 0xd8, 0x47, 0x82, 0x7f, 0x83, 0xc5, 0xf8, 0x03, 0x41, 0x12, 0x1b, 0x17, 0x2b, 0x27, 0xaf, 0x0c],
+        [0x18, 0x34, 0x3b, 0x84, 0xd8, 0x2a, 0xb4, 0x17, 0x65, 0x5b, 0xdb, 0xaf, 0x5e, 0xb9, 0x0c, 0x10, 0x58, 0xf2, 0x25, 0x1e, 0x3b, 0x76, 0x88, 0x9f, 0x47, 0xf1, 0x50, 0xbc, 0x6b, 0x44, 0x20, 0x37],
+        [0xbe, 0xd6, 0xce, 0xbc, 0x07, 0xdd, 0x13, 0x63, 0xc6, 0x3a, 0x2b, 0x9d, 0x26, 0xfb, 0x58, 0x36, 0x1f, 0xd2, 0x0f, 0x3b, 0x7d, 0x26, 0xfa, 0x8f, 0x7e, 0xef, 0x51, 0x24, 0xcf, 0x7f, 0x61, 0x0f],
+        [0xb5, 0xbf, 0x42, 0x27, 0x49, 0x2c, 0xee, 0xb0, 0xe6, 0x17, 0x6d, 0x60, 0xda, 0x66, 0x68, 0x54, 0x9c, 0x7f, 0x5b, 0xd9, 0x44, 0x41, 0x5b, 0x82, 0xa5, 0x03, 0x7a, 0xa0, 0xb3, 0x56, 0xf2, 0x1f],
+    ],
+    [
+        [0xfa, 0x69, 0x41, 0xc1, 0xdf, 0xe4, 0x91, 0xcf, 0x30, 0x29, 0xc7, 0x4e, 0x70, 0xb7, 0xbe, 0x49, 0xc9, 0x5a, 0x55, 0x25, 0xe9, 0x96, 0x44, 0xc6, 0x39, 0xbb, 0xfd, 0x3e, 0xdf, 0xfb, 0xcb, 0x29],
+        [0x88, 0xf0, 0xab, 0x87, 0x27, 0x7a, 0x5e, 0x45, 0x8f, 0x31, 0x60, 0x00, 0x94, 0xe3, 0xfb, 0x7d, 0x15, 0x57, 0x1c, 0x8d, 0x77, 0x6d, 0x84, 0x32, 0x36, 0xe4, 0x51, 0x0f, 0x34, 0xa2, 0xa3, 0x00],
+        [0xd3, 0x86, 0x52, 0x5a, 0xfc, 0xa3, 0x8b, 0x05, 0x23, 0x98, 0xd7, 0x98, 0xa3, 0x14, 0x93, 0xd7, 0x87, 0x21, 0x96, 0x76, 0x9a, 0xe2, 0xad, 0x17, 0x54, 0x02, 0x3b, 0xc5, 0x28, 0x29, 0x7e, 0x07],
+        [0xad, 0xa6, 0xa1, 0xe0, 0x87, 0x37, 0x99, 0xf3, 0xa3, 0xad, 0x88, 0xe1, 0x8b, 0x57, 0x38, 0x1c, 0x34, 0x5a, 0x77, 0xc3, 0x5d, 0x2a, 0xd6, 0xb6, 0xcb, 0xc1, 0xe3, 0x5e, 0x0c, 0x3e, 0x35, 0x3a],
+    ],
+    [
+        [0xa1, 0x6b, 0x1f, 0xdd, 0x2d, 0x57, 0x0b, 0x3f, 0xc3, 0x9a, 0xd9, 0x36, 0x97, 0x82, 0x10, 0x7e, 0x54, 0x8c, 0x53, 0x34, 0x78, 0x33, 0x6b, 0xbe, 0x6e, 0x0a, 0xc2, 0xb0, 0xcc, 0xf7, 0xd0, 0x07],
+        [0x2e, 0x5a, 0x5d, 0x5a, 0x9a, 0x52, 0x6e, 0xc9, 0x27, 0xf5, 0xa0, 0xa4, 0xdd, 0x8f, 0x35, 0x16, 0x40, 0x70, 0x12, 0x74, 0xe0, 0x4a, 0x07, 0x98, 0x68, 0xc9, 0x6b, 0x82, 0x32, 0x6e, 0xbe, 0x09],
+        [0xb7, 0xc8, 0xed, 0x2c, 0xcf, 0xbf, 0x7b, 0x5d, 0x42, 0xaf, 0xbf, 0x7e, 0x99, 0xbd, 0x0c, 0x9d, 0xc8, 0x66, 0xeb, 0x52, 0x26, 0xc0, 0x7d, 0x2c, 0xb2, 0xd6, 0xc0, 0x3e, 0x73, 0x26, 0xc3, 0x15],
+        [0x1b, 0xe3, 0xd4, 0x66, 0x04, 0xbf, 0x7a, 0xbf, 0x5a, 0x80, 0xee, 0xf9, 0xe5, 0xc8, 0x28, 0x1e, 0x54, 0xdc, 0x13, 0x3b, 0xe3, 0x7c, 0xde, 0xb8, 0x39, 0xee, 0x36, 0xdb, 0x17, 0x10, 0x4e, 0x35],
+    ],
+    [
+        [0x8a, 0xac, 0xb5, 0x0e, 0x70, 0xc8, 0xa3, 0x00, 0xcd, 0x50, 0xa2, 0x0b, 0xdc, 0xaf, 0x5d, 0xe4, 0xfa, 0xa6, 0x52, 0x21, 0x7a, 0xfb, 0xc8, 0x9f, 0x25, 0xd5, 0x82, 0x11, 0xb6, 0xff, 0x42, 0x01],
+        [0x0d, 0xd6, 0x38, 0xc0, 0xac, 0x9b, 0x29, 0x42, 0x37, 0x8e, 0x9c, 0x5e, 0x95, 0x46, 0x89, 0x25, 0x7c, 0x0b, 0x7b, 0xd2, 0xe2, 0x62, 0x1c, 0x76, 0x24, 0x02, 0x55, 0xbb, 0x4c, 0x37, 0x47, 0x0f],
+        [0xc3, 0x6a, 0x36, 0xff, 0x78, 0x05, 0xe2, 0x4f, 0x4e, 0x63, 0xbd, 0x5d, 0x7e, 0x37, 0xd7, 0xf1, 0xd0, 0x0a, 0x2a, 0xf0, 0x98, 0x1c, 0xbd, 0x7e, 0xc5, 0xf7, 0xad, 0xcf, 0xbc, 0x54, 0x54, 0x2a],
+        [0x48, 0xcc, 0x57, 0x70, 0x56, 0x31, 0xde, 0x07, 0xcf, 0x09, 0xb6, 0xad, 0xe1, 0xc9, 0x30, 0x56, 0x42, 0x6b, 0x63, 0xe4, 0x25, 0xd8, 0x22, 0x37, 0x09, 0xa8, 0xad, 0x47, 0x19, 0x3f, 0x56, 0x07],
+    ],
+    [
+        [0x33, 0xdd, 0x39, 0x82, 0x06, 0xf2, 0xe1, 0x50, 0x29, 0x2b, 0xc6, 0xfb, 0x50, 0x6c, 0x81, 0x24, 0xe2, 0x8d, 0xc5, 0x87, 0xd4, 0x52, 0xb4, 0xd8, 0xfe, 0x6f, 0x96, 0xa4, 0xe3, 0x2b, 0x5f, 0x0b],
+        [0x60, 0xb1, 0x1b, 0xb0, 0x6b, 0x47, 0xee, 0x9e, 0x71, 0xd0, 0xfd, 0x93, 0xf0, 0x43, 0x45, 0x3d, 0xde, 0xa0, 0x31, 0xc1, 0x91, 0xd8, 0xa8, 0x98, 0xbc, 0xb7, 0x3b, 0x76, 0xdd, 0x52, 0x57, 0x0a],
+        [0xb9, 0x97, 0x31, 0xa3, 0x16, 0xa5, 0x34, 0x9e, 0x4d, 0x5c, 0x36, 0xff, 0x6a, 0x74, 0x65, 0xd9, 0xe9, 0xd4, 0xf9, 0x7a, 0xcb, 0xa5, 0xeb, 0xe9, 0x6d, 0x69, 0xa3, 0x24, 0xe8, 0x7f, 0x07, 0x2b],
+        [0xe4, 0x91, 0x2a, 0x14, 0x26, 0xab, 0x4f, 0x69, 0x9c, 0xcd, 0x24, 0xe0, 0x7a, 0xe4, 0x4f, 0x21, 0xa2, 0x69, 0xd9, 0x69, 0x4d, 0x7f, 0x99, 0xf8, 0x25, 0x8c, 0x28, 0x9e, 0xd7, 0xb4, 0x07, 0x22],
+    ],
+    [
+        [0x4f, 0x70, 0x45, 0x28, 0x1d, 0x4d, 0x91, 0xbb, 0x05, 0x3b, 0x4d, 0x29, 0x37, 0xe4, 0x37, 0xc1, 0xe9, 0x16, 0x35, 0x16, 0x53, 0x4d, 0x88, 0x61, 0x14, 0xfd, 0xee, 0x04, 0xcc, 0xaa, 0x11, 0x19],
+        [0x6d, 0x44, 0x99, 0x7e, 0x1a, 0x46, 0x9e, 0xdd, 0x81, 0xb8, 0x79, 0xdc, 0x9a, 0x1b, 0xf0, 0xb0, 0x34, 0x1f, 0x25, 0x85, 0xb9, 0xe1, 0x96, 0x85, 0xb3, 0xd5, 0x1d, 0x92, 0x88, 0x5d, 0x09, 0x04],
+        [0xc0, 0xea, 0xf3, 0x67, 0x17, 0xb5, 0xbf, 0x63, 0xc5, 0xc2, 0x33, 0x89, 0xc5, 0x59, 0x35, 0x63, 0x68, 0x26, 0x2a, 0xf2, 0x19, 0x28, 0xe4, 0xf4, 0xa7, 0xa1, 0xdf, 0x14, 0xf4, 0x1b, 0x30, 0x3b],
+        [0x3f, 0x04, 0x71, 0x7f, 0xf4, 0xde, 0xb4, 0x07, 0x01, 0xcc, 0x5b, 0x04, 0x92, 0x58, 0x5a, 0x21, 0x56, 0x62, 0xfe, 0x14, 0x52, 0x1c, 0x52, 0xab, 0x52, 0x55, 0x2d, 0xbf, 0x64, 0x5f, 0x01, 0x09],
+    ],
+    [
+        [0x0f, 0xdd, 0x06, 0xda, 0xac, 0x1e, 0x1c, 0x00, 0xa3, 0x22, 0xbc, 0xe0, 0x3a, 0x40, 0x73, 0x85, 0xc7, 0x90, 0x2a, 0x24, 0x4a, 0x5c, 0xc4, 0x2b, 0xb7, 0x78, 0xf3, 0x85, 0xb5, 0x0f, 0xc0, 0x0a],
+        [0xcb, 0xef, 0xb4, 0x1f, 0xc3, 0xd0, 0x32, 0x2c, 0xa1, 0x7e, 0xb7, 0xbb, 0x94, 0xa2, 0x26, 0x9f, 0x72, 0x25, 0xba, 0xc5, 0xdd, 0x97, 0x7e, 0xec, 0xd0, 0xaf, 0x48, 0x11, 0xe1, 0x67, 0x8e, 0x22],
+        [0x3e, 0x08, 0x08, 0x32, 0xb5, 0x02, 0xc5, 0xe2, 0x75, 0x41, 0xe2, 0x89, 0x6f, 0xed, 0xac, 0xc1, 0x56, 0x0c, 0x38, 0xcb, 0xb2, 0x1a, 0x5b, 0x57, 0xd4, 0x03, 0xc4, 0x95, 0x7c, 0xe0, 0xd3, 0x27],
+        [0x0e, 0x4e, 0xa6, 0x91, 0xb4, 0x80, 0x09, 0x16, 0x9b, 0xf6, 0xa7, 0xaf, 0x84, 0x1d, 0xc3, 0xfd, 0x85, 0x12, 0xb0, 0x34, 0x78, 0xc3, 0x09, 0x15, 0x47, 0x84, 0xd8, 0xa7, 0x87, 0xb1, 0xea, 0x0e],
+    ],
+    [
+        [0xce, 0x9b, 0x81, 0x02, 0xbd, 0x2b, 0x77, 0x1e, 0x36, 0x98, 0x70, 0xb9, 0x36, 0x81, 0x32, 0x61, 0xf3, 0xd4, 0xf1, 0x8a, 0x15, 0x3f, 0x1b, 0xde, 0xe3, 0x9e, 0xd7, 0x75, 0xb8, 0xbd, 0xb7, 0x33],
+        [0xaa, 0xbb, 0x6b, 0x48, 0x34, 0x7a, 0x4f, 0xbd, 0x05, 0x7f, 0x33, 0x10, 0x0c, 0xda, 0x01, 0x42, 0xbe, 0x8a, 0x8c, 0x11, 0x0e, 0xbf, 0x33, 0xce, 0xa9, 0xce, 0x9b, 0xb0, 0x7f, 0x87, 0xac, 0x1b],
+        [0xda, 0x5a, 0x46, 0xc3, 0x39, 0xc9, 0xd5, 0x22, 0x7c, 0x48, 0x21, 0x4d, 0x5e, 0xa1, 0x40, 0x7a, 0x70, 0xeb, 0xb2, 0xde, 0x79, 0xe9, 0xd4, 0x33, 0x91, 0xd7, 0x1e, 0x6b, 0x74, 0x21, 0x56, 0x3c],
+        [0x09, 0x25, 0x31, 0x16, 0xde, 0xaa, 0x49, 0xb4, 0x19, 0x3d, 0x1e, 0x76, 0x2c, 0xd1, 0xfb, 0x9a, 0xf8, 0x1a, 0x39, 0xeb, 0x34, 0x0c, 0xb0, 0x16, 0xbb, 0xdb, 0x59, 0x54, 0x3d, 0x67, 0x69, 0x20],
+    ],
+    [
+        [0x44, 0xfe, 0x2e, 0x79, 0xa5, 0x48, 0xdf, 0x4a, 0xf4, 0xe8, 0xc6, 0x35, 0x5a, 0x51, 0xe4, 0xe5, 0x70, 0xe6, 0x4c, 0xd5, 0x58, 0xac, 0xd7, 0x88, 0x32, 0xd9, 0x04, 0xd7, 0x2a, 0x05, 0x81, 0x0a],
+        [0xce, 0x3f, 0xce, 0xf2, 0x46, 0x61, 0x74, 0x45, 0x37, 0xdd, 0x0a, 0x2f, 0xda, 0xa8, 0x52, 0xae, 0xc9, 0x12, 0x46, 0x50, 0x74, 0xb8, 0x83, 0x31, 0x99, 0x3e, 0xa0, 0xe7, 0x59, 0xd1, 0x10, 0x03],
+        [0x2b, 0x4c, 0x61, 0xb3, 0x6c, 0x69, 0x23, 0x25, 0xd8, 0xa4, 0x95, 0xcb, 0x85, 0xb0, 0x38, 0xb3, 0xd3, 0xc6, 0x10, 0x3c, 0x97, 0x83, 0x0f, 0xd2, 0x41, 0x70, 0xa2, 0x7b, 0x8e, 0xe1, 0x1b, 0x38],
+        [0x24, 0xbe, 0x68, 0x2a, 0xa4, 0x27, 0x4f, 0xee, 0x42, 0xfc, 0xdf, 0x05, 0x8d, 0xfb, 0xfb, 0xd5, 0x1c, 0x38, 0xe4, 0xc7, 0xe4, 0xcc, 0x49, 0x68, 0x55, 0x88, 0x66, 0x6b, 0xe2, 0x37, 0x2c, 0x24],
+    ],
+    [
+        [0x55, 0xf8, 0x49, 0x0b, 0x1c, 0x44, 0x52, 0x8e, 0x81, 0x64, 0x42, 0x19, 0x00, 0x03, 0xf7, 0x47, 0xd3, 0x54, 0x6a, 0x67, 0x1f, 0xb6, 0x78, 0xc1, 0x89, 0xdc, 0xd0, 0x46, 0x47, 0x6f, 0xcb, 0x39],
+        [0xd6, 0x25, 0xff, 0x84, 0xff, 0x89, 0xad, 0x83, 0xfe, 0x94, 0xf5, 0xe9, 0xc7, 0xdd, 0xcb, 0xbf, 0x84, 0xe7, 0x6d, 0x4c, 0x75, 0xb4, 0x2f, 0xc3, 0xec, 0x6d, 0xeb, 0xe5, 0xdd, 0xa8, 0xbb, 0x19],
+        [0x93, 0x0a, 0x58, 0xa3, 0xda, 0x01, 0xe6, 0x5d, 0x62, 0xfa, 0x07, 0xe5, 0xac, 0xa0, 0xe3, 0x19, 0xdf, 0x0f, 0x60, 0x1a, 0x3d, 0x6e, 0xd7, 0x16, 0xb5, 0xf8, 0xf2, 0xd4, 0x28, 0x96, 0x85, 0x1d],
+        [0xac, 0x33, 0x5d, 0x0d, 0x6d, 0x42, 0xd2, 0xd3, 0x3b, 0xae, 0x66, 0x25, 0xea, 0x37, 0x44, 0x3e, 0x20, 0x7c, 0xec, 0x8b, 0x7c, 0xd4, 0xaf, 0x5d, 0xbc, 0x86, 0x3a, 0xd5, 0x16, 0x99, 0xaa, 0x07],
+    ],
+    [
+        [0x69, 0x0e, 0x4f, 0xc5, 0xe5, 0xcc, 0xda, 0x48, 0x9b, 0xfb, 0x98, 0x2c, 0xb6, 0x14, 0x0a, 0x42, 0x33, 0x9a, 0x30, 0xc7, 0x45, 0x43, 0x6a, 0x0d, 0x75, 0xa2, 0xf9, 0xf3, 0x82, 0x3f, 0x60, 0x14],
+        [0xb7, 0x1e, 0x96, 0x9d, 0xc5, 0x62, 0xa0, 0x1d, 0x9a, 0x5d, 0xd1, 0xb9, 0x93, 0x03, 0xc1, 0xe5, 0x8b, 0x0b, 0x77, 0xde, 0x91, 0xb7, 0xd1, 0x3c, 0x6d, 0xc2, 0x47, 0x07, 0x2d, 0xe2, 0x64, 0x1b],
+        [0x05, 0x7f, 0xb9, 0xa3, 0x88, 0x96, 0x92, 0x21, 0x88, 0x10, 0x8d, 0xab, 0xc5, 0xc7, 0xa5, 0x8a, 0x2a, 0x65, 0x50, 0xe7, 0x91, 0x81, 0x22, 0xa3, 0xd9, 0x9d, 0x72, 0xfa, 0x06, 0x6d, 0x58, 0x21],
+        [0xa0, 0x6d, 0x1c, 0x28, 0xfb, 0x02, 0x00, 0xf5, 0x03, 0x37, 0xda, 0xaf, 0x8d, 0x18, 0xf1, 0x38, 0x8d, 0x15, 0x50, 0xd7, 0x44, 0x1f, 0x64, 0x7d, 0xeb, 0xef, 0xd8, 0x16, 0xa7, 0x2d, 0x9d, 0x28],
+    ],
+    [
+        [0x28, 0x57, 0x5f, 0x1e, 0xc0, 0xa1, 0x49, 0x0c, 0xb7, 0x94, 0xe1, 0xe3, 0x40, 0xfa, 0x57, 0x0e, 0xfd, 0xc9, 0x8c, 0x92, 0x91, 0x63, 0x11, 0xec, 0x9a, 0x27, 0x90, 0xae, 0x76, 0x27, 0xe9, 0x2a],
+        [0x8c, 0x93, 0xe5, 0x1c, 0xdd, 0xaf, 0x28, 0x65, 0x77, 0x4b, 0xc5, 0xcd, 0xe1, 0x6f, 0x37, 0xa8, 0x46, 0x1d, 0xe0, 0xfb, 0xb5, 0x41, 0x53, 0xb3, 0x8c, 0x96, 0xe2, 0x23, 0xf6, 0xbb, 0x53, 0x11],
+        [0x93, 0xe0, 0x2a, 0xb5, 0xf1, 0xe9, 0xfa, 0xe4, 0xed, 0x2f, 0x1d, 0xa6, 0xbb, 0x9b, 0x82, 0x2a, 0xe3, 0x8a, 0x5c, 0x31, 0x76, 0x4c, 0xb8, 0x76, 0xdd, 0x04, 0x32, 0x26, 0x7b, 0x6d, 0x1c, 0x00],
+        [0xb8, 0xa6, 0x2e, 0x0d, 0x91, 0x7b, 0x24, 0x62, 0xd0, 0xc5, 0x51, 0x0e, 0x4f, 0x4b, 0xdf, 0x3c, 0xb0, 0x96, 0xf1, 0xaa, 0x69, 0x45, 0xd0, 0x19, 0x3e, 0x7d, 0x19, 0x4f, 0x5f, 0x65, 0x57, 0x1c],
+    ],
+    [
+        [0xff, 0xd6, 0x4c, 0xfb, 0x3c, 0x74, 0x15, 0xec, 0xe8, 0xe3, 0x3d, 0x33, 0x69, 0xd7, 0xfa, 0xd2, 0x19, 0x42, 0xec, 0xa7, 0xba, 0x37, 0x1f, 0xc1, 0x85, 0x96, 0xd6, 0x65, 0xe3, 0xb4, 0xa1, 0x29],
+        [0xc3, 0x4e, 0xba, 0x5c, 0x7e, 0xa1, 0x85, 0x42, 0xaa, 0x91, 0x01, 0x16, 0x0c, 0xe8, 0x82, 0xfa, 0xc8, 0xc6, 0xbb, 0xbd, 0xef, 0xc6, 0xd9, 0x09, 0xbd, 0x72, 0x62, 0xce, 0x95, 0xbf, 0xa3, 0x24],
+        [0xf9, 0x13, 0x60, 0x36, 0x91, 0xe6, 0x76, 0xe2, 0x47, 0x2c, 0x63, 0x71, 0x05, 0xb4, 0xc5, 0xe5, 0x43, 0x2c, 0x74, 0xe3, 0xb5, 0x55, 0xa9, 0xdf, 0xa0, 0x7e, 0xcc, 0x01, 0xf8, 0x4b, 0x6c, 0x14],
+        [0xa9, 0x9d, 0x1c, 0x61, 0x4a, 0x4e, 0x86, 0x36, 0xbc, 0xbb, 0x8e, 0x64, 0x86, 0x92, 0xff, 0x02, 0x6a, 0x68, 0x12, 0x8e, 0xc7, 0x6c, 0x03, 0xc4, 0xaa, 0x40, 0xf8, 0xda, 0x67, 0x30, 0xe3, 0x1d],
+    ],
+    [
+        [0x04, 0x0f, 0x5a, 0xb8, 0x77, 0x94, 0x8f, 0x67, 0x7a, 0x90, 0xe3, 0xbe, 0xfb, 0xfc, 0xb4, 0xc0, 0x23, 0x9e, 0x17, 0x81, 0xfc, 0x2e, 0x89, 0x2a, 0x6c, 0xe7, 0xa4, 0x45, 0x81, 0x6b, 0xbc, 0x0b],
+        [0xda, 0x47, 0xe4, 0x35, 0x5a, 0xaf, 0xc0, 0x55, 0x4c, 0xc2, 0x81, 0x8b, 0x33, 0x63, 0xdc, 0x41, 0x5a, 0x3c, 0xeb, 0xd3, 0x47, 0x55, 0x2f, 0x83, 0x00, 0x49, 0x0c, 0xa2, 0x61, 0x84, 0x96, 0x11],
+        [0x3b, 0x6b, 0x41, 0xae, 0xe7, 0xb0, 0xb1, 0x18, 0xed, 0x43, 0xcd, 0x50, 0x12, 0x83, 0xde, 0x1a, 0xdf, 0xd0, 0x68, 0x22, 0xad, 0xae, 0xa1, 0x05, 0x53, 0x95, 0x73, 0x9c, 0x66, 0xd8, 0xb4, 0x24],
+        [0x14, 0xb8, 0x26, 0x7b, 0x31, 0x27, 0x31, 0xfa, 0x8a, 0x74, 0xa8, 0x0a, 0x2a, 0x27, 0xae, 0xc9, 0x9d, 0x72, 0x80, 0x8c, 0xfc, 0xde, 0xc9, 0xf9, 0x6d, 0x14, 0x77, 0x43, 0x57, 0xca, 0x57, 0x0f],
+    ],
+    [
+        [0xcf, 0x29, 0xc6, 0x2f, 0xc1, 0x5f, 0x37, 0xc5, 0xaf, 0x0c, 0x7b, 0x6e, 0xc8, 0x07, 0x30, 0x6c, 0x36, 0xc1, 0x0c, 0xc2, 0xaf, 0xb5, 0xc6, 0x73, 0xcc, 0xbe, 0x24, 0xe1, 0x9c, 0x4d, 0x4e, 0x08],
+        [0x27, 0xb0, 0x52, 0x0f, 0xf0, 0xda, 0x5e, 0xd8, 0xc2, 0x73, 0x7d, 0x5e, 0x8f, 0x38, 0xcb, 0x65, 0xa2, 0xc5, 0xf8, 0xe7, 0x51, 0xce, 0x60, 0x0f, 0x03, 0x9a, 0x0e, 0xbd, 0xe5, 0x4e, 0x2b, 0x23],
+        [0x04, 0x99, 0xd9, 0xa1, 0x3c, 0xaf, 0x26, 0x8f, 0x2d, 0x74, 0x9c, 0x38, 0xe8, 0xa0, 0xc5, 0x93, 0xd7, 0x4f, 0xa1, 0x22, 0x60, 0x4d, 0x60, 0x09, 0x4f, 0xc3, 0x3f, 0x6d, 0x3b, 0xee, 0xf2, 0x2a],
+        [0x31, 0x6c, 0x6a, 0x11, 0x81, 0x24, 0xd4, 0xd0, 0x11, 0x49, 0x2e, 0x80, 0x21, 0x45, 0xa4, 0x94, 0xfe, 0xb4, 0x55, 0x6e, 0x28, 0xfa, 0x49, 0xc0, 0xe6, 0xfd, 0x30, 0x05, 0x2d, 0x6b, 0x0c, 0x1c],
+    ],
+    [
+        [0x7d, 0xd7, 0x0a, 0x8b, 0x36, 0xc2, 0x9a, 0xd2, 0xcd, 0x99, 0xa1, 0x96, 0x1f, 0x8d, 0x79, 0x61, 0xb9, 0x10, 0x6e, 0x60, 0xc8, 0xe0, 0xd1, 0x2e, 0x5a, 0xd0, 0x77, 0xcc, 0xfb, 0x2b, 0x06, 0x16],
+        [0xab, 0x7e, 0xd1, 0xf6, 0x1b, 0xf3, 0x8f, 0x93, 0x30, 0x49, 0x07, 0x8f, 0x88, 0x09, 0x0d, 0xb8, 0xa1, 0x22, 0x47, 0x00, 0xea, 0xdf, 0x7a, 0xe1, 0x82, 0x97, 0xca, 0x9b, 0x9e, 0xd8, 0xa5, 0x36],
+        [0xe6, 0xa1, 0x0d, 0x3e, 0xfb, 0x7c, 0x74, 0x87, 0xd4, 0xfe, 0x71, 0x58, 0x85, 0xcc, 0x2a, 0x00, 0x14, 0x78, 0xde, 0x2e, 0x5f, 0xcc, 0xd5, 0x82, 0x4f, 0xbc, 0x38, 0xdd, 0x72, 0xb7, 0x1f, 0x02],
+        [0x42, 0xa0, 0x65, 0xe7, 0xc4, 0x3e, 0xc2, 0xa2, 0x98, 0x03, 0xe3, 0xf4, 0x46, 0x26, 0x41, 0x4f, 0x50, 0xb3, 0xc4, 0xf2, 0x12, 0x56, 0x6c, 0xaf, 0x81, 0x73, 0xd1, 0x97, 0x1e, 0xb6, 0x90, 0x3d],
+    ],
+    [
+        [0xfd, 0x61, 0x29, 0x97, 0xd0, 0xb5, 0xf2, 0xd2, 0xa3, 0x5e, 0x88, 0x21, 0xe2, 0xda, 0xed, 0xe2, 0x45, 0xaa, 0x7b, 0xc4, 0x74, 0x8c, 0x43, 0xab, 0xde, 0x6d, 0x6f, 0xe1, 0x64, 0xa9, 0x2e, 0x19],
+        [0xd6, 0x4b, 0x4b, 0x2b, 0x3a, 0x6a, 0xb6, 0x43, 0x33, 0xb1, 0x4f, 0x65, 0xe0, 0x1c, 0x0b, 0x2d, 0x8d, 0x98, 0x3d, 0xa4, 0x8b, 0xd2, 0xc8, 0xb5, 0x4c, 0xc6, 0xc1, 0x04, 0x48, 0x79, 0x4e, 0x25],
+        [0xfd, 0x35, 0xbf, 0x33, 0x47, 0x00, 0x7d, 0xf4, 0x1a, 0x65, 0x01, 0x80, 0x19, 0xce, 0xf2, 0x7c, 0x4a, 0x5e, 0xf7, 0x76, 0x09, 0x2c, 0xa4, 0x28, 0x82, 0x09, 0x0c, 0xd3, 0xbe, 0xaf, 0xeb, 0x1b],
+        [0x76, 0x86, 0xca, 0x7b, 0xe1, 0x42, 0x3f, 0x05, 0xbe, 0xf1, 0x9a, 0x06, 0x56, 0x22, 0xac, 0x80, 0xe0, 0x0a, 0x0a, 0x95, 0x34, 0xb6, 0x40, 0x6b, 0x1e, 0x16, 0x56, 0xf1, 0x1c, 0x7f, 0x19, 0x3b],
+    ],
+    [
+        [0xbf, 0xf8, 0xe0, 0xe9, 0x62, 0xbe, 0x48, 0x13, 0x3e, 0x93, 0x4e, 0xd0, 0xe2, 0x40, 0x2e, 0x4b, 0x56, 0x6e, 0x64, 0x05, 0xf6, 0x1a, 0x1f, 0x16, 0xb7, 0x7c, 0xd2, 0x80, 0x02, 0xf2, 0xf1, 0x0a],
+        [0x2c, 0x00, 0x0a, 0x3a, 0xa2, 0x74, 0xfe, 0x5e, 0x0b, 0x4b, 0xa6, 0xca, 0x3d, 0xb7, 0xda, 0x40, 0x4f, 0xa0, 0x40, 0xef, 0xe6, 0x6f, 0xfe, 0xdb, 0xfa, 0x23, 0x60, 0x72, 0x07, 0xe1, 0x7c, 0x2c],
+        [0x1e, 0x0f, 0xaf, 0xcd, 0x30, 0x5d, 0xbf, 0x63, 0x0c, 0x8d, 0x09, 0xfa, 0xd0, 0x39, 0xbf, 0x13, 0xc7, 0x30, 0xb8, 0x3b, 0xa7, 0xbc, 0x26, 0xb5, 0x0f, 0xac, 0x92, 0x5b, 0xd7, 0xd6, 0xdf, 0x36],
+        [0xba, 0xfa, 0x3d, 0x21, 0xe0, 0xf5, 0x4d, 0x54, 0x82, 0x3f, 0x31, 0x4b, 0x3a, 0x23, 0x15, 0x13, 0x48, 0xe1, 0x18, 0xd4, 0x2b, 0x0a, 0x8e, 0xb1, 0x2e, 0x91, 0x93, 0x00, 0xf7, 0x14, 0xc5, 0x22],
+    ],
+    [
+        [0xdc, 0x41, 0x30, 0x21, 0x33, 0x1e, 0x2e, 0x0d, 0x94, 0x33, 0x39, 0x9b, 0xcc, 0x17, 0xc3, 0xf3, 0xf0, 0x5f, 0x63, 0x0d, 0x86, 0x11, 0x77, 0x01, 0x70, 0x81, 0xe8, 0x32, 0xa8, 0xaf, 0x57, 0x09],
+        [0x8b, 0x0c, 0x75, 0x42, 0xeb, 0xd1, 0x4a, 0xfc, 0x25, 0xff, 0x61, 0x08, 0x6b, 0x1a, 0xb1, 0x45, 0xdf, 0xe8, 0x2a, 0x54, 0x27, 0x99, 0xe1, 0x52, 0xbd, 0xd8, 0xe6, 0x1a, 0x55, 0x1b, 0x32, 0x16],
+        [0x4d, 0xac, 0xcb, 0xb3, 0xa0, 0x43, 0xc6, 0x1f, 0x31, 0x42, 0xec, 0x01, 0x28, 0x5e, 0x0d, 0x9d, 0xf4, 0x6a, 0x05, 0xa5, 0x81, 0x4e, 0x9d, 0x78, 0xd0, 0x94, 0x61, 0x24, 0x56, 0x78, 0x97, 0x08],
+        [0x83, 0x58, 0xf5, 0x1f, 0x90, 0x4d, 0x89, 0x02, 0x22, 0xe5, 0xae, 0x40, 0x93, 0x5a, 0x2b, 0xec, 0x89, 0x0e, 0x77, 0xd1, 0x08, 0x9c, 0x87, 0x05, 0xf9, 0xab, 0x3e, 0x7a, 0xa4, 0xf6, 0x6c, 0x3f],
+    ],
+    [
+        [0xef, 0x6b, 0x95, 0x94, 0x8b, 0x88, 0xbf, 0x9c, 0x7d, 0x5b, 0xd7, 0xf4, 0xc0, 0xc6, 0xcf, 0x5f, 0x08, 0xee, 0xa0, 0x5b, 0x26, 0x4d, 0xde, 0xdb, 0x49, 0x17, 0x81, 0x9d, 0x20, 0xad, 0x29, 0x2f],
+        [0x36, 0x3c, 0x8f, 0xce, 0x7c, 0x23, 0xb6, 0xbf, 0xb8, 0x18, 0x00, 0xcc, 0xd1, 0x9c, 0x71, 0x53, 0x50, 0x30, 0x53, 0x5d, 0x34, 0x58, 0xd3, 0xfa, 0x8f, 0xc3, 0xff, 0x12, 0x46, 0x2c, 0x9d, 0x0c],
+        [0x39, 0x3e, 0xa6, 0x18, 0x23, 0x30, 0xcd, 0x31, 0x49, 0x29, 0xb0, 0x29, 0xdf, 0x31, 0x6c, 0x6d, 0x05, 0x53, 0x41, 0x57, 0x86, 0x8d, 0xef, 0x9f, 0xff, 0xa2, 0x17, 0x47, 0x08, 0x55, 0xd9, 0x14],
+        [0x88, 0x45, 0x56, 0x25, 0xf2, 0x72, 0x47, 0x62, 0x8c, 0x0c, 0xd2, 0x8f, 0x7e, 0xd1, 0xf2, 0xa2, 0x23, 0xbc, 0xba, 0x3d, 0x8a, 0xf7, 0x5c, 0x64, 0x97, 0x57, 0x73, 0x1f, 0x77, 0xa7, 0x7c, 0x39],
+    ],
+    [
+        [0x59, 0xfa, 0xc0, 0xff, 0x74, 0x93, 0xb5, 0xf3, 0x7c, 0x0a, 0xcb, 0xed, 0x97, 0x83, 0x3c, 0x78, 0xec, 0x69, 0x0e, 0x25, 0xb5, 0x0e, 0xce, 0xb3, 0x04, 0x43, 0x62, 0xbe, 0xe3, 0xa4, 0xf5, 0x3e],
+        [0xfe, 0x66, 0x0e, 0xe6, 0xd4, 0x23, 0xac, 0xb3, 0x45, 0xbc, 0x5f, 0xcb, 0x22, 0x7a, 0x34, 0x5f, 0xe0, 0x7c, 0x23, 0x58, 0x14, 0xac, 0x2c, 0x54, 0x8b, 0x5e, 0x0a, 0x7c, 0xaf, 0x94, 0xcc, 0x11],
+        [0xb6, 0x85, 0x9b, 0x67, 0x2a, 0xa3, 0xe5, 0xf6, 0xc7, 0x71, 0xc6, 0x13, 0xa0, 0xa2, 0x66, 0xdd, 0x16, 0xb9, 0xf3, 0xa9, 0x8a, 0x74, 0xb5, 0x0b, 0x17, 0x38, 0xd9, 0x03, 0x2f, 0xdb, 0x1a, 0x22],
+        [0x3c, 0x65, 0xc0, 0x6e, 0xae, 0x40, 0xa1, 0xca, 0xf8, 0x38, 0x8f, 0x81, 0x1a, 0x2a, 0x6e, 0x45, 0x9d, 0x36, 0xf8, 0xb6, 0x8b, 0xd7, 0xc7, 0x65, 0xe3, 0xc5, 0x04, 0xb5, 0x03, 0xd1, 0x45, 0x1d],
+    ],
+    [
+        [0x87, 0xd6, 0x02, 0x61, 0x57, 0xc0, 0xcb, 0x57, 0xd6, 0xf3, 0xdf, 0xdf, 0x60, 0xbd, 0x52, 0x65, 0xa2, 0x14, 0xf2, 0x48, 0xca, 0xbe, 0xc2, 0x71, 0x65, 0xb2, 0x08, 0x02, 0x9f, 0x31, 0x40, 0x36],
+        [0x61, 0xd6, 0x92, 0x4d, 0x13, 0x0b, 0xbc, 0xf4, 0x81, 0x8f, 0x89, 0x17, 0x53, 0x76, 0x9e, 0xfa, 0x2e, 0x06, 0xf6, 0xff, 0xa5, 0xda, 0x02, 0x8b, 0x44, 0x80, 0xdc, 0x40, 0x1b, 0x5b, 0x24, 0x32],
+        [0x4b, 0xce, 0x72, 0x9b, 0xf8, 0xd5, 0x53, 0x4d, 0xb8, 0xdc, 0x47, 0x64, 0x4f, 0x37, 0xe7, 0xad, 0x85, 0x2b, 0x04, 0x49, 0x15, 0x4e, 0x2b, 0xd3, 0x4e, 0x68, 0x90, 0xf9, 0x11, 0xf5, 0xcd, 0x2a],
+        [0x73, 0x6d, 0xc2, 0x4d, 0x40, 0x85, 0x31, 0x06, 0x2d, 0x01, 0x38, 0x11, 0x19, 0x77, 0x61, 0x62, 0x16, 0x82, 0xf3, 0x97, 0x21, 0x04, 0x81, 0x02, 0xcd, 0x1b, 0xaf, 0xe0, 0x33, 0x5f, 0xd5, 0x16],
+    ],
+    [
+        [0x57, 0x32, 0xcb, 0x5e, 0x40, 0xf5, 0x09, 0xcd, 0xab, 0x02, 0x04, 0x54, 0xc4, 0xf2, 0x20, 0x2d, 0x8f, 0x53, 0xc8, 0x50, 0x7a, 0xb1, 0x13, 0x92, 0x31, 0x65, 0xaa, 0x6e, 0xf2, 0x61, 0x6c, 0x1e],
+        [0xa1, 0x4c, 0x75, 0xdb, 0x61, 0x1b, 0x55, 0x0d, 0x07, 0xb3, 0xdd, 0xe7, 0x10, 0xc1, 0xc3, 0xa3, 0xff, 0x7f, 0x23, 0xef, 0x0f, 0x46, 0xf4, 0xd0, 0xbb, 0xde, 0x7c, 0xe6, 0x46, 0x6c, 0x18, 0x07],
+        [0x03, 0xb8, 0x3c, 0x0d, 0x63, 0xc8, 0x1d, 0x80, 0x09, 0x65, 0xc3, 0xf9, 0x0e, 0x4a, 0xbd, 0x07, 0xdc, 0xf0, 0x14, 0x12, 0xc9, 0xe9, 0xf8, 0x46, 0xd2, 0xf4, 0xb4, 0xcf, 0x43, 0x4b, 0xd7, 0x3c],
+        [0x8c, 0xc3, 0x61, 0xf5, 0xa6, 0xcd, 0x59, 0x4b, 0x87, 0x73, 0x24, 0xf5, 0x8e, 0x1b, 0x9f, 0x2d, 0x1b, 0x6f, 0x7e, 0x31, 0xdd, 0xf4, 0x3d, 0x5a, 0x24, 0x5d, 0xb9, 0x58, 0x0b, 0x90, 0x92, 0x33],
+    ],
+    [
+        [0xeb, 0xd4, 0x9f, 0x76, 0x04, 0x5d, 0x00, 0xb3, 0x12, 0xa0, 0x5c, 0x30, 0x4c, 0x03, 0x63, 0x7d, 0x84, 0x8d, 0xf0, 0x4d, 0x45, 0x79, 0x7d, 0x8b, 0x90, 0x09, 0xbf, 0x40, 0x30, 0x34, 0x0e, 0x13],
+        [0xc2, 0xca, 0xb0, 0xa1, 0xf1, 0xb1, 0x5f, 0x03, 0x29, 0x9a, 0xc3, 0x4a, 0x02, 0x2c, 0x7a, 0x2f, 0xd9, 0xf0, 0x97, 0xfd, 0x94, 0x03, 0x08, 0x33, 0xfe, 0x87, 0x5a, 0x4b, 0x91, 0x83, 0x00, 0x0e],
+        [0x47, 0x92, 0x85, 0x74, 0xe6, 0xdf, 0xdf, 0x91, 0xb0, 0x8e, 0xca, 0x8e, 0xf0, 0x16, 0xf3, 0x14, 0xce, 0xa0, 0x3c, 0x5a, 0x49, 0x4f, 0xd6, 0x58, 0xc4, 0x0e, 0x3f, 0x35, 0xbf, 0xaf, 0x93, 0x1f],
+        [0x72, 0x58, 0xd5, 0xdc, 0x09, 0xdb, 0x1b, 0x6f, 0x94, 0xaf, 0x48, 0x0a, 0xa5, 0x5b, 0x1b, 0x1e, 0x8f, 0x24, 0xe1, 0x3b, 0xe4, 0x6f, 0xc6, 0x2e, 0x1f, 0x4c, 0xb6, 0x87, 0x1d, 0x25, 0xd1, 0x39],
+    ],
+    [
+        [0x02, 0x89, 0x1c, 0xe5, 0xeb, 0x50, 0x4f, 0x48, 0x1e, 0xdb, 0xca, 0x2e, 0x49, 0xfd, 0x9d, 0x6b, 0xdc, 0x58, 0x83, 0x1e, 0x98, 0x8b, 0x4e, 0x86, 0x81, 0x0e, 0xdf, 0x0e, 0x27, 0xf6, 0x7f, 0x00],
+        [0x92, 0xb7, 0x4e, 0x18, 0x97, 0xb5, 0xba, 0x01, 0x98, 0xdc, 0x22, 0xc6, 0xb5, 0xe8, 0xf5, 0xd6, 0x78, 0xb2, 0x1d, 0x44, 0xb8, 0x53, 0xf3, 0x51, 0x9e, 0xce, 0xb5, 0xc1, 0xce, 0x21, 0x78, 0x06],
+        [0xb2, 0xdf, 0x2b, 0xcf, 0xd3, 0xb7, 0x88, 0xaa, 0xe0, 0xfd, 0xd4, 0x3d, 0xe9, 0xbc, 0x0a, 0x84, 0x06, 0x54, 0xca, 0x46, 0x32, 0xfe, 0x0b, 0x5e, 0x98, 0x27, 0x50, 0x05, 0xe4, 0xd5, 0xc1, 0x00],
+        [0xab, 0xff, 0xc7, 0xa3, 0x78, 0x6c, 0xaa, 0xc6, 0x2b, 0xd2, 0x35, 0x27, 0xaf, 0x45, 0x4f, 0x20, 0xba, 0x9c, 0xce, 0x09, 0x3b, 0x86, 0x75, 0x02, 0x34, 0x4c, 0xda, 0x9f, 0xf8, 0xbd, 0xc6, 0x3b],
+    ],
+    [
+        [0x0b, 0xcc, 0xa5, 0x12, 0x64, 0xe8, 0x3e, 0xf4, 0x9e, 0x7b, 0x58, 0xf0, 0x2a, 0x13, 0x7b, 0x40, 0x62, 0xba, 0x37, 0xd5, 0xb8, 0x9b, 0x10, 0x4b, 0x9e, 0x4e, 0x11, 0xe4, 0x7d, 0xbc, 0x6e, 0x2a],
+        [0x4f, 0x6b, 0x98, 0xd1, 0x63, 0x8c, 0x3f, 0x4d, 0xe6, 0xd2, 0x21, 0xf4, 0x69, 0x05, 0x44, 0xfb, 0xac, 0x96, 0x63, 0xe0, 0x9b, 0x76, 0x21, 0xf1, 0x77, 0x5a, 0xd2, 0x96, 0x5b, 0xbb, 0x6f, 0x38],
+        [0x47, 0x20, 0x30, 0x94, 0xa9, 0xe5, 0x24, 0x76, 0x24, 0x4d, 0xb4, 0x01, 0x0b, 0xa8, 0xfa, 0x23, 0x0a, 0x74, 0x3e, 0x2e, 0xdb, 0x6b, 0xde, 0xc4, 0x6c, 0x38, 0x94, 0xe0, 0xc1, 0xb5, 0x8c, 0x09],
+        [0x29, 0x98, 0x6a, 0x95, 0xf8, 0x63, 0x2f, 0x09, 0x3f, 0x4c, 0x61, 0x69, 0xdb, 0x06, 0xfe, 0xe7, 0x17, 0xca, 0x9e, 0xf6, 0x7b, 0x30, 0x4d, 0x8d, 0xd8, 0x05, 0xf9, 0x41, 0x68, 0xae, 0x4c, 0x39],
+    ],
+    [
+        [0xa7, 0xbb, 0x0b, 0x45, 0x03, 0x25, 0x4c, 0xc7, 0xf3, 0x0a, 0xd5, 0xa3, 0x8a, 0x49, 0x20, 0x34, 0xff, 0x5b, 0xa9, 0x39, 0x37, 0xc0, 0x62, 0xb0, 0xae, 0xbb, 0x35, 0x3e, 0x03, 0x64, 0x15, 0x0b],
+        [0x65, 0x87, 0xc8, 0x24, 0xdb, 0x72, 0xd0, 0xfa, 0xe3, 0x95, 0xad, 0x44, 0x4d, 0xda, 0xd8, 0x84, 0xb6, 0x80, 0xf6, 0xe0, 0x65, 0x7a, 0xa8, 0x09, 0x81, 0xc2, 0xfe, 0x12, 0xa9, 0x80, 0x8f, 0x04],
+        [0x3a, 0x76, 0x9e, 0x2e, 0x94, 0x0c, 0x11, 0x9b, 0xc1, 0x10, 0x9c, 0x94, 0x34, 0xb7, 0xd0, 0x20, 0x09, 0x8c, 0x4b, 0x9e, 0x64, 0x75, 0xaf, 0xcc, 0x66, 0xdf, 0x10, 0x7b, 0xc4, 0xe7, 0xa6, 0x24],
+        [0x22, 0xd2, 0x52, 0x57, 0xca, 0x8f, 0xa0, 0x07, 0xce, 0x48, 0x88, 0xb9, 0xfd, 0xbd, 0x43, 0x9f, 0x65, 0x16, 0xf0, 0xe6, 0xf1, 0x4c, 0xae, 0x7e, 0x1b, 0xf3, 0xa8, 0x16, 0x28, 0x08, 0xfe, 0x27],
+    ],
+    [
+        [0x13, 0x72, 0xf0, 0xea, 0x5d, 0x03, 0x58, 0xe6, 0x87, 0x5a, 0x5a, 0x3c, 0x9e, 0x9b, 0x0f, 0xba, 0x61, 0xde, 0x93, 0xd2, 0x7e, 0x9b, 0xe8, 0xba, 0x9c, 0xcb, 0x20, 0xcc, 0x9d, 0x73, 0xe7, 0x14],
+        [0xe4, 0x72, 0x54, 0x82, 0xf4, 0x1e, 0xac, 0x26, 0x37, 0xb2, 0x5c, 0x60, 0xc1, 0x89, 0x20, 0xd5, 0xdb, 0xbe, 0x83, 0xa5, 0xc0, 0x19, 0xa6, 0xa9, 0x15, 0xb5, 0xaf, 0xed, 0xd6, 0xae, 0x98, 0x3d],
+        [0x8b, 0x50, 0x48, 0xce, 0xb7, 0xe5, 0x8e, 0x15, 0x78, 0x9d, 0x6f, 0x78, 0xd8, 0x24, 0x63, 0x7b, 0xa9, 0x1f, 0x9d, 0x22, 0xb1, 0x16, 0x78, 0x80, 0xce, 0x71, 0xb2, 0x46, 0xc6, 0xee, 0x4b, 0x29],
+        [0xa0, 0x03, 0xa9, 0xe8, 0x05, 0xaa, 0xd3, 0xa0, 0x4b, 0x9c, 0xbc, 0xa6, 0x10, 0x1c, 0xbd, 0x98, 0x3c, 0x99, 0x9b, 0x3e, 0xd9, 0xd7, 0xad, 0xbf, 0x0b, 0xdc, 0x13, 0xcc, 0x05, 0xf0, 0x34, 0x19],
+    ],
+    [
+        [0x44, 0x24, 0x00, 0x77, 0x8e, 0x07, 0xc1, 0x56, 0x3c, 0x1b, 0x30, 0xf0, 0x21, 0x54, 0xe8, 0x4f, 0x1f, 0x2a, 0x6d, 0xa1, 0x48, 0x40, 0x78, 0x77, 0xf0, 0x92, 0xde, 0xef, 0x30, 0xc1, 0x4d, 0x1a],
+        [0x0e, 0x6c, 0xe0, 0xa4, 0x91, 0x4f, 0x30, 0x73, 0xb7, 0xf4, 0x34, 0x86, 0xff, 0x57, 0x6b, 0x4b, 0x2e, 0x72, 0x79, 0xbf, 0x89, 0xe5, 0xd9, 0x2c, 0x62, 0x41, 0x0d, 0xa2, 0x6a, 0x56, 0xd7, 0x03],
+        [0x15, 0x1c, 0xf3, 0xc0, 0xbf, 0xe5, 0xe7, 0x62, 0x43, 0x9e, 0x05, 0xf4, 0xed, 0xbf, 0x0c, 0xf2, 0x5e, 0x7a, 0xf3, 0x76, 0xb8, 0x7f, 0xbe, 0x66, 0xc4, 0x1a, 0xc4, 0x60, 0x9e, 0x83, 0xbe, 0x34],
+        [0xf1, 0x27, 0x6c, 0x48, 0xcf, 0x79, 0xad, 0xb4, 0x16, 0x4e, 0x6b, 0x81, 0xc0, 0x4d, 0xf2, 0x8f, 0xff, 0x93, 0x85, 0xa6, 0xf0, 0x1b, 0x24, 0x9d, 0xd1, 0x42, 0xbb, 0xcc, 0x86, 0x36, 0xed, 0x26],
+    ],
+    [
+        [0x5e, 0xf7, 0xdf, 0x98, 0x54, 0x04, 0x02, 0x0a, 0xf7, 0xa6, 0x69, 0x41, 0x5d, 0xca, 0x77, 0x51, 0xd1, 0xa3, 0xa0, 0x55, 0x33, 0x61, 0x6d, 0xcd, 0xe5, 0xee, 0xb4, 0x7a, 0xa9, 0x47, 0x60, 0x24],
+        [0xbe, 0x80, 0x99, 0xb7, 0x7b, 0x57, 0xca, 0x03, 0x89, 0xa6, 0x73, 0xf4, 0xac, 0x05, 0xfa, 0xc0, 0x04, 0xd5, 0x82, 0x4b, 0x10, 0x9a, 0x77, 0xfe, 0xf3, 0x0d, 0x96, 0x82, 0x62, 0xc4, 0x53, 0x28],
+        [0xa1, 0xde, 0xd1, 0xcb, 0x2b, 0xa7, 0x25, 0x11, 0x0a, 0x45, 0x37, 0x12, 0x49, 0xa5, 0xd3, 0xcc, 0x39, 0xc3, 0x74, 0x75, 0xee, 0x1a, 0x26, 0xb0, 0x7c, 0x69, 0xa4, 0xdd, 0xa7, 0x75, 0xaf, 0x35],
+        [0x5f, 0xae, 0xe5, 0xd3, 0x61, 0x54, 0x68, 0x8a, 0x7e, 0x43, 0x88, 0xab, 0x7e, 0x59, 0x1a, 0x27, 0xef, 0x47, 0xe4, 0xc7, 0xe1, 0x85, 0xd0, 0x8b, 0xe2, 0xb6, 0x2d, 0x24, 0xf8, 0x13, 0x80, 0x02],
+    ],
+    [
+        [0xdd, 0xfe, 0x59, 0x59, 0xac, 0x08, 0xe5, 0x86, 0x09, 0x79, 0x43, 0x78, 0xa0, 0xf8, 0x22, 0x32, 0x58, 0xd8, 0x05, 0x24, 0x11, 0x70, 0xa5, 0xed, 0xd5, 0x93, 0x84, 0xcf, 0x0c, 0x2d, 0xf2, 0x34],
+        [0xba, 0xd2, 0xd4, 0x22, 0xbe, 0xd7, 0x07, 0x2b, 0x4a, 0x88, 0xb8, 0x2b, 0x97, 0xd3, 0x10, 0x5b, 0x7f, 0x50, 0x0f, 0x63, 0x0e, 0xbd, 0xfa, 0x64, 0x5a, 0x62, 0xb3, 0x44, 0xcd, 0xa3, 0x22, 0x11],
+        [0xd5, 0x85, 0x73, 0xfe, 0x1e, 0x71, 0x0d, 0xc3, 0xb9, 0x18, 0xe5, 0x7c, 0x08, 0xd2, 0x35, 0xec, 0xaf, 0x02, 0xba, 0x55, 0x4e, 0x5e, 0x2f, 0x14, 0xf4, 0x16, 0xb6, 0x0a, 0x29, 0x0b, 0x79, 0x2a],
+        [0x79, 0xae, 0xdf, 0x7f, 0x77, 0x3f, 0x36, 0x3d, 0xb9, 0x1d, 0x05, 0x1d, 0x4c, 0x24, 0xb9, 0xab, 0x41, 0x12, 0xa2, 0xac, 0x15, 0xe8, 0xc9, 0xa6, 0xfb, 0xb1, 0x31, 0xb4, 0xa6, 0xf9, 0xe9, 0x20],
+    ],
+    [
+        [0xf5, 0x9b, 0x0f, 0x42, 0xaf, 0x4d, 0xcc, 0x90, 0x06, 0x1b, 0x5b, 0x66, 0x10, 0x1a, 0xa4, 0x6a, 0x58, 0x15, 0xe8, 0xd7, 0xf0, 0xfe, 0x21, 0xbd, 0xdc, 0x69, 0xfd, 0xcb, 0xee, 0xeb, 0xb2, 0x01],
+        [0x72, 0xa7, 0xe5, 0x40, 0xef, 0xd6, 0x3d, 0x96, 0x2b, 0x1f, 0x04, 0x06, 0x7a, 0xef, 0x46, 0xf1, 0x81, 0x29, 0x86, 0x30, 0xe6, 0xff, 0x3a, 0xb6, 0x9d, 0x9c, 0x3d, 0xf5, 0xa6, 0x61, 0x02, 0x3c],
+        [0xc5, 0xbd, 0x44, 0xaa, 0x17, 0xac, 0xf1, 0xc4, 0x45, 0xbd, 0xc5, 0xda, 0xa8, 0x09, 0x10, 0x09, 0xc3, 0x25, 0x48, 0x23, 0xea, 0xbe, 0x36, 0x48, 0x2b, 0x47, 0x30, 0xa2, 0x5d, 0xbf, 0x69, 0x2f],
+        [0x26, 0x96, 0xb7, 0xc2, 0x44, 0x33, 0xfa, 0x4a, 0x2e, 0xfe, 0x0b, 0x11, 0xf1, 0xf6, 0x3a, 0x42, 0xc1, 0x24, 0x2e, 0x88, 0xa2, 0xe6, 0x1c, 0x9a, 0x36, 0x4f, 0xdd, 0xbf, 0xe9, 0x02, 0xf1, 0x07],
+    ],
+    [
+        [0x33, 0x8e, 0x97, 0xf9, 0xb2, 0x27, 0x6b, 0x98, 0x88, 0xf8, 0x23, 0xf0, 0x76, 0xa6, 0x23, 0xea, 0x8d, 0xf1, 0x7c, 0x65, 0xc4, 0xd2, 0x67, 0x7a, 0x4a, 0xd1, 0x12, 0xca, 0xda, 0x2b, 0x85, 0x19],
+        [0xe9, 0x4d, 0x16, 0x8a, 0x55, 0x5c, 0x81, 0x68, 0xf3, 0xba, 0x99, 0x0d, 0x3f, 0xf7, 0xb0, 0xe2, 0x02, 0x3a, 0xb3, 0xa1, 0x22, 0x6a, 0x6f, 0xb7, 0x5c, 0xbc, 0x63, 0xd3, 0x0d, 0xe9, 0xc5, 0x36],
+        [0x33, 0xa1, 0x04, 0xb2, 0xe6, 0x3b, 0x7b, 0x6e, 0x1f, 0xd2, 0x27, 0x1a, 0xf6, 0x7c, 0xf3, 0x53, 0xc9, 0xf7, 0xb6, 0x13, 0xb7, 0x2e, 0x7e, 0xcb, 0x61, 0x40, 0x72, 0x4d, 0xef, 0x27, 0xce, 0x03],
+        [0xbe, 0xde, 0xb4, 0xbd, 0xba, 0xd5, 0x55, 0xa7, 0xa0, 0x29, 0x29, 0x31, 0xef, 0x59, 0x5f, 0xeb, 0x37, 0x76, 0x06, 0x95, 0x82, 0xf8, 0x1e, 0x42, 0x08, 0x49, 0xf1, 0xcc, 0x2f, 0x3a, 0xc7, 0x28],
+    ],
+    [
+        [0x04, 0x66, 0xd1, 0xcd, 0x4a, 0x8d, 0x5b, 0xef, 0x53, 0xe2, 0xe8, 0x2d, 0x20, 0x0b, 0x54, 0x71, 0x47, 0x1a, 0xe4, 0x33, 0x3c, 0xe5, 0xbd, 0x87, 0x58, 0x44, 0xbb, 0x65, 0x22, 0xbd, 0x5b, 0x19],
+        [0x1e, 0xdd, 0xb5, 0xc4, 0x6f, 0x79, 0xb4, 0xd7, 0x91, 0x92, 0x1a, 0x54, 0x13, 0x17, 0x24, 0xce, 0x01, 0x76, 0x87, 0xb7, 0xee, 0xb5, 0x25, 0xc1, 0x6c, 0xbd, 0x2e, 0x8c, 0xa6, 0x21, 0x17, 0x14],
+        [0x1c, 0xa6, 0x41, 0x14, 0x8d, 0x4f, 0x4b, 0xc0, 0xf1, 0x5c, 0x1b, 0xc2, 0x7c, 0x9a, 0x80, 0xc4, 0xc6, 0x00, 0x1c, 0x10, 0xe1, 0xe5, 0xca, 0x19, 0x80, 0x36, 0x12, 0x8d, 0xb5, 0xe3, 0x33, 0x04],
+        [0xa5, 0x78, 0xed, 0x6c, 0x68, 0xf9, 0x28, 0xcf, 0x99, 0x83, 0x92, 0x33, 0x59, 0x06, 0xcd, 0x71, 0x4f, 0x01, 0x6c, 0x4f, 0xd9, 0x5a, 0xe3, 0xcc, 0x90, 0xdb, 0x52, 0x31, 0x8e, 0x6b, 0x6c, 0x27],
+    ],
+    [
+        [0xe2, 0x9b, 0x44, 0x40, 0xba, 0x61, 0x4b, 0x78, 0xe8, 0x85, 0xe7, 0xc2, 0x17, 0xcd, 0x60, 0xa3, 0x49, 0xf9, 0xf5, 0x68, 0x66, 0x9f, 0xc5, 0x86, 0x02, 0x61, 0xbb, 0xd5, 0xf2, 0xc9, 0xb2, 0x1c],
+        [0x35, 0x5b, 0xdd, 0xd9, 0xd9, 0xcd, 0xea, 0x69, 0xc3, 0x9d, 0x3c, 0xa4, 0x44, 0xc9, 0xe3, 0x49, 0xa0, 0x9c, 0x4f, 0x00, 0x4c, 0x03, 0xc7, 0xef, 0x6e, 0x18, 0x6c, 0x00, 0xee, 0xf4, 0xbd, 0x18],
+        [0x77, 0xbd, 0xaa, 0x36, 0xa7, 0x42, 0x51, 0x4a, 0xfd, 0x21, 0x07, 0x5a, 0xb0, 0x8f, 0x65, 0x0b, 0xd3, 0xed, 0xaa, 0x12, 0xb0, 0x8f, 0xb1, 0x82, 0xeb, 0x43, 0x61, 0x25, 0x5d, 0xd6, 0xb9, 0x04],
+        [0x38, 0x7c, 0x73, 0x9a, 0xb1, 0x00, 0x5e, 0x9b, 0x02, 0xab, 0x01, 0xc9, 0x6b, 0xcd, 0x7b, 0x30, 0x26, 0x9f, 0xb4, 0xd7, 0xb8, 0x01, 0x00, 0xca, 0xfb, 0x22, 0x6c, 0x72, 0xda, 0x4f, 0xd7, 0x07],
+    ],
+    [
+        [0x06, 0x07, 0xc2, 0xbb, 0x38, 0x0b, 0x78, 0x7a, 0xb8, 0x38, 0xa8, 0x71, 0xde, 0x50, 0xaa, 0x73, 0xaf, 0x94, 0xb3, 0xf3, 0xac, 0x87, 0xa6, 0x72, 0x8c, 0x4a, 0xe9, 0xf0, 0x01, 0x8e, 0x90, 0x2b],
+        [0xd2, 0x75, 0x0c, 0x9e, 0x23, 0x86, 0x87, 0x5b, 0x81, 0xca, 0x7d, 0x02, 0x39, 0x39, 0x0e, 0x36, 0x95, 0xf8, 0x72, 0x08, 0x99, 0x50, 0x31, 0x5f, 0x32, 0xff, 0xbe, 0x2c, 0x86, 0xe2, 0x7d, 0x21],
+        [0xd5, 0x61, 0x4c, 0x55, 0xf6, 0xac, 0xd2, 0xe7, 0x96, 0xf0, 0x5e, 0xc7, 0xcb, 0x29, 0x2e, 0x40, 0x4c, 0x13, 0x5c, 0x65, 0x0e, 0x64, 0xba, 0xcd, 0x10, 0xe7, 0x76, 0x45, 0xc6, 0x9c, 0xe4, 0x0b],
+        [0x20, 0x74, 0xfa, 0xe6, 0x84, 0x72, 0x79, 0x3d, 0xa1, 0x79, 0x3d, 0xf5, 0x3e, 0x01, 0xb8, 0xc2, 0x30, 0x6e, 0x63, 0xed, 0xbb, 0xb0, 0xd8, 0x8d, 0xa4, 0x8d, 0x2a, 0x76, 0x9d, 0x7a, 0x3b, 0x39],
+    ],
+    [
+        [0x8c, 0xad, 0x5a, 0x6f, 0x19, 0x30, 0xb8, 0x28, 0xda, 0xcc, 0x5b, 0xea, 0xf9, 0xaf, 0xa3, 0x4c, 0x78, 0xed, 0x98, 0x3c, 0x96, 0x39, 0x58, 0x1e, 0x3d, 0xed, 0x0f, 0x88, 0x3a, 0xa8, 0x82, 0x36],
+        [0xca, 0x79, 0x2a, 0x5b, 0x51, 0xe5, 0x54, 0x0b, 0xad, 0xba, 0x46, 0x3e, 0xfa, 0x01, 0xca, 0x17, 0x0a, 0x86, 0x8a, 0x8e, 0x50, 0xb5, 0xd7, 0x24, 0x68, 0x5b, 0x97, 0xd6, 0x55, 0x50, 0x36, 0x2b],
+        [0xa3, 0x6c, 0x64, 0x42, 0xfb, 0x8f, 0xa5, 0x7f, 0xc0, 0x76, 0x11, 0xd5, 0x39, 0xa8, 0xba, 0xdd, 0xba, 0x93, 0xa4, 0x10, 0xde, 0xd6, 0x01, 0x64, 0x3f, 0x02, 0x00, 0x8f, 0xc4, 0xcf, 0xf3, 0x0f],
+        [0x63, 0xfd, 0x85, 0xf4, 0x09, 0x6d, 0xcb, 0x3e, 0x19, 0x42, 0xc6, 0x9b, 0xa3, 0x53, 0x46, 0xea, 0xa4, 0x99, 0x67, 0x17, 0x28, 0x59, 0xbd, 0xb4, 0x6d, 0x8a, 0xf7, 0x55, 0xdc, 0x70, 0xa5, 0x09],
+    ],
+    [
+        [0xa0, 0x91, 0x24, 0x5a, 0x9d, 0xc7, 0x4c, 0xc3, 0x2b, 0xa7, 0x86, 0x84, 0x28, 0xe1, 0x2f, 0xef, 0x19, 0x23, 0xf5, 0x09, 0x81, 0x93, 0xb2, 0x75, 0x9d, 0xc1, 0x1d, 0x96, 0xa8, 0x1b, 0xf1, 0x02],
+        [0xc9, 0xe0, 0x2c, 0x96, 0x02, 0x3e, 0x1d, 0xc2, 0x14, 0xbf, 0xb3, 0x0c, 0x02, 0x47, 0x78, 0x20, 0x8c, 0x27, 0x26, 0x81, 0x69, 0x75, 0x1e, 0x04, 0x21, 0x11, 0xa2, 0x22, 0x57, 0x14, 0x29, 0x04],
+        [0xfd, 0x29, 0xda, 0x64, 0xac, 0xd3, 0x28, 0x51, 0x27, 0x3f, 0x4f, 0xd9, 0x80, 0xa4, 0x9e, 0x1f, 0x45, 0x2b, 0x32, 0xee, 0xeb, 0x72, 0x79, 0x25, 0xf3, 0x8e, 0x36, 0xe7, 0x9c, 0xc2, 0xec, 0x23],
+        [0xa1, 0xc6, 0x16, 0x1d, 0x1f, 0x57, 0x1b, 0xa8, 0x9a, 0xbf, 0x0b, 0x93, 0xf5, 0x80, 0x59, 0x5a, 0x61, 0xe9, 0x55, 0x16, 0x35, 0x2e, 0xe8, 0x5b, 0xd5, 0xef, 0x36, 0x40, 0xfd, 0xf4, 0x61, 0x2d],
+    ],
+    [
+        [0x09, 0xf9, 0x30, 0x7d, 0x0b, 0x7d, 0x07, 0x8e, 0x2a, 0xc5, 0xf9, 0x7c, 0xe4, 0xab, 0x9d, 0x38, 0x04, 0x09, 0xf6, 0xe5, 0x3c, 0x65, 0xf4, 0x74, 0x0e, 0x21, 0xd8, 0x70, 0xe6, 0x82, 0x37, 0x25],
+        [0xd3, 0x60, 0xe7, 0xab, 0xd8, 0x1d, 0xc5, 0xfe, 0xf6, 0xbd, 0xe1, 0x39, 0x72, 0x33, 0x32, 0x4d, 0x61, 0x03, 0xd9, 0xc6, 0x6a, 0x88, 0xf4, 0x2b, 0x85, 0xa0, 0xe7, 0x88, 0x58, 0xa3, 0xed, 0x16],
+        [0x37, 0xbe, 0xc9, 0xac, 0x84, 0x01, 0xb0, 0x5e, 0xe3, 0xcf, 0x94, 0x3a, 0x2c, 0x2a, 0xd4, 0xf1, 0xc1, 0x96, 0xd4, 0x44, 0xa3, 0xc7, 0x4b, 0x98, 0x6c, 0x55, 0xfc, 0x57, 0xf2, 0xd6, 0x5a, 0x0a],
+        [0xdc, 0x85, 0xfb, 0x0f, 0xf7, 0xdf, 0x77, 0x81, 0xe0, 0xec, 0x16, 0x99, 0x47, 0xb5, 0xe7, 0x86, 0x9e, 0xc3, 0x14, 0xb6, 0xbb, 0x6b, 0xf7, 0xa9, 0x9a, 0x03, 0x54, 0xf8, 0x6b, 0x7c, 0xcc, 0x2a],
+    ],
+    [
+        [0x4f, 0xfa, 0xd6, 0xce, 0xf2, 0xa1, 0xef, 0xc5, 0x03, 0x07, 0x35, 0x1f, 0xc4, 0x45, 0x54, 0x69, 0xfb, 0xd9, 0xe2, 0x45, 0xc8, 0x60, 0x05, 0x29, 0x1c, 0x9f, 0x34, 0x9d, 0xe0, 0x13, 0xa7, 0x17],
+        [0xa7, 0xa8, 0x5b, 0x5b, 0xd0, 0x25, 0xf6, 0x51, 0x1c, 0x31, 0x19, 0xd9, 0x0e, 0xa1, 0x47, 0xe1, 0x23, 0xfb, 0x68, 0xe0, 0xc0, 0xe2, 0x41, 0x25, 0xa5, 0x7d, 0x4e, 0x8c, 0x9d, 0xad, 0x1e, 0x1a],
+        [0x9a, 0x50, 0x03, 0x57, 0x50, 0x10, 0x7d, 0x47, 0x94, 0x1f, 0x76, 0x13, 0xb5, 0xbe, 0x42, 0x6f, 0x7d, 0x4f, 0xcc, 0xed, 0x0a, 0x45, 0x03, 0x92, 0xbb, 0x3c, 0x7c, 0x74, 0x25, 0xcb, 0xc5, 0x1b],
+        [0x65, 0xb8, 0x36, 0x73, 0x22, 0x0c, 0xbf, 0xef, 0xc4, 0x80, 0x5e, 0x7f, 0x0c, 0xcc, 0x04, 0x17, 0xce, 0x92, 0x4a, 0x08, 0x39, 0x21, 0xbc, 0x2c, 0x41, 0xe9, 0x23, 0x45, 0x53, 0x04, 0xb2, 0x2a],
+    ],
+    [
+        [0xf8, 0xcb, 0x21, 0x24, 0xeb, 0x7f, 0xc5, 0xe3, 0x0d, 0x00, 0x69, 0x8f, 0xd0, 0x0b, 0x28, 0x45, 0xc0, 0xa5, 0x00, 0x6d, 0x74, 0xf3, 0x9c, 0x90, 0x41, 0xc7, 0x04, 0xc0, 0x6b, 0xa5, 0x60, 0x1c],
+        [0x07, 0x28, 0xc2, 0x17, 0x5c, 0xa3, 0xbb, 0x2a, 0x94, 0x27, 0x99, 0x3b, 0x54, 0xe6, 0x0d, 0x3f, 0x8c, 0xed, 0x0b, 0x06, 0x20, 0xa4, 0xfb, 0x0d, 0x62, 0xe8, 0x81, 0x6e, 0x6b, 0xf1, 0xc0, 0x17],
+        [0x9f, 0x17, 0xef, 0x84, 0x30, 0x5b, 0x8a, 0x27, 0x8b, 0x23, 0x41, 0x2a, 0x10, 0x47, 0x0a, 0xd2, 0xb5, 0x45, 0x62, 0xbc, 0x42, 0xa0, 0x04, 0x86, 0x5a, 0x10, 0x61, 0x1e, 0x15, 0xf2, 0x9f, 0x29],
+        [0x4a, 0x40, 0x0c, 0x6e, 0x6d, 0xcf, 0x98, 0x8d, 0x33, 0xe9, 0xd9, 0xf0, 0x7d, 0xfe, 0xb1, 0x2c, 0xf4, 0x57, 0x8f, 0x0b, 0x2d, 0x08, 0x43, 0xd8, 0xb9, 0x57, 0x14, 0xa0, 0x63, 0x30, 0xb5, 0x36],
+    ],
+    [
+        [0xb7, 0x33, 0xdb, 0x47, 0xc5, 0xa2, 0x8f, 0xff, 0xbd, 0x6d, 0x15, 0xcb, 0x5f, 0x78, 0xda, 0xe4, 0x79, 0xef, 0x81, 0xd4, 0x40, 0xed, 0x6f, 0x3e, 0xa2, 0x61, 0xa7, 0xee, 0xf5, 0x5b, 0x4c, 0x3a],
+        [0xbb, 0x84, 0xbc, 0x70, 0x23, 0x63, 0x5a, 0x4e, 0xa3, 0x59, 0x71, 0xab, 0x1e, 0x59, 0x4d, 0xc7, 0xbd, 0xb2, 0x84, 0x7e, 0xd4, 0xf9, 0xdc, 0x27, 0xcc, 0x5e, 0x30, 0x3c, 0xfe, 0x52, 0xda, 0x24],
+        [0xf4, 0x83, 0xb1, 0x78, 0xda, 0x57, 0x39, 0xab, 0x43, 0x80, 0x42, 0xf4, 0xcd, 0xbd, 0xcd, 0x7b, 0xaa, 0x28, 0x44, 0x72, 0x8a, 0x7d, 0xfe, 0x23, 0x90, 0x66, 0x87, 0xdf, 0xaf, 0x51, 0xe1, 0x25],
+        [0x95, 0xda, 0xf0, 0x80, 0x52, 0xdb, 0x86, 0x22, 0x93, 0x8d, 0xdf, 0xff, 0xdf, 0x45, 0x89, 0x28, 0x05, 0x81, 0xe1, 0xb5, 0xe3, 0xa9, 0x49, 0xae, 0x7c, 0x88, 0x47, 0x2a, 0x3a, 0xd1, 0x27, 0x3c],
+    ],
+    [
+        [0x34, 0x1e, 0x2c, 0xad, 0xad, 0x80, 0x02, 0x48, 0xe1, 0x89, 0x24, 0xad, 0xd2, 0xbe, 0x94, 0xa7, 0xa6, 0x6e, 0x39, 0xcf, 0xf8, 0x18, 0x45, 0x29, 0xd3, 0x86, 0x59, 0x5a, 0x68, 0x0d, 0x04, 0x1f],
+        [0x47, 0xae, 0x13, 0xcc, 0x8f, 0xb4, 0x95, 0x8b, 0x01, 0x5f, 0x82, 0x1a, 0x3a, 0x09, 0xc1, 0x8a, 0x0f, 0x03, 0xb7, 0xbc, 0x51, 0x33, 0x5e, 0xb4, 0xce, 0x27, 0x33, 0x53, 0x1d, 0x75, 0xf2, 0x09],
+        [0x36, 0xc7, 0x96, 0x59, 0x0f, 0x44, 0x1e, 0xc0, 0x52, 0x6e, 0x22, 0x59, 0x8d, 0x74, 0x2b, 0xf3, 0x33, 0xb2, 0x0d, 0x28, 0x38, 0x94, 0xe9, 0xbe, 0x8d, 0x0f, 0x71, 0xee, 0x65, 0x90, 0xd0, 0x2f],
+        [0xd2, 0x7d, 0xd4, 0x59, 0xb4, 0x25, 0x07, 0x39, 0xa7, 0xc5, 0x9a, 0xa5, 0x5a, 0xac, 0x54, 0x7d, 0x94, 0xd0, 0x32, 0x16, 0x30, 0x25, 0x10, 0x32, 0x30, 0x57, 0x01, 0x14, 0x58, 0x2b, 0xab, 0x1a],
+    ],
+    [
+        [0xc8, 0x07, 0x26, 0xc2, 0xea, 0x84, 0xcb, 0x5d, 0xd9, 0xd9, 0x2c, 0xeb, 0xd8, 0xf7, 0x4c, 0x67, 0xdd, 0xb6, 0x88, 0xbf, 0x45, 0xe8, 0x05, 0x46, 0x01, 0x1a, 0xb7, 0x30, 0x78, 0x76, 0x4f, 0x2f],
+        [0xb6, 0x2e, 0x65, 0xd1, 0x6b, 0xb9, 0x7a, 0x02, 0xa8, 0x72, 0x4d, 0x3b, 0xb8, 0x35, 0xfd, 0x8e, 0x7b, 0xc2, 0xbb, 0xc5, 0xf5, 0x3e, 0x5d, 0x3a, 0xcc, 0xbd, 0xac, 0x25, 0x26, 0xe5, 0xd3, 0x0d],
+        [0x0c, 0x7c, 0x84, 0x6d, 0x85, 0xce, 0x82, 0x69, 0xb5, 0xef, 0x46, 0xba, 0x20, 0x9f, 0x14, 0xd1, 0xbe, 0xd1, 0x14, 0x00, 0xe3, 0xed, 0x5d, 0x96, 0x1e, 0xdf, 0x1b, 0x12, 0x0c, 0x42, 0x51, 0x37],
+        [0x5a, 0x88, 0x4c, 0xa3, 0x8f, 0x76, 0xad, 0x86, 0x6a, 0xca, 0xc5, 0xe4, 0xd2, 0x5f, 0x78, 0xc2, 0x62, 0x8c, 0xb3, 0xcf, 0x0c, 0x50, 0x08, 0x7a, 0x3d, 0x26, 0x92, 0xc6, 0xc4, 0x39, 0xc8, 0x29],
+    ],
+    [
+        [0xef, 0xaf, 0x77, 0x6e, 0x3b, 0xcd, 0x2b, 0x09, 0x22, 0xaf, 0x88, 0x3e, 0x72, 0x39, 0x7b, 0xab, 0x5b, 0xd0, 0x1a, 0x18, 0xd0, 0x05, 0x66, 0xf5, 0x74, 0x22, 0xf4, 0x4d, 0xf5, 0x52, 0x34, 0x3e],
+        [0xbb, 0x3b, 0x3a, 0x03, 0x0a, 0x9d, 0xf9, 0x69, 0x5b, 0x3e, 0xba, 0x82, 0x9e, 0x2a, 0x72, 0x71, 0x8e, 0xa4, 0x2b, 0x77, 0x90, 0x0c, 0x96, 0xf9, 0x89, 0xa2, 0x82, 0xae, 0x7c, 0x5b, 0x1f, 0x31],
+        [0x80, 0xea, 0xf2, 0xb0, 0x19, 0x15, 0x06, 0x01, 0x38, 0xd7, 0xfe, 0xe1, 0xfe, 0x25, 0x81, 0x4b, 0x82, 0x10, 0x03, 0xfc, 0x1e, 0x09, 0xb3, 0x50, 0x3f, 0x92, 0xac, 0x0f, 0x38, 0xd9, 0xdd, 0x13],
+        [0x2e, 0xe3, 0x66, 0xfe, 0xbf, 0x2b, 0x28, 0x4f, 0x57, 0x5f, 0xe2, 0x39, 0x14, 0xb4, 0xc5, 0xb9, 0xc3, 0x06, 0xf0, 0x4c, 0xb3, 0x53, 0x99, 0x0b, 0xf1, 0x68, 0x4e, 0x9d, 0xbb, 0x76, 0x0d, 0x2b],
+    ],
+    [
+        [0xce, 0x8e, 0x01, 0x40, 0x48, 0x0d, 0x34, 0xfd, 0x4f, 0x05, 0x13, 0x8c, 0x28, 0x4c, 0xbf, 0x52, 0xb3, 0x9a, 0x81, 0xfb, 0xe5, 0xd4, 0x3f, 0xca, 0x7d, 0x20, 0xe1, 0x5e, 0xe6, 0x93, 0x83, 0x0b],
+        [0x3a, 0xfe, 0x40, 0x8e, 0x47, 0xe1, 0xfd, 0x00, 0x9c, 0xd4, 0x50, 0x00, 0x3b, 0x2e, 0x3c, 0x99, 0x5f, 0xe9, 0x6c, 0xd9, 0xd6, 0x42, 0x24, 0x83, 0xe6, 0x45, 0xfe, 0x9a, 0xb7, 0xba, 0x7a, 0x33],
+        [0xb0, 0x53, 0xf2, 0xf6, 0xda, 0xad, 0xaf, 0xf9, 0xeb, 0xf9, 0xc0, 0x70, 0x0b, 0xc0, 0x79, 0x22, 0x45, 0x7d, 0x1e, 0xe3, 0xe1, 0x0c, 0x00, 0x3d, 0xdb, 0xc7, 0x4f, 0x0a, 0x74, 0xf1, 0x85, 0x2f],
+        [0xc5, 0xf1, 0xc4, 0xa5, 0xf7, 0x69, 0xd1, 0x42, 0xd1, 0xae, 0xa9, 0x3d, 0x8d, 0xf5, 0x31, 0xac, 0x63, 0xca, 0x8c, 0x6d, 0xa0, 0x29, 0x2b, 0x73, 0x9b, 0xe6, 0x3f, 0x1b, 0x96, 0x2a, 0x7b, 0x07],
+    ],
+    [
+        [0x70, 0xe7, 0xa9, 0xe0, 0x88, 0x85, 0xb1, 0x08, 0xed, 0x84, 0x0f, 0x90, 0x09, 0xdd, 0x83, 0x50, 0x42, 0xb7, 0xa1, 0xc4, 0xea, 0x1b, 0x00, 0xef, 0x98, 0x2f, 0xd2, 0x15, 0x8d, 0x71, 0x5f, 0x11],
+        [0xd5, 0xf2, 0x2b, 0x39, 0xf5, 0x4b, 0x50, 0x8d, 0x68, 0x7d, 0xd2, 0x87, 0xb1, 0xca, 0x63, 0xf2, 0x73, 0x83, 0x9c, 0x1e, 0xb5, 0x73, 0xfe, 0xbc, 0x44, 0x7b, 0x76, 0x7c, 0xea, 0xf6, 0x85, 0x20],
+        [0x49, 0xf4, 0x39, 0xd9, 0x35, 0x10, 0x47, 0x3a, 0x53, 0x9f, 0x6a, 0x81, 0x89, 0xd8, 0x96, 0x3c, 0x1f, 0xa8, 0x95, 0xd7, 0x02, 0x55, 0x4c, 0x13, 0x60, 0x75, 0xfb, 0x9b, 0x21, 0x4d, 0xb2, 0x0c],
+        [0x53, 0x27, 0x2a, 0xfa, 0x6a, 0x8d, 0x92, 0x63, 0xec, 0x04, 0x6e, 0x4a, 0xa4, 0xba, 0x44, 0xdc, 0xe9, 0xca, 0xbd, 0xbb, 0xae, 0x8d, 0xd1, 0x4f, 0x43, 0x2a, 0x9e, 0x6b, 0x57, 0xa5, 0x6a, 0x0a],
+    ],
+    [
+        [0xce, 0x17, 0xd8, 0xc9, 0xb5, 0x3a, 0x3d, 0x7c, 0x5c, 0xa8, 0xa2, 0x8d, 0xd2, 0x31, 0xef, 0xd8, 0x1b, 0x94, 0x2c, 0x3b, 0xf4, 0x23, 0x86, 0x40, 0x5e, 0x64, 0x50, 0xb7, 0xe0, 0x2d, 0xde, 0x05],
+        [0x5b, 0x3c, 0x21, 0x2a, 0x3e, 0x64, 0xe8, 0x76, 0x6a, 0x60, 0x30, 0x21, 0x4a, 0x90, 0x43, 0xba, 0xf0, 0x3f, 0x46, 0xac, 0x8a, 0x7a, 0xc2, 0x7c, 0xdf, 0xb5, 0x18, 0x22, 0xe7, 0x7b, 0x46, 0x36],
+        [0xed, 0xad, 0xdc, 0xc5, 0x1a, 0x93, 0xaf, 0x7f, 0xfe, 0x8c, 0xad, 0xd4, 0xda, 0x4a, 0x40, 0xd3, 0x4e, 0xa2, 0x34, 0xde, 0x0f, 0x58, 0x05, 0xb5, 0x63, 0x36, 0x13, 0x7c, 0xcd, 0x65, 0xe4, 0x27],
+        [0xd8, 0x7e, 0x3d, 0x48, 0x16, 0xf0, 0xb8, 0x92, 0xcf, 0xdb, 0x3e, 0x56, 0x37, 0x18, 0xa9, 0x26, 0x83, 0x21, 0xf7, 0xd2, 0xb8, 0xc7, 0xcf, 0x37, 0x8e, 0x18, 0x7e, 0xf8, 0x28, 0xa1, 0x21, 0x04],
+    ],
+    [
+        [0x45, 0x0e, 0x71, 0x1d, 0x71, 0x07, 0xdf, 0x8b, 0x51, 0xa6, 0x03, 0xf4, 0x44, 0x63, 0x96, 0x08, 0x54, 0xc4, 0x07, 0x11, 0x22, 0x9f, 0x30, 0xe3, 0x76, 0xa2, 0xfb, 0x50, 0x18, 0x7c, 0xa0, 0x3d],
+        [0x39, 0xf9, 0xd6, 0xb0, 0xee, 0x08, 0x94, 0x8f, 0x26, 0x46, 0x69, 0xdb, 0xa7, 0xfe, 0x07, 0x57, 0xb3, 0xd6, 0x75, 0xaa, 0x88, 0xe4, 0x74, 0x58, 0xf8, 0xb5, 0x75, 0x15, 0x68, 0xf6, 0x53, 0x23],
+        [0xd9, 0x32, 0x37, 0x2e, 0x0f, 0xf3, 0xc5, 0xb3, 0x89, 0x6f, 0xd5, 0xbb, 0xf4, 0x68, 0x48, 0x53, 0x24, 0x40, 0xd3, 0x81, 0x87, 0xec, 0xeb, 0xee, 0x67, 0x6b, 0x1c, 0x69, 0x2d, 0x94, 0x5f, 0x0c],
+        [0x33, 0x6b, 0xdc, 0x77, 0x61, 0x39, 0x63, 0x8c, 0x63, 0xb1, 0x37, 0x51, 0xc5, 0x19, 0xfa, 0x58, 0xa2, 0xe6, 0xa3, 0x60, 0xf9, 0xa0, 0x55, 0x7f, 0x4f, 0x1f, 0xf1, 0x81, 0xe5, 0x57, 0xb8, 0x2e],
+    ],
+    [
+        [0xb8, 0x6e, 0xa4, 0x09, 0x7c, 0x5b, 0x3c, 0xc0, 0x69, 0xf2, 0xcc, 0x98, 0x0c, 0x9f, 0x39, 0x77, 0x12, 0xfc, 0x04, 0x4c, 0x28, 0xf6, 0x53, 0xa7, 0xad, 0x74, 0x44, 0xb5, 0xb7, 0x41, 0x4d, 0x2a],
+        [0x69, 0x06, 0x31, 0xfe, 0x3a, 0x25, 0x5d, 0x51, 0x19, 0x57, 0x0f, 0x3d, 0xfc, 0xbf, 0x38, 0xe6, 0x9e, 0xc3, 0x97, 0x12, 0xf5, 0x59, 0x0f, 0x26, 0x08, 0xa3, 0xc8, 0x22, 0x92, 0x06, 0x1a, 0x18],
+        [0xf8, 0xd4, 0xd8, 0xc1, 0xe3, 0xe7, 0xae, 0xde, 0xf6, 0x68, 0x0d, 0x4a, 0x97, 0xae, 0x90, 0xe4, 0x58, 0xba, 0xc9, 0x57, 0x61, 0x5b, 0x24, 0x06, 0x08, 0xf5, 0x77, 0xd2, 0x7b, 0xd9, 0x77, 0x20],
+        [0xc8, 0x26, 0x3c, 0xe3, 0xa1, 0x4f, 0x68, 0xf7, 0xff, 0x70, 0x60, 0x48, 0x57, 0x67, 0x45, 0xc9, 0x29, 0x22, 0x6d, 0xe4, 0xf5, 0xfc, 0xee, 0x2e, 0xbd, 0x19, 0x09, 0x38, 0x3f, 0xfb, 0x6e, 0x0c],
+    ],
+    [
+        [0x61, 0x99, 0xa2, 0x32, 0x56, 0x9d, 0x08, 0xbf, 0x8b, 0xb8, 0xeb, 0xeb, 0x1d, 0x51, 0xda, 0x68, 0x55, 0xd5, 0x70, 0x50, 0x38, 0xfe, 0x9a, 0x74, 0xcf, 0x9a, 0xb1, 0xf9, 0xc1, 0x58, 0x0c, 0x35],
+        [0xb0, 0x16, 0x23, 0x24, 0x0e, 0x08, 0xda, 0x8b, 0xad, 0x59, 0x11, 0xcf, 0xa5, 0xc8, 0x97, 0x0b, 0x51, 0xfe, 0xd3, 0xb4, 0x3d, 0x5e, 0x62, 0x4a, 0xad, 0x45, 0x79, 0x2c, 0xdb, 0x63, 0x29, 0x3e],
+        [0xb5, 0x1f, 0x69, 0xa2, 0xa7, 0x8d, 0x69, 0x6e, 0x91, 0xf0, 0x06, 0x7f, 0xe3, 0x34, 0x13, 0xfe, 0x70, 0x00, 0x9b, 0x66, 0xe3, 0x6e, 0x7f, 0x0f, 0xe4, 0x9f, 0x2a, 0xa3, 0x65, 0xe3, 0x57, 0x2a],
+        [0x80, 0x17, 0xbb, 0x3e, 0xa3, 0x0e, 0xf0, 0x15, 0xe6, 0xe3, 0x07, 0x46, 0x00, 0x51, 0x04, 0x42, 0xe2, 0xbf, 0x9e, 0xd1, 0xf0, 0x4f, 0x3a, 0x6d, 0x29, 0x14, 0xfc, 0x09, 0x03, 0x6f, 0xa5, 0x34],
+    ],
+    [
+        [0x60, 0xa9, 0xb0, 0xe4, 0xa6, 0x63, 0x46, 0xb3, 0xf2, 0xa0, 0x18, 0x20, 0xfc, 0x00, 0xee, 0xd2, 0x5f, 0x93, 0x27, 0x83, 0x70, 0xbd, 0x3d, 0xd3, 0xda, 0x65, 0xac, 0x2f, 0xbb, 0x97, 0x17, 0x34],
+        [0x88, 0xa3, 0xa9, 0x1b, 0xa6, 0x8d, 0x75, 0xce, 0xae, 0x9f, 0xdc, 0xe8, 0x7d, 0x24, 0x9c, 0x1e, 0x52, 0xa5, 0xf8, 0x66, 0xb7, 0x18, 0xbd, 0x58, 0x23, 0x7d, 0xdc, 0x6d, 0x3f, 0xf3, 0xb3, 0x2f],
+        [0x97, 0x3c, 0x93, 0xdb, 0x0e, 0xdc, 0xd8, 0xc3, 0x43, 0x71, 0xc3, 0xd2, 0x43, 0x4c, 0x9e, 0x41, 0xe0, 0xf3, 0x86, 0x1f, 0x15, 0x05, 0xee, 0xf4, 0xac, 0xfb, 0x33, 0x73, 0x00, 0xd8, 0x66, 0x16],
+        [0x4e, 0x7f, 0x8e, 0x2f, 0xc9, 0x01, 0xcd, 0x1b, 0x6e, 0x20, 0x75, 0xae, 0x6a, 0x42, 0xc1, 0x90, 0xac, 0xe2, 0xd4, 0x7a, 0x06, 0x32, 0x4d, 0xf1, 0x19, 0x6f, 0x7e, 0x97, 0x5f, 0x0e, 0xe4, 0x21],
+    ],
+    [
+        [0xd3, 0xa8, 0x3b, 0xb7, 0x42, 0x27, 0xba, 0x8c, 0xe2, 0x4a, 0xd2, 0x1f, 0x08, 0x66, 0xce, 0x21, 0x2b, 0x4f, 0x49, 0xb9, 0xcb, 0x42, 0x98, 0x1a, 0x19, 0xa5, 0x46, 0xbc, 0x08, 0x44, 0x39, 0x08],
+        [0x8d, 0xd4, 0xb7, 0xdf, 0x81, 0x45, 0xd4, 0x7a, 0x6a, 0xdb, 0x75, 0x92, 0xee, 0x00, 0x29, 0xa8, 0x99, 0xdb, 0x52, 0xef, 0x4b, 0x63, 0xec, 0x7f, 0x78, 0xab, 0x9a, 0x38, 0x82, 0x63, 0x1e, 0x23],
+        [0xb8, 0xe0, 0x47, 0x9b, 0x1f, 0xe7, 0x5b, 0x2f, 0x97, 0xec, 0x62, 0xf8, 0xe0, 0x76, 0xe0, 0xb8, 0xbe, 0xf6, 0x50, 0x9f, 0x79, 0x5a, 0x07, 0xe8, 0xd5, 0xe1, 0x62, 0x85, 0x2f, 0x13, 0xa6, 0x1f],
+        [0x2a, 0x01, 0x2f, 0xe6, 0x49, 0x76, 0x7e, 0x63, 0x29, 0x8c, 0xd3, 0xc1, 0x6f, 0x6f, 0x7d, 0xdc, 0x49, 0x20, 0x37, 0x9f, 0x29, 0x4a, 0x20, 0x36, 0x86, 0x3b, 0xba, 0xe3, 0x10, 0x90, 0x81, 0x2b],
+    ],
+    [
+        [0x48, 0x0d, 0xd8, 0x65, 0x84, 0x4c, 0xa1, 0x18, 0x67, 0xfd, 0x04, 0x51, 0x85, 0x27, 0x62, 0x2c, 0xba, 0x13, 0xe6, 0x7e, 0x1e, 0xbf, 0x38, 0x7b, 0xb3, 0x26, 0xb4, 0x82, 0x5f, 0x1f, 0xd7, 0x1e],
+        [0x5e, 0xd3, 0x8f, 0xf5, 0xa1, 0x75, 0x02, 0xab, 0x3c, 0x45, 0x21, 0xc7, 0x7f, 0x07, 0x29, 0x3d, 0xd3, 0xcb, 0x6f, 0x99, 0x9b, 0xef, 0xd6, 0x96, 0xe5, 0x6b, 0x08, 0x50, 0x87, 0xd3, 0xd4, 0x16],
+        [0xb4, 0xe8, 0xb6, 0xd4, 0x7e, 0xf8, 0x9e, 0x9e, 0x22, 0xa1, 0x99, 0x41, 0xe6, 0x1f, 0x28, 0x56, 0x5b, 0x4c, 0x09, 0x58, 0x06, 0xb9, 0xa3, 0xff, 0x0e, 0x7a, 0x16, 0x93, 0xa3, 0x1b, 0x83, 0x1a],
+        [0x32, 0x6f, 0x37, 0xff, 0x81, 0xb3, 0xa5, 0x9a, 0xb7, 0xb8, 0x34, 0x10, 0xe7, 0x12, 0xa3, 0x12, 0xa9, 0xcc, 0x8d, 0x35, 0x49, 0x83, 0x45, 0x22, 0xa0, 0xfc, 0x4c, 0x8a, 0x76, 0xde, 0x89, 0x2f],
+    ],
+    [
+        [0x03, 0xef, 0x46, 0x5d, 0xf3, 0x5b, 0x0d, 0x76, 0x39, 0x61, 0x77, 0x26, 0x54, 0x1b, 0x0f, 0x4f, 0xe0, 0x15, 0x6e, 0xf7, 0x13, 0x47, 0x95, 0xa0, 0x7b, 0x5b, 0xa9, 0x6a, 0x78, 0x3a, 0x12, 0x21],
+        [0xd1, 0xea, 0x81, 0xce, 0x15, 0x07, 0xb0, 0x5c, 0x77, 0xb8, 0x2e, 0x92, 0xc5, 0x6c, 0x96, 0x97, 0xa6, 0x46, 0x98, 0x37, 0xe4, 0x5c, 0x0e, 0x15, 0xfa, 0xa8, 0xba, 0x7c, 0xe4, 0x19, 0x23, 0x1a],
+        [0x2c, 0x30, 0x7a, 0xc8, 0xb1, 0xd9, 0x3f, 0x0e, 0xcf, 0xf7, 0x40, 0x80, 0xc0, 0x3e, 0x69, 0x39, 0x06, 0x8f, 0x5b, 0x4b, 0xbe, 0xef, 0x6e, 0x92, 0xfb, 0xec, 0xea, 0x1a, 0x94, 0x12, 0x95, 0x26],
+        [0x79, 0x8a, 0x4b, 0x7c, 0xd7, 0x08, 0xd2, 0xf1, 0xc2, 0x14, 0xa9, 0xae, 0x41, 0x37, 0xd1, 0xe1, 0x6a, 0x93, 0x37, 0x43, 0x4e, 0x83, 0x04, 0xa9, 0xcb, 0xa0, 0x8c, 0x96, 0xf6, 0x5d, 0x0a, 0x14],
+    ],
+    [
+        [0x32, 0x61, 0x44, 0x3f, 0xd9, 0x35, 0xc5, 0x97, 0x96, 0x4d, 0x08, 0xbb, 0x87, 0x13, 0xec, 0x1d, 0xbc, 0x07, 0xf0, 0x45, 0xc8, 0xcb, 0x87, 0x9d, 0xf3, 0x80, 0x04, 0xfb, 0x1c, 0x2b, 0x7a, 0x28],
+        [0x83, 0xb9, 0x76, 0x8c, 0xf5, 0x7b, 0x73, 0x15, 0xba, 0xfd, 0x6a, 0x2d, 0x16, 0x78, 0x5b, 0x9f, 0xc4, 0x87, 0x12, 0x6f, 0xf8, 0x9a, 0x5f, 0x9f, 0xc8, 0xaf, 0x88, 0x84, 0xc9, 0x10, 0x60, 0x0c],
+        [0xb6, 0x9a, 0xdc, 0x69, 0x0d, 0xd6, 0x3f, 0x98, 0x69, 0x44, 0x86, 0x98, 0x24, 0x2f, 0xf9, 0x65, 0x0a, 0xe0, 0x73, 0x7b, 0x29, 0x53, 0x71, 0xcd, 0x38, 0x6c, 0x19, 0x58, 0x29, 0x54, 0x69, 0x11],
+        [0x2e, 0x19, 0xb0, 0x80, 0x6b, 0xf8, 0x7b, 0xa1, 0x15, 0x9e, 0xb1, 0x62, 0xe7, 0xe4, 0x52, 0x95, 0x49, 0x75, 0xfb, 0x65, 0xd0, 0xb4, 0x53, 0xa2, 0xfd, 0xe6, 0xcd, 0xf9, 0x8a, 0x3b, 0xc9, 0x25],
+    ],
+    [
+        [0x84, 0x8f, 0xb3, 0xae, 0xa5, 0x18, 0x72, 0x53, 0x9f, 0x80, 0x26, 0xa2, 0x65, 0x74, 0x36, 0x18, 0x7c, 0x3a, 0x84, 0x40, 0xb3, 0x1f, 0x90, 0xff, 0x8d, 0xe0, 0x6a, 0xa0, 0x91, 0x1d, 0x4f, 0x16],
+        [0xcd, 0x13, 0x2d, 0xc6, 0x6a, 0x0f, 0xfc, 0x3a, 0xfa, 0x25, 0xd0, 0x1d, 0xa5, 0x8c, 0x4e, 0xc7, 0xdf, 0x57, 0xc5, 0x35, 0x18, 0xd1, 0x58, 0xe5, 0x4c, 0x71, 0xcf, 0x8f, 0x83, 0xc9, 0x4f, 0x11],
+        [0x02, 0xe4, 0x86, 0xd0, 0x55, 0x9c, 0xb3, 0x08, 0x08, 0x54, 0x9e, 0x7a, 0x27, 0xcb, 0x7c, 0xda, 0x10, 0xd3, 0x1a, 0xfc, 0x30, 0x5a, 0x8b, 0x7b, 0xf9, 0x9c, 0xff, 0x02, 0x5a, 0x62, 0xb9, 0x10],
+        [0xb8, 0xc9, 0x56, 0x48, 0xb7, 0xd7, 0x98, 0x73, 0x7c, 0xb9, 0x72, 0x4f, 0xed, 0xab, 0xae, 0xce, 0x11, 0xd0, 0xa9, 0x55, 0xa1, 0x4e, 0x20, 0x1e, 0xc6, 0xef, 0x2b, 0x5f, 0x19, 0x2d, 0x0c, 0x28],
+    ],
+    [
+        [0xb5, 0x04, 0x5e, 0x8e, 0x23, 0x88, 0xb7, 0xc5, 0xa4, 0xbf, 0x19, 0x3d, 0x44, 0xad, 0x4e, 0x0c, 0x9d, 0x4d, 0x79, 0x3d, 0x4c, 0x37, 0xec, 0x2f, 0x44, 0x54, 0x87, 0x23, 0x6b, 0xa4, 0xf0, 0x06],
+        [0x79, 0x46, 0x2e, 0x52, 0xd9, 0x8a, 0x74, 0x4b, 0xae, 0x35, 0xf9, 0x3f, 0x0e, 0x32, 0x3e, 0x5a, 0x34, 0xd7, 0x31, 0x59, 0x92, 0x9b, 0x29, 0x14, 0xbe, 0x9a, 0x2d, 0x72, 0x03, 0x48, 0x92, 0x0d],
+        [0xae, 0x2c, 0xdf, 0x5c, 0x62, 0xff, 0x77, 0x44, 0x32, 0x9c, 0x65, 0x6d, 0x2d, 0xc5, 0xc4, 0x08, 0xc9, 0x6d, 0x37, 0x8a, 0xa1, 0x71, 0x46, 0x53, 0x53, 0x3f, 0xf9, 0x1b, 0x67, 0xf9, 0x50, 0x36],
+        [0xf6, 0x6e, 0x0a, 0x11, 0xbe, 0x38, 0x45, 0x0f, 0xf0, 0x22, 0xce, 0x3e, 0x90, 0x52, 0x9d, 0x6f, 0x08, 0x47, 0xd1, 0xed, 0x8c, 0xa6, 0xe5, 0x09, 0xab, 0xb9, 0xff, 0x7a, 0x9e, 0xb2, 0x05, 0x33],
+    ],
+    [
+        [0xe4, 0x46, 0x51, 0x56, 0xd4, 0x76, 0xdf, 0x9f, 0x7b, 0x98, 0x88, 0x03, 0x5c, 0xc3, 0x17, 0xa4, 0x89, 0xc9, 0x82, 0x52, 0xd9, 0x62, 0xeb, 0xd2, 0xba, 0x6a, 0x8c, 0xfc, 0xc3, 0xed, 0x86, 0x11],
+        [0x77, 0x2a, 0x79, 0xf8, 0x44, 0x48, 0x12, 0xdc, 0xcd, 0xeb, 0x44, 0x13, 0x54, 0x45, 0x08, 0xba, 0x94, 0x77, 0x79, 0xb1, 0x91, 0x89, 0x27, 0x2b, 0xb7, 0x9b, 0xa3, 0x26, 0xae, 0x2b, 0xa4, 0x24],
+        [0xc5, 0x81, 0x98, 0x2d, 0x76, 0x07, 0xe4, 0x55, 0xa2, 0x5d, 0xfa, 0x7b, 0x55, 0x6e, 0x4a, 0x62, 0x1c, 0xe4, 0x2f, 0xc4, 0x68, 0xa4, 0xc5, 0xc0, 0x41, 0xfb, 0x8e, 0xc5, 0x1b, 0x4e, 0x28, 0x06],
+        [0x40, 0x9e, 0xeb, 0x96, 0x5f, 0xdf, 0xb6, 0xdb, 0xe6, 0xd8, 0x3f, 0xe5, 0xa2, 0x1c, 0xdf, 0x41, 0x6d, 0xc3, 0xbe, 0x16, 0xc8, 0x0a, 0xef, 0x46, 0x65, 0xaf, 0xe5, 0x42, 0xd6, 0xb5, 0x98, 0x09],
+    ],
+    [
+        [0x5a, 0x86, 0x1e, 0x98, 0x6f, 0x3d, 0x67, 0x07, 0x18, 0xa6, 0xc5, 0x5e, 0x14, 0xc0, 0xe6, 0x2b, 0x04, 0xff, 0xb7, 0x43, 0x3c, 0xef, 0x8c, 0xf1, 0xa5, 0x30, 0x4b, 0x4c, 0xa7, 0xd9, 0x63, 0x3b],
+        [0x0e, 0x4f, 0x32, 0x7c, 0x6c, 0x52, 0x98, 0xf1, 0xbf, 0xfe, 0x4b, 0xd5, 0x9a, 0x6c, 0x23, 0xdf, 0x3f, 0x20, 0xcc, 0x3c, 0xcf, 0x74, 0x4f, 0xb8, 0xf6, 0x30, 0x21, 0xbf, 0xf5, 0x15, 0x2b, 0x29],
+        [0x11, 0xfb, 0x25, 0xca, 0x0b, 0xb8, 0xdc, 0xc4, 0x66, 0xa6, 0x23, 0x16, 0xd3, 0x57, 0x37, 0x6f, 0x5e, 0xa0, 0x30, 0x31, 0xc8, 0x64, 0xd7, 0xe0, 0xcf, 0x82, 0xcc, 0x0b, 0xa1, 0x58, 0x31, 0x1e],
+        [0xc5, 0xad, 0x98, 0xc3, 0x71, 0x33, 0x99, 0x73, 0x2c, 0xab, 0x99, 0xc1, 0x99, 0x95, 0xc6, 0x26, 0x36, 0x33, 0x20, 0xc6, 0xac, 0x43, 0x07, 0x6a, 0x81, 0x6f, 0x51, 0x6a, 0xa2, 0xbc, 0x40, 0x38],
+    ],
+    [
+        [0xc7, 0xaf, 0x9c, 0xfb, 0x68, 0xb7, 0xf5, 0xc9, 0x8c, 0x13, 0xd3, 0x97, 0xf5, 0x23, 0xd9, 0x82, 0x92, 0x6b, 0x1d, 0xe6, 0x6e, 0x1f, 0x2d, 0xf5, 0x56, 0x4a, 0xef, 0xed, 0x85, 0x2e, 0x6f, 0x01],
+        [0x5c, 0xd5, 0xfb, 0xec, 0x87, 0xc1, 0x0f, 0x70, 0xd5, 0x5e, 0x38, 0xb4, 0x2e, 0xbe, 0xc6, 0xdc, 0x62, 0x6c, 0x1d, 0x0d, 0xe8, 0x1a, 0x44, 0x0a, 0x18, 0x24, 0x68, 0xbe, 0xc1, 0x15, 0xd4, 0x22],
+        [0x10, 0xa5, 0xc3, 0x0b, 0xd9, 0x23, 0xd3, 0x36, 0x87, 0x92, 0xb3, 0xf1, 0xc1, 0x75, 0xf4, 0x93, 0x0a, 0x9e, 0xc4, 0x86, 0xc4, 0xed, 0x52, 0x5d, 0x81, 0x5d, 0x9d, 0x06, 0x98, 0x04, 0x11, 0x16],
+        [0x0c, 0x13, 0x3c, 0xdb, 0x1c, 0xca, 0x94, 0xc1, 0x0f, 0xd1, 0x37, 0x5b, 0x85, 0xe3, 0x9b, 0x26, 0x17, 0xe9, 0x27, 0xba, 0x03, 0xa2, 0xc1, 0xa4, 0xd2, 0xb3, 0xdd, 0x25, 0xe4, 0x47, 0x14, 0x02],
+    ],
+    [
+        [0x90, 0x6b, 0x6a, 0x1c, 0xf8, 0x22, 0x1c, 0x37, 0x7d, 0xc0, 0x3f, 0x60, 0xe1, 0x8e, 0x80, 0x1d, 0x8c, 0x04, 0x17, 0xcb, 0xd2, 0x97, 0xd3, 0x7c, 0x39, 0x10, 0x14, 0xc7, 0x1b, 0x15, 0x92, 0x1f],
+        [0xa8, 0x0c, 0x1f, 0xa6, 0x4c, 0xef, 0x42, 0x47, 0x69, 0x63, 0x1d, 0x6b, 0xb1, 0x93, 0xcc, 0xaf, 0xb8, 0xe1, 0x76, 0xf3, 0xe1, 0xc1, 0x7b, 0x57, 0xf7, 0x03, 0x3b, 0xf2, 0x83, 0x7d, 0x7f, 0x11],
+        [0x64, 0x2e, 0x85, 0xb6, 0x49, 0x23, 0x0c, 0xc5, 0x8c, 0xcc, 0x0e, 0x89, 0x8e, 0x93, 0x84, 0x7c, 0xb0, 0x62, 0xfc, 0x66, 0x68, 0x97, 0x6c, 0x79, 0x62, 0xe0, 0xf6, 0x9f, 0xb4, 0xfd, 0xb6, 0x2e],
+        [0x86, 0xa4, 0x4b, 0x19, 0x62, 0xe5, 0xb4, 0xaf, 0xdd, 0x72, 0x9e, 0xdc, 0x75, 0xa4, 0xa1, 0x47, 0x43, 0x5a, 0x51, 0x2c, 0x7a, 0x24, 0x4f, 0x29, 0x72, 0xb7, 0x3a, 0x76, 0xd5, 0x53, 0xb5, 0x26],
+    ],
+    [
+        [0xea, 0x7e, 0xf1, 0x17, 0xdf, 0x8a, 0x46, 0x16, 0x1b, 0x39, 0x73, 0xb0, 0xfe, 0x6f, 0x84, 0xb8, 0x3c, 0x8b, 0xdd, 0x3a, 0x7e, 0x01, 0x7d, 0xd5, 0x56, 0xd8, 0xf8, 0xb8, 0xca, 0xc9, 0x1b, 0x02],
+        [0x60, 0xcf, 0xfb, 0xe4, 0x9f, 0x00, 0xf4, 0x0c, 0x9d, 0xfb, 0xd2, 0x86, 0xe8, 0x6a, 0x1b, 0x09, 0x17, 0xae, 0x7f, 0x8a, 0xc1, 0xe5, 0xf3, 0x68, 0xba, 0x3b, 0xb8, 0xaf, 0x23, 0x56, 0x20, 0x00],
+        [0xa4, 0xa0, 0xcf, 0x47, 0xa0, 0xab, 0xc9, 0x50, 0xea, 0x63, 0xc4, 0x6c, 0xc9, 0x1f, 0x3b, 0x12, 0x0c, 0x3a, 0x75, 0x44, 0xb2, 0x66, 0x3b, 0x41, 0x7f, 0x24, 0xe7, 0x3a, 0x9b, 0x21, 0x0a, 0x29],
+        [0xa1, 0xb8, 0x38, 0xba, 0x20, 0x89, 0xc9, 0x04, 0x57, 0x1e, 0xd9, 0xfd, 0x71, 0x38, 0xbd, 0x29, 0xce, 0x94, 0x0c, 0xf8, 0x98, 0x0b, 0x95, 0x33, 0x54, 0x36, 0xb7, 0xb5, 0x8c, 0xe9, 0x65, 0x17],
+    ],
+    [
+        [0x0e, 0xba, 0x6a, 0x8e, 0xf1, 0x6b, 0xab, 0xc2, 0xc1, 0xf7, 0xb3, 0x26, 0xc6, 0x5e, 0x4b, 0x34, 0xd0, 0x40, 0xf8, 0xca, 0x78, 0xf4, 0xe1, 0xb5, 0x6c, 0xf3, 0xfb, 0xbf, 0x37, 0xcc, 0x26, 0x23],
+        [0xdc, 0x12, 0xb6, 0x42, 0x6c, 0x77, 0x99, 0x8a, 0x77, 0x0e, 0xc4, 0x33, 0xde, 0x71, 0xcc, 0x5e, 0xe0, 0x23, 0xfd, 0x7c, 0x2f, 0x70, 0xa9, 0x42, 0xf3, 0x59, 0x3a, 0x42, 0x86, 0x82, 0x25, 0x2c],
+        [0xe3, 0xde, 0x18, 0x82, 0x8a, 0x34, 0x13, 0x3d, 0x9b, 0x36, 0x1f, 0xd9, 0xeb, 0xbd, 0xa8, 0x6b, 0x99, 0x50, 0x09, 0x25, 0x06, 0x0a, 0xb3, 0x5d, 0x29, 0x80, 0xf5, 0xb5, 0x10, 0x5f, 0x00, 0x37],
+        [0x65, 0x2d, 0x43, 0x31, 0xa5, 0x4a, 0xa1, 0x55, 0x75, 0x32, 0xc7, 0x20, 0x24, 0xdf, 0xa7, 0x03, 0xfd, 0xc3, 0x33, 0xe9, 0x69, 0x07, 0x7a, 0x99, 0xf2, 0x9b, 0x5c, 0x49, 0x63, 0x9c, 0x7b, 0x09],
+    ],
+    [
+        [0x25, 0x01, 0x77, 0x5d, 0xd4, 0xb9, 0x36, 0x17, 0x69, 0xd4, 0xf4, 0xd5, 0xef, 0xef, 0x9a, 0x3a, 0x35, 0xcd, 0x50, 0x8b, 0xe6, 0x99, 0x45, 0x5d, 0xe2, 0x4f, 0x36, 0xa6, 0x13, 0xd0, 0x97, 0x30],
+        [0x2c, 0x74, 0x92, 0x51, 0xf3, 0x98, 0x36, 0x2c, 0x76, 0xca, 0x72, 0x7a, 0x57, 0x58, 0x68, 0xf9, 0xfe, 0x12, 0x60, 0x71, 0x3f, 0xef, 0xcf, 0x89, 0xb9, 0xad, 0xa2, 0x72, 0xbc, 0xb1, 0xca, 0x14],
+        [0x95, 0xcb, 0x72, 0x76, 0x53, 0x52, 0x52, 0xc1, 0x35, 0xcf, 0x60, 0xa0, 0x77, 0x56, 0x2b, 0x41, 0xcf, 0xbb, 0x50, 0xe7, 0xb3, 0x58, 0x91, 0xf1, 0xc0, 0x27, 0xb1, 0xec, 0x02, 0x15, 0x40, 0x20],
+        [0xe6, 0x3a, 0x3a, 0xf1, 0x09, 0x00, 0x45, 0xc4, 0x8d, 0x6d, 0xfb, 0xad, 0x20, 0xd6, 0xbd, 0x42, 0x54, 0xbc, 0x9a, 0x1c, 0x4c, 0x92, 0x0a, 0xa2, 0x5d, 0xe3, 0x54, 0x06, 0xb4, 0x6e, 0x71, 0x1e],
+    ],
+    [
+        [0x59, 0x0c, 0xb7, 0xee, 0xf7, 0x57, 0xfb, 0x1b, 0x77, 0xc2, 0x1a, 0xd2, 0x04, 0x58, 0xda, 0xb6, 0x35, 0x05, 0x25, 0xfc, 0x44, 0x6d, 0xc8, 0x20, 0x07, 0xc0, 0x08, 0xff, 0x4e, 0xf6, 0x9a, 0x37],
+        [0xd0, 0x34, 0x3d, 0x5b, 0xbb, 0xdc, 0xae, 0x74, 0x33, 0x2b, 0xaa, 0xb2, 0x5d, 0x39, 0x93, 0xfc, 0xa1, 0xf4, 0x0d, 0xf3, 0x3e, 0xc0, 0x0e, 0x1e, 0x88, 0x99, 0x88, 0xaf, 0x99, 0x09, 0x7b, 0x09],
+        [0xed, 0x63, 0xee, 0xf6, 0x16, 0x3f, 0xaa, 0xcc, 0xa6, 0x39, 0x46, 0x1f, 0xac, 0x81, 0x85, 0xbf, 0xaa, 0x55, 0x5f, 0x1f, 0x4e, 0x52, 0xe2, 0xdf, 0xa5, 0x26, 0xed, 0x7c, 0x6f, 0xd6, 0x21, 0x1b],
+        [0x06, 0xa4, 0x1f, 0x0b, 0xcb, 0x18, 0xa9, 0x7e, 0x7d, 0x08, 0x7a, 0xb3, 0x89, 0x3e, 0xae, 0x0a, 0xba, 0x3a, 0x69, 0xa1, 0xed, 0x81, 0x3a, 0xdd, 0xa6, 0x67, 0xe7, 0xe0, 0x5d, 0x35, 0xe5, 0x15],
+    ],
+    [
+        [0x6d, 0xc2, 0x3d, 0xcf, 0xb9, 0x20, 0x59, 0x93, 0x04, 0xf4, 0x62, 0x4f, 0xee, 0x19, 0xce, 0x65, 0xbe, 0xb5, 0xf9, 0x9d, 0x90, 0x4d, 0xbf, 0x5d, 0xe3, 0xc9, 0x89, 0x7f, 0x36, 0xa5, 0x57, 0x0a],
+        [0xc9, 0xb0, 0x59, 0x0b, 0xa9, 0x01, 0x4a, 0x24, 0x47, 0x32, 0x6a, 0xc3, 0x82, 0x1c, 0xc1, 0xa0, 0xcb, 0xcc, 0xc8, 0x35, 0x21, 0x8f, 0xa9, 0x7f, 0x98, 0xea, 0x2d, 0x0e, 0xce, 0x93, 0x8c, 0x0e],
+        [0x87, 0x59, 0x8a, 0x83, 0xe1, 0x5e, 0xf2, 0x3a, 0x95, 0x0d, 0x40, 0x5d, 0x00, 0xab, 0x95, 0x5a, 0xea, 0x97, 0x89, 0xc8, 0x7e, 0x10, 0xcf, 0x3d, 0x25, 0xa6, 0x23, 0x6d, 0xa5, 0x2a, 0x57, 0x06],
+        [0x47, 0xa1, 0xa9, 0xfc, 0xaa, 0xef, 0xab, 0xbd, 0xf2, 0xb2, 0xb8, 0xd0, 0x6d, 0xcb, 0x74, 0xfe, 0xfc, 0x65, 0x6e, 0x0d, 0xf4, 0xdd, 0x1c, 0x7c, 0x99, 0x0a, 0xfb, 0x88, 0xe0, 0xee, 0xa3, 0x1a],
+    ],
+    [
+        [0x8f, 0x25, 0xe6, 0x4d, 0x16, 0xa7, 0x93, 0x75, 0x3f, 0x77, 0x26, 0x64, 0xeb, 0xb4, 0x38, 0x30, 0xa1, 0x7b, 0xfb, 0x15, 0x97, 0x85, 0x5b, 0x2a, 0x45, 0xd2, 0x89, 0x4e, 0x02, 0x67, 0x2c, 0x2e],
+        [0xd4, 0x0e, 0x8f, 0xa4, 0xf9, 0xc4, 0xee, 0x4b, 0xf8, 0x4b, 0xb8, 0xc8, 0x91, 0x8f, 0x15, 0x07, 0xe4, 0x7d, 0xab, 0x84, 0xf5, 0xd5, 0xca, 0x9c, 0x88, 0x9d, 0xc1, 0xcd, 0xe2, 0xef, 0xc7, 0x30],
+        [0xc0, 0x6b, 0x6a, 0xed, 0x1d, 0xae, 0xd0, 0xd0, 0xa3, 0x78, 0x0c, 0x66, 0x1b, 0x89, 0x2e, 0xe4, 0xc4, 0xe6, 0xd6, 0xfe, 0xd6, 0x63, 0x2c, 0x3d, 0xcb, 0xb7, 0x7e, 0xd6, 0xfe, 0x7d, 0x7f, 0x37],
+        [0x43, 0x7a, 0xbc, 0xf1, 0xe9, 0xd2, 0x9a, 0x90, 0x5f, 0x2e, 0x19, 0x79, 0xa9, 0x05, 0x0e, 0x6e, 0x14, 0xd5, 0xb2, 0xe9, 0xc8, 0x12, 0xfa, 0x2c, 0x99, 0xb8, 0xff, 0xde, 0x6d, 0x10, 0x2c, 0x00],
+    ],
+    [
+        [0xf8, 0xc9, 0x88, 0x73, 0xb1, 0xae, 0x96, 0xc8, 0x8b, 0x74, 0xd8, 0x13, 0x31, 0x23, 0x5d, 0x3f, 0xf5, 0x97, 0x53, 0xed, 0x90, 0x11, 0xd4, 0x8f, 0x16, 0x10, 0x10, 0x22, 0x03, 0xae, 0x12, 0x27],
+        [0x75, 0x69, 0x14, 0x6f, 0x2c, 0x56, 0xa4, 0x50, 0x09, 0x30, 0xe9, 0xf0, 0xcf, 0x70, 0x8c, 0x09, 0x0a, 0xc5, 0x94, 0x70, 0x3d, 0xb9, 0x60, 0x17, 0x6c, 0xc7, 0x65, 0xdc, 0xc5, 0x84, 0x1d, 0x04],
+        [0x5f, 0xf3, 0xe2, 0xdc, 0x89, 0xf9, 0xa2, 0x25, 0xc8, 0xfb, 0x25, 0x0b, 0xbc, 0xad, 0xd2, 0x45, 0x56, 0xf3, 0x84, 0xd5, 0x01, 0x93, 0xea, 0x1c, 0x5f, 0x17, 0xf9, 0xd6, 0xc8, 0x5d, 0xb6, 0x14],
+        [0x25, 0x61, 0x8b, 0x71, 0x73, 0xc2, 0x46, 0x74, 0xe0, 0xae, 0x38, 0x5d, 0xa7, 0x96, 0x91, 0x95, 0x2a, 0xb4, 0x26, 0x09, 0x2e, 0xdf, 0xdd, 0xaa, 0xb6, 0xae, 0xf0, 0xcf, 0xca, 0x29, 0xe4, 0x31],
+    ],
+    [
+        [0x3c, 0x7d, 0x06, 0x4b, 0xe0, 0xa9, 0x43, 0xeb, 0xde, 0xa0, 0x3d, 0x0e, 0x1f, 0xbd, 0xbf, 0x7e, 0x20, 0xca, 0x8b, 0x03, 0xc7, 0x18, 0xce, 0x29, 0x4c, 0x26, 0x6c, 0x8f, 0x52, 0x41, 0xda, 0x33],
+        [0x8d, 0x4f, 0x30, 0xcd, 0x81, 0x9c, 0xec, 0x8c, 0x73, 0x77, 0x50, 0xd5, 0x5c, 0xb7, 0x0c, 0xaa, 0x79, 0x4e, 0x0a, 0xbc, 0xa5, 0xc3, 0xdd, 0xb3, 0x53, 0xa3, 0x17, 0x90, 0x99, 0x18, 0x7a, 0x30],
+        [0xc9, 0x95, 0x5f, 0xbf, 0x4c, 0xc9, 0x0b, 0x95, 0x70, 0x34, 0x88, 0x23, 0x82, 0x73, 0x67, 0xb5, 0x54, 0xa5, 0x1b, 0x31, 0xd7, 0x58, 0xd3, 0x84, 0x9d, 0x8a, 0x89, 0xc2, 0x5d, 0x2d, 0x02, 0x19],
+        [0x83, 0x4d, 0x45, 0x11, 0x0e, 0xd1, 0xaa, 0xd8, 0xc2, 0x8d, 0x43, 0xd1, 0xa0, 0x9e, 0xe3, 0xd2, 0x2a, 0x6e, 0xab, 0x3c, 0x80, 0xcb, 0xa5, 0x1c, 0x54, 0x6d, 0x17, 0x02, 0x55, 0xd7, 0x53, 0x20],
+    ],
+    [
+        [0x76, 0xf9, 0xae, 0x6d, 0x9f, 0x48, 0x1b, 0x19, 0x26, 0x82, 0x21, 0x82, 0xed, 0x46, 0x51, 0x88, 0xa5, 0x41, 0x50, 0x66, 0x56, 0xd5, 0x39, 0x50, 0x10, 0x4d, 0x06, 0x54, 0x63, 0xcf, 0x7a, 0x22],
+        [0xa8, 0x7a, 0xfd, 0xfa, 0xfa, 0x2a, 0xae, 0x27, 0x4b, 0x77, 0x0a, 0xc2, 0x88, 0x93, 0x86, 0x4e, 0x1c, 0x69, 0xcb, 0x4a, 0xbf, 0x84, 0xd6, 0xec, 0x0c, 0x10, 0x74, 0x6a, 0x8a, 0x9f, 0xae, 0x26],
+        [0x82, 0xc6, 0x89, 0xc6, 0x20, 0xcb, 0x0d, 0xb5, 0x87, 0xfa, 0x4a, 0xe5, 0x43, 0x65, 0x56, 0xdc, 0x09, 0x16, 0x77, 0x7b, 0x73, 0x05, 0x9c, 0xd9, 0x2c, 0x7e, 0x07, 0xcd, 0x65, 0x82, 0xaa, 0x03],
+        [0x5c, 0x37, 0x47, 0x90, 0xc5, 0x21, 0xf1, 0xd1, 0xd4, 0xeb, 0x30, 0x55, 0xf5, 0x97, 0x16, 0xe6, 0x4a, 0xf7, 0x70, 0x6a, 0xfc, 0x9e, 0xab, 0x61, 0x9a, 0x25, 0xe3, 0xa1, 0x75, 0x56, 0xe1, 0x15],
+    ],
+    [
+        [0xd6, 0xbb, 0x77, 0x83, 0x2e, 0x1d, 0x62, 0x76, 0x8d, 0x40, 0x81, 0x5c, 0x0a, 0x3e, 0xfa, 0xbb, 0xb5, 0xbf, 0xdc, 0xff, 0x15, 0xd2, 0x04, 0xca, 0x3d, 0xdc, 0xd5, 0x83, 0xfe, 0xf9, 0xb5, 0x25],
+        [0x0b, 0x64, 0xaa, 0x8c, 0x4d, 0x1a, 0x4f, 0x33, 0x99, 0x66, 0x96, 0x8f, 0xb4, 0xab, 0x84, 0xc0, 0xca, 0xbe, 0x69, 0xfa, 0x34, 0x99, 0x04, 0x5e, 0x1c, 0xb0, 0xa2, 0x0c, 0x8f, 0xe7, 0x88, 0x25],
+        [0x1f, 0x43, 0x46, 0xb3, 0x9b, 0xf7, 0x5a, 0xb5, 0x8b, 0x78, 0x90, 0x5c, 0xb6, 0x70, 0x52, 0x6c, 0xdd, 0xfd, 0x13, 0x82, 0x67, 0x26, 0x3b, 0x1b, 0x31, 0xfe, 0xeb, 0x40, 0xdb, 0x5d, 0xbe, 0x19],
+        [0x21, 0xcb, 0x0a, 0x8d, 0x93, 0xd8, 0xa8, 0x21, 0xbb, 0xc9, 0x1e, 0x8e, 0x83, 0xa5, 0x6c, 0x4f, 0xb5, 0xd8, 0x10, 0x2b, 0xc3, 0xdf, 0xe3, 0x58, 0x82, 0x3c, 0x60, 0x17, 0x9d, 0x70, 0x1a, 0x32],
+    ],
+    [
+        [0x72, 0x5d, 0x72, 0x9e, 0x0d, 0x59, 0x64, 0x31, 0x09, 0xd0, 0xde, 0xdf, 0x94, 0xc1, 0x4c, 0x02, 0x16, 0xf3, 0xe9, 0x6d, 0xad, 0x73, 0xd3, 0x74, 0xeb, 0x19, 0x4b, 0x86, 0xc1, 0x28, 0x5c, 0x30],
+        [0x0b, 0x3f, 0x08, 0xe5, 0x64, 0x93, 0x1a, 0x89, 0x59, 0x8e, 0x8b, 0xea, 0x0b, 0x31, 0xa2, 0x36, 0x7a, 0x19, 0xf5, 0xc4, 0x79, 0x32, 0x6d, 0xe6, 0x9b, 0x9f, 0x41, 0xba, 0xaf, 0x70, 0xfd, 0x26],
+        [0x0a, 0xcc, 0xc8, 0x75, 0xa5, 0xb9, 0xed, 0x90, 0x9d, 0xa4, 0x0d, 0xbd, 0x83, 0x61, 0x05, 0x36, 0x4e, 0x8b, 0x3e, 0x85, 0xae, 0x0c, 0x43, 0x5e, 0x76, 0xe8, 0x32, 0xa3, 0x0d, 0x33, 0x27, 0x3e],
+        [0x19, 0x30, 0x71, 0x86, 0x0c, 0xf5, 0x30, 0xd4, 0xc5, 0x24, 0x77, 0x5c, 0xe9, 0xad, 0x1f, 0xab, 0xcf, 0x37, 0xe5, 0x22, 0x78, 0x3c, 0x6f, 0x76, 0xf1, 0xbb, 0xc5, 0x86, 0xf5, 0x56, 0x65, 0x10],
+    ],
+    [
+        [0x6f, 0xce, 0x4e, 0x56, 0xd9, 0x4e, 0xc8, 0x74, 0xa9, 0x57, 0x92, 0xa6, 0x2c, 0x2b, 0xe9, 0x9c, 0x5a, 0xb3, 0xfb, 0xa3, 0xa3, 0xec, 0xcd, 0x68, 0x2a, 0x1b, 0x5a, 0x20, 0xe2, 0x1b, 0xa6, 0x05],
+        [0x61, 0x14, 0x20, 0x52, 0x7a, 0x92, 0x9f, 0x8d, 0x73, 0xf2, 0xa6, 0xb9, 0xb8, 0x93, 0xbe, 0xa1, 0x6d, 0xcf, 0xb7, 0x32, 0xba, 0x8e, 0x1d, 0xa4, 0x1a, 0xa5, 0xa5, 0x99, 0x93, 0x7f, 0xf0, 0x3b],
+        [0xc5, 0x87, 0x9e, 0x0f, 0x19, 0x24, 0xb0, 0xca, 0xa7, 0xcf, 0x29, 0xf3, 0xdf, 0xc9, 0x9f, 0x27, 0x2e, 0xb8, 0x0a, 0x50, 0xae, 0xaf, 0xb5, 0x23, 0x3c, 0xa9, 0x8c, 0x41, 0x90, 0x75, 0xd0, 0x02],
+        [0x9b, 0x34, 0xbe, 0x9d, 0x39, 0x69, 0xf8, 0x4a, 0x70, 0x81, 0x5b, 0x38, 0x1c, 0xe7, 0xa9, 0xd1, 0x69, 0x2b, 0xee, 0x53, 0x9a, 0xa5, 0x18, 0x30, 0x4b, 0x8c, 0xce, 0xb1, 0x75, 0xdf, 0xdc, 0x3d],
+    ],
+    [
+        [0x46, 0x66, 0x35, 0x05, 0x37, 0xbe, 0x00, 0xac, 0x6d, 0x89, 0x50, 0x1b, 0xad, 0xd3, 0xa3, 0x4e, 0x82, 0xcd, 0x23, 0xe9, 0x8d, 0x5c, 0xcd, 0x80, 0x61, 0xe8, 0x10, 0xa7, 0x6f, 0x95, 0x72, 0x10],
+        [0x73, 0x85, 0xb9, 0xaf, 0xfc, 0x05, 0xb7, 0x6b, 0x08, 0xc5, 0x50, 0x7f, 0xe8, 0xe5, 0x72, 0x8a, 0x01, 0xea, 0xdd, 0xef, 0x74, 0xbc, 0xd9, 0x7d, 0x4c, 0x85, 0x8b, 0x83, 0x7a, 0x7f, 0x96, 0x3b],
+        [0x8c, 0x29, 0x24, 0xd0, 0x85, 0xac, 0x19, 0xd9, 0xea, 0x1f, 0x87, 0x4f, 0x8f, 0xd7, 0x4e, 0x12, 0xf5, 0x1b, 0x35, 0x00, 0xdd, 0x84, 0x8a, 0x3c, 0x56, 0x4e, 0x9f, 0x16, 0xa4, 0x6a, 0xc8, 0x15],
+        [0x12, 0x6a, 0xca, 0xd7, 0x61, 0x91, 0x75, 0x71, 0x8d, 0xd1, 0xa5, 0xd5, 0x73, 0xbd, 0x76, 0xb0, 0x53, 0x4a, 0x67, 0x5f, 0x88, 0x23, 0x1a, 0x40, 0xb7, 0x37, 0xf3, 0x1b, 0xe3, 0x11, 0x83, 0x2b],
+    ],
+    [
+        [0xe3, 0x7e, 0x6d, 0x80, 0x36, 0x7d, 0xfc, 0xc1, 0x53, 0x44, 0xba, 0xe7, 0x79, 0xe2, 0x8e, 0x41, 0xa4, 0xe2, 0xf5, 0xf9, 0x72, 0x2d, 0xac, 0xc3, 0x3c, 0xcd, 0x4f, 0xd4, 0x7d, 0xdb, 0x28, 0x2a],
+        [0xbd, 0xa9, 0x0c, 0x66, 0x45, 0x6b, 0xe4, 0x72, 0x0a, 0x5c, 0xe5, 0xce, 0x8c, 0x8a, 0x4a, 0x89, 0x39, 0x17, 0xdb, 0xa7, 0xf2, 0x45, 0x02, 0x9a, 0x7d, 0x84, 0x69, 0xae, 0xb4, 0x36, 0xe3, 0x20],
+        [0x16, 0x5c, 0xc4, 0xce, 0x9f, 0x5c, 0xc1, 0x72, 0x0e, 0x71, 0xe1, 0x43, 0x60, 0x60, 0xbc, 0x29, 0xcb, 0x23, 0x78, 0x0f, 0xb6, 0x28, 0xf8, 0x43, 0xd1, 0x0f, 0x34, 0x04, 0xa0, 0x98, 0x3e, 0x1f],
+        [0x59, 0x52, 0x0c, 0x9f, 0xa9, 0xae, 0xfe, 0x3b, 0x25, 0x8f, 0xe1, 0x84, 0xbc, 0x3e, 0x8d, 0xaa, 0xf2, 0x6b, 0x69, 0x36, 0x41, 0xe3, 0x6a, 0xad, 0x4b, 0x16, 0xad, 0x49, 0x53, 0x4d, 0xab, 0x25],
+    ],
+    [
+        [0x9d, 0xb4, 0xc9, 0xf6, 0x73, 0x32, 0x9b, 0x97, 0x20, 0x10, 0x81, 0xdb, 0x7e, 0x4d, 0x95, 0xa7, 0xfb, 0x2a, 0x04, 0xeb, 0x6f, 0xde, 0x2f, 0x20, 0x60, 0x4a, 0x3e, 0x48, 0x9b, 0xcb, 0x62, 0x22],
+        [0x7a, 0x08, 0xeb, 0x1f, 0x98, 0xc7, 0x37, 0x27, 0x96, 0x11, 0xf1, 0x93, 0x94, 0xb7, 0xda, 0xdd, 0xf8, 0xcd, 0x1d, 0x19, 0x22, 0xa4, 0x96, 0x50, 0x1d, 0x8b, 0xae, 0x05, 0x1d, 0x5a, 0x8f, 0x12],
+        [0xbf, 0x99, 0x0d, 0xf9, 0x43, 0x12, 0xd1, 0x31, 0xf5, 0x10, 0xef, 0xa4, 0x70, 0x3a, 0xee, 0x82, 0xb7, 0x73, 0x41, 0x41, 0x52, 0xe1, 0x67, 0x37, 0xbe, 0x8e, 0x92, 0xf2, 0xf1, 0xca, 0x00, 0x04],
+        [0x46, 0x28, 0xcb, 0x62, 0xa7, 0x41, 0xf7, 0xcd, 0x8b, 0x3e, 0xd7, 0x1a, 0xc7, 0x35, 0xe5, 0x32, 0x52, 0x4b, 0x78, 0x4e, 0x33, 0x9a, 0x51, 0xd8, 0xa9, 0xb4, 0xcf, 0x4a, 0x56, 0x74, 0xee, 0x3e],
+    ],
+    [
+        [0xe3, 0x22, 0xf5, 0x52, 0x73, 0x03, 0x73, 0x8f, 0x3c, 0xad, 0x12, 0xf4, 0x1d, 0x8b, 0x31, 0x48, 0xa0, 0x6a, 0xea, 0x36, 0x8a, 0xf8, 0x27, 0xb8, 0x27, 0xc6, 0xed, 0x7d, 0x40, 0xe4, 0xf2, 0x30],
+        [0x00, 0xe2, 0x64, 0x7d, 0x2a, 0x67, 0x58, 0x99, 0xcf, 0xa9, 0xcc, 0x0c, 0x96, 0xd7, 0xce, 0x4b, 0x77, 0x41, 0x27, 0xdf, 0xfd, 0xc5, 0x52, 0xe4, 0x3b, 0xb4, 0x1c, 0xa7, 0x3d, 0xb2, 0xbe, 0x0c],
+        [0xc0, 0x95, 0x9c, 0x44, 0xc3, 0xcf, 0xe6, 0xf0, 0x03, 0xa6, 0x04, 0x59, 0x1b, 0x19, 0xbb, 0xf9, 0xf3, 0xbb, 0x19, 0x77, 0xfe, 0x6a, 0x92, 0x57, 0x5f, 0xfd, 0x76, 0x5a, 0x8c, 0xaf, 0x89, 0x0b],
+        [0x06, 0x03, 0xeb, 0xfa, 0x1b, 0xfb, 0x86, 0xdd, 0x81, 0x86, 0x70, 0xf7, 0xab, 0xa6, 0x9d, 0x4e, 0xfa, 0xfb, 0x1d, 0x08, 0xb0, 0xc6, 0x57, 0x9d, 0x6e, 0x05, 0x52, 0x7f, 0x2d, 0x65, 0x7b, 0x1c],
+    ],
+    [
+        [0xb7, 0x98, 0xed, 0x1c, 0x73, 0x67, 0xb3, 0xa4, 0xd5, 0x6c, 0xa3, 0x2e, 0x45, 0x5c, 0x17, 0x80, 0x70, 0x15, 0xa7, 0x09, 0xa6, 0xae, 0xbc, 0x3f, 0xe7, 0xd3, 0x76, 0x34, 0xd0, 0x99, 0x7c, 0x2a],
+        [0xc4, 0xe3, 0x31, 0x96, 0x59, 0x73, 0x91, 0x2c, 0x5a, 0x9b, 0x65, 0x2c, 0x85, 0xbd, 0x06, 0x7c, 0x25, 0xf6, 0x45, 0x4c, 0x81, 0x59, 0x13, 0x95, 0x58, 0x9e, 0x52, 0xc7, 0xa1, 0xff, 0xcf, 0x02],
+        [0x0e, 0xdd, 0x0e, 0x6a, 0x7e, 0xb0, 0xfe, 0xe2, 0xf2, 0xe6, 0x0a, 0xcd, 0xcd, 0xab, 0x79, 0x97, 0xcc, 0x79, 0x5a, 0x28, 0x9b, 0x44, 0xbc, 0xcb, 0x61, 0x2f, 0x6c, 0xf8, 0xfa, 0xb1, 0xb5, 0x15],
+        [0x6c, 0x8d, 0x71, 0x59, 0xa2, 0xa3, 0x36, 0x4a, 0x33, 0xd6, 0xe0, 0xb3, 0x3c, 0xa8, 0xb9, 0x14, 0xd8, 0x98, 0xda, 0x0f, 0x85, 0x27, 0xa9, 0x40, 0x84, 0x43, 0x8c, 0x74, 0x54, 0xf9, 0x61, 0x0c],
+    ],
+    [
+        [0x31, 0xd0, 0x1b, 0x27, 0x2b, 0x95, 0x96, 0xe5, 0xa2, 0x27, 0xd4, 0x85, 0xbc, 0x4d, 0xd4, 0xa8, 0xd1, 0xb1, 0x62, 0x2b, 0xc1, 0xb2, 0xf5, 0x9d, 0x4f, 0xe7, 0x04, 0x3a, 0x05, 0x88, 0xf4, 0x33],
+        [0x37, 0x67, 0xbc, 0x3d, 0xf0, 0x24, 0x83, 0xd7, 0xfe, 0xb3, 0x8d, 0x60, 0x08, 0xe5, 0xef, 0x02, 0xe2, 0x84, 0xd6, 0xc9, 0x1e, 0xfa, 0x74, 0x53, 0x52, 0x07, 0xe2, 0x2c, 0x28, 0x9b, 0xc3, 0x23],
+        [0x41, 0x2a, 0x92, 0x20, 0xfc, 0x16, 0xc3, 0x12, 0x11, 0xd5, 0x6c, 0x53, 0x12, 0xba, 0xb5, 0xdb, 0x64, 0xda, 0xa1, 0xc6, 0xb5, 0xa5, 0x21, 0xfd, 0xa4, 0x49, 0xd0, 0xc5, 0xa9, 0xcb, 0xe0, 0x38],
+        [0x74, 0x03, 0xe8, 0x84, 0x04, 0xf0, 0xb0, 0x79, 0x98, 0xae, 0xb5, 0x36, 0xdb, 0x15, 0x0a, 0x7c, 0xfe, 0xce, 0xc5, 0xe7, 0x3a, 0x5f, 0x49, 0xd8, 0x8f, 0x87, 0x7f, 0x1b, 0x19, 0xe6, 0x68, 0x06],
+    ],
+    [
+        [0xb2, 0x74, 0x61, 0x44, 0x9b, 0xd8, 0x47, 0x34, 0x96, 0xfa, 0x5a, 0xe6, 0xe2, 0x14, 0xec, 0x29, 0x11, 0xf6, 0x03, 0x7b, 0xec, 0x69, 0x69, 0xaf, 0xbf, 0x7f, 0x72, 0x15, 0x3a, 0xe5, 0xca, 0x0a],
+        [0x72, 0xa4, 0x48, 0x41, 0x21, 0x4b, 0x6d, 0x8b, 0x52, 0x55, 0x2f, 0x92, 0x2d, 0x88, 0x15, 0x68, 0x14, 0x97, 0xba, 0x42, 0x47, 0xce, 0x48, 0xe2, 0x25, 0x3d, 0xb5, 0xdf, 0xdb, 0x10, 0x3c, 0x2c],
+        [0x00, 0x98, 0xc0, 0x2e, 0xa3, 0x46, 0xcf, 0x7f, 0x86, 0x36, 0x7b, 0x66, 0x9e, 0x86, 0xb3, 0xca, 0xa7, 0x1a, 0x13, 0x41, 0x73, 0x7c, 0xf4, 0x8f, 0x8e, 0xa5, 0xee, 0x7d, 0xea, 0xbc, 0xb1, 0x09],
+        [0xf0, 0x7e, 0xed, 0xb2, 0x79, 0xa2, 0x43, 0x3b, 0xcf, 0xe4, 0x65, 0xe8, 0x5e, 0xf6, 0x08, 0x25, 0x77, 0x5a, 0x6f, 0x8e, 0xd0, 0x6f, 0x98, 0x05, 0x45, 0x54, 0xaa, 0x56, 0x7c, 0xb7, 0x6b, 0x31],
+    ],
+    [
+        [0xce, 0x6b, 0xa7, 0x7e, 0x9f, 0x83, 0x2d, 0xce, 0x3c, 0xdb, 0xa0, 0x44, 0x6d, 0xd1, 0x03, 0x0c, 0x2f, 0xf8, 0x16, 0x4f, 0x14, 0x8f, 0xdd, 0x2f, 0x52, 0x5b, 0xdf, 0xa2, 0xe8, 0xc6, 0xad, 0x20],
+        [0x2e, 0xf4, 0x66, 0x29, 0x5b, 0x72, 0x4f, 0xd0, 0x28, 0x29, 0xf8, 0x1e, 0x58, 0x0e, 0x0a, 0x9a, 0x54, 0x2a, 0x8d, 0x1d, 0xd2, 0xcc, 0xa4, 0x1d, 0x8b, 0xf1, 0x6f, 0xbf, 0xdf, 0x43, 0xff, 0x35],
+        [0x68, 0x73, 0x8e, 0x88, 0x20, 0x1f, 0x83, 0x2f, 0x12, 0x31, 0xd6, 0xe1, 0xf8, 0x6b, 0x44, 0x28, 0x42, 0x04, 0x8e, 0x66, 0xeb, 0x6b, 0x1e, 0x94, 0x90, 0x5a, 0x12, 0x4e, 0xf2, 0x80, 0xde, 0x1a],
+        [0x63, 0xb7, 0x97, 0x82, 0xfd, 0x82, 0xc9, 0x12, 0x88, 0x48, 0xda, 0x48, 0xa1, 0x96, 0x35, 0x89, 0x6d, 0x1b, 0x8d, 0x3a, 0x91, 0x42, 0x9e, 0xe2, 0xd9, 0x80, 0x84, 0xee, 0x7d, 0xe3, 0x45, 0x09],
+    ],
+    [
+        [0x23, 0x7a, 0x48, 0xd5, 0x6a, 0xae, 0xbe, 0x66, 0xa5, 0x65, 0x26, 0x17, 0x01, 0xb8, 0x7c, 0xd1, 0xdd, 0xc9, 0x5d, 0xcd, 0xbc, 0xdc, 0x2b, 0xb1, 0x9f, 0xc6, 0x66, 0x58, 0x61, 0x11, 0x41, 0x04],
+        [0x39, 0x9b, 0x94, 0x2d, 0xb0, 0xf7, 0xf7, 0xe1, 0xc7, 0x43, 0x77, 0xc0, 0x3b, 0xe0, 0xf1, 0x0b, 0x85, 0xe1, 0x28, 0x87, 0xe1, 0x8d, 0x8e, 0xcb, 0x2a, 0x05, 0xe1, 0x0a, 0x4d, 0xbc, 0x5e, 0x37],
+        [0x29, 0x24, 0xe6, 0x92, 0x01, 0x74, 0x60, 0x89, 0x6e, 0xbf, 0x89, 0xac, 0xd7, 0x66, 0xa4, 0x59, 0xcd, 0x22, 0xaf, 0x52, 0x96, 0xa5, 0xb8, 0xc9, 0xfa, 0x53, 0x32, 0x24, 0xf1, 0x4f, 0x82, 0x15],
+        [0xd1, 0x62, 0x7c, 0xd3, 0xfe, 0xd1, 0x41, 0x99, 0x28, 0xcf, 0x8f, 0x16, 0x4c, 0x7a, 0x92, 0x2b, 0x67, 0x8f, 0x08, 0xc6, 0x7a, 0x09, 0x41, 0xc1, 0x4f, 0xfc, 0x28, 0x7d, 0x16, 0xe8, 0x95, 0x1e],
+    ],
+    [
+        [0x3f, 0x57, 0xc7, 0xbf, 0xeb, 0xea, 0x84, 0xe9, 0xe0, 0x81, 0x7d, 0xda, 0x05, 0x31, 0x30, 0x7d, 0x3a, 0x0d, 0xe9, 0x87, 0x53, 0xaa, 0x39, 0x8e, 0xff, 0x2a, 0x5f, 0x34, 0x40, 0x08, 0xc0, 0x1e],
+        [0x7e, 0xd2, 0xdd, 0xc0, 0x50, 0xf3, 0x73, 0xa4, 0x7a, 0x98, 0x5c, 0x2a, 0x94, 0xc2, 0x69, 0x3d, 0xc5, 0x3f, 0xf4, 0xf6, 0x65, 0x8d, 0x94, 0xfe, 0x5c, 0x8a, 0x4d, 0xd1, 0xcd, 0xff, 0xe8, 0x28],
+        [0xa4, 0x1a, 0x36, 0x46, 0xbf, 0xbe, 0x10, 0x25, 0x36, 0x5a, 0x85, 0xcd, 0xa1, 0xe9, 0x01, 0x6c, 0xa7, 0x5e, 0xb4, 0xa0, 0x52, 0x6a, 0x0e, 0xdd, 0x31, 0x78, 0x61, 0xc7, 0xfb, 0x63, 0x73, 0x1f],
+        [0x3c, 0xb4, 0x48, 0xc9, 0xf9, 0x1b, 0x6d, 0xee, 0x23, 0x40, 0x07, 0x7a, 0xe5, 0x5b, 0xe6, 0xa9, 0x37, 0x3a, 0xd3, 0x10, 0x8c, 0x24, 0xa5, 0x7e, 0x89, 0x97, 0xf3, 0xac, 0x55, 0x8a, 0x8c, 0x1b],
+    ],
+    [
+        [0xa8, 0xcc, 0xfa, 0x67, 0x73, 0x2f, 0x16, 0xb8, 0xb7, 0xbd, 0x01, 0xe1, 0x57, 0x50, 0x05, 0x85, 0xf7, 0xca, 0xf7, 0x7f, 0xf3, 0xb5, 0xa2, 0x10, 0x37, 0xe7, 0x5d, 0xff, 0x0f, 0xdc, 0x66, 0x39],
+        [0x71, 0x2f, 0xd8, 0xf0, 0x35, 0xdf, 0x6e, 0xc6, 0x21, 0x55, 0x35, 0xe4, 0x43, 0x38, 0x33, 0xd1, 0x53, 0x2c, 0x1c, 0xe1, 0x62, 0x1e, 0xbf, 0x47, 0xfe, 0x4d, 0xa4, 0x1d, 0xe5, 0xad, 0xf9, 0x02],
+        [0xce, 0x64, 0xdc, 0x7c, 0x5b, 0x1d, 0x60, 0xd9, 0x71, 0x64, 0x77, 0x1f, 0xec, 0xb4, 0x94, 0xc2, 0xa5, 0x5b, 0x06, 0x46, 0x95, 0x6a, 0x59, 0xa3, 0xb4, 0xdf, 0x0d, 0xb9, 0xe8, 0x11, 0x04, 0x0c],
+        [0x83, 0xc2, 0x94, 0x66, 0x48, 0x69, 0x17, 0x75, 0xb8, 0xba, 0xaf, 0x64, 0xfc, 0xb3, 0x46, 0xbf, 0xc6, 0xaf, 0x4b, 0xfa, 0xb4, 0x45, 0xe2, 0x0a, 0x5c, 0x23, 0x67, 0xc4, 0xb8, 0x47, 0xcb, 0x29],
+    ],
+    [
+        [0x97, 0x10, 0x3d, 0xbb, 0x10, 0x42, 0xad, 0x7f, 0x8c, 0x6e, 0x92, 0x9b, 0x26, 0x6e, 0x18, 0x46, 0x63, 0xe3, 0x8a, 0x73, 0x61, 0xee, 0x26, 0x94, 0x8e, 0x60, 0x0a, 0xc1, 0xde, 0x94, 0x99, 0x15],
+        [0xc2, 0x6e, 0x1f, 0x23, 0x06, 0x61, 0x3e, 0xe0, 0xa5, 0x50, 0xa6, 0xff, 0x70, 0xb1, 0x82, 0xe2, 0xc6, 0xae, 0x79, 0xf5, 0x84, 0xf0, 0xed, 0xe9, 0xb2, 0x13, 0x83, 0x0a, 0xc3, 0x3b, 0x62, 0x33],
+        [0x44, 0xb4, 0xc4, 0x81, 0x58, 0x8b, 0xa5, 0xc4, 0x24, 0x1a, 0x08, 0xe4, 0x5a, 0x8b, 0x81, 0x28, 0x6f, 0xa2, 0x23, 0xc9, 0xab, 0x26, 0x65, 0x9e, 0xdb, 0xdf, 0xc4, 0xf7, 0xeb, 0x3d, 0x20, 0x38],
+        [0xda, 0xbd, 0xfd, 0x47, 0x55, 0x88, 0x60, 0x07, 0x53, 0x5d, 0x5f, 0xa7, 0x87, 0x64, 0xae, 0x97, 0x11, 0x13, 0xe6, 0x86, 0x3a, 0x23, 0x1b, 0x6e, 0x20, 0x74, 0xa5, 0x84, 0x7c, 0xcd, 0xe9, 0x30],
+    ],
+    [
+        [0x7d, 0x93, 0xe1, 0x50, 0x4e, 0x24, 0xcd, 0x6c, 0x94, 0x7f, 0xe7, 0xba, 0x46, 0xd3, 0x18, 0xb1, 0x2d, 0x9e, 0x78, 0x31, 0x5e, 0x64, 0x18, 0xbd, 0xe4, 0xc5, 0xbe, 0x2f, 0x2d, 0x79, 0xcb, 0x19],
+        [0x52, 0x8c, 0x6a, 0x2e, 0x54, 0x17, 0xe4, 0x13, 0xee, 0xd8, 0x1c, 0x65, 0x0e, 0x6e, 0x73, 0x85, 0x6e, 0xfa, 0xd8, 0x62, 0x88, 0x49, 0x3b, 0xe9, 0x2a, 0x7e, 0x11, 0x20, 0x0c, 0x9b, 0x74, 0x0b],
+        [0xad, 0xbd, 0xaf, 0x35, 0x89, 0xe9, 0xb9, 0x1c, 0x06, 0x78, 0x85, 0x62, 0xdb, 0x7d, 0x49, 0xd8, 0xca, 0xd8, 0xb1, 0x2a, 0xbd, 0x86, 0x71, 0xae, 0x52, 0x1c, 0x99, 0x98, 0xfd, 0xc1, 0x72, 0x0b],
+        [0x3d, 0x03, 0x53, 0x53, 0xf0, 0x49, 0x75, 0xd3, 0x41, 0xaa, 0xe7, 0xdf, 0x09, 0x7c, 0xed, 0x7e, 0xd9, 0xc2, 0x04, 0xd1, 0x8e, 0x92, 0x2a, 0x04, 0xef, 0x59, 0x13, 0x51, 0x90, 0x18, 0x27, 0x34],
+    ],
+    [
+        [0x22, 0x92, 0xb0, 0x2c, 0x59, 0x38, 0x0b, 0xa7, 0x4a, 0x3d, 0xe8, 0x7d, 0xa0, 0xeb, 0x9a, 0x6a, 0x65, 0x64, 0xaa, 0xaf, 0x83, 0x7a, 0x08, 0x17, 0xd7, 0xdc, 0x98, 0xc5, 0x92, 0xda, 0xa5, 0x22],
+        [0xa4, 0xbd, 0x30, 0x87, 0xe9, 0x40, 0x9d, 0xa6, 0x76, 0x7c, 0x02, 0x8e, 0xcd, 0x99, 0xff, 0x71, 0x5b, 0xe5, 0x1e, 0xed, 0x3f, 0xaa, 0x23, 0x91, 0x7e, 0x61, 0xf3, 0x64, 0xd4, 0x00, 0xca, 0x1a],
+        [0xc6, 0xf4, 0x8b, 0xe4, 0x86, 0x3e, 0x49, 0x7f, 0x3b, 0x51, 0xd2, 0x2c, 0xc5, 0x85, 0x04, 0xcb, 0x07, 0xec, 0x85, 0x70, 0x00, 0xeb, 0xee, 0xaa, 0x80, 0x57, 0x1a, 0x04, 0x6b, 0x08, 0x0e, 0x11],
+        [0x69, 0x50, 0x70, 0xaf, 0x33, 0xe3, 0x82, 0xdb, 0x4a, 0x2f, 0xde, 0x83, 0x84, 0xc9, 0x67, 0x8d, 0xbf, 0x87, 0xa7, 0x7a, 0xad, 0xfb, 0xa6, 0x1d, 0x7b, 0xd3, 0x9f, 0x41, 0x47, 0x52, 0xb4, 0x19],
+    ],
+    [
+        [0xec, 0xc0, 0xcd, 0x9c, 0x4a, 0xef, 0x36, 0xdd, 0x78, 0xf8, 0xb9, 0xf8, 0xcc, 0x33, 0xad, 0x30, 0xaa, 0x99, 0xf0, 0x84, 0x91, 0x8f, 0x14, 0x78, 0x48, 0xe4, 0x93, 0x35, 0x0b, 0x4c, 0x9e, 0x23],
+        [0x63, 0xc7, 0x35, 0x1d, 0x82, 0x49, 0x6b, 0x04, 0x16, 0x29, 0xe6, 0x89, 0xa0, 0x1f, 0x0b, 0x06, 0x82, 0x29, 0x53, 0xf4, 0x3c, 0x2e, 0x9b, 0x32, 0x37, 0x55, 0x0d, 0x4e, 0xd7, 0x27, 0x00, 0x3f],
+        [0xc5, 0x6c, 0xd8, 0xbc, 0x03, 0x45, 0xe0, 0xac, 0x1c, 0xee, 0x56, 0x58, 0x15, 0x99, 0x1d, 0x25, 0xa3, 0x10, 0xd6, 0xa8, 0x08, 0x9f, 0x68, 0x54, 0xa6, 0x7a, 0x38, 0x3e, 0x27, 0x09, 0xaf, 0x28],
+        [0x6d, 0xf3, 0xb8, 0xb4, 0x43, 0x6b, 0x68, 0x82, 0xdb, 0x3f, 0x95, 0x3d, 0x74, 0xcc, 0xca, 0x44, 0xa1, 0x6a, 0xee, 0xee, 0xfa, 0x56, 0x73, 0xe4, 0x03, 0xeb, 0xd9, 0x74, 0xfa, 0xf0, 0xba, 0x10],
+    ],
+    [
+        [0xa6, 0xde, 0x8b, 0x9a, 0xaa, 0xb7, 0x0f, 0xb4, 0xf7, 0x6e, 0x89, 0x5d, 0x33, 0xa1, 0xf5, 0x43, 0xc4, 0xc2, 0xc1, 0x87, 0x31, 0xbf, 0xc5, 0x6a, 0xc1, 0xe8, 0x60, 0xa6, 0xec, 0x57, 0x0f, 0x15],
+        [0x4e, 0x1a, 0xed, 0x13, 0x3b, 0x63, 0x80, 0xbf, 0x91, 0xee, 0x9a, 0x11, 0x10, 0xcd, 0x02, 0x00, 0x19, 0x4d, 0x65, 0x36, 0x8c, 0x12, 0xf1, 0xd3, 0xe8, 0xe2, 0x50, 0xe4, 0xe1, 0x0c, 0x70, 0x28],
+        [0x97, 0xfd, 0xae, 0x17, 0x1d, 0xe2, 0x14, 0x27, 0x7d, 0x61, 0x60, 0xc2, 0x3a, 0x33, 0x11, 0x4b, 0x8c, 0x16, 0x01, 0x8f, 0x0f, 0x2a, 0x95, 0x99, 0x99, 0xa1, 0x5c, 0x30, 0xaa, 0x75, 0x26, 0x18],
+        [0x34, 0xa1, 0xfa, 0x5f, 0x4b, 0xbe, 0x57, 0x55, 0x11, 0x28, 0x07, 0x85, 0xa9, 0x32, 0x3c, 0x42, 0xd7, 0x2d, 0x40, 0x54, 0x2c, 0xaf, 0x5d, 0x96, 0xab, 0xa2, 0x9c, 0x27, 0x1f, 0xc2, 0xf3, 0x3b],
+    ],
+    [
+        [0xe3, 0xed, 0xfe, 0x5f, 0x4c, 0x05, 0xc1, 0xd2, 0x3f, 0x04, 0xbe, 0x19, 0x37, 0x4d, 0xda, 0xd1, 0x59, 0x35, 0x7b, 0x30, 0x47, 0xa9, 0xc6, 0x81, 0x20, 0xb3, 0xa4, 0x37, 0x41, 0xc0, 0xf3, 0x15],
+        [0x09, 0xe5, 0x01, 0xc2, 0xdb, 0x98, 0xfe, 0x2e, 0x42, 0xd9, 0x97, 0x9f, 0xca, 0x54, 0x1e, 0x29, 0xab, 0x62, 0x9a, 0x8d, 0x1b, 0x48, 0xc4, 0xb5, 0xcc, 0xca, 0x88, 0xf3, 0xed, 0xd6, 0x08, 0x33],
+        [0x33, 0x9a, 0x11, 0xf2, 0x24, 0x44, 0x82, 0x00, 0xdc, 0x98, 0x9c, 0xbc, 0x18, 0x4c, 0x61, 0xf1, 0x10, 0x22, 0xa5, 0x79, 0xd8, 0x48, 0xd4, 0x6d, 0x6d, 0x2c, 0x9c, 0x3c, 0x8a, 0xe4, 0xfc, 0x09],
+        [0x16, 0x05, 0x70, 0x3e, 0x49, 0x98, 0xef, 0x73, 0xaa, 0xf1, 0xa8, 0xcb, 0x70, 0x4f, 0x07, 0x62, 0x33, 0x75, 0xd9, 0x01, 0x9f, 0x5c, 0x13, 0x5a, 0xfd, 0x1b, 0x38, 0x0e, 0x94, 0x9b, 0x92, 0x05],
+    ],
+    [
+        [0xa7, 0x5d, 0x53, 0xce, 0x5c, 0xb7, 0x55, 0xac, 0x32, 0x9a, 0x56, 0xf7, 0x84, 0xf6, 0xfd, 0xac, 0x2e, 0x70, 0xf0, 0xab, 0x74, 0xbf, 0x24, 0x47, 0x82, 0xb0, 0xfe, 0x4f, 0x4c, 0x4f, 0x9c, 0x34],
+        [0xc8, 0x99, 0x2d, 0xfd, 0x90, 0x05, 0x2b, 0x89, 0x6d, 0x7a, 0x47, 0x90, 0x9d, 0x8a, 0xb1, 0x12, 0x96, 0x1b, 0x90, 0xfd, 0x3e, 0x60, 0xcc, 0x63, 0x7c, 0xa7, 0x92, 0x23, 0xe1, 0x73, 0x91, 0x18],
+        [0x6a, 0x64, 0x1c, 0xae, 0xc0, 0x70, 0x67, 0x51, 0x04, 0x2c, 0x31, 0x37, 0x37, 0x97, 0xfd, 0x78, 0xc0, 0x67, 0x3f, 0xd0, 0x60, 0xf0, 0x50, 0x5f, 0xf8, 0x16, 0x8c, 0x00, 0xdb, 0xfa, 0x79, 0x15],
+        [0xa3, 0x39, 0x58, 0x44, 0x3b, 0xb1, 0x20, 0x10, 0x83, 0x3d, 0x70, 0x70, 0x28, 0x59, 0x2d, 0xf1, 0x52, 0xe8, 0x29, 0xe9, 0x0c, 0x91, 0x18, 0x44, 0xea, 0xf5, 0x44, 0x69, 0xe5, 0x68, 0x14, 0x17],
+    ],
+    [
+        [0x61, 0x21, 0xf5, 0x2f, 0x98, 0x84, 0xac, 0xb1, 0x2d, 0xc8, 0xa4, 0x90, 0x38, 0x65, 0x22, 0x78, 0xaf, 0x2c, 0x74, 0x8e, 0x20, 0x11, 0x4c, 0xa9, 0xb7, 0xa0, 0x2f, 0xe4, 0x21, 0xf1, 0x1e, 0x34],
+        [0x61, 0xf5, 0x23, 0x60, 0xdb, 0x07, 0xae, 0x90, 0x08, 0x0b, 0xb7, 0x31, 0x69, 0xda, 0xb0, 0xde, 0x81, 0xb0, 0xc5, 0xdd, 0x6c, 0xa6, 0x8b, 0xdc, 0x21, 0x2b, 0x8e, 0x84, 0x30, 0x65, 0x86, 0x27],
+        [0xed, 0x5f, 0x89, 0xf9, 0x2b, 0xf2, 0xa2, 0x03, 0xc5, 0x13, 0x4d, 0x0b, 0x3c, 0x1b, 0xd6, 0x09, 0x5f, 0x47, 0x66, 0x0e, 0xa3, 0xa9, 0xdb, 0xe7, 0x59, 0xf7, 0x2c, 0x0e, 0xa6, 0x6b, 0xdf, 0x2a],
+        [0x15, 0x3f, 0xb9, 0x64, 0x91, 0x7a, 0x11, 0xe0, 0xb6, 0xd4, 0xff, 0x5a, 0x06, 0x4e, 0x64, 0x74, 0x17, 0x05, 0x24, 0x03, 0x44, 0xa7, 0xf1, 0x59, 0x1a, 0x4b, 0xcd, 0x53, 0x0a, 0xea, 0x2d, 0x12],
+    ],
+    [
+        [0xca, 0x3a, 0xd1, 0x49, 0x7d, 0x40, 0x5c, 0xa5, 0xf6, 0xb7, 0x67, 0x49, 0x81, 0x7c, 0xd9, 0x04, 0x8e, 0x7c, 0x1c, 0x58, 0x3f, 0xbc, 0xd0, 0x2e, 0xeb, 0x1a, 0x20, 0x9e, 0xf4, 0x72, 0xc9, 0x0e],
+        [0xad, 0x8f, 0xac, 0xf6, 0x04, 0x32, 0x87, 0xd6, 0xc7, 0x55, 0x61, 0x10, 0xc6, 0x84, 0xbc, 0x8e, 0x66, 0xe4, 0x6c, 0x7b, 0xa4, 0xa8, 0xc6, 0xbe, 0xea, 0xda, 0x6e, 0x23, 0xea, 0x6d, 0x20, 0x28],
+        [0x2f, 0x41, 0x40, 0xc6, 0x03, 0x3c, 0xee, 0xef, 0xc4, 0xaa, 0x02, 0xff, 0xad, 0xfe, 0xc3, 0x91, 0x50, 0x69, 0x30, 0x66, 0xdb, 0xeb, 0x61, 0x30, 0x16, 0xef, 0x5d, 0xbf, 0x9f, 0x55, 0x9d, 0x30],
+        [0xa7, 0xcf, 0x07, 0x18, 0xe2, 0xe8, 0x37, 0xab, 0xfb, 0x70, 0x5a, 0x46, 0x75, 0x7a, 0xf7, 0xc6, 0xaa, 0x1b, 0x03, 0xf3, 0xb9, 0x5f, 0x66, 0x98, 0x2a, 0xb9, 0xd3, 0x88, 0xba, 0x8c, 0x08, 0x0a],
+    ],
+    [
+        [0xba, 0xf9, 0x68, 0x06, 0xe1, 0xd4, 0x04, 0x50, 0xa3, 0x9d, 0x9d, 0x05, 0x4a, 0x33, 0x26, 0xc6, 0x48, 0xcc, 0xba, 0xb8, 0x57, 0x89, 0xd4, 0xf5, 0x4c, 0x0e, 0xbf, 0xa5, 0x2e, 0x93, 0x92, 0x19],
+        [0xfc, 0x46, 0xd8, 0x6c, 0x95, 0xa3, 0x44, 0x03, 0x35, 0xc9, 0xa3, 0x5d, 0x0a, 0xcf, 0x97, 0xdc, 0x0e, 0xde, 0x7a, 0x9d, 0xb2, 0x63, 0x60, 0xb8, 0xa6, 0x1a, 0xc1, 0x5d, 0x15, 0x7b, 0x51, 0x10],
+        [0x9a, 0x76, 0x2f, 0x8b, 0xa9, 0x31, 0x21, 0x26, 0x05, 0xe0, 0x3d, 0x3c, 0x0b, 0xd6, 0x9c, 0xdd, 0x54, 0x7e, 0x51, 0x5b, 0xc1, 0x2c, 0x6f, 0x5c, 0xd6, 0x82, 0xaf, 0x51, 0xfa, 0x87, 0x2e, 0x1c],
+        [0x89, 0x56, 0x03, 0x70, 0xc0, 0x51, 0x38, 0x43, 0x5f, 0x98, 0x6d, 0xaa, 0xff, 0xb7, 0xb2, 0xaa, 0x84, 0x60, 0xdf, 0xde, 0x24, 0x63, 0x29, 0x5d, 0x6f, 0x4e, 0x00, 0x9d, 0x24, 0x66, 0x7b, 0x36],
+    ],
+    [
+        [0x48, 0xe6, 0xae, 0xee, 0xa6, 0xbc, 0x6d, 0x3d, 0x02, 0xe3, 0x7b, 0x7b, 0xaf, 0x1f, 0x6c, 0xf3, 0xad, 0xa8, 0xd5, 0x56, 0x9e, 0x60, 0xb3, 0x39, 0x79, 0xc0, 0x47, 0xde, 0x15, 0xa0, 0xc2, 0x26],
+        [0x13, 0x75, 0xe6, 0x86, 0xd9, 0xdd, 0x6e, 0x83, 0x2d, 0x56, 0x29, 0xe6, 0x11, 0xd4, 0x5c, 0x10, 0x47, 0x08, 0x0e, 0xa5, 0x0e, 0x94, 0x64, 0x02, 0x1b, 0xa3, 0x53, 0x3c, 0x26, 0xd7, 0xbe, 0x3f],
+        [0xf8, 0x8a, 0x09, 0x6f, 0x9e, 0x25, 0xff, 0xd1, 0x57, 0x7c, 0x72, 0xbe, 0x08, 0x24, 0x97, 0xa2, 0x3d, 0xab, 0xf3, 0x78, 0x8c, 0xee, 0x3f, 0x95, 0x65, 0xdc, 0x6b, 0x51, 0x71, 0x9a, 0xb8, 0x3c],
+        [0x39, 0x57, 0x0a, 0x81, 0x84, 0x48, 0x0e, 0x0e, 0x5f, 0xe6, 0x88, 0x2d, 0xbb, 0x37, 0x41, 0x9c, 0x47, 0x5d, 0xad, 0x41, 0x8b, 0x98, 0x66, 0x74, 0xc2, 0x56, 0x50, 0x33, 0x74, 0xe6, 0xce, 0x2f],
+    ],
+    [
+        [0x28, 0xc8, 0x04, 0x4a, 0x60, 0x21, 0x09, 0x53, 0xb6, 0x9a, 0x54, 0xe6, 0x4a, 0xfc, 0xe8, 0x53, 0x1d, 0xdd, 0x17, 0xaa, 0x29, 0x97, 0x14, 0x19, 0xe0, 0x54, 0xf0, 0xe3, 0x7a, 0x99, 0xa6, 0x11],
+        [0xd3, 0xec, 0x8b, 0xc7, 0x96, 0xd4, 0x50, 0xb2, 0x52, 0x39, 0xa5, 0xfd, 0x14, 0xa6, 0xb1, 0xe5, 0x08, 0x37, 0x36, 0x2c, 0x96, 0x65, 0x36, 0xbe, 0x4c, 0xd6, 0x19, 0x47, 0x95, 0xa7, 0xb7, 0x27],
+        [0xab, 0xa5, 0x02, 0x75, 0x0a, 0x05, 0x06, 0x6f, 0xff, 0xee, 0xfb, 0x62, 0xb1, 0xd5, 0xdf, 0x23, 0x59, 0x14, 0xa9, 0xcc, 0xa2, 0x54, 0x18, 0x87, 0xf6, 0x8c, 0x84, 0x4c, 0xc1, 0x7b, 0xf2, 0x36],
+        [0xcf, 0xdb, 0x82, 0x12, 0x4b, 0x88, 0xa2, 0x78, 0xf1, 0x6a, 0x2c, 0xb9, 0x97, 0x0c, 0xe9, 0x0b, 0xaf, 0xf5, 0xd9, 0xa8, 0x47, 0xfd, 0x8d, 0xe8, 0x14, 0x00, 0x68, 0x35, 0x92, 0xa9, 0x86, 0x01],
+    ],
+    [
+        [0x57, 0xa1, 0x59, 0xc4, 0x35, 0x36, 0x84, 0x3a, 0x73, 0xbb, 0xbc, 0xa3, 0xe8, 0x39, 0xc0, 0xde, 0x5e, 0x3f, 0x21, 0x92, 0x34, 0xea, 0x30, 0xd8, 0x0b, 0xaa, 0x5f, 0x06, 0x65, 0xe9, 0x39, 0x01],
+        [0x40, 0x22, 0x63, 0x5f, 0x83, 0x67, 0xd8, 0x51, 0xb8, 0x19, 0x89, 0x65, 0x7c, 0x72, 0x03, 0x6c, 0x49, 0x21, 0x3a, 0xce, 0x6c, 0x17, 0xc9, 0x45, 0x8f, 0xc8, 0xd0, 0x44, 0xa8, 0x18, 0x23, 0x03],
+        [0x2a, 0xf5, 0xac, 0x88, 0xfa, 0x53, 0x7a, 0xa6, 0xf4, 0xdd, 0x41, 0x4c, 0xf2, 0x84, 0x3d, 0x73, 0x31, 0xd0, 0x1b, 0x6a, 0x02, 0x41, 0xc5, 0x14, 0xed, 0x53, 0xcc, 0xab, 0x81, 0xc4, 0xe3, 0x1f],
+        [0xea, 0xa9, 0x89, 0xf8, 0xa5, 0x62, 0x30, 0x12, 0x0f, 0x72, 0x20, 0xd0, 0x6f, 0x27, 0x52, 0x24, 0x57, 0xaa, 0xb1, 0x52, 0x1f, 0x79, 0x46, 0xf5, 0x2f, 0xe4, 0x3a, 0x15, 0x64, 0x0c, 0x4d, 0x37],
+    ],
+    [
+        [0x8e, 0xe5, 0x9c, 0x50, 0x82, 0x0b, 0x4f, 0xad, 0xb3, 0xc7, 0x25, 0x84, 0x4a, 0x38, 0xf3, 0xf4, 0x63, 0xdd, 0xc5, 0x07, 0x85, 0x42, 0x00, 0x54, 0xe7, 0xa0, 0x71, 0xc2, 0x87, 0xe4, 0x10, 0x09],
+        [0x7d, 0xab, 0x40, 0xe0, 0xf8, 0xc3, 0x6c, 0x42, 0x7d, 0x90, 0xc9, 0xc8, 0xa4, 0x8b, 0x27, 0xd4, 0x07, 0x9c, 0x46, 0x95, 0x7c, 0x57, 0x39, 0xdc, 0x88, 0x71, 0xcf, 0xd6, 0xfd, 0x80, 0xbd, 0x0a],
+        [0x05, 0x2e, 0x59, 0xb5, 0xc4, 0x5c, 0x78, 0x7a, 0xd6, 0x73, 0x8d, 0x62, 0x0d, 0xde, 0xf8, 0xec, 0xcb, 0x48, 0xed, 0x9e, 0x0b, 0xcc, 0x17, 0xd9, 0x1d, 0xec, 0xc1, 0x2b, 0xdb, 0x46, 0x4d, 0x20],
+        [0xbf, 0x63, 0x4a, 0xbf, 0x0e, 0x04, 0x6f, 0x1c, 0x95, 0x99, 0x8b, 0x44, 0x89, 0xc4, 0x60, 0x28, 0x4a, 0xf4, 0xf5, 0x3c, 0x3e, 0x16, 0x85, 0x62, 0x32, 0xc8, 0x0f, 0x79, 0xf6, 0xd7, 0x61, 0x3d],
+    ],
+    [
+        [0xc1, 0x8d, 0xa4, 0x49, 0xb7, 0x39, 0x75, 0x61, 0x1f, 0x4a, 0x11, 0x69, 0x7f, 0xfb, 0xad, 0x8f, 0x47, 0xd6, 0xb4, 0xad, 0xd2, 0xfc, 0xc0, 0x2d, 0xd3, 0x2a, 0x5b, 0xaf, 0x1c, 0x0f, 0x6a, 0x1f],
+        [0x2c, 0x18, 0x57, 0xc4, 0x8a, 0xaf, 0xb3, 0x9e, 0x4e, 0xef, 0x60, 0xf1, 0xec, 0x96, 0x1a, 0x80, 0xfb, 0x04, 0x6f, 0xc7, 0x49, 0xa3, 0xc8, 0x0d, 0x8d, 0xc4, 0x9f, 0x7d, 0x87, 0x99, 0x11, 0x23],
+        [0xf6, 0xa7, 0xb7, 0x08, 0x17, 0x19, 0x87, 0x70, 0xa9, 0x75, 0x99, 0x3a, 0x80, 0x09, 0xe0, 0x5f, 0x11, 0x05, 0x48, 0x10, 0x4e, 0x94, 0x03, 0x84, 0x73, 0x64, 0x4d, 0xf8, 0x22, 0x84, 0xef, 0x3a],
+        [0xc6, 0x01, 0xdc, 0xbf, 0xe9, 0xab, 0x46, 0xaa, 0xcf, 0x4f, 0x66, 0x0d, 0xee, 0xa7, 0x01, 0xb8, 0xac, 0x87, 0xb8, 0x88, 0x1c, 0x98, 0x67, 0x09, 0x43, 0x12, 0xb1, 0xff, 0xba, 0x08, 0xd2, 0x22],
+    ],
+    [
+        [0xeb, 0x44, 0x2c, 0x43, 0x0c, 0xa7, 0x8b, 0x03, 0x3a, 0xb3, 0xd0, 0x0b, 0x17, 0x12, 0x2a, 0x8a, 0xc4, 0x3d, 0x46, 0x19, 0xdc, 0x08, 0x6b, 0xba, 0x04, 0xf2, 0xaa, 0x39, 0xde, 0xcd, 0xd7, 0x24],
+        [0x18, 0xce, 0xdb, 0x38, 0xc2, 0x9c, 0x8e, 0xb1, 0x10, 0x9b, 0x15, 0x69, 0x79, 0xb9, 0x46, 0x86, 0xe3, 0xcb, 0x64, 0xe0, 0xae, 0x25, 0xae, 0x6b, 0xd8, 0x73, 0x61, 0x7b, 0xf8, 0xd4, 0x1e, 0x24],
+        [0x05, 0xbf, 0xb8, 0x3c, 0xe3, 0x85, 0x74, 0xcb, 0xe4, 0x16, 0x42, 0xce, 0x8a, 0x01, 0x22, 0xd8, 0x62, 0x35, 0x84, 0x5e, 0x49, 0x98, 0xbd, 0xed, 0xc7, 0x75, 0x1a, 0x70, 0xe1, 0xd7, 0x75, 0x2a],
+        [0xae, 0x4d, 0x8d, 0x9d, 0xf3, 0x3a, 0x59, 0x2c, 0xc6, 0xc7, 0xd5, 0x68, 0x4b, 0x7e, 0x0b, 0x6d, 0x84, 0xdd, 0x32, 0x9b, 0xb5, 0x91, 0x14, 0xb1, 0x52, 0xda, 0x88, 0xea, 0xd8, 0x92, 0x2a, 0x03],
+    ],
+    [
+        [0xfd, 0x8f, 0x1b, 0x6d, 0xbd, 0x9b, 0x90, 0x6d, 0x98, 0xcf, 0xbb, 0x86, 0x90, 0x41, 0x41, 0x33, 0xa9, 0x15, 0x68, 0xd2, 0x5e, 0xfc, 0xc6, 0x9c, 0xcc, 0x64, 0xe0, 0x8f, 0x12, 0x02, 0x9c, 0x24],
+        [0xc8, 0x58, 0x4a, 0xe8, 0x23, 0xcf, 0x28, 0x39, 0x4b, 0x98, 0x10, 0x09, 0x34, 0x59, 0xaa, 0x9c, 0x4e, 0x76, 0x8f, 0x8c, 0xb6, 0xa2, 0xdb, 0x77, 0xa9, 0x41, 0xb6, 0x40, 0x30, 0x16, 0x06, 0x0f],
+        [0xd2, 0xfc, 0x35, 0xb1, 0x81, 0xc4, 0xca, 0x67, 0xff, 0xf0, 0x1f, 0x1f, 0x4c, 0xdc, 0x61, 0xf5, 0x88, 0x1d, 0xfe, 0x88, 0xf0, 0x91, 0xc9, 0xd5, 0xaf, 0x4c, 0x7b, 0x9f, 0x73, 0xf6, 0x1a, 0x13],
+        [0x84, 0x79, 0x00, 0x8e, 0xb1, 0xa3, 0x21, 0x1c, 0xb1, 0x8e, 0xf1, 0xbf, 0x8c, 0xd7, 0x5e, 0xc9, 0xaa, 0x1a, 0x75, 0xae, 0x34, 0xb7, 0xae, 0x58, 0x25, 0x1a, 0x26, 0xeb, 0xed, 0xf5, 0x52, 0x13],
+    ],
+    [
+        [0x40, 0x3a, 0x6f, 0x26, 0xe1, 0x2c, 0xd6, 0x32, 0xcc, 0x17, 0xd4, 0x0f, 0x6b, 0x7e, 0xab, 0x89, 0xfe, 0x81, 0x2a, 0x2b, 0x62, 0x7b, 0x65, 0xb6, 0xda, 0x34, 0xf0, 0x97, 0x60, 0x72, 0xd1, 0x33],
+        [0xbd, 0xbd, 0xe4, 0x55, 0x9e, 0x75, 0x01, 0xb6, 0x81, 0x0a, 0xb0, 0x12, 0xd8, 0xb6, 0xef, 0x2d, 0x22, 0xa0, 0x81, 0x9b, 0xeb, 0xef, 0x20, 0x2a, 0x62, 0xa2, 0x12, 0x9d, 0x5d, 0x00, 0x9c, 0x2b],
+        [0xbb, 0x6f, 0x55, 0xfa, 0x9a, 0x61, 0xc1, 0x79, 0x87, 0x5e, 0xa5, 0xc7, 0xea, 0x65, 0x5d, 0xe1, 0xf1, 0x49, 0x49, 0x20, 0x74, 0x68, 0xae, 0x97, 0x25, 0x4b, 0x3b, 0xe6, 0x7d, 0xfa, 0x71, 0x31],
+        [0x2b, 0xdd, 0xc1, 0xd7, 0xf1, 0xa8, 0xbc, 0x2c, 0xe4, 0x7c, 0x9c, 0x5b, 0x0f, 0x8e, 0x06, 0xeb, 0xd0, 0x6f, 0x6f, 0x0e, 0xfd, 0xdd, 0x86, 0x63, 0x5f, 0x1c, 0x27, 0x9c, 0xd4, 0x12, 0x80, 0x04],
+    ],
+    [
+        [0xee, 0x64, 0x39, 0x87, 0xde, 0xcc, 0xa8, 0x07, 0x45, 0x2c, 0x33, 0x87, 0x7e, 0x87, 0xa6, 0x8a, 0x03, 0xa8, 0x38, 0x74, 0x99, 0x6f, 0x05, 0x09, 0xfa, 0x8e, 0x10, 0x36, 0xa4, 0xc3, 0x7e, 0x0a],
+        [0x9b, 0xc2, 0x88, 0x78, 0x65, 0xe6, 0x37, 0xb6, 0xbd, 0x01, 0xc2, 0x03, 0xbc, 0x27, 0xe4, 0xf0, 0x47, 0x2b, 0x82, 0x0f, 0xf6, 0xc6, 0x95, 0x86, 0x33, 0x3e, 0x0e, 0x94, 0xe3, 0x35, 0x25, 0x25],
+        [0x9e, 0xe0, 0xda, 0x88, 0xc4, 0xe3, 0x46, 0x73, 0x45, 0xaf, 0x6a, 0x5e, 0xe9, 0x0f, 0x7b, 0xb8, 0xf8, 0xf7, 0x67, 0x55, 0xc8, 0xd7, 0x1d, 0x54, 0xd9, 0xc7, 0xe7, 0x06, 0x1c, 0xbf, 0x5e, 0x1f],
+        [0xe9, 0x42, 0x9e, 0x5b, 0xca, 0x7e, 0xa7, 0xbe, 0x04, 0x1a, 0x42, 0xae, 0xff, 0xb2, 0x74, 0x73, 0xa8, 0x5f, 0xa6, 0x86, 0x18, 0xef, 0xd5, 0xea, 0x82, 0x2e, 0x45, 0xf3, 0x1b, 0x94, 0x03, 0x25],
+    ],
+    [
+        [0x73, 0x37, 0x90, 0x0b, 0x12, 0x18, 0x95, 0xd2, 0x6c, 0x8b, 0x27, 0x5f, 0xc4, 0x64, 0xf8, 0x2e, 0x51, 0x54, 0xc4, 0x18, 0xa5, 0xcd, 0x99, 0xc7, 0xfe, 0x8d, 0xf9, 0x59, 0x81, 0x88, 0x61, 0x37],
+        [0xa1, 0xe5, 0x09, 0x19, 0xdb, 0xf2, 0x5c, 0x0c, 0x34, 0x14, 0x5e, 0x27, 0x88, 0xf5, 0x34, 0xa3, 0x02, 0x59, 0xa5, 0x02, 0x4d, 0x13, 0x3c, 0x6d, 0xe5, 0x81, 0x79, 0x72, 0xf7, 0x7d, 0x9b, 0x23],
+        [0x10, 0xbb, 0xad, 0x34, 0x7d, 0xba, 0xfd, 0x84, 0x17, 0x3c, 0x8a, 0x11, 0x8e, 0x44, 0xd1, 0x50, 0x78, 0x39, 0x50, 0x2c, 0x18, 0x45, 0x38, 0x4b, 0xd0, 0x03, 0xd9, 0xb7, 0x9f, 0xfd, 0x1c, 0x3d],
+        [0xd8, 0x86, 0x1d, 0xf9, 0x0a, 0xbd, 0xe2, 0xa2, 0x80, 0x58, 0xd8, 0xc6, 0xd2, 0x07, 0x1e, 0x9d, 0xef, 0x58, 0xae, 0x17, 0xc0, 0x35, 0xad, 0x38, 0x73, 0x8a, 0x10, 0x3a, 0x5f, 0x68, 0xb0, 0x2f],
+    ],
+    [
+        [0x75, 0x8e, 0xc0, 0xe2, 0xb0, 0xd0, 0xcf, 0x0a, 0xf2, 0x26, 0x62, 0xb5, 0x42, 0xc7, 0x35, 0x3f, 0xc7, 0x10, 0x9b, 0x57, 0x0a, 0xbf, 0x87, 0x6c, 0x8c, 0xc9, 0xba, 0xaf, 0x3a, 0x6b, 0xd0, 0x24],
+        [0x13, 0x71, 0x08, 0x47, 0xf4, 0x2b, 0x7f, 0xee, 0x0b, 0x0c, 0xc4, 0x02, 0xa8, 0x62, 0x4f, 0xee, 0xc3, 0x62, 0x64, 0x15, 0xb7, 0xc5, 0x99, 0xc6, 0xd9, 0x30, 0x9e, 0x1d, 0x71, 0x91, 0x1a, 0x19],
+        [0x8b, 0x5e, 0x14, 0x66, 0xee, 0x71, 0x7e, 0xbd, 0x1a, 0x5e, 0x95, 0xf6, 0xc4, 0x7d, 0x0a, 0x2e, 0x55, 0x10, 0x7a, 0x1d, 0x00, 0x87, 0xca, 0xf1, 0x3d, 0x17, 0xa1, 0xf6, 0x81, 0xd6, 0x78, 0x03],
+        [0xbd, 0xbe, 0x4d, 0x11, 0xca, 0x26, 0x74, 0xc8, 0x4c, 0x5e, 0x49, 0x44, 0xc2, 0xe7, 0xdd, 0x62, 0xb7, 0x21, 0xfa, 0x37, 0x91, 0x16, 0x3e, 0x06, 0x20, 0x3d, 0x9f, 0x23, 0x51, 0x45, 0x3d, 0x02],
+    ],
+    [
+        [0x06, 0xc6, 0xa7, 0xd2, 0x66, 0xa0, 0xbe, 0x9d, 0x56, 0x8b, 0x60, 0x15, 0x86, 0x2d, 0xa3, 0x75, 0x20, 0xf5, 0xe3, 0x88, 0xf5, 0xd7, 0x06, 0xea, 0xe8, 0xaa, 0x26, 0xf8, 0x94, 0xd4, 0xd7, 0x2a],
+        [0x9e, 0x38, 0x0c, 0x2f, 0x6f, 0x03, 0xa7, 0xc5, 0x48, 0x57, 0x6b, 0x84, 0x5a, 0x9a, 0x0f, 0xf1, 0x80, 0xba, 0x9e, 0x64, 0x4e, 0xa6, 0x57, 0x3c, 0x38, 0xd7, 0x17, 0xa6, 0x49, 0x48, 0x24, 0x25],
+        [0x49, 0x46, 0xa1, 0xac, 0xfe, 0x95, 0x17, 0xc7, 0x97, 0xf5, 0x91, 0x6e, 0x95, 0xa8, 0x8d, 0xa8, 0x44, 0x71, 0xa3, 0x82, 0x32, 0x6e, 0x81, 0x71, 0xf1, 0x49, 0xaf, 0x30, 0x12, 0xbd, 0xec, 0x0f],
+        [0x28, 0xde, 0x91, 0x2a, 0x4f, 0x29, 0x2d, 0xe4, 0xaa, 0xe9, 0xca, 0xc3, 0x68, 0xcb, 0x11, 0xaa, 0x30, 0xbc, 0x7e, 0x4a, 0xe5, 0x0a, 0x59, 0xb8, 0xf2, 0xab, 0xa8, 0xf2, 0xec, 0x15, 0x0d, 0x25],
+    ],
+    [
+        [0xcb, 0x2a, 0x68, 0x95, 0x2f, 0xba, 0x78, 0xb4, 0x57, 0xa1, 0x5c, 0x20, 0x4e, 0x50, 0xfb, 0xcc, 0x6f, 0xe4, 0xa5, 0xe4, 0x80, 0x42, 0x6c, 0x0a, 0xa5, 0x45, 0x4e, 0xeb, 0x91, 0xde, 0x07, 0x0b],
+        [0x81, 0x7b, 0x12, 0x50, 0x56, 0x87, 0xb4, 0x36, 0x4a, 0x28, 0xea, 0xd2, 0x88, 0x19, 0x0b, 0xda, 0x32, 0xe8, 0xa7, 0xd5, 0x12, 0x63, 0xc4, 0x71, 0x10, 0x0a, 0x36, 0x58, 0x49, 0x69, 0xbc, 0x04],
+        [0xe3, 0x96, 0x5e, 0x90, 0xc0, 0x11, 0x1e, 0xf4, 0x71, 0x74, 0x4f, 0x0f, 0x13, 0x2b, 0x2f, 0x72, 0x5a, 0xd3, 0xfc, 0x07, 0xa5, 0x86, 0xe8, 0xd7, 0xd2, 0xe6, 0x28, 0xda, 0x30, 0xeb, 0x2c, 0x39],
+        [0x81, 0x16, 0x35, 0xca, 0xcb, 0x9c, 0x84, 0x4c, 0xb0, 0x26, 0xd4, 0xb5, 0x66, 0xcf, 0xee, 0xc9, 0x94, 0xa6, 0xce, 0x5b, 0x09, 0xa0, 0x89, 0xb5, 0x77, 0x29, 0xc5, 0x17, 0xd5, 0x97, 0x64, 0x39],
+    ],
+    [
+        [0x52, 0x4b, 0xc5, 0xe6, 0x7c, 0x8a, 0xd9, 0xc4, 0x84, 0xe5, 0xc7, 0x2e, 0x59, 0x4e, 0xff, 0xab, 0x20, 0x09, 0xe0, 0x92, 0xcc, 0x47, 0x40, 0xd2, 0x0e, 0xae, 0x8c, 0xda, 0x99, 0x19, 0xd1, 0x1f],
+        [0x1f, 0x32, 0x38, 0x80, 0x1b, 0x0d, 0x08, 0xe3, 0x3a, 0xf3, 0xfd, 0xd3, 0x49, 0xdb, 0x69, 0x70, 0xde, 0x76, 0x7e, 0x75, 0x33, 0xa9, 0x4c, 0x83, 0x09, 0xa7, 0x29, 0xa9, 0x85, 0x93, 0x1e, 0x14],
+        [0x1a, 0x54, 0x97, 0xa6, 0x7d, 0xf2, 0xd3, 0x9c, 0xff, 0x0d, 0x1b, 0x03, 0x14, 0x34, 0x17, 0x84, 0x97, 0x35, 0x05, 0x65, 0xb3, 0x02, 0xe8, 0x03, 0xbf, 0x0a, 0x4f, 0xe1, 0x15, 0xfc, 0x68, 0x3a],
+        [0x49, 0xbc, 0x96, 0x50, 0x6f, 0xfa, 0x6e, 0xb0, 0x62, 0x9b, 0x4a, 0xe3, 0x9c, 0x83, 0xf5, 0x23, 0xa8, 0xe9, 0x59, 0xb1, 0x8d, 0x57, 0xdb, 0xbf, 0x7e, 0xbd, 0x8f, 0x7a, 0x58, 0x6e, 0x87, 0x34],
+    ],
+    [
+        [0x76, 0x54, 0x97, 0xd2, 0xe0, 0x4a, 0xfc, 0x66, 0xd6, 0x19, 0x0b, 0x45, 0x62, 0x0e, 0xea, 0x4c, 0x51, 0xd6, 0x98, 0x68, 0x39, 0x0e, 0xcc, 0xb0, 0x15, 0x55, 0xf8, 0x6e, 0xd6, 0x9c, 0xfa, 0x06],
+        [0x04, 0x09, 0x0c, 0xf2, 0x3c, 0xee, 0xcd, 0x0b, 0xc3, 0x49, 0x4e, 0x27, 0xd6, 0x01, 0xb1, 0xf3, 0x59, 0xe0, 0x3c, 0x5d, 0x16, 0x3b, 0xa3, 0xdb, 0x73, 0x99, 0xe0, 0x6d, 0x7a, 0xca, 0x73, 0x14],
+        [0x8e, 0xe2, 0x2e, 0x26, 0x43, 0xbc, 0x21, 0x98, 0x12, 0xa7, 0x5a, 0xc2, 0x26, 0xfd, 0x39, 0xe6, 0x2b, 0xb8, 0x4a, 0xc3, 0x29, 0x47, 0x99, 0xdd, 0x6d, 0x08, 0xe3, 0x86, 0x32, 0x7c, 0x15, 0x2f],
+        [0x65, 0x7d, 0x1b, 0xd4, 0x20, 0x28, 0x25, 0x7b, 0xc8, 0x71, 0x51, 0x01, 0x2b, 0xd6, 0xd7, 0xf0, 0x1f, 0x15, 0x98, 0x34, 0x30, 0x07, 0xf5, 0x68, 0x60, 0x2b, 0x50, 0x64, 0x16, 0xe8, 0x47, 0x38],
+    ],
+    [
+        [0xb4, 0x30, 0x21, 0x4c, 0x54, 0xbe, 0xfc, 0xd4, 0xd7, 0xd6, 0x5d, 0xd9, 0x08, 0x9e, 0xd1, 0xfb, 0x0f, 0x75, 0xc4, 0x14, 0x83, 0x89, 0x8f, 0x61, 0xc9, 0xf7, 0x1a, 0xa3, 0x72, 0xdc, 0x06, 0x13],
+        [0x4b, 0x6d, 0x6f, 0xa4, 0x1f, 0x26, 0xe5, 0xe4, 0x9e, 0xf5, 0x9d, 0x2b, 0x20, 0x06, 0xaa, 0x41, 0x14, 0xb9, 0x17, 0x67, 0xd2, 0x83, 0xb1, 0xf9, 0x3b, 0x69, 0x30, 0x02, 0x0e, 0xc0, 0x31, 0x14],
+        [0x92, 0x19, 0x66, 0xe2, 0xa8, 0x9c, 0xf9, 0x21, 0x32, 0x8b, 0xc5, 0x8b, 0xb9, 0x17, 0x5d, 0x17, 0xce, 0x8d, 0xfe, 0xf4, 0x1b, 0x41, 0xe4, 0x3e, 0x10, 0x50, 0xe5, 0x22, 0xc6, 0xb2, 0x7d, 0x33],
+        [0xe5, 0x3d, 0x28, 0xac, 0x57, 0x6f, 0x49, 0x57, 0x81, 0x3a, 0x23, 0x92, 0xb9, 0x5d, 0xcb, 0x90, 0xaf, 0xe0, 0xd2, 0x3c, 0xe2, 0xd9, 0x27, 0xce, 0x68, 0x53, 0x1f, 0xa9, 0xcd, 0xc4, 0xb0, 0x08],
+    ],
+    [
+        [0x38, 0x02, 0x06, 0x04, 0x23, 0x6b, 0x59, 0xd8, 0x19, 0x60, 0xd0, 0x77, 0xc2, 0x21, 0x3b, 0x23, 0x0f, 0xb4, 0x8e, 0x7e, 0x57, 0xd3, 0x05, 0x9f, 0x1e, 0x72, 0xe1, 0xdf, 0xff, 0x6b, 0x37, 0x04],
+        [0x59, 0x84, 0x5c, 0xd7, 0x57, 0x09, 0xd6, 0x9d, 0x80, 0x24, 0xd6, 0x85, 0xf6, 0x33, 0xb5, 0x33, 0xc2, 0x45, 0x0f, 0x34, 0x66, 0x8b, 0xf5, 0xab, 0xba, 0xd6, 0x18, 0xdf, 0x53, 0xba, 0xa1, 0x00],
+        [0x44, 0x2c, 0x47, 0xc8, 0xfb, 0xd9, 0x13, 0x6e, 0x3c, 0xf8, 0xd1, 0x84, 0xa9, 0xe2, 0x3d, 0x52, 0xc9, 0x39, 0xe8, 0x83, 0x40, 0x28, 0x9a, 0xb8, 0x0a, 0xce, 0x3c, 0xd5, 0x1e, 0x1b, 0x75, 0x31],
+        [0x31, 0xde, 0xb7, 0xfe, 0x2f, 0x17, 0x5b, 0x17, 0x6f, 0x01, 0x24, 0xbb, 0x32, 0x63, 0x59, 0xf6, 0x28, 0x89, 0xd8, 0x79, 0x92, 0x5d, 0xce, 0x72, 0x72, 0x78, 0xd5, 0x5e, 0xf8, 0x80, 0xd5, 0x06],
+    ],
+    [
+        [0xc5, 0x3f, 0xdc, 0x9f, 0xf0, 0x52, 0x6b, 0x50, 0xec, 0x7e, 0x62, 0xe0, 0xe7, 0xa3, 0x61, 0x43, 0x39, 0xc3, 0x71, 0xdf, 0x84, 0x71, 0x3e, 0x57, 0x50, 0xe9, 0x49, 0x15, 0xdc, 0x3e, 0xaf, 0x09],
+        [0xda, 0xcf, 0xca, 0x76, 0x0e, 0x61, 0x92, 0x91, 0x1f, 0xf6, 0xdd, 0xa8, 0x75, 0xbc, 0xdd, 0xd4, 0x45, 0x12, 0xc6, 0x21, 0x7f, 0x1c, 0xcb, 0x88, 0x15, 0x63, 0xa9, 0x4c, 0x8a, 0x7a, 0x4c, 0x1b],
+        [0x48, 0x8e, 0x25, 0xf2, 0x64, 0xb2, 0x62, 0x73, 0xa1, 0x61, 0x41, 0xe8, 0x0d, 0xf4, 0x24, 0x9b, 0x0c, 0x0c, 0x59, 0xa4, 0x7f, 0x45, 0xe8, 0xa8, 0xe3, 0xe7, 0xed, 0x86, 0xd2, 0x02, 0x79, 0x33],
+        [0x56, 0xa7, 0xd1, 0xe0, 0xb6, 0xfe, 0x28, 0xda, 0xc2, 0x15, 0xd5, 0xc0, 0xfb, 0xff, 0x0d, 0x03, 0x46, 0x59, 0x7e, 0x97, 0x4c, 0x93, 0x9a, 0xe1, 0x82, 0xd4, 0x97, 0x4e, 0xbc, 0xd5, 0x80, 0x01],
+    ],
+    [
+        [0x67, 0x1d, 0xf6, 0x66, 0x26, 0x49, 0x76, 0x24, 0x65, 0x3e, 0x0e, 0x80, 0x39, 0x89, 0x85, 0x4f, 0x11, 0x6c, 0xe4, 0x7e, 0x30, 0xd8, 0x94, 0x83, 0xe6, 0xe0, 0xde, 0xcc, 0x1a, 0x1b, 0x29, 0x22],
+        [0xa6, 0x56, 0x8f, 0x23, 0x4b, 0xb9, 0x56, 0xb6, 0xf0, 0xc4, 0x39, 0xab, 0xe2, 0x7f, 0xc1, 0x9c, 0x5b, 0x9c, 0x8e, 0x97, 0x7b, 0x54, 0xd3, 0xb9, 0x30, 0xa6, 0x75, 0x6a, 0xd2, 0xe6, 0x06, 0x18],
+        [0x32, 0xc8, 0x68, 0xb2, 0x52, 0x64, 0xc0, 0x28, 0x5b, 0x04, 0xfc, 0x1c, 0x96, 0xc8, 0x36, 0x78, 0x31, 0x7c, 0xdd, 0x80, 0x56, 0x61, 0xd4, 0xf7, 0xd9, 0x6e, 0xc3, 0xbf, 0x15, 0x52, 0xaa, 0x36],
+        [0xa5, 0xb0, 0x68, 0x16, 0x19, 0x79, 0x40, 0xa0, 0x93, 0x52, 0xe7, 0xcb, 0xa3, 0xce, 0xcc, 0xe6, 0x31, 0x68, 0x47, 0xbd, 0x82, 0xec, 0x73, 0xaa, 0x45, 0x15, 0xc4, 0x53, 0x0d, 0x6b, 0xf7, 0x35],
+    ],
+    [
+        [0xde, 0x2d, 0x4f, 0xc0, 0xdd, 0xea, 0x70, 0x80, 0x9c, 0xb0, 0xae, 0xf3, 0x10, 0x5a, 0xd7, 0x3a, 0x99, 0x73, 0x4a, 0x94, 0x3e, 0x8c, 0x95, 0x2e, 0x06, 0x42, 0xb2, 0x20, 0x4c, 0x7d, 0x18, 0x12],
+        [0xd5, 0x08, 0x88, 0xff, 0x13, 0x9c, 0x19, 0x80, 0x5f, 0x50, 0x42, 0x67, 0xb2, 0xd4, 0x65, 0xf8, 0xdd, 0xbf, 0x63, 0xa4, 0xd7, 0x7c, 0x0b, 0x9a, 0x76, 0x31, 0x60, 0xe0, 0x4a, 0xa9, 0x16, 0x27],
+        [0x49, 0x2c, 0xab, 0xf7, 0xb8, 0x74, 0xe1, 0x38, 0xb0, 0xf8, 0x56, 0xa5, 0x2b, 0x3b, 0x6f, 0xc6, 0xc9, 0xa4, 0x4f, 0x74, 0xd3, 0x04, 0xf4, 0xb3, 0x88, 0x1e, 0xbf, 0x4b, 0xec, 0x66, 0x7e, 0x04],
+        [0x02, 0x27, 0x5d, 0x4a, 0x59, 0x50, 0x1a, 0xb0, 0xac, 0xa9, 0xc9, 0x54, 0x67, 0xbd, 0x3f, 0x4d, 0x78, 0xbb, 0x69, 0x77, 0xd2, 0xe5, 0x80, 0x0f, 0xc6, 0x17, 0x9f, 0xb8, 0x22, 0xa2, 0xfa, 0x26],
+    ],
+    [
+        [0x86, 0xbe, 0xe0, 0x2f, 0x81, 0x8c, 0xac, 0xe8, 0xc4, 0xdc, 0x00, 0x83, 0xb8, 0x1f, 0x2c, 0xb1, 0xc7, 0x54, 0x26, 0xe6, 0xe4, 0xdd, 0x47, 0xf6, 0x4f, 0x6e, 0xb9, 0x39, 0x31, 0xa0, 0x0b, 0x18],
+        [0x9e, 0x75, 0xe9, 0x27, 0x6b, 0x38, 0x97, 0x1e, 0xa2, 0xfd, 0x9a, 0x53, 0xbb, 0x99, 0x6a, 0xe5, 0x96, 0x4a, 0x4d, 0x29, 0xd5, 0x45, 0x1e, 0xfa, 0xb5, 0x8f, 0xc9, 0xc8, 0xc1, 0x2e, 0xf3, 0x20],
+        [0xd4, 0x65, 0x9b, 0xaf, 0x49, 0xdd, 0x74, 0xf4, 0x34, 0xa0, 0xb7, 0xf5, 0x24, 0x78, 0x29, 0xbd, 0x5d, 0x63, 0xb0, 0x5a, 0x66, 0x0f, 0x0f, 0x69, 0x42, 0x3d, 0x06, 0xea, 0x9d, 0xe4, 0x21, 0x28],
+        [0xd4, 0xcb, 0x03, 0xf6, 0x2a, 0x01, 0xb8, 0x35, 0x92, 0xa6, 0x1a, 0x41, 0x4f, 0x64, 0x47, 0x64, 0x8f, 0xb7, 0xf3, 0x3a, 0xa3, 0x6c, 0xfc, 0xd1, 0x79, 0xae, 0x12, 0x43, 0xec, 0x39, 0xda, 0x34],
+    ],
+    [
+        [0x34, 0x74, 0xbf, 0x9b, 0x78, 0x26, 0x42, 0xdc, 0x21, 0x65, 0x70, 0x73, 0x5b, 0xfb, 0x20, 0x21, 0x5f, 0xe6, 0xd9, 0xf7, 0xb8, 0x3c, 0x2c, 0x88, 0xd7, 0x5d, 0x9e, 0xc3, 0x28, 0x43, 0x41, 0x27],
+        [0xa4, 0x1e, 0x32, 0x1d, 0xf4, 0x92, 0xc3, 0x77, 0x39, 0x8b, 0xd9, 0xd2, 0xce, 0xa1, 0xc0, 0x08, 0xc2, 0x88, 0x69, 0x90, 0xc3, 0x65, 0x43, 0x5d, 0x32, 0xa2, 0xc4, 0xde, 0x5b, 0xe5, 0x30, 0x2d],
+        [0x59, 0x72, 0x07, 0xba, 0x0d, 0x92, 0x47, 0x99, 0xcf, 0x20, 0xce, 0x1b, 0x32, 0xfe, 0x76, 0xdf, 0xc9, 0xa2, 0x7b, 0xa5, 0xdb, 0xf7, 0x83, 0x91, 0xcd, 0x31, 0x8f, 0xec, 0x2d, 0xb8, 0x19, 0x28],
+        [0xdc, 0xa6, 0x13, 0x84, 0x77, 0x9f, 0x72, 0x8c, 0x9f, 0x6f, 0x1c, 0x62, 0x31, 0xe2, 0x56, 0xc0, 0x72, 0x88, 0xe0, 0x96, 0x51, 0x4c, 0xfd, 0x78, 0x1f, 0x9f, 0x2d, 0x82, 0xbb, 0xbc, 0xc0, 0x32],
+    ],
+    [
+        [0xc1, 0x5f, 0x4f, 0xe6, 0x4a, 0x04, 0x22, 0xc7, 0xb0, 0x8a, 0x4f, 0xe5, 0x39, 0x91, 0x1d, 0xdf, 0x70, 0xeb, 0xbd, 0x60, 0x62, 0xaf, 0xcc, 0x5f, 0x6f, 0x8c, 0x09, 0x64, 0x97, 0x37, 0x3c, 0x02],
+        [0x48, 0xa8, 0xbe, 0x7f, 0xa7, 0xfc, 0x3e, 0x7b, 0x78, 0xd5, 0x93, 0xc8, 0x5f, 0x3a, 0x2b, 0xcc, 0x52, 0x04, 0x5f, 0xbc, 0xff, 0xb5, 0xb2, 0xfb, 0xe2, 0x54, 0xcb, 0xc0, 0x8b, 0x71, 0x2a, 0x0a],
+        [0xd5, 0x95, 0xc2, 0xc0, 0x1b, 0x31, 0xf1, 0x75, 0x48, 0x2a, 0x94, 0x1a, 0x56, 0xd5, 0x68, 0x5e, 0x36, 0x44, 0x5c, 0x8a, 0xe0, 0x03, 0xd1, 0x23, 0xb9, 0x44, 0x02, 0x4b, 0x6f, 0xdf, 0x05, 0x0e],
+        [0x62, 0x12, 0x77, 0x12, 0x8a, 0x20, 0x2b, 0x4b, 0xec, 0xd3, 0x6f, 0xc8, 0xe9, 0xbb, 0xe8, 0xd0, 0xa0, 0xb3, 0x4c, 0x45, 0xe8, 0xbf, 0x51, 0x87, 0xa0, 0x5e, 0x15, 0x1e, 0x9f, 0xde, 0x42, 0x3d],
+    ],
+    [
+        [0xd6, 0x9e, 0x92, 0xe5, 0x68, 0x5b, 0x4e, 0xe2, 0xd4, 0x55, 0xb4, 0xc5, 0x69, 0x6b, 0x3f, 0x25, 0x5b, 0xd6, 0x8e, 0xaf, 0x8b, 0xf4, 0x7b, 0x74, 0x92, 0x44, 0xeb, 0x13, 0x92, 0x9f, 0xcf, 0x14],
+        [0x4a, 0x75, 0xd4, 0x1f, 0x43, 0x10, 0x79, 0xea, 0xc4, 0xf9, 0x4c, 0x2f, 0xf4, 0x9d, 0xbd, 0x5e, 0x7d, 0x96, 0x65, 0xac, 0xa0, 0xa0, 0xa1, 0x67, 0xc9, 0x09, 0xc0, 0xad, 0x38, 0xe2, 0xb1, 0x2a],
+        [0xea, 0x00, 0x48, 0xb3, 0xa8, 0x79, 0x50, 0x02, 0x9b, 0x21, 0x6b, 0xd5, 0x9e, 0x22, 0xf8, 0xfe, 0x56, 0x0b, 0x40, 0x96, 0xd5, 0x72, 0xec, 0x11, 0x93, 0xdf, 0xa5, 0x2e, 0xb2, 0x08, 0x59, 0x27],
+        [0x25, 0xb4, 0x96, 0xb2, 0x11, 0x79, 0xaa, 0x98, 0x1e, 0x42, 0xfb, 0x7b, 0x26, 0x73, 0x9e, 0xd0, 0xc3, 0x6c, 0x5d, 0xf1, 0x64, 0x73, 0x34, 0x64, 0x56, 0x91, 0x9d, 0x13, 0x84, 0xf2, 0x7f, 0x07],
+    ],
+    [
+        [0x5e, 0x3d, 0x98, 0x45, 0xd3, 0x40, 0xca, 0x94, 0x1a, 0xa1, 0x7e, 0xd3, 0x93, 0x70, 0x58, 0x48, 0x31, 0x14, 0xf0, 0x70, 0xb4, 0x56, 0xbe, 0xb8, 0x5c, 0x9e, 0xf5, 0xe5, 0xc8, 0x33, 0x3a, 0x0d],
+        [0x19, 0xd0, 0x19, 0xa6, 0xb0, 0x7c, 0x70, 0x8f, 0xe0, 0x09, 0x2b, 0x0b, 0xfe, 0x45, 0x6d, 0x1c, 0x57, 0x78, 0x5f, 0xae, 0xa8, 0xd9, 0xa6, 0xbd, 0xc5, 0xa5, 0x1b, 0xb5, 0x96, 0x03, 0x95, 0x04],
+        [0x78, 0x96, 0x5a, 0xab, 0x32, 0xb7, 0x4d, 0x9d, 0x59, 0x14, 0x37, 0x2c, 0xf7, 0xe9, 0xb7, 0x82, 0xb4, 0x5d, 0x42, 0x04, 0xd6, 0x39, 0x67, 0xf2, 0x4a, 0x7a, 0xf6, 0x2c, 0x70, 0xad, 0x96, 0x0d],
+        [0xae, 0xb2, 0xe2, 0x0a, 0x22, 0x61, 0x58, 0x3f, 0xfb, 0x3f, 0x53, 0xe0, 0xf6, 0x52, 0xfa, 0x3a, 0xae, 0xda, 0xa1, 0x86, 0xb9, 0x88, 0x74, 0xf4, 0x37, 0x52, 0x9e, 0xc4, 0xb7, 0x26, 0x8f, 0x30],
+    ],
+    [
+        [0x16, 0x3e, 0xf5, 0x54, 0xa5, 0x72, 0x30, 0x33, 0x4c, 0xa5, 0x8f, 0x8d, 0x1b, 0x86, 0xbe, 0xa9, 0x7f, 0x26, 0xae, 0xb2, 0xaf, 0xff, 0x2a, 0x86, 0xf9, 0x95, 0x7b, 0xc5, 0xc1, 0x17, 0x84, 0x24],
+        [0x29, 0xea, 0x13, 0x72, 0xa1, 0x82, 0xf3, 0x7c, 0x6f, 0x66, 0xa7, 0x44, 0x8b, 0x87, 0x1f, 0xcd, 0xdf, 0x30, 0xec, 0xe7, 0x15, 0x0e, 0x57, 0xc3, 0xc0, 0xac, 0x7c, 0x2b, 0xf3, 0x3c, 0xa3, 0x0e],
+        [0xc0, 0xf9, 0xc4, 0x3a, 0xd5, 0x9b, 0x0f, 0x44, 0x68, 0x93, 0x2c, 0xb0, 0xeb, 0x4d, 0x3c, 0x66, 0x8c, 0xfd, 0xf1, 0xe2, 0x50, 0x87, 0x79, 0x30, 0xbc, 0x8e, 0xf0, 0x40, 0x29, 0x5e, 0xf9, 0x1d],
+        [0x1b, 0x00, 0xc0, 0x4e, 0xd1, 0x56, 0x7b, 0x03, 0xe8, 0xc6, 0x58, 0x3d, 0x94, 0x67, 0x74, 0x81, 0x1f, 0x0c, 0xd0, 0x93, 0x08, 0xba, 0x0a, 0xa2, 0xfc, 0x28, 0x1e, 0x5d, 0x49, 0x0a, 0x1b, 0x1e],
+    ],
+    [
+        [0x51, 0xc4, 0x8c, 0x52, 0x9b, 0x6d, 0x14, 0x2e, 0x80, 0x14, 0xd8, 0xf8, 0xd7, 0xc6, 0xcf, 0x3f, 0xa2, 0x39, 0x03, 0xdd, 0x39, 0x55, 0x56, 0xd6, 0x76, 0xc8, 0xd3, 0xc8, 0x93, 0x76, 0x4a, 0x2f],
+        [0xe7, 0x7f, 0xcf, 0xec, 0x55, 0x77, 0x26, 0x78, 0x8f, 0xb1, 0x39, 0x5d, 0xdf, 0xeb, 0x25, 0x21, 0xca, 0xae, 0x05, 0x48, 0x4a, 0x61, 0x95, 0x35, 0x0f, 0x29, 0x7a, 0xf8, 0x81, 0xe5, 0xa2, 0x28],
+        [0xa1, 0x64, 0x2f, 0x9c, 0xc1, 0xbb, 0x87, 0x18, 0xbb, 0xed, 0xba, 0xdc, 0x8e, 0xf1, 0xa7, 0xd6, 0x52, 0xed, 0xa2, 0xa7, 0xfc, 0x6e, 0xeb, 0x94, 0xbb, 0xdb, 0xbb, 0xdb, 0xe5, 0x1a, 0xbf, 0x14],
+        [0x21, 0xc5, 0xeb, 0xd0, 0xa0, 0xd7, 0xc2, 0x97, 0xda, 0xbe, 0xb3, 0x0d, 0x96, 0xfe, 0x04, 0x6e, 0xbf, 0xa4, 0xe9, 0x15, 0x44, 0x36, 0x73, 0x5f, 0xe6, 0xea, 0x71, 0x2b, 0x59, 0x31, 0x3b, 0x3d],
+    ],
+    [
+        [0xa0, 0x1c, 0x9a, 0xb4, 0x2d, 0x78, 0x31, 0x97, 0x9b, 0x90, 0x60, 0xc1, 0xa1, 0xde, 0xf9, 0x66, 0x5b, 0x44, 0xd8, 0x71, 0x3d, 0x58, 0x19, 0x62, 0xae, 0x9a, 0x15, 0x44, 0xae, 0x08, 0x58, 0x02],
+        [0x8d, 0x57, 0x27, 0xf8, 0xcc, 0xa6, 0x87, 0x3b, 0x60, 0x5f, 0xc2, 0x3d, 0x3d, 0x42, 0x90, 0x59, 0xac, 0x04, 0x19, 0x66, 0xd5, 0xaf, 0x99, 0xcd, 0x22, 0x3b, 0xee, 0xc0, 0x41, 0xf1, 0xed, 0x17],
+        [0xd8, 0xb7, 0x19, 0x0f, 0x92, 0xdb, 0x90, 0xb3, 0x00, 0xbd, 0xd2, 0xe1, 0x83, 0xee, 0xf7, 0xd5, 0x8d, 0xd9, 0x3e, 0xdb, 0x1a, 0xcc, 0x54, 0xfb, 0x6f, 0xd0, 0x2e, 0x8e, 0x85, 0x93, 0x46, 0x07],
+        [0xac, 0xcc, 0x46, 0x32, 0x00, 0x31, 0xdc, 0x4b, 0xd9, 0x8d, 0xed, 0x2a, 0xf4, 0x01, 0x07, 0x27, 0xc4, 0x74, 0x7f, 0x69, 0x3a, 0x72, 0xe3, 0x4c, 0x25, 0xc8, 0x70, 0x4f, 0x1f, 0x52, 0x99, 0x3c],
+    ],
+];
+
+/// $z$-values for 9-bit short windows
+pub const Z_SHORT_9: [u64; NUM_WINDOWS_SHORT_9] = [85, 467, 40, 82, 182];
+
+/// $u$-values for 9-bit short windows
+pub const U_SHORT_9: [[[u8; 32]; 4]; NUM_WINDOWS_SHORT_9] = [
+    [
+        [0xa1, 0x60, 0xb0, 0x2c, 0x11, 0xc7, 0x54, 0xb8, 0x03, 0xe8, 0xb8, 0x76, 0x69, 0x61, 0x9c, 0x45, 0x48, 0xd4, 0xad, 0x37, 0xf7, 0x5f, 0xe9, 0xf1, 0x54, 0x71, 0xf3, 0xd6, 0x5c, 0x26, 0xfa, 0x1e],
+        [0xfc, 0xb5, 0x50, 0xdb, 0x70, 0xcd, 0xf9, 0x07, 0xb4, 0x7d, 0x93, 0x55, 0xb7, 0xc0, 0x25, 0x14, 0x4f, 0x47, 0x15, 0xbf, 0xa4, 0x9e, 0xb2, 0xd0, 0xe1, 0xad, 0x1b, 0x7b, 0xf7, 0x2c, 0x3b, 0x21],
+        [0xf5, 0xb7, 0x2e, 0x1a, 0x94, 0xc7, 0x7d, 0x8b, 0xaf, 0x20, 0x3d, 0x8f, 0xf1, 0x16, 0x3d, 0x38, 0xfe, 0x4f, 0xf0, 0xd5, 0x08, 0xe4, 0xb8, 0xec, 0x29, 0x9f, 0x87, 0xfe, 0x98, 0xd6, 0x6e, 0x3a],
+        [0x90, 0xdb, 0x5a, 0x59, 0x75, 0x8d, 0x87, 0xad, 0x21, 0xf3, 0xba, 0xa4, 0x53, 0x79, 0x4d, 0xe7, 0x18, 0xab, 0xfa, 0x8f, 0x7d, 0x86, 0x9f, 0xcb, 0x55, 0x61, 0x63, 0xe1, 0x5d, 0x73, 0xa8, 0x2e],
+    ],
+    [
+        [0x23, 0xfd, 0xd0, 0x4e, 0x0e, 0x99, 0xa0, 0x3b, 0x6d, 0xf4, 0x89, 0x13, 0x9f, 0x2d, 0xe1, 0xce, 0x69, 0x53, 0x69, 0x19, 0xff, 0x43, 0xbd, 0x3e, 0xd0, 0x48, 0x1f, 0xae, 0xf3, 0x7a, 0x9a, 0x12],
+        [0xb7, 0xa5, 0x42, 0x4f, 0xb0, 0xe8, 0x0f, 0x90, 0xa4, 0xff, 0xb4, 0x32, 0xb2, 0x00, 0x93, 0xaf, 0x88, 0xf8, 0x76, 0xdc, 0x35, 0x67, 0x75, 0xc3, 0x7d, 0x73, 0x8d, 0x6b, 0x4d, 0xe7, 0x1f, 0x1a],
+        [0xb1, 0x94, 0xcc, 0x7e, 0xcc, 0xfa, 0x47, 0x53, 0x61, 0x40, 0x68, 0x7d, 0x14, 0xd1, 0xa1, 0x11, 0x0e, 0xff, 0xef, 0xc0, 0xfe, 0x86, 0x58, 0x06, 0x65, 0xac, 0x03, 0x85, 0x6a, 0x91, 0x5d, 0x35],
+        [0xa6, 0x10, 0x1e, 0x19, 0x23, 0xaa, 0x99, 0x78, 0x06, 0xa8, 0x10, 0xd6, 0x7a, 0x6d, 0xbe, 0xa0, 0xb7, 0x13, 0x1a, 0xa4, 0x97, 0x2e, 0x97, 0xfa, 0xf1, 0x01, 0x75, 0x1c, 0x8d, 0x8c, 0x0c, 0x3a],
+    ],
+    [
+        [0x53, 0xf5, 0x31, 0x02, 0xa2, 0x67, 0x8c, 0xfc, 0x61, 0x45, 0xc8, 0xb3, 0x49, 0xd6, 0x85, 0xc6, 0xb1, 0xea, 0x94, 0x54, 0xe7, 0xe4, 0x44, 0xa9, 0x2e, 0x86, 0x80, 0x23, 0x8b, 0x09, 0x8c, 0x17],
+        [0x5f, 0x46, 0x47, 0x2a, 0x90, 0xae, 0x00, 0x8d, 0x54, 0x1b, 0x08, 0x91, 0x93, 0xec, 0x80, 0xd1, 0xd5, 0xc3, 0x1a, 0xd4, 0x02, 0xf5, 0x4c, 0x55, 0xca, 0x8c, 0xe5, 0x46, 0xe7, 0x6b, 0xd6, 0x21],
+        [0x74, 0xfa, 0xef, 0x80, 0x81, 0xac, 0xa8, 0x3c, 0x41, 0x75, 0x3e, 0xd6, 0x10, 0x72, 0x57, 0x51, 0x6f, 0xd1, 0xfe, 0x76, 0x28, 0xab, 0x59, 0xe3, 0x80, 0x30, 0x27, 0xf6, 0xe0, 0x29, 0xfb, 0x18],
+        [0xf3, 0xdf, 0xb5, 0xf8, 0x0a, 0x33, 0x5d, 0x75, 0x78, 0xd8, 0x3d, 0xb4, 0x93, 0xba, 0x00, 0x22, 0x3a, 0xe1, 0x37, 0x80, 0x58, 0xca, 0xd4, 0x5b, 0xda, 0x44, 0x86, 0x16, 0x10, 0x1f, 0xeb, 0x12],
+    ],
+    [
+        [0xcc, 0xb3, 0x06, 0x9a, 0xfd, 0xd9, 0xcf, 0x66, 0x07, 0xea, 0x8b, 0xa5, 0xf1, 0xff, 0x32, 0xbe, 0x3d, 0x40, 0xf6, 0x37, 0xd7, 0xdd, 0xe1, 0xa9, 0xc5, 0xd4, 0x6d, 0xa6, 0x6e, 0xd4, 0x89, 0x08],
+        [0x6c, 0x9a, 0x68, 0x45, 0x2f, 0x1b, 0x8b, 0xe3, 0x7e, 0x90, 0x16, 0xb7, 0xb1, 0xbe, 0x27, 0xa4, 0x5e, 0xe6, 0x18, 0x4b, 0xf6, 0xf1, 0x5c, 0x66, 0x41, 0xd6, 0x71, 0xd3, 0xb8, 0x68, 0xa2, 0x10],
+        [0xea, 0x3a, 0x77, 0xc2, 0x7c, 0x4a, 0xcb, 0xbb, 0xe6, 0x6d, 0x99, 0xb1, 0x43, 0xae, 0x92, 0xef, 0x4e, 0xda, 0x17, 0xbc, 0x0b, 0x73, 0xfd, 0x3d, 0xc0, 0x98, 0xb8, 0x3a, 0x42, 0x2f, 0x8e, 0x05],
+        [0x98, 0x79, 0x31, 0x52, 0x21, 0x8b, 0x4a, 0xcc, 0x5e, 0xf2, 0xa0, 0xd4, 0x27, 0x23, 0x02, 0x01, 0x2c, 0x22, 0xd3, 0x03, 0xa1, 0x18, 0xd7, 0x5b, 0x63, 0x60, 0x17, 0x5a, 0x81, 0x01, 0xc5, 0x0a],
+    ],
+    [
+        [0x3b, 0xc7, 0x89, 0x55, 0x3f, 0x92, 0x6e, 0xe1, 0x5b, 0x75, 0x02, 0xe1, 0x59, 0x91, 0x7e, 0xca, 0x04, 0x97, 0xd6, 0x51, 0x6b, 0xc8, 0x21, 0x8a, 0x16, 0xc9, 0xf7, 0xfe, 0xb5, 0x1e, 0xe1, 0x39],
+        [0x1a, 0x3d, 0xb8, 0x44, 0x66, 0xac, 0x15, 0x7b, 0x23, 0xfb, 0x04, 0xc9, 0x09, 0x12, 0x6f, 0x75, 0x5f, 0x4d, 0x67, 0x0b, 0xc5, 0x40, 0x9a, 0x8a, 0x25, 0x44, 0xa7, 0xf6, 0x6e, 0x3f, 0xb0, 0x1d],
+        [0x0f, 0xf0, 0x39, 0x20, 0xd3, 0x51, 0x17, 0xd8, 0xe6, 0x94, 0xfa, 0xae, 0x58, 0x7d, 0x78, 0xf8, 0xb8, 0xa6, 0xea, 0x0a, 0xf2, 0x82, 0x6c, 0x77, 0x1e, 0x69, 0xa5, 0xc6, 0x9d, 0xf6, 0x2a, 0x34],
+        [0x5d, 0x58, 0xa2, 0x9f, 0x48, 0xa8, 0x68, 0x07, 0xed, 0x4b, 0x26, 0xee, 0x1a, 0x2b, 0xf8, 0xb7, 0x06, 0x52, 0xa8, 0x84, 0x9f, 0x6e, 0x1a, 0xa1, 0xe8, 0x63, 0xb4, 0xdf, 0xab, 0x40, 0xd2, 0x38],
+    ],
+];
+
+/// $z$-values for 64-bit short windows
+pub const Z_SHORT: [u64; NUM_WINDOWS_SHORT] = [85, 467, 40, 82, 719, 933, 60, 434, 482, 180, 32, 839, 1444, 146, 611, 115, 106, 337, 321, 333, 75, 822, 3, 86, 304, 214, 139, 428, 729, 480, 1076, 32];
+
+/// $u$-values for 64-bit short windows
+pub const U_SHORT: [[[u8; 32]; 4]; NUM_WINDOWS_SHORT] = [
+    [
+        [0xa1, 0x60, 0xb0, 0x2c, 0x11, 0xc7, 0x54, 0xb8, 0x03, 0xe8, 0xb8, 0x76, 0x69, 0x61, 0x9c, 0x45, 0x48, 0xd4, 0xad, 0x37, 0xf7, 0x5f, 0xe9, 0xf1, 0x54, 0x71, 0xf3, 0xd6, 0x5c, 0x26, 0xfa, 0x1e],
+        [0xfc, 0xb5, 0x50, 0xdb, 0x70, 0xcd, 0xf9, 0x07, 0xb4, 0x7d, 0x93, 0x55, 0xb7, 0xc0, 0x25, 0x14, 0x4f, 0x47, 0x15, 0xbf, 0xa4, 0x9e, 0xb2, 0xd0, 0xe1, 0xad, 0x1b, 0x7b, 0xf7, 0x2c, 0x3b, 0x21],
+        [0xf5, 0xb7, 0x2e, 0x1a, 0x94, 0xc7, 0x7d, 0x8b, 0xaf, 0x20, 0x3d, 0x8f, 0xf1, 0x16, 0x3d, 0x38, 0xfe, 0x4f, 0xf0, 0xd5, 0x08, 0xe4, 0xb8, 0xec, 0x29, 0x9f, 0x87, 0xfe, 0x98, 0xd6, 0x6e, 0x3a],
+        [0x90, 0xdb, 0x5a, 0x59, 0x75, 0x8d, 0x87, 0xad, 0x21, 0xf3, 0xba, 0xa4, 0x53, 0x79, 0x4d, 0xe7, 0x18, 0xab, 0xfa, 0x8f, 0x7d, 0x86, 0x9f, 0xcb, 0x55, 0x61, 0x63, 0xe1, 0x5d, 0x73, 0xa8, 0x2e],
+    ],
+    [
+        [0x23, 0xfd, 0xd0, 0x4e, 0x0e, 0x99, 0xa0, 0x3b, 0x6d, 0xf4, 0x89, 0x13, 0x9f, 0x2d, 0xe1, 0xce, 0x69, 0x53, 0x69, 0x19, 0xff, 0x43, 0xbd, 0x3e, 0xd0, 0x48, 0x1f, 0xae, 0xf3, 0x7a, 0x9a, 0x12],
+        [0xb7, 0xa5, 0x42, 0x4f, 0xb0, 0xe8, 0x0f, 0x90, 0xa4, 0xff, 0xb4, 0x32, 0xb2, 0x00, 0x93, 0xaf, 0x88, 0xf8, 0x76, 0xdc, 0x35, 0x67, 0x75, 0xc3, 0x7d, 0x73, 0x8d, 0x6b, 0x4d, 0xe7, 0x1f, 0x1a],
+        [0xb1, 0x94, 0xcc, 0x7e, 0xcc, 0xfa, 0x47, 0x53, 0x61, 0x40, 0x68, 0x7d, 0x14, 0xd1, 0xa1, 0x11, 0x0e, 0xff, 0xef, 0xc0, 0xfe, 0x86, 0x58, 0x06, 0x65, 0xac, 0x03, 0x85, 0x6a, 0x91, 0x5d, 0x35],
+        [0xa6, 0x10, 0x1e, 0x19, 0x23, 0xaa, 0x99, 0x78, 0x06, 0xa8, 0x10, 0xd6, 0x7a, 0x6d, 0xbe, 0xa0, 0xb7, 0x13, 0x1a, 0xa4, 0x97, 0x2e, 0x97, 0xfa, 0xf1, 0x01, 0x75, 0x1c, 0x8d, 0x8c, 0x0c, 0x3a],
+    ],
+    [
+        [0x53, 0xf5, 0x31, 0x02, 0xa2, 0x67, 0x8c, 0xfc, 0x61, 0x45, 0xc8, 0xb3, 0x49, 0xd6, 0x85, 0xc6, 0xb1, 0xea, 0x94, 0x54, 0xe7, 0xe4, 0x44, 0xa9, 0x2e, 0x86, 0x80, 0x23, 0x8b, 0x09, 0x8c, 0x17],
+        [0x5f, 0x46, 0x47, 0x2a, 0x90, 0xae, 0x00, 0x8d, 0x54, 0x1b, 0x08, 0x91, 0x93, 0xec, 0x80, 0xd1, 0xd5, 0xc3, 0x1a, 0xd4, 0x02, 0xf5, 0x4c, 0x55, 0xca, 0x8c, 0xe5, 0x46, 0xe7, 0x6b, 0xd6, 0x21],
+        [0x74, 0xfa, 0xef, 0x80, 0x81, 0xac, 0xa8, 0x3c, 0x41, 0x75, 0x3e, 0xd6, 0x10, 0x72, 0x57, 0x51, 0x6f, 0xd1, 0xfe, 0x76, 0x28, 0xab, 0x59, 0xe3, 0x80, 0x30, 0x27, 0xf6, 0xe0, 0x29, 0xfb, 0x18],
+        [0xf3, 0xdf, 0xb5, 0xf8, 0x0a, 0x33, 0x5d, 0x75, 0x78, 0xd8, 0x3d, 0xb4, 0x93, 0xba, 0x00, 0x22, 0x3a, 0xe1, 0x37, 0x80, 0x58, 0xca, 0xd4, 0x5b, 0xda, 0x44, 0x86, 0x16, 0x10, 0x1f, 0xeb, 0x12],
+    ],
+    [
+        [0xcc, 0xb3, 0x06, 0x9a, 0xfd, 0xd9, 0xcf, 0x66, 0x07, 0xea, 0x8b, 0xa5, 0xf1, 0xff, 0x32, 0xbe, 0x3d, 0x40, 0xf6, 0x37, 0xd7, 0xdd, 0xe1, 0xa9, 0xc5, 0xd4, 0x6d, 0xa6, 0x6e, 0xd4, 0x89, 0x08],
+        [0x6c, 0x9a, 0x68, 0x45, 0x2f, 0x1b, 0x8b, 0xe3, 0x7e, 0x90, 0x16, 0xb7, 0xb1, 0xbe, 0x27, 0xa4, 0x5e, 0xe6, 0x18, 0x4b, 0xf6, 0xf1, 0x5c, 0x66, 0x41, 0xd6, 0x71, 0xd3, 0xb8, 0x68, 0xa2, 0x10],
+        [0xea, 0x3a, 0x77, 0xc2, 0x7c, 0x4a, 0xcb, 0xbb, 0xe6, 0x6d, 0x99, 0xb1, 0x43, 0xae, 0x92, 0xef, 0x4e, 0xda, 0x17, 0xbc, 0x0b, 0x73, 0xfd, 0x3d, 0xc0, 0x98, 0xb8, 0x3a, 0x42, 0x2f, 0x8e, 0x05],
+        [0x98, 0x79, 0x31, 0x52, 0x21, 0x8b, 0x4a, 0xcc, 0x5e, 0xf2, 0xa0, 0xd4, 0x27, 0x23, 0x02, 0x01, 0x2c, 0x22, 0xd3, 0x03, 0xa1, 0x18, 0xd7, 0x5b, 0x63, 0x60, 0x17, 0x5a, 0x81, 0x01, 0xc5, 0x0a],
+    ],
+    [
+        [0xdf, 0xd3, 0x08, 0x2b, 0x32, 0xb5, 0xda, 0xac, 0x88, 0xd5, 0xce, 0xc0, 0x66, 0x6b, 0xdf, 0x84, 0x43, 0x4b, 0x49, 0xc8, 0x8a, 0xdc, 0x9e, 0xb3, 0xe8, 0x9c, 0xe0, 0x5d, 0x2c, 0x7b, 0xe1, 0x21],
+        [0xdd, 0x73, 0x0c, 0xfb, 0x07, 0x56, 0x89, 0x53, 0x01, 0x89, 0x63, 0xdb, 0xba, 0x02, 0x59, 0x94, 0x75, 0xca, 0xc7, 0xbc, 0xac, 0x7f, 0xd2, 0x0b, 0xd1, 0xae, 0xb9, 0x8a, 0x6b, 0xaf, 0x1b, 0x12],
+        [0x32, 0x5e, 0x4c, 0xd6, 0x26, 0xec, 0xc2, 0xdc, 0xe5, 0xc0, 0xb4, 0xf5, 0x2b, 0xb0, 0xd3, 0xd3, 0xe2, 0x0c, 0xfd, 0xd2, 0x28, 0x0d, 0x83, 0x80, 0x55, 0x31, 0x92, 0x46, 0xd9, 0x23, 0x7a, 0x39],
+        [0x9c, 0xac, 0x1a, 0xca, 0x85, 0x65, 0x40, 0xb7, 0x64, 0xd8, 0xc7, 0x35, 0x8b, 0x35, 0xaf, 0xf0, 0x6a, 0xf1, 0xd2, 0xdf, 0xc2, 0x7d, 0xca, 0xdd, 0xf3, 0x26, 0xfc, 0x57, 0x47, 0x65, 0x3e, 0x00],
+    ],
+    [
+        [0x01, 0x71, 0x5d, 0x02, 0xec, 0xd2, 0x33, 0xb5, 0xe9, 0x14, 0x35, 0xe0, 0x68, 0xa0, 0xf1, 0x33, 0xd8, 0x47, 0x82, 0x7f, 0x83, 0xc5, 0xf8, 0x03, 0x41, 0x12, 0x1b, 0x17, 0x2b, 0x27, 0xaf, 0x0c],
+        [0x18, 0x34, 0x3b, 0x84, 0xd8, 0x2a, 0xb4, 0x17, 0x65, 0x5b, 0xdb, 0xaf, 0x5e, 0xb9, 0x0c, 0x10, 0x58, 0xf2, 0x25, 0x1e, 0x3b, 0x76, 0x88, 0x9f, 0x47, 0xf1, 0x50, 0xbc, 0x6b, 0x44, 0x20, 0x37],
+        [0xbe, 0xd6, 0xce, 0xbc, 0x07, 0xdd, 0x13, 0x63, 0xc6, 0x3a, 0x2b, 0x9d, 0x26, 0xfb, 0x58, 0x36, 0x1f, 0xd2, 0x0f, 0x3b, 0x7d, 0x26, 0xfa, 0x8f, 0x7e, 0xef, 0x51, 0x24, 0xcf, 0x7f, 0x61, 0x0f],
+        [0xb5, 0xbf, 0x42, 0x27, 0x49, 0x2c, 0xee, 0xb0, 0xe6, 0x17, 0x6d, 0x60, 0xda, 0x66, 0x68, 0x54, 0x9c, 0x7f, 0x5b, 0xd9, 0x44, 0x41, 0x5b, 0x82, 0xa5, 0x03, 0x7a, 0xa0, 0xb3, 0x56, 0xf2, 0x1f],
+    ],
+    [
+        [0xfa, 0x69, 0x41, 0xc1, 0xdf, 0xe4, 0x91, 0xcf, 0x30, 0x29, 0xc7, 0x4e, 0x70, 0xb7, 0xbe, 0x49, 0xc9, 0x5a, 0x55, 0x25, 0xe9, 0x96, 0x44, 0xc6, 0x39, 0xbb, 0xfd, 0x3e, 0xdf, 0xfb, 0xcb, 0x29],
+        [0x88, 0xf0, 0xab, 0x87, 0x27, 0x7a, 0x5e, 0x45, 0x8f, 0x31, 0x60, 0x00, 0x94, 0xe3, 0xfb, 0x7d, 0x15, 0x57, 0x1c, 0x8d, 0x77, 0x6d, 0x84, 0x32, 0x36, 0xe4, 0x51, 0x0f, 0x34, 0xa2, 0xa3, 0x00],
+        [0xd3, 0x86, 0x52, 0x5a, 0xfc, 0xa3, 0x8b, 0x05, 0x23, 0x98, 0xd7, 0x98, 0xa3, 0x14, 0x93, 0xd7, 0x87, 0x21, 0x96, 0x76, 0x9a, 0xe2, 0xad, 0x17, 0x54, 0x02, 0x3b, 0xc5, 0x28, 0x29, 0x7e, 0x07],
+        [0xad, 0xa6, 0xa1, 0xe0, 0x87, 0x37, 0x99, 0xf3, 0xa3, 0xad, 0x88, 0xe1, 0x8b, 0x57, 0x38, 0x1c, 0x34, 0x5a, 0x77, 0xc3, 0x5d, 0x2a, 0xd6, 0xb6, 0xcb, 0xc1, 0xe3, 0x5e, 0x0c, 0x3e, 0x35, 0x3a],
+    ],
+    [
+        [0xa1, 0x6b, 0x1f, 0xdd, 0x2d, 0x57, 0x0b, 0x3f, 0xc3, 0x9a, 0xd9, 0x36, 0x97, 0x82, 0x10, 0x7e, 0x54, 0x8c, 0x53, 0x34, 0x78, 0x33, 0x6b, 0xbe, 0x6e, 0x0a, 0xc2, 0xb0, 0xcc, 0xf7, 0xd0, 0x07],
+        [0x2e, 0x5a, 0x5d, 0x5a, 0x9a, 0x52, 0x6e, 0xc9, 0x27, 0xf5, 0xa0, 0xa4, 0xdd, 0x8f, 0x35, 0x16, 0x40, 0x70, 0x12, 0x74, 0xe0, 0x4a, 0x07, 0x98, 0x68, 0xc9, 0x6b, 0x82, 0x32, 0x6e, 0xbe, 0x09],
+        [0xb7, 0xc8, 0xed, 0x2c, 0xcf, 0xbf, 0x7b, 0x5d, 0x42, 0xaf, 0xbf, 0x7e, 0x99, 0xbd, 0x0c, 0x9d, 0xc8, 0x66, 0xeb, 0x52, 0x26, 0xc0, 0x7d, 0x2c, 0xb2, 0xd6, 0xc0, 0x3e, 0x73, 0x26, 0xc3, 0x15],
+        [0x1b, 0xe3, 0xd4, 0x66, 0x04, 0xbf, 0x7a, 0xbf, 0x5a, 0x80, 0xee, 0xf9, 0xe5, 0xc8, 0x28, 0x1e, 0x54, 0xdc, 0x13, 0x3b, 0xe3, 0x7c, 0xde, 0xb8, 0x39, 0xee, 0x36, 0xdb, 0x17, 0x10, 0x4e, 0x35],
+    ],
+    [
+        [0x8a, 0xac, 0xb5, 0x0e, 0x70, 0xc8, 0xa3, 0x00, 0xcd, 0x50, 0xa2, 0x0b, 0xdc, 0xaf, 0x5d, 0xe4, 0xfa, 0xa6, 0x52, 0x21, 0x7a, 0xfb, 0xc8, 0x9f, 0x25, 0xd5, 0x82, 0x11, 0xb6, 0xff, 0x42, 0x01],
+        [0x0d, 0xd6, 0x38, 0xc0, 0xac, 0x9b, 0x29, 0x42, 0x37, 0x8e, 0x9c, 0x5e, 0x95, 0x46, 0x89, 0x25, 0x7c, 0x0b, 0x7b, 0xd2, 0xe2, 0x62, 0x1c, 0x76, 0x24, 0x02, 0x55, 0xbb, 0x4c, 0x37, 0x47, 0x0f],
+        [0xc3, 0x6a, 0x36, 0xff, 0x78, 0x05, 0xe2, 0x4f, 0x4e, 0x63, 0xbd, 0x5d, 0x7e, 0x37, 0xd7, 0xf1, 0xd0, 0x0a, 0x2a, 0xf0, 0x98, 0x1c, 0xbd, 0x7e, 0xc5, 0xf7, 0xad, 0xcf, 0xbc, 0x54, 0x54, 0x2a],
+        [0x48, 0xcc, 0x57, 0x70, 0x56, 0x31, 0xde, 0x07, 0xcf, 0x09, 0xb6, 0xad, 0xe1, 0xc9, 0x30, 0x56, 0x42, 0x6b, 0x63, 0xe4, 0x25, 0xd8, 0x22, 0x37, 0x09, 0xa8, 0xad, 0x47, 0x19, 0x3f, 0x56, 0x07],
+    ],
+    [
+        [0x33, 0xdd, 0x39, 0x82, 0x06, 0xf2, 0xe1, 0x50, 0x29, 0x2b, 0xc6, 0xfb, 0x50, 0x6c, 0x81, 0x24, 0xe2, 0x8d, 0xc5, 0x87, 0xd4, 0x52, 0xb4, 0xd8, 0xfe, 0x6f, 0x96, 0xa4, 0xe3, 0x2b, 0x5f, 0x0b],
+        [0x60, 0xb1, 0x1b, 0xb0, 0x6b, 0x47, 0xee, 0x9e, 0x71, 0xd0, 0xfd, 0x93, 0xf0, 0x43, 0x45, 0x3d, 0xde, 0xa0, 0x31, 0xc1, 0x91, 0xd8, 0xa8, 0x98, 0xbc, 0xb7, 0x3b, 0x76, 0xdd, 0x52, 0x57, 0x0a],
+        [0xb9, 0x97, 0x31, 0xa3, 0x16, 0xa5, 0x34, 0x9e, 0x4d, 0x5c, 0x36, 0xff, 0x6a, 0x74, 0x65, 0xd9, 0xe9, 0xd4, 0xf9, 0x7a, 0xcb, 0xa5, 0xeb, 0xe9, 0x6d, 0x69, 0xa3, 0x24, 0xe8, 0x7f, 0x07, 0x2b],
+        [0xe4, 0x91, 0x2a, 0x14, 0x26, 0xab, 0x4f, 0x69, 0x9c, 0xcd, 0x24, 0xe0, 0x7a, 0xe4, 0x4f, 0x21, 0xa2, 0x69, 0xd9, 0x69, 0x4d, 0x7f, 0x99, 0xf8, 0x25, 0x8c, 0x28, 0x9e, 0xd7, 0xb4, 0x07, 0x22],
+    ],
+    [
+        [0x4f, 0x70, 0x45, 0x28, 0x1d, 0x4d, 0x91, 0xbb, 0x05, 0x3b, 0x4d, 0x29, 0x37, 0xe4, 0x37, 0xc1, 0xe9, 0x16, 0x35, 0x16, 0x53, 0x4d, 0x88, 0x61, 0x14, 0xfd, 0xee, 0x04, 0xcc, 0xaa, 0x11, 0x19],
+        [0x6d, 0x44, 0x99, 0x7e, 0x1a, 0x46, 0x9e, 0xdd, 0x81, 0xb8, 0x79, 0xdc, 0x9a, 0x1b, 0xf0, 0xb0, 0x34, 0x1f, 0x25, 0x85, 0xb9, 0xe1, 0x96, 0x85, 0xb3, 0xd5, 0x1d, 0x92, 0x88, 0x5d, 0x09, 0x04],
+        [0xc0, 0xea, 0xf3, 0x67, 0x17, 0xb5, 0xbf, 0x63, 0xc5, 0xc2, 0x33, 0x89, 0xc5, 0x59, 0x35, 0x63, 0x68, 0x26, 0x2a, 0xf2, 0x19, 0x28, 0xe4, 0xf4, 0xa7, 0xa1, 0xdf, 0x14, 0xf4, 0x1b, 0x30, 0x3b],
+        [0x3f, 0x04, 0x71, 0x7f, 0xf4, 0xde, 0xb4, 0x07, 0x01, 0xcc, 0x5b, 0x04, 0x92, 0x58, 0x5a, 0x21, 0x56, 0x62, 0xfe, 0x14, 0x52, 0x1c, 0x52, 0xab, 0x52, 0x55, 0x2d, 0xbf, 0x64, 0x5f, 0x01, 0x09],
+    ],
+    [
+        [0x0f, 0xdd, 0x06, 0xda, 0xac, 0x1e, 0x1c, 0x00, 0xa3, 0x22, 0xbc, 0xe0, 0x3a, 0x40, 0x73, 0x85, 0xc7, 0x90, 0x2a, 0x24, 0x4a, 0x5c, 0xc4, 0x2b, 0xb7, 0x78, 0xf3, 0x85, 0xb5, 0x0f, 0xc0, 0x0a],
+        [0xcb, 0xef, 0xb4, 0x1f, 0xc3, 0xd0, 0x32, 0x2c, 0xa1, 0x7e, 0xb7, 0xbb, 0x94, 0xa2, 0x26, 0x9f, 0x72, 0x25, 0xba, 0xc5, 0xdd, 0x97, 0x7e, 0xec, 0xd0, 0xaf, 0x48, 0x11, 0xe1, 0x67, 0x8e, 0x22],
+        [0x3e, 0x08, 0x08, 0x32, 0xb5, 0x02, 0xc5, 0xe2, 0x75, 0x41, 0xe2, 0x89, 0x6f, 0xed, 0xac, 0xc1, 0x56, 0x0c, 0x38, 0xcb, 0xb2, 0x1a, 0x5b, 0x57, 0xd4, 0x03, 0xc4, 0x95, 0x7c, 0xe0, 0xd3, 0x27],
+        [0x0e, 0x4e, 0xa6, 0x91, 0xb4, 0x80, 0x09, 0x16, 0x9b, 0xf6, 0xa7, 0xaf, 0x84, 0x1d, 0xc3, 0xfd, 0x85, 0x12, 0xb0, 0x34, 0x78, 0xc3, 0x09, 0x15, 0x47, 0x84, 0xd8, 0xa7, 0x87, 0xb1, 0xea, 0x0e],
+    ],
+    [
+        [0xce, 0x9b, 0x81, 0x02, 0xbd, 0x2b, 0x77, 0x1e, 0x36, 0x98, 0x70, 0xb9, 0x36, 0x81, 0x32, 0x61, 0xf3, 0xd4, 0xf1, 0x8a, 0x15, 0x3f, 0x1b, 0xde, 0xe3, 0x9e, 0xd7, 0x75, 0xb8, 0xbd, 0xb7, 0x33],
+        [0xaa, 0xbb, 0x6b, 0x48, 0x34, 0x7a, 0x4f, 0xbd, 0x05, 0x7f, 0x33, 0x10, 0x0c, 0xda, 0x01, 0x42, 0xbe, 0x8a, 0x8c, 0x11, 0x0e, 0xbf, 0x33, 0xce, 0xa9, 0xce, 0x9b, 0xb0, 0x7f, 0x87, 0xac, 0x1b],
+        [0xda, 0x5a, 0x46, 0xc3, 0x39, 0xc9, 0xd5, 0x22, 0x7c, 0x48, 0x21, 0x4d, 0x5e, 0xa1, 0x40, 0x7a, 0x70, 0xeb, 0xb2, 0xde, 0x79, 0xe9, 0xd4, 0x33, 0x91, 0xd7, 0x1e, 0x6b, 0x74, 0x21, 0x56, 0x3c],
+        [0x09, 0x25, 0x31, 0x16, 0xde, 0xaa, 0x49, 0xb4, 0x19, 0x3d, 0x1e, 0x76, 0x2c, 0xd1, 0xfb, 0x9a, 0xf8, 0x1a, 0x39, 0xeb, 0x34, 0x0c, 0xb0, 0x16, 0xbb, 0xdb, 0x59, 0x54, 0x3d, 0x67, 0x69, 0x20],
+    ],
+    [
+        [0x44, 0xfe, 0x2e, 0x79, 0xa5, 0x48, 0xdf, 0x4a, 0xf4, 0xe8, 0xc6, 0x35, 0x5a, 0x51, 0xe4, 0xe5, 0x70, 0xe6, 0x4c, 0xd5, 0x58, 0xac, 0xd7, 0x88, 0x32, 0xd9, 0x04, 0xd7, 0x2a, 0x05, 0x81, 0x0a],
+        [0xce, 0x3f, 0xce, 0xf2, 0x46, 0x61, 0x74, 0x45, 0x37, 0xdd, 0x0a, 0x2f, 0xda, 0xa8, 0x52, 0xae, 0xc9, 0x12, 0x46, 0x50, 0x74, 0xb8, 0x83, 0x31, 0x99, 0x3e, 0xa0, 0xe7, 0x59, 0xd1, 0x10, 0x03],
+        [0x2b, 0x4c, 0x61, 0xb3, 0x6c, 0x69, 0x23, 0x25, 0xd8, 0xa4, 0x95, 0xcb, 0x85, 0xb0, 0x38, 0xb3, 0xd3, 0xc6, 0x10, 0x3c, 0x97, 0x83, 0x0f, 0xd2, 0x41, 0x70, 0xa2, 0x7b, 0x8e, 0xe1, 0x1b, 0x38],
+        [0x24, 0xbe, 0x68, 0x2a, 0xa4, 0x27, 0x4f, 0xee, 0x42, 0xfc, 0xdf, 0x05, 0x8d, 0xfb, 0xfb, 0xd5, 0x1c, 0x38, 0xe4, 0xc7, 0xe4, 0xcc, 0x49, 0x68, 0x55, 0x88, 0x66, 0x6b, 0xe2, 0x37, 0x2c, 0x24],
+    ],
+    [
+        [0x55, 0xf8, 0x49, 0x0b, 0x1c, 0x44, 0x52, 0x8e, 0x81, 0x64, 0x42, 0x19, 0x00, 0x03, 0xf7, 0x47, 0xd3, 0x54, 0x6a, 0x67, 0x1f, 0xb6, 0x78, 0xc1, 0x89, 0xdc, 0xd0, 0x46, 0x47, 0x6f, 0xcb, 0x39],
+        [0xd6, 0x25, 0xff, 0x84, 0xff, 0x89, 0xad, 0x83, 0xfe, 0x94, 0xf5, 0xe9, 0xc7, 0xdd, 0xcb, 0xbf, 0x84, 0xe7, 0x6d, 0x4c, 0x75, 0xb4, 0x2f, 0xc3, 0xec, 0x6d, 0xeb, 0xe5, 0xdd, 0xa8, 0xbb, 0x19],
+        [0x93, 0x0a, 0x58, 0xa3, 0xda, 0x01, 0xe6, 0x5d, 0x62, 0xfa, 0x07, 0xe5, 0xac, 0xa0, 0xe3, 0x19, 0xdf, 0x0f, 0x60, 0x1a, 0x3d, 0x6e, 0xd7, 0x16, 0xb5, 0xf8, 0xf2, 0xd4, 0x28, 0x96, 0x85, 0x1d],
+        [0xac, 0x33, 0x5d, 0x0d, 0x6d, 0x42, 0xd2, 0xd3, 0x3b, 0xae, 0x66, 0x25, 0xea, 0x37, 0x44, 0x3e, 0x20, 0x7c, 0xec, 0x8b, 0x7c, 0xd4, 0xaf, 0x5d, 0xbc, 0x86, 0x3a, 0xd5, 0x16, 0x99, 0xaa, 0x07],
+    ],
+    [
+        [0x69, 0x0e, 0x4f, 0xc5, 0xe5, 0xcc, 0xda, 0x48, 0x9b, 0xfb, 0x98, 0x2c, 0xb6, 0x14, 0x0a, 0x42, 0x33, 0x9a, 0x30, 0xc7, 0x45, 0x43, 0x6a, 0x0d, 0x75, 0xa2, 0xf9, 0xf3, 0x82, 0x3f, 0x60, 0x14],
+        [0xb7, 0x1e, 0x96, 0x9d, 0xc5, 0x62, 0xa0, 0x1d, 0x9a, 0x5d, 0xd1, 0xb9, 0x93, 0x03, 0xc1, 0xe5, 0x8b, 0x0b, 0x77, 0xde, 0x91, 0xb7, 0xd1, 0x3c, 0x6d, 0xc2, 0x47, 0x07, 0x2d, 0xe2, 0x64, 0x1b],
+        [0x05, 0x7f, 0xb9, 0xa3, 0x88, 0x96, 0x92, 0x21, 0x88, 0x10, 0x8d, 0xab, 0xc5, 0xc7, 0xa5, 0x8a, 0x2a, 0x65, 0x50, 0xe7, 0x91, 0x81, 0x22, 0xa3, 0xd9, 0x9d, 0x72, 0xfa, 0x06, 0x6d, 0x58, 0x21],
+        [0xa0, 0x6d, 0x1c, 0x28, 0xfb, 0x02, 0x00, 0xf5, 0x03, 0x37, 0xda, 0xaf, 0x8d, 0x18, 0xf1, 0x38, 0x8d, 0x15, 0x50, 0xd7, 0x44, 0x1f, 0x64, 0x7d, 0xeb, 0xef, 0xd8, 0x16, 0xa7, 0x2d, 0x9d, 0x28],
+    ],
+    [
+        [0x28, 0x57, 0x5f, 0x1e, 0xc0, 0xa1, 0x49, 0x0c, 0xb7, 0x94, 0xe1, 0xe3, 0x40, 0xfa, 0x57, 0x0e, 0xfd, 0xc9, 0x8c, 0x92, 0x91, 0x63, 0x11, 0xec, 0x9a, 0x27, 0x90, 0xae, 0x76, 0x27, 0xe9, 0x2a],
+        [0x8c, 0x93, 0xe5, 0x1c, 0xdd, 0xaf, 0x28, 0x65, 0x77, 0x4b, 0xc5, 0xcd, 0xe1, 0x6f, 0x37, 0xa8, 0x46, 0x1d, 0xe0, 0xfb, 0xb5, 0x41, 0x53, 0xb3, 0x8c, 0x96, 0xe2, 0x23, 0xf6, 0xbb, 0x53, 0x11],
+        [0x93, 0xe0, 0x2a, 0xb5, 0xf1, 0xe9, 0xfa, 0xe4, 0xed, 0x2f, 0x1d, 0xa6, 0xbb, 0x9b, 0x82, 0x2a, 0xe3, 0x8a, 0x5c, 0x31, 0x76, 0x4c, 0xb8, 0x76, 0xdd, 0x04, 0x32, 0x26, 0x7b, 0x6d, 0x1c, 0x00],
+        [0xb8, 0xa6, 0x2e, 0x0d, 0x91, 0x7b, 0x24, 0x62, 0xd0, 0xc5, 0x51, 0x0e, 0x4f, 0x4b, 0xdf, 0x3c, 0xb0, 0x96, 0xf1, 0xaa, 0x69, 0x45, 0xd0, 0x19, 0x3e, 0x7d, 0x19, 0x4f, 0x5f, 0x65, 0x57, 0x1c],
+    ],
+    [
+        [0xff, 0xd6, 0x4c, 0xfb, 0x3c, 0x74, 0x15, 0xec, 0xe8, 0xe3, 0x3d, 0x33, 0x69, 0xd7, 0xfa, 0xd2, 0x19, 0x42, 0xec, 0xa7, 0xba, 0x37, 0x1f, 0xc1, 0x85, 0x96, 0xd6, 0x65, 0xe3, 0xb4, 0xa1, 0x29],
+        [0xc3, 0x4e, 0xba, 0x5c, 0x7e, 0xa1, 0x85, 0x42, 0xaa, 0x91, 0x01, 0x16, 0x0c, 0xe8, 0x82, 0xfa, 0xc8, 0xc6, 0xbb, 0xbd, 0xef, 0xc6, 0xd9, 0x09, 0xbd, 0x72, 0x62, 0xce, 0x95, 0xbf, 0xa3, 0x24],
+        [0xf9, 0x13, 0x60, 0x36, 0x91, 0xe6, 0x76, 0xe2, 0x47, 0x2c, 0x63, 0x71, 0x05, 0xb4, 0xc5, 0xe5, 0x43, 0x2c, 0x74, 0xe3, 0xb5, 0x55, 0xa9, 0xdf, 0xa0, 0x7e, 0xcc, 0x01, 0xf8, 0x4b, 0x6c, 0x14],
+        [0xa9, 0x9d, 0x1c, 0x61, 0x4a, 0x4e, 0x86, 0x36, 0xbc, 0xbb, 0x8e, 0x64, 0x86, 0x92, 0xff, 0x02, 0x6a, 0x68, 0x12, 0x8e, 0xc7, 0x6c, 0x03, 0xc4, 0xaa, 0x40, 0xf8, 0xda, 0x67, 0x30, 0xe3, 0x1d],
+    ],
+    [
+        [0x04, 0x0f, 0x5a, 0xb8, 0x77, 0x94, 0x8f, 0x67, 0x7a, 0x90, 0xe3, 0xbe, 0xfb, 0xfc, 0xb4, 0xc0, 0x23, 0x9e, 0x17, 0x81, 0xfc, 0x2e, 0x89, 0x2a, 0x6c, 0xe7, 0xa4, 0x45, 0x81, 0x6b, 0xbc, 0x0b],
+        [0xda, 0x47, 0xe4, 0x35, 0x5a, 0xaf, 0xc0, 0x55, 0x4c, 0xc2, 0x81, 0x8b, 0x33, 0x63, 0xdc, 0x41, 0x5a, 0x3c, 0xeb, 0xd3, 0x47, 0x55, 0x2f, 0x83, 0x00, 0x49, 0x0c, 0xa2, 0x61, 0x84, 0x96, 0x11],
+        [0x3b, 0x6b, 0x41, 0xae, 0xe7, 0xb0, 0xb1, 0x18, 0xed, 0x43, 0xcd, 0x50, 0x12, 0x83, 0xde, 0x1a, 0xdf, 0xd0, 0x68, 0x22, 0xad, 0xae, 0xa1, 0x05, 0x53, 0x95, 0x73, 0x9c, 0x66, 0xd8, 0xb4, 0x24],
+        [0x14, 0xb8, 0x26, 0x7b, 0x31, 0x27, 0x31, 0xfa, 0x8a, 0x74, 0xa8, 0x0a, 0x2a, 0x27, 0xae, 0xc9, 0x9d, 0x72, 0x80, 0x8c, 0xfc, 0xde, 0xc9, 0xf9, 0x6d, 0x14, 0x77, 0x43, 0x57, 0xca, 0x57, 0x0f],
+    ],
+    [
+        [0xcf, 0x29, 0xc6, 0x2f, 0xc1, 0x5f, 0x37, 0xc5, 0xaf, 0x0c, 0x7b, 0x6e, 0xc8, 0x07, 0x30, 0x6c, 0x36, 0xc1, 0x0c, 0xc2, 0xaf, 0xb5, 0xc6, 0x73, 0xcc, 0xbe, 0x24, 0xe1, 0x9c, 0x4d, 0x4e, 0x08],
+        [0x27, 0xb0, 0x52, 0x0f, 0xf0, 0xda, 0x5e, 0xd8, 0xc2, 0x73, 0x7d, 0x5e, 0x8f, 0x38, 0xcb, 0x65, 0xa2, 0xc5, 0xf8, 0xe7, 0x51, 0xce, 0x60, 0x0f, 0x03, 0x9a, 0x0e, 0xbd, 0xe5, 0x4e, 0x2b, 0x23],
+        [0x04, 0x99, 0xd9, 0xa1, 0x3c, 0xaf, 0x26, 0x8f, 0x2d, 0x74, 0x9c, 0x38, 0xe8, 0xa0, 0xc5, 0x93, 0xd7, 0x4f, 0xa1, 0x22, 0x60, 0x4d, 0x60, 0x09, 0x4f, 0xc3, 0x3f, 0x6d, 0x3b, 0xee, 0xf2, 0x2a],
+        [0x31, 0x6c, 0x6a, 0x11, 0x81, 0x24, 0xd4, 0xd0, 0x11, 0x49, 0x2e, 0x80, 0x21, 0x45, 0xa4, 0x94, 0xfe, 0xb4, 0x55, 0x6e, 0x28, 0xfa, 0x49, 0xc0, 0xe6, 0xfd, 0x30, 0x05, 0x2d, 0x6b, 0x0c, 0x1c],
+    ],
+    [
+        [0x7d, 0xd7, 0x0a, 0x8b, 0x36, 0xc2, 0x9a, 0xd2, 0xcd, 0x99, 0xa1, 0x96, 0x1f, 0x8d, 0x79, 0x61, 0xb9, 0x10, 0x6e, 0x60, 0xc8, 0xe0, 0xd1, 0x2e, 0x5a, 0xd0, 0x77, 0xcc, 0xfb, 0x2b, 0x06, 0x16],
+        [0xab, 0x7e, 0xd1, 0xf6, 0x1b, 0xf3, 0x8f, 0x93, 0x30, 0x49, 0x07, 0x8f, 0x88, 0x09, 0x0d, 0xb8, 0xa1, 0x22, 0x47, 0x00, 0xea, 0xdf, 0x7a, 0xe1, 0x82, 0x97, 0xca, 0x9b, 0x9e, 0xd8, 0xa5, 0x36],
+        [0xe6, 0xa1, 0x0d, 0x3e, 0xfb, 0x7c, 0x74, 0x87, 0xd4, 0xfe, 0x71, 0x58, 0x85, 0xcc, 0x2a, 0x00, 0x14, 0x78, 0xde, 0x2e, 0x5f, 0xcc, 0xd5, 0x82, 0x4f, 0xbc, 0x38, 0xdd, 0x72, 0xb7, 0x1f, 0x02],
+        [0x42, 0xa0, 0x65, 0xe7, 0xc4, 0x3e, 0xc2, 0xa2, 0x98, 0x03, 0xe3, 0xf4, 0x46, 0x26, 0x41, 0x4f, 0x50, 0xb3, 0xc4, 0xf2, 0x12, 0x56, 0x6c, 0xaf, 0x81, 0x73, 0xd1, 0x97, 0x1e, 0xb6, 0x90, 0x3d],
+    ],
+    [
+        [0xfd, 0x61, 0x29, 0x97, 0xd0, 0xb5, 0xf2, 0xd2, 0xa3, 0x5e, 0x88, 0x21, 0xe2, 0xda, 0xed, 0xe2, 0x45, 0xaa, 0x7b, 0xc4, 0x74, 0x8c, 0x43, 0xab, 0xde, 0x6d, 0x6f, 0xe1, 0x64, 0xa9, 0x2e, 0x19],
+        [0xd6, 0x4b, 0x4b, 0x2b, 0x3a, 0x6a, 0xb6, 0x43, 0x33, 0xb1, 0x4f, 0x65, 0xe0, 0x1c, 0x0b, 0x2d, 0x8d, 0x98, 0x3d, 0xa4, 0x8b, 0xd2, 0xc8, 0xb5, 0x4c, 0xc6, 0xc1, 0x04, 0x48, 0x79, 0x4e, 0x25],
+        [0xfd, 0x35, 0xbf, 0x33, 0x47, 0x00, 0x7d, 0xf4, 0x1a, 0x65, 0x01, 0x80, 0x19, 0xce, 0xf2, 0x7c, 0x4a, 0x5e, 0xf7, 0x76, 0x09, 0x2c, 0xa4, 0x28, 0x82, 0x09, 0x0c, 0xd3, 0xbe, 0xaf, 0xeb, 0x1b],
+        [0x76, 0x86, 0xca, 0x7b, 0xe1, 0x42, 0x3f, 0x05, 0xbe, 0xf1, 0x9a, 0x06, 0x56, 0x22, 0xac, 0x80, 0xe0, 0x0a, 0x0a, 0x95, 0x34, 0xb6, 0x40, 0x6b, 0x1e, 0x16, 0x56, 0xf1, 0x1c, 0x7f, 0x19, 0x3b],
+    ],
+    [
+        [0xbf, 0xf8, 0xe0, 0xe9, 0x62, 0xbe, 0x48, 0x13, 0x3e, 0x93, 0x4e, 0xd0, 0xe2, 0x40, 0x2e, 0x4b, 0x56, 0x6e, 0x64, 0x05, 0xf6, 0x1a, 0x1f, 0x16, 0xb7, 0x7c, 0xd2, 0x80, 0x02, 0xf2, 0xf1, 0x0a],
+        [0x2c, 0x00, 0x0a, 0x3a, 0xa2, 0x74, 0xfe, 0x5e, 0x0b, 0x4b, 0xa6, 0xca, 0x3d, 0xb7, 0xda, 0x40, 0x4f, 0xa0, 0x40, 0xef, 0xe6, 0x6f, 0xfe, 0xdb, 0xfa, 0x23, 0x60, 0x72, 0x07, 0xe1, 0x7c, 0x2c],
+        [0x1e, 0x0f, 0xaf, 0xcd, 0x30, 0x5d, 0xbf, 0x63, 0x0c, 0x8d, 0x09, 0xfa, 0xd0, 0x39, 0xbf, 0x13, 0xc7, 0x30, 0xb8, 0x3b, 0xa7, 0xbc, 0x26, 0xb5, 0x0f, 0xac, 0x92, 0x5b, 0xd7, 0xd6, 0xdf, 0x36],
+        [0xba, 0xfa, 0x3d, 0x21, 0xe0, 0xf5, 0x4d, 0x54, 0x82, 0x3f, 0x31, 0x4b, 0x3a, 0x23, 0x15, 0x13, 0x48, 0xe1, 0x18, 0xd4, 0x2b, 0x0a, 0x8e, 0xb1, 0x2e, 0x91, 0x93, 0x00, 0xf7, 0x14, 0xc5, 0x22],
+    ],
+    [
+        [0xdc, 0x41, 0x30, 0x21, 0x33, 0x1e, 0x2e, 0x0d, 0x94, 0x33, 0x39, 0x9b, 0xcc, 0x17, 0xc3, 0xf3, 0xf0, 0x5f, 0x63, 0x0d, 0x86, 0x11, 0x77, 0x01, 0x70, 0x81, 0xe8, 0x32, 0xa8, 0xaf, 0x57, 0x09],
+        [0x8b, 0x0c, 0x75, 0x42, 0xeb, 0xd1, 0x4a, 0xfc, 0x25, 0xff, 0x61, 0x08, 0x6b, 0x1a, 0xb1, 0x45, 0xdf, 0xe8, 0x2a, 0x54, 0x27, 0x99, 0xe1, 0x52, 0xbd, 0xd8, 0xe6, 0x1a, 0x55, 0x1b, 0x32, 0x16],
+        [0x4d, 0xac, 0xcb, 0xb3, 0xa0, 0x43, 0xc6, 0x1f, 0x31, 0x42, 0xec, 0x01, 0x28, 0x5e, 0x0d, 0x9d, 0xf4, 0x6a, 0x05, 0xa5, 0x81, 0x4e, 0x9d, 0x78, 0xd0, 0x94, 0x61, 0x24, 0x56, 0x78, 0x97, 0x08],
+        [0x83, 0x58, 0xf5, 0x1f, 0x90, 0x4d, 0x89, 0x02, 0x22, 0xe5, 0xae, 0x40, 0x93, 0x5a, 0x2b, 0xec, 0x89, 0x0e, 0x77, 0xd1, 0x08, 0x9c, 0x87, 0x05, 0xf9, 0xab, 0x3e, 0x7a, 0xa4, 0xf6, 0x6c, 0x3f],
+    ],
+    [
+        [0xef, 0x6b, 0x95, 0x94, 0x8b, 0x88, 0xbf, 0x9c, 0x7d, 0x5b, 0xd7, 0xf4, 0xc0, 0xc6, 0xcf, 0x5f, 0x08, 0xee, 0xa0, 0x5b, 0x26, 0x4d, 0xde, 0xdb, 0x49, 0x17, 0x81, 0x9d, 0x20, 0xad, 0x29, 0x2f],
+        [0x36, 0x3c, 0x8f, 0xce, 0x7c, 0x23, 0xb6, 0xbf, 0xb8, 0x18, 0x00, 0xcc, 0xd1, 0x9c, 0x71, 0x53, 0x50, 0x30, 0x53, 0x5d, 0x34, 0x58, 0xd3, 0xfa, 0x8f, 0xc3, 0xff, 0x12, 0x46, 0x2c, 0x9d, 0x0c],
+        [0x39, 0x3e, 0xa6, 0x18, 0x23, 0x30, 0xcd, 0x31, 0x49, 0x29, 0xb0, 0x29, 0xdf, 0x31, 0x6c, 0x6d, 0x05, 0x53, 0x41, 0x57, 0x86, 0x8d, 0xef, 0x9f, 0xff, 0xa2, 0x17, 0x47, 0x08, 0x55, 0xd9, 0x14],
+        [0x88, 0x45, 0x56, 0x25, 0xf2, 0x72, 0x47, 0x62, 0x8c, 0x0c, 0xd2, 0x8f, 0x7e, 0xd1, 0xf2, 0xa2, 0x23, 0xbc, 0xba, 0x3d, 0x8a, 0xf7, 0x5c, 0x64, 0x97, 0x57, 0x73, 0x1f, 0x77, 0xa7, 0x7c, 0x39],
+    ],
+    [
+        [0x59, 0xfa, 0xc0, 0xff, 0x74, 0x93, 0xb5, 0xf3, 0x7c, 0x0a, 0xcb, 0xed, 0x97, 0x83, 0x3c, 0x78, 0xec, 0x69, 0x0e, 0x25, 0xb5, 0x0e, 0xce, 0xb3, 0x04, 0x43, 0x62, 0xbe, 0xe3, 0xa4, 0xf5, 0x3e],
+        [0xfe, 0x66, 0x0e, 0xe6, 0xd4, 0x23, 0xac, 0xb3, 0x45, 0xbc, 0x5f, 0xcb, 0x22, 0x7a, 0x34, 0x5f, 0xe0, 0x7c, 0x23, 0x58, 0x14, 0xac, 0x2c, 0x54, 0x8b, 0x5e, 0x0a, 0x7c, 0xaf, 0x94, 0xcc, 0x11],
+        [0xb6, 0x85, 0x9b, 0x67, 0x2a, 0xa3, 0xe5, 0xf6, 0xc7, 0x71, 0xc6, 0x13, 0xa0, 0xa2, 0x66, 0xdd, 0x16, 0xb9, 0xf3, 0xa9, 0x8a, 0x74, 0xb5, 0x0b, 0x17, 0x38, 0xd9, 0x03, 0x2f, 0xdb, 0x1a, 0x22],
+        [0x3c, 0x65, 0xc0, 0x6e, 0xae, 0x40, 0xa1, 0xca, 0xf8, 0x38, 0x8f, 0x81, 0x1a, 0x2a, 0x6e, 0x45, 0x9d, 0x36, 0xf8, 0xb6, 0x8b, 0xd7, 0xc7, 0x65, 0xe3, 0xc5, 0x04, 0xb5, 0x03, 0xd1, 0x45, 0x1d],
+    ],
+    [
+        [0x87, 0xd6, 0x02, 0x61, 0x57, 0xc0, 0xcb, 0x57, 0xd6, 0xf3, 0xdf, 0xdf, 0x60, 0xbd, 0x52, 0x65, 0xa2, 0x14, 0xf2, 0x48, 0xca, 0xbe, 0xc2, 0x71, 0x65, 0xb2, 0x08, 0x02, 0x9f, 0x31, 0x40, 0x36],
+        [0x61, 0xd6, 0x92, 0x4d, 0x13, 0x0b, 0xbc, 0xf4, 0x81, 0x8f, 0x89, 0x17, 0x53, 0x76, 0x9e, 0xfa, 0x2e, 0x06, 0xf6, 0xff, 0xa5, 0xda, 0x02, 0x8b, 0x44, 0x80, 0xdc, 0x40, 0x1b, 0x5b, 0x24, 0x32],
+        [0x4b, 0xce, 0x72, 0x9b, 0xf8, 0xd5, 0x53, 0x4d, 0xb8, 0xdc, 0x47, 0x64, 0x4f, 0x37, 0xe7, 0xad, 0x85, 0x2b, 0x04, 0x49, 0x15, 0x4e, 0x2b, 0xd3, 0x4e, 0x68, 0x90, 0xf9, 0x11, 0xf5, 0xcd, 0x2a],
+        [0x73, 0x6d, 0xc2, 0x4d, 0x40, 0x85, 0x31, 0x06, 0x2d, 0x01, 0x38, 0x11, 0x19, 0x77, 0x61, 0x62, 0x16, 0x82, 0xf3, 0x97, 0x21, 0x04, 0x81, 0x02, 0xcd, 0x1b, 0xaf, 0xe0, 0x33, 0x5f, 0xd5, 0x16],
+    ],
+    [
+        [0x57, 0x32, 0xcb, 0x5e, 0x40, 0xf5, 0x09, 0xcd, 0xab, 0x02, 0x04, 0x54, 0xc4, 0xf2, 0x20, 0x2d, 0x8f, 0x53, 0xc8, 0x50, 0x7a, 0xb1, 0x13, 0x92, 0x31, 0x65, 0xaa, 0x6e, 0xf2, 0x61, 0x6c, 0x1e],
+        [0xa1, 0x4c, 0x75, 0xdb, 0x61, 0x1b, 0x55, 0x0d, 0x07, 0xb3, 0xdd, 0xe7, 0x10, 0xc1, 0xc3, 0xa3, 0xff, 0x7f, 0x23, 0xef, 0x0f, 0x46, 0xf4, 0xd0, 0xbb, 0xde, 0x7c, 0xe6, 0x46, 0x6c, 0x18, 0x07],
+        [0x03, 0xb8, 0x3c, 0x0d, 0x63, 0xc8, 0x1d, 0x80, 0x09, 0x65, 0xc3, 0xf9, 0x0e, 0x4a, 0xbd, 0x07, 0xdc, 0xf0, 0x14, 0x12, 0xc9, 0xe9, 0xf8, 0x46, 0xd2, 0xf4, 0xb4, 0xcf, 0x43, 0x4b, 0xd7, 0x3c],
+        [0x8c, 0xc3, 0x61, 0xf5, 0xa6, 0xcd, 0x59, 0x4b, 0x87, 0x73, 0x24, 0xf5, 0x8e, 0x1b, 0x9f, 0x2d, 0x1b, 0x6f, 0x7e, 0x31, 0xdd, 0xf4, 0x3d, 0x5a, 0x24, 0x5d, 0xb9, 0x58, 0x0b, 0x90, 0x92, 0x33],
+    ],
+    [
+        [0xeb, 0xd4, 0x9f, 0x76, 0x04, 0x5d, 0x00, 0xb3, 0x12, 0xa0, 0x5c, 0x30, 0x4c, 0x03, 0x63, 0x7d, 0x84, 0x8d, 0xf0, 0x4d, 0x45, 0x79, 0x7d, 0x8b, 0x90, 0x09, 0xbf, 0x40, 0x30, 0x34, 0x0e, 0x13],
+        [0xc2, 0xca, 0xb0, 0xa1, 0xf1, 0xb1, 0x5f, 0x03, 0x29, 0x9a, 0xc3, 0x4a, 0x02, 0x2c, 0x7a, 0x2f, 0xd9, 0xf0, 0x97, 0xfd, 0x94, 0x03, 0x08, 0x33, 0xfe, 0x87, 0x5a, 0x4b, 0x91, 0x83, 0x00, 0x0e],
+        [0x47, 0x92, 0x85, 0x74, 0xe6, 0xdf, 0xdf, 0x91, 0xb0, 0x8e, 0xca, 0x8e, 0xf0, 0x16, 0xf3, 0x14, 0xce, 0xa0, 0x3c, 0x5a, 0x49, 0x4f, 0xd6, 0x58, 0xc4, 0x0e, 0x3f, 0x35, 0xbf, 0xaf, 0x93, 0x1f],
+        [0x72, 0x58, 0xd5, 0xdc, 0x09, 0xdb, 0x1b, 0x6f, 0x94, 0xaf, 0x48, 0x0a, 0xa5, 0x5b, 0x1b, 0x1e, 0x8f, 0x24, 0xe1, 0x3b, 0xe4, 0x6f, 0xc6, 0x2e, 0x1f, 0x4c, 0xb6, 0x87, 0x1d, 0x25, 0xd1, 0x39],
+    ],
+    [
+        [0x02, 0x89, 0x1c, 0xe5, 0xeb, 0x50, 0x4f, 0x48, 0x1e, 0xdb, 0xca, 0x2e, 0x49, 0xfd, 0x9d, 0x6b, 0xdc, 0x58, 0x83, 0x1e, 0x98, 0x8b, 0x4e, 0x86, 0x81, 0x0e, 0xdf, 0x0e, 0x27, 0xf6, 0x7f, 0x00],
+        [0x92, 0xb7, 0x4e, 0x18, 0x97, 0xb5, 0xba, 0x01, 0x98, 0xdc, 0x22, 0xc6, 0xb5, 0xe8, 0xf5, 0xd6, 0x78, 0xb2, 0x1d, 0x44, 0xb8, 0x53, 0xf3, 0x51, 0x9e, 0xce, 0xb5, 0xc1, 0xce, 0x21, 0x78, 0x06],
+        [0xb2, 0xdf, 0x2b, 0xcf, 0xd3, 0xb7, 0x88, 0xaa, 0xe0, 0xfd, 0xd4, 0x3d, 0xe9, 0xbc, 0x0a, 0x84, 0x06, 0x54, 0xca, 0x46, 0x32, 0xfe, 0x0b, 0x5e, 0x98, 0x27, 0x50, 0x05, 0xe4, 0xd5, 0xc1, 0x00],
+        [0xab, 0xff, 0xc7, 0xa3, 0x78, 0x6c, 0xaa, 0xc6, 0x2b, 0xd2, 0x35, 0x27, 0xaf, 0x45, 0x4f, 0x20, 0xba, 0x9c, 0xce, 0x09, 0x3b, 0x86, 0x75, 0x02, 0x34, 0x4c, 0xda, 0x9f, 0xf8, 0xbd, 0xc6, 0x3b],
+    ],
+    [
+        [0x0b, 0xcc, 0xa5, 0x12, 0x64, 0xe8, 0x3e, 0xf4, 0x9e, 0x7b, 0x58, 0xf0, 0x2a, 0x13, 0x7b, 0x40, 0x62, 0xba, 0x37, 0xd5, 0xb8, 0x9b, 0x10, 0x4b, 0x9e, 0x4e, 0x11, 0xe4, 0x7d, 0xbc, 0x6e, 0x2a],
+        [0x4f, 0x6b, 0x98, 0xd1, 0x63, 0x8c, 0x3f, 0x4d, 0xe6, 0xd2, 0x21, 0xf4, 0x69, 0x05, 0x44, 0xfb, 0xac, 0x96, 0x63, 0xe0, 0x9b, 0x76, 0x21, 0xf1, 0x77, 0x5a, 0xd2, 0x96, 0x5b, 0xbb, 0x6f, 0x38],
+        [0x47, 0x20, 0x30, 0x94, 0xa9, 0xe5, 0x24, 0x76, 0x24, 0x4d, 0xb4, 0x01, 0x0b, 0xa8, 0xfa, 0x23, 0x0a, 0x74, 0x3e, 0x2e, 0xdb, 0x6b, 0xde, 0xc4, 0x6c, 0x38, 0x94, 0xe0, 0xc1, 0xb5, 0x8c, 0x09],
+        [0x29, 0x98, 0x6a, 0x95, 0xf8, 0x63, 0x2f, 0x09, 0x3f, 0x4c, 0x61, 0x69, 0xdb, 0x06, 0xfe, 0xe7, 0x17, 0xca, 0x9e, 0xf6, 0x7b, 0x30, 0x4d, 0x8d, 0xd8, 0x05, 0xf9, 0x41, 0x68, 0xae, 0x4c, 0x39],
+    ],
+    [
+        [0x4b, 0xa2, 0xb3, 0xf8, 0x98, 0xa9, 0x7d, 0xed, 0x13, 0xd7, 0x65, 0x7c, 0x55, 0xf4, 0xe9, 0x04, 0x02, 0x71, 0x9c, 0x05, 0xa5, 0x95, 0x61, 0xa3, 0x7b, 0x96, 0xdf, 0xc5, 0x11, 0xdd, 0x81, 0x01],
+        [0x52, 0x6b, 0xf7, 0x06, 0xc1, 0xa5, 0x72, 0xb8, 0x0e, 0xbd, 0xce, 0xca, 0x02, 0x96, 0xeb, 0x84, 0xb3, 0x45, 0xdb, 0xbe, 0x73, 0x42, 0x4a, 0xd9, 0xb9, 0xc9, 0x9d, 0x63, 0x46, 0xed, 0x51, 0x15],
+        [0xe2, 0x25, 0x1c, 0xf5, 0xaa, 0x00, 0xd5, 0xd0, 0x3d, 0x38, 0xa5, 0xc5, 0x99, 0xe2, 0x42, 0x95, 0x71, 0x12, 0x70, 0x4b, 0x9e, 0xf6, 0xef, 0x1b, 0x51, 0x6e, 0x2b, 0x49, 0x81, 0x00, 0xc2, 0x25],
+        [0xfb, 0x3b, 0x01, 0x2b, 0x36, 0x3f, 0x25, 0x1f, 0xe0, 0xba, 0x71, 0x59, 0x34, 0xf2, 0x4a, 0x7f, 0x67, 0xfe, 0x68, 0x49, 0x5e, 0x08, 0x4b, 0x6c, 0x3c, 0x61, 0x71, 0x8c, 0xa2, 0xa7, 0x1c, 0x37],
+    ],
+];
+
+/// Returns the generator.
+pub fn generator() -> pallas::Affine {
+    pallas::Affine::from_xy(
+        pallas::Base::from_bytes(&GENERATOR.0).unwrap(),
+        pallas::Base::from_bytes(&GENERATOR.1).unwrap(),
+    )
+    .unwrap()
+}
+
+/// The fixed base `test_base`.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TestBase {
+    /// Multiplication by a full-width scalar or a base field element
+    FullWidth,
+    /// Multiplication by a 9-bit short signed scalar
+    Short9,
+    /// Multiplication by a 64-bit short signed scalar
+    Short,
+}
+
+impl FixedPoints<pallas::Affine, WINDOW_SIZE> for TestBase {
+    fn generator(&self) -> pallas::Affine {
+        generator()
+    }
+
+    fn u(&self) -> Vec<Vec<[u8; 32]>> {
+        match self {
+            TestBase::FullWidth => U.iter().map(|us| us.to_vec()).collect(),
+            TestBase::Short9 => U_SHORT_9.iter().map(|us| us.to_vec()).collect(),
+            TestBase::Short => U_SHORT.iter().map(|us| us.to_vec()).collect(),
+        }
+    }
+
+    fn z(&self) -> Vec<u64> {
+        match self {
+            TestBase::FullWidth => Z.to_vec(),
+            TestBase::Short9 => Z_SHORT_9.to_vec(),
+            TestBase::Short => Z_SHORT.to_vec(),
+        }
+    }
+
+    fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+        let num_windows = match self {
+            TestBase::FullWidth => NUM_WINDOWS,
+            TestBase::Short9 => NUM_WINDOWS_SHORT_9,
+            TestBase::Short => NUM_WINDOWS_SHORT,
+        };
+        compute_lagrange_coeffs::<_, WINDOW_SIZE>(generator(), num_windows)
+    }
+
+    fn u_short<const N: usize>(&self) -> Vec<Vec<[u8; 32]>> {
+        match N {
+            9 => U_SHORT_9.iter().map(|us| us.to_vec()).collect(),
+            64 => U_SHORT.iter().map(|us| us.to_vec()).collect(),
+            _ => panic!("no u-values for {}-bit short scalars", N),
+        }
+    }
+
+    fn z_short<const N: usize>(&self) -> Vec<u64> {
+        match N {
+            9 => Z_SHORT_9.to_vec(),
+            64 => Z_SHORT.to_vec(),
+            _ => panic!("no z-values for {}-bit short scalars", N),
+        }
+    }
+
+    fn lagrange_coeffs_short<const N: usize>(&self) -> Vec<Vec<pallas::Base>> {
+        let num_windows = match N {
+            9 => NUM_WINDOWS_SHORT_9,
+            64 => NUM_WINDOWS_SHORT,
+            _ => panic!("no Lagrange coefficients for {}-bit short scalars", N),
+        };
+        compute_lagrange_coeffs::<_, WINDOW_SIZE>(generator(), num_windows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use halo2_ecc::chip::mul_fixed_from_tables;
+
+    #[test]
+    fn tables_reproduce_multiples() {
+        let scalars = [
+            pallas::Scalar::from_u64(1),
+            -pallas::Scalar::from_u64(1),
+            pallas::Scalar::rand(),
+        ];
+        for scalar in scalars.iter() {
+            assert_eq!(
+                mul_fixed_from_tables::<_, WINDOW_SIZE>(&TestBase::FullWidth, *scalar),
+                Some(generator() * *scalar)
+            );
+        }
+
+        // 1 and 2^9 - 1
+        let one = pallas::Scalar::from_u64(1);
+        let max = (0..9).fold(pallas::Scalar::from_u64(0), |acc, _| acc + acc + one);
+        for scalar in [one, max].iter() {
+            assert_eq!(
+                mul_fixed_from_tables::<_, WINDOW_SIZE>(&TestBase::Short9, *scalar),
+                Some(generator() * *scalar)
+            );
+        }
+
+        // 1 and 2^64 - 1
+        let one = pallas::Scalar::from_u64(1);
+        let max = (0..64).fold(pallas::Scalar::from_u64(0), |acc, _| acc + acc + one);
+        for scalar in [one, max].iter() {
+            assert_eq!(
+                mul_fixed_from_tables::<_, WINDOW_SIZE>(&TestBase::Short, *scalar),
+                Some(generator() * *scalar)
+            );
+        }
+    }
+}
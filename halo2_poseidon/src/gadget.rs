@@ -2,6 +2,7 @@
 
 use std::array;
 use std::fmt;
+use std::iter;
 
 use halo2::{
     arithmetic::FieldExt,
@@ -9,12 +10,16 @@ use halo2::{
     plonk::Error,
 };
 
-use super::primitive::{ConstantLength, Domain, Spec, Sponge, SpongeState, State};
+use super::primitive::{ConstantLength, Domain, Sponge, SpongeState, State, VariableLength};
 
 /// The set of circuit instructions required to use the Poseidon permutation.
-pub trait PoseidonInstructions<F: FieldExt, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>:
-    Chip<F>
-{
+///
+/// `S` is the specification of the permutation that the chip implements, such as a
+/// [`Spec`] or a [`Poseidon2Spec`].
+///
+/// [`Spec`]: crate::primitive::Spec
+/// [`Poseidon2Spec`]: crate::primitive::poseidon2::Poseidon2Spec
+pub trait PoseidonInstructions<F: FieldExt, S, const T: usize, const RATE: usize>: Chip<F> {
     /// Variable representing the word over which the Poseidon permutation operates.
     type Word: Copy + fmt::Debug;
 
@@ -29,12 +34,8 @@ pub trait PoseidonInstructions<F: FieldExt, S: Spec<F, T, RATE>, const T: usize,
 /// The set of circuit instructions required to use the [`Duplex`] and [`Hash`] gadgets.
 ///
 /// [`Hash`]: self::Hash
-pub trait PoseidonDuplexInstructions<
-    F: FieldExt,
-    S: Spec<F, T, RATE>,
-    const T: usize,
-    const RATE: usize,
->: PoseidonInstructions<F, S, T, RATE>
+pub trait PoseidonDuplexInstructions<F: FieldExt, S, const T: usize, const RATE: usize>:
+    PoseidonInstructions<F, S, T, RATE>
 {
     /// Returns the initial empty state for the given domain.
     fn initial_state(
@@ -56,11 +57,43 @@ pub trait PoseidonDuplexInstructions<
     fn get_output(state: &State<Self::Word, T>) -> SpongeState<Self::Word, RATE>;
 }
 
+/// The set of circuit instructions required to use the [`Hash`] gadget with the
+/// [`VariableLength`] domain.
+///
+/// [`Hash`]: self::Hash
+pub trait PoseidonVariableLengthInstructions<F: FieldExt, S, const T: usize, const RATE: usize>:
+    PoseidonDuplexInstructions<F, S, T, RATE>
+{
+    /// Pads the first `length` words of `message` with a single one followed by zeros,
+    /// to `(message.len() / RATE + 1) * RATE` words.
+    ///
+    /// Returns the padded message, along with a flag for each padded word that is one
+    /// for the word at index `length` and zero otherwise. The constraints cannot be
+    /// satisfied if `length` is greater than `message.len()`.
+    fn pad_variable_length(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        message: &[Self::Word],
+        length: &Self::Word,
+    ) -> Result<(Vec<Self::Word>, Vec<Self::Word>), Error>;
+
+    /// Returns the sum of `flags[i] * words[i]`.
+    ///
+    /// The flags are assumed to be constrained elsewhere, as they are by
+    /// [`PoseidonVariableLengthInstructions::pad_variable_length`].
+    fn inner_product(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        flags: &[Self::Word],
+        words: &[Self::Word],
+    ) -> Result<Self::Word, Error>;
+}
+
 /// A word over which the Poseidon permutation operates.
 pub struct Word<
     F: FieldExt,
     PoseidonChip: PoseidonInstructions<F, S, T, RATE>,
-    S: Spec<F, T, RATE>,
+    S,
     const T: usize,
     const RATE: usize,
 > {
@@ -70,7 +103,7 @@ pub struct Word<
 impl<
         F: FieldExt,
         PoseidonChip: PoseidonInstructions<F, S, T, RATE>,
-        S: Spec<F, T, RATE>,
+        S,
         const T: usize,
         const RATE: usize,
     > Word<F, PoseidonChip, S, T, RATE>
@@ -87,7 +120,7 @@ impl<
 fn poseidon_duplex<
     F: FieldExt,
     PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-    S: Spec<F, T, RATE>,
+    S,
     D: Domain<F, T, RATE>,
     const T: usize,
     const RATE: usize,
@@ -107,7 +140,7 @@ fn poseidon_duplex<
 pub struct Duplex<
     F: FieldExt,
     PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-    S: Spec<F, T, RATE>,
+    S,
     D: Domain<F, T, RATE>,
     const T: usize,
     const RATE: usize,
@@ -121,7 +154,7 @@ pub struct Duplex<
 impl<
         F: FieldExt,
         PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-        S: Spec<F, T, RATE>,
+        S,
         D: Domain<F, T, RATE>,
         const T: usize,
         const RATE: usize,
@@ -211,7 +244,7 @@ impl<
 pub struct Hash<
     F: FieldExt,
     PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-    S: Spec<F, T, RATE>,
+    S,
     D: Domain<F, T, RATE>,
     const T: usize,
     const RATE: usize,
@@ -222,7 +255,7 @@ pub struct Hash<
 impl<
         F: FieldExt,
         PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-        S: Spec<F, T, RATE>,
+        S,
         D: Domain<F, T, RATE>,
         const T: usize,
         const RATE: usize,
@@ -237,7 +270,7 @@ impl<
 impl<
         F: FieldExt,
         PoseidonChip: PoseidonDuplexInstructions<F, S, T, RATE>,
-        S: Spec<F, T, RATE>,
+        S,
         const T: usize,
         const RATE: usize,
         const L: usize,
@@ -256,3 +289,55 @@ impl<
         self.duplex.squeeze(layouter.namespace(|| "squeeze"))
     }
 }
+
+impl<
+        F: FieldExt,
+        PoseidonChip: PoseidonVariableLengthInstructions<F, S, T, RATE>,
+        S,
+        const T: usize,
+        const RATE: usize,
+    > Hash<F, PoseidonChip, S, VariableLength, T, RATE>
+{
+    /// Hashes the first `length` words of the given input.
+    ///
+    /// The cost of the hash depends only on `message.len()`, which bounds `length`.
+    pub fn hash(
+        mut self,
+        mut layouter: impl Layouter<F>,
+        message: Vec<Word<F, PoseidonChip, S, T, RATE>>,
+        length: Word<F, PoseidonChip, S, T, RATE>,
+    ) -> Result<Word<F, PoseidonChip, S, T, RATE>, Error> {
+        let duplex = &mut self.duplex;
+        let message: Vec<_> = message.iter().map(|word| word.inner).collect();
+        let (padded, flags) = duplex.chip.pad_variable_length(
+            &mut layouter.namespace(|| "pad"),
+            &message,
+            &length.inner,
+        )?;
+
+        // Absorb every block that a message of the maximum length could occupy,
+        // recording the output after each block against each of its words.
+        let mut outputs = Vec::with_capacity(padded.len());
+        for (i, block) in padded.chunks(RATE).enumerate() {
+            let mut input = [None; RATE];
+            for (entry, word) in input.iter_mut().zip(block.iter()) {
+                *entry = Some(*word);
+            }
+            let output = poseidon_duplex(
+                &duplex.chip,
+                layouter.namespace(|| format!("block_{}", i)),
+                &duplex.domain,
+                &mut duplex.state,
+                &input,
+            )?;
+            outputs.extend(iter::repeat(output[0].unwrap()).take(RATE));
+        }
+
+        // The flag of the first padding word selects the output of the final block
+        // of the padded message.
+        duplex
+            .chip
+            .inner_product(&mut layouter.namespace(|| "squeeze"), &flags, &outputs)
+            .map(|inner| Word { inner })
+    }
+}
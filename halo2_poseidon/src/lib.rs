@@ -2,5 +2,9 @@
 #[cfg(feature = "halo2")]
 pub mod gadget;
 #[cfg(feature = "halo2")]
+pub mod poseidon2;
+#[cfg(feature = "halo2")]
+pub mod pow5;
+#[cfg(feature = "halo2")]
 pub mod pow5t3;
 pub mod primitive;
@@ -0,0 +1,760 @@
+//! A chip implementing the Poseidon2 permutation.
+
+use std::convert::TryInto;
+use std::iter;
+
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+
+use super::gadget::{PoseidonDuplexInstructions, PoseidonInstructions};
+use super::pow5::{mds_mul, StateWord};
+use super::primitive::{
+    poseidon2::{external_matrix, internal_matrix, Poseidon2Spec},
+    Domain, Mds, SpongeState, State,
+};
+
+/// Configuration for a [`Poseidon2Chip`].
+#[derive(Clone, Debug)]
+pub struct Poseidon2Config<F: FieldExt, const WIDTH: usize, const RATE: usize> {
+    state: [Column<Advice>; WIDTH],
+    rc: [Column<Fixed>; WIDTH],
+    s_initial: Selector,
+    s_external: Selector,
+    s_internal: Selector,
+    s_pad_and_add: Selector,
+
+    half_full_rounds: usize,
+    partial_rounds: usize,
+    alpha: [u64; 4],
+    round_constants: Vec<[F; WIDTH]>,
+    m_ext: Mds<F, WIDTH>,
+    m_int: Mds<F, WIDTH>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Poseidon2Config<F, WIDTH, RATE> {
+    pub fn state(&self) -> [Column<Advice>; WIDTH] {
+        self.state
+    }
+}
+
+/// A Poseidon2 chip using an $x^5$ S-Box, with a width of `WIDTH` and a rate of `RATE`.
+///
+/// Each round of the permutation occupies a single row, as does the external matrix
+/// applied to the input. The capacity of the sponge is a single field element, so
+/// `RATE` must be `WIDTH - 1`.
+#[derive(Debug)]
+pub struct Poseidon2Chip<F: FieldExt, const WIDTH: usize, const RATE: usize> {
+    config: Poseidon2Config<F, WIDTH, RATE>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Poseidon2Chip<F, WIDTH, RATE> {
+    /// Configures this chip for use in a circuit.
+    ///
+    /// # Side-effects
+    ///
+    /// All columns in `state` and `rc` will be equality-enabled.
+    pub fn configure<S: Poseidon2Spec<F, WIDTH, RATE>>(
+        meta: &mut ConstraintSystem<F>,
+        spec: S,
+        state: [Column<Advice>; WIDTH],
+        rc: [Column<Fixed>; WIDTH],
+    ) -> Poseidon2Config<F, WIDTH, RATE> {
+        assert_eq!(RATE, WIDTH - 1);
+
+        // Generate constants for the Poseidon2 permutation.
+        assert!(S::full_rounds() & 1 == 0);
+        let half_full_rounds = S::full_rounds() / 2;
+        let partial_rounds = S::partial_rounds();
+        let round_constants = spec.constants();
+        let m_ext = external_matrix::<F, WIDTH>();
+        let m_int = internal_matrix(&spec.internal_diagonal());
+
+        // This allows state words to be initialized (by constraining them equal to fixed
+        // values), and used in a permutation from an arbitrary region. The round constant
+        // columns are empty outside of the permutation rounds, so we use them as "scratch
+        // space" for fixed values.
+        for column in iter::empty()
+            .chain(state.iter().cloned().map(|c| c.into()))
+            .chain(rc.iter().cloned().map(|c| c.into()))
+        {
+            meta.enable_equality(column);
+        }
+
+        let s_initial = meta.selector();
+        let s_external = meta.selector();
+        let s_internal = meta.selector();
+        let s_pad_and_add = meta.selector();
+
+        let alpha = [5, 0, 0, 0];
+        let pow_5 = |v: Expression<F>| {
+            let v2 = v.clone() * v.clone();
+            v2.clone() * v2 * v
+        };
+
+        meta.create_gate("initial linear layer", |meta| {
+            let cur: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let next: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+
+            let s_initial = meta.query_selector(s_initial);
+
+            (0..WIDTH)
+                .map(|next_idx| {
+                    let expr = (0..WIDTH)
+                        .map(|idx| cur[idx].clone() * m_ext[next_idx][idx])
+                        .reduce(|acc, term| acc + term)
+                        .expect("WIDTH > 0");
+                    s_initial.clone() * (expr - next[next_idx].clone())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("external round", |meta| {
+            let cur: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let next: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+            let rcs: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_fixed(rc[idx], Rotation::cur()))
+                .collect();
+
+            let s_external = meta.query_selector(s_external);
+
+            (0..WIDTH)
+                .map(|next_idx| {
+                    let expr = (0..WIDTH)
+                        .map(|idx| {
+                            pow_5(cur[idx].clone() + rcs[idx].clone()) * m_ext[next_idx][idx]
+                        })
+                        .reduce(|acc, term| acc + term)
+                        .expect("WIDTH > 0");
+                    s_external.clone() * (expr - next[next_idx].clone())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("internal round", |meta| {
+            let cur: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let next: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+            let rc_0 = meta.query_fixed(rc[0], Rotation::cur());
+
+            let s_internal = meta.query_selector(s_internal);
+
+            // In an internal round, the round constant and S-box are only applied to the
+            // first state word.
+            let sbox_0 = pow_5(cur[0].clone() + rc_0);
+
+            (0..WIDTH)
+                .map(|next_idx| {
+                    let expr = (1..WIDTH).fold(sbox_0.clone() * m_int[next_idx][0], |acc, idx| {
+                        acc + cur[idx].clone() * m_int[next_idx][idx]
+                    });
+                    s_internal.clone() * (expr - next[next_idx].clone())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("pad-and-add", |meta| {
+            let initial_state: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::prev()))
+                .collect();
+            let input: Vec<_> = (0..RATE)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let output_state: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+
+            let s_pad_and_add = meta.query_selector(s_pad_and_add);
+
+            // We pad the input by storing the required padding in fixed columns and
+            // then constraining the corresponding input columns to be equal to it.
+            (0..RATE)
+                .map(|idx| {
+                    initial_state[idx].clone() + input[idx].clone() - output_state[idx].clone()
+                })
+                // The capacity element is never altered by the input.
+                .chain(iter::once(
+                    initial_state[RATE].clone() - output_state[RATE].clone(),
+                ))
+                .map(|expr| s_pad_and_add.clone() * expr)
+                .collect::<Vec<_>>()
+        });
+
+        Poseidon2Config {
+            state,
+            rc,
+            s_initial,
+            s_external,
+            s_internal,
+            s_pad_and_add,
+            half_full_rounds,
+            partial_rounds,
+            alpha,
+            round_constants,
+            m_ext,
+            m_int,
+        }
+    }
+
+    pub fn construct(config: Poseidon2Config<F, WIDTH, RATE>) -> Self {
+        Poseidon2Chip { config }
+    }
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Chip<F> for Poseidon2Chip<F, WIDTH, RATE> {
+    type Config = Poseidon2Config<F, WIDTH, RATE>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt, S: Poseidon2Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>
+    PoseidonInstructions<F, S, WIDTH, RATE> for Poseidon2Chip<F, WIDTH, RATE>
+{
+    type Word = StateWord<F>;
+
+    fn permute(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        initial_state: &State<Self::Word, WIDTH>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "permute state",
+            |mut region| {
+                // Load the initial state into this region.
+                let state = Poseidon2State::load(&mut region, config, initial_state)?;
+
+                let state = state.initial_linear_layer(&mut region, config)?;
+
+                let internal_rounds =
+                    config.half_full_rounds..(config.half_full_rounds + config.partial_rounds);
+                let rounds = 2 * config.half_full_rounds + config.partial_rounds;
+
+                let state = (0..rounds).fold(Ok(state), |res, r| {
+                    res.and_then(|state| {
+                        if internal_rounds.contains(&r) {
+                            state.internal_round(&mut region, config, r)
+                        } else {
+                            state.external_round(&mut region, config, r)
+                        }
+                    })
+                })?;
+
+                Ok(state.0)
+            },
+        )
+    }
+}
+
+impl<F: FieldExt, S: Poseidon2Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>
+    PoseidonDuplexInstructions<F, S, WIDTH, RATE> for Poseidon2Chip<F, WIDTH, RATE>
+{
+    fn initial_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        domain: &impl Domain<F, WIDTH, RATE>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || format!("initial state for domain {:?}", domain),
+            |mut region| {
+                let mut load_state_word = |i: usize, value: F| -> Result<_, Error> {
+                    let var = region.assign_advice_from_constant(
+                        || format!("state_{}", i),
+                        config.state[i],
+                        0,
+                        value,
+                    )?;
+                    Ok(StateWord::new(var, Some(value)))
+                };
+
+                // The rate portion of the state is zero, followed by the capacity element.
+                let state = (0..WIDTH)
+                    .map(|i| {
+                        let value = if i == RATE {
+                            domain.initial_capacity_element()
+                        } else {
+                            F::zero()
+                        };
+                        load_state_word(i, value)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(state.try_into().unwrap())
+            },
+        )
+    }
+
+    fn pad_and_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        domain: &impl Domain<F, WIDTH, RATE>,
+        initial_state: &State<Self::Word, WIDTH>,
+        input: &SpongeState<Self::Word, RATE>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || format!("pad-and-add for domain {:?}", domain),
+            |mut region| {
+                config.s_pad_and_add.enable(&mut region, 1)?;
+
+                // Load the initial state into this region.
+                let initial_state = Poseidon2State::load(&mut region, config, initial_state)?.0;
+
+                let padding_values = domain.padding();
+
+                // Load the input and padding into this region.
+                let mut load_input_word = |i: usize| -> Result<_, Error> {
+                    let (constraint_var, value) = match (input[i], padding_values[i]) {
+                        (Some(word), None) => (word.var, word.value),
+                        (None, Some(padding_value)) => {
+                            let padding_var = region.assign_fixed(
+                                || format!("load pad_{}", i),
+                                config.rc[i],
+                                1,
+                                || Ok(padding_value),
+                            )?;
+                            (padding_var, Some(padding_value))
+                        }
+                        _ => panic!("Input and padding don't match"),
+                    };
+                    let var = region.assign_advice(
+                        || format!("load input_{}", i),
+                        config.state[i],
+                        1,
+                        || value.ok_or(Error::SynthesisError),
+                    )?;
+                    region.constrain_equal(constraint_var, var)?;
+
+                    Ok(StateWord::new(var, value))
+                };
+                let input = (0..RATE)
+                    .map(&mut load_input_word)
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                // Constrain the output.
+                let mut constrain_output_word = |i: usize| -> Result<_, Error> {
+                    let value = initial_state[i].value.and_then(|initial_word| {
+                        input
+                            .get(i)
+                            .map(|word| word.value)
+                            // The capacity element is never altered by the input.
+                            .unwrap_or_else(|| Some(F::zero()))
+                            .map(|input_word| initial_word + input_word)
+                    });
+                    let var = region.assign_advice(
+                        || format!("load output_{}", i),
+                        config.state[i],
+                        2,
+                        || value.ok_or(Error::SynthesisError),
+                    )?;
+                    Ok(StateWord::new(var, value))
+                };
+
+                let output = (0..WIDTH)
+                    .map(&mut constrain_output_word)
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(output.try_into().unwrap())
+            },
+        )
+    }
+
+    fn get_output(state: &State<Self::Word, WIDTH>) -> SpongeState<Self::Word, RATE> {
+        let mut output = [None; RATE];
+        for (word, value) in output.iter_mut().zip(state.iter()) {
+            *word = Some(*value);
+        }
+        output
+    }
+}
+
+#[derive(Debug)]
+struct Poseidon2State<F: FieldExt, const WIDTH: usize>([StateWord<F>; WIDTH]);
+
+impl<F: FieldExt, const WIDTH: usize> Poseidon2State<F, WIDTH> {
+    fn initial_linear_layer<const RATE: usize>(
+        self,
+        region: &mut Region<F>,
+        config: &Poseidon2Config<F, WIDTH, RATE>,
+    ) -> Result<Self, Error> {
+        let p: Option<Vec<_>> = self.0.iter().map(|word| word.value).collect();
+
+        Self::round(
+            region,
+            config,
+            None,
+            config.s_initial,
+            mds_mul(&config.m_ext, p.as_deref()),
+        )
+    }
+
+    fn external_round<const RATE: usize>(
+        self,
+        region: &mut Region<F>,
+        config: &Poseidon2Config<F, WIDTH, RATE>,
+        round: usize,
+    ) -> Result<Self, Error> {
+        let r: Option<Vec<_>> = self
+            .0
+            .iter()
+            .zip(config.round_constants[round].iter())
+            .map(|(word, rc)| word.value.map(|v| (v + rc).pow(&config.alpha)))
+            .collect();
+
+        Self::round(
+            region,
+            config,
+            Some(round),
+            config.s_external,
+            mds_mul(&config.m_ext, r.as_deref()),
+        )
+    }
+
+    fn internal_round<const RATE: usize>(
+        self,
+        region: &mut Region<F>,
+        config: &Poseidon2Config<F, WIDTH, RATE>,
+        round: usize,
+    ) -> Result<Self, Error> {
+        // In an internal round, the round constant and S-box are only applied to the
+        // first state word.
+        let r: Option<Vec<_>> = self
+            .0
+            .iter()
+            .enumerate()
+            .map(|(i, word)| {
+                word.value.map(|v| {
+                    if i == 0 {
+                        (v + config.round_constants[round][0]).pow(&config.alpha)
+                    } else {
+                        v
+                    }
+                })
+            })
+            .collect();
+
+        Self::round(
+            region,
+            config,
+            Some(round),
+            config.s_internal,
+            mds_mul(&config.m_int, r.as_deref()),
+        )
+    }
+
+    fn load<const RATE: usize>(
+        region: &mut Region<F>,
+        config: &Poseidon2Config<F, WIDTH, RATE>,
+        initial_state: &State<StateWord<F>, WIDTH>,
+    ) -> Result<Self, Error> {
+        let mut load_state_word = |i: usize| -> Result<_, Error> {
+            let value = initial_state[i].value;
+            let var = region.assign_advice(
+                || format!("load state_{}", i),
+                config.state[i],
+                0,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            region.constrain_equal(initial_state[i].var, var)?;
+            Ok(StateWord::new(var, value))
+        };
+
+        let state = (0..WIDTH)
+            .map(&mut load_state_word)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Poseidon2State(state.try_into().unwrap()))
+    }
+
+    /// Assigns `next_state` on the row after the given round, or after the initial linear
+    /// layer if `round` is `None`.
+    fn round<const RATE: usize>(
+        region: &mut Region<F>,
+        config: &Poseidon2Config<F, WIDTH, RATE>,
+        round: Option<usize>,
+        round_gate: Selector,
+        next_state: [Option<F>; WIDTH],
+    ) -> Result<Self, Error> {
+        // The initial linear layer occupies the first row, followed by one row per round.
+        let offset = round.map_or(0, |round| round + 1);
+
+        // Enable the required gate.
+        round_gate.enable(region, offset)?;
+
+        // Load the round constants.
+        if let Some(round) = round {
+            let mut load_round_constant = |i: usize| {
+                region.assign_fixed(
+                    || format!("round_{} rc_{}", round, i),
+                    config.rc[i],
+                    offset,
+                    || Ok(config.round_constants[round][i]),
+                )
+            };
+            for i in 0..WIDTH {
+                load_round_constant(i)?;
+            }
+        }
+
+        let mut next_state_word = |i: usize| -> Result<_, Error> {
+            let value = next_state[i];
+            let var = region.assign_advice(
+                || format!("round_{} state_{}", offset, i),
+                config.state[i],
+                offset + 1,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            Ok(StateWord::new(var, value))
+        };
+
+        let state = (0..WIDTH)
+            .map(&mut next_state_word)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Poseidon2State(state.try_into().unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+    use std::marker::PhantomData;
+
+    use halo2::{
+        arithmetic::FieldExt,
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::vesta;
+
+    use super::{Poseidon2Chip, Poseidon2Config, PoseidonInstructions, StateWord};
+    use crate::{
+        gadget::{Hash, Word},
+        primitive::{
+            poseidon2::{self, Poseidon2Pow5T3, Poseidon2Spec},
+            ConstantLength,
+        },
+    };
+
+    struct PermuteCircuit<F: FieldExt>(PhantomData<F>);
+
+    impl<F: FieldExt> Circuit<F> for PermuteCircuit<F> {
+        type Config = Poseidon2Config<F, 3, 2>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            PermuteCircuit(PhantomData)
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Poseidon2Config<F, 3, 2> {
+            let state = [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ];
+            let rc = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+
+            Poseidon2Chip::configure(meta, Poseidon2Pow5T3, state, rc)
+        }
+
+        fn synthesize(
+            &self,
+            config: Poseidon2Config<F, 3, 2>,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let initial_state: [StateWord<F>; 3] = layouter.assign_region(
+                || "prepare initial state",
+                |mut region| {
+                    let mut state_word = |i: usize| -> Result<_, Error> {
+                        let value = Some(F::from_u64(i as u64));
+                        let var = region.assign_advice(
+                            || format!("load state_{}", i),
+                            config.state[i],
+                            0,
+                            || value.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(StateWord { var, value })
+                    };
+
+                    let state = (0..3)
+                        .map(&mut state_word)
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    Ok(state.try_into().unwrap())
+                },
+            )?;
+
+            let chip = Poseidon2Chip::construct(config.clone());
+            let final_state = <Poseidon2Chip<_, 3, 2> as PoseidonInstructions<
+                F,
+                Poseidon2Pow5T3,
+                3,
+                2,
+            >>::permute(&chip, &mut layouter, &initial_state)?;
+
+            // For the purpose of this test, compute the real final state inline.
+            let mut expected_final_state = [F::zero(), F::one(), F::from_u64(2)];
+            let round_constants: Vec<[F; 3]> = Poseidon2Pow5T3.constants();
+            let internal_diagonal: [F; 3] = Poseidon2Pow5T3.internal_diagonal();
+            poseidon2::permute::<_, Poseidon2Pow5T3, 3, 2>(
+                &mut expected_final_state,
+                &internal_diagonal,
+                &round_constants,
+            );
+
+            layouter.assign_region(
+                || "constrain final state",
+                |mut region| {
+                    for (i, (word, expected)) in final_state
+                        .iter()
+                        .zip(expected_final_state.iter())
+                        .enumerate()
+                    {
+                        let var = region.assign_advice(
+                            || format!("load final_state_{}", i),
+                            config.state[i],
+                            0,
+                            || Ok(*expected),
+                        )?;
+                        region.constrain_equal(word.var, var)?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn poseidon2_permute() {
+        let k = 7;
+        let circuit = PermuteCircuit::<Fp>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon2_permute_vesta() {
+        let k = 7;
+        let circuit = PermuteCircuit::<vesta::Base>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[derive(Default)]
+    struct HashCircuit {
+        message: Option<[Fp; 2]>,
+        // For the purpose of this test, witness the result.
+        // TODO: Move this into an instance column.
+        output: Option<Fp>,
+    }
+
+    impl Circuit<Fp> for HashCircuit {
+        type Config = Poseidon2Config<Fp, 3, 2>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Poseidon2Config<Fp, 3, 2> {
+            let state = [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ];
+            let rc = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+
+            meta.enable_constant(rc[0]);
+
+            Poseidon2Chip::configure(meta, Poseidon2Pow5T3, state, rc)
+        }
+
+        fn synthesize(
+            &self,
+            config: Poseidon2Config<Fp, 3, 2>,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let chip = Poseidon2Chip::construct(config.clone());
+
+            let message = layouter.assign_region(
+                || "load message",
+                |mut region| {
+                    let mut message_word = |i: usize| {
+                        let value = self.message.map(|message_vals| message_vals[i]);
+                        let var = region.assign_advice(
+                            || format!("load message_{}", i),
+                            config.state[i],
+                            0,
+                            || value.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(Word::<_, _, Poseidon2Pow5T3, 3, 2>::from_inner(StateWord {
+                            var,
+                            value,
+                        }))
+                    };
+
+                    Ok([message_word(0)?, message_word(1)?])
+                },
+            )?;
+
+            let hasher = Hash::init(chip, layouter.namespace(|| "init"), ConstantLength::<2>)?;
+            let output = hasher.hash(layouter.namespace(|| "hash"), message)?;
+
+            layouter.assign_region(
+                || "constrain output",
+                |mut region| {
+                    let expected_var = region.assign_advice(
+                        || "load output",
+                        config.state[0],
+                        0,
+                        || self.output.ok_or(Error::SynthesisError),
+                    )?;
+                    let word: StateWord<_> = output.inner();
+                    region.constrain_equal(word.var, expected_var)
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn poseidon2_hash() {
+        let message = [Fp::rand(), Fp::rand()];
+        let output = poseidon2::Hash::init(Poseidon2Pow5T3, ConstantLength::<2>).hash(message);
+
+        let k = 7;
+        let circuit = HashCircuit {
+            message: Some(message),
+            output: Some(output),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+}
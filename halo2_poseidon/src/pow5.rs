@@ -0,0 +1,1348 @@
+use std::convert::TryInto;
+use std::iter;
+
+use halo2::{
+    arithmetic::FieldExt,
+    circuit::{Cell, Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Fixed, Selector},
+    poly::Rotation,
+};
+
+use super::gadget::{
+    PoseidonDuplexInstructions, PoseidonInstructions, PoseidonVariableLengthInstructions,
+};
+use super::primitive::{padded_len, Domain, Mds, Spec, SpongeState, State};
+use utilities::{CellValue, Var};
+
+/// Configuration for a [`Pow5Chip`].
+#[derive(Clone, Debug)]
+pub struct Pow5Config<F: FieldExt, const WIDTH: usize, const RATE: usize> {
+    state: [Column<Advice>; WIDTH],
+    partial_sbox: Column<Advice>,
+    rc_a: [Column<Fixed>; WIDTH],
+    rc_b: [Column<Fixed>; WIDTH],
+    s_full: Selector,
+    s_partial: Selector,
+    s_pad_and_add: Selector,
+    s_var_pad: Selector,
+    s_var_output: Selector,
+
+    half_full_rounds: usize,
+    half_partial_rounds: usize,
+    alpha: [u64; 4],
+    round_constants: Vec<[F; WIDTH]>,
+    m_reg: Mds<F, WIDTH>,
+    m_inv: Mds<F, WIDTH>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Pow5Config<F, WIDTH, RATE> {
+    pub fn state(&self) -> [Column<Advice>; WIDTH] {
+        self.state
+    }
+}
+
+/// A Poseidon chip using an $x^5$ S-Box, with a width of `WIDTH` and a rate of `RATE`.
+///
+/// The capacity of the sponge is a single field element, so `RATE` must be
+/// `WIDTH - 1`.
+#[derive(Debug)]
+pub struct Pow5Chip<F: FieldExt, const WIDTH: usize, const RATE: usize> {
+    config: Pow5Config<F, WIDTH, RATE>,
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Pow5Chip<F, WIDTH, RATE> {
+    /// Configures this chip for use in a circuit.
+    ///
+    /// # Side-effects
+    ///
+    /// All columns in `state` will be equality-enabled.
+    //
+    // TODO: Does the rate need to be hard-coded here, or only the width? It probably
+    // needs to be known wherever we implement the hashing gadget, but it isn't strictly
+    // necessary for the permutation.
+    pub fn configure<S: Spec<F, WIDTH, RATE>>(
+        meta: &mut ConstraintSystem<F>,
+        spec: S,
+        state: [Column<Advice>; WIDTH],
+        partial_sbox: Column<Advice>,
+        rc_a: [Column<Fixed>; WIDTH],
+        rc_b: [Column<Fixed>; WIDTH],
+    ) -> Pow5Config<F, WIDTH, RATE> {
+        assert_eq!(RATE, WIDTH - 1);
+        // The variable-length gates use three state columns.
+        assert!(WIDTH >= 3);
+
+        // Generate constants for the Poseidon permutation.
+        // This gadget requires R_F and R_P to be even.
+        assert!(S::full_rounds() & 1 == 0);
+        assert!(S::partial_rounds() & 1 == 0);
+        let half_full_rounds = S::full_rounds() / 2;
+        let half_partial_rounds = S::partial_rounds() / 2;
+        let (round_constants, m_reg, m_inv) = spec.constants();
+
+        // This allows state words to be initialized (by constraining them equal to fixed
+        // values), and used in a permutation from an arbitrary region. rc_a is used in
+        // every permutation round, while rc_b is empty in the initial and final full
+        // rounds, so we use rc_b as "scratch space" for fixed values (enabling potential
+        // layouter optimisations).
+        for column in iter::empty()
+            .chain(state.iter().cloned().map(|c| c.into()))
+            .chain(rc_b.iter().cloned().map(|c| c.into()))
+        {
+            meta.enable_equality(column);
+        }
+
+        let s_full = meta.selector();
+        let s_partial = meta.selector();
+        let s_pad_and_add = meta.selector();
+        let s_var_pad = meta.selector();
+        let s_var_output = meta.selector();
+
+        let alpha = [5, 0, 0, 0];
+        let pow_5 = |v: Expression<F>| {
+            let v2 = v.clone() * v.clone();
+            v2.clone() * v2 * v
+        };
+
+        meta.create_gate("full round", |meta| {
+            let cur: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let next: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+            let rcs: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_fixed(rc_a[idx], Rotation::cur()))
+                .collect();
+
+            let s_full = meta.query_selector(s_full);
+
+            (0..WIDTH)
+                .map(|next_idx| {
+                    let expr = (0..WIDTH)
+                        .map(|idx| {
+                            pow_5(cur[idx].clone() + rcs[idx].clone()) * m_reg[next_idx][idx]
+                        })
+                        .reduce(|acc, term| acc + term)
+                        .expect("WIDTH > 0");
+                    s_full.clone() * (expr - next[next_idx].clone())
+                })
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("partial round", |meta| {
+            let cur: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let mid_0 = meta.query_advice(partial_sbox, Rotation::cur());
+            let next: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+
+            let rcs_a: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_fixed(rc_a[idx], Rotation::cur()))
+                .collect();
+            let rcs_b: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_fixed(rc_b[idx], Rotation::cur()))
+                .collect();
+
+            let s_partial = meta.query_selector(s_partial);
+
+            // The state after the first half of the partial round pair, before the second
+            // round constants are added.
+            let mid = |idx: usize| {
+                (1..WIDTH).fold(mid_0.clone() * m_reg[idx][0], |acc, cur_idx| {
+                    acc + (cur[cur_idx].clone() + rcs_a[cur_idx].clone()) * m_reg[idx][cur_idx]
+                })
+            };
+
+            // The next state, multiplied by the inverse MDS matrix.
+            let next_inv = |idx: usize| {
+                (0..WIDTH)
+                    .map(|next_idx| next[next_idx].clone() * m_inv[idx][next_idx])
+                    .reduce(|acc, term| acc + term)
+                    .expect("WIDTH > 0")
+            };
+
+            iter::empty()
+                .chain(Some(
+                    pow_5(cur[0].clone() + rcs_a[0].clone()) - mid_0.clone(),
+                ))
+                .chain(Some(pow_5(mid(0) + rcs_b[0].clone()) - next_inv(0)))
+                .chain((1..WIDTH).map(|idx| mid(idx) + rcs_b[idx].clone() - next_inv(idx)))
+                .map(|expr| s_partial.clone() * expr)
+                .collect::<Vec<_>>()
+        });
+
+        meta.create_gate("pad-and-add", |meta| {
+            let initial_state: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::prev()))
+                .collect();
+            let input: Vec<_> = (0..RATE)
+                .map(|idx| meta.query_advice(state[idx], Rotation::cur()))
+                .collect();
+            let output_state: Vec<_> = (0..WIDTH)
+                .map(|idx| meta.query_advice(state[idx], Rotation::next()))
+                .collect();
+
+            let s_pad_and_add = meta.query_selector(s_pad_and_add);
+
+            // We pad the input by storing the required padding in fixed columns and
+            // then constraining the corresponding input columns to be equal to it.
+            (0..RATE)
+                .map(|idx| {
+                    initial_state[idx].clone() + input[idx].clone() - output_state[idx].clone()
+                })
+                // The capacity element is never altered by the input.
+                .chain(iter::once(
+                    initial_state[RATE].clone() - output_state[RATE].clone(),
+                ))
+                .map(|expr| s_pad_and_add.clone() * expr)
+                .collect::<Vec<_>>()
+        });
+
+        // Pads the message word at the index stored in rc_a[0] for variable-length
+        // hashing. `is_end` is one at the index equal to the message length, and the
+        // running sum `ended` is one at that index and every index after it.
+        meta.create_gate("variable-length padding", |meta| {
+            let length = meta.query_advice(state[0], Rotation::cur());
+            let is_end = meta.query_advice(state[1], Rotation::cur());
+            let ended = meta.query_advice(state[2], Rotation::cur());
+            let inv = meta.query_advice(partial_sbox, Rotation::cur());
+            let message_word = meta.query_advice(state[0], Rotation::next());
+            let padded_word = meta.query_advice(state[1], Rotation::next());
+            let ended_prev = meta.query_advice(state[2], Rotation::next());
+            let index = meta.query_fixed(rc_a[0], Rotation::cur());
+
+            let s_var_pad = meta.query_selector(s_var_pad);
+
+            let one = Expression::Constant(F::one());
+            let diff = length - index;
+
+            vec![
+                // is_end = 1 if length = index, and 0 otherwise.
+                s_var_pad.clone() * (one.clone() - diff.clone() * inv - is_end.clone()),
+                s_var_pad.clone() * (diff * is_end.clone()),
+                s_var_pad.clone() * (ended_prev + is_end.clone() - ended.clone()),
+                // Message words are kept until the end, which is replaced with a one.
+                s_var_pad * ((one - ended) * message_word + is_end - padded_word),
+            ]
+        });
+
+        meta.create_gate("variable-length output", |meta| {
+            let flag = meta.query_advice(state[0], Rotation::cur());
+            let word = meta.query_advice(state[1], Rotation::cur());
+            let acc = meta.query_advice(state[2], Rotation::cur());
+            let acc_next = meta.query_advice(state[2], Rotation::next());
+
+            let s_var_output = meta.query_selector(s_var_output);
+
+            vec![s_var_output * (acc + flag * word - acc_next)]
+        });
+
+        Pow5Config {
+            state,
+            partial_sbox,
+            rc_a,
+            rc_b,
+            s_full,
+            s_partial,
+            s_pad_and_add,
+            s_var_pad,
+            s_var_output,
+            half_full_rounds,
+            half_partial_rounds,
+            alpha,
+            round_constants,
+            m_reg,
+            m_inv,
+        }
+    }
+
+    pub fn construct(config: Pow5Config<F, WIDTH, RATE>) -> Self {
+        Pow5Chip { config }
+    }
+}
+
+impl<F: FieldExt, const WIDTH: usize, const RATE: usize> Chip<F> for Pow5Chip<F, WIDTH, RATE> {
+    type Config = Pow5Config<F, WIDTH, RATE>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt, S: Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>
+    PoseidonInstructions<F, S, WIDTH, RATE> for Pow5Chip<F, WIDTH, RATE>
+{
+    type Word = StateWord<F>;
+
+    fn permute(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        initial_state: &State<Self::Word, WIDTH>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+
+        layouter.assign_region(
+            || "permute state",
+            |mut region| {
+                // Load the initial state into this region.
+                let state = Pow5State::load(&mut region, config, initial_state)?;
+
+                let state = (0..config.half_full_rounds).fold(Ok(state), |res, r| {
+                    res.and_then(|state| state.full_round(&mut region, config, r, r))
+                })?;
+
+                let state = (0..config.half_partial_rounds).fold(Ok(state), |res, r| {
+                    res.and_then(|state| {
+                        state.partial_round(
+                            &mut region,
+                            config,
+                            config.half_full_rounds + 2 * r,
+                            config.half_full_rounds + r,
+                        )
+                    })
+                })?;
+
+                let state = (0..config.half_full_rounds).fold(Ok(state), |res, r| {
+                    res.and_then(|state| {
+                        state.full_round(
+                            &mut region,
+                            config,
+                            config.half_full_rounds + 2 * config.half_partial_rounds + r,
+                            config.half_full_rounds + config.half_partial_rounds + r,
+                        )
+                    })
+                })?;
+
+                Ok(state.0)
+            },
+        )
+    }
+}
+
+impl<F: FieldExt, S: Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>
+    PoseidonDuplexInstructions<F, S, WIDTH, RATE> for Pow5Chip<F, WIDTH, RATE>
+{
+    fn initial_state(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        domain: &impl Domain<F, WIDTH, RATE>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || format!("initial state for domain {:?}", domain),
+            |mut region| {
+                let mut load_state_word = |i: usize, value: F| -> Result<_, Error> {
+                    let var = region.assign_advice_from_constant(
+                        || format!("state_{}", i),
+                        config.state[i],
+                        0,
+                        value,
+                    )?;
+                    Ok(StateWord {
+                        var,
+                        value: Some(value),
+                    })
+                };
+
+                // The rate portion of the state is zero, followed by the capacity element.
+                let state = (0..WIDTH)
+                    .map(|i| {
+                        let value = if i == RATE {
+                            domain.initial_capacity_element()
+                        } else {
+                            F::zero()
+                        };
+                        load_state_word(i, value)
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(state.try_into().unwrap())
+            },
+        )
+    }
+
+    fn pad_and_add(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        domain: &impl Domain<F, WIDTH, RATE>,
+        initial_state: &State<Self::Word, WIDTH>,
+        input: &SpongeState<Self::Word, RATE>,
+    ) -> Result<State<Self::Word, WIDTH>, Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || format!("pad-and-add for domain {:?}", domain),
+            |mut region| {
+                config.s_pad_and_add.enable(&mut region, 1)?;
+
+                // Load the initial state into this region.
+                let initial_state = Pow5State::load(&mut region, config, initial_state)?.0;
+
+                let padding_values = domain.padding();
+
+                // Load the input and padding into this region.
+                let mut load_input_word = |i: usize| -> Result<_, Error> {
+                    let (constraint_var, value) = match (input[i], padding_values[i]) {
+                        (Some(word), None) => (word.var, word.value),
+                        (None, Some(padding_value)) => {
+                            let padding_var = region.assign_fixed(
+                                || format!("load pad_{}", i),
+                                config.rc_b[i],
+                                1,
+                                || Ok(padding_value),
+                            )?;
+                            (padding_var, Some(padding_value))
+                        }
+                        _ => panic!("Input and padding don't match"),
+                    };
+                    let var = region.assign_advice(
+                        || format!("load input_{}", i),
+                        config.state[i],
+                        1,
+                        || value.ok_or(Error::SynthesisError),
+                    )?;
+                    region.constrain_equal(constraint_var, var)?;
+
+                    Ok(StateWord { var, value })
+                };
+                let input = (0..RATE)
+                    .map(&mut load_input_word)
+                    .collect::<Result<Vec<_>, Error>>()?;
+
+                // Constrain the output.
+                let mut constrain_output_word = |i: usize| -> Result<_, Error> {
+                    let value = initial_state[i].value.and_then(|initial_word| {
+                        input
+                            .get(i)
+                            .map(|word| word.value)
+                            // The capacity element is never altered by the input.
+                            .unwrap_or_else(|| Some(F::zero()))
+                            .map(|input_word| initial_word + input_word)
+                    });
+                    let var = region.assign_advice(
+                        || format!("load output_{}", i),
+                        config.state[i],
+                        2,
+                        || value.ok_or(Error::SynthesisError),
+                    )?;
+                    Ok(StateWord { var, value })
+                };
+
+                let output = (0..WIDTH)
+                    .map(&mut constrain_output_word)
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(output.try_into().unwrap())
+            },
+        )
+    }
+
+    fn get_output(state: &State<Self::Word, WIDTH>) -> SpongeState<Self::Word, RATE> {
+        let mut output = [None; RATE];
+        for (word, value) in output.iter_mut().zip(state.iter()) {
+            *word = Some(*value);
+        }
+        output
+    }
+}
+
+impl<F: FieldExt, S: Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>
+    PoseidonVariableLengthInstructions<F, S, WIDTH, RATE> for Pow5Chip<F, WIDTH, RATE>
+{
+    fn pad_variable_length(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        message: &[Self::Word],
+        length: &Self::Word,
+    ) -> Result<(Vec<Self::Word>, Vec<Self::Word>), Error> {
+        let config = self.config();
+        layouter.assign_region(
+            || "variable-length padding",
+            |mut region| {
+                let mut padded = vec![];
+                let mut flags = vec![];
+                let mut ended_prev: Option<StateWord<F>> = None;
+
+                // Each word of the padded message occupies two rows.
+                for i in 0..padded_len::<RATE>(message.len()) {
+                    let offset = 2 * i;
+                    config.s_var_pad.enable(&mut region, offset)?;
+
+                    let index = F::from_u64(i as u64);
+                    region.assign_fixed(
+                        || format!("index_{}", i),
+                        config.rc_a[0],
+                        offset,
+                        || Ok(index),
+                    )?;
+
+                    let length_var = region.assign_advice(
+                        || format!("length_{}", i),
+                        config.state[0],
+                        offset,
+                        || length.value.ok_or(Error::SynthesisError),
+                    )?;
+                    region.constrain_equal(length.var, length_var)?;
+
+                    let diff = length.value.map(|length| length - index);
+                    region.assign_advice(
+                        || format!("inv_{}", i),
+                        config.partial_sbox,
+                        offset,
+                        || {
+                            diff.map(|diff| {
+                                Option::<F>::from(diff.invert()).unwrap_or_else(F::zero)
+                            })
+                            .ok_or(Error::SynthesisError)
+                        },
+                    )?;
+
+                    let is_end = diff.map(|diff| {
+                        if diff == F::zero() {
+                            F::one()
+                        } else {
+                            F::zero()
+                        }
+                    });
+                    let is_end_var = region.assign_advice(
+                        || format!("is_end_{}", i),
+                        config.state[1],
+                        offset,
+                        || is_end.ok_or(Error::SynthesisError),
+                    )?;
+
+                    // Load the running sum for the previous index, which starts at zero.
+                    let ended_prev_value = match ended_prev {
+                        None => {
+                            region.assign_advice_from_constant(
+                                || "ended_prev_0",
+                                config.state[2],
+                                offset + 1,
+                                F::zero(),
+                            )?;
+                            Some(F::zero())
+                        }
+                        Some(word) => {
+                            let var = region.assign_advice(
+                                || format!("ended_prev_{}", i),
+                                config.state[2],
+                                offset + 1,
+                                || word.value.ok_or(Error::SynthesisError),
+                            )?;
+                            region.constrain_equal(word.var, var)?;
+                            word.value
+                        }
+                    };
+
+                    let ended = ended_prev_value
+                        .zip(is_end)
+                        .map(|(ended_prev, is_end)| ended_prev + is_end);
+                    let ended_var = region.assign_advice(
+                        || format!("ended_{}", i),
+                        config.state[2],
+                        offset,
+                        || ended.ok_or(Error::SynthesisError),
+                    )?;
+
+                    // Words beyond the end of the message are zero.
+                    let message_word = match message.get(i) {
+                        Some(word) => {
+                            let var = region.assign_advice(
+                                || format!("message_{}", i),
+                                config.state[0],
+                                offset + 1,
+                                || word.value.ok_or(Error::SynthesisError),
+                            )?;
+                            region.constrain_equal(word.var, var)?;
+                            word.value
+                        }
+                        None => {
+                            region.assign_advice_from_constant(
+                                || format!("message_{}", i),
+                                config.state[0],
+                                offset + 1,
+                                F::zero(),
+                            )?;
+                            Some(F::zero())
+                        }
+                    };
+
+                    let padded_word = ended
+                        .zip(message_word)
+                        .zip(is_end)
+                        .map(|((ended, word), is_end)| (F::one() - ended) * word + is_end);
+                    let padded_var = region.assign_advice(
+                        || format!("padded_{}", i),
+                        config.state[1],
+                        offset + 1,
+                        || padded_word.ok_or(Error::SynthesisError),
+                    )?;
+
+                    // The end must have been reached by the index equal to the length of
+                    // the message, which bounds the length.
+                    if i == message.len() {
+                        region.constrain_constant(ended_var, F::one())?;
+                    }
+
+                    padded.push(StateWord::new(padded_var, padded_word));
+                    flags.push(StateWord::new(is_end_var, is_end));
+                    ended_prev = Some(StateWord::new(ended_var, ended));
+                }
+
+                Ok((padded, flags))
+            },
+        )
+    }
+
+    fn inner_product(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        flags: &[Self::Word],
+        words: &[Self::Word],
+    ) -> Result<Self::Word, Error> {
+        assert_eq!(flags.len(), words.len());
+
+        let config = self.config();
+        layouter.assign_region(
+            || "variable-length output",
+            |mut region| {
+                let mut acc = StateWord::new(
+                    region.assign_advice_from_constant(
+                        || "acc_0",
+                        config.state[2],
+                        0,
+                        F::zero(),
+                    )?,
+                    Some(F::zero()),
+                );
+
+                for (i, (flag, word)) in flags.iter().zip(words.iter()).enumerate() {
+                    config.s_var_output.enable(&mut region, i)?;
+
+                    let mut load_word = |name: &str, column: usize, word: &StateWord<F>| {
+                        let var = region.assign_advice(
+                            || format!("{}_{}", name, i),
+                            config.state[column],
+                            i,
+                            || word.value.ok_or(Error::SynthesisError),
+                        )?;
+                        region.constrain_equal(word.var, var)
+                    };
+                    load_word("flag", 0, flag)?;
+                    load_word("word", 1, word)?;
+
+                    let value = acc
+                        .value
+                        .zip(flag.value)
+                        .zip(word.value)
+                        .map(|((acc, flag), word)| acc + flag * word);
+                    let var = region.assign_advice(
+                        || format!("acc_{}", i + 1),
+                        config.state[2],
+                        i + 1,
+                        || value.ok_or(Error::SynthesisError),
+                    )?;
+                    acc = StateWord::new(var, value);
+                }
+
+                Ok(acc)
+            },
+        )
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct StateWord<F: FieldExt> {
+    pub(crate) var: Cell,
+    pub(crate) value: Option<F>,
+}
+
+impl<F: FieldExt> StateWord<F> {
+    pub fn new(var: Cell, value: Option<F>) -> Self {
+        Self { var, value }
+    }
+}
+
+impl<F: FieldExt> From<StateWord<F>> for CellValue<F> {
+    fn from(state_word: StateWord<F>) -> CellValue<F> {
+        CellValue::new(state_word.var, state_word.value)
+    }
+}
+
+#[derive(Debug)]
+struct Pow5State<F: FieldExt, const WIDTH: usize>([StateWord<F>; WIDTH]);
+
+impl<F: FieldExt, const WIDTH: usize> Pow5State<F, WIDTH> {
+    fn full_round<const RATE: usize>(
+        self,
+        region: &mut Region<F>,
+        config: &Pow5Config<F, WIDTH, RATE>,
+        round: usize,
+        offset: usize,
+    ) -> Result<Self, Error> {
+        Self::round(region, config, round, offset, config.s_full, |_| {
+            let r: Option<Vec<_>> = self
+                .0
+                .iter()
+                .zip(config.round_constants[round].iter())
+                .map(|(word, rc)| word.value.map(|v| (v + rc).pow(&config.alpha)))
+                .collect();
+
+            Ok((round + 1, mds_mul(&config.m_reg, r.as_deref())))
+        })
+    }
+
+    fn partial_round<const RATE: usize>(
+        self,
+        region: &mut Region<F>,
+        config: &Pow5Config<F, WIDTH, RATE>,
+        round: usize,
+        offset: usize,
+    ) -> Result<Self, Error> {
+        Self::round(region, config, round, offset, config.s_partial, |region| {
+            // In a partial round, the S-box is only applied to the first state word.
+            let add_round_constants = |p: &[F], round: usize| -> Vec<F> {
+                p.iter()
+                    .zip(config.round_constants[round].iter())
+                    .enumerate()
+                    .map(|(i, (p_i, rc))| {
+                        if i == 0 {
+                            (*p_i + rc).pow(&config.alpha)
+                        } else {
+                            *p_i + rc
+                        }
+                    })
+                    .collect()
+            };
+
+            let p: Option<Vec<_>> = self.0.iter().map(|word| word.value).collect();
+            let r = p.map(|p| add_round_constants(&p, round));
+
+            region.assign_advice(
+                || format!("round_{} partial_sbox", round),
+                config.partial_sbox,
+                offset,
+                || r.as_ref().map(|r| r[0]).ok_or(Error::SynthesisError),
+            )?;
+
+            let p_mid = mds_mul(&config.m_reg, r.as_deref());
+
+            // Load the second round constants.
+            let mut load_round_constant = |i: usize| {
+                region.assign_fixed(
+                    || format!("round_{} rc_{}", round + 1, i),
+                    config.rc_b[i],
+                    offset,
+                    || Ok(config.round_constants[round + 1][i]),
+                )
+            };
+            for i in 0..WIDTH {
+                load_round_constant(i)?;
+            }
+
+            let p_mid: Option<Vec<_>> = p_mid.iter().cloned().collect();
+            let r_mid = p_mid.map(|p| add_round_constants(&p, round + 1));
+
+            Ok((round + 2, mds_mul(&config.m_reg, r_mid.as_deref())))
+        })
+    }
+
+    fn load<const RATE: usize>(
+        region: &mut Region<F>,
+        config: &Pow5Config<F, WIDTH, RATE>,
+        initial_state: &State<StateWord<F>, WIDTH>,
+    ) -> Result<Self, Error> {
+        let mut load_state_word = |i: usize| -> Result<_, Error> {
+            let value = initial_state[i].value;
+            let var = region.assign_advice(
+                || format!("load state_{}", i),
+                config.state[i],
+                0,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            region.constrain_equal(initial_state[i].var, var)?;
+            Ok(StateWord { var, value })
+        };
+
+        let state = (0..WIDTH)
+            .map(&mut load_state_word)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Pow5State(state.try_into().unwrap()))
+    }
+
+    fn round<const RATE: usize>(
+        region: &mut Region<F>,
+        config: &Pow5Config<F, WIDTH, RATE>,
+        round: usize,
+        offset: usize,
+        round_gate: Selector,
+        round_fn: impl FnOnce(&mut Region<F>) -> Result<(usize, [Option<F>; WIDTH]), Error>,
+    ) -> Result<Self, Error> {
+        // Enable the required gate.
+        round_gate.enable(region, offset)?;
+
+        // Load the round constants.
+        let mut load_round_constant = |i: usize| {
+            region.assign_fixed(
+                || format!("round_{} rc_{}", round, i),
+                config.rc_a[i],
+                offset,
+                || Ok(config.round_constants[round][i]),
+            )
+        };
+        for i in 0..WIDTH {
+            load_round_constant(i)?;
+        }
+
+        // Compute the next round's state.
+        let (next_round, next_state) = round_fn(region)?;
+
+        let mut next_state_word = |i: usize| -> Result<_, Error> {
+            let value = next_state[i];
+            let var = region.assign_advice(
+                || format!("round_{} state_{}", next_round, i),
+                config.state[i],
+                offset + 1,
+                || value.ok_or(Error::SynthesisError),
+            )?;
+            Ok(StateWord { var, value })
+        };
+
+        let state = (0..WIDTH)
+            .map(&mut next_state_word)
+            .collect::<Result<Vec<_>, Error>>()?;
+        Ok(Pow5State(state.try_into().unwrap()))
+    }
+}
+
+/// Multiplies the given state by the MDS matrix, if the state is known.
+pub(crate) fn mds_mul<F: FieldExt, const WIDTH: usize>(
+    m: &Mds<F, WIDTH>,
+    state: Option<&[F]>,
+) -> [Option<F>; WIDTH] {
+    let mut next_state = [None; WIDTH];
+    if let Some(state) = state {
+        for (next_word, m_i) in next_state.iter_mut().zip(m.iter()) {
+            *next_word = Some(
+                m_i.iter()
+                    .zip(state.iter())
+                    .fold(F::zero(), |acc, (m_ij, s_j)| acc + *m_ij * s_j),
+            );
+        }
+    }
+    next_state
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryInto;
+    use std::marker::PhantomData;
+
+    use ff::{Field, PrimeField};
+    use halo2::{
+        arithmetic::FieldExt,
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        pasta::Fp,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::{pallas, vesta};
+
+    use super::{PoseidonInstructions, Pow5Chip, Pow5Config, StateWord};
+    use crate::{
+        gadget::{Hash, Word},
+        pow5t3::{Pow5T3Chip, Pow5T3Config},
+        primitive::{
+            self as poseidon, test_vectors, ConstantLength, P128Pow5T3 as OrchardNullifier, Spec,
+            VariableLength,
+        },
+    };
+
+    /// Poseidon with a width of 5, using constants generated by the Grain LFSR.
+    #[derive(Debug, Default)]
+    struct P128Pow5T5;
+
+    impl Spec<Fp, 5, 4> for P128Pow5T5 {
+        fn full_rounds() -> usize {
+            8
+        }
+
+        fn partial_rounds() -> usize {
+            60
+        }
+
+        fn sbox(val: Fp) -> Fp {
+            val.pow_vartime(&[5])
+        }
+
+        fn secure_mds(&self) -> usize {
+            0
+        }
+    }
+
+    /// Poseidon with a width of 9, using constants generated by the Grain LFSR.
+    #[derive(Debug, Default)]
+    struct P128Pow5T9;
+
+    impl Spec<Fp, 9, 8> for P128Pow5T9 {
+        fn full_rounds() -> usize {
+            8
+        }
+
+        fn partial_rounds() -> usize {
+            // Rounded up from 63, as the chip applies partial rounds in pairs.
+            64
+        }
+
+        fn sbox(val: Fp) -> Fp {
+            val.pow_vartime(&[5])
+        }
+
+        fn secure_mds(&self) -> usize {
+            0
+        }
+    }
+
+    /// Poseidon with a width of 12, using constants generated by the Grain LFSR.
+    #[derive(Debug, Default)]
+    struct P128Pow5T12;
+
+    impl Spec<Fp, 12, 11> for P128Pow5T12 {
+        fn full_rounds() -> usize {
+            8
+        }
+
+        fn partial_rounds() -> usize {
+            60
+        }
+
+        fn sbox(val: Fp) -> Fp {
+            val.pow_vartime(&[5])
+        }
+
+        fn secure_mds(&self) -> usize {
+            0
+        }
+    }
+
+    struct PermuteCircuit<F: FieldExt, S: Spec<F, WIDTH, RATE>, const WIDTH: usize, const RATE: usize>(
+        PhantomData<(F, S)>,
+    );
+
+    impl<F: FieldExt, S: Spec<F, WIDTH, RATE> + Default, const WIDTH: usize, const RATE: usize>
+        Circuit<F> for PermuteCircuit<F, S, WIDTH, RATE>
+    {
+        type Config = Pow5Config<F, WIDTH, RATE>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            PermuteCircuit(PhantomData)
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Pow5Config<F, WIDTH, RATE> {
+            let state = (0..WIDTH).map(|_| meta.advice_column()).collect::<Vec<_>>();
+            let partial_sbox = meta.advice_column();
+
+            let rc_a = (0..WIDTH).map(|_| meta.fixed_column()).collect::<Vec<_>>();
+            let rc_b = (0..WIDTH).map(|_| meta.fixed_column()).collect::<Vec<_>>();
+
+            Pow5Chip::configure(
+                meta,
+                S::default(),
+                state.try_into().unwrap(),
+                partial_sbox,
+                rc_a.try_into().unwrap(),
+                rc_b.try_into().unwrap(),
+            )
+        }
+
+        fn synthesize(
+            &self,
+            config: Pow5Config<F, WIDTH, RATE>,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let initial_state: [StateWord<F>; WIDTH] = layouter.assign_region(
+                || "prepare initial state",
+                |mut region| {
+                    let mut state_word = |i: usize| -> Result<_, Error> {
+                        let value = Some(F::from_u64(i as u64));
+                        let var = region.assign_advice(
+                            || format!("load state_{}", i),
+                            config.state[i],
+                            0,
+                            || value.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(StateWord { var, value })
+                    };
+
+                    let state = (0..WIDTH)
+                        .map(&mut state_word)
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    Ok(state.try_into().unwrap())
+                },
+            )?;
+
+            let chip = Pow5Chip::construct(config.clone());
+            let final_state = <Pow5Chip<_, WIDTH, RATE> as PoseidonInstructions<
+                F,
+                S,
+                WIDTH,
+                RATE,
+            >>::permute(&chip, &mut layouter, &initial_state)?;
+
+            // For the purpose of this test, compute the real final state inline.
+            let mut expected_final_state: [F; WIDTH] = (0..WIDTH)
+                .map(|i| F::from_u64(i as u64))
+                .collect::<Vec<_>>()
+                .try_into()
+                .unwrap();
+            let (round_constants, mds, _) = S::default().constants();
+            poseidon::permute::<_, S, WIDTH, RATE>(
+                &mut expected_final_state,
+                &mds,
+                &round_constants,
+            );
+
+            layouter.assign_region(
+                || "constrain final state",
+                |mut region| {
+                    for (i, (word, expected)) in final_state
+                        .iter()
+                        .zip(expected_final_state.iter())
+                        .enumerate()
+                    {
+                        let var = region.assign_advice(
+                            || format!("load final_state_{}", i),
+                            config.state[i],
+                            0,
+                            || Ok(*expected),
+                        )?;
+                        region.constrain_equal(word.var, var)?;
+                    }
+                    Ok(())
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn poseidon_permute() {
+        let k = 6;
+        let circuit = PermuteCircuit::<Fp, OrchardNullifier, 3, 2>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon_permute_vesta() {
+        let k = 6;
+        let circuit = PermuteCircuit::<vesta::Base, OrchardNullifier, 3, 2>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon_permute_width_5() {
+        let k = 6;
+        let circuit = PermuteCircuit::<Fp, P128Pow5T5, 5, 4>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon_permute_width_9() {
+        let k = 6;
+        let circuit = PermuteCircuit::<Fp, P128Pow5T9, 9, 8>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon_permute_width_12() {
+        let k = 6;
+        let circuit = PermuteCircuit::<Fp, P128Pow5T12, 12, 11>(PhantomData);
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[derive(Default)]
+    struct HashCircuit<F: FieldExt> {
+        message: Option<[F; 2]>,
+        // For the purpose of this test, witness the result.
+        // TODO: Move this into an instance column.
+        output: Option<F>,
+    }
+
+    impl<F: FieldExt> Circuit<F> for HashCircuit<F>
+    where
+        OrchardNullifier: Spec<F, 3, 2>,
+    {
+        type Config = Pow5T3Config<F>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<F>) -> Pow5T3Config<F> {
+            let state = [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ];
+            let partial_sbox = meta.advice_column();
+
+            let rc_a = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+            let rc_b = [
+                meta.fixed_column(),
+                meta.fixed_column(),
+                meta.fixed_column(),
+            ];
+
+            meta.enable_constant(rc_b[0]);
+
+            Pow5T3Chip::configure(meta, OrchardNullifier, state, partial_sbox, rc_a, rc_b)
+        }
+
+        fn synthesize(
+            &self,
+            config: Pow5T3Config<F>,
+            mut layouter: impl Layouter<F>,
+        ) -> Result<(), Error> {
+            let chip = Pow5T3Chip::construct(config.clone());
+
+            let message = layouter.assign_region(
+                || "load message",
+                |mut region| {
+                    let mut message_word = |i: usize| {
+                        let value = self.message.map(|message_vals| message_vals[i]);
+                        let var = region.assign_advice(
+                            || format!("load message_{}", i),
+                            config.state[i],
+                            0,
+                            || value.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(Word::<_, _, OrchardNullifier, 3, 2>::from_inner(
+                            StateWord { var, value },
+                        ))
+                    };
+
+                    Ok([message_word(0)?, message_word(1)?])
+                },
+            )?;
+
+            let hasher = Hash::init(chip, layouter.namespace(|| "init"), ConstantLength::<2>)?;
+            let output = hasher.hash(layouter.namespace(|| "hash"), message)?;
+
+            layouter.assign_region(
+                || "constrain output",
+                |mut region| {
+                    let expected_var = region.assign_advice(
+                        || "load output",
+                        config.state[0],
+                        0,
+                        || self.output.ok_or(Error::SynthesisError),
+                    )?;
+                    let word: StateWord<_> = output.inner();
+                    region.constrain_equal(word.var, expected_var)
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn poseidon_hash() {
+        let message = [Fp::rand(), Fp::rand()];
+        let output = poseidon::Hash::init(OrchardNullifier, ConstantLength::<2>).hash(message);
+
+        let k = 6;
+        let circuit = HashCircuit {
+            message: Some(message),
+            output: Some(output),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    #[test]
+    fn poseidon_hash_vesta() {
+        let message = [vesta::Base::rand(), vesta::Base::rand()];
+        let output = poseidon::Hash::init(OrchardNullifier, ConstantLength::<2>).hash(message);
+
+        let k = 6;
+        let circuit = HashCircuit {
+            message: Some(message),
+            output: Some(output),
+        };
+        let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+        assert_eq!(prover.verify(), Ok(()))
+    }
+
+    fn hash_test_vectors_helper<F: FieldExt<Repr = [u8; 32]>>(
+        test_vectors: Vec<test_vectors::HashTestVector>,
+    ) where
+        OrchardNullifier: Spec<F, 3, 2>,
+    {
+        for tv in test_vectors {
+            let message = [
+                F::from_repr(tv.input[0]).unwrap(),
+                F::from_repr(tv.input[1]).unwrap(),
+            ];
+            let output = poseidon::Hash::init(OrchardNullifier, ConstantLength).hash(message);
+            assert_eq!(output.to_repr(), tv.output);
+
+            let k = 6;
+            let circuit = HashCircuit {
+                message: Some(message),
+                output: Some(output),
+            };
+            let prover = MockProver::run(k, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+    }
+
+    #[test]
+    fn hash_test_vectors() {
+        hash_test_vectors_helper::<pallas::Base>(test_vectors::fp::hash());
+    }
+
+    #[test]
+    fn hash_test_vectors_vesta() {
+        hash_test_vectors_helper::<vesta::Base>(test_vectors::fq::hash());
+    }
+
+    const MAX_LEN: usize = 5;
+
+    #[derive(Default)]
+    struct VariableLengthHashCircuit {
+        message: Option<[Fp; MAX_LEN]>,
+        length: Option<Fp>,
+        // For the purpose of this test, witness the result.
+        output: Option<Fp>,
+    }
+
+    impl Circuit<Fp> for VariableLengthHashCircuit {
+        type Config = Pow5T3Config<Fp>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            Self::default()
+        }
+
+        fn configure(meta: &mut ConstraintSystem<Fp>) -> Pow5T3Config<Fp> {
+            HashCircuit::<Fp>::configure(meta)
+        }
+
+        fn synthesize(
+            &self,
+            config: Pow5T3Config<Fp>,
+            mut layouter: impl Layouter<Fp>,
+        ) -> Result<(), Error> {
+            let chip = Pow5T3Chip::construct(config.clone());
+
+            let (message, length) = layouter.assign_region(
+                || "load message",
+                |mut region| {
+                    let mut load_word = |name: &str, offset: usize, value: Option<Fp>| {
+                        let var = region.assign_advice(
+                            || format!("load {}", name),
+                            config.state[0],
+                            offset,
+                            || value.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(Word::<_, _, OrchardNullifier, 3, 2>::from_inner(
+                            StateWord { var, value },
+                        ))
+                    };
+
+                    let message = (0..MAX_LEN)
+                        .map(|i| {
+                            load_word(
+                                &format!("message_{}", i),
+                                i,
+                                self.message.map(|message| message[i]),
+                            )
+                        })
+                        .collect::<Result<Vec<_>, Error>>()?;
+                    let length = load_word("length", MAX_LEN, self.length)?;
+                    Ok((message, length))
+                },
+            )?;
+
+            let hasher = Hash::init(chip, layouter.namespace(|| "init"), VariableLength)?;
+            let output = hasher.hash(layouter.namespace(|| "hash"), message, length)?;
+
+            layouter.assign_region(
+                || "constrain output",
+                |mut region| {
+                    let expected_var = region.assign_advice(
+                        || "load output",
+                        config.state[0],
+                        0,
+                        || self.output.ok_or(Error::SynthesisError),
+                    )?;
+                    let word: StateWord<_> = output.inner();
+                    region.constrain_equal(word.var, expected_var)
+                },
+            )
+        }
+    }
+
+    #[test]
+    fn poseidon_hash_variable_length() {
+        let message = [Fp::rand(), Fp::rand(), Fp::rand(), Fp::rand(), Fp::rand()];
+
+        let k = 8;
+        let check = |length: usize, output: Fp| {
+            let circuit = VariableLengthHashCircuit {
+                message: Some(message),
+                length: Some(Fp::from_u64(length as u64)),
+                output: Some(output),
+            };
+            MockProver::run(k, &circuit, vec![]).unwrap().verify()
+        };
+        let hash = |length: usize| {
+            poseidon::Hash::init(OrchardNullifier, VariableLength).hash(&message[..length])
+        };
+
+        for length in 0..=MAX_LEN {
+            assert_eq!(check(length, hash(length)), Ok(()));
+        }
+
+        // The output depends on the length.
+        assert!(check(3, hash(2)).is_err());
+
+        // The length cannot exceed the number of message words.
+        assert!(check(MAX_LEN + 1, hash(MAX_LEN)).is_err());
+        assert!(check(2 * MAX_LEN, hash(MAX_LEN)).is_err());
+    }
+
+    #[cfg(feature = "dev-graph")]
+    #[test]
+    fn print_poseidon_chip() {
+        use plotters::prelude::*;
+
+        let root = BitMapBackend::new("poseidon-chip-layout.png", (1024, 768)).into_drawing_area();
+        root.fill(&WHITE).unwrap();
+        let root = root
+            .titled("Poseidon Chip Layout", ("sans-serif", 60))
+            .unwrap();
+
+        let circuit = HashCircuit::<Fp> {
+            message: None,
+            output: None,
+        };
+        halo2::dev::CircuitLayout::default()
+            .render(6, &circuit, &root)
+            .unwrap();
+    }
+}
@@ -8,7 +8,6 @@ use std::marker::PhantomData;
 use pasta_curves::arithmetic::FieldExt;
 
 pub(crate) mod fp;
-#[allow(dead_code)]
 pub(crate) mod fq;
 pub(crate) mod grain;
 pub(crate) mod mds;
@@ -19,6 +18,8 @@ pub(crate) mod test_vectors;
 mod p128pow5t3;
 pub use p128pow5t3::P128Pow5T3;
 
+pub mod poseidon2;
+
 use grain::SboxType;
 
 /// The type used to hold permutation state.
@@ -315,6 +316,50 @@ impl<F: FieldExt, const T: usize, const RATE: usize, const L: usize> Domain<F, T
     }
 }
 
+/// A Poseidon hash function used with variable input length.
+///
+/// Domain specified in section 4.2 of https://eprint.iacr.org/2019/458.pdf
+///
+/// The message is padded with a single one followed by as many zeros as are needed
+/// to fill the final block, so messages of different lengths never share padded
+/// inputs. The capacity element of this domain is distinct from that of every
+/// [`ConstantLength`] domain.
+#[derive(Clone, Copy, Debug)]
+pub struct VariableLength;
+
+impl<F: FieldExt, const T: usize, const RATE: usize> Domain<F, T, RATE> for VariableLength {
+    fn initial_capacity_element(&self) -> F {
+        // Capacity value is $2^128 + (o-1)$ where o is the output length. Constant
+        // input lengths are less than $2^64$, so this cannot coincide with the capacity
+        // value $length \cdot 2^64 + (o-1)$ of any constant-length domain.
+        // We hard-code an output length of 1.
+        let two_pow_64 = F::from_u128(1 << 64);
+        two_pow_64 * two_pow_64
+    }
+
+    fn padding(&self) -> SpongeState<F, RATE> {
+        // The padding is absorbed as part of the message by `Hash::hash`, as it depends
+        // on the length of the message. Every block is therefore full.
+        [None; RATE]
+    }
+
+    fn pad_and_add(&self) -> Box<dyn Fn(&mut State<F, T>, &SpongeState<F, RATE>)> {
+        Box::new(|state, input| {
+            // `Iterator::zip` short-circuits when one iterator completes, so this will only
+            // mutate the rate portion of the state.
+            for (word, value) in state.iter_mut().zip(input.iter()) {
+                *word += value.expect("padded message fills every block");
+            }
+        })
+    }
+}
+
+/// Returns the number of words that a message of `len` words occupies once padded
+/// for [`VariableLength`] hashing.
+pub(crate) fn padded_len<const RATE: usize>(len: usize) -> usize {
+    (len / RATE + 1) * RATE
+}
+
 /// A Poseidon hash function, built around a duplex sponge.
 pub struct Hash<
     F: FieldExt,
@@ -379,11 +424,29 @@ impl<F: FieldExt, S: Spec<F, T, RATE>, const T: usize, const RATE: usize, const
     }
 }
 
+impl<F: FieldExt, S: Spec<F, T, RATE>, const T: usize, const RATE: usize>
+    Hash<F, S, VariableLength, T, RATE>
+{
+    /// Hashes the given input.
+    pub fn hash(mut self, message: &[F]) -> F {
+        // Pad the message with a one followed by zeros, up to a multiple of the rate.
+        let padding = iter::once(F::one())
+            .chain(iter::repeat(F::zero()))
+            .take(padded_len::<RATE>(message.len()) - message.len());
+        for value in message.iter().cloned().chain(padding) {
+            self.duplex.absorb(value);
+        }
+        self.duplex.squeeze()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pasta_curves::{arithmetic::FieldExt, pallas};
 
-    use super::{permute, ConstantLength, Hash, P128Pow5T3 as OrchardNullifier, Spec};
+    use super::{
+        permute, ConstantLength, Hash, P128Pow5T3 as OrchardNullifier, Spec, VariableLength,
+    };
 
     #[test]
     fn orchard_spec_equivalence() {
@@ -400,4 +463,54 @@ mod tests {
         permute::<_, OrchardNullifier, 3, 2>(&mut state, &mds, &round_constants);
         assert_eq!(state[0], result);
     }
+
+    #[test]
+    fn variable_length_padding() {
+        let (round_constants, mds, _) = OrchardNullifier.constants();
+        let one = pallas::Base::one();
+        let zero = pallas::Base::zero();
+        let m = [pallas::Base::from_u64(6), pallas::Base::from_u64(42)];
+
+        let two_pow_64 = pallas::Base::from_u128(1 << 64);
+
+        // Absorbs the given padded blocks into a fresh VariableLength state.
+        let permute_blocks = |blocks: &[[pallas::Base; 2]]| {
+            let mut state = [zero, zero, two_pow_64 * two_pow_64];
+            for block in blocks {
+                state[0] += block[0];
+                state[1] += block[1];
+                permute::<_, OrchardNullifier, 3, 2>(&mut state, &mds, &round_constants);
+            }
+            state[0]
+        };
+
+        let hash = |message: &[pallas::Base]| {
+            Hash::<_, _, VariableLength, 3, 2>::init(OrchardNullifier, VariableLength).hash(message)
+        };
+
+        assert_eq!(hash(&[]), permute_blocks(&[[one, zero]]));
+        assert_eq!(hash(&m[..1]), permute_blocks(&[[m[0], one]]));
+        assert_eq!(hash(&m), permute_blocks(&[[m[0], m[1]], [one, zero]]));
+
+        // Trailing zeros are not absorbed by the padding.
+        assert_ne!(hash(&m[..1]), hash(&[m[0], zero]));
+
+        // The domain is separated from constant-length hashing.
+        assert_ne!(
+            hash(&m),
+            Hash::init(OrchardNullifier, ConstantLength).hash(m)
+        );
+    }
+
+    #[test]
+    fn variable_length_capacity_is_distinct() {
+        // Both of these absorb the single block [1, 0]; only the capacity element
+        // separates them.
+        let constant =
+            Hash::<_, _, ConstantLength<1>, 3, 2>::init(OrchardNullifier, ConstantLength)
+                .hash([pallas::Base::one()]);
+        let variable =
+            Hash::<_, _, VariableLength, 3, 2>::init(OrchardNullifier, VariableLength).hash(&[]);
+        assert_ne!(constant, variable);
+    }
 }
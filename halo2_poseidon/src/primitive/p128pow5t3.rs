@@ -1,9 +1,6 @@
-use pasta_curves::{arithmetic::Field, pallas};
+use pasta_curves::{arithmetic::Field, pallas, vesta};
 
-use super::{
-    fp::{MDS, MDS_INV, ROUND_CONSTANTS},
-    Mds, Spec,
-};
+use super::{fp, fq, Mds, Spec};
 
 /// Poseidon-128 using the $x^5$ S-box, with a width of 3 field elements, and the
 /// standard number of rounds for 128-bit security "with margin".
@@ -11,7 +8,10 @@ use super::{
 /// The standard specification for this set of parameters uses $R_F = 8, R_P = 56$.
 /// This is conveniently an even number of partial rounds, making it easier to
 /// construct a Halo 2 circuit.
-#[derive(Debug)]
+///
+/// This specification is implemented for both the Pallas and Vesta base fields, with
+/// constants generated for each field.
+#[derive(Debug, Default)]
 pub struct P128Pow5T3;
 
 impl Spec<pallas::Base, 3, 2> for P128Pow5T3 {
@@ -38,7 +38,35 @@ impl Spec<pallas::Base, 3, 2> for P128Pow5T3 {
         Mds<pallas::Base, 3>,
         Mds<pallas::Base, 3>,
     ) {
-        (ROUND_CONSTANTS[..].to_vec(), MDS, MDS_INV)
+        (fp::ROUND_CONSTANTS[..].to_vec(), fp::MDS, fp::MDS_INV)
+    }
+}
+
+impl Spec<vesta::Base, 3, 2> for P128Pow5T3 {
+    fn full_rounds() -> usize {
+        8
+    }
+
+    fn partial_rounds() -> usize {
+        56
+    }
+
+    fn sbox(val: vesta::Base) -> vesta::Base {
+        val.pow_vartime(&[5])
+    }
+
+    fn secure_mds(&self) -> usize {
+        unimplemented!()
+    }
+
+    fn constants(
+        &self,
+    ) -> (
+        Vec<[vesta::Base; 3]>,
+        Mds<vesta::Base, 3>,
+        Mds<vesta::Base, 3>,
+    ) {
+        (fq::ROUND_CONSTANTS[..].to_vec(), fq::MDS, fq::MDS_INV)
     }
 }
 
@@ -47,11 +75,11 @@ mod tests {
     use std::marker::PhantomData;
 
     use ff::PrimeField;
-    use pasta_curves::{arithmetic::FieldExt, pallas};
+    use pasta_curves::{arithmetic::FieldExt, pallas, vesta};
 
-    use crate::primitive::{permute, ConstantLength, Hash, Spec};
+    use crate::primitive::{permute, test_vectors, ConstantLength, Hash, Spec};
 
-    use super::{MDS, MDS_INV, ROUND_CONSTANTS};
+    use super::{fp, fq};
 
     /// The same Poseidon specification as poseidon::P128Pow5T3, but constructed
     /// such that its constants will be generated at runtime.
@@ -90,24 +118,37 @@ mod tests {
 
     #[test]
     fn verify_constants() {
-        let poseidon = P128Pow5T3::<pallas::Base>::new(0);
-        let (round_constants, mds, mds_inv) = poseidon.constants();
-
-        for (actual, expected) in round_constants
-            .iter()
-            .flatten()
-            .zip(ROUND_CONSTANTS.iter().flatten())
-        {
-            assert_eq!(actual, expected);
-        }
+        fn verify_constants_helper<F: FieldExt>(
+            expected_round_constants: [[F; 3]; 64],
+            expected_mds: [[F; 3]; 3],
+            expected_mds_inv: [[F; 3]; 3],
+        ) {
+            let poseidon = P128Pow5T3::<F>::new(0);
+            let (round_constants, mds, mds_inv) = poseidon.constants();
+
+            for (actual, expected) in round_constants
+                .iter()
+                .flatten()
+                .zip(expected_round_constants.iter().flatten())
+            {
+                assert_eq!(actual, expected);
+            }
 
-        for (actual, expected) in mds.iter().flatten().zip(MDS.iter().flatten()) {
-            assert_eq!(actual, expected);
-        }
+            for (actual, expected) in mds.iter().flatten().zip(expected_mds.iter().flatten()) {
+                assert_eq!(actual, expected);
+            }
 
-        for (actual, expected) in mds_inv.iter().flatten().zip(MDS_INV.iter().flatten()) {
-            assert_eq!(actual, expected);
+            for (actual, expected) in mds_inv
+                .iter()
+                .flatten()
+                .zip(expected_mds_inv.iter().flatten())
+            {
+                assert_eq!(actual, expected);
+            }
         }
+
+        verify_constants_helper(fp::ROUND_CONSTANTS, fp::MDS, fp::MDS_INV);
+        verify_constants_helper(fq::ROUND_CONSTANTS, fq::MDS, fq::MDS_INV);
     }
 
     #[test]
@@ -158,22 +199,29 @@ mod tests {
             ]),
         ];
 
-        permute::<pallas::Base, P128Pow5T3<pallas::Base>, 3, 2>(&mut input, &MDS, &ROUND_CONSTANTS);
+        permute::<pallas::Base, P128Pow5T3<pallas::Base>, 3, 2>(
+            &mut input,
+            &fp::MDS,
+            &fp::ROUND_CONSTANTS,
+        );
         assert_eq!(input, expected_output);
     }
 
-    #[test]
-    fn permute_test_vectors() {
+    fn permute_test_vectors_helper<F: FieldExt<Repr = [u8; 32]>>(
+        test_vectors: Vec<test_vectors::PermuteTestVector>,
+    ) where
+        super::P128Pow5T3: Spec<F, 3, 2>,
+    {
         let (round_constants, mds, _) = super::P128Pow5T3.constants();
 
-        for tv in crate::primitive::test_vectors::permute() {
+        for tv in test_vectors {
             let mut state = [
-                pallas::Base::from_repr(tv.initial_state[0]).unwrap(),
-                pallas::Base::from_repr(tv.initial_state[1]).unwrap(),
-                pallas::Base::from_repr(tv.initial_state[2]).unwrap(),
+                F::from_repr(tv.initial_state[0]).unwrap(),
+                F::from_repr(tv.initial_state[1]).unwrap(),
+                F::from_repr(tv.initial_state[2]).unwrap(),
             ];
 
-            permute::<pallas::Base, super::P128Pow5T3, 3, 2>(&mut state, &mds, &round_constants);
+            permute::<F, super::P128Pow5T3, 3, 2>(&mut state, &mds, &round_constants);
 
             for (expected, actual) in tv.final_state.iter().zip(state.iter()) {
                 assert_eq!(&actual.to_repr(), expected);
@@ -182,11 +230,24 @@ mod tests {
     }
 
     #[test]
-    fn hash_test_vectors() {
-        for tv in crate::primitive::test_vectors::hash() {
+    fn permute_test_vectors() {
+        permute_test_vectors_helper::<pallas::Base>(test_vectors::fp::permute());
+    }
+
+    #[test]
+    fn permute_test_vectors_vesta() {
+        permute_test_vectors_helper::<vesta::Base>(test_vectors::fq::permute());
+    }
+
+    fn hash_test_vectors_helper<F: FieldExt<Repr = [u8; 32]>>(
+        test_vectors: Vec<test_vectors::HashTestVector>,
+    ) where
+        super::P128Pow5T3: Spec<F, 3, 2>,
+    {
+        for tv in test_vectors {
             let message = [
-                pallas::Base::from_repr(tv.input[0]).unwrap(),
-                pallas::Base::from_repr(tv.input[1]).unwrap(),
+                F::from_repr(tv.input[0]).unwrap(),
+                F::from_repr(tv.input[1]).unwrap(),
             ];
 
             let result = Hash::init(super::P128Pow5T3, ConstantLength).hash(message);
@@ -194,4 +255,14 @@ mod tests {
             assert_eq!(result.to_repr(), tv.output);
         }
     }
+
+    #[test]
+    fn hash_test_vectors() {
+        hash_test_vectors_helper::<pallas::Base>(test_vectors::fp::hash());
+    }
+
+    #[test]
+    fn hash_test_vectors_vesta() {
+        hash_test_vectors_helper::<vesta::Base>(test_vectors::fq::hash());
+    }
 }
@@ -1,6 +1,6 @@
 //! Decomposes an $n$-bit field element $\alpha$ into $W$ windows, each window
 //! being a $K$-bit word, using a running sum $z$.
-//! We constrain $K \leq 3$ for this helper.
+//! We constrain $K \leq 4$ for this helper.
 //!     $$\alpha = k_0 + (2^K) k_1 + (2^{2K}) k_2 + ... + (2^{(W-1)K}) k_{W-1}$$
 //!
 //! $z_0$ is initialized as $\alpha$. Each successive $z_{i+1}$ is computed as
@@ -57,7 +57,7 @@ impl<F: FieldExt + PrimeFieldBits, const WINDOW_NUM_BITS: usize>
     ///
     /// # Panics
     ///
-    /// Panics if WINDOW_NUM_BITS > 3.
+    /// Panics if WINDOW_NUM_BITS > 4.
     ///
     /// # Side-effects
     ///
@@ -67,7 +67,7 @@ impl<F: FieldExt + PrimeFieldBits, const WINDOW_NUM_BITS: usize>
         q_range_check: Selector,
         z: Column<Advice>,
     ) -> Self {
-        assert!(WINDOW_NUM_BITS <= 3);
+        assert!(WINDOW_NUM_BITS <= 4);
 
         meta.enable_equality(z.into());
 
@@ -2,6 +2,7 @@
 pub mod cond_swap;
 pub mod decompose_running_sum;
 pub mod lookup_range_check;
+pub mod non_native;
 pub mod utilities;
 
 pub use utilities::*;
@@ -3,7 +3,9 @@
 
 use halo2::{
     circuit::{Layouter, Region},
-    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, TableColumn},
+    plonk::{
+        Advice, Column, ConstraintSystem, Error, Expression, Selector, TableColumn, VirtualCells,
+    },
     poly::Rotation,
 };
 use pasta_curves::arithmetic::FieldExt;
@@ -126,6 +128,40 @@ impl<F: FieldExt + PrimeFieldBits, const K: usize> LookupRangeCheckConfig<F, K>
         config
     }
 
+    /// Constrains the word returned by `word` to be a `num_bits`-bit value on every
+    /// row where `q_short` is enabled. This is done by looking up both the word and
+    /// the word shifted left by `K - num_bits` bits in `table_idx`, so that gates
+    /// outside this helper can range-check short words in their own columns.
+    ///
+    /// `q_short` must be a complex selector.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` is larger than `K`.
+    pub fn configure_short_lookup(
+        &self,
+        meta: &mut ConstraintSystem<F>,
+        q_short: Selector,
+        num_bits: usize,
+        word: impl Fn(&mut VirtualCells<'_, F>) -> Expression<F>,
+    ) {
+        assert!(num_bits <= K);
+
+        meta.lookup(|meta| {
+            let q_short = meta.query_selector(q_short);
+            vec![(q_short * word(meta), self.table_idx)]
+        });
+
+        // A `K`-bit word is already constrained by the first lookup.
+        if num_bits < K {
+            meta.lookup(|meta| {
+                let q_short = meta.query_selector(q_short);
+                let shifted_word = word(meta) * F::from_u64(1 << (K - num_bits));
+                vec![(q_short * shifted_word, self.table_idx)]
+            });
+        }
+    }
+
     // Loads the values [0..2^K) into `table_idx`. This is only used in testing
     // for now, since the Sinsemilla chip provides a pre-loaded table in the
     // Orchard context.
@@ -387,7 +423,8 @@ mod tests {
     use halo2::{
         circuit::{Layouter, SimpleFloorPlanner},
         dev::{MockProver, VerifyFailure},
-        plonk::{Circuit, ConstraintSystem, Error},
+        plonk::{Advice, Circuit, Column, ConstraintSystem, Error, Selector},
+        poly::Rotation,
     };
     use pasta_curves::{arithmetic::FieldExt, pallas};
     use sinsemilla::primitive::{INV_TWO_POW_K, K};
@@ -636,4 +673,96 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn short_lookup() {
+        const NUM_BITS: usize = 4;
+
+        struct MyCircuit<F: FieldExt + PrimeFieldBits> {
+            word: Option<F>,
+        }
+
+        impl<F: FieldExt + PrimeFieldBits> Circuit<F> for MyCircuit<F> {
+            type Config = (LookupRangeCheckConfig<F, K>, Column<Advice>, Selector);
+            type FloorPlanner = SimpleFloorPlanner;
+
+            fn without_witnesses(&self) -> Self {
+                MyCircuit { word: None }
+            }
+
+            fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+                let running_sum = meta.advice_column();
+                let word = meta.advice_column();
+                let table_idx = meta.lookup_table_column();
+                let q_short = meta.complex_selector();
+
+                let config =
+                    LookupRangeCheckConfig::<F, K>::configure(meta, running_sum, table_idx);
+                config.configure_short_lookup(meta, q_short, NUM_BITS, |meta| {
+                    meta.query_advice(word, Rotation::cur())
+                });
+
+                (config, word, q_short)
+            }
+
+            fn synthesize(
+                &self,
+                (config, word, q_short): Self::Config,
+                mut layouter: impl Layouter<F>,
+            ) -> Result<(), Error> {
+                // Load table_idx
+                config.load(&mut layouter)?;
+
+                layouter.assign_region(
+                    || "word",
+                    |mut region| {
+                        q_short.enable(&mut region, 0)?;
+                        region.assign_advice(
+                            || "word",
+                            word,
+                            0,
+                            || self.word.ok_or(Error::SynthesisError),
+                        )?;
+                        Ok(())
+                    },
+                )
+            }
+        }
+
+        let check = |word: pallas::Base, failures: Vec<usize>| {
+            let circuit = MyCircuit { word: Some(word) };
+            let prover = MockProver::<pallas::Base>::run(11, &circuit, vec![]).unwrap();
+            let expected = failures
+                .into_iter()
+                .map(|lookup_index| VerifyFailure::Lookup {
+                    lookup_index,
+                    row: 0,
+                })
+                .collect::<Vec<_>>();
+            if expected.is_empty() {
+                assert_eq!(prover.verify(), Ok(()));
+            } else {
+                assert_eq!(prover.verify(), Err(expected));
+            }
+        };
+
+        // The config's own lookup has index 0. The word is looked up with index 1,
+        // and the shifted word with index 2.
+
+        // Words within `NUM_BITS`
+        check(pallas::Base::zero(), vec![]);
+        check(pallas::Base::from_u64((1 << NUM_BITS) - 1), vec![]);
+
+        // Word larger than `NUM_BITS` but within K bits
+        check(pallas::Base::from_u64(1 << NUM_BITS), vec![2]);
+
+        // Word which is not within `NUM_BITS`, but which has a shifted value within
+        // NUM_BITS
+        let shifted = pallas::Base::from_u64((1 << NUM_BITS) - 1);
+        let word = shifted
+            * pallas::Base::from_u64(1 << (K - NUM_BITS))
+                .invert()
+                .unwrap();
+        check(word, vec![1]);
+    }
 }
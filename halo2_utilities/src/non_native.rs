@@ -0,0 +1,1731 @@
+//! Arithmetic over a foreign field, whose elements are represented in the native
+//! field as limbs.
+//!
+//! An element $a$ of the foreign field $\mathbb{F}_m$ is represented by
+//! [`NUM_LIMBS`] little-endian limbs $a_0, \ldots, a_3$ of [`LIMB_BITS`] bits each,
+//! so that $a = \sum_i a_i \cdot 2^{70 i}$ as an integer. Every limb is
+//! range-constrained using [`LookupRangeCheckConfig`], so that $a < 2^{280}$; an
+//! element is not necessarily canonical (that is, less than $m$) unless it has been
+//! checked by [`NonNativeFieldInstructions::assert_canonical`] or produced by
+//! [`NonNativeFieldInstructions::reduce`].
+//!
+//! Each operation witnesses its result $r$ along with a quotient $q$, and checks an
+//! identity over the integers such as $a \cdot b = q \cdot m + r$. The identity is
+//! checked limb by limb, with a range-constrained carry between adjacent limbs, so
+//! that each limb equation holds over the integers as long as it does not wrap
+//! around the native modulus.
+
+use super::{bool_check, copy, transpose_option_array, CellValue, UtilitiesInstructions, Var};
+use crate::lookup_range_check::LookupRangeCheckConfig;
+
+use bigint::{U256, U512};
+use ff::PrimeFieldBits;
+use halo2::{
+    circuit::{Chip, Layouter, Region},
+    plonk::{Advice, Column, ConstraintSystem, Error, Expression, Selector, VirtualCells},
+    poly::Rotation,
+};
+use pasta_curves::arithmetic::FieldExt;
+use std::{array, convert::TryInto, fmt::Debug, marker::PhantomData};
+
+/// Number of limbs in the representation of a foreign field element.
+pub const NUM_LIMBS: usize = 4;
+
+/// Number of bits in each limb.
+pub const LIMB_BITS: usize = 70;
+
+/// Number of limbs in the quotient of a multiplication. Since both operands are
+/// less than $2^{280}$ and the modulus is larger than $2^{212}$, the quotient is
+/// less than $2^{348}$.
+const NUM_QUOTIENT_LIMBS: usize = 5;
+
+/// Number of bits in the lookup table used for range checks.
+const LOOKUP_K: usize = 10;
+
+/// Number of lookup words in a limb.
+const LIMB_WORDS: usize = LIMB_BITS / LOOKUP_K;
+
+/// Number of lookup words in an offset carry.
+const CARRY_WORDS: usize = 8;
+
+/// Offset added to each carry so that it is range-constrained as a non-negative
+/// integer. Every carry is less than $2^{75}$ in absolute value.
+const CARRY_OFFSET: u128 = 1 << 75;
+
+/// A prime field that is emulated in the native field.
+pub trait ForeignField: Copy + Clone + Debug + Eq {
+    /// The modulus $m$, which must lie in $(2^{212}, 2^{256})$.
+    const MODULUS: U256;
+}
+
+/// The base field of Vesta, which is the scalar field of Pallas.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct VestaBase;
+
+impl ForeignField for VestaBase {
+    const MODULUS: U256 = U256([
+        0x8c46_eb21_0000_0001,
+        0x2246_98fc_0994_a8dd,
+        0x0000_0000_0000_0000,
+        0x4000_0000_0000_0000,
+    ]);
+}
+
+/// The base field of Pallas, which is the scalar field of Vesta.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct PallasBase;
+
+impl ForeignField for PallasBase {
+    const MODULUS: U256 = U256([
+        0x992d_30ed_0000_0001,
+        0x2246_98fc_094c_f91b,
+        0x0000_0000_0000_0000,
+        0x4000_0000_0000_0000,
+    ]);
+}
+
+/// The base field of secp256k1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Base;
+
+impl ForeignField for Secp256k1Base {
+    const MODULUS: U256 = U256([
+        0xffff_fffe_ffff_fc2f,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+        0xffff_ffff_ffff_ffff,
+    ]);
+}
+
+/// The scalar field of secp256k1.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Secp256k1Scalar;
+
+impl ForeignField for Secp256k1Scalar {
+    const MODULUS: U256 = U256([
+        0xbfd2_5e8c_d036_4141,
+        0xbaae_dce6_af48_a03b,
+        0xffff_ffff_ffff_fffe,
+        0xffff_ffff_ffff_ffff,
+    ]);
+}
+
+/// The base field of BN254.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bn254Base;
+
+impl ForeignField for Bn254Base {
+    const MODULUS: U256 = U256([
+        0x3c20_8c16_d87c_fd47,
+        0x9781_6a91_6871_ca8d,
+        0xb850_45b6_8181_585d,
+        0x3064_4e72_e131_a029,
+    ]);
+}
+
+/// The set of circuit instructions required for arithmetic over a foreign field.
+pub trait NonNativeFieldInstructions<F: FieldExt, FF: ForeignField>:
+    UtilitiesInstructions<F> + Chip<F>
+{
+    /// A foreign field element.
+    type Element: Clone + Debug;
+
+    /// Witnesses an element, given as an integer less than $2^{256}$. The element
+    /// is not constrained to be canonical.
+    fn witness_element(
+        &self,
+        layouter: impl Layouter<F>,
+        value: Option<U256>,
+    ) -> Result<Self::Element, Error>;
+
+    /// Assigns a constant element.
+    fn constant_element(
+        &self,
+        layouter: impl Layouter<F>,
+        value: U256,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns `a + b`.
+    fn add(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns `a - b`.
+    fn sub(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns `a * b`.
+    fn mul(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns the canonical representative of `a`.
+    fn reduce(&self, layouter: impl Layouter<F>, a: &Self::Element)
+        -> Result<Self::Element, Error>;
+
+    /// Constrains `a` to be canonical, i.e. less than the modulus.
+    fn assert_canonical(&self, layouter: impl Layouter<F>, a: &Self::Element) -> Result<(), Error>;
+
+    /// Constrains `a` and `b` to be equal as foreign field elements, i.e. congruent
+    /// modulo the modulus.
+    fn assert_equal(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<(), Error>;
+
+    /// Returns `-a`.
+    fn neg(&self, layouter: impl Layouter<F>, a: &Self::Element) -> Result<Self::Element, Error>;
+
+    /// Returns `a / b`.
+    ///
+    /// The constraints cannot be satisfied if `b` is zero, unless `a` is also zero.
+    fn div(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns `a` if `cond` is 1, and `b` if `cond` is 0. `cond` is constrained to
+    /// be boolean.
+    fn select(
+        &self,
+        layouter: impl Layouter<F>,
+        cond: &Self::Var,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns a boolean variable that is 1 if `a` is zero modulo the modulus, and 0
+    /// otherwise.
+    fn is_zero(&self, layouter: impl Layouter<F>, a: &Self::Element) -> Result<Self::Var, Error>;
+
+    /// Returns the element whose integer value is the native field element `value`,
+    /// interpreted as an integer in $[0, p)$.
+    fn from_native(
+        &self,
+        layouter: impl Layouter<F>,
+        value: &Self::Var,
+    ) -> Result<Self::Element, Error>;
+
+    /// Returns the little-endian bits of the integer value of `a`, constraining it
+    /// to be less than $2^\mathsf{num\_bits}$.
+    fn to_bits(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &Self::Element,
+        num_bits: usize,
+    ) -> Result<Vec<Self::Var>, Error>;
+
+    /// Returns the element whose integer value has the little-endian boolean
+    /// decomposition `bits`, which must have at most `NUM_LIMBS * LIMB_BITS` bits.
+    fn from_bits(
+        &self,
+        layouter: impl Layouter<F>,
+        bits: &[Self::Var],
+    ) -> Result<Self::Element, Error>;
+}
+
+/// A foreign field element, represented as range-constrained limbs.
+#[derive(Clone, Debug)]
+pub struct NonNativeElement<F: FieldExt, FF: ForeignField> {
+    limbs: [CellValue<F>; NUM_LIMBS],
+    value: Option<U256>,
+    _marker: PhantomData<FF>,
+}
+
+impl<F: FieldExt, FF: ForeignField> NonNativeElement<F, FF> {
+    /// The cells containing the little-endian limbs of this element.
+    pub fn limbs(&self) -> &[CellValue<F>; NUM_LIMBS] {
+        &self.limbs
+    }
+
+    /// The integer value of this element, if known.
+    pub fn value(&self) -> Option<U256> {
+        self.value
+    }
+}
+
+/// An operand of the limb-wise linear check, which is either an element or a
+/// constant.
+#[derive(Clone, Copy, Debug)]
+enum Operand<'a, F: FieldExt> {
+    Cells(&'a [CellValue<F>; NUM_LIMBS]),
+    Constant([F; NUM_LIMBS]),
+}
+
+/// The source of a running sum over bits, which either decomposes a value into
+/// bits or recomposes a value from bits.
+#[derive(Clone, Copy, Debug)]
+enum BitsSource<'a, F: FieldExt> {
+    Value(&'a CellValue<F>, usize),
+    Bits(&'a [CellValue<F>]),
+}
+
+/// An operand of a selection between native variables, which is either a cell or
+/// a constant.
+#[derive(Clone, Copy, Debug)]
+enum NativeOperand<'a, F: FieldExt> {
+    Cell(&'a CellValue<F>),
+    Constant(F),
+}
+
+/// Configuration for the [`NonNativeFieldChip`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NonNativeFieldConfig<F: FieldExt + PrimeFieldBits> {
+    pub q_linear: Selector,
+    pub q_mul: Selector,
+    pub q_select: Selector,
+    pub q_is_zero: Selector,
+    pub q_from_native: Selector,
+    pub q_bits: Selector,
+    pub advices: [Column<Advice>; 5],
+    pub range_check: LookupRangeCheckConfig<F, LOOKUP_K>,
+}
+
+/// A chip implementing arithmetic over the foreign field `FF`.
+#[derive(Clone, Debug)]
+pub struct NonNativeFieldChip<F: FieldExt + PrimeFieldBits, FF: ForeignField> {
+    config: NonNativeFieldConfig<F>,
+    _marker: PhantomData<FF>,
+}
+
+impl<F: FieldExt + PrimeFieldBits, FF: ForeignField> Chip<F> for NonNativeFieldChip<F, FF> {
+    type Config = NonNativeFieldConfig<F>;
+    type Loaded = ();
+
+    fn config(&self) -> &Self::Config {
+        &self.config
+    }
+
+    fn loaded(&self) -> &Self::Loaded {
+        &()
+    }
+}
+
+impl<F: FieldExt + PrimeFieldBits, FF: ForeignField> UtilitiesInstructions<F>
+    for NonNativeFieldChip<F, FF>
+{
+    type Var = CellValue<F>;
+}
+
+impl<F: FieldExt + PrimeFieldBits, FF: ForeignField> NonNativeFieldChip<F, FF> {
+    /// Configures this chip for use in a circuit.
+    ///
+    /// # Side-effects
+    ///
+    /// All columns in `advices` will be equality-enabled.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the modulus of `FF` is not larger than $2^{212}$, or if the native
+    /// field is smaller than 253 bits.
+    pub fn configure(
+        meta: &mut ConstraintSystem<F>,
+        advices: [Column<Advice>; 5],
+        range_check: LookupRangeCheckConfig<F, LOOKUP_K>,
+    ) -> NonNativeFieldConfig<F> {
+        // The quotient of a multiplication must fit in its limbs.
+        assert!(FF::MODULUS.bits() > 212);
+        // The limb equations must not wrap around the native modulus.
+        assert!(F::NUM_BITS >= 253);
+
+        for column in &advices {
+            meta.enable_equality((*column).into());
+        }
+
+        let config = NonNativeFieldConfig {
+            q_linear: meta.selector(),
+            q_mul: meta.selector(),
+            q_select: meta.selector(),
+            q_is_zero: meta.selector(),
+            q_from_native: meta.selector(),
+            q_bits: meta.selector(),
+            advices,
+            range_check,
+        };
+
+        let modulus = modulus_limbs::<F, FF>();
+        let two_pow_limb_bits = Expression::Constant(F::from_u128(1 << LIMB_BITS));
+        let carry = |meta: &mut VirtualCells<F>, column: Column<Advice>, k: usize| {
+            meta.query_advice(column, Rotation(k as i32))
+                - Expression::Constant(F::from_u128(CARRY_OFFSET))
+        };
+
+        // Checks x + y = q⋅m + z over the integers, where q is a single limb.
+        //
+        // ```text
+        // | x_0 | y_0 | z_0 | carry_0 | q | q_linear
+        // | x_1 | y_1 | z_1 | carry_1 |   |
+        // | x_2 | y_2 | z_2 | carry_2 |   |
+        // | x_3 | y_3 | z_3 |         |   |
+        // ```
+        meta.create_gate("Non-native linear check", |meta| {
+            let q_linear = meta.query_selector(config.q_linear);
+            let q = meta.query_advice(config.advices[4], Rotation::cur());
+
+            (0..NUM_LIMBS)
+                .map(|k| {
+                    let x = meta.query_advice(config.advices[0], Rotation(k as i32));
+                    let y = meta.query_advice(config.advices[1], Rotation(k as i32));
+                    let z = meta.query_advice(config.advices[2], Rotation(k as i32));
+
+                    // x_k + y_k - q⋅m_k - z_k + carry_{k-1} - 2^70⋅carry_k = 0
+                    let mut poly = x + y - q.clone() * Expression::Constant(modulus[k]) - z;
+                    if k > 0 {
+                        poly = poly + carry(meta, config.advices[3], k - 1);
+                    }
+                    if k < NUM_LIMBS - 1 {
+                        poly = poly - carry(meta, config.advices[3], k) * two_pow_limb_bits.clone();
+                    }
+                    poly
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(move |poly| q_linear.clone() * poly)
+        });
+
+        // Checks a⋅b = q⋅m + r over the integers.
+        //
+        // ```text
+        // | a_0 | b_0 | q_0 | r_0 | carry_0 | q_mul
+        // | a_1 | b_1 | q_1 | r_1 | carry_1 |
+        // | a_2 | b_2 | q_2 | r_2 | carry_2 |
+        // | a_3 | b_3 | q_3 | r_3 | carry_3 |
+        // |     |     | q_4 |     | carry_4 |
+        // |     |     |     |     | carry_5 |
+        // |     |     |     |     | carry_6 |
+        // ```
+        meta.create_gate("Non-native multiplication check", |meta| {
+            let q_mul = meta.query_selector(config.q_mul);
+
+            let query = |meta: &mut VirtualCells<F>, column: Column<Advice>, len: usize| {
+                (0..len)
+                    .map(|i| meta.query_advice(column, Rotation(i as i32)))
+                    .collect::<Vec<_>>()
+            };
+            let a = query(meta, config.advices[0], NUM_LIMBS);
+            let b = query(meta, config.advices[1], NUM_LIMBS);
+            let q = query(meta, config.advices[2], NUM_QUOTIENT_LIMBS);
+            let r = query(meta, config.advices[3], NUM_LIMBS);
+
+            let num_positions = NUM_QUOTIENT_LIMBS + NUM_LIMBS - 1;
+            (0..num_positions)
+                .map(|k| {
+                    let mut poly = Expression::Constant(F::zero());
+                    // Σ_{i+j=k} a_i⋅b_j
+                    for i in 0..NUM_LIMBS {
+                        if k >= i && k - i < NUM_LIMBS {
+                            poly = poly + a[i].clone() * b[k - i].clone();
+                        }
+                    }
+                    // - Σ_{i+j=k} q_i⋅m_j
+                    for i in 0..NUM_QUOTIENT_LIMBS {
+                        if k >= i && k - i < NUM_LIMBS {
+                            poly = poly - q[i].clone() * Expression::Constant(modulus[k - i]);
+                        }
+                    }
+                    // - r_k
+                    if k < NUM_LIMBS {
+                        poly = poly - r[k].clone();
+                    }
+                    // + carry_{k-1} - 2^70⋅carry_k
+                    if k > 0 {
+                        poly = poly + carry(meta, config.advices[4], k - 1);
+                    }
+                    if k < num_positions - 1 {
+                        poly = poly - carry(meta, config.advices[4], k) * two_pow_limb_bits.clone();
+                    }
+                    poly
+                })
+                .collect::<Vec<_>>()
+                .into_iter()
+                .map(move |poly| q_mul.clone() * poly)
+        });
+
+        // Selects r = cond ? a : b, one limb (or native variable) per row.
+        //
+        // ```text
+        // | a | b | r | cond | q_select
+        // ```
+        meta.create_gate("Non-native selection", |meta| {
+            let q_select = meta.query_selector(config.q_select);
+            let a = meta.query_advice(config.advices[0], Rotation::cur());
+            let b = meta.query_advice(config.advices[1], Rotation::cur());
+            let r = meta.query_advice(config.advices[2], Rotation::cur());
+            let cond = meta.query_advice(config.advices[3], Rotation::cur());
+
+            // r = cond⋅(a - b) + b
+            let select_check = r - (cond.clone() * (a - b.clone()) + b);
+
+            array::IntoIter::new([bool_check(cond), select_check])
+                .map(move |poly| q_select.clone() * poly)
+        });
+
+        // Checks that z = 1 if the limbs r_k of a canonical element are all zero, and
+        // z = 0 otherwise. Since each limb is non-negative and less than 2^70, the
+        // limbs are all zero exactly when their sum s is zero.
+        //
+        // ```text
+        // | r_0 | r_1 | r_2 | r_3 | inv | q_is_zero
+        // |  z  |     |     |     |     |
+        // ```
+        meta.create_gate("Non-native is zero", |meta| {
+            let q_is_zero = meta.query_selector(config.q_is_zero);
+            let s = (0..NUM_LIMBS)
+                .map(|k| meta.query_advice(config.advices[k], Rotation::cur()))
+                .fold(Expression::Constant(F::zero()), |acc, limb| acc + limb);
+            let inv = meta.query_advice(config.advices[4], Rotation::cur());
+            let z = meta.query_advice(config.advices[0], Rotation::next());
+
+            // s⋅inv = 1 - z, s⋅z = 0
+            let one = Expression::Constant(F::one());
+            array::IntoIter::new([s.clone() * inv - (one - z.clone()), s * z])
+                .map(move |poly| q_is_zero.clone() * poly)
+        });
+
+        // Checks that v = Σ l_k⋅2^{70k} in the native field.
+        //
+        // ```text
+        // | l_0 | l_1 | l_2 | l_3 | v | q_from_native
+        // ```
+        meta.create_gate("Non-native from native", |meta| {
+            let q_from_native = meta.query_selector(config.q_from_native);
+            let v = meta.query_advice(config.advices[4], Rotation::cur());
+            let sum = (0..NUM_LIMBS)
+                .rev()
+                .fold(Expression::Constant(F::zero()), |acc, k| {
+                    acc * two_pow_limb_bits.clone()
+                        + meta.query_advice(config.advices[k], Rotation::cur())
+                });
+
+            vec![q_from_native * (sum - v)]
+        });
+
+        // Running sum over bits, z_i = 2⋅z_{i+1} + b_i.
+        //
+        // ```text
+        // | z_i     | b_i | q_bits
+        // | z_{i+1} |     |
+        // ```
+        meta.create_gate("Non-native bits", |meta| {
+            let q_bits = meta.query_selector(config.q_bits);
+            let z_cur = meta.query_advice(config.advices[0], Rotation::cur());
+            let z_next = meta.query_advice(config.advices[0], Rotation::next());
+            let bit = meta.query_advice(config.advices[1], Rotation::cur());
+
+            let two = Expression::Constant(F::from_u64(2));
+            let running_sum_check = z_cur - z_next * two - bit.clone();
+
+            array::IntoIter::new([bool_check(bit), running_sum_check])
+                .map(move |poly| q_bits.clone() * poly)
+        });
+
+        config
+    }
+
+    pub fn construct(config: NonNativeFieldConfig<F>) -> Self {
+        NonNativeFieldChip {
+            config,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Witnesses `N` limbs, range-constraining each of them to `num_words` lookup
+    /// words.
+    fn range_checked<const N: usize>(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        values: [Option<F>; N],
+        num_words: usize,
+    ) -> Result<[CellValue<F>; N], Error> {
+        let cells = values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                self.config
+                    .range_check
+                    .witness_check(
+                        layouter.namespace(|| format!("limb {}", i)),
+                        *value,
+                        num_words,
+                        true,
+                    )
+                    .map(|zs| zs[0])
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(cells.try_into().unwrap())
+    }
+
+    /// Constrains `x + y = q⋅m + z` over the integers, where `q` is a single limb
+    /// (or zero if `None`).
+    fn linear_check(
+        &self,
+        mut layouter: impl Layouter<F>,
+        (x, y, z): (Operand<F>, Operand<F>, Operand<F>),
+        q: Option<CellValue<F>>,
+    ) -> Result<(), Error> {
+        let values = |operand: &Operand<F>| -> Option<[F; NUM_LIMBS]> {
+            match operand {
+                Operand::Cells(cells) => {
+                    let values = cells
+                        .iter()
+                        .map(|cell| cell.value())
+                        .collect::<Option<Vec<_>>>();
+                    values.map(|values| values.try_into().unwrap())
+                }
+                Operand::Constant(limbs) => Some(*limbs),
+            }
+        };
+        let q_value = q.map_or(Some(F::zero()), |q| q.value());
+
+        // carry_k = (x_k + y_k - q⋅m_k - z_k + carry_{k-1}) / 2^70
+        let carries = {
+            let modulus = modulus_limbs::<F, FF>();
+            let inv_two_pow_limb_bits = F::from_u128(1 << LIMB_BITS).invert().unwrap();
+            values(&x)
+                .zip(values(&y))
+                .zip(values(&z))
+                .zip(q_value)
+                .map(|(((x, y), z), q)| {
+                    let mut carries = [F::zero(); NUM_LIMBS - 1];
+                    let mut carry = F::zero();
+                    for k in 0..(NUM_LIMBS - 1) {
+                        carry =
+                            (x[k] + y[k] - q * modulus[k] - z[k] + carry) * inv_two_pow_limb_bits;
+                        carries[k] = carry + F::from_u128(CARRY_OFFSET);
+                    }
+                    carries
+                })
+        };
+        let carries =
+            self.range_checked(&mut layouter, transpose_option_array(carries), CARRY_WORDS)?;
+
+        layouter.assign_region(
+            || "non-native linear check",
+            |mut region| {
+                self.config.q_linear.enable(&mut region, 0)?;
+
+                for (operand, column) in [x, y, z].iter().zip(self.config.advices[..3].iter()) {
+                    assign_operand(&mut region, operand, *column)?;
+                }
+                for (k, carry) in carries.iter().enumerate() {
+                    copy(&mut region, || "carry", self.config.advices[3], k, carry)?;
+                }
+                match q {
+                    Some(q) => {
+                        copy(&mut region, || "q", self.config.advices[4], 0, &q)?;
+                    }
+                    None => {
+                        region.assign_advice_from_constant(
+                            || "q = 0",
+                            self.config.advices[4],
+                            0,
+                            F::zero(),
+                        )?;
+                    }
+                }
+
+                Ok(())
+            },
+        )
+    }
+
+    /// Witnesses a range-constrained element with the given limbs.
+    fn element(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<U256>,
+    ) -> Result<NonNativeElement<F, FF>, Error> {
+        let limbs = value.map(|value| to_limbs::<F, NUM_LIMBS>(U512::from(value)));
+        let limbs = self.range_checked(layouter, transpose_option_array(limbs), LIMB_WORDS)?;
+        Ok(NonNativeElement {
+            limbs,
+            value,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Witnesses a single-limb quotient.
+    fn quotient(
+        &self,
+        layouter: &mut impl Layouter<F>,
+        value: Option<U512>,
+    ) -> Result<CellValue<F>, Error> {
+        let limb = value.map(|value| to_limbs::<F, 1>(value)[0]);
+        self.range_checked(layouter, [limb], LIMB_WORDS)
+            .map(|cells| cells[0])
+    }
+
+    /// Constrains the integer value of `a` to be at most `bound`.
+    fn assert_at_most(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &NonNativeElement<F, FF>,
+        bound: U256,
+    ) -> Result<(), Error> {
+        // a + d = bound, where d is range-constrained to be non-negative. If
+        // a > bound, the witnessed d wraps around and the check fails.
+        let d = a.value.map(|a| bound.overflowing_sub(a).0);
+        let d = self.element(&mut layouter, d)?;
+
+        self.linear_check(
+            layouter.namespace(|| "a + d = bound"),
+            (
+                Operand::Cells(&a.limbs),
+                Operand::Cells(&d.limbs),
+                Operand::Constant(to_limbs::<F, NUM_LIMBS>(U512::from(bound))),
+            ),
+            None,
+        )
+    }
+
+    /// Assigns the running sum $z_i = 2 z_{i+1} + b_i$ over bits $b_i$, with the
+    /// final $z$ constrained to be zero. Returns $z_0$ and the bits.
+    fn running_sum_bits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        source: BitsSource<F>,
+    ) -> Result<(CellValue<F>, Vec<CellValue<F>>), Error> {
+        let bit_values: Vec<Option<F>> = match source {
+            BitsSource::Value(value, num_bits) => (0..num_bits)
+                .map(|i| value.value().map(|value| bit(value, i)))
+                .collect(),
+            BitsSource::Bits(bits) => bits.iter().map(|bit| bit.value()).collect(),
+        };
+        let num_bits = bit_values.len();
+        assert!(num_bits > 0);
+
+        layouter.assign_region(
+            || "running sum over bits",
+            |mut region| {
+                let mut bits = Vec::with_capacity(num_bits);
+                for (i, value) in bit_values.iter().enumerate() {
+                    self.config.q_bits.enable(&mut region, i)?;
+                    let bit = match source {
+                        BitsSource::Value(..) => {
+                            let cell = region.assign_advice(
+                                || format!("b_{}", i),
+                                self.config.advices[1],
+                                i,
+                                || value.ok_or(Error::SynthesisError),
+                            )?;
+                            CellValue::new(cell, *value)
+                        }
+                        BitsSource::Bits(source) => copy(
+                            &mut region,
+                            || format!("b_{}", i),
+                            self.config.advices[1],
+                            i,
+                            &source[i],
+                        )?,
+                    };
+                    bits.push(bit);
+                }
+
+                // z_n = 0
+                region.assign_advice_from_constant(
+                    || "z_n",
+                    self.config.advices[0],
+                    num_bits,
+                    F::zero(),
+                )?;
+
+                // z_i = 2⋅z_{i+1} + b_i
+                let mut z = Some(F::zero());
+                let mut z_0 = None;
+                for i in (0..num_bits).rev() {
+                    z = z.zip(bit_values[i]).map(|(z, bit)| z.double() + bit);
+                    let cell = match source {
+                        BitsSource::Value(value, _) if i == 0 => {
+                            copy(&mut region, || "z_0", self.config.advices[0], 0, value)?
+                        }
+                        _ => {
+                            let cell = region.assign_advice(
+                                || format!("z_{}", i),
+                                self.config.advices[0],
+                                i,
+                                || z.ok_or(Error::SynthesisError),
+                            )?;
+                            CellValue::new(cell, z)
+                        }
+                    };
+                    if i == 0 {
+                        z_0 = Some(cell);
+                    }
+                }
+
+                Ok((z_0.unwrap(), bits))
+            },
+        )
+    }
+
+    /// Returns `a` if `cond` is 1, and `b` if `cond` is 0, for native variables.
+    fn select_native(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cond: &CellValue<F>,
+        a: NativeOperand<F>,
+        b: NativeOperand<F>,
+    ) -> Result<CellValue<F>, Error> {
+        layouter.assign_region(
+            || "select native",
+            |mut region| {
+                self.config.q_select.enable(&mut region, 0)?;
+
+                let mut values = vec![];
+                for (operand, column) in [a, b].iter().zip(self.config.advices.iter()) {
+                    let value = match operand {
+                        NativeOperand::Cell(cell) => {
+                            copy(&mut region, || "operand", *column, 0, cell)?.value()
+                        }
+                        NativeOperand::Constant(constant) => {
+                            region.assign_advice_from_constant(
+                                || "operand",
+                                *column,
+                                0,
+                                *constant,
+                            )?;
+                            Some(*constant)
+                        }
+                    };
+                    values.push(value);
+                }
+                copy(&mut region, || "cond", self.config.advices[3], 0, cond)?;
+
+                let r = cond
+                    .value()
+                    .zip(values[0])
+                    .zip(values[1])
+                    .map(|((cond, a), b)| if cond == F::one() { a } else { b });
+                let cell = region.assign_advice(
+                    || "r",
+                    self.config.advices[2],
+                    0,
+                    || r.ok_or(Error::SynthesisError),
+                )?;
+                Ok(CellValue::new(cell, r))
+            },
+        )
+    }
+
+    /// Returns `a ∧ b` for boolean variables `a` and `b`.
+    pub fn and(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &CellValue<F>,
+        b: &CellValue<F>,
+    ) -> Result<CellValue<F>, Error> {
+        self.select_native(
+            layouter,
+            a,
+            NativeOperand::Cell(b),
+            NativeOperand::Constant(F::zero()),
+        )
+    }
+
+    /// Returns `a ∨ b` for boolean variables `a` and `b`.
+    pub fn or(
+        &self,
+        layouter: impl Layouter<F>,
+        a: &CellValue<F>,
+        b: &CellValue<F>,
+    ) -> Result<CellValue<F>, Error> {
+        self.select_native(
+            layouter,
+            a,
+            NativeOperand::Constant(F::one()),
+            NativeOperand::Cell(b),
+        )
+    }
+
+    /// Returns `¬a` for a boolean variable `a`.
+    pub fn not(&self, layouter: impl Layouter<F>, a: &CellValue<F>) -> Result<CellValue<F>, Error> {
+        self.select_native(
+            layouter,
+            a,
+            NativeOperand::Constant(F::zero()),
+            NativeOperand::Constant(F::one()),
+        )
+    }
+
+    /// Returns the little-endian bits of the native variable `value`, constraining
+    /// it to be less than $2^\mathsf{num\_bits}$.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bits` is not less than the bit length of the native modulus,
+    /// for which the decomposition would not be unique.
+    pub fn native_to_bits(
+        &self,
+        layouter: impl Layouter<F>,
+        value: &CellValue<F>,
+        num_bits: usize,
+    ) -> Result<Vec<CellValue<F>>, Error> {
+        assert!(num_bits < F::NUM_BITS as usize);
+        self.running_sum_bits(layouter, BitsSource::Value(value, num_bits))
+            .map(|(_, bits)| bits)
+    }
+
+    /// Returns the boolean variable `b` such that `sign = 1 - 2⋅b`, constraining
+    /// `sign` to be ±1.
+    pub fn sign_to_bit(
+        &self,
+        mut layouter: impl Layouter<F>,
+        sign: &CellValue<F>,
+    ) -> Result<CellValue<F>, Error> {
+        layouter.assign_region(
+            || "sign to bit",
+            |mut region| {
+                self.config.q_select.enable(&mut region, 0)?;
+
+                // sign = b ? -1 : 1
+                region.assign_advice_from_constant(
+                    || "-1",
+                    self.config.advices[0],
+                    0,
+                    -F::one(),
+                )?;
+                region.assign_advice_from_constant(|| "1", self.config.advices[1], 0, F::one())?;
+                copy(&mut region, || "sign", self.config.advices[2], 0, sign)?;
+
+                let b = sign.value().map(|sign| {
+                    if sign == -F::one() {
+                        F::one()
+                    } else {
+                        F::zero()
+                    }
+                });
+                let cell = region.assign_advice(
+                    || "b",
+                    self.config.advices[3],
+                    0,
+                    || b.ok_or(Error::SynthesisError),
+                )?;
+                Ok(CellValue::new(cell, b))
+            },
+        )
+    }
+}
+
+impl<F: FieldExt + PrimeFieldBits, FF: ForeignField> NonNativeFieldInstructions<F, FF>
+    for NonNativeFieldChip<F, FF>
+{
+    type Element = NonNativeElement<F, FF>;
+
+    fn witness_element(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: Option<U256>,
+    ) -> Result<Self::Element, Error> {
+        self.element(&mut layouter, value)
+    }
+
+    fn constant_element(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: U256,
+    ) -> Result<Self::Element, Error> {
+        let limbs = to_limbs::<F, NUM_LIMBS>(U512::from(value));
+        let limbs = layouter.assign_region(
+            || "non-native constant",
+            |mut region| {
+                let cells = limbs
+                    .iter()
+                    .enumerate()
+                    .map(|(k, limb)| {
+                        region
+                            .assign_advice_from_constant(
+                                || format!("limb {}", k),
+                                self.config.advices[0],
+                                k,
+                                *limb,
+                            )
+                            .map(|cell| CellValue::new(cell, Some(*limb)))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                Ok(cells.try_into().unwrap())
+            },
+        )?;
+        Ok(NonNativeElement {
+            limbs,
+            value: Some(value),
+            _marker: PhantomData,
+        })
+    }
+
+    fn add(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // a + b = q⋅m + r
+        let modulus = U512::from(FF::MODULUS);
+        let sum = a
+            .value
+            .zip(b.value)
+            .map(|(a, b)| U512::from(a) + U512::from(b));
+
+        let r = self.element(&mut layouter, sum.map(|sum| narrow(sum % modulus)))?;
+        let q = self.quotient(&mut layouter, sum.map(|sum| sum / modulus))?;
+        self.linear_check(
+            layouter.namespace(|| "a + b = q⋅m + r"),
+            (
+                Operand::Cells(&a.limbs),
+                Operand::Cells(&b.limbs),
+                Operand::Cells(&r.limbs),
+            ),
+            Some(q),
+        )?;
+
+        Ok(r)
+    }
+
+    fn sub(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // r + b = q⋅m + a, where q is the smallest non-negative integer such that
+        // r = a - b + q⋅m is non-negative.
+        let modulus = U512::from(FF::MODULUS);
+        let r_q = a.value.zip(b.value).map(|(a, b)| {
+            let (a, b) = (U512::from(a), U512::from(b));
+            if a >= b {
+                (a - b, U512::zero())
+            } else {
+                let q = (b - a + modulus - U512::one()) / modulus;
+                (a + q * modulus - b, q)
+            }
+        });
+
+        let r = self.element(&mut layouter, r_q.map(|(r, _)| narrow(r)))?;
+        let q = self.quotient(&mut layouter, r_q.map(|(_, q)| q))?;
+        self.linear_check(
+            layouter.namespace(|| "r + b = q⋅m + a"),
+            (
+                Operand::Cells(&r.limbs),
+                Operand::Cells(&b.limbs),
+                Operand::Cells(&a.limbs),
+            ),
+            Some(q),
+        )?;
+
+        Ok(r)
+    }
+
+    fn mul(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // a⋅b = q⋅m + r
+        let modulus = U512::from(FF::MODULUS);
+        let product = a.value.zip(b.value).map(|(a, b)| a.full_mul(b));
+
+        let r = self.element(&mut layouter, product.map(|p| narrow(p % modulus)))?;
+        let q = {
+            let limbs = product.map(|p| to_limbs::<F, NUM_QUOTIENT_LIMBS>(p / modulus));
+            self.range_checked(&mut layouter, transpose_option_array(limbs), LIMB_WORDS)?
+        };
+
+        // carry_k = (Σ_{i+j=k} a_i⋅b_j - Σ_{i+j=k} q_i⋅m_j - r_k + carry_{k-1}) / 2^70
+        let carries = {
+            let modulus = modulus_limbs::<F, FF>();
+            let inv_two_pow_limb_bits = F::from_u128(1 << LIMB_BITS).invert().unwrap();
+            let values = |cells: &[CellValue<F>]| -> Option<Vec<F>> {
+                cells.iter().map(|cell| cell.value()).collect()
+            };
+            values(&a.limbs)
+                .zip(values(&b.limbs))
+                .zip(values(&q))
+                .zip(values(&r.limbs))
+                .map(|(((a, b), q), r)| {
+                    let mut carries = [F::zero(); NUM_QUOTIENT_LIMBS + NUM_LIMBS - 2];
+                    let mut carry = F::zero();
+                    for (k, offset_carry) in carries.iter_mut().enumerate() {
+                        let mut t = carry;
+                        for i in 0..NUM_LIMBS {
+                            if k >= i && k - i < NUM_LIMBS {
+                                t += a[i] * b[k - i];
+                            }
+                        }
+                        for (i, q_i) in q.iter().enumerate() {
+                            if k >= i && k - i < NUM_LIMBS {
+                                t -= *q_i * modulus[k - i];
+                            }
+                        }
+                        if k < NUM_LIMBS {
+                            t -= r[k];
+                        }
+                        carry = t * inv_two_pow_limb_bits;
+                        *offset_carry = carry + F::from_u128(CARRY_OFFSET);
+                    }
+                    carries
+                })
+        };
+        let carries =
+            self.range_checked(&mut layouter, transpose_option_array(carries), CARRY_WORDS)?;
+
+        layouter.assign_region(
+            || "non-native multiplication check",
+            |mut region| {
+                self.config.q_mul.enable(&mut region, 0)?;
+
+                let columns = &self.config.advices;
+                for (cells, column) in [
+                    &a.limbs[..],
+                    &b.limbs[..],
+                    &q[..],
+                    &r.limbs[..],
+                    &carries[..],
+                ]
+                .iter()
+                .zip(columns.iter())
+                {
+                    for (offset, cell) in cells.iter().enumerate() {
+                        copy(&mut region, || "limb", *column, offset, cell)?;
+                    }
+                }
+
+                Ok(())
+            },
+        )?;
+
+        Ok(r)
+    }
+
+    fn reduce(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // a = q⋅m + r
+        let modulus = U512::from(FF::MODULUS);
+        let a_value = a.value.map(U512::from);
+
+        let r = self.element(&mut layouter, a_value.map(|a| narrow(a % modulus)))?;
+        let q = self.quotient(&mut layouter, a_value.map(|a| a / modulus))?;
+        self.linear_check(
+            layouter.namespace(|| "a + 0 = q⋅m + r"),
+            (
+                Operand::Cells(&a.limbs),
+                Operand::Constant([F::zero(); NUM_LIMBS]),
+                Operand::Cells(&r.limbs),
+            ),
+            Some(q),
+        )?;
+        self.assert_canonical(layouter.namespace(|| "r < m"), &r)?;
+
+        Ok(r)
+    }
+
+    fn assert_canonical(&self, layouter: impl Layouter<F>, a: &Self::Element) -> Result<(), Error> {
+        self.assert_at_most(layouter, a, FF::MODULUS - U256::one())
+    }
+
+    fn assert_equal(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<(), Error> {
+        // d = a - b = q⋅m
+        let d = self.sub(layouter.namespace(|| "a - b"), a, b)?;
+
+        let modulus = U512::from(FF::MODULUS);
+        let q = self.quotient(&mut layouter, d.value.map(|d| U512::from(d) / modulus))?;
+        self.linear_check(
+            layouter.namespace(|| "d + 0 = q⋅m + 0"),
+            (
+                Operand::Cells(&d.limbs),
+                Operand::Constant([F::zero(); NUM_LIMBS]),
+                Operand::Constant([F::zero(); NUM_LIMBS]),
+            ),
+            Some(q),
+        )
+    }
+
+    fn neg(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // r + a = q⋅m
+        let modulus = U512::from(FF::MODULUS);
+        let r_q = a.value.map(|a| {
+            let a = U512::from(a);
+            let r = (modulus - a % modulus) % modulus;
+            (r, (r + a) / modulus)
+        });
+
+        let r = self.element(&mut layouter, r_q.map(|(r, _)| narrow(r)))?;
+        let q = self.quotient(&mut layouter, r_q.map(|(_, q)| q))?;
+        self.linear_check(
+            layouter.namespace(|| "r + a = q⋅m + 0"),
+            (
+                Operand::Cells(&r.limbs),
+                Operand::Cells(&a.limbs),
+                Operand::Constant([F::zero(); NUM_LIMBS]),
+            ),
+            Some(q),
+        )?;
+
+        Ok(r)
+    }
+
+    fn div(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        // r⋅b = a
+        let r = a.value.zip(b.value).map(|(a, b)| {
+            let b_inv = invert_mod(b, FF::MODULUS).unwrap_or_else(U256::zero);
+            mul_mod(a, b_inv, FF::MODULUS)
+        });
+        let r = self.element(&mut layouter, r)?;
+
+        let product = self.mul(layouter.namespace(|| "r⋅b"), &r, b)?;
+        self.assert_equal(layouter.namespace(|| "r⋅b = a"), &product, a)?;
+
+        Ok(r)
+    }
+
+    fn select(
+        &self,
+        mut layouter: impl Layouter<F>,
+        cond: &Self::Var,
+        a: &Self::Element,
+        b: &Self::Element,
+    ) -> Result<Self::Element, Error> {
+        let value = cond
+            .value()
+            .zip(a.value)
+            .zip(b.value)
+            .map(|((cond, a), b)| if cond == F::one() { a } else { b });
+
+        let limbs = layouter.assign_region(
+            || "non-native selection",
+            |mut region| {
+                let mut limbs = Vec::with_capacity(NUM_LIMBS);
+                for k in 0..NUM_LIMBS {
+                    self.config.q_select.enable(&mut region, k)?;
+
+                    let a_k = copy(&mut region, || "a", self.config.advices[0], k, &a.limbs[k])?;
+                    let b_k = copy(&mut region, || "b", self.config.advices[1], k, &b.limbs[k])?;
+                    copy(&mut region, || "cond", self.config.advices[3], k, cond)?;
+
+                    let r = cond
+                        .value()
+                        .zip(a_k.value())
+                        .zip(b_k.value())
+                        .map(|((cond, a), b)| if cond == F::one() { a } else { b });
+                    let cell = region.assign_advice(
+                        || "r",
+                        self.config.advices[2],
+                        k,
+                        || r.ok_or(Error::SynthesisError),
+                    )?;
+                    limbs.push(CellValue::new(cell, r));
+                }
+                Ok(limbs.try_into().unwrap())
+            },
+        )?;
+
+        Ok(NonNativeElement {
+            limbs,
+            value,
+            _marker: PhantomData,
+        })
+    }
+
+    fn is_zero(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+    ) -> Result<Self::Var, Error> {
+        let r = self.reduce(layouter.namespace(|| "reduce"), a)?;
+
+        layouter.assign_region(
+            || "non-native is zero",
+            |mut region| {
+                self.config.q_is_zero.enable(&mut region, 0)?;
+
+                for (k, limb) in r.limbs.iter().enumerate() {
+                    copy(&mut region, || "limb", self.config.advices[k], 0, limb)?;
+                }
+
+                let s = r
+                    .limbs
+                    .iter()
+                    .map(|limb| limb.value())
+                    .fold(Some(F::zero()), |acc, limb| {
+                        acc.zip(limb).map(|(acc, limb)| acc + limb)
+                    });
+                let inv = s.map(|s| Option::<F>::from(s.invert()).unwrap_or_else(F::zero));
+                region.assign_advice(
+                    || "inv",
+                    self.config.advices[4],
+                    0,
+                    || inv.ok_or(Error::SynthesisError),
+                )?;
+
+                let z = s.map(|s| if s == F::zero() { F::one() } else { F::zero() });
+                let cell = region.assign_advice(
+                    || "z",
+                    self.config.advices[0],
+                    1,
+                    || z.ok_or(Error::SynthesisError),
+                )?;
+                Ok(CellValue::new(cell, z))
+            },
+        )
+    }
+
+    fn from_native(
+        &self,
+        mut layouter: impl Layouter<F>,
+        value: &Self::Var,
+    ) -> Result<Self::Element, Error> {
+        let a = self.element(
+            &mut layouter,
+            value
+                .value()
+                .map(|value| U256::from_little_endian(&value.to_bytes())),
+        )?;
+
+        // v = Σ l_k⋅2^{70k} (mod p)
+        layouter.assign_region(
+            || "non-native from native",
+            |mut region| {
+                self.config.q_from_native.enable(&mut region, 0)?;
+
+                for (k, limb) in a.limbs.iter().enumerate() {
+                    copy(&mut region, || "limb", self.config.advices[k], 0, limb)?;
+                }
+                copy(&mut region, || "v", self.config.advices[4], 0, value)?;
+
+                Ok(())
+            },
+        )?;
+
+        // The integer value of the limbs must be less than p, so that it is the
+        // unique representative of v.
+        let p_minus_one = U256::from_little_endian(&(-F::one()).to_bytes());
+        self.assert_at_most(layouter.namespace(|| "a < p"), &a, p_minus_one)?;
+
+        Ok(a)
+    }
+
+    fn to_bits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        a: &Self::Element,
+        num_bits: usize,
+    ) -> Result<Vec<Self::Var>, Error> {
+        assert!(num_bits <= NUM_LIMBS * LIMB_BITS);
+
+        let mut bits = Vec::with_capacity(num_bits);
+        for (k, limb) in a.limbs.iter().enumerate() {
+            let limb_bits = num_bits.saturating_sub(k * LIMB_BITS).min(LIMB_BITS);
+            if limb_bits == 0 {
+                layouter.assign_region(
+                    || format!("limb {} = 0", k),
+                    |mut region| {
+                        let cell = copy(&mut region, || "limb", self.config.advices[0], 0, limb)?;
+                        region.constrain_constant(cell.cell(), F::zero())
+                    },
+                )?;
+            } else {
+                let (_, decomposed) = self.running_sum_bits(
+                    layouter.namespace(|| format!("decompose limb {}", k)),
+                    BitsSource::Value(limb, limb_bits),
+                )?;
+                bits.extend(decomposed);
+            }
+        }
+
+        Ok(bits)
+    }
+
+    fn from_bits(
+        &self,
+        mut layouter: impl Layouter<F>,
+        bits: &[Self::Var],
+    ) -> Result<Self::Element, Error> {
+        assert!(bits.len() <= NUM_LIMBS * LIMB_BITS);
+
+        let mut limbs = Vec::with_capacity(NUM_LIMBS);
+        for k in 0..NUM_LIMBS {
+            let start = (k * LIMB_BITS).min(bits.len());
+            let end = ((k + 1) * LIMB_BITS).min(bits.len());
+            let limb = if start == end {
+                layouter.assign_region(
+                    || format!("limb {} = 0", k),
+                    |mut region| {
+                        let cell = region.assign_advice_from_constant(
+                            || "limb",
+                            self.config.advices[0],
+                            0,
+                            F::zero(),
+                        )?;
+                        Ok(CellValue::new(cell, Some(F::zero())))
+                    },
+                )?
+            } else {
+                self.running_sum_bits(
+                    layouter.namespace(|| format!("recompose limb {}", k)),
+                    BitsSource::Bits(&bits[start..end]),
+                )?
+                .0
+            };
+            limbs.push(limb);
+        }
+
+        let value = bits
+            .iter()
+            .enumerate()
+            .map(|(i, bit)| bit.value().map(|bit| (i, bit)))
+            .collect::<Option<Vec<_>>>()
+            .map(|bits| {
+                bits.into_iter()
+                    .filter(|(_, bit)| *bit == F::one())
+                    .fold(U256::zero(), |acc, (i, _)| acc | (U256::one() << i))
+            });
+
+        Ok(NonNativeElement {
+            limbs: limbs.try_into().unwrap(),
+            value,
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Returns `(a + b) mod m`.
+pub fn add_mod(a: U256, b: U256, m: U256) -> U256 {
+    narrow((U512::from(a) + U512::from(b)) % U512::from(m))
+}
+
+/// Returns `(a - b) mod m`.
+pub fn sub_mod(a: U256, b: U256, m: U256) -> U256 {
+    let m = U512::from(m);
+    narrow((U512::from(a) + m - U512::from(b) % m) % m)
+}
+
+/// Returns `(a * b) mod m`.
+pub fn mul_mod(a: U256, b: U256, m: U256) -> U256 {
+    narrow(a.full_mul(b) % U512::from(m))
+}
+
+/// Returns the inverse of `a` modulo the prime `m`, or `None` if `a` is zero
+/// modulo `m`.
+pub fn invert_mod(a: U256, m: U256) -> Option<U256> {
+    if (a % m).is_zero() {
+        return None;
+    }
+
+    // a^{m - 2} mod m
+    let exponent = m - U256::from(2u64);
+    let mut acc = U256::one();
+    for i in (0..exponent.bits()).rev() {
+        acc = mul_mod(acc, acc, m);
+        if exponent.bit(i) {
+            acc = mul_mod(acc, a, m);
+        }
+    }
+    Some(acc)
+}
+
+/// Returns the `i`-th little-endian bit of `value`.
+fn bit<F: FieldExt>(value: F, i: usize) -> F {
+    let bytes = value.to_bytes();
+    F::from_u64(((bytes[i / 8] >> (i % 8)) & 1) as u64)
+}
+
+/// Assigns the limbs of `operand` in `column`, at offsets `0..NUM_LIMBS`.
+fn assign_operand<F: FieldExt>(
+    region: &mut Region<'_, F>,
+    operand: &Operand<F>,
+    column: Column<Advice>,
+) -> Result<(), Error> {
+    for k in 0..NUM_LIMBS {
+        match operand {
+            Operand::Cells(cells) => {
+                copy(region, || format!("limb {}", k), column, k, &cells[k])?;
+            }
+            Operand::Constant(limbs) => {
+                region.assign_advice_from_constant(
+                    || format!("limb {}", k),
+                    column,
+                    k,
+                    limbs[k],
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// The limbs of the modulus of `FF`.
+fn modulus_limbs<F: FieldExt, FF: ForeignField>() -> [F; NUM_LIMBS] {
+    to_limbs(U512::from(FF::MODULUS))
+}
+
+/// Splits the low `N * LIMB_BITS` bits of `value` into `N` little-endian limbs.
+fn to_limbs<F: FieldExt, const N: usize>(value: U512) -> [F; N] {
+    let mask = (U512::one() << LIMB_BITS) - U512::one();
+    let mut limbs = [F::zero(); N];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        let word = (value >> (LIMB_BITS * i)) & mask;
+        *limb = F::from_u128((((word >> 64).low_u64() as u128) << 64) | word.low_u64() as u128);
+    }
+    limbs
+}
+
+/// Returns the low 256 bits of `value`.
+fn narrow(value: U512) -> U256 {
+    let mut bytes = [0u8; 64];
+    value.to_little_endian(&mut bytes);
+    U256::from_little_endian(&bytes[..32])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        add_mod, invert_mod, mul_mod, sub_mod, ForeignField, NonNativeElement, NonNativeFieldChip,
+        NonNativeFieldConfig, NonNativeFieldInstructions, PallasBase, Secp256k1Base, VestaBase,
+        LOOKUP_K,
+    };
+    use crate::{lookup_range_check::LookupRangeCheckConfig, UtilitiesInstructions, Var};
+
+    use bigint::U256;
+    use halo2::{
+        circuit::{Layouter, SimpleFloorPlanner},
+        dev::MockProver,
+        plonk::{Circuit, ConstraintSystem, Error},
+    };
+    use pasta_curves::{arithmetic::FieldExt, pallas};
+
+    fn random_element() -> U256 {
+        U256(rand::random::<[u64; 4]>()) % Secp256k1Base::MODULUS
+    }
+
+    struct MyCircuit {
+        a: Option<U256>,
+        b: Option<U256>,
+        // An element that is claimed to be canonical.
+        canonical: Option<U256>,
+        native: Option<pallas::Base>,
+    }
+
+    impl Circuit<pallas::Base> for MyCircuit {
+        type Config = NonNativeFieldConfig<pallas::Base>;
+        type FloorPlanner = SimpleFloorPlanner;
+
+        fn without_witnesses(&self) -> Self {
+            MyCircuit {
+                a: None,
+                b: None,
+                canonical: None,
+                native: None,
+            }
+        }
+
+        fn configure(meta: &mut ConstraintSystem<pallas::Base>) -> Self::Config {
+            let advices = [
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+                meta.advice_column(),
+            ];
+            let running_sum = meta.advice_column();
+            let table_idx = meta.lookup_table_column();
+            let constants = meta.fixed_column();
+            meta.enable_constant(constants);
+
+            let range_check = LookupRangeCheckConfig::<pallas::Base, LOOKUP_K>::configure(
+                meta,
+                running_sum,
+                table_idx,
+            );
+            NonNativeFieldChip::<pallas::Base, Secp256k1Base>::configure(meta, advices, range_check)
+        }
+
+        fn synthesize(
+            &self,
+            config: Self::Config,
+            mut layouter: impl Layouter<pallas::Base>,
+        ) -> Result<(), Error> {
+            config.range_check.load(&mut layouter)?;
+            let chip = NonNativeFieldChip::<pallas::Base, Secp256k1Base>::construct(config.clone());
+
+            let a = chip.witness_element(layouter.namespace(|| "a"), self.a)?;
+            let b = chip.witness_element(layouter.namespace(|| "b"), self.b)?;
+
+            let check = |result: &NonNativeElement<pallas::Base, Secp256k1Base>,
+                         op: fn(U256, U256, U256) -> U256| {
+                if let (Some(a), Some(b), Some(result)) = (self.a, self.b, result.value()) {
+                    assert_eq!(result, op(a, b, Secp256k1Base::MODULUS));
+                }
+            };
+
+            // a + b
+            let sum = chip.add(layouter.namespace(|| "a + b"), &a, &b)?;
+            check(&sum, add_mod);
+
+            // a - b and b - a
+            let difference = chip.sub(layouter.namespace(|| "a - b"), &a, &b)?;
+            check(&difference, sub_mod);
+            chip.sub(layouter.namespace(|| "b - a"), &b, &a)?;
+
+            // a * b
+            let product = chip.mul(layouter.namespace(|| "a * b"), &a, &b)?;
+            check(&product, mul_mod);
+
+            // a * b = b * a
+            let commuted = chip.mul(layouter.namespace(|| "b * a"), &b, &a)?;
+            chip.assert_equal(layouter.namespace(|| "a * b = b * a"), &product, &commuted)?;
+
+            // (a + b) - b = a
+            let a_again = chip.sub(layouter.namespace(|| "(a + b) - b"), &sum, &b)?;
+            chip.assert_equal(layouter.namespace(|| "(a + b) - b = a"), &a_again, &a)?;
+
+            // The largest element, and its reduction.
+            let max =
+                chip.constant_element(layouter.namespace(|| "2^256 - 1"), U256::max_value())?;
+            let reduced = chip.reduce(layouter.namespace(|| "reduce 2^256 - 1"), &max)?;
+            chip.assert_equal(layouter.namespace(|| "2^256 - 1"), &max, &reduced)?;
+            let max_squared = chip.mul(layouter.namespace(|| "(2^256 - 1)^2"), &max, &max)?;
+            chip.reduce(layouter.namespace(|| "reduce (2^256 - 1)^2"), &max_squared)?;
+
+            // -a
+            let minus_a = chip.neg(layouter.namespace(|| "-a"), &a)?;
+            check(&minus_a, |a, _, m| sub_mod(U256::zero(), a, m));
+
+            // a / b
+            let quotient = chip.div(layouter.namespace(|| "a / b"), &a, &b)?;
+            check(&quotient, |a, b, m| {
+                mul_mod(a, invert_mod(b, m).unwrap(), m)
+            });
+
+            // -a + a = 0
+            let zero = chip.add(layouter.namespace(|| "-a + a"), &minus_a, &a)?;
+            let zero_is_zero = chip.is_zero(layouter.namespace(|| "-a + a = 0"), &zero)?;
+            assert_eq!(zero_is_zero.value(), Some(pallas::Base::one()));
+
+            // a = 0 ?
+            let a_is_zero = chip.is_zero(layouter.namespace(|| "a = 0"), &a)?;
+            if let Some(a) = self.a {
+                let expected = if a.is_zero() {
+                    pallas::Base::one()
+                } else {
+                    pallas::Base::zero()
+                };
+                assert_eq!(a_is_zero.value(), Some(expected));
+            }
+
+            // Boolean operations
+            let a_is_nonzero = chip.not(layouter.namespace(|| "a ≠ 0"), &a_is_zero)?;
+            let both = chip.and(layouter.namespace(|| "∧"), &a_is_nonzero, &zero_is_zero)?;
+            let either = chip.or(layouter.namespace(|| "∨"), &a_is_zero, &zero_is_zero)?;
+            assert_eq!(both.value(), a_is_nonzero.value());
+            assert_eq!(either.value(), Some(pallas::Base::one()));
+
+            // a ≠ 0 ? a : b
+            let selected = chip.select(layouter.namespace(|| "select"), &a_is_nonzero, &a, &b)?;
+            check(&selected, |a, b, _| if a.is_zero() { b } else { a });
+
+            // Conversion from a native element, and to and from bits.
+            let native = chip.load_private(
+                layouter.namespace(|| "native"),
+                config.advices[0],
+                self.native,
+            )?;
+            let from_native = chip.from_native(layouter.namespace(|| "from native"), &native)?;
+            if let Some(native) = self.native {
+                assert_eq!(
+                    from_native.value(),
+                    Some(U256::from_little_endian(&native.to_bytes()))
+                );
+            }
+            let bits = chip.to_bits(layouter.namespace(|| "to bits"), &from_native, 255)?;
+            let from_bits = chip.from_bits(layouter.namespace(|| "from bits"), &bits)?;
+            assert_eq!(from_bits.value(), from_native.value());
+            chip.assert_equal(layouter.namespace(|| "bits"), &from_bits, &from_native)?;
+
+            let canonical =
+                chip.witness_element(layouter.namespace(|| "canonical"), self.canonical)?;
+            chip.assert_canonical(layouter.namespace(|| "canonical"), &canonical)
+        }
+    }
+
+    #[test]
+    fn non_native_arithmetic() {
+        let modulus = Secp256k1Base::MODULUS;
+        for (a, b, canonical) in [
+            (random_element(), random_element(), random_element()),
+            (U256::zero(), modulus - U256::one(), modulus - U256::one()),
+            (modulus - U256::one(), modulus - U256::one(), U256::zero()),
+        ]
+        .iter()
+        {
+            let circuit = MyCircuit {
+                a: Some(*a),
+                b: Some(*b),
+                canonical: Some(*canonical),
+                native: Some(-pallas::Base::one()),
+            };
+            let prover = MockProver::<pallas::Base>::run(13, &circuit, vec![]).unwrap();
+            assert_eq!(prover.verify(), Ok(()));
+        }
+
+        // The modulus itself is not canonical.
+        let circuit = MyCircuit {
+            a: Some(random_element()),
+            b: Some(random_element()),
+            canonical: Some(modulus),
+            native: Some(pallas::Base::rand()),
+        };
+        let prover = MockProver::<pallas::Base>::run(13, &circuit, vec![]).unwrap();
+        assert!(prover.verify().is_err());
+    }
+
+    #[test]
+    fn pasta_moduli() {
+        // -1 + 1 = m
+        let minus_one = U256::from_little_endian(&(-pallas::Scalar::one()).to_bytes());
+        assert_eq!(minus_one + U256::one(), VestaBase::MODULUS);
+        let minus_one = U256::from_little_endian(&(-pallas::Base::one()).to_bytes());
+        assert_eq!(minus_one + U256::one(), PallasBase::MODULUS);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let m = Secp256k1Base::MODULUS;
+        let a = random_element();
+        let b = random_element();
+
+        assert_eq!(add_mod(sub_mod(a, b, m), b, m), a);
+        assert_eq!(sub_mod(a, a, m), U256::zero());
+        assert_eq!(mul_mod(a, invert_mod(a, m).unwrap(), m), U256::one());
+        assert_eq!(invert_mod(m, m), None);
+    }
+}
@@ -3,7 +3,7 @@ use super::{
     message::{Message, MessagePiece},
     primitive as sinsemilla,
 };
-use ecc::gadget::FixedPoints;
+use ecc::gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE};
 use std::marker::PhantomData;
 use utilities::{
     lookup_range_check::LookupRangeCheckConfig, CellValue, UtilitiesInstructions, Var,
@@ -45,7 +45,7 @@ impl gadget::Point<pallas::Affine, CellValue<pallas::Base>> for Point {
 pub struct SinsemillaConfig<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     /// Binary selector used in lookup argument and in the body of the Sinsemilla hash.
@@ -88,7 +88,7 @@ where
 impl<Hash, Commit, F> SinsemillaConfig<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     /// Returns an array of all advice columns in this config, in arbitrary order.
@@ -107,7 +107,7 @@ pub struct SinsemillaChip<Hash, Commit, Fixed>
 where
     Hash: HashDomains<pallas::Affine>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
 {
     config: SinsemillaConfig<Hash, Commit, Fixed>,
 }
@@ -117,7 +117,7 @@ impl<Hash, Commit, Fixed> UtilitiesInstructions<pallas::Base>
 where
     Hash: HashDomains<pallas::Affine>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
 {
     type Var = CellValue<pallas::Base>;
 }
@@ -126,7 +126,7 @@ impl<Hash, Commit, Fixed> Chip<pallas::Base> for SinsemillaChip<Hash, Commit, Fi
 where
     Hash: HashDomains<pallas::Affine>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
 {
     type Config = SinsemillaConfig<Hash, Commit, Fixed>;
     type Loaded = ();
@@ -143,7 +143,7 @@ where
 impl<Hash, Commit, F> SinsemillaChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     pub fn construct(config: <Self as Chip<pallas::Base>>::Config) -> Self {
@@ -293,7 +293,7 @@ impl<Hash, Commit, F> SinsemillaInstructions<pallas::Affine, { sinsemilla::K },
     for SinsemillaChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     type Message = Message<pallas::Base, { sinsemilla::K }, { sinsemilla::C }>;
@@ -5,7 +5,7 @@ use halo2::{
     poly::Rotation,
 };
 
-use super::{CommitDomains, FixedPoints, HashDomains};
+use super::{CommitDomains, FixedPoints, HashDomains, FIXED_BASE_WINDOW_SIZE};
 use pasta_curves::{arithmetic::FieldExt, pallas};
 
 /// Table containing independent generators S[0..2^k]
@@ -27,7 +27,7 @@ impl GeneratorTableConfig {
         config: super::SinsemillaConfig<Hash, Commit, F>,
     ) where
         Hash: HashDomains<pallas::Affine>,
-        F: FixedPoints<pallas::Affine>,
+        F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
         Commit: CommitDomains<pallas::Affine, F, Hash>,
     {
         let (table_idx, table_x, table_y) = (
@@ -2,7 +2,7 @@ use super::{CellValue, Point, SinsemillaChip, Var};
 use crate::gadget::{CommitDomains, HashDomains, SinsemillaInstructions};
 
 use crate::primitive::{self as sinsemilla, lebs2ip_k, INV_TWO_POW_K, SINSEMILLA_S};
-use ecc::gadget::FixedPoints;
+use ecc::gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE};
 use halo2::{
     circuit::{Chip, Region},
     plonk::Error,
@@ -19,7 +19,7 @@ use std::ops::Deref;
 impl<Hash, Commit, Fixed> SinsemillaChip<Hash, Commit, Fixed>
 where
     Hash: HashDomains<pallas::Affine>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
 {
     #[allow(non_snake_case)]
@@ -1,5 +1,5 @@
 //! Gadget and chips for the Sinsemilla hash function.
-use ecc::gadget::{self as Ecc, EccInstructions, FixedPoints};
+use ecc::gadget::{self as Ecc, EccInstructions, FixedPoints, FIXED_BASE_WINDOW_SIZE};
 use ff::PrimeField;
 use halo2::{circuit::Layouter, plonk::Error};
 use pasta_curves::arithmetic::{CurveAffine, FieldExt};
@@ -40,7 +40,7 @@ pub trait SinsemillaInstructions<C: CurveAffine, const K: usize, const MAX_WORDS
     /// A point output of [`Self::hash_to_point`].
     type Point: Point<C, Self::Var>;
     /// A type enumerating the fixed points used in `CommitDomains`.
-    type FixedPoints: FixedPoints<C>;
+    type FixedPoints: FixedPoints<C, { FIXED_BASE_WINDOW_SIZE }>;
 
     /// HashDomains used in this instruction.
     type HashDomains: HashDomains<C>;
@@ -310,8 +310,11 @@ pub trait Point<C: CurveAffine, V: Var<C::Base>> {
 }
 
 /// Trait allowing circuit's Sinsemilla CommitDomains to be enumerated.
-pub trait CommitDomains<C: CurveAffine, F: FixedPoints<C>, H: HashDomains<C>>:
-    Clone + Debug + Eq
+pub trait CommitDomains<
+    C: CurveAffine,
+    F: FixedPoints<C, { FIXED_BASE_WINDOW_SIZE }>,
+    H: HashDomains<C>,
+>: Clone + Debug + Eq
 {
     /// Returns the fixed point corresponding to the R constant used for
     /// randomization in this CommitDomain.
@@ -386,7 +389,8 @@ where
         assert_eq!(self.M.sinsemilla_chip, message.chip);
         let (blind, _) = self.R.mul(layouter.namespace(|| "[r] R"), r)?;
         let (p, zs) = self.M.hash_to_point(layouter.namespace(|| "M"), message)?;
-        let commitment = p.add_incomplete(layouter.namespace(|| "M ⸭ [r] R"), &blind)?;
+        // [r] R is the identity when r = 0, so we use complete addition here.
+        let commitment = p.add(layouter.namespace(|| "M + [r] R"), &blind)?;
         Ok((commitment, zs))
     }
 
@@ -416,7 +420,7 @@ pub mod testing {
 
     use ecc::{
         chip::{EccChip, EccConfig},
-        gadget::{FixedPoints, Point},
+        gadget::{FixedPoints, Point, FIXED_BASE_WINDOW_SIZE},
     };
     use utilities::lookup_range_check::LookupRangeCheckConfig;
 
@@ -436,17 +440,17 @@ pub mod testing {
     where
         Hash: HashDomains<pallas::Affine>,
         Commit: CommitDomains<pallas::Affine, FixedBase, Hash>,
-        FixedBase: FixedPoints<pallas::Affine>;
+        FixedBase: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>;
 
     impl<Hash, Commit, FixedBase, S: SinsemillaTest<Hash, Commit, FixedBase>> Circuit<pallas::Base>
         for MyCircuit<Hash, Commit, FixedBase, S>
     where
         Hash: HashDomains<pallas::Affine>,
         Commit: CommitDomains<pallas::Affine, FixedBase, Hash>,
-        FixedBase: FixedPoints<pallas::Affine>,
+        FixedBase: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     {
         type Config = (
-            EccConfig,
+            EccConfig<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
             SinsemillaConfig<Hash, Commit, FixedBase>,
             SinsemillaConfig<Hash, Commit, FixedBase>,
         );
@@ -496,12 +500,13 @@ pub mod testing {
 
             let range_check = LookupRangeCheckConfig::configure(meta, advices[9], table_idx);
 
-            let ecc_config = EccChip::<FixedBase>::configure(
-                meta,
-                advices,
-                lagrange_coeffs,
-                range_check.clone(),
-            );
+            let ecc_config =
+                EccChip::<pallas::Affine, FixedBase, { FIXED_BASE_WINDOW_SIZE }>::configure(
+                    meta,
+                    advices,
+                    &lagrange_coeffs,
+                    range_check.clone(),
+                );
 
             let config1 = SinsemillaChip::configure(
                 meta,
@@ -631,7 +636,7 @@ pub mod testing {
     where
         Hash: HashDomains<pallas::Affine>,
         Commit: CommitDomains<pallas::Affine, FixedBase, Hash>,
-        FixedBase: FixedPoints<pallas::Affine>,
+        FixedBase: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     {
         fn hash_domains() -> Vec<Hash>;
         fn commit_domains() -> Vec<Commit>;
@@ -646,8 +651,8 @@ pub mod tests {
         primitive as sinsemilla,
     };
     use ecc::{
-        chip::{compute_lagrange_coeffs, find_zs_and_us, NUM_WINDOWS},
-        gadget::{FixedPoints, H},
+        chip::{compute_lagrange_coeffs, find_zs_and_us_cached, NUM_WINDOWS},
+        gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE},
     };
 
     use group::Curve;
@@ -663,26 +668,27 @@ pub mod tests {
             sinsemilla::CommitDomain::new(*PERSONALIZATION);
         static ref Q: pallas::Affine = COMMIT_DOMAIN.Q().to_affine();
         static ref R: pallas::Affine = COMMIT_DOMAIN.R().to_affine();
-        static ref ZS_AND_US: Vec<(u64, [[u8; 32]; H])> = find_zs_and_us(*R, NUM_WINDOWS).unwrap();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, { FIXED_BASE_WINDOW_SIZE }>(*R, NUM_WINDOWS).unwrap();
     }
 
     #[derive(Debug, Eq, PartialEq, Clone)]
     pub struct FixedBase;
-    impl FixedPoints<pallas::Affine> for FixedBase {
+    impl FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }> for FixedBase {
         fn generator(&self) -> pallas::Affine {
             *R
         }
 
-        fn u(&self) -> Vec<[[u8; 32]; H]> {
-            ZS_AND_US.iter().map(|(_, us)| *us).collect()
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
         }
 
         fn z(&self) -> Vec<u64> {
             ZS_AND_US.iter().map(|(z, _)| *z).collect()
         }
 
-        fn lagrange_coeffs(&self) -> Vec<[pallas::Base; H]> {
-            compute_lagrange_coeffs(self.generator(), NUM_WINDOWS)
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, { FIXED_BASE_WINDOW_SIZE }>(self.generator(), NUM_WINDOWS)
         }
     }
 
@@ -173,7 +173,7 @@ pub mod testing {
         primitive::HashDomain,
     };
 
-    use ecc::gadget::FixedPoints;
+    use ecc::gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE};
     use utilities::{lookup_range_check::LookupRangeCheckConfig, UtilitiesInstructions, Var};
 
     use ff::PrimeFieldBits;
@@ -192,7 +192,7 @@ pub mod testing {
     where
         Hash: HashDomains<pallas::Affine>,
         Commit: CommitDomains<pallas::Affine, FixedBase, Hash>,
-        FixedBase: FixedPoints<pallas::Affine>,
+        FixedBase: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     {
         pub leaf: Option<pallas::Base>,
         pub leaf_pos: Option<u32>,
@@ -205,7 +205,7 @@ pub mod testing {
     where
         Hash: HashDomains<pallas::Affine>,
         Commit: CommitDomains<pallas::Affine, FixedBase, Hash>,
-        FixedBase: FixedPoints<pallas::Affine>,
+        FixedBase: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     {
         type Config = (
             MerkleConfig<Hash, Commit, FixedBase>,
@@ -374,8 +374,8 @@ pub mod testing {
 #[cfg(feature = "test-ecc")]
 pub mod tests {
     use ecc::{
-        chip::{compute_lagrange_coeffs, find_zs_and_us, NUM_WINDOWS},
-        gadget::{FixedPoints, H},
+        chip::{compute_lagrange_coeffs, find_zs_and_us_cached, NUM_WINDOWS},
+        gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE},
     };
 
     use crate::{
@@ -393,26 +393,27 @@ pub mod tests {
         static ref HASH_DOMAIN: HashDomain = HashDomain::new(*PERSONALIZATION);
         static ref Q: pallas::Affine = HASH_DOMAIN.Q().to_affine();
         static ref R: pallas::Affine = CommitDomain::new(*PERSONALIZATION).R().to_affine();
-        static ref ZS_AND_US: Vec<(u64, [[u8; 32]; H])> = find_zs_and_us(*R, NUM_WINDOWS).unwrap();
+        static ref ZS_AND_US: Vec<(u64, Vec<[u8; 32]>)> =
+            find_zs_and_us_cached::<_, { FIXED_BASE_WINDOW_SIZE }>(*R, NUM_WINDOWS).unwrap();
     }
 
     #[derive(Debug, Eq, PartialEq, Clone)]
     pub struct FixedBase;
-    impl FixedPoints<pallas::Affine> for FixedBase {
+    impl FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }> for FixedBase {
         fn generator(&self) -> pallas::Affine {
             *R
         }
 
-        fn u(&self) -> Vec<[[u8; 32]; H]> {
-            ZS_AND_US.iter().map(|(_, us)| *us).collect()
+        fn u(&self) -> Vec<Vec<[u8; 32]>> {
+            ZS_AND_US.iter().map(|(_, us)| us.clone()).collect()
         }
 
         fn z(&self) -> Vec<u64> {
             ZS_AND_US.iter().map(|(z, _)| *z).collect()
         }
 
-        fn lagrange_coeffs(&self) -> Vec<[pallas::Base; H]> {
-            compute_lagrange_coeffs(self.generator(), NUM_WINDOWS)
+        fn lagrange_coeffs(&self) -> Vec<Vec<pallas::Base>> {
+            compute_lagrange_coeffs::<_, { FIXED_BASE_WINDOW_SIZE }>(self.generator(), NUM_WINDOWS)
         }
     }
 
@@ -12,7 +12,7 @@ use crate::{
     gadget::{CommitDomains, HashDomains, SinsemillaInstructions},
     primitive as sinsemilla,
 };
-use ecc::gadget::FixedPoints;
+use ecc::gadget::{FixedPoints, FIXED_BASE_WINDOW_SIZE};
 use std::array;
 use utilities::{
     bitrange_subset,
@@ -24,7 +24,7 @@ use utilities::{
 pub struct MerkleConfig<Hash, Commit, Fixed>
 where
     Hash: HashDomains<pallas::Affine>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
 {
     advices: [Column<Advice>; 5],
@@ -37,7 +37,7 @@ where
 pub struct MerkleChip<Hash, Commit, Fixed>
 where
     Hash: HashDomains<pallas::Affine>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
 {
     config: MerkleConfig<Hash, Commit, Fixed>,
@@ -46,7 +46,7 @@ where
 impl<Hash, Commit, Fixed> Chip<pallas::Base> for MerkleChip<Hash, Commit, Fixed>
 where
     Hash: HashDomains<pallas::Affine>,
-    Fixed: FixedPoints<pallas::Affine>,
+    Fixed: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, Fixed, Hash>,
 {
     type Config = MerkleConfig<Hash, Commit, Fixed>;
@@ -64,7 +64,7 @@ where
 impl<Hash, Commit, F> MerkleChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     pub fn configure(
@@ -180,7 +180,7 @@ impl<Hash, Commit, F>
     for MerkleChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     #[allow(non_snake_case)]
@@ -393,7 +393,7 @@ where
 impl<Hash, Commit, F> UtilitiesInstructions<pallas::Base> for MerkleChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     type Var = CellValue<pallas::Base>;
@@ -402,7 +402,7 @@ where
 impl<Hash, Commit, F> CondSwapInstructions<pallas::Base> for MerkleChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     #[allow(clippy::type_complexity)]
@@ -422,7 +422,7 @@ impl<Hash, Commit, F> SinsemillaInstructions<pallas::Affine, { sinsemilla::K },
     for MerkleChip<Hash, Commit, F>
 where
     Hash: HashDomains<pallas::Affine>,
-    F: FixedPoints<pallas::Affine>,
+    F: FixedPoints<pallas::Affine, { FIXED_BASE_WINDOW_SIZE }>,
     Commit: CommitDomains<pallas::Affine, F, Hash>,
 {
     type Message = <SinsemillaChip<Hash, Commit, F> as SinsemillaInstructions<